@@ -198,8 +198,9 @@ mod tests {
         "remainining_settlement_claim_amount",
     ];
 
-    const PROTECTED_EVENT_DECIMAL_FIELDS: [(&str, &[&str]); 4] = [
+    const PROTECTED_EVENT_DECIMAL_FIELDS: [(&str, &[&str]); 5] = [
         ("DowntimeRevenueImpact", &["expected_epr", "actual_epr"]),
+        ("UptimeGuaranteeBreach", &["expected_epr", "actual_epr"]),
         (
             "CommissionSamIncrease",
             &[
@@ -506,6 +507,17 @@ mod tests {
                 epr_loss_bps: 14,
                 stake: 15,
             },
+            ProtectedEvent::UptimeGuaranteeBreach {
+                vote_account,
+                actual_credits: 1,
+                expected_credits: 2,
+                min_uptime_bps: 9900,
+                actual_uptime_bps: 5000,
+                expected_epr: Decimal::new(3, 4),
+                actual_epr: Decimal::new(5, 4),
+                epr_loss_bps: 6,
+                stake: 7,
+            },
             ProtectedEvent::CommissionIncrease {
                 vote_account,
                 previous_commission: 1,
@@ -1,3 +1,4 @@
+use bonds_collector::commands::bond_products::collect_bond_products;
 use bonds_collector::commands::bonds::collect_bonds;
 use bonds_collector::commands::common::{CollectStakeOptions, CommonCollectOptions};
 use bonds_collector::commands::stake::collect_stake;
@@ -23,6 +24,7 @@ struct Params {
 #[derive(Debug, Subcommand)]
 pub enum Command {
     CollectBonds(CommonCollectOptions),
+    CollectBondProducts(CommonCollectOptions),
     CollectStake(CollectStakeOptions),
 }
 
@@ -56,6 +58,7 @@ async fn real_main() -> anyhow::Result<()> {
 
     match params.command {
         Command::CollectBonds(options) => collect_bonds(options).await?,
+        Command::CollectBondProducts(options) => collect_bond_products(options).await?,
         Command::CollectStake(options) => collect_stake(options).await?,
     };
     Ok(())
@@ -0,0 +1,72 @@
+use crate::commands::common::CommonCollectOptions;
+use crate::utils::rpc::get_rpc_client;
+use log::{log, Level};
+use serde_yaml;
+use std::sync::Arc;
+use validator_bonds_common::bond_products::get_typed_bond_products_for_config;
+use validator_bonds_common::bonds::get_bonds_for_config;
+use validator_bonds_common::cli_result::CliError;
+use validator_bonds_common::dto::ValidatorBondProductsRecord;
+
+pub async fn collect_bond_products(options: CommonCollectOptions) -> anyhow::Result<()> {
+    let rpc_client = Arc::new(get_rpc_client(
+        options.rpc.rpc_url,
+        options.rpc.commitment.to_string(),
+    ));
+
+    let config_address = options.bond_type.config_address();
+    log!(
+        Level::Info,
+        "Collecting bond products '{}', config: {}",
+        options.bond_type,
+        config_address
+    );
+    let current_epoch_info = rpc_client
+        .get_epoch_info()
+        .await
+        .map_err(CliError::retry_able)?;
+    let epoch = current_epoch_info.epoch;
+
+    let bonds = get_bonds_for_config(rpc_client.clone(), &config_address)
+        .await
+        .map_err(CliError::retry_able)?;
+    let typed_bond_products =
+        get_typed_bond_products_for_config(rpc_client.clone(), &config_address, epoch)
+            .await
+            .map_err(CliError::retry_able)?;
+
+    let mut bond_products: Vec<ValidatorBondProductsRecord> = vec![];
+
+    for (pubkey, bond) in bonds {
+        let products = match typed_bond_products.get(&pubkey) {
+            Some(products) => products,
+            None => continue,
+        };
+        if products.uptime_guarantee.is_none() && products.max_stake_cap.is_none() {
+            continue;
+        }
+        bond_products.push(ValidatorBondProductsRecord {
+            bond: pubkey.to_string(),
+            vote_account: bond.vote_account.to_string(),
+            epoch,
+            bond_type: options.bond_type.clone(),
+            uptime_guarantee_bps: products
+                .uptime_guarantee
+                .as_ref()
+                .map(|config| config.min_uptime_bps),
+            max_stake_cap_lamports: products
+                .max_stake_cap
+                .as_ref()
+                .map(|config| config.lamports),
+        })
+    }
+    log!(
+        Level::Info,
+        "Found {} bonds with uptime guarantee or max stake cap products",
+        bond_products.len()
+    );
+
+    serde_yaml::to_writer(std::io::stdout(), &bond_products)?;
+
+    Ok(())
+}
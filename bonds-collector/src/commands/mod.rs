@@ -1,3 +1,4 @@
+pub mod bond_products;
 pub mod bonds;
 pub mod common;
 pub mod stake;
@@ -3,8 +3,12 @@ use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_client::rpc_filter::{Memcmp, RpcFilterType};
 use solana_sdk::bs58;
 use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
 use std::sync::Arc;
-use validator_bonds::state::bond_product::{BondProduct, ProductType};
+use validator_bonds::state::bond_product::{
+    BondProduct, CommissionProductConfig, MaxStakeCapProductConfig, ProductType, ProductTypeConfig,
+    UptimeGuaranteeProductConfig,
+};
 
 use crate::get_validator_bonds_program;
 use crate::utils::get_accounts_for_pubkeys;
@@ -89,3 +93,51 @@ pub async fn find_bond_products(
     let program = get_validator_bonds_program(rpc_client, None)?;
     Ok(program.accounts(filters).await?)
 }
+
+/// Typed products configured for one bond, `None` when the product is not configured.
+#[derive(Default, Clone, Debug)]
+pub struct TypedBondProducts {
+    pub commission: Option<CommissionProductConfig>,
+    pub uptime_guarantee: Option<UptimeGuaranteeProductConfig>,
+    pub max_stake_cap: Option<MaxStakeCapProductConfig>,
+}
+
+impl TypedBondProducts {
+    fn add(&mut self, bond_product: &BondProduct, epoch: u64) {
+        match bond_product.config_for_epoch(epoch) {
+            ProductTypeConfig::Commission(config) => self.commission = Some(config.clone()),
+            ProductTypeConfig::UptimeGuarantee(config) => {
+                self.uptime_guarantee = Some(config.clone())
+            }
+            ProductTypeConfig::MaxStakeCap(config) => self.max_stake_cap = Some(config.clone()),
+            ProductTypeConfig::Custom(_) => {}
+        }
+    }
+}
+
+/// Loads all products of the bonds under the config, grouped by the bond address.
+/// The product configs are resolved for the epoch, see [BondProduct::config_for_epoch].
+/// Custom products are skipped as their data has no known structure.
+pub async fn get_typed_bond_products_for_config(
+    rpc_client: Arc<RpcClient>,
+    config_address: &Pubkey,
+    epoch: u64,
+) -> anyhow::Result<HashMap<Pubkey, TypedBondProducts>> {
+    let bond_products = find_bond_products(
+        rpc_client,
+        FindBondProductsArgs {
+            config: Some(config_address),
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    let mut typed_bond_products: HashMap<Pubkey, TypedBondProducts> = HashMap::new();
+    for (_, bond_product) in bond_products {
+        typed_bond_products
+            .entry(bond_product.bond)
+            .or_default()
+            .add(&bond_product, epoch);
+    }
+    Ok(typed_bond_products)
+}
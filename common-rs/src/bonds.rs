@@ -1,8 +1,10 @@
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_client::rpc_filter::{Memcmp, RpcFilterType};
 use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
 use std::sync::Arc;
 use validator_bonds::state::bond::Bond;
+use validator_bonds::state::bond_ledger::{find_bond_ledger_address, BondLedger};
 
 use crate::get_validator_bonds_program;
 use crate::utils::get_accounts_for_pubkeys;
@@ -32,3 +34,28 @@ pub async fn get_bonds_for_pubkeys(
 ) -> anyhow::Result<Vec<(Pubkey, Option<Bond>)>> {
     get_accounts_for_pubkeys(rpc_client, pubkeys).await
 }
+
+pub async fn get_bond_ledgers_for_bonds(
+    rpc_client: Arc<RpcClient>,
+    bond_pubkeys: &[Pubkey],
+) -> anyhow::Result<Vec<(Pubkey, Option<BondLedger>)>> {
+    let ledger_addresses = bond_pubkeys
+        .iter()
+        .map(|bond| find_bond_ledger_address(bond).0)
+        .collect::<Vec<Pubkey>>();
+    get_accounts_for_pubkeys(rpc_client, &ledger_addresses).await
+}
+
+/// Bond ledger is an optional account, returns mapping of bond pubkey -> bond ledger pubkey
+/// for the bonds that have the ledger initialized.
+pub async fn get_existing_bond_ledgers(
+    rpc_client: Arc<RpcClient>,
+    bond_pubkeys: &[Pubkey],
+) -> anyhow::Result<HashMap<Pubkey, Pubkey>> {
+    let ledgers = get_bond_ledgers_for_bonds(rpc_client, bond_pubkeys).await?;
+    Ok(bond_pubkeys
+        .iter()
+        .zip(ledgers)
+        .filter_map(|(bond, (ledger_address, ledger))| ledger.map(|_| (*bond, ledger_address)))
+        .collect())
+}
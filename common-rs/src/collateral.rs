@@ -0,0 +1,105 @@
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType};
+use solana_sdk::pubkey::Pubkey;
+use std::sync::Arc;
+use validator_bonds::state::collateral_mint::{find_bond_token_vault_address, CollateralMint};
+
+use crate::get_validator_bonds_program;
+use crate::utils::get_account_infos_for_pubkeys;
+
+const CONFIG_ADDRESS_OFFSET: usize = 8;
+// SPL token (and token-2022) account layout starts with: mint (32B), owner (32B), amount (u64)
+const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+
+/// Token collateral of a bond held in the bond token vault of one collateral mint
+#[derive(Clone, Debug)]
+pub struct BondTokenCollateral {
+    pub bond: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub collateral_mint_account: CollateralMint,
+    pub bond_token_vault: Pubkey,
+    /// owner of the bond token vault, i.e., the token program of the mint
+    pub token_program: Pubkey,
+    pub token_amount: u64,
+    /// lamports value of the token amount by the exchange rate after the haircut
+    pub lamports_value: u64,
+}
+
+pub async fn get_collateral_mints_for_config(
+    rpc_client: Arc<RpcClient>,
+    config_address: &Pubkey,
+) -> anyhow::Result<Vec<(Pubkey, CollateralMint)>> {
+    let program = get_validator_bonds_program(rpc_client, None)?;
+    let filters = vec![RpcFilterType::Memcmp(Memcmp::new(
+        CONFIG_ADDRESS_OFFSET,
+        MemcmpEncodedBytes::Base58(config_address.to_string()),
+    ))];
+    Ok(program.accounts(filters).await?)
+}
+
+/// Loads the bond token vaults of all the collateral mints for the bonds,
+/// returns the non-empty ones only.
+pub async fn get_bonds_token_collateral(
+    rpc_client: Arc<RpcClient>,
+    collateral_mints: &[(Pubkey, CollateralMint)],
+    bond_pubkeys: &[Pubkey],
+) -> anyhow::Result<Vec<BondTokenCollateral>> {
+    let vaults = bond_pubkeys
+        .iter()
+        .flat_map(|bond| {
+            collateral_mints
+                .iter()
+                .map(move |(collateral_mint_address, collateral_mint)| {
+                    (
+                        *bond,
+                        *collateral_mint_address,
+                        collateral_mint,
+                        find_bond_token_vault_address(bond, &collateral_mint.mint).0,
+                    )
+                })
+        })
+        .collect::<Vec<_>>();
+    let vault_addresses = vaults
+        .iter()
+        .map(|(_, _, _, vault)| *vault)
+        .collect::<Vec<Pubkey>>();
+    let vault_accounts = get_account_infos_for_pubkeys(rpc_client, &vault_addresses).await?;
+
+    Ok(vaults
+        .into_iter()
+        .zip(vault_accounts)
+        .filter_map(
+            |((bond, collateral_mint_address, collateral_mint, vault), (_, account))| {
+                let account = account?;
+                let token_amount = token_account_amount(&account.data)?;
+                (token_amount > 0).then(|| BondTokenCollateral {
+                    bond,
+                    collateral_mint: collateral_mint_address,
+                    collateral_mint_account: collateral_mint.clone(),
+                    bond_token_vault: vault,
+                    token_program: account.owner,
+                    token_amount,
+                    lamports_value: collateral_mint.lamports_value(token_amount),
+                })
+            },
+        )
+        .collect())
+}
+
+fn token_account_amount(data: &[u8]) -> Option<u64> {
+    data.get(TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8)
+        .map(|amount| u64::from_le_bytes(amount.try_into().expect("slice of 8 bytes")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::token_account_amount;
+
+    #[test]
+    fn token_account_amount_is_read_after_mint_and_owner() {
+        let mut data = vec![0u8; 165];
+        data[64..72].copy_from_slice(&1_234u64.to_le_bytes());
+        assert_eq!(token_account_amount(&data), Some(1_234));
+        assert_eq!(token_account_amount(&data[..70]), None);
+    }
+}
@@ -89,3 +89,14 @@ pub struct ValidatorBondRecord {
     pub mev_commission_bps: Option<i64>,
     pub block_commission_bps: Option<i64>,
 }
+
+/// Typed bond products of a validator, read by the PSR settlement generation.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ValidatorBondProductsRecord {
+    pub bond: String,
+    pub vote_account: String,
+    pub epoch: u64,
+    pub bond_type: BondType,
+    pub uptime_guarantee_bps: Option<u64>,
+    pub max_stake_cap_lamports: Option<u64>,
+}
@@ -1,5 +1,6 @@
 use crate::bond_products::{find_bond_products, FindBondProductsArgs};
 use crate::cli_result::CliError;
+use crate::collateral::{get_bonds_token_collateral, get_collateral_mints_for_config};
 use crate::{
     bonds::get_bonds_for_config,
     settlements::get_settlements_for_config,
@@ -10,7 +11,7 @@ use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::pubkey::Pubkey;
 use std::{collections::HashMap, sync::Arc};
 use validator_bonds::state::bond_product::{
-    CommissionProductConfig, ProductType, ProductTypeConfig,
+    BondProduct, CommissionProductConfig, ProductType, ProductTypeConfig,
 };
 use validator_bonds::state::withdraw_request::WithdrawRequest;
 use validator_bonds::state::{bond::Bond, config::find_bonds_withdrawer_authority};
@@ -18,6 +19,8 @@ use validator_bonds::state::{bond::Bond, config::find_bonds_withdrawer_authority
 #[derive(Default, Clone, Debug)]
 pub struct Funds {
     pub funded_amount: u64,
+    /// lamports value of the bond token collateral, part of the funded amount
+    pub token_collateral_amount: u64,
     pub effective_amount: u64,
     pub remaining_witdraw_request_amount: u64,
     pub remainining_settlement_claim_amount: u64,
@@ -34,6 +37,7 @@ fn outstanding_withdraw_amount(requested: u64, withdrawn: u64, available: u64) -
 
 fn aggregate_funds(
     delegated_stake: &[(Pubkey, u64)],
+    token_collateral: &[(Pubkey, u64)],
     withdraw_requests: &[(Pubkey, u64, u64)],
     settlement_claims: &[(Pubkey, u64)],
 ) -> HashMap<Pubkey, Funds> {
@@ -45,6 +49,15 @@ fn aggregate_funds(
         funds.effective_amount += *lamports;
     }
 
+    // Token collateral is valued by the collateral mint exchange rate after the haircut,
+    // it is withdrawn by withdraw requests and it funds settlements the same way as stake.
+    for (vote_account, lamports_value) in token_collateral {
+        let funds = validator_funds.entry(*vote_account).or_default();
+        funds.funded_amount += *lamports_value;
+        funds.token_collateral_amount += *lamports_value;
+        funds.effective_amount += *lamports_value;
+    }
+
     // Before the withdraw loop: settlement-funded stake is not withdrawable, so a "withdraw
     // everything" request must not report it as available.
     for (vote_account, claim) in settlement_claims {
@@ -53,7 +66,12 @@ fn aggregate_funds(
         funds.effective_amount = funds.effective_amount.saturating_sub(*claim);
     }
 
-    for (vote_account, requested, withdrawn) in withdraw_requests {
+    // A bond may have several withdraw requests (indexed ones next to the legacy one).
+    // The "withdraw everything" requests go last to take only what the other requests left.
+    let (sentinel_requests, amount_requests): (Vec<_>, Vec<_>) = withdraw_requests
+        .iter()
+        .partition(|(_, requested, _)| *requested == u64::MAX);
+    for (vote_account, requested, withdrawn) in amount_requests.iter().chain(&sentinel_requests) {
         let funds = validator_funds.entry(*vote_account).or_default();
         let outstanding =
             outstanding_withdraw_amount(*requested, *withdrawn, funds.effective_amount);
@@ -64,6 +82,15 @@ fn aggregate_funds(
     validator_funds
 }
 
+// A scheduled commission change applies from its effective epoch, independently of when
+// the bond product account was last written.
+fn commission_config_for_epoch(bond_product: &BondProduct, epoch: u64) -> CommissionProductConfig {
+    match bond_product.config_for_epoch(epoch) {
+        ProductTypeConfig::Commission(data) => data.clone(),
+        _ => CommissionProductConfig::default(),
+    }
+}
+
 pub async fn collect_validator_bonds_with_funds(
     rpc_client: Arc<RpcClient>,
     config_address: Pubkey,
@@ -107,11 +134,25 @@ pub async fn collect_validator_bonds_with_funds(
         }
     }
 
+    let collateral_mints = get_collateral_mints_for_config(rpc_client.clone(), &config_address)
+        .await
+        .map_err(CliError::retry_able)?;
+    let bond_pubkeys = bonds.keys().copied().collect::<Vec<Pubkey>>();
+    let bonds_token_collateral =
+        get_bonds_token_collateral(rpc_client.clone(), &collateral_mints, &bond_pubkeys)
+            .await
+            .map_err(CliError::retry_able)?;
+
     log::info!("Found bonds: {}", bonds.len());
     log::info!("Found stake accounts: {}", stake_accounts.len());
     log::info!("Found withdraw requests: {}", withdraw_requests.len());
     log::info!("Found settlements: {}", settlements.len());
     log::info!("Found bond commission products: {}", bond_products.len());
+    log::info!(
+        "Found bond token collaterals: {} (collateral mints: {})",
+        bonds_token_collateral.len(),
+        collateral_mints.len()
+    );
 
     let clock = get_clock(rpc_client.clone())
         .await
@@ -128,6 +169,15 @@ pub async fn collect_validator_bonds_with_funds(
         }
     }
 
+    let token_collateral: Vec<(Pubkey, u64)> = bonds_token_collateral
+        .iter()
+        .filter_map(|collateral| {
+            bonds
+                .get(&collateral.bond)
+                .map(|bond| (bond.vote_account, collateral.lamports_value))
+        })
+        .collect();
+
     let withdraw_request_amounts: Vec<(Pubkey, u64, u64)> = withdraw_requests
         .into_iter()
         .map(|(_, wr)| (wr.vote_account, wr.requested_amount, wr.withdrawn_amount))
@@ -152,6 +202,7 @@ pub async fn collect_validator_bonds_with_funds(
 
     let validator_funds = aggregate_funds(
         &delegated_stake,
+        &token_collateral,
         &withdraw_request_amounts,
         &settlement_claims,
     );
@@ -165,10 +216,7 @@ pub async fn collect_validator_bonds_with_funds(
                 .unwrap_or_default();
             let commission_config = bond_products
                 .get(&pubkey)
-                .and_then(|(_, bp)| match &bp.config_data {
-                    ProductTypeConfig::Commission(data) => Some(data.clone()),
-                    _ => None,
-                })
+                .map(|(_, bp)| commission_config_for_epoch(bp, clock.epoch))
                 .unwrap_or_default();
             (pubkey, bond, funds, commission_config)
         })
@@ -177,8 +225,11 @@ pub async fn collect_validator_bonds_with_funds(
 
 #[cfg(test)]
 mod tests {
-    use super::{aggregate_funds, outstanding_withdraw_amount};
+    use super::{aggregate_funds, commission_config_for_epoch, outstanding_withdraw_amount};
     use solana_sdk::pubkey::Pubkey;
+    use validator_bonds::state::bond_product::{
+        BondProduct, CommissionProductConfig, PendingProductConfig, ProductType, ProductTypeConfig,
+    };
 
     // Stake funded to a settlement keeps the bonds withdrawer authority, so it is counted into
     // `funded_amount`, but `claim_withdraw_request` refuses to withdraw it
@@ -188,6 +239,7 @@ mod tests {
         let vote_account = Pubkey::new_unique();
         let funds = aggregate_funds(
             &[(vote_account, 100)],
+            &[],
             &[(vote_account, u64::MAX, 0)],
             &[(vote_account, 60)],
         );
@@ -209,6 +261,7 @@ mod tests {
         let vote_account = Pubkey::new_unique();
         let funds = aggregate_funds(
             &[(vote_account, 100)],
+            &[],
             &[(vote_account, u64::MAX, 0)],
             &[(vote_account, 60)],
         );
@@ -223,6 +276,57 @@ mod tests {
         );
     }
 
+    // A bond may run several withdraw requests side by side (indexed withdraw requests),
+    // each one is accounted on its own and the outstanding amounts are summed up.
+    #[test]
+    fn multiple_withdraw_requests_of_one_bond_are_summed() {
+        let vote_account = Pubkey::new_unique();
+        let funds = aggregate_funds(
+            &[(vote_account, 100)],
+            &[],
+            &[(vote_account, 30, 10), (vote_account, 50, 0)],
+            &[],
+        );
+        let funds = funds.get(&vote_account).unwrap();
+
+        assert_eq!(funds.remaining_witdraw_request_amount, 70);
+        assert_eq!(funds.effective_amount, 30);
+    }
+
+    // Token collateral is funded the same way as stake and it is withdrawable by a withdraw request.
+    #[test]
+    fn token_collateral_is_counted_into_funds() {
+        let vote_account = Pubkey::new_unique();
+        let funds = aggregate_funds(
+            &[(vote_account, 100)],
+            &[(vote_account, 50)],
+            &[(vote_account, u64::MAX, 0)],
+            &[(vote_account, 60)],
+        );
+        let funds = funds.get(&vote_account).unwrap();
+
+        assert_eq!(funds.funded_amount, 150);
+        assert_eq!(funds.token_collateral_amount, 50);
+        assert_eq!(funds.remaining_witdraw_request_amount, 90);
+        assert_eq!(funds.effective_amount, 0);
+    }
+
+    // The sentinel request takes what is left after the other requests processed before it.
+    #[test]
+    fn sentinel_request_next_to_other_requests_is_not_double_counted() {
+        let vote_account = Pubkey::new_unique();
+        let funds = aggregate_funds(
+            &[(vote_account, 100)],
+            &[],
+            &[(vote_account, u64::MAX, 0), (vote_account, 30, 0)],
+            &[],
+        );
+        let funds = funds.get(&vote_account).unwrap();
+
+        assert_eq!(funds.remaining_witdraw_request_amount, 100);
+        assert_eq!(funds.effective_amount, 0);
+    }
+
     #[test]
     fn request_is_reported_verbatim() {
         assert_eq!(outstanding_withdraw_amount(50, 10, 100), 40);
@@ -265,4 +369,43 @@ mod tests {
             );
         }
     }
+
+    fn commission(inflation_bps: i64) -> ProductTypeConfig {
+        ProductTypeConfig::Commission(CommissionProductConfig {
+            inflation_bps: Some(inflation_bps),
+            mev_bps: None,
+            block_bps: None,
+        })
+    }
+
+    // The resolved commission depends on the epoch only, not on when the snapshot of
+    // the bond product was taken relative to the scheduled change.
+    #[test]
+    fn pending_commission_applies_from_its_effective_epoch() {
+        let bond_product = BondProduct {
+            config: Pubkey::new_unique(),
+            bond: Pubkey::new_unique(),
+            vote_account: Pubkey::new_unique(),
+            product_type: ProductType::Commission,
+            config_data: commission(500),
+            bump: 255,
+            pending_config: Some(PendingProductConfig {
+                config_data: commission(700),
+                effective_from_epoch: 10,
+            }),
+        };
+
+        assert_eq!(
+            commission_config_for_epoch(&bond_product, 9).inflation_bps,
+            Some(500)
+        );
+        assert_eq!(
+            commission_config_for_epoch(&bond_product, 10).inflation_bps,
+            Some(700)
+        );
+        assert_eq!(
+            commission_config_for_epoch(&bond_product, 11).inflation_bps,
+            Some(700)
+        );
+    }
 }
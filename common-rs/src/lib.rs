@@ -6,6 +6,7 @@ use std::{str::FromStr, sync::Arc};
 pub mod bond_products;
 pub mod bonds;
 pub mod cli_result;
+pub mod collateral;
 pub mod config;
 pub mod constants;
 pub mod dto;
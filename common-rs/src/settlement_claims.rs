@@ -1,36 +1,97 @@
 use anchor_client::anchor_lang::AccountDeserialize;
-use anyhow::anyhow;
+use anyhow::{anyhow, bail};
 use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
 use std::fmt::Debug;
-use validator_bonds::constants::SETTLEMENT_CLAIMS_ANCHOR_HEADER_SIZE;
+use validator_bonds::constants::{
+    SETTLEMENT_CLAIMS_ANCHOR_HEADER_SIZE, SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS,
+};
+use validator_bonds::state::settlement::find_settlement_claims_chunk_address;
 use validator_bonds::state::settlement_claims::SettlementClaims;
 use validator_bonds::utils::BitmapProjection;
 
 /// Off-chain handler for accessing bitmap from SettlementClaims account
 /// The struct stores memory "copied" from the loaded Solana account.
 /// The struct provides methods redirecting to [BitmapProjection] to access the bitmap data.
+/// When the bitmap is split into chunk accounts, the chunk bitmaps are placed one after another,
+/// the records of chunks that were not added are considered as not set.
 pub struct SettlementClaimsBitmap {
     pub data: Vec<u8>,
     bitmap_projection: BitmapProjection,
+    settlement: Pubkey,
+    chunks: u16,
+}
+
+fn deserialize_settlement_claims(account: &Account) -> anyhow::Result<(SettlementClaims, Vec<u8>)> {
+    let mut data = account.data.to_vec();
+    let settlement_claims = SettlementClaims::try_deserialize(&mut data.as_slice()).map_or_else(
+        |e| Err(anyhow!("Cannot deserialize SettlementClaims data: {e}")),
+        Ok,
+    )?;
+    data.drain(0..SETTLEMENT_CLAIMS_ANCHOR_HEADER_SIZE as usize);
+    BitmapProjection::check_size(settlement_claims.max_records, &data)?;
+    Ok((settlement_claims, data))
 }
 
 impl SettlementClaimsBitmap {
     pub fn new(account: Account) -> anyhow::Result<Self> {
-        let mut data = account.data.to_vec();
-        let settlement_claims = SettlementClaims::try_deserialize(&mut data.as_slice())
-            .map_or_else(
-                |e| Err(anyhow!("Cannot deserialize SettlementClaims data: {e}")),
-                Ok,
-            )?;
-        data.drain(0..SETTLEMENT_CLAIMS_ANCHOR_HEADER_SIZE as usize);
-        BitmapProjection::check_size(settlement_claims.max_records, &data)?;
-        let bitmap_projection = BitmapProjection(settlement_claims.max_records);
+        let (settlement_claims, mut data) = deserialize_settlement_claims(&account)?;
+        let max_records = if settlement_claims.chunks > 0 {
+            let max_records = settlement_claims.chunks as u64 * SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS;
+            data.resize(BitmapProjection::bitmap_size_in_bytes(max_records), 0);
+            max_records
+        } else {
+            settlement_claims.max_records
+        };
         Ok(Self {
             data,
-            bitmap_projection,
+            bitmap_projection: BitmapProjection(max_records),
+            settlement: settlement_claims.settlement,
+            chunks: settlement_claims.chunks,
         })
     }
 
+    /// Number of chunks the bitmap is split into, 0 when not chunked
+    pub fn chunks(&self) -> u16 {
+        self.chunks
+    }
+
+    /// Chunk of the bitmap the claim record index belongs to, always 0 when not chunked
+    pub fn chunk_index(&self, index: u64) -> u16 {
+        if self.chunks == 0 {
+            0
+        } else {
+            (index / SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS) as u16
+        }
+    }
+
+    /// Address of the SettlementClaims (chunk) account to be used for claiming the record index
+    pub fn claims_address(&self, index: u64) -> Pubkey {
+        find_settlement_claims_chunk_address(&self.settlement, self.chunk_index(index)).0
+    }
+
+    /// Adds bitmap data of a chunk account created by 'init_settlement_claims_chunk'
+    pub fn add_chunk(&mut self, account: Account) -> anyhow::Result<()> {
+        let (chunk, chunk_data) = deserialize_settlement_claims(&account)?;
+        if chunk.settlement != self.settlement
+            || chunk.chunk_index == 0
+            || chunk.chunk_index >= self.chunks
+        {
+            bail!(
+                "SettlementClaims chunk {} of settlement {} does not match settlement {} with {} chunks",
+                chunk.chunk_index,
+                chunk.settlement,
+                self.settlement,
+                self.chunks
+            );
+        }
+        let offset = BitmapProjection::bitmap_size_in_bytes(
+            chunk.chunk_index as u64 * SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS,
+        );
+        self.data[offset..offset + chunk_data.len()].copy_from_slice(&chunk_data);
+        Ok(())
+    }
+
     pub fn max_records(&self) -> u64 {
         self.bitmap_projection.0
     }
@@ -59,3 +120,67 @@ impl Debug for SettlementClaimsBitmap {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_client::anchor_lang::AccountSerialize;
+
+    fn settlement_claims_account(settlement_claims: SettlementClaims, set: &[u64]) -> Account {
+        let bitmap_projection = BitmapProjection(settlement_claims.max_records);
+        let mut data = vec![];
+        settlement_claims.try_serialize(&mut data).unwrap();
+        data.resize(SETTLEMENT_CLAIMS_ANCHOR_HEADER_SIZE as usize, 0);
+        let mut bitmap =
+            vec![0u8; BitmapProjection::bitmap_size_in_bytes(settlement_claims.max_records)];
+        for index in set {
+            bitmap_projection.try_to_set(*index, &mut bitmap).unwrap();
+        }
+        data.extend(bitmap);
+        Account {
+            lamports: 1,
+            data,
+            owner: validator_bonds::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    #[test]
+    fn chunked_bitmap() {
+        let settlement = Pubkey::new_unique();
+        let chunk = |chunk_index: u16, set: &[u64]| {
+            settlement_claims_account(
+                SettlementClaims {
+                    settlement,
+                    version: 0,
+                    max_records: SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS,
+                    chunk_index,
+                    chunks: 3,
+                    chunks_initialized: 0,
+                    bump: 0,
+                },
+                set,
+            )
+        };
+        let mut bitmap = SettlementClaimsBitmap::new(chunk(0, &[3])).unwrap();
+        assert_eq!(
+            bitmap.max_records(),
+            3 * SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS
+        );
+        bitmap.add_chunk(chunk(2, &[7])).unwrap();
+        assert!(bitmap.is_set(3));
+        assert!(bitmap.is_set(2 * SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS + 7));
+        assert!(!bitmap.is_set(SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS + 7));
+        assert_eq!(bitmap.number_of_set_bits(), 2);
+        assert!(bitmap.add_chunk(chunk(0, &[])).is_err());
+        assert_eq!(
+            bitmap.chunk_index(SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS - 1),
+            0
+        );
+        assert_eq!(
+            bitmap.chunk_index(2 * SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS),
+            2
+        );
+    }
+}
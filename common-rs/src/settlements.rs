@@ -10,7 +10,9 @@ use solana_sdk::pubkey::Pubkey;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use validator_bonds::state::bond::Bond;
-use validator_bonds::state::settlement::{find_settlement_claims_address, Settlement};
+use validator_bonds::state::settlement::{
+    find_settlement_claims_address, find_settlement_claims_chunk_address, Settlement,
+};
 
 pub async fn get_settlements(
     rpc_client: Arc<RpcClient>,
@@ -89,6 +91,7 @@ pub async fn get_settlements_for_pubkeys(
 }
 
 /// settlement address, settlement claims address, settlement claims bitmap
+/// (bitmap of the settlement claims chunk accounts is loaded into the settlement claims bitmap)
 pub async fn get_settlement_claims_for_settlement_pubkeys(
     rpc_client: Arc<RpcClient>,
     settlement_pubkeys: &[Pubkey],
@@ -97,22 +100,53 @@ pub async fn get_settlement_claims_for_settlement_pubkeys(
         .iter()
         .map(|settlement_pubkey| find_settlement_claims_address(settlement_pubkey).0)
         .collect::<Vec<Pubkey>>();
-    let settlement_claims = get_account_infos_for_pubkeys(rpc_client, &settlement_claims_pubkeys)
-        .await?
-        .into_iter()
-        .map(|(pubkey, account)| {
-            if let Some(account) = account {
-                Ok((pubkey, Some(SettlementClaimsBitmap::new(account)?)))
-            } else {
-                Ok((pubkey, None))
+    let mut settlement_claims =
+        get_account_infos_for_pubkeys(rpc_client.clone(), &settlement_claims_pubkeys)
+            .await?
+            .into_iter()
+            .map(|(pubkey, account)| {
+                if let Some(account) = account {
+                    Ok((pubkey, Some(SettlementClaimsBitmap::new(account)?)))
+                } else {
+                    Ok((pubkey, None))
+                }
+            })
+            .collect::<anyhow::Result<
+                Vec<(
+                    Pubkey,
+                    Option<crate::settlement_claims::SettlementClaimsBitmap>,
+                )>,
+            >>()?;
+
+    let mut chunk_positions: Vec<usize> = vec![];
+    let mut chunk_pubkeys: Vec<Pubkey> = vec![];
+    for (position, (settlement_pubkey, (_, settlement_claims_bitmap))) in settlement_pubkeys
+        .iter()
+        .zip(settlement_claims.iter())
+        .enumerate()
+    {
+        if let Some(settlement_claims_bitmap) = settlement_claims_bitmap {
+            for chunk_index in 1..settlement_claims_bitmap.chunks() {
+                chunk_positions.push(position);
+                chunk_pubkeys
+                    .push(find_settlement_claims_chunk_address(settlement_pubkey, chunk_index).0);
             }
-        })
-        .collect::<anyhow::Result<
-            Vec<(
-                Pubkey,
-                Option<crate::settlement_claims::SettlementClaimsBitmap>,
-            )>,
-        >>()?;
+        }
+    }
+    if !chunk_pubkeys.is_empty() {
+        let chunk_accounts = get_account_infos_for_pubkeys(rpc_client, &chunk_pubkeys).await?;
+        for (position, (chunk_pubkey, chunk_account)) in
+            chunk_positions.into_iter().zip(chunk_accounts.into_iter())
+        {
+            match (chunk_account, &mut settlement_claims[position].1) {
+                (Some(chunk_account), Some(settlement_claims_bitmap)) => {
+                    settlement_claims_bitmap.add_chunk(chunk_account)?
+                }
+                _ => debug!("SettlementClaims chunk {chunk_pubkey} not found"),
+            }
+        }
+    }
+
     let result = settlement_pubkeys
         .iter()
         .zip(settlement_claims.into_iter())
@@ -129,6 +163,31 @@ pub async fn get_settlement_claims_for_settlement_pubkeys(
     Ok(result)
 }
 
+/// Addresses of the existing SettlementClaims chunk accounts of the settlement ordered by the chunk index,
+/// empty when the claims bitmap is not split into chunks
+pub async fn get_settlement_claims_chunk_addresses(
+    rpc_client: Arc<RpcClient>,
+    settlement_pubkey: &Pubkey,
+) -> anyhow::Result<Vec<Pubkey>> {
+    let chunks =
+        get_settlement_claims_for_settlement_pubkeys(rpc_client.clone(), &[*settlement_pubkey])
+            .await?
+            .into_iter()
+            .next()
+            .and_then(|(_, _, settlement_claims_bitmap)| settlement_claims_bitmap)
+            .map_or(0, |settlement_claims_bitmap| {
+                settlement_claims_bitmap.chunks()
+            });
+    let chunk_pubkeys = (1..chunks)
+        .map(|chunk_index| find_settlement_claims_chunk_address(settlement_pubkey, chunk_index).0)
+        .collect::<Vec<Pubkey>>();
+    Ok(get_account_infos_for_pubkeys(rpc_client, &chunk_pubkeys)
+        .await?
+        .into_iter()
+        .filter_map(|(chunk_pubkey, chunk_account)| chunk_account.map(|_| chunk_pubkey))
+        .collect())
+}
+
 pub async fn get_bonds_for_settlements(
     rpc_client: Arc<RpcClient>,
     settlements: &[(Pubkey, Settlement)],
@@ -0,0 +1,388 @@
+//! Incremental merkle tree building from a stream of leaves.
+//!
+//! The [`MerkleTreeBuilder`] keeps only the frontier of not yet paired nodes (one hash per level)
+//! to compute the root, producing the same root as [`crate::MerkleTree::new`]
+//! (including the duplication of the last node of an odd level and the `sorted_hashes` semantics).
+//! When proofs are needed the builder is created with a [`NodeStore`] where all tree nodes
+//! are appended level by level, the proofs are then read from the store.
+
+use crate::{INTERMEDIATE_PREFIX, LEAF_PREFIX};
+use solana_program::hash::{hashv, Hash};
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+const HASH_BYTES: usize = 32;
+
+/// Storage of the merkle tree nodes, nodes of every level are appended in order of their index.
+pub trait NodeStore {
+    fn append(&mut self, level: usize, hash: Hash) -> io::Result<()>;
+
+    /// Called by the builder when all nodes are appended, the store is read only afterward.
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn get(&self, level: usize, index: usize) -> io::Result<Hash>;
+
+    fn level_len(&self, level: usize) -> usize;
+}
+
+/// No nodes are stored, only the root can be computed.
+#[derive(Debug, Default)]
+pub struct NoNodeStore;
+
+impl NodeStore for NoNodeStore {
+    fn append(&mut self, _level: usize, _hash: Hash) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn get(&self, level: usize, index: usize) -> io::Result<Hash> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("no node store configured, cannot read node {index} at level {level}"),
+        ))
+    }
+
+    fn level_len(&self, _level: usize) -> usize {
+        0
+    }
+}
+
+/// Nodes kept in memory, 32 bytes per node without the overhead of the tree items.
+#[derive(Debug, Default)]
+pub struct MemoryNodeStore {
+    levels: Vec<Vec<Hash>>,
+}
+
+impl NodeStore for MemoryNodeStore {
+    fn append(&mut self, level: usize, hash: Hash) -> io::Result<()> {
+        if self.levels.len() <= level {
+            self.levels.resize_with(level + 1, Vec::new);
+        }
+        self.levels[level].push(hash);
+        Ok(())
+    }
+
+    fn get(&self, level: usize, index: usize) -> io::Result<Hash> {
+        self.levels
+            .get(level)
+            .and_then(|nodes| nodes.get(index))
+            .copied()
+            .ok_or_else(|| node_not_found(level, index))
+    }
+
+    fn level_len(&self, level: usize) -> usize {
+        self.levels.get(level).map_or(0, Vec::len)
+    }
+}
+
+/// Nodes stored on disk in a directory, one file per tree level with 32 bytes per node.
+#[derive(Debug)]
+pub struct FileNodeStore {
+    dir: PathBuf,
+    writers: Vec<BufWriter<File>>,
+    readers: Vec<File>,
+    level_lens: Vec<usize>,
+}
+
+impl FileNodeStore {
+    /// Creates the directory when it does not exist, existing level files are overwritten.
+    pub fn new(dir: &Path) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            writers: Vec::new(),
+            readers: Vec::new(),
+            level_lens: Vec::new(),
+        })
+    }
+
+    fn level_path(&self, level: usize) -> PathBuf {
+        self.dir.join(format!("level_{level}.bin"))
+    }
+}
+
+impl NodeStore for FileNodeStore {
+    fn append(&mut self, level: usize, hash: Hash) -> io::Result<()> {
+        if !self.readers.is_empty() {
+            return Err(io::Error::other("file node store is already flushed"));
+        }
+        while self.writers.len() <= level {
+            let file = File::create(self.level_path(self.writers.len()))?;
+            self.writers.push(BufWriter::new(file));
+            self.level_lens.push(0);
+        }
+        self.writers[level].write_all(hash.as_ref())?;
+        self.level_lens[level] += 1;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        for writer in self.writers.iter_mut() {
+            writer.flush()?;
+        }
+        self.writers.clear();
+        self.readers = (0..self.level_lens.len())
+            .map(|level| File::open(self.level_path(level)))
+            .collect::<io::Result<Vec<File>>>()?;
+        Ok(())
+    }
+
+    fn get(&self, level: usize, index: usize) -> io::Result<Hash> {
+        if index >= self.level_len(level) {
+            return Err(node_not_found(level, index));
+        }
+        let mut reader = self.readers.get(level).ok_or_else(|| {
+            io::Error::other(format!("file node store level {level} is not flushed"))
+        })?;
+        let mut bytes = [0u8; HASH_BYTES];
+        reader.seek(SeekFrom::Start((index * HASH_BYTES) as u64))?;
+        reader.read_exact(&mut bytes)?;
+        Ok(Hash::new_from_array(bytes))
+    }
+
+    fn level_len(&self, level: usize) -> usize {
+        self.level_lens.get(level).copied().unwrap_or(0)
+    }
+}
+
+fn node_not_found(level: usize, index: usize) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("merkle tree node {index} at level {level} not found in the node store"),
+    )
+}
+
+fn hash_pair(lsib: &Hash, rsib: &Hash, sorted_hashes: bool) -> Hash {
+    if sorted_hashes && lsib > rsib {
+        hashv(&[INTERMEDIATE_PREFIX, rsib.as_ref(), lsib.as_ref()])
+    } else {
+        hashv(&[INTERMEDIATE_PREFIX, lsib.as_ref(), rsib.as_ref()])
+    }
+}
+
+/// Builds the merkle tree from leaves pushed one by one, keeping O(log n) frontier hashes.
+#[derive(Debug)]
+pub struct MerkleTreeBuilder<S: NodeStore = NoNodeStore> {
+    sorted_hashes: bool,
+    leaf_count: usize,
+    /// left node of each level waiting for its right sibling
+    frontier: Vec<Option<Hash>>,
+    node_store: S,
+}
+
+impl MerkleTreeBuilder<NoNodeStore> {
+    pub fn new(sorted_hashes: bool) -> Self {
+        Self::with_node_store(sorted_hashes, NoNodeStore)
+    }
+}
+
+impl<S: NodeStore> MerkleTreeBuilder<S> {
+    pub fn with_node_store(sorted_hashes: bool, node_store: S) -> Self {
+        Self {
+            sorted_hashes,
+            leaf_count: 0,
+            frontier: Vec::new(),
+            node_store,
+        }
+    }
+
+    pub fn leaf_count(&self) -> usize {
+        self.leaf_count
+    }
+
+    pub fn push_leaf<T: AsRef<[u8]>>(&mut self, item: T) -> io::Result<()> {
+        let mut node = hashv(&[LEAF_PREFIX, item.as_ref()]);
+        self.leaf_count += 1;
+        let mut level = 0;
+        loop {
+            self.node_store.append(level, node)?;
+            if self.frontier.len() <= level {
+                self.frontier.push(None);
+            }
+            match self.frontier[level].take() {
+                None => {
+                    self.frontier[level] = Some(node);
+                    return Ok(());
+                }
+                Some(left) => {
+                    node = hash_pair(&left, &node, self.sorted_hashes);
+                    level += 1;
+                }
+            }
+        }
+    }
+
+    /// Pairs the remaining frontier nodes, the last node of an odd level is paired with itself.
+    pub fn finalize(mut self) -> io::Result<StreamedMerkleTree<S>> {
+        let mut level_len = self.leaf_count;
+        // partial node of the level built from the incomplete subtree on the right
+        let mut carry: Option<Hash> = None;
+        let mut level = 0;
+        let mut root = None;
+        while level_len > 0 {
+            if let Some(node) = carry {
+                self.node_store.append(level, node)?;
+            }
+            let left = self.frontier.get_mut(level).and_then(Option::take);
+            if level_len == 1 {
+                root = carry.or(left);
+                break;
+            }
+            carry = match (left, carry) {
+                (Some(left), Some(right)) => Some(hash_pair(&left, &right, self.sorted_hashes)),
+                (Some(node), None) | (None, Some(node)) => {
+                    Some(hash_pair(&node, &node, self.sorted_hashes))
+                }
+                (None, None) => None,
+            };
+            level += 1;
+            level_len = level_len.div_ceil(2);
+        }
+        self.node_store.flush()?;
+        Ok(StreamedMerkleTree {
+            leaf_count: self.leaf_count,
+            root,
+            node_store: self.node_store,
+        })
+    }
+}
+
+/// Merkle tree computed by the [`MerkleTreeBuilder`].
+#[derive(Debug)]
+pub struct StreamedMerkleTree<S: NodeStore = NoNodeStore> {
+    leaf_count: usize,
+    root: Option<Hash>,
+    node_store: S,
+}
+
+impl<S: NodeStore> StreamedMerkleTree<S> {
+    pub fn get_root(&self) -> Option<&Hash> {
+        self.root.as_ref()
+    }
+
+    pub fn leaf_count(&self) -> usize {
+        self.leaf_count
+    }
+
+    pub fn into_node_store(self) -> S {
+        self.node_store
+    }
+
+    /// Sibling hashes from the leaf level up to the root; the same proof as `MerkleTree::find_path` provides.
+    pub fn find_proof(&self, index: usize) -> io::Result<Option<Vec<Hash>>> {
+        if index >= self.leaf_count {
+            return Ok(None);
+        }
+        let mut proof = Vec::new();
+        let mut level_len = self.leaf_count;
+        let mut node_index = index;
+        let mut level = 0;
+        while level_len > 1 {
+            let sibling_index = if node_index % 2 == 1 {
+                node_index - 1
+            } else if node_index + 1 < level_len {
+                node_index + 1
+            } else {
+                node_index
+            };
+            proof.push(self.node_store.get(level, sibling_index)?);
+            node_index /= 2;
+            level_len = level_len.div_ceil(2);
+            level += 1;
+        }
+        Ok(Some(proof))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MerkleTree;
+
+    fn items(count: usize) -> Vec<[u8; 8]> {
+        (0..count as u64).map(|i| i.to_le_bytes()).collect()
+    }
+
+    fn tree_proof(merkle_tree: &MerkleTree, index: usize) -> Vec<Hash> {
+        merkle_tree
+            .find_path(index)
+            .unwrap()
+            .get_proof_entries()
+            .iter()
+            .map(|entry| {
+                *entry
+                    .get_left_sibling()
+                    .or(entry.get_right_sibling())
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    fn build<S: NodeStore>(
+        items: &[[u8; 8]],
+        sorted_hashes: bool,
+        node_store: S,
+    ) -> StreamedMerkleTree<S> {
+        let mut builder = MerkleTreeBuilder::with_node_store(sorted_hashes, node_store);
+        for item in items {
+            builder.push_leaf(item).unwrap();
+        }
+        builder.finalize().unwrap()
+    }
+
+    #[test]
+    fn test_root_matches_merkle_tree() {
+        for sorted_hashes in [false, true] {
+            for count in 0..100 {
+                let items = items(count);
+                let merkle_tree = MerkleTree::new(&items, sorted_hashes);
+                let streamed = build(&items, sorted_hashes, NoNodeStore);
+                assert_eq!(streamed.get_root(), merkle_tree.get_root(), "count {count}");
+                assert_eq!(streamed.leaf_count(), count);
+            }
+        }
+    }
+
+    #[test]
+    fn test_proofs_match_merkle_tree() {
+        for sorted_hashes in [false, true] {
+            for count in 1..70 {
+                let items = items(count);
+                let merkle_tree = MerkleTree::new(&items, sorted_hashes);
+                let streamed = build(&items, sorted_hashes, MemoryNodeStore::default());
+                for index in 0..count {
+                    assert_eq!(
+                        streamed.find_proof(index).unwrap().unwrap(),
+                        tree_proof(&merkle_tree, index),
+                        "count {count}, index {index}"
+                    );
+                }
+                assert_eq!(streamed.find_proof(count).unwrap(), None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_proofs_from_file_node_store() {
+        let dir = std::env::temp_dir().join(format!("merkle-node-store-{}", std::process::id()));
+        let items = items(1_111);
+        let merkle_tree = MerkleTree::new(&items, true);
+        let streamed = build(&items, true, FileNodeStore::new(&dir).unwrap());
+        assert_eq!(streamed.get_root(), merkle_tree.get_root());
+        for index in [0, 1, 500, 1_109, 1_110] {
+            assert_eq!(
+                streamed.find_proof(index).unwrap().unwrap(),
+                tree_proof(&merkle_tree, index)
+            );
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_no_node_store_proof_fails() {
+        let streamed = build(&items(3), false, NoNodeStore);
+        assert!(streamed.find_proof(0).is_err());
+    }
+}
@@ -2,6 +2,8 @@
 
 use solana_program::hash::{hashv, Hash};
 
+pub mod builder;
+pub mod multi_proof;
 pub mod psr_claim;
 pub mod serde_serialize;
 
@@ -0,0 +1,261 @@
+//! Compact proofs of several leaves of one merkle tree.
+//!
+//! The multi-proof contains the sibling hashes that cannot be computed from the proven leaves,
+//! ordered level by level from the leaves up and by the node index within a level.
+//! A sibling shared by several leaf paths is included only once.
+
+use crate::{MerkleTree, INTERMEDIATE_PREFIX};
+use solana_program::hash::{hashv, Hash};
+
+/// Sibling hashes of the multi-proof, `sibling(level, index)` provides the sibling
+/// of the node at the `index` in the tree `level` (the node itself for the last node of an odd level).
+fn build_multi_proof<F>(indices: &[usize], depth: usize, mut sibling: F) -> Option<Vec<Hash>>
+where
+    F: FnMut(usize, usize) -> Option<Hash>,
+{
+    let mut known = indices.to_vec();
+    known.sort_unstable();
+    known.dedup();
+    let mut proof = Vec::new();
+    for level in 0..depth {
+        let mut next_known = Vec::with_capacity(known.len());
+        let mut i = 0;
+        while i < known.len() {
+            let index = known[i];
+            if index % 2 == 0 && known.get(i + 1) == Some(&(index + 1)) {
+                i += 2;
+            } else {
+                proof.push(sibling(level, index)?);
+                i += 1;
+            }
+            next_known.push(index / 2);
+        }
+        known = next_known;
+    }
+    Some(proof)
+}
+
+impl MerkleTree {
+    /// Multi-proof of leaves at the `indices`, `None` when any index is out of the tree.
+    pub fn find_multi_proof(&self, indices: &[usize]) -> Option<Vec<Hash>> {
+        if indices.is_empty() || indices.iter().any(|index| *index >= self.leaf_count) {
+            return None;
+        }
+        let mut level_starts = vec![];
+        let mut level_len = self.leaf_count;
+        let mut level_start = 0;
+        while level_len > 1 {
+            level_starts.push((level_start, level_len));
+            level_start += level_len;
+            level_len = MerkleTree::next_level_len(level_len);
+        }
+        build_multi_proof(indices, level_starts.len(), |level, index| {
+            let (level_start, level_len) = level_starts[level];
+            let sibling_index = if index % 2 == 1 {
+                index - 1
+            } else if index + 1 < level_len {
+                index + 1
+            } else {
+                index
+            };
+            self.nodes.get(level_start + sibling_index).copied()
+        })
+    }
+}
+
+/// Multi-proof composed from single leaf proofs (sibling hashes from the leaf up to the root)
+/// of the same tree, i.e., without the need of the whole tree.
+/// `None` when no proof is provided or the proofs are of different depth.
+pub fn multi_proof_from_proofs(leaves: &[(usize, &[[u8; 32]])]) -> Option<Vec<[u8; 32]>> {
+    let depth = leaves.first()?.1.len();
+    if leaves.iter().any(|(_, proof)| proof.len() != depth) {
+        return None;
+    }
+    let indices: Vec<usize> = leaves.iter().map(|(index, _)| *index).collect();
+    let proof = build_multi_proof(&indices, depth, |level, index| {
+        leaves
+            .iter()
+            .find(|(leaf_index, _)| leaf_index >> level == index)
+            .map(|(_, proof)| Hash::new_from_array(proof[level]))
+    })?;
+    Some(proof.into_iter().map(|hash| hash.to_bytes()).collect())
+}
+
+/// Verifies that the `leaves` (leaf index, leaf hash) are part of the tree with the `root`.
+pub fn verify_multi_proof(
+    proof: &[Hash],
+    root: &Hash,
+    leaves: &[(usize, Hash)],
+    sorted_hashes: bool,
+) -> bool {
+    let mut known = leaves.to_vec();
+    known.sort_unstable_by_key(|(index, _)| *index);
+    if known.is_empty() || known.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+        return false;
+    }
+    let mut proof = proof.iter();
+    loop {
+        if known.len() == 1 && known[0].0 == 0 && proof.len() == 0 {
+            return known[0].1 == *root;
+        }
+        let mut next_known = Vec::with_capacity(known.len());
+        let mut i = 0;
+        while i < known.len() {
+            let (index, hash) = known[i];
+            let (lsib, rsib) = if index % 2 == 0 {
+                if let Some((_, right)) = known.get(i + 1).filter(|(next, _)| *next == index + 1) {
+                    i += 1;
+                    (hash, *right)
+                } else if let Some(right) = proof.next() {
+                    (hash, *right)
+                } else {
+                    return false;
+                }
+            } else if let Some(left) = proof.next() {
+                (*left, hash)
+            } else {
+                return false;
+            };
+            i += 1;
+            let parent = if sorted_hashes && lsib > rsib {
+                hashv(&[INTERMEDIATE_PREFIX, rsib.as_ref(), lsib.as_ref()])
+            } else {
+                hashv(&[INTERMEDIATE_PREFIX, lsib.as_ref(), rsib.as_ref()])
+            };
+            next_known.push((index / 2, parent));
+        }
+        known = next_known;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hash_leaf, LEAF_PREFIX};
+
+    fn items(count: usize) -> Vec<[u8; 8]> {
+        (0..count as u64).map(|i| i.to_le_bytes()).collect()
+    }
+
+    fn single_proof(merkle_tree: &MerkleTree, index: usize) -> Vec<[u8; 32]> {
+        merkle_tree
+            .find_path(index)
+            .unwrap()
+            .get_proof_entries()
+            .iter()
+            .map(|entry| {
+                entry
+                    .get_left_sibling()
+                    .or(entry.get_right_sibling())
+                    .unwrap()
+                    .to_bytes()
+            })
+            .collect()
+    }
+
+    fn leaves(items: &[[u8; 8]], indices: &[usize]) -> Vec<(usize, Hash)> {
+        indices
+            .iter()
+            .map(|index| {
+                let item = items[*index];
+                (*index, hash_leaf!(item))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_multi_proof_verify() {
+        for sorted_hashes in [false, true] {
+            for count in 1..40 {
+                let items = items(count);
+                let merkle_tree = MerkleTree::new(&items, sorted_hashes);
+                let root = merkle_tree.get_root().unwrap();
+                for indices in [
+                    vec![0],
+                    vec![count - 1],
+                    (0..count).collect(),
+                    (0..count).step_by(3).collect::<Vec<_>>(),
+                    vec![count / 2, 0, count - 1],
+                ] {
+                    let proof = merkle_tree.find_multi_proof(&indices).unwrap();
+                    let leaves = leaves(&items, &indices);
+                    let mut dedup_leaves = leaves.clone();
+                    dedup_leaves.sort_unstable_by_key(|(index, _)| *index);
+                    dedup_leaves.dedup_by_key(|(index, _)| *index);
+                    assert!(
+                        verify_multi_proof(&proof, root, &dedup_leaves, sorted_hashes),
+                        "count {count}, indices {indices:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_multi_proof_deduplicates_siblings() {
+        let items = items(16);
+        let merkle_tree = MerkleTree::new(&items, true);
+        // a pair of neighbours shares the whole path above the leaves
+        assert_eq!(merkle_tree.find_multi_proof(&[4, 5]).unwrap().len(), 3);
+        assert_eq!(merkle_tree.find_multi_proof(&[4]).unwrap().len(), 4);
+        assert!(merkle_tree
+            .find_multi_proof(&(0..16).collect::<Vec<_>>())
+            .unwrap()
+            .is_empty());
+        assert_eq!(merkle_tree.find_multi_proof(&[16]), None);
+        assert_eq!(merkle_tree.find_multi_proof(&[]), None);
+    }
+
+    #[test]
+    fn test_multi_proof_verify_bad() {
+        let items = items(11);
+        let merkle_tree = MerkleTree::new(&items, true);
+        let root = merkle_tree.get_root().unwrap();
+        let proof = merkle_tree.find_multi_proof(&[2, 7]).unwrap();
+        let good_leaves = leaves(&items, &[2, 7]);
+        assert!(verify_multi_proof(&proof, root, &good_leaves, true));
+        assert!(!verify_multi_proof(
+            &proof,
+            root,
+            &leaves(&items, &[2, 8]),
+            true
+        ));
+        assert!(!verify_multi_proof(&proof[1..], root, &good_leaves, true));
+        let mut longer_proof = proof.clone();
+        longer_proof.push(Hash::default());
+        assert!(!verify_multi_proof(&longer_proof, root, &good_leaves, true));
+        assert!(!verify_multi_proof(
+            &proof,
+            root,
+            &[good_leaves[0], good_leaves[0]],
+            true
+        ));
+        assert!(!verify_multi_proof(&proof, root, &[], true));
+    }
+
+    #[test]
+    fn test_multi_proof_from_proofs() {
+        for count in 1..40 {
+            let items = items(count);
+            let merkle_tree = MerkleTree::new(&items, true);
+            let indices: Vec<usize> = (0..count).filter(|i| i % 4 != 1).collect();
+            let proofs: Vec<Vec<[u8; 32]>> = indices
+                .iter()
+                .map(|index| single_proof(&merkle_tree, *index))
+                .collect();
+            let leaf_proofs: Vec<(usize, &[[u8; 32]])> = indices
+                .iter()
+                .zip(proofs.iter())
+                .map(|(index, proof)| (*index, proof.as_slice()))
+                .collect();
+            let expected: Vec<[u8; 32]> = merkle_tree
+                .find_multi_proof(&indices)
+                .unwrap()
+                .into_iter()
+                .map(|hash| hash.to_bytes())
+                .collect();
+            assert_eq!(multi_proof_from_proofs(&leaf_proofs).unwrap(), expected);
+        }
+        assert_eq!(multi_proof_from_proofs(&[]), None);
+    }
+}
@@ -125,12 +125,16 @@ async function fetchFreshBondBalanceSol(
         voteAccount,
         program.programId,
       )
-      const [withdrawRequest] = await findWithdrawRequests({ program, bond })
-      const pendingLamports = withdrawRequest
-        ? withdrawRequest.account.requestedAmount
-            .sub(withdrawRequest.account.withdrawnAmount)
-            .toNumber()
-        : 0
+      // the legacy and all indexed withdraw requests of the bond
+      const withdrawRequests = await findWithdrawRequests({ program, bond })
+      const pendingLamports = withdrawRequests.reduce(
+        (sum, { account }) =>
+          sum +
+          Number(
+            account.requestedAmount.sub(account.withdrawnAmount).toString(),
+          ),
+        0,
+      )
       return {
         bondBalanceSol: ownedLamports / LAMPORTS_PER_SOL,
         claimableBondBalanceSol:
@@ -0,0 +1,130 @@
+import { cancelBondAuthorityInstruction } from '@marinade.finance/validator-bonds-sdk'
+import {
+  instanceOfWallet,
+  parsePubkey,
+  parseWalletOrPubkeyOption,
+  transaction,
+} from '@marinade.finance/web3js-1x'
+
+import {
+  recordResolvedAccounts,
+  setProgramTelemetryFields,
+} from '../../cliUsage'
+import {
+  BOND_AUTHORITY_LIMIT_UNITS,
+  computeUnitLimitOption,
+} from '../../computeUnits'
+import { getCliContext } from '../../context'
+import {
+  executeTxHandleErrors,
+  getBondFromAddress,
+  txOutcomeMessage,
+} from '../../utils'
+
+import type {
+  Wallet,
+  Wallet as WalletInterface,
+} from '@marinade.finance/web3js-1x'
+import type { PublicKey, Signer } from '@solana/web3.js'
+import type { Command } from 'commander'
+
+export function configureCancelBondAuthority(program: Command): Command {
+  return setProgramTelemetryFields(program.command('cancel-bond-authority'), {
+    accountField: 'account',
+  })
+    .description(
+      'Cancel the pending bond authority proposed by the "configure-bond --bond-authority" command.',
+    )
+    .argument(
+      '<bond-or-vote>',
+      'Address of the bond account or vote account.',
+      parsePubkey,
+    )
+    .option(
+      '--authority <keypair-or-ledger-or-pubkey>',
+      'Authority that is permitted to do changes in bonds account. ' +
+        'It is either the authority defined in bonds account OR ' +
+        'vote account validator identity. (default: wallet keypair)',
+      parseWalletOrPubkeyOption,
+    )
+    .addOption(computeUnitLimitOption(BOND_AUTHORITY_LIMIT_UNITS))
+}
+
+export async function manageCancelBondAuthority({
+  address,
+  config,
+  authority,
+  computeUnitLimit,
+}: {
+  address: PublicKey
+  config: PublicKey
+  authority?: WalletInterface | PublicKey
+  computeUnitLimit: number
+}) {
+  const {
+    program,
+    provider,
+    logger,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    wallet,
+    confirmationFinality,
+    confirmWaitTime,
+    skipPreflight,
+  } = getCliContext()
+
+  const tx = await transaction(provider)
+  const signers: (Signer | Wallet)[] = [wallet]
+
+  const bondAccountData = await getBondFromAddress({
+    program,
+    address,
+    config,
+    logger,
+  })
+  const bondAccount = bondAccountData.publicKey
+  config = bondAccountData.account.data.config
+  const voteAccount = bondAccountData.account.data.voteAccount
+  recordResolvedAccounts({ bondAccount, voteAccount, configAccount: config })
+
+  authority = authority ?? wallet.publicKey
+  if (instanceOfWallet(authority)) {
+    signers.push(authority)
+    authority = authority.publicKey
+  }
+
+  const { instruction } = await cancelBondAuthorityInstruction({
+    program,
+    bondAccount,
+    configAccount: config,
+    voteAccount,
+    authority,
+  })
+  tx.add(instruction)
+
+  logger.info(
+    `Cancelling pending authority ${bondAccountData.account.data.pendingAuthority.toBase58()} ` +
+      `of bond account ${bondAccount.toBase58()}`,
+  )
+  await executeTxHandleErrors({
+    connection: provider.connection,
+    transaction: tx,
+    errMessage: `Failed to cancel pending authority of bond account ${bondAccount.toBase58()}`,
+    signers,
+    logger,
+    computeUnitLimit,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    confirmOpts: confirmationFinality,
+    confirmWaitTime,
+    sendOpts: { skipPreflight },
+  })
+  logger.info(
+    txOutcomeMessage(
+      simulate || printOnly,
+      `Pending authority of bond account ${bondAccount.toBase58()} successfully cancelled`,
+    ),
+  )
+}
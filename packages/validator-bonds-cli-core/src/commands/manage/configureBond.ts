@@ -73,7 +73,8 @@ export function configureConfigureBond(program: Command): Command {
     )
     .option(
       '--bond-authority <pubkey>',
-      'New value of "bond authority" that is permitted to operate with the bond account.',
+      'New value of "bond authority" that is permitted to operate with the bond account. ' +
+        'The new authority is only proposed, it takes the role after signing the "accept-bond-authority" command.',
       parsePubkeyOrPubkeyFromWallet,
     )
     .option(
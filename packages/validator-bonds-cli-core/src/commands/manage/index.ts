@@ -1,5 +1,7 @@
 export * from './initBond'
 export * from './configureBond'
+export * from './acceptBondAuthority'
+export * from './cancelBondAuthority'
 export * from './fundBond'
 export * from './fundBondWithSol'
 export * from './initWithdrawRequest'
@@ -15,7 +15,8 @@ export const CANCEL_WITHDRAW_REQUEST_LIMIT_UNITS = 27_000
 export const CLAIM_WITHDRAW_REQUEST_LIMIT_UNITS = 600_000
 export const CONFIGURE_BOND_LIMIT_UNITS = 24_000
 export const CONFIGURE_BOND_MINT_LIMIT_UNITS = 60_000
-export const CONFIGURE_CONFIG_LIMIT_UNITS = 21_000
+// configure config may propose new authorities within the same transaction
+export const CONFIGURE_CONFIG_LIMIT_UNITS = 42_000
 export const EMERGENCY_LIMIT_UNITS = 19_000
 export const FUND_BOND_LIMIT_UNITS = 100_000
 export const FUND_BOND_WITH_SOL_LIMIT_UNITS = 200_000
@@ -28,6 +29,8 @@ export const CLOSE_SETTLEMENT_LIMIT_UNITS = 50_000
 export const RESET_STAKE_LIMIT_UNITS = 50_000
 export const INIT_BOND_CONFIG_COMMISSION_LIMIT_UNITS = 50_000
 export const CONFIGURE_BOND_CONFIG_COMMISSION_LIMIT_UNITS = 24_000
+export const CONFIG_AUTHORITY_LIMIT_UNITS = 21_000
+export const BOND_AUTHORITY_LIMIT_UNITS = 24_000
 
 export function computeUnitLimitOption(defaultValue: number): Option {
   return createOption(
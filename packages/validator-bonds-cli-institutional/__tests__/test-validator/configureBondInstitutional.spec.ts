@@ -178,7 +178,8 @@ describe('Configure bond account using CLI (institutional)', () => {
     )
     await provider.sendIx([validatorIdentity], createTokenIx, transferIx)
 
-    const newBondAuthority = PublicKey.unique()
+    // the token owner proposes itself as the bond authority and accepts it
+    const newBondAuthority = userKeypair.publicKey
     await expect([
       'pnpm',
       [
@@ -201,7 +202,29 @@ describe('Configure bond account using CLI (institutional)', () => {
       stdout: /Bond account.*successfully configured/,
     })
 
-    const bondsData = await getBond(program, bondAccount)
+    let bondsData = await getBond(program, bondAccount)
+    expect(bondsData.pendingAuthority).toEqual(newBondAuthority)
+
+    await expect([
+      'pnpm',
+      [
+        'cli:institutional',
+        '-u',
+        provider.connection.rpcEndpoint,
+        'accept-bond-authority',
+        voteAccount.toBase58(),
+        '--authority',
+        userPath,
+        '--confirmation-finality',
+        'confirmed',
+      ],
+    ]).toHaveMatchingSpawnOutput({
+      code: 0,
+      stdout: /successfully accepted/,
+    })
+
+    bondsData = await getBond(program, bondAccount)
     expect(bondsData.authority).toEqual(newBondAuthority)
+    expect(bondsData.pendingAuthority).toEqual(PublicKey.default)
   })
 })
@@ -0,0 +1,31 @@
+import {
+  configureAcceptBondAuthority,
+  manageAcceptBondAuthority,
+} from '@marinade.finance/validator-bonds-cli-core'
+import { MARINADE_INSTITUTIONAL_CONFIG_ADDRESS } from '@marinade.finance/validator-bonds-sdk'
+
+import type { Wallet as WalletInterface } from '@marinade.finance/web3js-1x'
+import type { PublicKey } from '@solana/web3.js'
+import type { Command } from 'commander'
+
+export function installAcceptBondAuthority(program: Command) {
+  configureAcceptBondAuthority(program).action(
+    async (
+      address: Promise<PublicKey>,
+      {
+        authority,
+        computeUnitLimit,
+      }: {
+        authority?: Promise<WalletInterface | PublicKey>
+        computeUnitLimit: number
+      },
+    ) => {
+      await manageAcceptBondAuthority({
+        address: await address,
+        config: MARINADE_INSTITUTIONAL_CONFIG_ADDRESS,
+        authority: await authority,
+        computeUnitLimit,
+      })
+    },
+  )
+}
@@ -6,6 +6,8 @@ import {
 } from '@marinade.finance/validator-bonds-cli-core'
 import { MARINADE_INSTITUTIONAL_CONFIG_ADDRESS } from '@marinade.finance/validator-bonds-sdk'
 
+import { installAcceptBondAuthority } from './acceptBondAuthority'
+import { installCancelBondAuthority } from './cancelBondAuthority'
 import { installCancelWithdrawRequest } from './cancelWithdrawRequest'
 import { installClaimWithdrawRequest } from './claimWithdrawRequest'
 import { installConfigureBond } from './configureBond'
@@ -20,6 +22,8 @@ import type { Command } from 'commander'
 export function installManage(program: Command) {
   installInitBond(program)
   installConfigureBond(program)
+  installAcceptBondAuthority(program)
+  installCancelBondAuthority(program)
   installFundBond(program)
   installFundBondWithSol(program)
   installMintBond(program)
@@ -154,7 +154,12 @@ describe('Configure bond account using CLI', () => {
     await expect(
       provider.connection.getBalance(rentPayerKeypair.publicKey),
     ).resolves.toBe(airdropFunds)
-    const newBondAuthority = PublicKey.unique()
+    const {
+      path: newBondAuthorityPath,
+      keypair: newBondAuthorityKeypair,
+      cleanup: newBondAuthorityCleanup,
+    } = await createTempFileKeypair()
+    const newBondAuthority = newBondAuthorityKeypair.publicKey
     await expect([
       'pnpm',
       [
@@ -191,8 +196,38 @@ describe('Configure bond account using CLI', () => {
       stdout: /Bond account.*successfully configured/,
     })
 
-    const bondsData2 = await getBond(program, bondAccount)
+    let bondsData2 = await getBond(program, bondAccount)
+    expect(bondsData2.authority).toEqual(bondsData1.authority)
+    expect(bondsData2.pendingAuthority).toEqual(newBondAuthority)
+
+    try {
+      await expect([
+        'pnpm',
+        [
+          'cli',
+          '-u',
+          provider.connection.rpcEndpoint,
+          '--program-id',
+          program.programId.toBase58(),
+          'accept-bond-authority',
+          voteAccount.toBase58(),
+          '--config',
+          configAccount.toBase58(),
+          '--authority',
+          newBondAuthorityPath,
+          '--confirmation-finality',
+          'confirmed',
+        ],
+      ]).toHaveMatchingSpawnOutput({
+        code: 0,
+        stdout: /successfully accepted/,
+      })
+    } finally {
+      await newBondAuthorityCleanup()
+    }
+    bondsData2 = await getBond(program, bondAccount)
     expect(bondsData2.authority).toEqual(newBondAuthority)
+    expect(bondsData2.pendingAuthority).toEqual(PublicKey.default)
     expect(bondsData2.cpmpe).toEqual(32)
     expect(bondsData2.maxStakeWanted).toEqual(1000 * LAMPORTS_PER_SOL)
 
@@ -389,7 +424,7 @@ describe('Configure bond account using CLI', () => {
     })
 
     const bondsData = await getBond(program, bondAccount)
-    expect(bondsData.authority).toEqual(newBondAuthority)
+    expect(bondsData.pendingAuthority).toEqual(newBondAuthority)
     expect(bondsData.cpmpe).toEqual(2)
     expect(bondsData.maxStakeWanted).toEqual(999 * LAMPORTS_PER_SOL)
   })
@@ -50,7 +50,12 @@ describe('Configure config account using CLI', () => {
   })
 
   it('configure config account', async () => {
-    const newAdmin = Keypair.generate()
+    const {
+      path: newAdminPath,
+      keypair: newAdmin,
+      cleanup: newAdminCleanup,
+    } = await createTempFileKeypair()
+    const newOperator = Keypair.generate()
 
     await expect([
       'pnpm',
@@ -86,11 +91,11 @@ describe('Configure config account using CLI', () => {
         '--admin-authority',
         adminPath,
         '--operator',
-        PublicKey.default.toBase58(),
+        newOperator.publicKey.toBase58(),
         '--admin',
         newAdmin.publicKey.toBase58(),
         '--pause-authority',
-        PublicKey.default.toBase58(),
+        newOperator.publicKey.toBase58(),
         '--epochs-to-claim-settlement',
         111,
         '--slots-to-start-settlement-claiming',
@@ -111,15 +116,112 @@ describe('Configure config account using CLI', () => {
       stdout: /successfully configured/,
     })
 
-    const configData = await getConfig(program, configAccount)
-    expect(configData.adminAuthority).toEqual(newAdmin.publicKey)
-    expect(configData.operatorAuthority).toEqual(PublicKey.default)
-    expect(configData.pauseAuthority).toEqual(PublicKey.default)
-    expect(configData.epochsToClaimSettlement).toEqual(111)
-    expect(configData.slotsToStartSettlementClaiming).toEqual(143)
-    expect(configData.withdrawLockupEpochs).toEqual(112)
-    expect(configData.minimumStakeLamports).toEqual(134)
+    let configData = await getConfig(program, configAccount)
+    expect(configData.adminAuthority).toEqual(adminKeypair.publicKey)
+    expect(configData.operatorAuthority).toEqual(operatorAuthority.publicKey)
+    expect(configData.pendingAdminAuthority).toEqual(newAdmin.publicKey)
+    expect(configData.pendingOperatorAuthority).toEqual(newOperator.publicKey)
+    expect(configData.pendingPauseAuthority).toEqual(newOperator.publicKey)
+    // lockup-sensitive params are queued until the activation epoch
+    expect(configData.epochsToClaimSettlement).toEqual(1)
+    expect(configData.slotsToStartSettlementClaiming).toEqual(3)
+    expect(configData.withdrawLockupEpochs).toEqual(2)
     expect(configData.minBondMaxStakeWanted).toEqual(111)
+    const { epoch } = await provider.connection.getEpochInfo()
+    expect(configData.queuedChange?.epochsToClaimSettlement).toEqual(111)
+    expect(configData.queuedChange?.slotsToStartSettlementClaiming).toEqual(143)
+    expect(configData.queuedChange?.withdrawLockupEpochs).toEqual(112)
+    expect(configData.queuedChange?.minimumStakeLamports).toEqual(134)
+    expect(configData.queuedChange?.activationEpoch.toNumber()).toBeGreaterThan(
+      epoch,
+    )
+
+    await expect([
+      'pnpm',
+      [
+        'cli',
+        '-u',
+        provider.connection.rpcEndpoint,
+        '--program-id',
+        program.programId.toBase58(),
+        'execute-configure-config',
+        configAccount.toBase58(),
+        '--confirmation-finality',
+        'confirmed',
+      ],
+    ]).toHaveMatchingSpawnOutput({
+      code: 200,
+      stdout: /activation epoch has not been reached/,
+    })
+
+    await expect([
+      'pnpm',
+      [
+        'cli',
+        '-u',
+        provider.connection.rpcEndpoint,
+        '--program-id',
+        program.programId.toBase58(),
+        'cancel-queued-config',
+        configAccount.toBase58(),
+        '--admin-authority',
+        adminPath,
+        '--confirmation-finality',
+        'confirmed',
+      ],
+    ]).toHaveMatchingSpawnOutput({
+      code: 0,
+      stdout: /Succeeded to cancel queued change/,
+    })
+    expect((await getConfig(program, configAccount)).queuedChange).toBeNull()
+
+    try {
+      await expect([
+        'pnpm',
+        [
+          'cli',
+          '-u',
+          provider.connection.rpcEndpoint,
+          '--program-id',
+          program.programId.toBase58(),
+          'accept-config-authority',
+          configAccount.toBase58(),
+          '--authority',
+          newAdminPath,
+          '--confirmation-finality',
+          'confirmed',
+        ],
+      ]).toHaveMatchingSpawnOutput({
+        code: 0,
+        stdout: /Succeeded to accept authority/,
+      })
+    } finally {
+      await newAdminCleanup()
+    }
+
+    configData = await getConfig(program, configAccount)
+    expect(configData.adminAuthority).toEqual(newAdmin.publicKey)
+    expect(configData.pendingAdminAuthority).toEqual(PublicKey.default)
+    expect(configData.pendingOperatorAuthority).toEqual(newOperator.publicKey)
+
+    await expect([
+      'pnpm',
+      [
+        'cli',
+        '-u',
+        provider.connection.rpcEndpoint,
+        '--program-id',
+        program.programId.toBase58(),
+        'cancel-config-authority',
+        configAccount.toBase58(),
+        '--admin-authority',
+        newAdmin.publicKey.toBase58(),
+        '--print-only',
+      ],
+    ]).toHaveMatchingSpawnOutput({
+      code: 0,
+      stdout: dryRunOutput(/Succeeded to cancel authority/),
+    })
   })
 
   it('configure config in print-only mode', async () => {
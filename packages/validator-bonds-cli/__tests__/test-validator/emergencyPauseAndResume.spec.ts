@@ -1,10 +1,15 @@
 import { extendJestWithShellMatchers } from '@marinade.finance/jest-shell-matcher'
 import {
-  configureConfigInstruction,
+  PAUSE_ALL,
+  PAUSE_CLAIM,
+  PAUSE_FUNDING,
   getConfig,
 } from '@marinade.finance/validator-bonds-sdk'
 import { initTest } from '@marinade.finance/validator-bonds-sdk/__tests__/utils/testValidator'
-import { executeInitConfigInstruction } from '@marinade.finance/validator-bonds-sdk/dist/__tests__/utils/testTransactions'
+import {
+  executeChangeConfigAuthority,
+  executeInitConfigInstruction,
+} from '@marinade.finance/validator-bonds-sdk/dist/__tests__/utils/testTransactions'
 import { createTempFileKeypair } from '@marinade.finance/web3js-1x'
 
 import { dryRunOutput } from './utils'
@@ -40,12 +45,13 @@ describe('Pause and resume using CLI', () => {
         withdrawLockupEpochs: 2,
       })
     config = configAccount
-    const { instruction: configIx } = await configureConfigInstruction({
+    await executeChangeConfigAuthority({
       program,
+      provider,
       configAccount,
-      newPauseAuthority: pauseAuthorityKeypair.publicKey,
+      adminAuthority,
+      newPauseAuthority: pauseAuthorityKeypair,
     })
-    await provider.sendIx([adminAuthority], configIx)
   })
 
   afterEach(async () => {
@@ -54,7 +60,7 @@ describe('Pause and resume using CLI', () => {
 
   it('pause and resume', async () => {
     let configData = await getConfig(program, config)
-    expect(configData.paused).toEqual(false)
+    expect(configData.paused).toEqual(0)
 
     await expect([
       'pnpm',
@@ -77,7 +83,7 @@ describe('Pause and resume using CLI', () => {
       stdout: /Succeeded to pause/,
     })
     configData = await getConfig(program, config)
-    expect(configData.paused).toEqual(true)
+    expect(configData.paused).toEqual(PAUSE_ALL)
 
     await expect([
       'pnpm',
@@ -100,7 +106,57 @@ describe('Pause and resume using CLI', () => {
       stdout: /Succeeded to resume/,
     })
     configData = await getConfig(program, config)
-    expect(configData.paused).toEqual(false)
+    expect(configData.paused).toEqual(0)
+  })
+
+  it('pause and resume operation groups', async () => {
+    await expect([
+      'pnpm',
+      [
+        'cli',
+        '-u',
+        provider.connection.rpcEndpoint,
+        '--program-id',
+        program.programId.toBase58(),
+        'pause',
+        config.toBase58(),
+        '--authority',
+        pauseAuthorityPath,
+        '--operations',
+        'funding,claim',
+        '--confirmation-finality',
+        'confirmed',
+      ],
+    ]).toHaveMatchingSpawnOutput({
+      code: 0,
+      stdout: /Succeeded to pause/,
+    })
+    expect((await getConfig(program, config)).paused).toEqual(
+      PAUSE_FUNDING | PAUSE_CLAIM,
+    )
+
+    await expect([
+      'pnpm',
+      [
+        'cli',
+        '-u',
+        provider.connection.rpcEndpoint,
+        '--program-id',
+        program.programId.toBase58(),
+        'resume',
+        config.toBase58(),
+        '--authority',
+        pauseAuthorityPath,
+        '--operations',
+        'claim',
+        '--confirmation-finality',
+        'confirmed',
+      ],
+    ]).toHaveMatchingSpawnOutput({
+      code: 0,
+      stdout: /Succeeded to resume/,
+    })
+    expect((await getConfig(program, config)).paused).toEqual(PAUSE_FUNDING)
   })
 
   it('pause and resume in print-only mode', async () => {
@@ -121,7 +177,7 @@ describe('Pause and resume using CLI', () => {
       // stderr: '',
       stdout: dryRunOutput(/Succeeded to pause/),
     })
-    expect((await getConfig(program, config)).paused).toEqual(false)
+    expect((await getConfig(program, config)).paused).toEqual(0)
 
     await expect([
       'pnpm',
@@ -140,6 +196,6 @@ describe('Pause and resume using CLI', () => {
       // stderr: '',
       stdout: dryRunOutput(/Succeeded to resume/),
     })
-    expect((await getConfig(program, config)).paused).toEqual(false)
+    expect((await getConfig(program, config)).paused).toEqual(0)
   })
 })
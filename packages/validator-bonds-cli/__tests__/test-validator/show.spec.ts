@@ -111,10 +111,14 @@ describe('Show command using CLI', () => {
           withdrawLockupEpochs: 103,
           minimumStakeLamports: LAMPORTS_PER_SOL,
           pauseAuthority: admin.toBase58(),
-          paused: false,
+          paused: 0,
           slotsToStartSettlementClaiming: 102,
           minBondMaxStakeWanted: 0,
-          reserved: [463],
+          pendingAdminAuthority: PublicKey.default.toBase58(),
+          pendingOperatorAuthority: PublicKey.default.toBase58(),
+          pendingPauseAuthority: PublicKey.default.toBase58(),
+          queuedChange: null,
+          reserved: [322],
         },
         bondsWithdrawerAuthority: bondsWithdrawerAuthority(
           configPubkey,
@@ -157,10 +161,14 @@ describe('Show command using CLI', () => {
             withdrawLockupEpochs: 103,
             minimumStakeLamports: LAMPORTS_PER_SOL,
             pauseAuthority: admin.toBase58(),
-            paused: false,
+            paused: 0,
             slotsToStartSettlementClaiming: 102,
             minBondMaxStakeWanted: 0,
-            reserved: [463],
+            pendingAdminAuthority: PublicKey.default.toBase58(),
+            pendingOperatorAuthority: PublicKey.default.toBase58(),
+            pendingPauseAuthority: PublicKey.default.toBase58(),
+            queuedChange: null,
+            reserved: [322],
           },
           bondsWithdrawerAuthority: bondsWithdrawerAuthority(
             configPubkey,
@@ -225,10 +233,14 @@ describe('Show command using CLI', () => {
             withdrawLockupEpochs: 103,
             minimumStakeLamports: LAMPORTS_PER_SOL,
             pauseAuthority: admin.toBase58(),
-            paused: false,
+            paused: 0,
             slotsToStartSettlementClaiming: 102,
             minBondMaxStakeWanted: 0,
-            reserved: [463],
+            pendingAdminAuthority: PublicKey.default.toBase58(),
+            pendingOperatorAuthority: PublicKey.default.toBase58(),
+            pendingPauseAuthority: PublicKey.default.toBase58(),
+            queuedChange: null,
+            reserved: [322],
           },
           bondsWithdrawerAuthority: bondsWithdrawerAuthority(
             configPubkey,
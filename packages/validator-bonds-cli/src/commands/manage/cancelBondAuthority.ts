@@ -0,0 +1,42 @@
+import {
+  configureCancelBondAuthority,
+  manageCancelBondAuthority,
+} from '@marinade.finance/validator-bonds-cli-core'
+import { MARINADE_CONFIG_ADDRESS } from '@marinade.finance/validator-bonds-sdk'
+import { parsePubkey } from '@marinade.finance/web3js-1x'
+
+import type { Wallet as WalletInterface } from '@marinade.finance/web3js-1x'
+import type { PublicKey } from '@solana/web3.js'
+import type { Command } from 'commander'
+
+export function installCancelBondAuthority(program: Command) {
+  configureCancelBondAuthority(program)
+    .option(
+      '--config <pubkey>',
+      'The config account that the bond account is created under ' +
+        '(optional; to derive bond address from vote account address) ' +
+        `(default: ${MARINADE_CONFIG_ADDRESS.toBase58()})`,
+      parsePubkey,
+    )
+    .action(
+      async (
+        address: Promise<PublicKey>,
+        {
+          config,
+          authority,
+          computeUnitLimit,
+        }: {
+          config?: Promise<PublicKey>
+          authority?: Promise<WalletInterface | PublicKey>
+          computeUnitLimit: number
+        },
+      ) => {
+        await manageCancelBondAuthority({
+          address: await address,
+          config: (await config) ?? MARINADE_CONFIG_ADDRESS,
+          authority: await authority,
+          computeUnitLimit,
+        })
+      },
+    )
+}
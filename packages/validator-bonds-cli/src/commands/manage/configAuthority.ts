@@ -0,0 +1,185 @@
+import {
+  CONFIG_AUTHORITY_LIMIT_UNITS,
+  computeUnitLimitOption,
+  executeTxHandleErrors,
+  getCliContext,
+  setProgramTelemetryFields,
+  txOutcomeMessage,
+} from '@marinade.finance/validator-bonds-cli-core'
+import {
+  MARINADE_CONFIG_ADDRESS,
+  acceptConfigAuthorityInstruction,
+  cancelConfigAuthorityInstruction,
+  getConfig,
+} from '@marinade.finance/validator-bonds-sdk'
+import {
+  instanceOfWallet,
+  parsePubkey,
+  parseWalletOrPubkeyOption,
+  transaction,
+} from '@marinade.finance/web3js-1x'
+
+import type {
+  Wallet as WalletInterface,
+  Wallet,
+} from '@marinade.finance/web3js-1x'
+import type { PublicKey, Signer, TransactionInstruction } from '@solana/web3.js'
+import type { Command } from 'commander'
+
+export function installAcceptConfigAuthority(program: Command) {
+  setProgramTelemetryFields(program.command('accept-config-authority'), {
+    accountField: 'config_account',
+  })
+    .description(
+      'Accept the config authority roles proposed by the "configure-config" command.',
+    )
+    .argument(
+      '[config-address]',
+      'Address of the validator bonds config account ' +
+        `(default: ${MARINADE_CONFIG_ADDRESS.toBase58()})`,
+      parsePubkey,
+    )
+    .option(
+      '--authority <keypair-or-ledger-or-pubkey>',
+      'The proposed authority accepting all the roles proposed to it (default: wallet)',
+      parseWalletOrPubkeyOption,
+    )
+    .addOption(computeUnitLimitOption(CONFIG_AUTHORITY_LIMIT_UNITS))
+    .action(
+      async (
+        address: Promise<undefined | PublicKey>,
+        {
+          authority,
+          computeUnitLimit,
+        }: {
+          authority?: Promise<WalletInterface | PublicKey>
+          computeUnitLimit: number
+        },
+      ) => {
+        await manageConfigAuthority({
+          action: 'accept',
+          address: (await address) ?? MARINADE_CONFIG_ADDRESS,
+          authority: await authority,
+          computeUnitLimit,
+        })
+      },
+    )
+}
+
+export function installCancelConfigAuthority(program: Command) {
+  setProgramTelemetryFields(program.command('cancel-config-authority'), {
+    accountField: 'config_account',
+  })
+    .description(
+      'Cancel all the config authorities proposed by the "configure-config" command and not accepted yet.',
+    )
+    .argument(
+      '[config-address]',
+      'Address of the validator bonds config account ' +
+        `(default: ${MARINADE_CONFIG_ADDRESS.toBase58()})`,
+      parsePubkey,
+    )
+    .option(
+      '--admin-authority <keypair-or-ledger-or-pubkey>',
+      'Admin authority that is permitted to cancel the proposal (default: admin authority from config)',
+      parseWalletOrPubkeyOption,
+    )
+    .addOption(computeUnitLimitOption(CONFIG_AUTHORITY_LIMIT_UNITS))
+    .action(
+      async (
+        address: Promise<undefined | PublicKey>,
+        {
+          adminAuthority,
+          computeUnitLimit,
+        }: {
+          adminAuthority?: Promise<WalletInterface | PublicKey>
+          computeUnitLimit: number
+        },
+      ) => {
+        await manageConfigAuthority({
+          action: 'cancel',
+          address: (await address) ?? MARINADE_CONFIG_ADDRESS,
+          authority: await adminAuthority,
+          computeUnitLimit,
+        })
+      },
+    )
+}
+
+async function manageConfigAuthority({
+  action,
+  address,
+  authority,
+  computeUnitLimit,
+}: {
+  action: 'accept' | 'cancel'
+  address: PublicKey
+  authority?: WalletInterface | PublicKey
+  computeUnitLimit: number
+}) {
+  const {
+    program,
+    provider,
+    logger,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    wallet,
+    confirmationFinality,
+    confirmWaitTime,
+    skipPreflight,
+  } = getCliContext()
+
+  const tx = await transaction(provider)
+  const signers: (Signer | Wallet)[] = [wallet]
+
+  if (authority === undefined) {
+    authority =
+      action === 'accept'
+        ? wallet.publicKey
+        : (await getConfig(program, address)).adminAuthority
+  }
+  if (instanceOfWallet(authority)) {
+    signers.push(authority)
+    authority = authority.publicKey
+  }
+
+  let instruction: TransactionInstruction
+  if (action === 'accept') {
+    ;({ instruction } = await acceptConfigAuthorityInstruction({
+      program,
+      configAccount: address,
+      newAuthority: authority,
+      logger,
+    }))
+  } else {
+    ;({ instruction } = await cancelConfigAuthorityInstruction({
+      program,
+      configAccount: address,
+      adminAuthority: authority,
+      logger,
+    }))
+  }
+  tx.add(instruction)
+
+  await executeTxHandleErrors({
+    connection: provider.connection,
+    transaction: tx,
+    errMessage: `Failed to ${action} authority of config account ${address.toBase58()}`,
+    signers,
+    logger,
+    computeUnitLimit,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    confirmOpts: confirmationFinality,
+    confirmWaitTime,
+    sendOpts: { skipPreflight },
+  })
+  logger.info(
+    txOutcomeMessage(
+      simulate || printOnly,
+      `Succeeded to ${action} authority of config account ${address.toBase58()}`,
+    ),
+  )
+}
@@ -11,6 +11,8 @@ import {
   MARINADE_CONFIG_ADDRESS,
   configureConfigInstruction,
   getConfig,
+  proposeConfigAuthorityInstruction,
+  queueConfigureConfigInstruction,
 } from '@marinade.finance/validator-bonds-sdk'
 import {
   instanceOfWallet,
@@ -44,39 +46,49 @@ export function installConfigureConfig(program: Command) {
     )
     .option(
       '--admin <pubkey>',
-      'New admin authority to be configured',
+      'New admin authority to be proposed, it takes the role after accept-config-authority',
       parsePubkeyOrPubkeyFromWallet,
     )
     .option(
       '--operator <pubkey>',
-      'New operator authority to be configured',
+      'New operator authority to be proposed, it takes the role after accept-config-authority',
       parsePubkeyOrPubkeyFromWallet,
     )
     .option(
       '--pause-authority <pubkey>',
-      'New pause authority to be configured',
+      'New pause authority to be proposed, it takes the role after accept-config-authority',
       parsePubkeyOrPubkeyFromWallet,
     )
     .option(
       '--epochs-to-claim-settlement <number>',
-      'New number of epochs after which claim can be settled',
+      'New number of epochs after which claim can be settled, ' +
+        'the change is queued until the activation epoch',
       v => parseInt(v, 10),
     )
     .option(
       '--slots-to-start-settlement-claiming <number>',
-      'number of slots after which settlement claim can be settled',
+      'number of slots after which settlement claim can be settled, ' +
+        'the change is queued until the activation epoch',
       v => parseInt(v, 10),
     )
     .option(
       '--withdraw-lockup-epochs <number>',
-      'New number of epochs after which withdraw can be executed',
+      'New number of epochs after which withdraw can be executed, ' +
+        'the change is queued until the activation epoch',
       v => parseInt(v, 10),
     )
     .option(
       '--minimum-stake-lamports <number>',
-      'New value of minimum stake lamports used when program do splitting of stake',
+      'New value of minimum stake lamports used when program do splitting of stake, ' +
+        'the change is queued until the activation epoch',
       value => toBN(value),
     )
+    .option(
+      '--activation-epoch <number>',
+      'Epoch when the queued change may be applied with "execute-configure-config" ' +
+        '(default: current epoch + withdraw lockup epochs + 1)',
+      v => parseInt(v, 10),
+    )
     .option(
       '--min-bond-max-stake-wanted <number>',
       'New value of minimum for max-stake-wanted field, in lamports, configured by validators in bond.',
@@ -95,6 +107,7 @@ export function installConfigureConfig(program: Command) {
           slotsToStartSettlementClaiming,
           withdrawLockupEpochs,
           minimumStakeLamports,
+          activationEpoch,
           minBondMaxStakeWanted,
           computeUnitLimit,
         }: {
@@ -106,6 +119,7 @@ export function installConfigureConfig(program: Command) {
           slotsToStartSettlementClaiming?: number
           withdrawLockupEpochs?: number
           minimumStakeLamports?: BN
+          activationEpoch?: number
           minBondMaxStakeWanted?: BN
           computeUnitLimit: number
         },
@@ -120,6 +134,7 @@ export function installConfigureConfig(program: Command) {
           slotsToStartSettlementClaiming,
           withdrawLockupEpochs,
           minimumStakeLamports,
+          activationEpoch,
           minBondMaxStakeWanted,
           computeUnitLimit,
         })
@@ -137,6 +152,7 @@ async function manageConfigureConfig({
   slotsToStartSettlementClaiming,
   withdrawLockupEpochs,
   minimumStakeLamports,
+  activationEpoch,
   minBondMaxStakeWanted,
   computeUnitLimit,
 }: {
@@ -149,6 +165,7 @@ async function manageConfigureConfig({
   slotsToStartSettlementClaiming?: number
   withdrawLockupEpochs?: number
   minimumStakeLamports?: BN
+  activationEpoch?: number
   minBondMaxStakeWanted?: BN
   computeUnitLimit: number
 }) {
@@ -183,21 +200,61 @@ async function manageConfigureConfig({
     adminAuthority = adminAuthority.publicKey
   }
 
-  const { instruction } = await configureConfigInstruction({
-    program,
-    configAccount: address,
-    adminAuthority,
-    newAdmin: admin,
-    newOperator: operator,
-    newPauseAuthority: pauseAuthority,
-    newEpochsToClaimSettlement: epochsToClaimSettlement,
-    newSlotsToStartSettlementClaiming: slotsToStartSettlementClaiming,
-    newWithdrawLockupEpochs: withdrawLockupEpochs,
-    newMinimumStakeLamports: minimumStakeLamports,
-    newMinBondMaxStakeWanted: minBondMaxStakeWanted,
-    logger,
-  })
-  tx.add(instruction)
+  // authorities are changed in two steps, the new ones are only proposed here
+  if (
+    admin !== undefined ||
+    operator !== undefined ||
+    pauseAuthority !== undefined
+  ) {
+    const { instruction } = await proposeConfigAuthorityInstruction({
+      program,
+      configAccount: address,
+      adminAuthority,
+      newAdmin: admin,
+      newOperator: operator,
+      newPauseAuthority: pauseAuthority,
+      logger,
+    })
+    tx.add(instruction)
+  }
+  // lockup-sensitive params are queued and applied after the activation epoch
+  if (
+    epochsToClaimSettlement !== undefined ||
+    slotsToStartSettlementClaiming !== undefined ||
+    withdrawLockupEpochs !== undefined ||
+    minimumStakeLamports !== undefined
+  ) {
+    if (activationEpoch === undefined) {
+      const configData = await getConfig(program, address)
+      const { epoch } = await provider.connection.getEpochInfo()
+      activationEpoch = epoch + configData.withdrawLockupEpochs.toNumber() + 1
+    }
+    const { instruction } = await queueConfigureConfigInstruction({
+      program,
+      configAccount: address,
+      adminAuthority,
+      activationEpoch,
+      newEpochsToClaimSettlement: epochsToClaimSettlement,
+      newSlotsToStartSettlementClaiming: slotsToStartSettlementClaiming,
+      newWithdrawLockupEpochs: withdrawLockupEpochs,
+      newMinimumStakeLamports: minimumStakeLamports,
+      logger,
+    })
+    tx.add(instruction)
+    logger.info(
+      `Queueing config change of ${address.toBase58()} with activation epoch ${activationEpoch}`,
+    )
+  }
+  if (tx.instructions.length === 0 || minBondMaxStakeWanted !== undefined) {
+    const { instruction } = await configureConfigInstruction({
+      program,
+      configAccount: address,
+      adminAuthority,
+      newMinBondMaxStakeWanted: minBondMaxStakeWanted,
+      logger,
+    })
+    tx.add(instruction)
+  }
 
   await executeTxHandleErrors({
     connection: provider.connection,
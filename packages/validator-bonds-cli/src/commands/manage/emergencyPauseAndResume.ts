@@ -8,6 +8,12 @@ import {
 import { EMERGENCY_LIMIT_UNITS } from '@marinade.finance/validator-bonds-cli-core'
 import {
   MARINADE_CONFIG_ADDRESS,
+  PAUSE_ALL,
+  PAUSE_CLAIM,
+  PAUSE_FUNDING,
+  PAUSE_SETTLEMENT,
+  PAUSE_STAKE_MANAGEMENT,
+  PAUSE_WITHDRAW,
   emergencyPauseInstruction,
   emergencyResumeInstruction,
 } from '@marinade.finance/validator-bonds-sdk'
@@ -25,6 +31,28 @@ import type {
 import type { PublicKey, Signer, TransactionInstruction } from '@solana/web3.js'
 import type { Command } from 'commander'
 
+const PAUSE_OPERATIONS: Record<string, number> = {
+  funding: PAUSE_FUNDING,
+  withdraw: PAUSE_WITHDRAW,
+  settlement: PAUSE_SETTLEMENT,
+  claim: PAUSE_CLAIM,
+  'stake-management': PAUSE_STAKE_MANAGEMENT,
+  all: PAUSE_ALL,
+}
+
+function parsePauseOperations(value: string): number {
+  return value.split(',').reduce((operations, name) => {
+    const operation = PAUSE_OPERATIONS[name.trim().toLowerCase()]
+    if (operation === undefined) {
+      throw new Error(
+        `Unknown operation group '${name}', expected one of: ` +
+          Object.keys(PAUSE_OPERATIONS).join(', '),
+      )
+    }
+    return operations | operation
+  }, 0)
+}
+
 export function installEmergencyPause(program: Command) {
   setProgramTelemetryFields(program.command('pause'), {
     accountField: 'config_account',
@@ -41,15 +69,23 @@ export function installEmergencyPause(program: Command) {
       'Pause authority with permission to pause the contract (default: wallet)',
       parseWalletOrPubkeyOption,
     )
+    .option(
+      '--operations <groups>',
+      'Comma separated operation groups to be paused ' +
+        `(${Object.keys(PAUSE_OPERATIONS).join(', ')}) (default: all)`,
+      parsePauseOperations,
+    )
     .addOption(computeUnitLimitOption(EMERGENCY_LIMIT_UNITS))
     .action(
       async (
         address: Promise<undefined | PublicKey>,
         {
           authority,
+          operations,
           computeUnitLimit,
         }: {
           authority?: Promise<WalletInterface | PublicKey>
+          operations?: number
           computeUnitLimit: number
         },
       ) => {
@@ -57,6 +93,7 @@ export function installEmergencyPause(program: Command) {
           action: 'pause',
           address: (await address) ?? MARINADE_CONFIG_ADDRESS,
           authority: await authority,
+          operations,
           computeUnitLimit,
         })
       },
@@ -79,15 +116,23 @@ export function installEmergencyResume(program: Command) {
       'Pause authority with permission to resume the contract (default: wallet)',
       parseWalletOrPubkeyOption,
     )
+    .option(
+      '--operations <groups>',
+      'Comma separated operation groups to be resumed ' +
+        `(${Object.keys(PAUSE_OPERATIONS).join(', ')}) (default: all)`,
+      parsePauseOperations,
+    )
     .addOption(computeUnitLimitOption(EMERGENCY_LIMIT_UNITS))
     .action(
       async (
         address: Promise<undefined | PublicKey>,
         {
           authority,
+          operations,
           computeUnitLimit,
         }: {
           authority?: Promise<WalletInterface | PublicKey>
+          operations?: number
           computeUnitLimit: number
         },
       ) => {
@@ -95,6 +140,7 @@ export function installEmergencyResume(program: Command) {
           action: 'resume',
           address: (await address) ?? MARINADE_CONFIG_ADDRESS,
           authority: await authority,
+          operations,
           computeUnitLimit,
         })
       },
@@ -105,11 +151,13 @@ async function manageEmergencyPauseAndResume({
   action,
   address,
   authority,
+  operations = PAUSE_ALL,
   computeUnitLimit,
 }: {
   action: 'pause' | 'resume'
   address: PublicKey
   authority?: WalletInterface | PublicKey
+  operations?: number
   computeUnitLimit: number
 }) {
   const {
@@ -140,6 +188,7 @@ async function manageEmergencyPauseAndResume({
       program,
       configAccount: address,
       pauseAuthority: authority,
+      operations,
       logger,
     }))
   } else {
@@ -147,6 +196,7 @@ async function manageEmergencyPauseAndResume({
       program,
       configAccount: address,
       pauseAuthority: authority,
+      operations,
       logger,
     }))
   }
@@ -6,9 +6,15 @@ import {
 } from '@marinade.finance/validator-bonds-cli-core'
 import { MARINADE_CONFIG_ADDRESS } from '@marinade.finance/validator-bonds-sdk'
 
+import { installAcceptBondAuthority } from './acceptBondAuthority'
+import { installCancelBondAuthority } from './cancelBondAuthority'
 import { installCancelWithdrawRequest } from './cancelWithdrawRequest'
 import { installClaimWithdrawRequest } from './claimWithdrawRequest'
 import { installCloseSettlement } from './closeSettlement'
+import {
+  installAcceptConfigAuthority,
+  installCancelConfigAuthority,
+} from './configAuthority'
 import { installConfigureBond } from './configureBond'
 import { installConfigureConfig } from './configureConfig'
 import {
@@ -22,6 +28,10 @@ import { installInitConfig } from './initConfig'
 import { installInitWithdrawRequest } from './initWithdrawRequest'
 import { installStakeMerge } from './mergeStake'
 import { installMintBond } from './mintBond'
+import {
+  installCancelQueuedConfig,
+  installExecuteConfigureConfig,
+} from './queuedConfig'
 import { installResetStake } from './resetStake'
 
 import type { Command } from 'commander'
@@ -29,9 +39,15 @@ import type { Command } from 'commander'
 export function installManage(program: Command) {
   installInitConfig(program)
   installConfigureConfig(program)
+  installAcceptConfigAuthority(program)
+  installCancelConfigAuthority(program)
+  installExecuteConfigureConfig(program)
+  installCancelQueuedConfig(program)
   installMintBond(program)
   installInitBond(program)
   installConfigureBond(program)
+  installAcceptBondAuthority(program)
+  installCancelBondAuthority(program)
   installStakeMerge(program)
   installFundBond(program)
   installFundBondWithSol(program)
@@ -0,0 +1,173 @@
+import {
+  CONFIGURE_CONFIG_LIMIT_UNITS,
+  computeUnitLimitOption,
+  executeTxHandleErrors,
+  getCliContext,
+  setProgramTelemetryFields,
+  txOutcomeMessage,
+} from '@marinade.finance/validator-bonds-cli-core'
+import {
+  MARINADE_CONFIG_ADDRESS,
+  cancelQueuedConfigInstruction,
+  executeConfigureConfigInstruction,
+  getConfig,
+} from '@marinade.finance/validator-bonds-sdk'
+import {
+  instanceOfWallet,
+  parsePubkey,
+  parseWalletOrPubkeyOption,
+  transaction,
+} from '@marinade.finance/web3js-1x'
+
+import type {
+  Wallet as WalletInterface,
+  Wallet,
+} from '@marinade.finance/web3js-1x'
+import type { PublicKey, Signer, TransactionInstruction } from '@solana/web3.js'
+import type { Command } from 'commander'
+
+export function installExecuteConfigureConfig(program: Command) {
+  setProgramTelemetryFields(program.command('execute-configure-config'), {
+    accountField: 'config_account',
+  })
+    .description(
+      'Apply the config change queued by the "configure-config" command when the activation epoch is reached. ' +
+        'Permission-less operation.',
+    )
+    .argument(
+      '[config-address]',
+      'Address of the validator bonds config account ' +
+        `(default: ${MARINADE_CONFIG_ADDRESS.toBase58()})`,
+      parsePubkey,
+    )
+    .addOption(computeUnitLimitOption(CONFIGURE_CONFIG_LIMIT_UNITS))
+    .action(
+      async (
+        address: Promise<undefined | PublicKey>,
+        {
+          computeUnitLimit,
+        }: {
+          computeUnitLimit: number
+        },
+      ) => {
+        await manageQueuedConfig({
+          action: 'execute',
+          address: (await address) ?? MARINADE_CONFIG_ADDRESS,
+          computeUnitLimit,
+        })
+      },
+    )
+}
+
+export function installCancelQueuedConfig(program: Command) {
+  setProgramTelemetryFields(program.command('cancel-queued-config'), {
+    accountField: 'config_account',
+  })
+    .description(
+      'Cancel the config change queued by the "configure-config" command and not executed yet.',
+    )
+    .argument(
+      '[config-address]',
+      'Address of the validator bonds config account ' +
+        `(default: ${MARINADE_CONFIG_ADDRESS.toBase58()})`,
+      parsePubkey,
+    )
+    .option(
+      '--admin-authority <keypair-or-ledger-or-pubkey>',
+      'Admin authority that is permitted to cancel the queued change (default: admin authority from config)',
+      parseWalletOrPubkeyOption,
+    )
+    .addOption(computeUnitLimitOption(CONFIGURE_CONFIG_LIMIT_UNITS))
+    .action(
+      async (
+        address: Promise<undefined | PublicKey>,
+        {
+          adminAuthority,
+          computeUnitLimit,
+        }: {
+          adminAuthority?: Promise<WalletInterface | PublicKey>
+          computeUnitLimit: number
+        },
+      ) => {
+        await manageQueuedConfig({
+          action: 'cancel',
+          address: (await address) ?? MARINADE_CONFIG_ADDRESS,
+          adminAuthority: await adminAuthority,
+          computeUnitLimit,
+        })
+      },
+    )
+}
+
+async function manageQueuedConfig({
+  action,
+  address,
+  adminAuthority,
+  computeUnitLimit,
+}: {
+  action: 'execute' | 'cancel'
+  address: PublicKey
+  adminAuthority?: WalletInterface | PublicKey
+  computeUnitLimit: number
+}) {
+  const {
+    program,
+    provider,
+    logger,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    wallet,
+    confirmationFinality,
+    confirmWaitTime,
+    skipPreflight,
+  } = getCliContext()
+
+  const tx = await transaction(provider)
+  const signers: (Signer | Wallet)[] = [wallet]
+
+  let instruction: TransactionInstruction
+  if (action === 'execute') {
+    ;({ instruction } = await executeConfigureConfigInstruction({
+      program,
+      configAccount: address,
+      logger,
+    }))
+  } else {
+    if (adminAuthority === undefined) {
+      adminAuthority = (await getConfig(program, address)).adminAuthority
+    }
+    if (instanceOfWallet(adminAuthority)) {
+      signers.push(adminAuthority)
+      adminAuthority = adminAuthority.publicKey
+    }
+    ;({ instruction } = await cancelQueuedConfigInstruction({
+      program,
+      configAccount: address,
+      adminAuthority,
+      logger,
+    }))
+  }
+  tx.add(instruction)
+
+  await executeTxHandleErrors({
+    connection: provider.connection,
+    transaction: tx,
+    errMessage: `Failed to ${action} queued change of config account ${address.toBase58()}`,
+    signers,
+    logger,
+    computeUnitLimit,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    confirmOpts: confirmationFinality,
+    confirmWaitTime,
+    sendOpts: { skipPreflight },
+  })
+  logger.info(
+    txOutcomeMessage(
+      simulate || printOnly,
+      `Succeeded to ${action} queued change of config account ${address.toBase58()}`,
+    ),
+  )
+}
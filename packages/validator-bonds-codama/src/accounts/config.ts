@@ -17,10 +17,10 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
-  getBooleanDecoder,
-  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -30,16 +30,24 @@ import {
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit'
+import {
+  getQueuedConfigChangeDecoder,
+  getQueuedConfigChangeEncoder,
+  type QueuedConfigChange,
+  type QueuedConfigChangeArgs,
+} from '../types'
 
 export const CONFIG_DISCRIMINATOR = new Uint8Array([
   155, 12, 170, 224, 30, 250, 204, 130,
@@ -65,11 +73,20 @@ export type Config = {
   bondsWithdrawerAuthorityBump: number
   /** Authority that can pause the program in case of emergency */
   pauseAuthority: Address
-  paused: boolean
+  /** Bitmask of the paused operation groups (see PAUSE_* constants), legacy value 1 pauses all of them */
+  paused: number
   /** How many slots to wait before settlement is permitted to be claimed */
   slotsToStartSettlementClaiming: bigint
   /** Minimum value of max_stake_wanted to be configured by vote account owners at bond. */
   minBondMaxStakeWanted: bigint
+  /** Proposed admin authority that has to accept the role; default pubkey when nothing is proposed */
+  pendingAdminAuthority: Address
+  /** Proposed operator authority that has to accept the role; default pubkey when nothing is proposed */
+  pendingOperatorAuthority: Address
+  /** Proposed pause authority that has to accept the role; default pubkey when nothing is proposed */
+  pendingPauseAuthority: Address
+  /** Change of the lockup-sensitive parameters waiting for its activation epoch */
+  queuedChange: Option<QueuedConfigChange>
   /** reserved space for future changes */
   reserved: ReadonlyUint8Array
 }
@@ -89,17 +106,26 @@ export type ConfigArgs = {
   bondsWithdrawerAuthorityBump: number
   /** Authority that can pause the program in case of emergency */
   pauseAuthority: Address
-  paused: boolean
+  /** Bitmask of the paused operation groups (see PAUSE_* constants), legacy value 1 pauses all of them */
+  paused: number
   /** How many slots to wait before settlement is permitted to be claimed */
   slotsToStartSettlementClaiming: number | bigint
   /** Minimum value of max_stake_wanted to be configured by vote account owners at bond. */
   minBondMaxStakeWanted: number | bigint
+  /** Proposed admin authority that has to accept the role; default pubkey when nothing is proposed */
+  pendingAdminAuthority: Address
+  /** Proposed operator authority that has to accept the role; default pubkey when nothing is proposed */
+  pendingOperatorAuthority: Address
+  /** Proposed pause authority that has to accept the role; default pubkey when nothing is proposed */
+  pendingPauseAuthority: Address
+  /** Change of the lockup-sensitive parameters waiting for its activation epoch */
+  queuedChange: OptionOrNullable<QueuedConfigChangeArgs>
   /** reserved space for future changes */
   reserved: ReadonlyUint8Array
 }
 
 /** Gets the encoder for {@link ConfigArgs} account data. */
-export function getConfigEncoder(): FixedSizeEncoder<ConfigArgs> {
+export function getConfigEncoder(): Encoder<ConfigArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
@@ -110,17 +136,21 @@ export function getConfigEncoder(): FixedSizeEncoder<ConfigArgs> {
       ['minimumStakeLamports', getU64Encoder()],
       ['bondsWithdrawerAuthorityBump', getU8Encoder()],
       ['pauseAuthority', getAddressEncoder()],
-      ['paused', getBooleanEncoder()],
+      ['paused', getU8Encoder()],
       ['slotsToStartSettlementClaiming', getU64Encoder()],
       ['minBondMaxStakeWanted', getU64Encoder()],
-      ['reserved', fixEncoderSize(getBytesEncoder(), 463)],
+      ['pendingAdminAuthority', getAddressEncoder()],
+      ['pendingOperatorAuthority', getAddressEncoder()],
+      ['pendingPauseAuthority', getAddressEncoder()],
+      ['queuedChange', getOptionEncoder(getQueuedConfigChangeEncoder())],
+      ['reserved', fixEncoderSize(getBytesEncoder(), 322)],
     ]),
     value => ({ ...value, discriminator: CONFIG_DISCRIMINATOR }),
   )
 }
 
 /** Gets the decoder for {@link Config} account data. */
-export function getConfigDecoder(): FixedSizeDecoder<Config> {
+export function getConfigDecoder(): Decoder<Config> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['adminAuthority', getAddressDecoder()],
@@ -130,15 +160,19 @@ export function getConfigDecoder(): FixedSizeDecoder<Config> {
     ['minimumStakeLamports', getU64Decoder()],
     ['bondsWithdrawerAuthorityBump', getU8Decoder()],
     ['pauseAuthority', getAddressDecoder()],
-    ['paused', getBooleanDecoder()],
+    ['paused', getU8Decoder()],
     ['slotsToStartSettlementClaiming', getU64Decoder()],
     ['minBondMaxStakeWanted', getU64Decoder()],
-    ['reserved', fixDecoderSize(getBytesDecoder(), 463)],
+    ['pendingAdminAuthority', getAddressDecoder()],
+    ['pendingOperatorAuthority', getAddressDecoder()],
+    ['pendingPauseAuthority', getAddressDecoder()],
+    ['queuedChange', getOptionDecoder(getQueuedConfigChangeDecoder())],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 322)],
   ])
 }
 
 /** Gets the codec for {@link Config} account data. */
-export function getConfigCodec(): FixedSizeCodec<ConfigArgs, Config> {
+export function getConfigCodec(): Codec<ConfigArgs, Config> {
   return combineCodec(getConfigEncoder(), getConfigDecoder())
 }
 
@@ -194,7 +228,3 @@ export async function fetchAllMaybeConfig(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config)
   return maybeAccounts.map(maybeAccount => decodeConfig(maybeAccount))
 }
-
-export function getConfigSize(): number {
-  return 609
-}
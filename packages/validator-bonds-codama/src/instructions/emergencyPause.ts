@@ -15,6 +15,8 @@ import {
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
   SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS,
   SolanaError,
   transformEncoder,
@@ -79,13 +81,21 @@ export type EmergencyPauseInstruction<
 
 export type EmergencyPauseInstructionData = {
   discriminator: ReadonlyUint8Array
+  /** bitmask of operation groups to be paused or resumed (see PAUSE_* constants) */
+  operations: number
 }
 
-export type EmergencyPauseInstructionDataArgs = {}
+export type EmergencyPauseInstructionDataArgs = {
+  /** bitmask of operation groups to be paused or resumed (see PAUSE_* constants) */
+  operations: number
+}
 
 export function getEmergencyPauseInstructionDataEncoder(): FixedSizeEncoder<EmergencyPauseInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    getStructEncoder([
+      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['operations', getU8Encoder()],
+    ]),
     value => ({ ...value, discriminator: EMERGENCY_PAUSE_DISCRIMINATOR }),
   )
 }
@@ -93,6 +103,7 @@ export function getEmergencyPauseInstructionDataEncoder(): FixedSizeEncoder<Emer
 export function getEmergencyPauseInstructionDataDecoder(): FixedSizeDecoder<EmergencyPauseInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['operations', getU8Decoder()],
   ])
 }
 
@@ -116,6 +127,7 @@ export type EmergencyPauseAsyncInput<
   pauseAuthority: TransactionSigner<TAccountPauseAuthority>
   eventAuthority?: Address<TAccountEventAuthority>
   program: Address<TAccountProgram>
+  operations: EmergencyPauseInstructionDataArgs['operations']
 }
 
 export async function getEmergencyPauseInstructionAsync<
@@ -157,6 +169,9 @@ export async function getEmergencyPauseInstructionAsync<
     ResolvedInstructionAccount
   >
 
+  // Original args.
+  const args = { ...input }
+
   // Resolve default values.
   if (!accounts.eventAuthority.value) {
     accounts.eventAuthority.value = await getProgramDerivedAddress({
@@ -180,7 +195,9 @@ export async function getEmergencyPauseInstructionAsync<
       getAccountMeta('eventAuthority', accounts.eventAuthority),
       getAccountMeta('program', accounts.program),
     ],
-    data: getEmergencyPauseInstructionDataEncoder().encode({}),
+    data: getEmergencyPauseInstructionDataEncoder().encode(
+      args as EmergencyPauseInstructionDataArgs,
+    ),
     programAddress,
   } as EmergencyPauseInstruction<
     TProgramAddress,
@@ -201,6 +218,7 @@ export type EmergencyPauseInput<
   pauseAuthority: TransactionSigner<TAccountPauseAuthority>
   eventAuthority: Address<TAccountEventAuthority>
   program: Address<TAccountProgram>
+  operations: EmergencyPauseInstructionDataArgs['operations']
 }
 
 export function getEmergencyPauseInstruction<
@@ -240,6 +258,9 @@ export function getEmergencyPauseInstruction<
     ResolvedInstructionAccount
   >
 
+  // Original args.
+  const args = { ...input }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId')
   return Object.freeze({
     accounts: [
@@ -248,7 +269,9 @@ export function getEmergencyPauseInstruction<
       getAccountMeta('eventAuthority', accounts.eventAuthority),
       getAccountMeta('program', accounts.program),
     ],
-    data: getEmergencyPauseInstructionDataEncoder().encode({}),
+    data: getEmergencyPauseInstructionDataEncoder().encode(
+      args as EmergencyPauseInstructionDataArgs,
+    ),
     programAddress,
   } as EmergencyPauseInstruction<
     TProgramAddress,
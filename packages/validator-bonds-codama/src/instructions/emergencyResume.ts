@@ -15,6 +15,8 @@ import {
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
   SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS,
   SolanaError,
   transformEncoder,
@@ -79,13 +81,21 @@ export type EmergencyResumeInstruction<
 
 export type EmergencyResumeInstructionData = {
   discriminator: ReadonlyUint8Array
+  /** bitmask of operation groups to be paused or resumed (see PAUSE_* constants) */
+  operations: number
 }
 
-export type EmergencyResumeInstructionDataArgs = {}
+export type EmergencyResumeInstructionDataArgs = {
+  /** bitmask of operation groups to be paused or resumed (see PAUSE_* constants) */
+  operations: number
+}
 
 export function getEmergencyResumeInstructionDataEncoder(): FixedSizeEncoder<EmergencyResumeInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
+    getStructEncoder([
+      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['operations', getU8Encoder()],
+    ]),
     value => ({ ...value, discriminator: EMERGENCY_RESUME_DISCRIMINATOR }),
   )
 }
@@ -93,6 +103,7 @@ export function getEmergencyResumeInstructionDataEncoder(): FixedSizeEncoder<Eme
 export function getEmergencyResumeInstructionDataDecoder(): FixedSizeDecoder<EmergencyResumeInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['operations', getU8Decoder()],
   ])
 }
 
@@ -116,6 +127,7 @@ export type EmergencyResumeAsyncInput<
   pauseAuthority: TransactionSigner<TAccountPauseAuthority>
   eventAuthority?: Address<TAccountEventAuthority>
   program: Address<TAccountProgram>
+  operations: EmergencyResumeInstructionDataArgs['operations']
 }
 
 export async function getEmergencyResumeInstructionAsync<
@@ -157,6 +169,9 @@ export async function getEmergencyResumeInstructionAsync<
     ResolvedInstructionAccount
   >
 
+  // Original args.
+  const args = { ...input }
+
   // Resolve default values.
   if (!accounts.eventAuthority.value) {
     accounts.eventAuthority.value = await getProgramDerivedAddress({
@@ -180,7 +195,9 @@ export async function getEmergencyResumeInstructionAsync<
       getAccountMeta('eventAuthority', accounts.eventAuthority),
       getAccountMeta('program', accounts.program),
     ],
-    data: getEmergencyResumeInstructionDataEncoder().encode({}),
+    data: getEmergencyResumeInstructionDataEncoder().encode(
+      args as EmergencyResumeInstructionDataArgs,
+    ),
     programAddress,
   } as EmergencyResumeInstruction<
     TProgramAddress,
@@ -201,6 +218,7 @@ export type EmergencyResumeInput<
   pauseAuthority: TransactionSigner<TAccountPauseAuthority>
   eventAuthority: Address<TAccountEventAuthority>
   program: Address<TAccountProgram>
+  operations: EmergencyResumeInstructionDataArgs['operations']
 }
 
 export function getEmergencyResumeInstruction<
@@ -240,6 +258,9 @@ export function getEmergencyResumeInstruction<
     ResolvedInstructionAccount
   >
 
+  // Original args.
+  const args = { ...input }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId')
   return Object.freeze({
     accounts: [
@@ -248,7 +269,9 @@ export function getEmergencyResumeInstruction<
       getAccountMeta('eventAuthority', accounts.eventAuthority),
       getAccountMeta('program', accounts.program),
     ],
-    data: getEmergencyResumeInstructionDataEncoder().encode({}),
+    data: getEmergencyResumeInstructionDataEncoder().encode(
+      args as EmergencyResumeInstructionDataArgs,
+    ),
     programAddress,
   } as EmergencyResumeInstruction<
     TProgramAddress,
@@ -14,6 +14,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -28,6 +30,10 @@ export type EmergencyResumeEvent = {
   withdrawLockupEpochs: bigint
   minimumStakeLamports: bigint
   pauseAuthority: Address
+  /** operation groups whose pause state was changed */
+  changedOperations: number
+  /** all operation groups paused after the change */
+  pausedOperations: number
 }
 
 export type EmergencyResumeEventArgs = {
@@ -38,6 +44,10 @@ export type EmergencyResumeEventArgs = {
   withdrawLockupEpochs: number | bigint
   minimumStakeLamports: number | bigint
   pauseAuthority: Address
+  /** operation groups whose pause state was changed */
+  changedOperations: number
+  /** all operation groups paused after the change */
+  pausedOperations: number
 }
 
 export function getEmergencyResumeEventEncoder(): FixedSizeEncoder<EmergencyResumeEventArgs> {
@@ -49,6 +59,8 @@ export function getEmergencyResumeEventEncoder(): FixedSizeEncoder<EmergencyResu
     ['withdrawLockupEpochs', getU64Encoder()],
     ['minimumStakeLamports', getU64Encoder()],
     ['pauseAuthority', getAddressEncoder()],
+    ['changedOperations', getU8Encoder()],
+    ['pausedOperations', getU8Encoder()],
   ])
 }
 
@@ -61,6 +73,8 @@ export function getEmergencyResumeEventDecoder(): FixedSizeDecoder<EmergencyResu
     ['withdrawLockupEpochs', getU64Decoder()],
     ['minimumStakeLamports', getU64Decoder()],
     ['pauseAuthority', getAddressDecoder()],
+    ['changedOperations', getU8Decoder()],
+    ['pausedOperations', getU8Decoder()],
   ])
 }
 
@@ -33,6 +33,7 @@ export * from './mintBondEvent'
 export * from './productType'
 export * from './productTypeConfig'
 export * from './pubkeyValueChange'
+export * from './queuedConfigChange'
 export * from './resetStakeEvent'
 export * from './splitStakeData'
 export * from './u64ValueChange'
@@ -0,0 +1,146 @@
+import { verifyError } from '@marinade.finance/anchor-common'
+import { Keypair, PublicKey } from '@solana/web3.js'
+
+import { initBankrunTest } from './bankrun'
+import {
+  Errors,
+  acceptBondAuthorityInstruction,
+  cancelBondAuthorityInstruction,
+  configureBondInstruction,
+  getBond,
+} from '../../src'
+import { createVoteAccount } from '../utils/staking'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+} from '../utils/testTransactions'
+
+import type { ValidatorBondsProgram } from '../../src'
+import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+
+describe('Validator Bonds accept and cancel bond authority', () => {
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let bondAccount: PublicKey
+  let bondAuthority: Keypair
+  let validatorIdentity: Keypair
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+  })
+
+  beforeEach(async () => {
+    ;({ configAccount } = await executeInitConfigInstruction({
+      program,
+      provider,
+    }))
+    const { voteAccount, validatorIdentity: nodePubkey } =
+      await createVoteAccount({ provider })
+    validatorIdentity = nodePubkey
+    ;({ bondAccount, bondAuthority } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+    }))
+  })
+
+  async function proposeBondAuthority(newBondAuthority: PublicKey) {
+    const { instruction } = await configureBondInstruction({
+      program,
+      bondAccount,
+      authority: bondAuthority,
+      newBondAuthority,
+    })
+    await provider.sendIx([bondAuthority], instruction)
+  }
+
+  it('accept proposed bond authority', async () => {
+    const newBondAuthority = Keypair.generate()
+    await proposeBondAuthority(newBondAuthority.publicKey)
+    let bondData = await getBond(program, bondAccount)
+    expect(bondData.authority).toEqual(bondAuthority.publicKey)
+    expect(bondData.pendingAuthority).toEqual(newBondAuthority.publicKey)
+
+    const randomKey = Keypair.generate()
+    const { instruction: wrongAcceptIx } =
+      await acceptBondAuthorityInstruction({
+        program,
+        bondAccount,
+        newAuthority: randomKey,
+      })
+    try {
+      await provider.sendIx([randomKey], wrongAcceptIx)
+      throw new Error('failure expected; not the pending authority')
+    } catch (e) {
+      verifyError(e, Errors, 6088, 'not the pending authority')
+    }
+
+    const { instruction } = await acceptBondAuthorityInstruction({
+      program,
+      bondAccount,
+      newAuthority: newBondAuthority,
+    })
+    await provider.sendIx([newBondAuthority], instruction)
+    bondData = await getBond(program, bondAccount)
+    expect(bondData.authority).toEqual(newBondAuthority.publicKey)
+    expect(bondData.pendingAuthority).toEqual(PublicKey.default)
+  })
+
+  it('cancel proposed bond authority', async () => {
+    const newBondAuthority = Keypair.generate()
+    await proposeBondAuthority(newBondAuthority.publicKey)
+
+    const randomKey = Keypair.generate()
+    const { instruction: wrongCancelIx } = await cancelBondAuthorityInstruction(
+      {
+        program,
+        bondAccount,
+        authority: randomKey,
+      },
+    )
+    try {
+      await provider.sendIx([randomKey], wrongCancelIx)
+      throw new Error('failure expected; wrong authority')
+    } catch (e) {
+      verifyError(e, Errors, 6018, 'Wrong authority')
+    }
+
+    // validator identity is permitted to cancel as well as the bond authority
+    const { instruction: cancelIx } = await cancelBondAuthorityInstruction({
+      program,
+      bondAccount,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], cancelIx)
+    const bondData = await getBond(program, bondAccount)
+    expect(bondData.authority).toEqual(bondAuthority.publicKey)
+    expect(bondData.pendingAuthority).toEqual(PublicKey.default)
+
+    const { instruction: acceptIx } = await acceptBondAuthorityInstruction({
+      program,
+      bondAccount,
+      newAuthority: newBondAuthority,
+    })
+    try {
+      await provider.sendIx([newBondAuthority], acceptIx)
+      throw new Error('failure expected; proposal was cancelled')
+    } catch (e) {
+      verifyError(e, Errors, 6088, 'not the pending authority')
+    }
+
+    await proposeBondAuthority(randomKey.publicKey)
+    const { instruction: cancelByAuthorityIx } =
+      await cancelBondAuthorityInstruction({
+        program,
+        bondAccount,
+        authority: bondAuthority,
+      })
+    await provider.sendIx([bondAuthority], cancelByAuthorityIx)
+    expect((await getBond(program, bondAccount)).pendingAuthority).toEqual(
+      PublicKey.default,
+    )
+  })
+})
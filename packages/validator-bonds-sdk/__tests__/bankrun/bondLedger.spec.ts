@@ -0,0 +1,206 @@
+import { verifyError } from '@marinade.finance/anchor-common'
+import {
+  assertNotExist,
+  currentEpoch,
+  warpToNextEpoch,
+} from '@marinade.finance/bankrun-utils'
+import { signer } from '@marinade.finance/web3js-1x'
+import { LAMPORTS_PER_SOL, TransactionInstruction } from '@solana/web3.js'
+
+import { initBankrunTest } from './bankrun'
+import {
+  Errors,
+  MerkleTreeNode,
+  bondLedgerAddress,
+  claimSettlementV2Instruction,
+  closeBondLedgerInstruction,
+  closeSettlementV2Instruction,
+  fundSettlementInstruction,
+  getBond,
+  getBondLedger,
+  getSettlement,
+  initBondLedgerInstruction,
+} from '../../src'
+import {
+  buildMerkleTree,
+  staker1,
+  withdrawer1,
+} from '../utils/merkleTreeTestData'
+import {
+  createBondsFundedStakeAccount,
+  createDelegatedStakeAccount,
+  createVoteAccount,
+} from '../utils/staking'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+  executeInitSettlement,
+} from '../utils/testTransactions'
+
+import type { ValidatorBondsProgram } from '../../src'
+import type { MerkleTreeNodeWithProof } from '../utils/merkleTreeTestData'
+import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+import type { Keypair, PublicKey } from '@solana/web3.js'
+
+describe('Validator Bonds bond ledger', () => {
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let operatorAuthority: Keypair
+  let validatorIdentity: Keypair
+  let voteAccount: PublicKey
+  let bondAccount: PublicKey
+  let bondLedgerAccount: PublicKey
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+    ;({ configAccount, operatorAuthority } = await executeInitConfigInstruction(
+      {
+        program,
+        provider,
+        epochsToClaimSettlement: 4,
+      },
+    ))
+    ;({ voteAccount, validatorIdentity } = await createVoteAccount({
+      provider,
+    }))
+    ;({ bondAccount } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+    }))
+  })
+
+  it('init bond ledger', async () => {
+    expect((await getBond(program, bondAccount)).bondLedgerBump).toBeNull()
+    const { instruction, bondLedgerAccount: ledgerAccount } =
+      await initBondLedgerInstruction({
+        program,
+        bondAccount,
+      })
+    await provider.sendIx([], instruction)
+    bondLedgerAccount = ledgerAccount
+
+    const [expectedAddress, bump] = bondLedgerAddress(
+      bondAccount,
+      program.programId,
+    )
+    expect(bondLedgerAccount).toEqual(expectedAddress)
+    expect((await getBond(program, bondAccount)).bondLedgerBump).toEqual(bump)
+    const bondLedger = await getBondLedger(program, bondLedgerAccount)
+    expect(bondLedger.bond).toEqual(bondAccount)
+    expect(bondLedger.bump).toEqual(bump)
+  })
+
+  it('bond ledger records settlement funding, claiming and closing', async () => {
+    const { merkleRoot, items } = buildMerkleTree([
+      new MerkleTreeNode({
+        stakeAuthority: staker1,
+        withdrawAuthority: withdrawer1,
+        claim: LAMPORTS_PER_SOL,
+        index: 0,
+      }),
+    ])
+    const [item] = items as [MerkleTreeNodeWithProof]
+    await warpToNextEpoch(provider)
+    const { settlementAccount } = await executeInitSettlement({
+      configAccount,
+      program,
+      provider,
+      voteAccount,
+      operatorAuthority,
+      currentEpoch: await currentEpoch(provider),
+      merkleRoot,
+      maxMerkleNodes: 1,
+      maxTotalClaim: LAMPORTS_PER_SOL,
+    })
+    const stakeAccountFrom = await createBondsFundedStakeAccount({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      lamports: LAMPORTS_PER_SOL * 6,
+    })
+    await warpToNextEpoch(provider) // activate stake account
+
+    const { instruction: fundIx, splitStakeAccount } =
+      await fundSettlementInstruction({
+        program,
+        settlementAccount,
+        stakeAccount: stakeAccountFrom,
+      })
+    // the bond ledger cannot be skipped when created for the bond
+    const fundNoLedgerIx = new TransactionInstruction({
+      ...fundIx,
+      keys: fundIx.keys.filter(key => !key.pubkey.equals(bondLedgerAccount)),
+    })
+    try {
+      await provider.sendIx(
+        [signer(splitStakeAccount), operatorAuthority],
+        fundNoLedgerIx,
+      )
+      throw new Error('failure expected; bond ledger not provided')
+    } catch (e) {
+      verifyError(e, Errors, 6116, 'Bond ledger account is required')
+    }
+    await provider.sendIx(
+      [signer(splitStakeAccount), operatorAuthority],
+      fundIx,
+    )
+    const fundEpoch = Number(await currentEpoch(provider))
+    expect((await ledgerRecord(fundEpoch))?.fundedLamports).toEqual(
+      (await getSettlement(program, settlementAccount)).lamportsFunded,
+    )
+
+    const stakeAccountTo = await createDelegatedStakeAccount({
+      provider,
+      lamports: 3 * LAMPORTS_PER_SOL,
+      voteAccount,
+      staker: staker1,
+      withdrawer: withdrawer1,
+    })
+    await warpToNextEpoch(provider) // deactivate stake account
+    const { instruction: claimIx } = await claimSettlementV2Instruction({
+      program,
+      claimAmount: item.treeNode.data.claim,
+      index: item.treeNode.data.index,
+      merkleProof: item.proof,
+      settlementAccount,
+      stakeAccountFrom,
+      stakeAccountTo,
+    })
+    await provider.sendIx([], claimIx)
+
+    // fully claimed settlement closed in the same epoch
+    const { instruction: closeIx } = await closeSettlementV2Instruction({
+      program,
+      settlementAccount,
+    })
+    await provider.sendIx([], closeIx)
+    await assertNotExist(provider, settlementAccount)
+
+    const claimEpoch = Number(await currentEpoch(provider))
+    const claimRecord = await ledgerRecord(claimEpoch)
+    expect(claimRecord?.claimedLamports).toEqual(LAMPORTS_PER_SOL)
+    expect(claimRecord?.closedSettlements).toEqual(1)
+    expect((await ledgerRecord(fundEpoch))?.claimedLamports).toEqual(0)
+  })
+
+  it('close bond ledger', async () => {
+    const { instruction } = await closeBondLedgerInstruction({
+      program,
+      bondAccount,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], instruction)
+    await assertNotExist(provider, bondLedgerAccount)
+    expect((await getBond(program, bondAccount)).bondLedgerBump).toBeNull()
+  })
+
+  async function ledgerRecord(epoch: number) {
+    const bondLedger = await getBondLedger(program, bondLedgerAccount)
+    return bondLedger.records.find(record => record.epoch.toNumber() === epoch)
+  }
+})
@@ -10,12 +10,12 @@ import {
   Errors,
   cancelSettlementInstruction,
   closeSettlementV2Instruction,
-  configureConfigInstruction,
   getSettlement,
 } from '../../src'
 import { getRentExempt } from '../utils/helpers'
 import { createVoteAccount } from '../utils/staking'
 import {
+  executeChangeConfigAuthority,
   executeInitBondInstruction,
   executeInitConfigInstruction,
   executeInitSettlement,
@@ -110,14 +110,13 @@ describe('Validator Bonds cancel settlement', () => {
 
   it('cancel settlement with pause authority', async () => {
     const pauseAuthority = Keypair.generate()
-    const { instruction: configureConfigIx } = await configureConfigInstruction(
-      {
-        program,
-        configAccount: configAccount,
-        newPauseAuthority: pauseAuthority.publicKey,
-      },
-    )
-    await provider.sendIx([adminAuthority], configureConfigIx)
+    await executeChangeConfigAuthority({
+      program,
+      provider,
+      configAccount,
+      adminAuthority,
+      newPauseAuthority: pauseAuthority,
+    })
 
     const { instruction } = await cancelSettlementInstruction({
       program,
@@ -0,0 +1,309 @@
+import { verifyError } from '@marinade.finance/anchor-common'
+import { currentEpoch, warpToNextEpoch } from '@marinade.finance/bankrun-utils'
+import { signer } from '@marinade.finance/web3js-1x'
+import {
+  LAMPORTS_PER_SOL,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_STAKE_HISTORY_PUBKEY,
+  StakeProgram,
+} from '@solana/web3.js'
+
+import { initBankrunTest } from './bankrun'
+import {
+  Errors,
+  MerkleTreeNode,
+  bondsWithdrawerAuthority,
+  claimSettlementBatchInstruction,
+  fundSettlementInstruction,
+  getSettlement,
+  isClaimed,
+  settlementClaimsAddress,
+} from '../../src'
+import {
+  buildMerkleTree,
+  staker1,
+  staker2,
+  withdrawer1,
+  withdrawer2,
+} from '../utils/merkleTreeTestData'
+import {
+  createBondsFundedStakeAccount,
+  createDelegatedStakeAccount,
+  createVoteAccount,
+} from '../utils/staking'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+  executeInitSettlement,
+} from '../utils/testTransactions'
+
+import type { ValidatorBondsProgram } from '../../src'
+import type { MerkleTreeNodeWithProof } from '../utils/merkleTreeTestData'
+import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+import type { Keypair, PublicKey } from '@solana/web3.js'
+
+describe('Validator Bonds claim settlement batch', () => {
+  // tree nodes 0..2 share the stake and withdraw authorities
+  const { merkleRoot, items } = buildMerkleTree([
+    new MerkleTreeNode({
+      stakeAuthority: staker1,
+      withdrawAuthority: withdrawer1,
+      claim: LAMPORTS_PER_SOL,
+      index: 0,
+    }),
+    new MerkleTreeNode({
+      stakeAuthority: staker1,
+      withdrawAuthority: withdrawer1,
+      claim: 2 * LAMPORTS_PER_SOL,
+      index: 1,
+    }),
+    new MerkleTreeNode({
+      stakeAuthority: staker1,
+      withdrawAuthority: withdrawer1,
+      claim: 3 * LAMPORTS_PER_SOL,
+      index: 2,
+    }),
+    new MerkleTreeNode({
+      stakeAuthority: staker2,
+      withdrawAuthority: withdrawer2,
+      claim: 4 * LAMPORTS_PER_SOL,
+      index: 3,
+    }),
+  ])
+  const maxTotalClaim = 10 * LAMPORTS_PER_SOL
+  const toClaim = (item: MerkleTreeNodeWithProof) => ({
+    claimAmount: item.treeNode.data.claim,
+    index: item.treeNode.data.index,
+    merkleProof: item.proof,
+  })
+
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let operatorAuthority: Keypair
+  let voteAccount: PublicKey
+  let settlementAccount: PublicKey
+  let stakeAccountFrom: PublicKey
+  let stakeAccountTo: PublicKey
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+    ;({ configAccount, operatorAuthority } = await executeInitConfigInstruction(
+      {
+        program,
+        provider,
+        epochsToClaimSettlement: 4,
+      },
+    ))
+    let validatorIdentity: Keypair | undefined
+    ;({ voteAccount, validatorIdentity } = await createVoteAccount({
+      provider,
+    }))
+    await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+    })
+  })
+
+  beforeEach(async () => {
+    // a new settlement for every test
+    await warpToNextEpoch(provider)
+    ;({ settlementAccount } = await executeInitSettlement({
+      configAccount,
+      program,
+      provider,
+      voteAccount,
+      operatorAuthority,
+      currentEpoch: await currentEpoch(provider),
+      merkleRoot,
+      maxMerkleNodes: items.length,
+      maxTotalClaim,
+    }))
+    stakeAccountFrom = await createBondsFundedStakeAccount({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      lamports: maxTotalClaim + LAMPORTS_PER_SOL * 5,
+    })
+    await warpToNextEpoch(provider) // activate stake account
+    const { instruction, splitStakeAccount } = await fundSettlementInstruction(
+      {
+        program,
+        settlementAccount,
+        stakeAccount: stakeAccountFrom,
+      },
+    )
+    await provider.sendIx(
+      [signer(splitStakeAccount), operatorAuthority],
+      instruction,
+    )
+    stakeAccountTo = await createDelegatedStakeAccount({
+      provider,
+      lamports: 3 * LAMPORTS_PER_SOL,
+      voteAccount,
+      staker: staker1,
+      withdrawer: withdrawer1,
+    })
+    await warpToNextEpoch(provider) // deactivate stake account
+  })
+
+  it('claim settlement batch', async () => {
+    const [item0, item1, item2] = items as [
+      MerkleTreeNodeWithProof,
+      MerkleTreeNodeWithProof,
+      MerkleTreeNodeWithProof,
+    ]
+    const lamportsToBefore = (
+      await provider.connection.getAccountInfo(stakeAccountTo)
+    )?.lamports as number
+    const { instruction } = await claimSettlementBatchInstruction({
+      program,
+      claims: [toClaim(item0), toClaim(item2)],
+      settlementAccount,
+      stakeAccountFrom,
+      stakeAccountTo,
+    })
+    await provider.sendIx([], instruction)
+
+    const batchClaim = item0.treeNode.data.claim.add(item2.treeNode.data.claim)
+    expect(
+      (await provider.connection.getAccountInfo(stakeAccountTo))?.lamports,
+    ).toEqual(lamportsToBefore + batchClaim.toNumber())
+    expect(await isClaimed(program, settlementAccount, 0)).toBe(true)
+    expect(await isClaimed(program, settlementAccount, 1)).toBe(false)
+    expect(await isClaimed(program, settlementAccount, 2)).toBe(true)
+    let settlementData = await getSettlement(program, settlementAccount)
+    expect(settlementData.lamportsClaimed).toEqual(batchClaim.toNumber())
+    expect(settlementData.merkleNodesClaimed).toEqual(2)
+
+    const { instruction: reClaimIx } = await claimSettlementBatchInstruction({
+      program,
+      claims: [toClaim(item1), toClaim(item2)],
+      settlementAccount,
+      stakeAccountFrom,
+      stakeAccountTo,
+    })
+    try {
+      await provider.sendIx([], reClaimIx)
+      throw new Error('failure expected; already claimed')
+    } catch (e) {
+      verifyError(e, Errors, 6070, 'already claimed')
+    }
+
+    const { instruction: singleIx } = await claimSettlementBatchInstruction({
+      program,
+      claims: [toClaim(item1)],
+      settlementAccount,
+      stakeAccountFrom,
+      stakeAccountTo,
+    })
+    await provider.sendIx([], singleIx)
+    expect(await isClaimed(program, settlementAccount, 1)).toBe(true)
+    settlementData = await getSettlement(program, settlementAccount)
+    expect(settlementData.lamportsClaimed).toEqual(
+      batchClaim.add(item1.treeNode.data.claim).toNumber(),
+    )
+    expect(settlementData.merkleNodesClaimed).toEqual(3)
+  })
+
+  it('cannot claim settlement batch with wrong claims', async () => {
+    const [item0, item1, , item3] = items as [
+      MerkleTreeNodeWithProof,
+      MerkleTreeNodeWithProof,
+      MerkleTreeNodeWithProof,
+      MerkleTreeNodeWithProof,
+    ]
+
+    // tree node of other authorities cannot be claimed to the stake account
+    const { instruction: otherAuthoritiesIx } =
+      await claimSettlementBatchInstruction({
+        program,
+        claims: [toClaim(item0), toClaim(item3)],
+        settlementAccount,
+        stakeAccountFrom,
+        stakeAccountTo,
+      })
+    try {
+      await provider.sendIx([], otherAuthoritiesIx)
+      throw new Error('failure expected; tree node of other authorities')
+    } catch (e) {
+      verifyError(e, Errors, 6029, 'claim proof failed')
+    }
+
+    const { instruction: wrongAmountIx } =
+      await claimSettlementBatchInstruction({
+        program,
+        claims: [
+          toClaim(item0),
+          { ...toClaim(item1), claimAmount: item1.treeNode.data.claim.addn(1) },
+        ],
+        settlementAccount,
+        stakeAccountFrom,
+        stakeAccountTo,
+      })
+    try {
+      await provider.sendIx([], wrongAmountIx)
+      throw new Error('failure expected; wrong claim amount')
+    } catch (e) {
+      verifyError(e, Errors, 6029, 'claim proof failed')
+    }
+
+    const { instruction: wrongStakeAccountIx } =
+      await claimSettlementBatchInstruction({
+        program,
+        claims: [toClaim(item3)],
+        settlementAccount,
+        stakeAccountFrom,
+        stakeAccountTo,
+        stakeAccountStaker: staker2,
+        stakeAccountWithdrawer: withdrawer2,
+      })
+    try {
+      await provider.sendIx([], wrongStakeAccountIx)
+      throw new Error('failure expected; stake account of other withdrawer')
+    } catch (e) {
+      verifyError(e, Errors, 6012, 'Wrong withdrawer authority')
+    }
+
+    const emptyIx = await program.methods
+      .claimSettlementBatch({
+        proof: [],
+        stakeAccountStaker: staker1,
+        stakeAccountWithdrawer: withdrawer1,
+        claims: [],
+      })
+      .accountsPartial({
+        config: configAccount,
+        bond: (await getSettlement(program, settlementAccount)).bond,
+        settlement: settlementAccount,
+        settlementClaims: settlementClaimsAddress(
+          settlementAccount,
+          program.programId,
+        )[0],
+        stakeAccountFrom,
+        stakeAccountTo,
+        bondsWithdrawerAuthority: bondsWithdrawerAuthority(
+          configAccount,
+          program.programId,
+        )[0],
+        stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        stakeProgram: StakeProgram.programId,
+      })
+      .instruction()
+    try {
+      await provider.sendIx([], emptyIx)
+      throw new Error('failure expected; empty batch')
+    } catch (e) {
+      verifyError(e, Errors, 6099, 'Batch of settlement claims is empty')
+    }
+
+    const settlementData = await getSettlement(program, settlementAccount)
+    expect(settlementData.lamportsClaimed).toEqual(0)
+    expect(settlementData.merkleNodesClaimed).toEqual(0)
+  })
+})
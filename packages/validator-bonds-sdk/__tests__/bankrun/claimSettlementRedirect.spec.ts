@@ -0,0 +1,252 @@
+import { verifyError } from '@marinade.finance/anchor-common'
+import { currentEpoch, warpToNextEpoch } from '@marinade.finance/bankrun-utils'
+import { createUserAndFund, signer } from '@marinade.finance/web3js-1x'
+import { Keypair, LAMPORTS_PER_SOL } from '@solana/web3.js'
+
+import { initBankrunTest } from './bankrun'
+import {
+  Errors,
+  claimSettlementRedirectInstruction,
+  fundSettlementInstruction,
+  getSettlement,
+  isClaimed,
+} from '../../src'
+import {
+  MERKLE_ROOT_VOTE_ACCOUNT_1_BUF,
+  configAccountKeypair,
+  createWithdrawerUsers,
+  totalClaimVoteAccount1,
+  treeNodeBy,
+  treeNodesVoteAccount1,
+  voteAccount1Keypair,
+  withdrawer1,
+  withdrawer1Keypair,
+  withdrawer2,
+  withdrawer2Keypair,
+} from '../utils/merkleTreeTestData'
+import {
+  createBondsFundedStakeAccount,
+  createDelegatedStakeAccount,
+  createVoteAccount,
+} from '../utils/staking'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+  executeInitSettlement,
+} from '../utils/testTransactions'
+
+import type { ValidatorBondsProgram } from '../../src'
+import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+import type { PublicKey } from '@solana/web3.js'
+
+describe('Validator Bonds claim settlement redirect', () => {
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let operatorAuthority: Keypair
+  let voteAccount1: PublicKey
+  let settlementAccount: PublicKey
+  let stakeAccountFrom: PublicKey
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+    ;({ configAccount, operatorAuthority } = await executeInitConfigInstruction(
+      {
+        program,
+        provider,
+        epochsToClaimSettlement: 4,
+        configAccountKeypair,
+      },
+    ))
+    let validatorIdentity: Keypair | undefined
+    ;({ voteAccount: voteAccount1, validatorIdentity } =
+      await createVoteAccount({
+        voteAccount: voteAccount1Keypair,
+        provider,
+      }))
+    await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount: voteAccount1,
+      validatorIdentity,
+    })
+    await createWithdrawerUsers(provider)
+  })
+
+  beforeEach(async () => {
+    // a new settlement for every test
+    await warpToNextEpoch(provider)
+    ;({ settlementAccount } = await executeInitSettlement({
+      configAccount,
+      program,
+      provider,
+      voteAccount: voteAccount1,
+      operatorAuthority,
+      currentEpoch: await currentEpoch(provider),
+      merkleRoot: MERKLE_ROOT_VOTE_ACCOUNT_1_BUF,
+      maxMerkleNodes: treeNodesVoteAccount1.length,
+      maxTotalClaim: totalClaimVoteAccount1,
+    }))
+    stakeAccountFrom = await createBondsFundedStakeAccount({
+      program,
+      provider,
+      configAccount,
+      voteAccount: voteAccount1,
+      lamports: totalClaimVoteAccount1.toNumber() + LAMPORTS_PER_SOL * 5,
+    })
+    await warpToNextEpoch(provider) // activate stake account
+    const { instruction, splitStakeAccount } = await fundSettlementInstruction(
+      {
+        program,
+        settlementAccount,
+        stakeAccount: stakeAccountFrom,
+      },
+    )
+    await provider.sendIx(
+      [signer(splitStakeAccount), operatorAuthority],
+      instruction,
+    )
+    await warpToNextEpoch(provider) // deactivate stake account
+  })
+
+  it('claim settlement to system account', async () => {
+    const treeNode = treeNodeBy(voteAccount1, withdrawer1)
+    const claimTo = await createUserAndFund({
+      provider,
+      lamports: LAMPORTS_PER_SOL,
+    })
+    const { instruction } = await claimSettlementRedirectInstruction({
+      program,
+      claimAmount: treeNode.treeNode.data.claim,
+      index: treeNode.treeNode.data.index,
+      merkleProof: treeNode.proof,
+      settlementAccount,
+      stakeAccountFrom,
+      claimTo: claimTo.publicKey,
+      stakeAccountStaker: treeNode.treeNode.stakeAuthority,
+      withdrawAuthority: withdrawer1Keypair,
+    })
+    await provider.sendIx([withdrawer1Keypair], instruction)
+
+    expect(
+      (await provider.connection.getAccountInfo(claimTo.publicKey))?.lamports,
+    ).toEqual(LAMPORTS_PER_SOL + treeNode.treeNode.data.claim.toNumber())
+    expect(
+      await isClaimed(program, settlementAccount, treeNode.treeNode.data.index),
+    ).toBe(true)
+    const settlementData = await getSettlement(program, settlementAccount)
+    expect(settlementData.lamportsClaimed).toEqual(
+      treeNode.treeNode.data.claim.toNumber(),
+    )
+    expect(settlementData.merkleNodesClaimed).toEqual(1)
+
+    await warpToNextEpoch(provider)
+    try {
+      await provider.sendIx([withdrawer1Keypair], instruction)
+      throw new Error('failure expected; already claimed')
+    } catch (e) {
+      verifyError(e, Errors, 6070, 'already claimed')
+    }
+  })
+
+  it('claim settlement to stake account delegated elsewhere', async () => {
+    const treeNode = treeNodeBy(voteAccount1, withdrawer2)
+    const { voteAccount: otherVoteAccount } = await createVoteAccount({
+      provider,
+    })
+    const lamportsTo = 3 * LAMPORTS_PER_SOL
+    const claimTo = await createDelegatedStakeAccount({
+      provider,
+      lamports: lamportsTo,
+      voteAccount: otherVoteAccount,
+      staker: Keypair.generate().publicKey,
+      withdrawer: withdrawer2,
+    })
+    const { instruction } = await claimSettlementRedirectInstruction({
+      program,
+      claimAmount: treeNode.treeNode.data.claim,
+      index: treeNode.treeNode.data.index,
+      merkleProof: treeNode.proof,
+      settlementAccount,
+      stakeAccountFrom,
+      claimTo,
+      stakeAccountStaker: treeNode.treeNode.stakeAuthority,
+      withdrawAuthority: withdrawer2Keypair,
+    })
+    await provider.sendIx([withdrawer2Keypair], instruction)
+
+    expect(
+      (await provider.connection.getAccountInfo(claimTo))?.lamports,
+    ).toEqual(lamportsTo + treeNode.treeNode.data.claim.toNumber())
+    expect(
+      await isClaimed(program, settlementAccount, treeNode.treeNode.data.index),
+    ).toBe(true)
+  })
+
+  it('cannot claim settlement to account not controlled by withdraw authority', async () => {
+    const treeNode = treeNodeBy(voteAccount1, withdrawer1)
+    const claimParams = {
+      program,
+      claimAmount: treeNode.treeNode.data.claim,
+      index: treeNode.treeNode.data.index,
+      merkleProof: treeNode.proof,
+      settlementAccount,
+      stakeAccountFrom,
+      stakeAccountStaker: treeNode.treeNode.stakeAuthority,
+      withdrawAuthority: withdrawer1Keypair,
+    }
+
+    const foreignStakeAccount = await createDelegatedStakeAccount({
+      provider,
+      lamports: 3 * LAMPORTS_PER_SOL,
+      voteAccount: voteAccount1,
+      staker: withdrawer1,
+      withdrawer: withdrawer2,
+    })
+    const { instruction: foreignStakeIx } =
+      await claimSettlementRedirectInstruction({
+        ...claimParams,
+        claimTo: foreignStakeAccount,
+      })
+    try {
+      await provider.sendIx([withdrawer1Keypair], foreignStakeIx)
+      throw new Error('failure expected; stake account of other withdrawer')
+    } catch (e) {
+      verifyError(e, Errors, 6012, 'Wrong withdrawer authority')
+    }
+
+    const { instruction: voteAccountIx } =
+      await claimSettlementRedirectInstruction({
+        ...claimParams,
+        claimTo: voteAccount1,
+      })
+    try {
+      await provider.sendIx([withdrawer1Keypair], voteAccountIx)
+      throw new Error('failure expected; claim to not a system account')
+    } catch (e) {
+      verifyError(e, Errors, 6098, 'system account or a stake account')
+    }
+
+    // the withdraw authority of another tree node cannot claim
+    const claimTo = await createUserAndFund({
+      provider,
+      lamports: LAMPORTS_PER_SOL,
+    })
+    const { instruction: wrongSignerIx } =
+      await claimSettlementRedirectInstruction({
+        ...claimParams,
+        claimTo: claimTo.publicKey,
+        withdrawAuthority: withdrawer2Keypair,
+      })
+    try {
+      await provider.sendIx([withdrawer2Keypair], wrongSignerIx)
+      throw new Error('failure expected; wrong withdraw authority')
+    } catch (e) {
+      verifyError(e, Errors, 6029, 'claim proof failed')
+    }
+    expect(
+      await isClaimed(program, settlementAccount, treeNode.treeNode.data.index),
+    ).toBe(false)
+  })
+})
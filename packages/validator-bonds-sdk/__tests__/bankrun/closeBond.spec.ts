@@ -0,0 +1,420 @@
+import assert from 'assert'
+
+import { verifyError } from '@marinade.finance/anchor-common'
+import {
+  assertNotExist,
+  currentEpoch,
+  warpToNextEpoch,
+} from '@marinade.finance/bankrun-utils'
+import { createUserAndFund } from '@marinade.finance/web3js-1x'
+import { Keypair, LAMPORTS_PER_SOL } from '@solana/web3.js'
+
+import { initBankrunTest } from './bankrun'
+import {
+  Errors,
+  cancelSettlementInstruction,
+  cancelWithdrawRequestInstruction,
+  closeBondInstruction,
+  closeBondLedgerInstruction,
+  countBondAccountsInstruction,
+  fundBondTokenInstruction,
+  getBond,
+  getSettlement,
+  getWithdrawRequest,
+  initBondLedgerInstruction,
+} from '../../src'
+import { getRentExempt } from '../utils/helpers'
+import {
+  createBondsFundedStakeAccount,
+  createVoteAccount,
+} from '../utils/staking'
+import {
+  executeInitBondInstruction,
+  executeInitCollateralMintInstruction,
+  executeInitCommissionProductInstruction,
+  executeInitConfigInstruction,
+  executeInitSettlement,
+  executeInitWithdrawRequestInstruction,
+} from '../utils/testTransactions'
+import { mintTo } from '../utils/tokens'
+
+import type { ValidatorBondsProgram } from '../../src'
+import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+import type { PublicKey } from '@solana/web3.js'
+
+describe('Validator Bonds close bond', () => {
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let adminAuthority: Keypair
+  let operatorAuthority: Keypair
+  let bondAccount: PublicKey
+  let bondAuthority: Keypair
+  let voteAccount: PublicKey
+  let validatorIdentity: Keypair
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+  })
+
+  beforeEach(async () => {
+    ;({ configAccount, adminAuthority, operatorAuthority } =
+      await executeInitConfigInstruction({
+        program,
+        provider,
+        epochsToClaimSettlement: 1_000,
+      }))
+    ;({ voteAccount, validatorIdentity } = await createVoteAccount({
+      provider,
+    }))
+    ;({ bondAccount, bondAuthority } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+    }))
+  })
+
+  async function expectCloseBondError(
+    code: number,
+    message: string,
+    stakeAccounts: PublicKey[] = [],
+  ) {
+    const { instruction } = await closeBondInstruction({
+      program,
+      bondAccount,
+      authority: bondAuthority,
+      stakeAccounts,
+    })
+    try {
+      await provider.sendIx([bondAuthority], instruction)
+      throw new Error('failure expected; bond cannot be closed')
+    } catch (e) {
+      verifyError(e, Errors, code, message)
+    }
+    expect(await provider.connection.getAccountInfo(bondAccount)).not.toBeNull()
+  }
+
+  async function overwriteAccount(
+    address: PublicKey,
+    accountName: 'bond' | 'settlement',
+    accountData: object,
+  ) {
+    const accountInfo = await provider.connection.getAccountInfo(address)
+    assert(accountInfo !== null)
+    const encoded = await program.coder.accounts.encode(
+      accountName,
+      accountData,
+    )
+    const data = Buffer.from(accountInfo.data)
+    encoded.copy(data)
+    provider.context.setAccount(address, { ...accountInfo, data })
+  }
+
+  async function closeBond() {
+    await warpToNextEpoch(provider)
+    const { instruction } = await closeBondInstruction({
+      program,
+      bondAccount,
+      authority: validatorIdentity,
+      stakeAccounts: [],
+    })
+    await provider.sendIx([validatorIdentity], instruction)
+    await assertNotExist(provider, bondAccount)
+  }
+
+  it('close bond and reclaim rent', async () => {
+    const rentCollector = Keypair.generate()
+    await createUserAndFund({
+      provider,
+      lamports: LAMPORTS_PER_SOL,
+      user: rentCollector,
+    })
+    const rentExemptBond = await getRentExempt(provider, bondAccount)
+
+    const wrongAuthority = Keypair.generate()
+    const { instruction: wrongIx } = await closeBondInstruction({
+      program,
+      bondAccount,
+      authority: wrongAuthority,
+      stakeAccounts: [],
+    })
+    try {
+      await provider.sendIx([wrongAuthority], wrongIx)
+      throw new Error('failure expected; wrong authority')
+    } catch (e) {
+      verifyError(
+        e,
+        Errors,
+        6018,
+        'Wrong authority for changing the validator bond',
+      )
+    }
+
+    const { instruction } = await closeBondInstruction({
+      program,
+      bondAccount,
+      authority: bondAuthority,
+      stakeAccounts: [],
+      rentCollector: rentCollector.publicKey,
+    })
+    await provider.sendIx([bondAuthority], instruction)
+    await assertNotExist(provider, bondAccount)
+    const rentCollectorInfo = await provider.connection.getAccountInfo(
+      rentCollector.publicKey,
+    )
+    assert(rentCollectorInfo !== null)
+    expect(rentCollectorInfo.lamports).toEqual(
+      LAMPORTS_PER_SOL + rentExemptBond,
+    )
+  })
+
+  it('cannot close bond with delegated stake account', async () => {
+    const stakeAccount = await createBondsFundedStakeAccount({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      lamports: 2 * LAMPORTS_PER_SOL,
+    })
+    await expectCloseBondError(6112, 'stake account is delegated', [
+      stakeAccount,
+    ])
+
+    // stake delegated to another vote account does not prevent closing
+    const { voteAccount: otherVoteAccount } = await createVoteAccount({
+      provider,
+    })
+    const otherStakeAccount = await createBondsFundedStakeAccount({
+      program,
+      provider,
+      configAccount,
+      voteAccount: otherVoteAccount,
+      lamports: 2 * LAMPORTS_PER_SOL,
+    })
+    const { instruction } = await closeBondInstruction({
+      program,
+      bondAccount,
+      authority: bondAuthority,
+      stakeAccounts: [otherStakeAccount],
+    })
+    await provider.sendIx([bondAuthority], instruction)
+    await assertNotExist(provider, bondAccount)
+  })
+
+  it('cannot close bond with outstanding withdraw request', async () => {
+    const { withdrawRequestAccount } =
+      await executeInitWithdrawRequestInstruction({
+        program,
+        provider,
+        bondAccount,
+        validatorIdentity,
+      })
+    expect((await getBond(program, bondAccount)).openWithdrawRequests).toEqual(
+      1,
+    )
+    expect(
+      (await getWithdrawRequest(program, withdrawRequestAccount)).counted,
+    ).toBe(true)
+    await expectCloseBondError(6096, 'withdraw request is outstanding')
+
+    const { instruction } = await cancelWithdrawRequestInstruction({
+      program,
+      withdrawRequestAccount,
+      authority: bondAuthority,
+    })
+    await provider.sendIx([bondAuthority], instruction)
+    expect((await getBond(program, bondAccount)).openWithdrawRequests).toEqual(
+      0,
+    )
+    await closeBond()
+  })
+
+  it('cancel of not counted withdraw request keeps the counter', async () => {
+    const { withdrawRequestAccount } =
+      await executeInitWithdrawRequestInstruction({
+        program,
+        provider,
+        bondAccount,
+        validatorIdentity,
+      })
+    // withdraw request created before the counter was introduced is not counted
+    const accountInfo = await provider.connection.getAccountInfo(
+      withdrawRequestAccount,
+    )
+    assert(accountInfo !== null)
+    const encoded = await program.coder.accounts.encode('withdrawRequest', {
+      ...(await getWithdrawRequest(program, withdrawRequestAccount)),
+      counted: false,
+    })
+    const data = Buffer.from(accountInfo.data)
+    encoded.copy(data)
+    provider.context.setAccount(withdrawRequestAccount, {
+      ...accountInfo,
+      data,
+    })
+
+    const { instruction } = await cancelWithdrawRequestInstruction({
+      program,
+      withdrawRequestAccount,
+      authority: bondAuthority,
+    })
+    await provider.sendIx([bondAuthority], instruction)
+    await assertNotExist(provider, withdrawRequestAccount)
+    expect((await getBond(program, bondAccount)).openWithdrawRequests).toEqual(
+      1,
+    )
+  })
+
+  it('cannot close bond with open settlement', async () => {
+    const { settlementAccount, rentCollector } = await executeInitSettlement({
+      configAccount,
+      program,
+      provider,
+      voteAccount,
+      operatorAuthority,
+      currentEpoch: await currentEpoch(provider),
+    })
+    expect((await getBond(program, bondAccount)).openSettlements).toEqual(1)
+    await expectCloseBondError(6095, 'settlements are open')
+
+    const { instruction } = await cancelSettlementInstruction({
+      program,
+      settlementAccount,
+      rentCollector,
+      authority: operatorAuthority,
+    })
+    await provider.sendIx([operatorAuthority], instruction)
+    expect((await getBond(program, bondAccount)).openSettlements).toEqual(0)
+    await closeBond()
+  })
+
+  it('cannot close legacy bond before its accounts are counted', async () => {
+    const { settlementAccount, rentCollector } = await executeInitSettlement({
+      configAccount,
+      program,
+      provider,
+      voteAccount,
+      operatorAuthority,
+      currentEpoch: await currentEpoch(provider),
+    })
+    // bond and settlement created before the counters were introduced
+    await overwriteAccount(bondAccount, 'bond', {
+      ...(await getBond(program, bondAccount)),
+      openSettlements: 0,
+      accountsCounted: false,
+    })
+    await overwriteAccount(settlementAccount, 'settlement', {
+      ...(await getSettlement(program, settlementAccount)),
+      counted: false,
+    })
+    await expectCloseBondError(6117, 'accounts are counted')
+
+    const { instruction: wrongAuthorityIx } =
+      await countBondAccountsInstruction({
+        program,
+        bondAccount,
+        operatorAuthority: bondAuthority,
+      })
+    try {
+      await provider.sendIx([bondAuthority], wrongAuthorityIx)
+      throw new Error('failure expected; not operator authority')
+    } catch (e) {
+      verifyError(e, Errors, 6003, 'operator authority')
+    }
+
+    const { instruction: countIx } = await countBondAccountsInstruction({
+      program,
+      bondAccount,
+    })
+    await provider.sendIx([operatorAuthority], countIx)
+    const bondData = await getBond(program, bondAccount)
+    expect(bondData.accountsCounted).toBe(true)
+    expect(bondData.openSettlements).toEqual(1)
+    expect((await getSettlement(program, settlementAccount)).counted).toBe(true)
+    await expectCloseBondError(6095, 'settlements are open')
+
+    const { instruction } = await cancelSettlementInstruction({
+      program,
+      settlementAccount,
+      rentCollector,
+      authority: operatorAuthority,
+    })
+    await provider.sendIx([operatorAuthority], instruction)
+    expect((await getBond(program, bondAccount)).openSettlements).toEqual(0)
+    await closeBond()
+  })
+
+  it('cannot close bond with bond product', async () => {
+    const { bondProduct } = await executeInitCommissionProductInstruction({
+      program,
+      provider,
+      bondAccount,
+      configAccount,
+      voteAccount,
+      authority: bondAuthority,
+    })
+    expect((await getBond(program, bondAccount)).openBondProducts).toEqual(1)
+    await expectCloseBondError(6113, 'bond products exist')
+
+    const instruction = await program.methods
+      .closeBondProduct()
+      .accountsPartial({
+        config: configAccount,
+        bond: bondAccount,
+        voteAccount,
+        bondProduct,
+        authority: bondAuthority.publicKey,
+        rentCollector: bondAuthority.publicKey,
+      })
+      .instruction()
+    await provider.sendIx([bondAuthority], instruction)
+    await assertNotExist(provider, bondProduct)
+    expect((await getBond(program, bondAccount)).openBondProducts).toEqual(0)
+    await closeBond()
+  })
+
+  it('cannot close bond with bond ledger', async () => {
+    const { instruction: initLedgerIx } = await initBondLedgerInstruction({
+      program,
+      bondAccount,
+    })
+    await provider.sendIx([], initLedgerIx)
+    await expectCloseBondError(6115, 'bond ledger exists')
+
+    const { instruction } = await closeBondLedgerInstruction({
+      program,
+      bondAccount,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], instruction)
+    await closeBond()
+  })
+
+  it('cannot close bond with token collateral', async () => {
+    const { mint } = await executeInitCollateralMintInstruction({
+      program,
+      provider,
+      configAccount,
+      adminAuthority,
+    })
+    const depositor = Keypair.generate()
+    await mintTo({
+      provider,
+      mint,
+      owner: depositor.publicKey,
+      amount: 10 * LAMPORTS_PER_SOL,
+    })
+    const { instruction } = await fundBondTokenInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: 10 * LAMPORTS_PER_SOL,
+      depositorAuthority: depositor,
+    })
+    await provider.sendIx([depositor], instruction)
+    expect((await getBond(program, bondAccount)).fundedTokenVaults).toEqual(1)
+    await expectCloseBondError(6114, 'vault holds collateral')
+  })
+})
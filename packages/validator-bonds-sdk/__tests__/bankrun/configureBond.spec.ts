@@ -4,6 +4,7 @@ import { Keypair, PublicKey } from '@solana/web3.js'
 import { initBankrunTest } from './bankrun'
 import {
   Errors,
+  acceptBondAuthorityInstruction,
   configureBondInstruction,
   getBond,
   getConfig,
@@ -83,10 +84,21 @@ describe('Validator Bonds configure bond account', () => {
 
     let bondData = await getBond(program, bond.publicKey)
     expect(bondData.config).toEqual(configAccount)
-    expect(bondData.authority).toEqual(newBondAuthority.publicKey)
+    expect(bondData.authority).toEqual(bondAuthority.publicKey)
+    expect(bondData.pendingAuthority).toEqual(newBondAuthority.publicKey)
     expect(bondData.cpmpe).toEqual(321)
     expect(bondData.maxStakeWanted).toEqual(10123)
 
+    const { instruction: acceptIx1 } = await acceptBondAuthorityInstruction({
+      program,
+      bondAccount: bond.publicKey,
+      newAuthority: newBondAuthority,
+    })
+    await provider.sendIx([newBondAuthority], acceptIx1)
+    bondData = await getBond(program, bond.publicKey)
+    expect(bondData.authority).toEqual(newBondAuthority.publicKey)
+    expect(bondData.pendingAuthority).toEqual(PublicKey.default)
+
     const { instruction: ix2 } = await configureBondInstruction({
       program,
       bondAccount: bond.publicKey,
@@ -94,6 +106,12 @@ describe('Validator Bonds configure bond account', () => {
       newBondAuthority: bondAuthority.publicKey,
     })
     await provider.sendIx([newBondAuthority], ix2)
+    const { instruction: acceptIx2 } = await acceptBondAuthorityInstruction({
+      program,
+      bondAccount: bond.publicKey,
+      newAuthority: bondAuthority,
+    })
+    await provider.sendIx([bondAuthority], acceptIx2)
 
     bondData = await getBond(program, bond.publicKey)
     expect(bondData.authority).toEqual(bondAuthority.publicKey)
@@ -108,6 +126,12 @@ describe('Validator Bonds configure bond account', () => {
       newBondAuthority: newBondAuthority.publicKey,
     })
     await provider.sendIx([validatorIdentity], instruction)
+    const { instruction: acceptIx } = await acceptBondAuthorityInstruction({
+      program,
+      bondAccount: bond.publicKey,
+      newAuthority: newBondAuthority,
+    })
+    await provider.sendIx([newBondAuthority], acceptIx)
 
     const bondData = await getBond(program, bond.publicKey)
     expect(bondData.config).toEqual(configAccount)
@@ -167,7 +191,7 @@ describe('Validator Bonds configure bond account', () => {
       program,
       bondAccount: permissionLessBondAccount,
       authority: randomAuthority.publicKey,
-      newBondAuthority: PublicKey.default,
+      newBondAuthority: randomAuthority.publicKey,
     })
     try {
       await provider.sendIx([randomAuthority], ixWrongAuth)
@@ -182,11 +206,17 @@ describe('Validator Bonds configure bond account', () => {
       program,
       bondAccount: permissionLessBondAccount,
       authority: validatorIdentity.publicKey,
-      newBondAuthority: PublicKey.default,
+      newBondAuthority: randomAuthority.publicKey,
     })
     await provider.sendIx([validatorIdentity], instruction)
+    const { instruction: acceptIx } = await acceptBondAuthorityInstruction({
+      program,
+      bondAccount: permissionLessBondAccount,
+      newAuthority: randomAuthority,
+    })
+    await provider.sendIx([randomAuthority], acceptIx)
     bondsData = await getBond(program, permissionLessBondAccount)
-    expect(bondsData.authority).toEqual(PublicKey.default)
+    expect(bondsData.authority).toEqual(randomAuthority.publicKey)
   })
 
   it('configures bond max stake wanted', async () => {
@@ -5,7 +5,7 @@ import {
   getVoteAccount,
   signer,
 } from '@marinade.finance/web3js-1x'
-import { Keypair, PublicKey, VoteProgram } from '@solana/web3.js'
+import { Keypair, VoteProgram } from '@solana/web3.js'
 import {
   createAssociatedTokenAccountInstruction,
   createTransferInstruction,
@@ -17,6 +17,7 @@ import {
 import { initBankrunTest } from './bankrun'
 import {
   Errors,
+  acceptBondAuthorityInstruction,
   configureBondWithMintInstruction,
   getBond,
   mintBondInstruction,
@@ -30,6 +31,7 @@ import {
 
 import type { ValidatorBondsProgram } from '../../src'
 import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+import type { PublicKey } from '@solana/web3.js'
 
 describe('Validator Bonds mint configure bond account', () => {
   let provider: BankrunExtendedProvider
@@ -137,6 +139,15 @@ describe('Validator Bonds mint configure bond account', () => {
     )
     await provider.sendIx([user], ixConfigure)
 
+    bondData = await getBond(program, bondAccount)
+    expect(bondData.authority).toEqual(bondAuthority.publicKey)
+    expect(bondData.pendingAuthority).toEqual(user.publicKey)
+    const { instruction: ixAccept } = await acceptBondAuthorityInstruction({
+      program,
+      bondAccount,
+      newAuthority: user,
+    })
+    await provider.sendIx([user], ixAccept)
     bondData = await getBond(program, bondAccount)
     expect(bondData.authority).toEqual(user.publicKey)
 
@@ -144,16 +155,22 @@ describe('Validator Bonds mint configure bond account', () => {
     await provider.sendIx([], ixMint)
     const { instruction: ixConfigure2 } =
       await configureBondWithMintInstruction({
-        newBondAuthority: PublicKey.default,
+        newBondAuthority: validatorIdentity.publicKey,
         program,
         bondAccount,
         configAccount,
         tokenAuthority: validatorIdentity,
       })
     await provider.sendIx([validatorIdentity], ixConfigure2)
+    const { instruction: ixAccept2 } = await acceptBondAuthorityInstruction({
+      program,
+      bondAccount,
+      newAuthority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], ixAccept2)
 
     bondData = await getBond(program, bondAccount)
-    expect(bondData.authority).toEqual(PublicKey.default)
+    expect(bondData.authority).toEqual(validatorIdentity.publicKey)
   })
 
   it('fail to bond mint with changed validator identity', async () => {
@@ -1,5 +1,9 @@
+import assert from 'assert'
+
 import { verifyError } from '@marinade.finance/anchor-common'
-import { Keypair } from '@solana/web3.js'
+import { currentEpoch } from '@marinade.finance/bankrun-utils'
+import { createUserAndFund } from '@marinade.finance/web3js-1x'
+import { Keypair, LAMPORTS_PER_SOL } from '@solana/web3.js'
 
 import { initBankrunTest } from './bankrun'
 import {
@@ -357,22 +361,66 @@ describe('Validator Bonds configure bond product account', () => {
     expect(parsedData.length).toEqual(0)
   })
 
-  it('cannot enlarge custom data now', async () => {
+  it('enlarge custom data with account reallocation', async () => {
+    await createUserAndFund({
+      provider,
+      lamports: LAMPORTS_PER_SOL,
+      user: bondAuthority,
+    })
+    const sizeBefore = (await getAccountData(customProduct.publicKey)).length
     const largeData = Buffer.alloc(512, 0xcd)
-    // Contract currently does not support resizing the bond product account.
-    // AnchorError caused by account: bond_product. Error Code: AccountDidNotSerialize.
-    //   Error Number: 3004. Error Message: Failed to serialize the account.
-    await expect(
-      executeConfigureCustomProductInstruction({
-        program,
-        provider,
-        bondProductAccount: customProduct.publicKey,
-        authority: bondAuthority,
-        customProductData: largeData,
-      }),
-    ).rejects.toThrow(/custom program error: 0xbbc/)
+    await executeConfigureCustomProductInstruction({
+      program,
+      provider,
+      bondProductAccount: customProduct.publicKey,
+      authority: bondAuthority,
+      customProductData: largeData,
+    })
+    const bondProductData = await getBondProduct(
+      program,
+      customProduct.publicKey,
+    )
+    expect(parseCustomData(bondProductData.configData)).toEqual(largeData)
+    expect(
+      (await getAccountData(customProduct.publicKey)).length,
+    ).toBeGreaterThan(sizeBefore)
   })
 
+  it('schedule larger pending custom data with account reallocation', async () => {
+    await createUserAndFund({
+      provider,
+      lamports: LAMPORTS_PER_SOL,
+      user: bondAuthority,
+    })
+    const largeData = Buffer.alloc(256, 0xab)
+    const effectiveFromEpoch = Number(await currentEpoch(provider)) + 2
+    const { instruction } = await configureCustomProductInstruction({
+      program,
+      bondProductAccount: customProduct.publicKey,
+      authority: bondAuthority,
+      customProductData: largeData,
+      effectiveFromEpoch,
+    })
+    await provider.sendIx([bondAuthority], instruction)
+    const bondProductData = await getBondProduct(
+      program,
+      customProduct.publicKey,
+    )
+    expect(parseCustomData(bondProductData.configData)).toEqual(
+      Buffer.from('initial data'),
+    )
+    const pendingConfig = bondProductData.pendingConfig
+    expect(pendingConfig?.effectiveFromEpoch).toEqual(effectiveFromEpoch)
+    assert(pendingConfig !== null)
+    expect(parseCustomData(pendingConfig.configData)).toEqual(largeData)
+  })
+
+  async function getAccountData(address: PublicKey): Promise<Buffer> {
+    const accountInfo = await provider.connection.getAccountInfo(address)
+    assert(accountInfo !== null)
+    return accountInfo.data
+  }
+
   it('configure custom product multiple times', async () => {
     for (let i = 1; i <= 3; i++) {
       const data = Buffer.from(`version ${i}`)
@@ -6,11 +6,12 @@ import {
   bankrunExecuteIx,
   bankrunTransaction,
 } from '@marinade.finance/bankrun-utils'
-import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js'
+import { Keypair, LAMPORTS_PER_SOL } from '@solana/web3.js'
 
 import { initBankrunTest } from './bankrun'
 import { getConfig, configureConfigInstruction, Errors } from '../../src'
 import {
+  executeChangeConfigAuthority,
   executeConfigureConfigInstruction,
   executeInitConfigInstruction,
 } from '../utils/testTransactions'
@@ -18,7 +19,7 @@ import {
 import type { Config, ValidatorBondsProgram } from '../../src'
 import type { ProgramAccount } from '@coral-xyz/anchor'
 import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
-import type { Transaction } from '@solana/web3.js'
+import type { PublicKey, Transaction } from '@solana/web3.js'
 
 describe('Validator Bonds configure config tests', () => {
   let provider: BankrunExtendedProvider
@@ -58,14 +59,18 @@ describe('Validator Bonds configure config tests', () => {
 
   it('configure config', async () => {
     const newAdminAuthority = Keypair.generate()
-    await executeConfigureConfigInstruction({
+    await executeChangeConfigAuthority({
       program,
       provider,
       configAccount: configInitialized.publicKey,
       adminAuthority,
-      newAdmin: newAdminAuthority.publicKey,
-      newEpochsToClaimSettlement: 3,
-      newSlotsToStartSettlementClaiming: 10,
+      newAdmin: newAdminAuthority,
+    })
+    await executeConfigureConfigInstruction({
+      program,
+      provider,
+      configAccount: configInitialized.publicKey,
+      adminAuthority: newAdminAuthority,
       newMinBondMaxStakeWanted: LAMPORTS_PER_SOL * 10_000,
     })
     const config = await getConfig(program, configInitialized.publicKey)
@@ -73,22 +78,29 @@ describe('Validator Bonds configure config tests', () => {
     expect(config.operatorAuthority).toEqual(
       configInitialized.account.operatorAuthority,
     )
-    expect(config.epochsToClaimSettlement).toEqual(3)
-    expect(config.slotsToStartSettlementClaiming).toEqual(10)
+    expect(config.epochsToClaimSettlement).toEqual(
+      configInitialized.account.epochsToClaimSettlement,
+    )
     expect(config.withdrawLockupEpochs).toEqual(
       configInitialized.account.withdrawLockupEpochs,
     )
     expect(config.minBondMaxStakeWanted).toEqual(LAMPORTS_PER_SOL * 10_000)
 
-    const pauseAuthority = PublicKey.unique()
-    const { instruction: instruction2 } = await configureConfigInstruction({
+    const newOperatorAuthority = Keypair.generate()
+    const pauseAuthority = Keypair.generate()
+    await executeChangeConfigAuthority({
       program,
+      provider,
       configAccount: configInitialized.publicKey,
-      newEpochsToClaimSettlement: 3,
-      newWithdrawLockupEpochs: 4,
-      newOperator: PublicKey.default,
+      adminAuthority: newAdminAuthority,
+      newOperator: newOperatorAuthority,
       newPauseAuthority: pauseAuthority,
     })
+    const { instruction: instruction2 } = await configureConfigInstruction({
+      program,
+      configAccount: configInitialized.publicKey,
+      newMinBondMaxStakeWanted: LAMPORTS_PER_SOL * 20_000,
+    })
     await bankrunExecuteIx(
       provider,
       [provider.wallet, newAdminAuthority],
@@ -96,13 +108,13 @@ describe('Validator Bonds configure config tests', () => {
     )
     const config2 = await getConfig(program, configInitialized.publicKey)
     expect(config2.adminAuthority).toEqual(newAdminAuthority.publicKey)
-    expect(config2.operatorAuthority).toEqual(PublicKey.default)
-    expect(config2.pauseAuthority).toEqual(pauseAuthority)
-    expect(config2.paused).toBeFalsy()
-    expect(config2.epochsToClaimSettlement).toEqual(3)
-    expect(config2.slotsToStartSettlementClaiming).toEqual(10)
-    expect(config2.withdrawLockupEpochs).toEqual(4)
-    expect(config.minBondMaxStakeWanted).toEqual(LAMPORTS_PER_SOL * 10_000)
+    expect(config2.operatorAuthority).toEqual(newOperatorAuthority.publicKey)
+    expect(config2.pauseAuthority).toEqual(pauseAuthority.publicKey)
+    expect(config2.paused).toEqual(0)
+    expect(config2.withdrawLockupEpochs).toEqual(
+      configInitialized.account.withdrawLockupEpochs,
+    )
+    expect(config2.minBondMaxStakeWanted).toEqual(LAMPORTS_PER_SOL * 20_000)
   })
 
   it('configure config wrong keys', async () => {
@@ -138,7 +150,7 @@ describe('Validator Bonds configure config tests', () => {
       program,
       adminAuthority,
       configAccount: configInitialized.publicKey,
-      newWithdrawLockupEpochs: 42,
+      newMinBondMaxStakeWanted: 42,
     })
     tx.add(instruction)
     await provider.wallet.signTransaction(tx)
@@ -15,7 +15,6 @@ import {
   claimSettlementV2Instruction,
   closeSettlementV2Instruction,
   configureBondInstruction,
-  configureConfigInstruction,
   emergencyPauseInstruction,
   emergencyResumeInstruction,
   fundBondInstruction,
@@ -32,6 +31,14 @@ import {
   cancelSettlementInstruction,
   upsizeSettlementClaims,
   getSettlementClaims,
+  pausedOperations,
+  PAUSE_ALL,
+  PAUSE_CLAIM,
+  PAUSE_FUNDING,
+  PAUSE_LEGACY_ALL,
+  PAUSE_SETTLEMENT,
+  PAUSE_STAKE_MANAGEMENT,
+  PAUSE_WITHDRAW,
 } from '../../src'
 import { claimWithdrawRequestInstruction } from '../../src/instructions/claimWithdrawRequest'
 import { isInitialized } from '../../src/settlementClaims'
@@ -53,7 +60,10 @@ import {
   createVoteAccount,
   delegatedStakeAccount,
 } from '../utils/staking'
-import { executeInitConfigInstruction } from '../utils/testTransactions'
+import {
+  executeChangeConfigAuthority,
+  executeInitConfigInstruction,
+} from '../utils/testTransactions'
 
 import type { Config, ValidatorBondsProgram } from '../../src'
 import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
@@ -91,12 +101,13 @@ describe('Validator Bonds pause&resume', () => {
       withdrawLockupEpochs,
       configAccountKeypair: configAccountKeypair,
     }))
-    const { instruction: configIx } = await configureConfigInstruction({
+    await executeChangeConfigAuthority({
       program,
+      provider,
       configAccount,
-      newPauseAuthority: pauseAuthority.publicKey,
+      adminAuthority,
+      newPauseAuthority: pauseAuthority,
     })
-    await provider.sendIx([adminAuthority], configIx)
     ;({ validatorIdentity } = await createVoteAccount({
       voteAccount: voteAccount1Keypair,
       provider,
@@ -104,17 +115,18 @@ describe('Validator Bonds pause&resume', () => {
   })
 
   it('pause and resume validator bonds contract', async () => {
-    let configData = await pause()
+    let configData = await pause(PAUSE_FUNDING)
 
     // we can configure despite the pause
     expect(configData.pauseAuthority).toEqual(pauseAuthority.publicKey)
     expect(configData.operatorAuthority).toEqual(pauseAuthority.publicKey)
-    const { instruction: configIx } = await configureConfigInstruction({
+    await executeChangeConfigAuthority({
       program,
+      provider,
       configAccount,
-      newOperator: adminAuthority.publicKey,
+      adminAuthority,
+      newOperator: adminAuthority,
     })
-    await provider.sendIx([adminAuthority], configIx)
     configData = await getConfig(program, configAccount)
     expect(configData.operatorAuthority).toEqual(adminAuthority.publicKey)
 
@@ -127,10 +139,10 @@ describe('Validator Bonds pause&resume', () => {
     })
     await verifyIsPaused([validatorIdentity], initBondIx)
 
-    await resume()
+    await resume(PAUSE_FUNDING)
     await provider.sendIx([validatorIdentity], initBondIx)
 
-    await pause()
+    await pause(PAUSE_FUNDING)
     const { instruction: configBondIx } = await configureBondInstruction({
       program,
       bondAccount,
@@ -139,10 +151,10 @@ describe('Validator Bonds pause&resume', () => {
     })
     await verifyIsPaused([validatorIdentity], configBondIx)
 
-    await resume()
+    await resume(PAUSE_FUNDING)
     await provider.sendIx([validatorIdentity], configBondIx)
 
-    await pause()
+    await pause(PAUSE_FUNDING)
     const { instruction: mintBondIx } = await mintBondInstruction({
       program,
       validatorIdentity: validatorIdentity.publicKey,
@@ -152,10 +164,10 @@ describe('Validator Bonds pause&resume', () => {
     })
     await verifyIsPaused([], mintBondIx)
 
-    await resume()
+    await resume(PAUSE_FUNDING)
     await provider.sendIx([], mintBondIx)
 
-    await pause()
+    await pause(PAUSE_FUNDING)
     const { instruction: configureMintBondIx } =
       await configureBondWithMintInstruction({
         program,
@@ -166,7 +178,7 @@ describe('Validator Bonds pause&resume', () => {
       })
     await verifyIsPaused([validatorIdentity], configureMintBondIx)
 
-    await resume()
+    await resume(PAUSE_FUNDING)
     await provider.sendIx([validatorIdentity], configureMintBondIx)
 
     const { stakeAccount: stakeAccountBond, withdrawer } =
@@ -177,7 +189,7 @@ describe('Validator Bonds pause&resume', () => {
       })
     let stakeAccount = stakeAccountBond
 
-    await pause()
+    await pause(PAUSE_FUNDING)
     const { instruction: fundBondIx } = await fundBondInstruction({
       program,
       configAccount,
@@ -187,7 +199,7 @@ describe('Validator Bonds pause&resume', () => {
     })
     await verifyIsPaused([withdrawer], fundBondIx)
 
-    await resume()
+    await resume(PAUSE_FUNDING)
     await provider.sendIx([withdrawer], fundBondIx)
 
     const stakeToMerge = await createBondsFundedStakeAccount({
@@ -198,7 +210,7 @@ describe('Validator Bonds pause&resume', () => {
       voteAccount: voteAccount1Keypair.publicKey,
     })
 
-    await pause()
+    await pause(PAUSE_STAKE_MANAGEMENT)
     const { instruction: mergeIx } = await mergeStakeInstruction({
       program,
       configAccount,
@@ -207,10 +219,10 @@ describe('Validator Bonds pause&resume', () => {
     })
     await verifyIsPaused([], mergeIx)
 
-    await resume()
+    await resume(PAUSE_STAKE_MANAGEMENT)
     await provider.sendIx([], mergeIx)
 
-    await pause()
+    await pause(PAUSE_WITHDRAW)
     const initWithdrawerRequestEpoch = await currentEpoch(provider)
     const { instruction: initWithdrawIx, withdrawRequestAccount } =
       await initWithdrawRequestInstruction({
@@ -223,10 +235,10 @@ describe('Validator Bonds pause&resume', () => {
       })
     await verifyIsPaused([validatorIdentity], initWithdrawIx)
 
-    await resume()
+    await resume(PAUSE_WITHDRAW)
     await provider.sendIx([validatorIdentity], initWithdrawIx)
 
-    await pause()
+    await pause(PAUSE_WITHDRAW)
     const { instruction: claimWithdrawIx, splitStakeAccount } =
       await claimWithdrawRequestInstruction({
         program,
@@ -249,13 +261,13 @@ describe('Validator Bonds pause&resume', () => {
     // the split stake account is the new stake account to be used in further instructions
     stakeAccount = splitStakeAccount.publicKey
 
-    await resume()
+    await resume(PAUSE_WITHDRAW)
     await provider.sendIx(
       [splitStakeAccount, validatorIdentity],
       claimWithdrawIx,
     )
 
-    await pause()
+    await pause(PAUSE_SETTLEMENT)
     const settlementEpoch = await currentEpoch(provider)
     const {
       instruction: initSettlementIx,
@@ -274,10 +286,10 @@ describe('Validator Bonds pause&resume', () => {
     })
     await verifyIsPaused([adminAuthority], initSettlementIx)
 
-    await resume()
+    await resume(PAUSE_SETTLEMENT)
     await provider.sendIx([adminAuthority], initSettlementIx)
 
-    await pause()
+    await pause(PAUSE_SETTLEMENT)
 
     const settlementClaimsAccountInfo =
       await provider.connection.getAccountInfo(settlementClaimsAccount)
@@ -309,13 +321,13 @@ describe('Validator Bonds pause&resume', () => {
       fundSettlementIx,
     )
 
-    await resume()
+    await resume(PAUSE_SETTLEMENT)
     await provider.sendIx(
       [adminAuthority, signer(settlementSplitStake)],
       fundSettlementIx,
     )
 
-    await pause()
+    await pause(PAUSE_CLAIM)
     await createWithdrawerUsers(provider)
     const treeNode1Withdrawer1 = treeNodeBy(voteAccount1, withdrawer1)
     const stakeAccountSettlementWithdrawer = await createDelegatedStakeAccount({
@@ -340,10 +352,10 @@ describe('Validator Bonds pause&resume', () => {
 
     await verifyIsPaused([], claimIx)
 
-    await resume()
+    await resume(PAUSE_CLAIM)
     await provider.sendIx([], claimIx)
 
-    await pause()
+    await pause(PAUSE_SETTLEMENT)
     await warpOffsetEpoch(
       provider,
       initWithdrawerRequestEpoch + epochsToClaimSettlement + BigInt(1),
@@ -371,10 +383,10 @@ describe('Validator Bonds pause&resume', () => {
       })
     await verifyIsPaused([adminAuthority], cancelSettlementIx)
 
-    await resume()
+    await resume(PAUSE_SETTLEMENT)
     await provider.sendIx([], closeSettlementIx)
 
-    await pause()
+    await pause(PAUSE_STAKE_MANAGEMENT)
     await warpOffsetEpoch(
       provider,
       initWithdrawerRequestEpoch + epochsToClaimSettlement + BigInt(1),
@@ -398,10 +410,10 @@ describe('Validator Bonds pause&resume', () => {
     })
     await verifyIsPaused([], resetIx)
 
-    await resume()
+    await resume(PAUSE_STAKE_MANAGEMENT)
     await provider.sendIx([], resetIx)
 
-    await pause()
+    await pause(PAUSE_STAKE_MANAGEMENT)
     const createInitializedStakeAccount =
       await createSettlementFundedInitializedStake({
         program,
@@ -420,37 +432,135 @@ describe('Validator Bonds pause&resume', () => {
     })
     await verifyIsPaused([adminAuthority], withdrawIx)
 
-    await resume()
+    await resume(PAUSE_STAKE_MANAGEMENT)
     await provider.sendIx([adminAuthority], withdrawIx)
   })
 
-  async function pause(isWarp = true): Promise<Config> {
-    const { instruction } = await emergencyPauseInstruction({
+  it('pause only the requested operation groups', async () => {
+    const { voteAccount, validatorIdentity: identity } =
+      await createVoteAccount({ provider })
+
+    // all groups but funding are paused
+    await pause(PAUSE_ALL & ~PAUSE_FUNDING)
+    const { instruction: initBondIx } = await initBondInstruction({
+      program,
+      configAccount,
+      voteAccount,
+      validatorIdentity: identity.publicKey,
+      bondAuthority: identity.publicKey,
+    })
+    await provider.sendIx([identity], initBondIx)
+
+    const { instruction: alreadyPausedIx } = await emergencyPauseInstruction({
       program,
       configAccount,
       pauseAuthority: pauseAuthority.publicKey,
+      operations: PAUSE_WITHDRAW | PAUSE_CLAIM,
     })
-    if (isWarp) {
-      await warpToNextEpoch(provider)
+    try {
+      await provider.sendIx([pauseAuthority], alreadyPausedIx)
+      throw new Error('failure expected; groups already paused')
+    } catch (e) {
+      verifyError(e, Errors, 6052, 'already Paused')
     }
+
+    for (const operations of [0, PAUSE_LEGACY_ALL, PAUSE_ALL + 64]) {
+      const { instruction } = await emergencyPauseInstruction({
+        program,
+        configAccount,
+        pauseAuthority: pauseAuthority.publicKey,
+        operations,
+      })
+      try {
+        await provider.sendIx([pauseAuthority], instruction)
+        throw new Error('failure expected; invalid operations ' + operations)
+      } catch (e) {
+        verifyError(e, Errors, 6094, 'Invalid bitmask')
+      }
+    }
+
+    await resume(PAUSE_ALL)
+    const { instruction: notPausedIx } = await emergencyResumeInstruction({
+      program,
+      configAccount,
+      pauseAuthority: pauseAuthority.publicKey,
+      operations: PAUSE_SETTLEMENT,
+    })
+    try {
+      await provider.sendIx([pauseAuthority], notPausedIx)
+      throw new Error('failure expected; group is not paused')
+    } catch (e) {
+      verifyError(e, Errors, 6053, 'not Paused')
+    }
+  })
+
+  it('legacy paused flag pauses all operation groups', async () => {
+    // config stored before the bitmask was introduced has the paused value 1
+    const accountInfo = await provider.connection.getAccountInfo(configAccount)
+    assert(accountInfo !== null)
+    const configData = await getConfig(program, configAccount)
+    const encoded = await program.coder.accounts.encode('config', {
+      ...configData,
+      paused: PAUSE_LEGACY_ALL,
+    })
+    const data = Buffer.from(accountInfo.data)
+    encoded.copy(data)
+    provider.context.setAccount(configAccount, { ...accountInfo, data })
+    expect((await getConfig(program, configAccount)).paused).toEqual(
+      PAUSE_LEGACY_ALL,
+    )
+
+    const { voteAccount, validatorIdentity: identity } =
+      await createVoteAccount({ provider })
+    const { instruction: initBondIx } = await initBondInstruction({
+      program,
+      configAccount,
+      voteAccount,
+      validatorIdentity: identity.publicKey,
+      bondAuthority: identity.publicKey,
+    })
+    await verifyIsPaused([identity], initBondIx)
+
+    // resuming a group expands the legacy flag to all the other groups
+    await resume(PAUSE_FUNDING)
+    expect((await getConfig(program, configAccount)).paused).toEqual(
+      PAUSE_ALL & ~PAUSE_FUNDING,
+    )
+    await provider.sendIx([identity], initBondIx)
+    await resume(PAUSE_ALL)
+  })
+
+  async function pause(operations = PAUSE_ALL): Promise<Config> {
+    const pausedBefore = (await getConfig(program, configAccount)).paused
+    const { instruction } = await emergencyPauseInstruction({
+      program,
+      configAccount,
+      pauseAuthority: pauseAuthority.publicKey,
+      operations,
+    })
+    await warpToNextEpoch(provider)
     await provider.sendIx([pauseAuthority], instruction)
     const configData = await getConfig(program, configAccount)
-    expect(configData.paused).toEqual(true)
+    expect(configData.paused).toEqual(
+      pausedOperations(pausedBefore) | operations,
+    )
     return configData
   }
 
-  async function resume(isWarp = true): Promise<Config> {
+  async function resume(operations = PAUSE_ALL): Promise<Config> {
+    const pausedBefore = (await getConfig(program, configAccount)).paused
     const { instruction } = await emergencyResumeInstruction({
       program,
       configAccount,
       pauseAuthority: pauseAuthority.publicKey,
+      operations,
     })
-    if (isWarp) {
-      await warpToNextEpoch(provider)
-    }
+    await warpToNextEpoch(provider)
     await provider.sendIx([pauseAuthority], instruction)
     const configData = await getConfig(program, configAccount)
-    expect(configData.paused).toEqual(false)
+    expect(configData.paused).toEqual(
+      pausedOperations(pausedBefore) & ~operations,
+    )
     return configData
   }
 
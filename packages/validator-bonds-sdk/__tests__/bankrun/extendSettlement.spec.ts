@@ -0,0 +1,179 @@
+import { verifyError } from '@marinade.finance/anchor-common'
+import {
+  assertNotExist,
+  currentEpoch,
+  warpToEpoch,
+  warpToNextEpoch,
+} from '@marinade.finance/bankrun-utils'
+import { Keypair } from '@solana/web3.js'
+
+import { initBankrunTest } from './bankrun'
+import {
+  Errors,
+  PAUSE_SETTLEMENT,
+  closeSettlementV2Instruction,
+  emergencyPauseInstruction,
+  emergencyResumeInstruction,
+  extendSettlementInstruction,
+  getSettlement,
+} from '../../src'
+import { createVoteAccount } from '../utils/staking'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+  executeInitSettlement,
+} from '../utils/testTransactions'
+
+import type { ValidatorBondsProgram } from '../../src'
+import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+import type { PublicKey } from '@solana/web3.js'
+
+describe('Validator Bonds extend settlement', () => {
+  const epochsToClaimSettlement = 1
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let adminAuthority: Keypair
+  let operatorAuthority: Keypair
+  let voteAccount: PublicKey
+  let settlementAccount: PublicKey
+  let rentCollector: PublicKey
+  let settlementEpoch: number
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+    ;({ configAccount, adminAuthority, operatorAuthority } =
+      await executeInitConfigInstruction({
+        program,
+        provider,
+        epochsToClaimSettlement,
+      }))
+    let validatorIdentity: Keypair | undefined
+    ;({ voteAccount, validatorIdentity } = await createVoteAccount({
+      provider,
+    }))
+    await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+    })
+  })
+
+  beforeEach(async () => {
+    await warpToNextEpoch(provider)
+    settlementEpoch = Number(await currentEpoch(provider))
+    ;({ settlementAccount, rentCollector } = await executeInitSettlement({
+      configAccount,
+      program,
+      provider,
+      voteAccount,
+      operatorAuthority,
+      currentEpoch: settlementEpoch,
+    }))
+  })
+
+  it('extend settlement expiration', async () => {
+    const expirationEpoch = settlementEpoch + epochsToClaimSettlement + 2
+    const { instruction } = await extendSettlementInstruction({
+      program,
+      settlementAccount,
+      expirationEpoch,
+      operatorAuthority,
+    })
+    await provider.sendIx([operatorAuthority], instruction)
+    expect(
+      (await getSettlement(program, settlementAccount)).expirationEpochOverride,
+    ).toEqual(expirationEpoch)
+
+    // not expired at the original expiration epoch
+    warpToEpoch(provider, settlementEpoch + epochsToClaimSettlement + 1)
+    const { instruction: closeIx } = await closeSettlementV2Instruction({
+      program,
+      settlementAccount,
+      rentCollector,
+    })
+    try {
+      await provider.sendIx([], closeIx)
+      throw new Error('failure expected; settlement extended')
+    } catch (e) {
+      verifyError(e, Errors, 6022, 'has not expired yet')
+    }
+
+    warpToEpoch(provider, expirationEpoch + 1)
+    await provider.sendIx([], closeIx)
+    await assertNotExist(provider, settlementAccount)
+  })
+
+  it('cannot extend settlement to earlier epoch or with wrong authority', async () => {
+    const originalExpiration = settlementEpoch + epochsToClaimSettlement
+    const { instruction: notLaterIx } = await extendSettlementInstruction({
+      program,
+      settlementAccount,
+      expirationEpoch: originalExpiration,
+      operatorAuthority,
+    })
+    try {
+      await provider.sendIx([operatorAuthority], notLaterIx)
+      throw new Error('failure expected; expiration not extended')
+    } catch (e) {
+      verifyError(e, Errors, 6100, 'has to be extended')
+    }
+
+    const wrongOperator = Keypair.generate()
+    const { instruction: wrongOperatorIx } = await extendSettlementInstruction(
+      {
+        program,
+        settlementAccount,
+        expirationEpoch: originalExpiration + 1,
+        operatorAuthority: wrongOperator,
+      },
+    )
+    try {
+      await provider.sendIx([wrongOperator], wrongOperatorIx)
+      throw new Error('failure expected; wrong operator')
+    } catch (e) {
+      verifyError(e, Errors, 6003, 'operator authority')
+    }
+    expect(
+      (await getSettlement(program, settlementAccount)).expirationEpochOverride,
+    ).toEqual(0)
+  })
+
+  it('cannot extend settlement when settlement operations are paused', async () => {
+    const { instruction: pauseIx } = await emergencyPauseInstruction({
+      program,
+      configAccount,
+      pauseAuthority: adminAuthority,
+      operations: PAUSE_SETTLEMENT,
+    })
+    await provider.sendIx([adminAuthority], pauseIx)
+
+    const { instruction } = await extendSettlementInstruction({
+      program,
+      settlementAccount,
+      expirationEpoch: settlementEpoch + epochsToClaimSettlement + 1,
+      operatorAuthority,
+    })
+    try {
+      await provider.sendIx([operatorAuthority], instruction)
+      throw new Error('failure expected; paused')
+    } catch (e) {
+      verifyError(e, Errors, 6054, 'Emergency Pause is Active')
+    }
+
+    const { instruction: resumeIx } = await emergencyResumeInstruction({
+      program,
+      configAccount,
+      pauseAuthority: adminAuthority,
+      operations: PAUSE_SETTLEMENT,
+    })
+    await provider.sendIx([adminAuthority], resumeIx)
+    await warpToNextEpoch(provider)
+    await provider.sendIx([operatorAuthority], instruction)
+    expect(
+      (await getSettlement(program, settlementAccount)).expirationEpochOverride,
+    ).toEqual(settlementEpoch + epochsToClaimSettlement + 1)
+  })
+})
@@ -0,0 +1,163 @@
+import { verifyError } from '@marinade.finance/anchor-common'
+import { Keypair, LAMPORTS_PER_SOL } from '@solana/web3.js'
+import { getAccount as getTokenAccount } from 'solana-spl-token-modern'
+
+import { initBankrunTest } from './bankrun'
+import {
+  Errors,
+  bondTokenVaultAddress,
+  bondsWithdrawerAuthority,
+  configureCollateralMintInstruction,
+  fundBondTokenInstruction,
+  getBond,
+} from '../../src'
+import { createVoteAccount } from '../utils/staking'
+import {
+  executeInitBondInstruction,
+  executeInitCollateralMintInstruction,
+  executeInitConfigInstruction,
+} from '../utils/testTransactions'
+import { createMint, mintTo } from '../utils/tokens'
+
+import type { ValidatorBondsProgram } from '../../src'
+import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+import type { PublicKey } from '@solana/web3.js'
+
+describe('Validator Bonds fund bond with collateral tokens', () => {
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let adminAuthority: Keypair
+  let bondAccount: PublicKey
+  let mint: PublicKey
+  let collateralMintAccount: PublicKey
+  let depositor: Keypair
+  let depositorTokenAccount: PublicKey
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+  })
+
+  beforeEach(async () => {
+    ;({ configAccount, adminAuthority } = await executeInitConfigInstruction({
+      program,
+      provider,
+    }))
+    const { voteAccount, validatorIdentity } = await createVoteAccount({
+      provider,
+    })
+    ;({ bondAccount } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+    }))
+    ;({ mint, collateralMintAccount } =
+      await executeInitCollateralMintInstruction({
+        program,
+        provider,
+        configAccount,
+        adminAuthority,
+      }))
+    depositor = Keypair.generate()
+    depositorTokenAccount = await mintTo({
+      provider,
+      mint,
+      owner: depositor.publicKey,
+      amount: 100 * LAMPORTS_PER_SOL,
+    })
+  })
+
+  it('fund bond with tokens', async () => {
+    const { instruction, bondTokenVault } = await fundBondTokenInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: 40 * LAMPORTS_PER_SOL,
+      depositorAuthority: depositor,
+    })
+    expect(bondTokenVault).toEqual(
+      bondTokenVaultAddress(bondAccount, mint, program.programId)[0],
+    )
+    await provider.sendIx([depositor], instruction)
+
+    let vaultData = await getTokenAccount(provider.connection, bondTokenVault)
+    expect(vaultData.amount).toEqual(40 * LAMPORTS_PER_SOL)
+    expect(vaultData.mint).toEqual(mint)
+    expect(vaultData.owner).toEqual(
+      bondsWithdrawerAuthority(configAccount, program.programId)[0],
+    )
+    let depositorData = await getTokenAccount(
+      provider.connection,
+      depositorTokenAccount,
+    )
+    expect(depositorData.amount).toEqual(60 * LAMPORTS_PER_SOL)
+
+    // the vault exists, the second funding deposits to it
+    const { instruction: ix2 } = await fundBondTokenInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: 10 * LAMPORTS_PER_SOL,
+      depositorAuthority: depositor,
+      depositorTokenAccount,
+    })
+    await provider.sendIx([depositor], ix2)
+    vaultData = await getTokenAccount(provider.connection, bondTokenVault)
+    expect(vaultData.amount).toEqual(50 * LAMPORTS_PER_SOL)
+    depositorData = await getTokenAccount(
+      provider.connection,
+      depositorTokenAccount,
+    )
+    expect(depositorData.amount).toEqual(50 * LAMPORTS_PER_SOL)
+    // the vault holding collateral is counted once
+    expect((await getBond(program, bondAccount)).fundedTokenVaults).toEqual(1)
+  })
+
+  it('cannot fund bond with disabled collateral mint', async () => {
+    const { instruction: disableIx } = await configureCollateralMintInstruction(
+      {
+        program,
+        collateralMintAccount,
+        enabled: false,
+      },
+    )
+    await provider.sendIx([adminAuthority], disableIx)
+
+    const { instruction } = await fundBondTokenInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: LAMPORTS_PER_SOL,
+      depositorAuthority: depositor,
+    })
+    try {
+      await provider.sendIx([depositor], instruction)
+      throw new Error('failure expected; collateral mint disabled')
+    } catch (e) {
+      verifyError(e, Errors, 6083, 'not enabled for funding bonds')
+    }
+  })
+
+  it('cannot fund bond with not permitted mint', async () => {
+    const notPermittedMint = await createMint({ provider })
+    await mintTo({
+      provider,
+      mint: notPermittedMint,
+      owner: depositor.publicKey,
+      amount: LAMPORTS_PER_SOL,
+    })
+    const { instruction } = await fundBondTokenInstruction({
+      program,
+      bondAccount,
+      mint: notPermittedMint,
+      amount: LAMPORTS_PER_SOL,
+      depositorAuthority: depositor,
+    })
+    await expect(
+      provider.sendIx([depositor], instruction),
+      // 3012. Error Message: The program expected this account to be already initialized.
+    ).rejects.toThrow(/custom program error: 0xbc4/)
+  })
+})
@@ -0,0 +1,324 @@
+import { verifyError } from '@marinade.finance/anchor-common'
+import { currentEpoch, warpToNextEpoch } from '@marinade.finance/bankrun-utils'
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import { getAccount as getTokenAccount } from 'solana-spl-token-modern'
+
+import { initBankrunTest } from './bankrun'
+import {
+  Errors,
+  bondsWithdrawerAuthority,
+  fundBondTokenInstruction,
+  fundSettlementTokenInstruction,
+  getBondLedger,
+  getConfig,
+  getRentExemptStake,
+  getSettlement,
+  initBondLedgerInstruction,
+  settlementStakerAuthority,
+  updateCollateralExchangeRateInstruction,
+} from '../../src'
+import {
+  StakeStates,
+  createVoteAccount,
+  delegatedStakeAccount,
+  getAndCheckStakeAccount,
+} from '../utils/staking'
+import {
+  executeInitBondInstruction,
+  executeInitCollateralMintInstruction,
+  executeInitConfigInstruction,
+  executeInitSettlement,
+} from '../utils/testTransactions'
+import { mintTo } from '../utils/tokens'
+
+import type { ValidatorBondsProgram } from '../../src'
+import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+import type { PublicKey } from '@solana/web3.js'
+
+describe('Validator Bonds fund settlement from bond collateral tokens', () => {
+  const haircutBps = 1_000
+  const vaultTokens = 20 * LAMPORTS_PER_SOL
+  const maxTotalClaim = 10 * LAMPORTS_PER_SOL
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let operatorAuthority: Keypair
+  let voteAccount: PublicKey
+  let bondAccount: PublicKey
+  let mint: PublicKey
+  let collateralMintAccount: PublicKey
+  let bondTokenVault: PublicKey
+  let settlementAccount: PublicKey
+  let liquidator: Keypair
+  let liquidatorTokenAccount: PublicKey
+  let stakeAccountMinimalAmount: number
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+  })
+
+  beforeEach(async () => {
+    let adminAuthority: Keypair
+    ;({ configAccount, adminAuthority, operatorAuthority } =
+      await executeInitConfigInstruction({
+        program,
+        provider,
+      }))
+    const config = await getConfig(program, configAccount)
+    stakeAccountMinimalAmount =
+      (await getRentExemptStake(provider)) +
+      config.minimumStakeLamports.toNumber()
+    let validatorIdentity: Keypair
+    ;({ voteAccount, validatorIdentity } = await createVoteAccount({
+      provider,
+    }))
+    ;({ bondAccount } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+    }))
+    ;({ mint, collateralMintAccount } =
+      await executeInitCollateralMintInstruction({
+        program,
+        provider,
+        configAccount,
+        adminAuthority,
+        haircutBps,
+      }))
+    const depositor = Keypair.generate()
+    await mintTo({
+      provider,
+      mint,
+      owner: depositor.publicKey,
+      amount: vaultTokens,
+    })
+    const { instruction: fundIx, bondTokenVault: vault } =
+      await fundBondTokenInstruction({
+        program,
+        bondAccount,
+        mint,
+        amount: vaultTokens,
+        depositorAuthority: depositor,
+      })
+    await provider.sendIx([depositor], fundIx)
+    bondTokenVault = vault
+    ;({ settlementAccount } = await executeInitSettlement({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      operatorAuthority,
+      maxTotalClaim,
+    }))
+    liquidator = Keypair.generate()
+    liquidatorTokenAccount = await mintTo({
+      provider,
+      mint,
+      owner: liquidator.publicKey,
+      amount: 0,
+    })
+  })
+
+  it('fund settlement with liquidator stake for tokens', async () => {
+    const fundingAmount = 4 * LAMPORTS_PER_SOL
+    const stakeAccount = await liquidatorStakeAccount(fundingAmount)
+    await updateExchangeRate(2 * LAMPORTS_PER_SOL)
+
+    const { instruction, liquidatorTokenAccount: liquidatorAta } =
+      await fundSettlementTokenInstruction({
+        program,
+        settlementAccount,
+        stakeAccount,
+        mint,
+        stakeAuthority: liquidator,
+      })
+    expect(liquidatorAta).toEqual(liquidatorTokenAccount)
+    await provider.sendIx([operatorAuthority, liquidator], instruction)
+
+    const settlementData = await getSettlement(program, settlementAccount)
+    expect(settlementData.lamportsFunded).toEqual(fundingAmount)
+    // 4 SOL by 2 SOL per token is 2 tokens, no haircut for the liquidator
+    const liquidatedTokens = 2 * LAMPORTS_PER_SOL
+    expect(
+      (await getTokenAccount(provider.connection, liquidatorTokenAccount))
+        .amount,
+    ).toEqual(liquidatedTokens)
+    expect(
+      (await getTokenAccount(provider.connection, bondTokenVault)).amount,
+    ).toEqual(vaultTokens - liquidatedTokens)
+
+    const [stakeAccountData] = await getAndCheckStakeAccount(
+      provider,
+      stakeAccount,
+      StakeStates.Delegated,
+    )
+    expect(stakeAccountData.Stake?.meta.authorized.staker).toEqual(
+      settlementStakerAuthority(settlementAccount, program.programId)[0],
+    )
+    expect(stakeAccountData.Stake?.meta.authorized.withdrawer).toEqual(
+      bondsWithdrawerAuthority(configAccount, program.programId)[0],
+    )
+  })
+
+  it('bond ledger records the token funding', async () => {
+    const { instruction: initLedgerIx, bondLedgerAccount } =
+      await initBondLedgerInstruction({
+        program,
+        bondAccount,
+      })
+    await provider.sendIx([], initLedgerIx)
+    const fundingAmount = 2 * LAMPORTS_PER_SOL
+    const stakeAccount = await liquidatorStakeAccount(fundingAmount)
+    await updateExchangeRate(2 * LAMPORTS_PER_SOL)
+
+    const { instruction } = await fundSettlementTokenInstruction({
+      program,
+      settlementAccount,
+      stakeAccount,
+      mint,
+      stakeAuthority: liquidator,
+    })
+    // the bond ledger cannot be skipped when created for the bond
+    const fundNoLedgerIx = new TransactionInstruction({
+      ...instruction,
+      keys: instruction.keys.filter(
+        key => !key.pubkey.equals(bondLedgerAccount),
+      ),
+    })
+    try {
+      await provider.sendIx([operatorAuthority, liquidator], fundNoLedgerIx)
+      throw new Error('failure expected; bond ledger not provided')
+    } catch (e) {
+      verifyError(e, Errors, 6116, 'Bond ledger account is required')
+    }
+    await provider.sendIx([operatorAuthority, liquidator], instruction)
+
+    const epoch = Number(await currentEpoch(provider))
+    const bondLedger = await getBondLedger(program, bondLedgerAccount)
+    const ledgerRecord = bondLedger.records.find(
+      record => record.epoch.toNumber() === epoch,
+    )
+    expect(ledgerRecord?.fundedLamports).toEqual(fundingAmount)
+    expect(
+      (await getSettlement(program, settlementAccount)).lamportsFunded,
+    ).toEqual(fundingAmount)
+  })
+
+  it('cannot fund settlement with stale exchange rate', async () => {
+    const stakeAccount = await liquidatorStakeAccount(LAMPORTS_PER_SOL)
+    const { instruction } = await fundSettlementTokenInstruction({
+      program,
+      settlementAccount,
+      stakeAccount,
+      mint,
+      stakeAuthority: liquidator,
+    })
+
+    // never updated exchange rate
+    try {
+      await provider.sendIx([operatorAuthority, liquidator], instruction)
+      throw new Error('failure expected; exchange rate not updated')
+    } catch (e) {
+      verifyError(e, Errors, 6110, 'not updated for the current epoch')
+    }
+
+    // exchange rate of the previous epoch
+    await updateExchangeRate(2 * LAMPORTS_PER_SOL)
+    await warpToNextEpoch(provider)
+    try {
+      await provider.sendIx([operatorAuthority, liquidator], instruction)
+      throw new Error('failure expected; exchange rate stale')
+    } catch (e) {
+      verifyError(e, Errors, 6110, 'not updated for the current epoch')
+    }
+  })
+
+  it('cannot fund settlement over max total claim', async () => {
+    const stakeAccount = await liquidatorStakeAccount(
+      maxTotalClaim + LAMPORTS_PER_SOL,
+    )
+    await updateExchangeRate(2 * LAMPORTS_PER_SOL)
+    const { instruction } = await fundSettlementTokenInstruction({
+      program,
+      settlementAccount,
+      stakeAccount,
+      mint,
+      stakeAuthority: liquidator,
+    })
+    try {
+      await provider.sendIx([operatorAuthority, liquidator], instruction)
+      throw new Error('failure expected; stake over the settlement amount')
+    } catch (e) {
+      verifyError(e, Errors, 6087, 'do not fit the settlement funding')
+    }
+  })
+
+  it('cannot fund settlement over bond token collateral', async () => {
+    const stakeAccount = await liquidatorStakeAccount(4 * LAMPORTS_PER_SOL)
+    // 0.1 SOL per token, 4 SOL requires 40 tokens
+    await updateExchangeRate(LAMPORTS_PER_SOL / 10)
+    const { instruction } = await fundSettlementTokenInstruction({
+      program,
+      settlementAccount,
+      stakeAccount,
+      mint,
+      stakeAuthority: liquidator,
+    })
+    try {
+      await provider.sendIx([operatorAuthority, liquidator], instruction)
+      throw new Error('failure expected; insufficient token collateral')
+    } catch (e) {
+      verifyError(e, Errors, 6086, 'not enough collateral')
+    }
+  })
+
+  it('cannot fund settlement with wrong operator', async () => {
+    const stakeAccount = await liquidatorStakeAccount(LAMPORTS_PER_SOL)
+    await updateExchangeRate(2 * LAMPORTS_PER_SOL)
+    const wrongOperator = Keypair.generate()
+    const { instruction } = await fundSettlementTokenInstruction({
+      program,
+      settlementAccount,
+      stakeAccount,
+      mint,
+      stakeAuthority: liquidator,
+      operatorAuthority: wrongOperator,
+    })
+    try {
+      await provider.sendIx([wrongOperator, liquidator], instruction)
+      throw new Error('failure expected; wrong operator')
+    } catch (e) {
+      verifyError(e, Errors, 6003, 'requires operator authority')
+    }
+  })
+
+  async function liquidatorStakeAccount(
+    fundingAmount: number,
+  ): Promise<PublicKey> {
+    const { stakeAccount } = await delegatedStakeAccount({
+      provider,
+      voteAccountToDelegate: voteAccount,
+      lamports: fundingAmount + stakeAccountMinimalAmount,
+      staker: liquidator,
+      withdrawer: liquidator,
+    })
+    await warpToNextEpoch(provider) // activating stake account
+    return stakeAccount
+  }
+
+  async function updateExchangeRate(exchangeRate: number) {
+    const { instruction } = await updateCollateralExchangeRateInstruction({
+      program,
+      collateralMintAccount,
+      exchangeRate,
+      operatorAuthority,
+    })
+    await provider.sendIx([operatorAuthority], instruction)
+  }
+})
@@ -0,0 +1,243 @@
+import { verifyError } from '@marinade.finance/anchor-common'
+import { currentEpoch, warpToNextEpoch } from '@marinade.finance/bankrun-utils'
+import { Keypair, LAMPORTS_PER_SOL } from '@solana/web3.js'
+
+import { initBankrunTest } from './bankrun'
+import {
+  Errors,
+  collateralMintAddress,
+  configureCollateralMintInstruction,
+  getCollateralMint,
+  initCollateralMintInstruction,
+  updateCollateralExchangeRateInstruction,
+} from '../../src'
+import { executeInitConfigInstruction } from '../utils/testTransactions'
+import { createMint } from '../utils/tokens'
+
+import type { ValidatorBondsProgram } from '../../src'
+import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+import type { PublicKey } from '@solana/web3.js'
+
+describe('Validator Bonds init and configure collateral mint', () => {
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let adminAuthority: Keypair
+  let operatorAuthority: Keypair
+  let mint: PublicKey
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+  })
+
+  beforeEach(async () => {
+    ;({ configAccount, adminAuthority, operatorAuthority } =
+      await executeInitConfigInstruction({
+        program,
+        provider,
+      }))
+    mint = await createMint({ provider })
+  })
+
+  it('init collateral mint', async () => {
+    const { instruction, collateralMintAccount } =
+      await initCollateralMintInstruction({
+        program,
+        configAccount,
+        mint,
+        haircutBps: 500,
+        adminAuthority,
+      })
+    await provider.sendIx([adminAuthority], instruction)
+
+    expect(collateralMintAccount).toEqual(
+      collateralMintAddress(configAccount, mint, program.programId)[0],
+    )
+    const collateralMintData = await getCollateralMint(
+      program,
+      collateralMintAccount,
+    )
+    expect(collateralMintData.config).toEqual(configAccount)
+    expect(collateralMintData.mint).toEqual(mint)
+    expect(collateralMintData.haircutBps).toEqual(500)
+    expect(collateralMintData.enabled).toBeTruthy()
+    // 1:1 to SOL never updated by operator
+    expect(collateralMintData.exchangeRate).toEqual(LAMPORTS_PER_SOL)
+    expect(collateralMintData.exchangeRateEpoch).toEqual(0)
+  })
+
+  it('cannot init collateral mint with wrong admin', async () => {
+    const wrongAdmin = Keypair.generate()
+    const { instruction } = await initCollateralMintInstruction({
+      program,
+      configAccount,
+      mint,
+      haircutBps: 0,
+      adminAuthority: wrongAdmin,
+    })
+    try {
+      await provider.sendIx([wrongAdmin], instruction)
+      throw new Error('failure expected; wrong admin')
+    } catch (e) {
+      verifyError(e, Errors, 6001, 'requires admin authority')
+    }
+  })
+
+  it('cannot init collateral mint with wrong decimals or haircut', async () => {
+    const mint6Decimals = await createMint({ provider, decimals: 6 })
+    const { instruction: ixDecimals } = await initCollateralMintInstruction({
+      program,
+      configAccount,
+      mint: mint6Decimals,
+      haircutBps: 0,
+      adminAuthority,
+    })
+    try {
+      await provider.sendIx([adminAuthority], ixDecimals)
+      throw new Error('failure expected; wrong decimals')
+    } catch (e) {
+      verifyError(e, Errors, 6082, 'same number of decimals as SOL')
+    }
+
+    const { instruction: ixHaircut } = await initCollateralMintInstruction({
+      program,
+      configAccount,
+      mint,
+      haircutBps: 10_000,
+      adminAuthority,
+    })
+    try {
+      await provider.sendIx([adminAuthority], ixHaircut)
+      throw new Error('failure expected; haircut too high')
+    } catch (e) {
+      verifyError(e, Errors, 6081, 'lower than 10000 basis points')
+    }
+  })
+
+  it('configure collateral mint', async () => {
+    const { instruction: initIx, collateralMintAccount } =
+      await initCollateralMintInstruction({
+        program,
+        configAccount,
+        mint,
+        haircutBps: 500,
+        adminAuthority,
+      })
+    await provider.sendIx([adminAuthority], initIx)
+
+    const { instruction } = await configureCollateralMintInstruction({
+      program,
+      collateralMintAccount,
+      newHaircutBps: 1_000,
+      enabled: false,
+    })
+    await provider.sendIx([adminAuthority], instruction)
+
+    let collateralMintData = await getCollateralMint(
+      program,
+      collateralMintAccount,
+    )
+    expect(collateralMintData.haircutBps).toEqual(1_000)
+    expect(collateralMintData.enabled).toBeFalsy()
+
+    // no change of haircut when not provided
+    const { instruction: enableIx } = await configureCollateralMintInstruction(
+      {
+        program,
+        collateralMintAccount,
+        enabled: true,
+      },
+    )
+    await provider.sendIx([adminAuthority], enableIx)
+    collateralMintData = await getCollateralMint(program, collateralMintAccount)
+    expect(collateralMintData.haircutBps).toEqual(1_000)
+    expect(collateralMintData.enabled).toBeTruthy()
+
+    const { instruction: haircutIx } = await configureCollateralMintInstruction(
+      {
+        program,
+        collateralMintAccount,
+        newHaircutBps: 10_000,
+      },
+    )
+    try {
+      await provider.sendIx([adminAuthority], haircutIx)
+      throw new Error('failure expected; haircut too high')
+    } catch (e) {
+      verifyError(e, Errors, 6081, 'lower than 10000 basis points')
+    }
+
+    const wrongAdmin = Keypair.generate()
+    const { instruction: wrongAdminIx } =
+      await configureCollateralMintInstruction({
+        program,
+        collateralMintAccount,
+        enabled: false,
+        adminAuthority: wrongAdmin,
+      })
+    try {
+      await provider.sendIx([wrongAdmin], wrongAdminIx)
+      throw new Error('failure expected; wrong admin')
+    } catch (e) {
+      verifyError(e, Errors, 6001, 'requires admin authority')
+    }
+  })
+
+  it('update collateral exchange rate', async () => {
+    const { instruction: initIx, collateralMintAccount } =
+      await initCollateralMintInstruction({
+        program,
+        configAccount,
+        mint,
+        haircutBps: 0,
+        adminAuthority,
+      })
+    await provider.sendIx([adminAuthority], initIx)
+
+    await warpToNextEpoch(provider)
+    const exchangeRate = 2 * LAMPORTS_PER_SOL
+    const { instruction } = await updateCollateralExchangeRateInstruction({
+      program,
+      collateralMintAccount,
+      exchangeRate,
+    })
+    await provider.sendIx([operatorAuthority], instruction)
+
+    const collateralMintData = await getCollateralMint(
+      program,
+      collateralMintAccount,
+    )
+    expect(collateralMintData.exchangeRate).toEqual(exchangeRate)
+    expect(collateralMintData.exchangeRateEpoch).toEqual(
+      Number(await currentEpoch(provider)),
+    )
+
+    const { instruction: zeroRateIx } =
+      await updateCollateralExchangeRateInstruction({
+        program,
+        collateralMintAccount,
+        exchangeRate: 0,
+      })
+    try {
+      await provider.sendIx([operatorAuthority], zeroRateIx)
+      throw new Error('failure expected; zero exchange rate')
+    } catch (e) {
+      verifyError(e, Errors, 6109, 'exchange rate has to be positive')
+    }
+
+    // admin is not permitted to update the rate
+    const { instruction: adminIx } =
+      await updateCollateralExchangeRateInstruction({
+        program,
+        collateralMintAccount,
+        exchangeRate,
+        operatorAuthority: adminAuthority,
+      })
+    try {
+      await provider.sendIx([adminAuthority], adminIx)
+      throw new Error('failure expected; wrong operator')
+    } catch (e) {
+      verifyError(e, Errors, 6003, 'requires operator authority')
+    }
+  })
+})
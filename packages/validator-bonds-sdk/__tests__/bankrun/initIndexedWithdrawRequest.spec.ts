@@ -0,0 +1,177 @@
+import { verifyError } from '@marinade.finance/anchor-common'
+import { warpToEpoch } from '@marinade.finance/bankrun-utils'
+import { signer } from '@marinade.finance/web3js-1x'
+import { Keypair, LAMPORTS_PER_SOL } from '@solana/web3.js'
+
+import { initBankrunTest } from './bankrun'
+import {
+  Errors,
+  findWithdrawRequests,
+  getBond,
+  getWithdrawRequest,
+  indexedWithdrawRequestAddress,
+  initIndexedWithdrawRequestInstruction,
+  withdrawRequestAddress,
+} from '../../src'
+import { getSecureRandomInt } from '../utils/helpers'
+import { createVoteAccount } from '../utils/staking'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+  executeInitWithdrawRequestInstruction,
+} from '../utils/testTransactions'
+
+import type { Bond, ValidatorBondsProgram } from '../../src'
+import type { ProgramAccount } from '@coral-xyz/anchor'
+import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+import type { PublicKey } from '@solana/web3.js'
+
+describe('Validator Bonds init indexed withdraw request', () => {
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let bond: ProgramAccount<Bond>
+  let bondAuthority: Keypair
+  let validatorIdentity: Keypair
+  let startUpEpoch: number
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+    const startUpEpochPlus = getSecureRandomInt(100, 200)
+    const currentEpoch = Number(
+      (await provider.context.banksClient.getClock()).epoch,
+    )
+    startUpEpoch = currentEpoch + startUpEpochPlus
+    warpToEpoch(provider, startUpEpoch)
+  })
+
+  beforeEach(async () => {
+    ;({ configAccount } = await executeInitConfigInstruction({
+      program,
+      provider,
+    }))
+    const { voteAccount, validatorIdentity: nodeIdentity } =
+      await createVoteAccount({ provider })
+    validatorIdentity = nodeIdentity
+    bondAuthority = Keypair.generate()
+    const { bondAccount } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      bondAuthority,
+      voteAccount,
+      validatorIdentity,
+    })
+    bond = {
+      publicKey: bondAccount,
+      account: await getBond(program, bondAccount),
+    }
+  })
+
+  it('cannot init indexed withdraw request with wrong authority', async () => {
+    try {
+      const randomAuthority = Keypair.generate()
+      const { instruction } = await initIndexedWithdrawRequestInstruction({
+        program,
+        bondAccount: bond.publicKey,
+        authority: randomAuthority,
+        amount: LAMPORTS_PER_SOL,
+      })
+      await provider.sendIx([signer(randomAuthority)], instruction)
+      throw new Error('failure; expected wrong authority')
+    } catch (e) {
+      verifyError(e, Errors, 6002, 'Invalid authority')
+    }
+  })
+
+  it('init indexed withdraw requests next to the legacy one', async () => {
+    const { withdrawRequestAccount: legacyWithdrawRequest } =
+      await executeInitWithdrawRequestInstruction({
+        program,
+        provider,
+        bondAccount: bond.publicKey,
+        validatorIdentity,
+      })
+    expect(legacyWithdrawRequest).toEqual(
+      withdrawRequestAddress(bond.publicKey, program.programId)[0],
+    )
+    // the index 0 is the legacy withdraw request
+    expect(legacyWithdrawRequest).toEqual(
+      indexedWithdrawRequestAddress(bond.publicKey, 0, program.programId)[0],
+    )
+
+    const epoch = Number((await provider.context.banksClient.getClock()).epoch)
+    for (const index of [1, 2]) {
+      const { instruction, withdrawRequestAccount, withdrawRequestIndex } =
+        await initIndexedWithdrawRequestInstruction({
+          program,
+          bondAccount: bond.publicKey,
+          authority: bondAuthority,
+          amount: index * LAMPORTS_PER_SOL,
+        })
+      await provider.sendIx([signer(bondAuthority)], instruction)
+
+      const [withdrawRequestAddr, bump] = indexedWithdrawRequestAddress(
+        bond.publicKey,
+        index,
+        program.programId,
+      )
+      expect(withdrawRequestIndex).toEqual(index)
+      expect(withdrawRequestAccount).toEqual(withdrawRequestAddr)
+      const withdrawRequestData = await getWithdrawRequest(
+        program,
+        withdrawRequestAccount,
+      )
+      expect(withdrawRequestData.bond).toEqual(bond.publicKey)
+      expect(withdrawRequestData.bump).toEqual(bump)
+      expect(withdrawRequestData.index).toEqual(index)
+      expect(withdrawRequestData.epoch).toEqual(epoch)
+      expect(withdrawRequestData.requestedAmount).toEqual(
+        index * LAMPORTS_PER_SOL,
+      )
+      expect(withdrawRequestData.voteAccount).toEqual(bond.account.voteAccount)
+      expect(withdrawRequestData.withdrawnAmount).toEqual(0)
+    }
+
+    const bondData = await getBond(program, bond.publicKey)
+    expect(bondData.withdrawRequestIndex).toEqual(2)
+
+    const withdrawRequests = await findWithdrawRequests({
+      program,
+      bond: bond.publicKey,
+    })
+    expect(withdrawRequests.map(w => w.publicKey.toBase58()).sort()).toEqual(
+      [0, 1, 2]
+        .map(index =>
+          indexedWithdrawRequestAddress(
+            bond.publicKey,
+            index,
+            program.programId,
+          )[0].toBase58(),
+        )
+        .sort(),
+    )
+  })
+
+  it('init indexed withdraw request without the legacy one', async () => {
+    const { instruction, withdrawRequestAccount } =
+      await initIndexedWithdrawRequestInstruction({
+        program,
+        bondAccount: bond.publicKey,
+        authority: validatorIdentity,
+        amount: 123,
+      })
+    await provider.sendIx([validatorIdentity], instruction)
+
+    expect(withdrawRequestAccount).toEqual(
+      indexedWithdrawRequestAddress(bond.publicKey, 1, program.programId)[0],
+    )
+    const withdrawRequests = await findWithdrawRequests({
+      program,
+      bond: bond.publicKey,
+    })
+    expect(withdrawRequests.length).toEqual(1)
+    expect(withdrawRequests[0]!.publicKey).toEqual(withdrawRequestAccount)
+    expect(withdrawRequests[0]!.account.requestedAmount).toEqual(123)
+  })
+})
@@ -0,0 +1,181 @@
+import { verifyError } from '@marinade.finance/anchor-common'
+import { Keypair, PublicKey } from '@solana/web3.js'
+
+import { initBankrunTest } from './bankrun'
+import {
+  Errors,
+  acceptConfigAuthorityInstruction,
+  cancelConfigAuthorityInstruction,
+  getConfig,
+  proposeConfigAuthorityInstruction,
+} from '../../src'
+import { executeInitConfigInstruction } from '../utils/testTransactions'
+
+import type { ValidatorBondsProgram } from '../../src'
+import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+
+describe('Validator Bonds propose, accept and cancel config authority', () => {
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let adminAuthority: Keypair
+  let operatorAuthority: Keypair
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+  })
+
+  beforeEach(async () => {
+    ;({ configAccount, adminAuthority, operatorAuthority } =
+      await executeInitConfigInstruction({
+        program,
+        provider,
+      }))
+  })
+
+  it('propose and accept config authorities', async () => {
+    const newAdmin = Keypair.generate()
+    const newOperator = Keypair.generate()
+    const { instruction } = await proposeConfigAuthorityInstruction({
+      program,
+      configAccount,
+      adminAuthority,
+      newAdmin: newAdmin.publicKey,
+      newOperator: newOperator.publicKey,
+      newPauseAuthority: newOperator.publicKey,
+    })
+    await provider.sendIx([adminAuthority], instruction)
+
+    let configData = await getConfig(program, configAccount)
+    expect(configData.adminAuthority).toEqual(adminAuthority.publicKey)
+    expect(configData.operatorAuthority).toEqual(operatorAuthority.publicKey)
+    expect(configData.pendingAdminAuthority).toEqual(newAdmin.publicKey)
+    expect(configData.pendingOperatorAuthority).toEqual(newOperator.publicKey)
+    expect(configData.pendingPauseAuthority).toEqual(newOperator.publicKey)
+
+    const randomKey = Keypair.generate()
+    const { instruction: wrongAcceptIx } =
+      await acceptConfigAuthorityInstruction({
+        program,
+        configAccount,
+        newAuthority: randomKey,
+      })
+    try {
+      await provider.sendIx([randomKey], wrongAcceptIx)
+      throw new Error('failure expected; not the pending authority')
+    } catch (e) {
+      verifyError(e, Errors, 6088, 'not the pending authority')
+    }
+
+    const { instruction: acceptAdminIx } =
+      await acceptConfigAuthorityInstruction({
+        program,
+        configAccount,
+        newAuthority: newAdmin,
+      })
+    await provider.sendIx([newAdmin], acceptAdminIx)
+    configData = await getConfig(program, configAccount)
+    expect(configData.adminAuthority).toEqual(newAdmin.publicKey)
+    expect(configData.pendingAdminAuthority).toEqual(PublicKey.default)
+    expect(configData.operatorAuthority).toEqual(operatorAuthority.publicKey)
+
+    // one accept takes all the roles proposed to the signer
+    const { instruction: acceptOperatorIx } =
+      await acceptConfigAuthorityInstruction({
+        program,
+        configAccount,
+        newAuthority: newOperator,
+      })
+    await provider.sendIx([newOperator], acceptOperatorIx)
+    configData = await getConfig(program, configAccount)
+    expect(configData.operatorAuthority).toEqual(newOperator.publicKey)
+    expect(configData.pauseAuthority).toEqual(newOperator.publicKey)
+    expect(configData.pendingOperatorAuthority).toEqual(PublicKey.default)
+    expect(configData.pendingPauseAuthority).toEqual(PublicKey.default)
+  })
+
+  it('cancel proposed config authority', async () => {
+    const newAdmin = Keypair.generate()
+    const { instruction } = await proposeConfigAuthorityInstruction({
+      program,
+      configAccount,
+      adminAuthority,
+      newAdmin: newAdmin.publicKey,
+    })
+    await provider.sendIx([adminAuthority], instruction)
+
+    const { instruction: cancelIx } = await cancelConfigAuthorityInstruction({
+      program,
+      configAccount,
+      adminAuthority,
+    })
+    await provider.sendIx([adminAuthority], cancelIx)
+    const configData = await getConfig(program, configAccount)
+    expect(configData.adminAuthority).toEqual(adminAuthority.publicKey)
+    expect(configData.pendingAdminAuthority).toEqual(PublicKey.default)
+
+    const { instruction: acceptIx } = await acceptConfigAuthorityInstruction({
+      program,
+      configAccount,
+      newAuthority: newAdmin,
+    })
+    try {
+      await provider.sendIx([newAdmin], acceptIx)
+      throw new Error('failure expected; proposal was cancelled')
+    } catch (e) {
+      verifyError(e, Errors, 6088, 'not the pending authority')
+    }
+  })
+
+  it('fails to propose and cancel with wrong admin', async () => {
+    const { instruction } = await proposeConfigAuthorityInstruction({
+      program,
+      configAccount,
+      adminAuthority: operatorAuthority,
+      newAdmin: operatorAuthority.publicKey,
+    })
+    try {
+      await provider.sendIx([operatorAuthority], instruction)
+      throw new Error('failure expected; wrong admin')
+    } catch (e) {
+      verifyError(e, Errors, 6001, 'requires admin authority')
+    }
+
+    const { instruction: cancelIx } = await cancelConfigAuthorityInstruction({
+      program,
+      configAccount,
+      adminAuthority: operatorAuthority,
+    })
+    try {
+      await provider.sendIx([operatorAuthority], cancelIx)
+      throw new Error('failure expected; wrong admin')
+    } catch (e) {
+      verifyError(e, Errors, 6001, 'requires admin authority')
+    }
+  })
+
+  it('fails to change config authority with configure config', async () => {
+    const instruction = await program.methods
+      .configureConfig({
+        admin: Keypair.generate().publicKey,
+        operator: null,
+        pauseAuthority: null,
+        epochsToClaimSettlement: null,
+        withdrawLockupEpochs: null,
+        minimumStakeLamports: null,
+        slotsToStartSettlementClaiming: null,
+        minBondMaxStakeWanted: null,
+      })
+      .accountsPartial({
+        config: configAccount,
+        adminAuthority: adminAuthority.publicKey,
+      })
+      .instruction()
+    try {
+      await provider.sendIx([adminAuthority], instruction)
+      throw new Error('failure expected; authority change not proposed')
+    } catch (e) {
+      verifyError(e, Errors, 6089, 'proposing and accepting')
+    }
+  })
+})
@@ -0,0 +1,229 @@
+import { verifyError } from '@marinade.finance/anchor-common'
+import {
+  currentEpoch,
+  warpToEpoch,
+  warpToNextEpoch,
+} from '@marinade.finance/bankrun-utils'
+import BN from 'bn.js'
+
+import { initBankrunTest } from './bankrun'
+import {
+  Errors,
+  cancelQueuedConfigInstruction,
+  executeConfigureConfigInstruction,
+  getConfig,
+  queueConfigureConfigInstruction,
+} from '../../src'
+import { executeInitConfigInstruction } from '../utils/testTransactions'
+
+import type { ValidatorBondsProgram } from '../../src'
+import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+import type { Keypair, PublicKey } from '@solana/web3.js'
+
+describe('Validator Bonds queue, execute and cancel config change', () => {
+  const withdrawLockupEpochs = 2
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let adminAuthority: Keypair
+  let operatorAuthority: Keypair
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+  })
+
+  beforeEach(async () => {
+    ;({ configAccount, adminAuthority, operatorAuthority } =
+      await executeInitConfigInstruction({
+        program,
+        provider,
+        epochsToClaimSettlement: 1,
+        withdrawLockupEpochs,
+      }))
+  })
+
+  it('queue and execute config change at activation epoch', async () => {
+    const epoch = Number(await currentEpoch(provider))
+    const activationEpoch = epoch + withdrawLockupEpochs + 1
+
+    const { instruction: tooEarlyIx } = await queueConfigureConfigInstruction({
+      program,
+      configAccount,
+      adminAuthority,
+      activationEpoch: activationEpoch - 1,
+      newWithdrawLockupEpochs: 5,
+    })
+    try {
+      await provider.sendIx([adminAuthority], tooEarlyIx)
+      throw new Error('failure expected; activation epoch too early')
+    } catch (e) {
+      verifyError(e, Errors, 6091, 'earlier than the withdraw lockup')
+    }
+
+    const { instruction } = await queueConfigureConfigInstruction({
+      program,
+      configAccount,
+      adminAuthority,
+      activationEpoch,
+      newEpochsToClaimSettlement: 7,
+      newWithdrawLockupEpochs: 5,
+    })
+    await provider.sendIx([adminAuthority], instruction)
+    let configData = await getConfig(program, configAccount)
+    expect(configData.withdrawLockupEpochs).toEqual(withdrawLockupEpochs)
+    expect(configData.queuedChange?.epochsToClaimSettlement).toEqual(7)
+    expect(configData.queuedChange?.withdrawLockupEpochs).toEqual(5)
+    expect(configData.queuedChange?.minimumStakeLamports).toBeNull()
+    expect(configData.queuedChange?.activationEpoch).toEqual(activationEpoch)
+
+    warpToEpoch(provider, activationEpoch - 1)
+    const { instruction: executeIx } = await executeConfigureConfigInstruction(
+      {
+        program,
+        configAccount,
+      },
+    )
+    try {
+      await provider.sendIx([], executeIx)
+      throw new Error('failure expected; activation epoch not reached')
+    } catch (e) {
+      verifyError(e, Errors, 6093, 'has not been reached yet')
+    }
+    configData = await getConfig(program, configAccount)
+    expect(configData.withdrawLockupEpochs).toEqual(withdrawLockupEpochs)
+
+    warpToEpoch(provider, activationEpoch)
+    await provider.sendIx([], executeIx)
+    configData = await getConfig(program, configAccount)
+    expect(configData.epochsToClaimSettlement).toEqual(7)
+    expect(configData.withdrawLockupEpochs).toEqual(5)
+    expect(configData.queuedChange).toBeNull()
+
+    await warpToNextEpoch(provider)
+    try {
+      await provider.sendIx([], executeIx)
+      throw new Error('failure expected; change already executed')
+    } catch (e) {
+      verifyError(e, Errors, 6092, 'No config change is queued')
+    }
+  })
+
+  it('cancel queued config change', async () => {
+    const epoch = Number(await currentEpoch(provider))
+    const activationEpoch = epoch + withdrawLockupEpochs + 1
+    const { instruction } = await queueConfigureConfigInstruction({
+      program,
+      configAccount,
+      adminAuthority,
+      activationEpoch,
+      newMinimumStakeLamports: 42,
+    })
+    await provider.sendIx([adminAuthority], instruction)
+
+    const { instruction: wrongCancelIx } = await cancelQueuedConfigInstruction({
+      program,
+      configAccount,
+      adminAuthority: operatorAuthority,
+    })
+    try {
+      await provider.sendIx([operatorAuthority], wrongCancelIx)
+      throw new Error('failure expected; wrong admin')
+    } catch (e) {
+      verifyError(e, Errors, 6001, 'requires admin authority')
+    }
+
+    const { instruction: cancelIx } = await cancelQueuedConfigInstruction({
+      program,
+      configAccount,
+      adminAuthority,
+    })
+    await provider.sendIx([adminAuthority], cancelIx)
+    expect((await getConfig(program, configAccount)).queuedChange).toBeNull()
+
+    warpToEpoch(provider, activationEpoch)
+    const { instruction: executeIx } = await executeConfigureConfigInstruction(
+      {
+        program,
+        configAccount,
+      },
+    )
+    try {
+      await provider.sendIx([], executeIx)
+      throw new Error('failure expected; change was cancelled')
+    } catch (e) {
+      verifyError(e, Errors, 6092, 'No config change is queued')
+    }
+    try {
+      await provider.sendIx([adminAuthority], cancelIx)
+      throw new Error('failure expected; nothing to cancel')
+    } catch (e) {
+      verifyError(e, Errors, 6092, 'No config change is queued')
+    }
+    expect(
+      (await getConfig(program, configAccount)).minimumStakeLamports,
+    ).not.toEqual(42)
+  })
+
+  it('fails to queue config change with wrong admin or no change', async () => {
+    const epoch = Number(await currentEpoch(provider))
+    const activationEpoch = epoch + withdrawLockupEpochs + 1
+    const { instruction } = await queueConfigureConfigInstruction({
+      program,
+      configAccount,
+      adminAuthority: operatorAuthority,
+      activationEpoch,
+      newWithdrawLockupEpochs: 5,
+    })
+    try {
+      await provider.sendIx([operatorAuthority], instruction)
+      throw new Error('failure expected; wrong admin')
+    } catch (e) {
+      verifyError(e, Errors, 6001, 'requires admin authority')
+    }
+
+    const emptyIx = await program.methods
+      .queueConfigureConfig({
+        epochsToClaimSettlement: null,
+        withdrawLockupEpochs: null,
+        minimumStakeLamports: null,
+        slotsToStartSettlementClaiming: null,
+        activationEpoch: new BN(activationEpoch),
+      })
+      .accountsPartial({
+        config: configAccount,
+        adminAuthority: adminAuthority.publicKey,
+      })
+      .instruction()
+    try {
+      await provider.sendIx([adminAuthority], emptyIx)
+      throw new Error('failure expected; no change to queue')
+    } catch (e) {
+      verifyError(e, Errors, 6092, 'No config change is queued')
+    }
+  })
+
+  it('fails to change lockup params with configure config', async () => {
+    const instruction = await program.methods
+      .configureConfig({
+        admin: null,
+        operator: null,
+        pauseAuthority: null,
+        epochsToClaimSettlement: null,
+        withdrawLockupEpochs: new BN(42),
+        minimumStakeLamports: null,
+        slotsToStartSettlementClaiming: null,
+        minBondMaxStakeWanted: null,
+      })
+      .accountsPartial({
+        config: configAccount,
+        adminAuthority: adminAuthority.publicKey,
+      })
+      .instruction()
+    try {
+      await provider.sendIx([adminAuthority], instruction)
+      throw new Error('failure expected; lockup params require timelock')
+    } catch (e) {
+      verifyError(e, Errors, 6090, 'only by queueing the change')
+    }
+  })
+})
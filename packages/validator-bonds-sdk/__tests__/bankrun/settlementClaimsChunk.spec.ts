@@ -0,0 +1,350 @@
+import { verifyError } from '@marinade.finance/anchor-common'
+import {
+  assertNotExist,
+  currentEpoch,
+  warpToEpoch,
+  warpToNextEpoch,
+} from '@marinade.finance/bankrun-utils'
+import { signer } from '@marinade.finance/web3js-1x'
+import { LAMPORTS_PER_SOL } from '@solana/web3.js'
+
+import { initBankrunTest } from './bankrun'
+import {
+  Errors,
+  MerkleTreeNode,
+  SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS,
+  claimSettlementV2Instruction,
+  closeSettlementV2Instruction,
+  fundSettlementInstruction,
+  getSettlementClaimsBySettlement,
+  initSettlementClaimsChunkInstruction,
+  isClaimed,
+  settlementClaimsAddress,
+  settlementClaimsChunkAddress,
+} from '../../src'
+import {
+  buildMerkleTree,
+  staker1,
+  withdrawer1,
+} from '../utils/merkleTreeTestData'
+import {
+  createBondsFundedStakeAccount,
+  createDelegatedStakeAccount,
+  createVoteAccount,
+} from '../utils/staking'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+  executeInitSettlement,
+} from '../utils/testTransactions'
+
+import type { ValidatorBondsProgram } from '../../src'
+import type { MerkleTreeNodeWithProof } from '../utils/merkleTreeTestData'
+import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+import type { Keypair, PublicKey } from '@solana/web3.js'
+
+describe('Validator Bonds settlement claims chunks and early close', () => {
+  const epochsToClaimSettlement = 4
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let operatorAuthority: Keypair
+  let voteAccount: PublicKey
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+    ;({ configAccount, operatorAuthority } = await executeInitConfigInstruction(
+      {
+        program,
+        provider,
+        epochsToClaimSettlement,
+      },
+    ))
+    let validatorIdentity: Keypair | undefined
+    ;({ voteAccount, validatorIdentity } = await createVoteAccount({
+      provider,
+    }))
+    await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+    })
+  })
+
+  it('close fully claimed settlement before expiration', async () => {
+    const { merkleRoot, items } = buildMerkleTree([
+      new MerkleTreeNode({
+        stakeAuthority: staker1,
+        withdrawAuthority: withdrawer1,
+        claim: LAMPORTS_PER_SOL,
+        index: 0,
+      }),
+      new MerkleTreeNode({
+        stakeAuthority: staker1,
+        withdrawAuthority: withdrawer1,
+        claim: 2 * LAMPORTS_PER_SOL,
+        index: 1,
+      }),
+    ])
+    const [item0, item1] = items as [
+      MerkleTreeNodeWithProof,
+      MerkleTreeNodeWithProof,
+    ]
+    const { settlementAccount, stakeAccountFrom, stakeAccountTo } =
+      await initFundedSettlement({
+        merkleRoot,
+        maxMerkleNodes: items.length,
+        maxTotalClaim: 3 * LAMPORTS_PER_SOL,
+      })
+
+    const { instruction: claim0Ix } = await claimSettlementV2Instruction({
+      program,
+      claimAmount: item0.treeNode.data.claim,
+      index: item0.treeNode.data.index,
+      merkleProof: item0.proof,
+      settlementAccount,
+      stakeAccountFrom,
+      stakeAccountTo,
+    })
+    await provider.sendIx([], claim0Ix)
+
+    const { instruction: closeIx } = await closeSettlementV2Instruction({
+      program,
+      settlementAccount,
+    })
+    try {
+      await provider.sendIx([], closeIx)
+      throw new Error('failure expected; not all merkle nodes claimed')
+    } catch (e) {
+      verifyError(e, Errors, 6022, 'has not expired yet')
+    }
+
+    const { instruction: claim1Ix } = await claimSettlementV2Instruction({
+      program,
+      claimAmount: item1.treeNode.data.claim,
+      index: item1.treeNode.data.index,
+      merkleProof: item1.proof,
+      settlementAccount,
+      stakeAccountFrom,
+      stakeAccountTo,
+    })
+    await provider.sendIx([], claim1Ix)
+
+    // the same epoch, the settlement is fully claimed
+    await provider.sendIx([], closeIx)
+    await assertNotExist(provider, settlementAccount)
+    await assertNotExist(
+      provider,
+      settlementClaimsAddress(settlementAccount, program.programId)[0],
+    )
+  })
+
+  it('claim and close settlement with chunked claims bitmap', async () => {
+    const chunkedIndex = SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS + 1
+    const { merkleRoot, items } = buildMerkleTree([
+      new MerkleTreeNode({
+        stakeAuthority: staker1,
+        withdrawAuthority: withdrawer1,
+        claim: LAMPORTS_PER_SOL,
+        index: 0,
+      }),
+      new MerkleTreeNode({
+        stakeAuthority: staker1,
+        withdrawAuthority: withdrawer1,
+        claim: 2 * LAMPORTS_PER_SOL,
+        index: chunkedIndex,
+      }),
+    ])
+    const [item0, itemChunked] = items as [
+      MerkleTreeNodeWithProof,
+      MerkleTreeNodeWithProof,
+    ]
+    const {
+      settlementAccount,
+      settlementEpoch,
+      stakeAccountFrom,
+      stakeAccountTo,
+    } = await initFundedSettlement({
+      merkleRoot,
+      maxMerkleNodes: 2 * SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS + 1,
+      maxTotalClaim: 3 * LAMPORTS_PER_SOL,
+      initChunks: async settlement => {
+        // chunks are independent on each other, created in any order
+        for (const chunkIndex of [2, 1]) {
+          const { instruction } = await initSettlementClaimsChunkInstruction({
+            program,
+            settlementAccount: settlement,
+            chunkIndex,
+          })
+          await provider.sendIx([], instruction)
+        }
+        const { instruction: outOfRangeIx } =
+          await initSettlementClaimsChunkInstruction({
+            program,
+            settlementAccount: settlement,
+            chunkIndex: 3,
+          })
+        try {
+          await provider.sendIx([], outOfRangeIx)
+          throw new Error('failure expected; chunk index out of range')
+        } catch (e) {
+          verifyError(e, Errors, 6104, 'chunk does not match')
+        }
+      },
+    })
+    const settlementClaims = await getSettlementClaimsBySettlement(
+      program,
+      settlementAccount,
+    )
+    expect(settlementClaims.account.chunks).toEqual(3)
+    expect(settlementClaims.account.chunksInitialized).toEqual(2)
+    expect(settlementClaims.account.maxRecords).toEqual(
+      SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS,
+    )
+
+    const { instruction: claim0Ix } = await claimSettlementV2Instruction({
+      program,
+      claimAmount: item0.treeNode.data.claim,
+      index: item0.treeNode.data.index,
+      merkleProof: item0.proof,
+      settlementAccount,
+      stakeAccountFrom,
+      stakeAccountTo,
+    })
+    await provider.sendIx([], claim0Ix)
+
+    const claimChunkedParams = {
+      program,
+      claimAmount: itemChunked.treeNode.data.claim,
+      index: itemChunked.treeNode.data.index,
+      merkleProof: itemChunked.proof,
+      settlementAccount,
+      stakeAccountFrom,
+      stakeAccountTo,
+    }
+    const { instruction: wrongChunkIx } =
+      await claimSettlementV2Instruction(claimChunkedParams)
+    try {
+      await provider.sendIx([], wrongChunkIx)
+      throw new Error('failure expected; index is not part of the chunk 0')
+    } catch (e) {
+      verifyError(e, Errors, 6104, 'chunk does not match')
+    }
+    const { instruction: claimChunkedIx } = await claimSettlementV2Instruction({
+      ...claimChunkedParams,
+      settlementClaimsAccount: settlementClaimsChunkAddress(
+        settlementAccount,
+        1,
+        program.programId,
+      )[0],
+    })
+    await provider.sendIx([], claimChunkedIx)
+
+    expect(await isClaimed(program, settlementAccount, 0)).toBe(true)
+    expect(await isClaimed(program, settlementAccount, chunkedIndex)).toBe(true)
+    expect(await isClaimed(program, settlementAccount, chunkedIndex + 1)).toBe(
+      false,
+    )
+
+    warpToEpoch(provider, settlementEpoch + epochsToClaimSettlement + 1)
+    const { instruction: closeNoChunksIx } = await closeSettlementV2Instruction(
+      {
+        program,
+        settlementAccount,
+        settlementClaimsChunks: [],
+      },
+    )
+    try {
+      await provider.sendIx([], closeNoChunksIx)
+      throw new Error('failure expected; chunk accounts not provided')
+    } catch (e) {
+      verifyError(e, Errors, 6106, 'do not match the initialized chunks')
+    }
+
+    const { instruction: closeIx } = await closeSettlementV2Instruction({
+      program,
+      settlementAccount,
+    })
+    await provider.sendIx([], closeIx)
+    await assertNotExist(provider, settlementAccount)
+    for (const chunkIndex of [0, 1, 2]) {
+      await assertNotExist(
+        provider,
+        settlementClaimsChunkAddress(
+          settlementAccount,
+          chunkIndex,
+          program.programId,
+        )[0],
+      )
+    }
+  })
+
+  async function initFundedSettlement({
+    merkleRoot,
+    maxMerkleNodes,
+    maxTotalClaim,
+    initChunks,
+  }: {
+    merkleRoot: Buffer
+    maxMerkleNodes: number
+    maxTotalClaim: number
+    initChunks?: (settlementAccount: PublicKey) => Promise<void>
+  }): Promise<{
+    settlementAccount: PublicKey
+    settlementEpoch: number
+    stakeAccountFrom: PublicKey
+    stakeAccountTo: PublicKey
+  }> {
+    await warpToNextEpoch(provider)
+    const { settlementAccount, epoch: settlementEpoch } =
+      await executeInitSettlement({
+        configAccount,
+        program,
+        provider,
+        voteAccount,
+        operatorAuthority,
+        currentEpoch: await currentEpoch(provider),
+        merkleRoot,
+        maxMerkleNodes,
+        maxTotalClaim,
+      })
+    if (initChunks !== undefined) {
+      await initChunks(settlementAccount)
+    }
+    const stakeAccountFrom = await createBondsFundedStakeAccount({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      lamports: maxTotalClaim + LAMPORTS_PER_SOL * 5,
+    })
+    await warpToNextEpoch(provider) // activate stake account
+    const { instruction, splitStakeAccount } = await fundSettlementInstruction(
+      {
+        program,
+        settlementAccount,
+        stakeAccount: stakeAccountFrom,
+      },
+    )
+    await provider.sendIx(
+      [signer(splitStakeAccount), operatorAuthority],
+      instruction,
+    )
+    const stakeAccountTo = await createDelegatedStakeAccount({
+      provider,
+      lamports: 3 * LAMPORTS_PER_SOL,
+      voteAccount,
+      staker: staker1,
+      withdrawer: withdrawer1,
+    })
+    await warpToNextEpoch(provider) // deactivate stake account
+    return {
+      settlementAccount,
+      settlementEpoch: settlementEpoch.toNumber(),
+      stakeAccountFrom,
+      stakeAccountTo,
+    }
+  }
+})
@@ -0,0 +1,290 @@
+import { verifyError } from '@marinade.finance/anchor-common'
+import { warpOffsetEpoch } from '@marinade.finance/bankrun-utils'
+import { Keypair, LAMPORTS_PER_SOL } from '@solana/web3.js'
+import { getAccount as getTokenAccount } from 'solana-spl-token-modern'
+
+import { initBankrunTest } from './bankrun'
+import {
+  Errors,
+  fundBondTokenInstruction,
+  getWithdrawRequest,
+  updateCollateralExchangeRateInstruction,
+  withdrawBondTokenInstruction,
+} from '../../src'
+import { createVoteAccount } from '../utils/staking'
+import {
+  executeInitBondInstruction,
+  executeInitCollateralMintInstruction,
+  executeInitConfigInstruction,
+  executeInitWithdrawRequestInstruction,
+} from '../utils/testTransactions'
+import { mintTo } from '../utils/tokens'
+
+import type { ValidatorBondsProgram } from '../../src'
+import type { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+import type { PublicKey } from '@solana/web3.js'
+
+describe('Validator Bonds withdraw bond collateral tokens', () => {
+  const withdrawLockupEpochs = 1
+  // 1 token is valued at 2 SOL, 10% haircut
+  const exchangeRate = 2 * LAMPORTS_PER_SOL
+  const haircutBps = 1_000
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let adminAuthority: Keypair
+  let operatorAuthority: Keypair
+  let bondAccount: PublicKey
+  let validatorIdentity: Keypair
+  let mint: PublicKey
+  let collateralMintAccount: PublicKey
+  let bondTokenVault: PublicKey
+  let withdrawerTokenAccount: PublicKey
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+  })
+
+  beforeEach(async () => {
+    ;({ configAccount, adminAuthority, operatorAuthority } =
+      await executeInitConfigInstruction({
+        program,
+        provider,
+        withdrawLockupEpochs,
+      }))
+    const voteAccountData = await createVoteAccount({ provider })
+    validatorIdentity = voteAccountData.validatorIdentity
+    ;({ bondAccount } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount: voteAccountData.voteAccount,
+      validatorIdentity,
+    }))
+    ;({ mint, collateralMintAccount } =
+      await executeInitCollateralMintInstruction({
+        program,
+        provider,
+        configAccount,
+        adminAuthority,
+        operatorAuthority,
+        haircutBps,
+        exchangeRate,
+      }))
+
+    const depositor = Keypair.generate()
+    await mintTo({
+      provider,
+      mint,
+      owner: depositor.publicKey,
+      amount: 10 * LAMPORTS_PER_SOL,
+    })
+    const { instruction, bondTokenVault: vault } =
+      await fundBondTokenInstruction({
+        program,
+        bondAccount,
+        mint,
+        amount: 10 * LAMPORTS_PER_SOL,
+        depositorAuthority: depositor,
+      })
+    await provider.sendIx([depositor], instruction)
+    bondTokenVault = vault
+    withdrawerTokenAccount = await mintTo({
+      provider,
+      mint,
+      owner: validatorIdentity.publicKey,
+      amount: 0,
+    })
+  })
+
+  it('withdraw tokens accounted in withdraw request', async () => {
+    const { withdrawRequestAccount } =
+      await executeInitWithdrawRequestInstruction({
+        program,
+        provider,
+        bondAccount,
+        validatorIdentity,
+        amount: 3 * LAMPORTS_PER_SOL,
+      })
+
+    const { instruction: notReadyIx } = await withdrawBondTokenInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: LAMPORTS_PER_SOL,
+      authority: validatorIdentity,
+    })
+    try {
+      await provider.sendIx([validatorIdentity], notReadyIx)
+      throw new Error('failure expected; lockup not elapsed')
+    } catch (e) {
+      verifyError(e, Errors, 6021, 'not elapsed the epoch lockup period')
+    }
+
+    await warpOffsetEpoch(provider, withdrawLockupEpochs + 1)
+    await updateExchangeRate()
+    const { instruction, withdrawerTokenAccount: withdrawerAta } =
+      await withdrawBondTokenInstruction({
+        program,
+        bondAccount,
+        mint,
+        amount: LAMPORTS_PER_SOL,
+        authority: validatorIdentity,
+      })
+    expect(withdrawerAta).toEqual(withdrawerTokenAccount)
+    await provider.sendIx([validatorIdentity], instruction)
+
+    // 1 token * 2 SOL * (1 - 10%)
+    let withdrawRequestData = await getWithdrawRequest(
+      program,
+      withdrawRequestAccount,
+    )
+    expect(withdrawRequestData.withdrawnAmount).toEqual(
+      1.8 * LAMPORTS_PER_SOL,
+    )
+    expect(
+      (await getTokenAccount(provider.connection, withdrawerTokenAccount))
+        .amount,
+    ).toEqual(LAMPORTS_PER_SOL)
+    expect(
+      (await getTokenAccount(provider.connection, bondTokenVault)).amount,
+    ).toEqual(9 * LAMPORTS_PER_SOL)
+
+    // 1.8 SOL is over the remaining 1.2 SOL of the withdraw request
+    const { instruction: exceededIx } = await withdrawBondTokenInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: LAMPORTS_PER_SOL,
+      authority: validatorIdentity,
+    })
+    try {
+      await provider.sendIx([validatorIdentity], exceededIx)
+      throw new Error('failure expected; withdraw request amount exceeded')
+    } catch (e) {
+      verifyError(e, Errors, 6085, 'exceeds the amount remaining')
+    }
+
+    const { instruction: halfIx } = await withdrawBondTokenInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: LAMPORTS_PER_SOL / 2,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], halfIx)
+    withdrawRequestData = await getWithdrawRequest(
+      program,
+      withdrawRequestAccount,
+    )
+    expect(withdrawRequestData.withdrawnAmount).toEqual(
+      2.7 * LAMPORTS_PER_SOL,
+    )
+  })
+
+  it('withdrawn lamports value is rounded up', async () => {
+    const { withdrawRequestAccount } =
+      await executeInitWithdrawRequestInstruction({
+        program,
+        provider,
+        bondAccount,
+        validatorIdentity,
+        amount: LAMPORTS_PER_SOL,
+      })
+    await warpOffsetEpoch(provider, withdrawLockupEpochs + 1)
+    await updateExchangeRate()
+
+    // 1 base unit is valued 1.8 lamports, accounted as 2 lamports withdrawn
+    const { instruction } = await withdrawBondTokenInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: 1,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], instruction)
+    const withdrawRequestData = await getWithdrawRequest(
+      program,
+      withdrawRequestAccount,
+    )
+    expect(withdrawRequestData.withdrawnAmount).toEqual(2)
+  })
+
+  it('cannot withdraw tokens with wrong authority', async () => {
+    await executeInitWithdrawRequestInstruction({
+      program,
+      provider,
+      bondAccount,
+      validatorIdentity,
+      amount: LAMPORTS_PER_SOL,
+    })
+    await warpOffsetEpoch(provider, withdrawLockupEpochs + 1)
+    await updateExchangeRate()
+
+    const wrongAuthority = Keypair.generate()
+    const { instruction } = await withdrawBondTokenInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: 1,
+      authority: wrongAuthority,
+      withdrawerTokenAccount,
+    })
+    try {
+      await provider.sendIx([wrongAuthority], instruction)
+      throw new Error('failure expected; wrong authority')
+    } catch (e) {
+      verifyError(e, Errors, 6002, 'Invalid authority')
+    }
+  })
+
+  it('cannot withdraw tokens with stale exchange rate', async () => {
+    await executeInitWithdrawRequestInstruction({
+      program,
+      provider,
+      bondAccount,
+      validatorIdentity,
+      amount: LAMPORTS_PER_SOL,
+    })
+    // exchange rate updated at the epoch before the lockup elapsed
+    await warpOffsetEpoch(provider, withdrawLockupEpochs + 1)
+
+    const { instruction } = await withdrawBondTokenInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: LAMPORTS_PER_SOL,
+      authority: validatorIdentity,
+    })
+    try {
+      await provider.sendIx([validatorIdentity], instruction)
+      throw new Error('failure expected; exchange rate stale')
+    } catch (e) {
+      verifyError(e, Errors, 6110, 'not updated for the current epoch')
+    }
+
+    await updateExchangeRate()
+    const { instruction: currentRateIx } = await withdrawBondTokenInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: LAMPORTS_PER_SOL / 2,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], currentRateIx)
+    expect(
+      (await getTokenAccount(provider.connection, withdrawerTokenAccount))
+        .amount,
+    ).toEqual(LAMPORTS_PER_SOL / 2)
+  })
+
+  async function updateExchangeRate() {
+    const { instruction } = await updateCollateralExchangeRateInstruction({
+      program,
+      collateralMintAccount,
+      exchangeRate,
+      operatorAuthority,
+    })
+    await provider.sendIx([operatorAuthority], instruction)
+  }
+})
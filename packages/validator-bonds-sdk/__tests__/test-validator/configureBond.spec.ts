@@ -76,20 +76,18 @@ describe('Validator Bonds configure bond', () => {
     ])
 
     const bondData = await getBond(program, bondAccount)
-    expect(bondData.authority).toEqual(newBondAuthority.publicKey)
+    expect(bondData.authority).toEqual(bondAuthority.publicKey)
     expect(bondData.config).toEqual(configAccount)
     expect(bondData.cpmpe).toEqual(31)
-    expect(bondData.authority).toEqual(newBondAuthority.publicKey)
+    expect(bondData.pendingAuthority).toEqual(newBondAuthority.publicKey)
     expect(bondData.maxStakeWanted).toEqual(1_000_001)
 
     const events = parseCpiEvents(program, executionReturn?.response)
     const e = assertEvent(events, CONFIGURE_BOND_EVENT)
     // Ensure the event was emitted
     assert(e !== undefined)
-    expect(e.bondAuthority).toEqual({
-      old: bondAuthority.publicKey,
-      new: newBondAuthority.publicKey,
-    })
+    expect(e.bondAuthority).toBeNull()
+    expect(e.pendingBondAuthority).toEqual(newBondAuthority.publicKey)
     expect(e.cpmpe).toEqual({
       old: 22,
       new: 31,
@@ -79,7 +79,8 @@ describe('Validator Bonds configure bond with mint', () => {
     )
     expect(tokenData.amount).toEqual(0) // burnt
     const bondData = await getBond(program, bondAccount)
-    expect(bondData.authority).toEqual(newBondAuthority)
+    expect(bondData.authority).toEqual(oldBondData.authority)
+    expect(bondData.pendingAuthority).toEqual(newBondAuthority)
     expect(bondData.cpmpe).toEqual(newCpmpe)
 
     const events = parseCpiEvents(program, executionReturn?.response)
@@ -87,10 +88,8 @@ describe('Validator Bonds configure bond with mint', () => {
     // Ensure the event was emitted
     assert(e !== undefined)
     expect(e.validatorIdentity).toEqual(validatorIdentity.publicKey)
-    expect(e.bondAuthority).toEqual({
-      old: oldBondData.authority,
-      new: newBondAuthority,
-    })
+    expect(e.bondAuthority).toBeNull()
+    expect(e.pendingBondAuthority).toEqual(newBondAuthority)
     expect(e.cpmpe).toEqual({
       old: oldBondData.cpmpe,
       new: newCpmpe,
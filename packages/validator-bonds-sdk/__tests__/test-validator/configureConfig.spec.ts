@@ -1,14 +1,17 @@
 import assert from 'assert'
 
 import { executeTxSimple, transaction } from '@marinade.finance/web3js-1x'
-import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js'
+import { Keypair, PublicKey } from '@solana/web3.js'
 
 import {
   CONFIGURE_CONFIG_EVENT,
+  PROPOSE_CONFIG_AUTHORITY_EVENT,
+  acceptConfigAuthorityInstruction,
   assertEvent,
   configureConfigInstruction,
   getConfig,
   parseCpiEvents,
+  proposeConfigAuthorityInstruction,
 } from '../../src'
 import { executeInitConfigInstruction } from '../utils/testTransactions'
 import { initTest } from '../utils/testValidator'
@@ -49,21 +52,11 @@ describe('Validator Bonds configure config', () => {
   })
 
   it('configure config', async () => {
-    const newAdminAuthority = Keypair.generate()
-    const newOperatorAuthority = PublicKey.unique()
-    const newPauseAuthority = PublicKey.unique()
-
     const tx = await transaction(provider)
     const { instruction } = await configureConfigInstruction({
       program,
       configAccount: configInitialized.publicKey,
       adminAuthority,
-      newOperator: newOperatorAuthority,
-      newAdmin: newAdminAuthority.publicKey,
-      newPauseAuthority: newPauseAuthority,
-      newEpochsToClaimSettlement: 100,
-      newWithdrawLockupEpochs: 103,
-      newMinimumStakeLamports: 1001,
       newMinBondMaxStakeWanted: 1002,
     })
     tx.add(instruction)
@@ -73,46 +66,90 @@ describe('Validator Bonds configure config', () => {
     ])
 
     const configData = await getConfig(program, configInitialized.publicKey)
-    expect(configData.adminAuthority).toEqual(newAdminAuthority.publicKey)
-    expect(configData.operatorAuthority).toEqual(newOperatorAuthority)
-    expect(configData.pauseAuthority).toEqual(newPauseAuthority)
-    expect(configData.paused).toBeFalsy()
-    expect(configData.epochsToClaimSettlement).toEqual(100)
-    expect(configData.withdrawLockupEpochs).toEqual(103)
-    expect(configData.minimumStakeLamports).toEqual(1001)
+    expect(configData.adminAuthority).toEqual(adminAuthority.publicKey)
+    expect(configData.paused).toEqual(0)
+    expect(configData.epochsToClaimSettlement).toEqual(1)
+    expect(configData.withdrawLockupEpochs).toEqual(2)
     expect(configData.minBondMaxStakeWanted).toEqual(1002)
 
     const events = parseCpiEvents(program, executionReturn?.response)
     const e = assertEvent(events, CONFIGURE_CONFIG_EVENT)
     // Ensure the event was emitted
     assert(e !== undefined)
-    expect(e.adminAuthority).toEqual({
-      old: adminAuthority.publicKey,
-      new: newAdminAuthority.publicKey,
-    })
-    expect(e.operatorAuthority).toEqual({
-      old: configInitialized.account.operatorAuthority,
-      new: newOperatorAuthority,
-    })
-    expect(e.pauseAuthority).toEqual({
-      old: configInitialized.account.pauseAuthority,
-      new: newPauseAuthority,
-    })
-    expect(e.epochsToClaimSettlement).toEqual({
-      old: configInitialized.account.epochsToClaimSettlement,
-      new: 100,
-    })
-    expect(e.withdrawLockupEpochs).toEqual({
-      old: configInitialized.account.withdrawLockupEpochs,
-      new: 103,
-    })
-    expect(e.minimumStakeLamports).toEqual({
-      old: LAMPORTS_PER_SOL,
-      new: 1001,
-    })
+    expect(e.adminAuthority).toBeNull()
+    expect(e.epochsToClaimSettlement).toBeNull()
+    expect(e.withdrawLockupEpochs).toBeNull()
+    expect(e.minimumStakeLamports).toBeNull()
     expect(e.minBondMaxStakeWanted).toEqual({
       old: 0,
       new: 1002,
     })
   })
+
+  it('propose and accept config authority', async () => {
+    const newAdminAuthority = Keypair.generate()
+    const newOperatorAuthority = Keypair.generate()
+
+    const tx = await transaction(provider)
+    const { instruction } = await proposeConfigAuthorityInstruction({
+      program,
+      configAccount: configInitialized.publicKey,
+      adminAuthority,
+      newAdmin: newAdminAuthority.publicKey,
+      newOperator: newOperatorAuthority.publicKey,
+    })
+    tx.add(instruction)
+    const executionReturn = await executeTxSimple(provider.connection, tx, [
+      provider.wallet,
+      adminAuthority,
+    ])
+
+    let configData = await getConfig(program, configInitialized.publicKey)
+    expect(configData.adminAuthority).toEqual(adminAuthority.publicKey)
+    expect(configData.pendingAdminAuthority).toEqual(
+      newAdminAuthority.publicKey,
+    )
+    expect(configData.pendingOperatorAuthority).toEqual(
+      newOperatorAuthority.publicKey,
+    )
+    expect(configData.pendingPauseAuthority).toEqual(PublicKey.default)
+
+    const events = parseCpiEvents(program, executionReturn?.response)
+    const e = assertEvent(events, PROPOSE_CONFIG_AUTHORITY_EVENT)
+    assert(e !== undefined)
+    expect(e.pendingAdminAuthority).toEqual(newAdminAuthority.publicKey)
+    expect(e.pendingOperatorAuthority).toEqual(newOperatorAuthority.publicKey)
+    expect(e.pendingPauseAuthority).toBeNull()
+
+    const txAccept = await transaction(provider)
+    const { instruction: acceptIx } = await acceptConfigAuthorityInstruction({
+      program,
+      configAccount: configInitialized.publicKey,
+      newAuthority: newAdminAuthority,
+    })
+    txAccept.add(acceptIx)
+    const acceptReturn = await executeTxSimple(provider.connection, txAccept, [
+      provider.wallet,
+      newAdminAuthority,
+    ])
+
+    configData = await getConfig(program, configInitialized.publicKey)
+    expect(configData.adminAuthority).toEqual(newAdminAuthority.publicKey)
+    expect(configData.pendingAdminAuthority).toEqual(PublicKey.default)
+    expect(configData.operatorAuthority).toEqual(
+      configInitialized.account.operatorAuthority,
+    )
+    expect(configData.pendingOperatorAuthority).toEqual(
+      newOperatorAuthority.publicKey,
+    )
+
+    const acceptEvents = parseCpiEvents(program, acceptReturn?.response)
+    const acceptEvent = assertEvent(acceptEvents, CONFIGURE_CONFIG_EVENT)
+    assert(acceptEvent !== undefined)
+    expect(acceptEvent.adminAuthority).toEqual({
+      old: adminAuthority.publicKey,
+      new: newAdminAuthority.publicKey,
+    })
+    expect(acceptEvent.operatorAuthority).toBeNull()
+  })
 })
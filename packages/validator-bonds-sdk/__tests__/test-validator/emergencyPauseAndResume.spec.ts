@@ -6,14 +6,17 @@ import { Keypair } from '@solana/web3.js'
 import {
   EMERGENCY_PAUSE_EVENT,
   EMERGENCY_RESUME_EVENT,
+  PAUSE_ALL,
   assertEvent,
-  configureConfigInstruction,
   emergencyPauseInstruction,
   emergencyResumeInstruction,
   getConfig,
   parseCpiEvents,
 } from '../../src'
-import { executeInitConfigInstruction } from '../utils/testTransactions'
+import {
+  executeChangeConfigAuthority,
+  executeInitConfigInstruction,
+} from '../utils/testTransactions'
 import { initTest } from '../utils/testValidator'
 
 import type { Config, ValidatorBondsProgram } from '../../src'
@@ -43,14 +46,13 @@ describe('Validator Bonds pause and resume', () => {
       account: await getConfig(program, configAccount),
     }
     pauseAuthority = Keypair.generate()
-    const { instruction: configureConfigIx } = await configureConfigInstruction(
-      {
-        program,
-        configAccount: config.publicKey,
-        newPauseAuthority: pauseAuthority.publicKey,
-      },
-    )
-    await provider.sendIx([adminAuth], configureConfigIx)
+    await executeChangeConfigAuthority({
+      program,
+      provider,
+      configAccount: config.publicKey,
+      adminAuthority: adminAuth,
+      newPauseAuthority: pauseAuthority,
+    })
   })
 
   it('pause and resume', async () => {
@@ -67,7 +69,7 @@ describe('Validator Bonds pause and resume', () => {
     )
 
     let configData = await getConfig(program, config.publicKey)
-    expect(configData.paused).toBeTruthy()
+    expect(configData.paused).toEqual(PAUSE_ALL)
 
     tx = await transaction(provider)
     const { instruction: resumeIx } = await emergencyResumeInstruction({
@@ -82,13 +84,15 @@ describe('Validator Bonds pause and resume', () => {
     )
 
     configData = await getConfig(program, config.publicKey)
-    expect(configData.paused).toBeFalsy()
+    expect(configData.paused).toEqual(0)
 
     const eventsPause = parseCpiEvents(program, executionReturnPause?.response)
     const ePause = assertEvent(eventsPause, EMERGENCY_PAUSE_EVENT)
     assert(ePause !== undefined)
     expect(ePause.config).toEqual(config.publicKey)
     expect(ePause.pauseAuthority).toEqual(pauseAuthority.publicKey)
+    expect(ePause.changedOperations).toEqual(PAUSE_ALL)
+    expect(ePause.pausedOperations).toEqual(PAUSE_ALL)
 
     const eventsResume = parseCpiEvents(
       program,
@@ -97,5 +101,7 @@ describe('Validator Bonds pause and resume', () => {
     const eResume = assertEvent(eventsResume, EMERGENCY_RESUME_EVENT)
     expect(eResume.config).toEqual(config.publicKey)
     expect(eResume.pauseAuthority).toEqual(pauseAuthority.publicKey)
+    expect(eResume.changedOperations).toEqual(PAUSE_ALL)
+    expect(eResume.pausedOperations).toEqual(0)
   })
 })
@@ -77,7 +77,7 @@ describe('Validator Bonds init config', () => {
     expect(configData.adminAuthority).toEqual(adminAuthority)
     expect(configData.operatorAuthority).toEqual(operatorAuthority)
     expect(configData.pauseAuthority).toEqual(adminAuthority)
-    expect(configData.paused).toBeFalsy()
+    expect(configData.paused).toEqual(0)
     expect(configData.epochsToClaimSettlement).toEqual(1)
     expect(configData.withdrawLockupEpochs).toEqual(2)
     expect(configData.slotsToStartSettlementClaiming).toEqual(3)
@@ -2,8 +2,9 @@ import { bs58 } from '@coral-xyz/anchor/dist/cjs/utils/bytes'
 import { createUserAndFund } from '@marinade.finance/web3js-1x'
 import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js'
 import BN from 'bn.js'
+import CryptoJS from 'crypto-js'
 
-import { MerkleTreeNode } from '../../src'
+import { INTERMEDIATE_NODE_PREFIX_BUF, MerkleTreeNode } from '../../src'
 
 import type { ExtendedProvider } from '@marinade.finance/web3js-1x'
 
@@ -347,6 +348,48 @@ export const totalClaimVoteAccount2 = treeNodesVoteAccount2.reduce(
   new BN(0),
 )
 
+/**
+ * Builds merkle tree of the tree nodes in the same way as merkle-tree `MerkleTree::new`
+ * with sorted hashes, i.e., the last node of an odd level is paired with itself.
+ */
+export function buildMerkleTree(treeNodes: MerkleTreeNode[]): {
+  merkleRoot: Buffer
+  items: MerkleTreeNodeWithProof[]
+} {
+  const hashIntermediate = (left: Buffer, right: Buffer): Buffer => {
+    const sorted =
+      Buffer.compare(left, right) <= 0 ? [left, right] : [right, left]
+    const data = Buffer.concat([INTERMEDIATE_NODE_PREFIX_BUF, ...sorted])
+    return MerkleTreeNode.toEncodings(
+      CryptoJS.SHA256(CryptoJS.enc.Hex.parse(data.toString('hex'))),
+    ).buffer
+  }
+  const levels: Buffer[][] = [
+    treeNodes.map(treeNode => treeNode.hashLeafNode().buffer),
+  ]
+  let level = levels[0] as Buffer[]
+  while (level.length > 1) {
+    const nextLevel: Buffer[] = []
+    for (let i = 0; i < level.length; i += 2) {
+      const left = level[i] as Buffer
+      nextLevel.push(hashIntermediate(left, level[i + 1] ?? left))
+    }
+    levels.push(nextLevel)
+    level = nextLevel
+  }
+  const items = treeNodes.map((treeNode, leafIndex) => {
+    const proof: number[][] = []
+    let index = leafIndex
+    for (const levelNodes of levels.slice(0, -1)) {
+      const sibling = levelNodes[index ^ 1] ?? levelNodes[index]
+      proof.push(Array.from(sibling as Buffer))
+      index = Math.floor(index / 2)
+    }
+    return { treeNode, proof }
+  })
+  return { merkleRoot: level[0] as Buffer, items }
+}
+
 export function treeNodeBy(
   voteAccount: PublicKey,
   withdrawer: PublicKey,
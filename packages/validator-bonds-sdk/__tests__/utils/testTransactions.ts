@@ -15,6 +15,7 @@ import {
   createVoteAccountWithIdentity,
   retryOnEpochRewardsPeriod,
 } from './staking'
+import { createMint } from './tokens'
 import {
   cancelWithdrawRequestInstruction,
   fundBondInstruction,
@@ -29,6 +30,10 @@ import {
   initCustomProductInstruction,
   configureCommissionProductInstruction,
   configureCustomProductInstruction,
+  initCollateralMintInstruction,
+  updateCollateralExchangeRateInstruction,
+  proposeConfigAuthorityInstruction,
+  acceptConfigAuthorityInstruction,
 } from '../../src'
 
 import type {
@@ -136,26 +141,12 @@ export async function executeConfigureConfigInstruction({
   provider,
   configAccount,
   adminAuthority,
-  newAdmin,
-  newOperator,
-  newPauseAuthority,
-  newEpochsToClaimSettlement,
-  newSlotsToStartSettlementClaiming,
-  newWithdrawLockupEpochs,
-  newMinimumStakeLamports,
   newMinBondMaxStakeWanted,
 }: {
   program: ValidatorBondsProgram
   provider: ExtendedProvider
   configAccount: PublicKey
   adminAuthority: Keypair
-  newAdmin?: PublicKey
-  newOperator?: PublicKey
-  newPauseAuthority?: PublicKey
-  newEpochsToClaimSettlement?: BN | number
-  newSlotsToStartSettlementClaiming?: BN | number
-  newWithdrawLockupEpochs?: BN | number
-  newMinimumStakeLamports?: BN | number
   newMinBondMaxStakeWanted?: BN | number
 }): Promise<{
   configAccount: PublicKey
@@ -164,13 +155,6 @@ export async function executeConfigureConfigInstruction({
     program,
     configAccount: configAccount,
     adminAuthority: adminAuthority.publicKey,
-    newAdmin,
-    newOperator,
-    newPauseAuthority,
-    newEpochsToClaimSettlement,
-    newSlotsToStartSettlementClaiming,
-    newWithdrawLockupEpochs,
-    newMinimumStakeLamports,
     newMinBondMaxStakeWanted,
   })
   try {
@@ -189,6 +173,52 @@ export async function executeConfigureConfigInstruction({
   }
 }
 
+/**
+ * Changes the config authorities in two steps, the admin proposes
+ * and the new authorities accept the roles.
+ */
+export async function executeChangeConfigAuthority({
+  program,
+  provider,
+  configAccount,
+  adminAuthority,
+  newAdmin,
+  newOperator,
+  newPauseAuthority,
+}: {
+  program: ValidatorBondsProgram
+  provider: ExtendedProvider
+  configAccount: PublicKey
+  adminAuthority: Keypair
+  newAdmin?: Keypair
+  newOperator?: Keypair
+  newPauseAuthority?: Keypair
+}): Promise<void> {
+  const { instruction } = await proposeConfigAuthorityInstruction({
+    program,
+    configAccount,
+    adminAuthority,
+    newAdmin: newAdmin?.publicKey,
+    newOperator: newOperator?.publicKey,
+    newPauseAuthority: newPauseAuthority?.publicKey,
+  })
+  await provider.sendIx([adminAuthority], instruction)
+  // a single accept takes all the roles proposed to the same key
+  const newAuthorities = new Set(
+    [newAdmin, newOperator, newPauseAuthority].filter(
+      (k): k is Keypair => k !== undefined,
+    ),
+  )
+  for (const newAuthority of newAuthorities) {
+    const { instruction: acceptIx } = await acceptConfigAuthorityInstruction({
+      program,
+      configAccount,
+      newAuthority,
+    })
+    await provider.sendIx([newAuthority], acceptIx)
+  }
+}
+
 export async function executeInitBondInstruction({
   program,
   provider,
@@ -828,3 +858,51 @@ export async function executeConfigureCustomProductInstruction({
 export const computeUnitIx = ComputeBudgetProgram.setComputeUnitLimit({
   units: 1_500_000,
 })
+
+export async function executeInitCollateralMintInstruction({
+  program,
+  provider,
+  configAccount,
+  adminAuthority,
+  operatorAuthority,
+  haircutBps = 0,
+  exchangeRate,
+}: {
+  program: ValidatorBondsProgram
+  provider: ExtendedProvider
+  configAccount: PublicKey
+  adminAuthority: Keypair
+  operatorAuthority?: Keypair
+  haircutBps?: number | BN
+  exchangeRate?: number | BN
+}): Promise<{
+  mint: PublicKey
+  collateralMintAccount: PublicKey
+}> {
+  const mint = await createMint({ provider })
+  const { instruction, collateralMintAccount } =
+    await initCollateralMintInstruction({
+      program,
+      configAccount,
+      mint,
+      haircutBps,
+      adminAuthority,
+    })
+  await provider.sendIx([adminAuthority], instruction)
+  if (exchangeRate !== undefined) {
+    if (operatorAuthority === undefined) {
+      throw new Error(
+        'executeInitCollateralMintInstruction: exchange rate requires operatorAuthority',
+      )
+    }
+    const { instruction: rateIx } =
+      await updateCollateralExchangeRateInstruction({
+        program,
+        collateralMintAccount,
+        exchangeRate,
+        operatorAuthority,
+      })
+    await provider.sendIx([operatorAuthority], rateIx)
+  }
+  return { mint, collateralMintAccount }
+}
@@ -0,0 +1,74 @@
+import { Keypair, SystemProgram } from '@solana/web3.js'
+import BN from 'bn.js'
+import {
+  MINT_SIZE,
+  TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountIdempotentInstruction,
+  createInitializeMint2Instruction,
+  createMintToInstruction,
+  getAssociatedTokenAddressSync,
+} from 'solana-spl-token-modern'
+
+import type { ExtendedProvider } from '@marinade.finance/web3js-1x'
+import type { PublicKey } from '@solana/web3.js'
+
+/**
+ * Creates a new SPL token mint with the provider wallet as the mint authority.
+ * The collateral mints are required to have the same decimals as SOL.
+ */
+export async function createMint({
+  provider,
+  decimals = 9,
+}: {
+  provider: ExtendedProvider
+  decimals?: number
+}): Promise<PublicKey> {
+  const mint = Keypair.generate()
+  const createAccountIx = SystemProgram.createAccount({
+    fromPubkey: provider.walletPubkey,
+    newAccountPubkey: mint.publicKey,
+    space: MINT_SIZE,
+    lamports:
+      await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE),
+    programId: TOKEN_PROGRAM_ID,
+  })
+  const initMintIx = createInitializeMint2Instruction(
+    mint.publicKey,
+    decimals,
+    provider.walletPubkey,
+    null,
+  )
+  await provider.sendIx([mint], createAccountIx, initMintIx)
+  return mint.publicKey
+}
+
+/**
+ * Mints tokens to the associated token account of the owner, creating it when not existing.
+ */
+export async function mintTo({
+  provider,
+  mint,
+  owner,
+  amount,
+}: {
+  provider: ExtendedProvider
+  mint: PublicKey
+  owner: PublicKey
+  amount: number | BN
+}): Promise<PublicKey> {
+  const tokenAccount = getAssociatedTokenAddressSync(mint, owner, true)
+  const createAtaIx = createAssociatedTokenAccountIdempotentInstruction(
+    provider.walletPubkey,
+    tokenAccount,
+    owner,
+    mint,
+  )
+  const mintToIx = createMintToInstruction(
+    mint,
+    tokenAccount,
+    provider.walletPubkey,
+    BigInt(new BN(amount).toString()),
+  )
+  await provider.sendIx([], createAtaIx, mintToIx)
+  return tokenAccount
+}
@@ -14,27 +14,27 @@ export type ValidatorBonds = {
   },
   "instructions": [
     {
-      "name": "cancelSettlement",
+      "name": "acceptBondAuthority",
       "discriminator": [
-        33,
-        241,
-        96,
-        62,
-        228,
-        178,
-        1,
-        120
+        8,
+        226,
+        238,
+        31,
+        210,
+        196,
+        148,
+        49
       ],
       "accounts": [
         {
           "name": "config",
-          "writable": true,
           "relations": [
             "bond"
           ]
         },
         {
           "name": "bond",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -64,112 +64,29 @@ export type ValidatorBonds = {
                 "account": "bond"
               }
             ]
-          },
-          "relations": [
-            "settlement"
-          ]
-        },
-        {
-          "name": "settlement",
-          "docs": [
-            "settlement to close whenever the operator decides"
-          ],
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  115,
-                  101,
-                  116,
-                  116,
-                  108,
-                  101,
-                  109,
-                  101,
-                  110,
-                  116,
-                  95,
-                  97,
-                  99,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "bond"
-              },
-              {
-                "kind": "account",
-                "path": "settlement.merkle_root",
-                "account": "settlement"
-              },
-              {
-                "kind": "account",
-                "path": "settlement.epoch_created_for",
-                "account": "settlement"
-              }
-            ]
-          },
-          "relations": [
-            "settlementClaims"
-          ]
-        },
-        {
-          "name": "settlementClaims",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  108,
-                  97,
-                  105,
-                  109,
-                  115,
-                  95,
-                  97,
-                  99,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "settlement"
-              }
-            ]
           }
         },
         {
-          "name": "authority",
+          "name": "newAuthority",
           "docs": [
-            "Cancelling is permitted only to emergency or operator authority"
+            "authority proposed to take the bond authority role"
           ],
           "signer": true
         },
         {
-          "name": "bondsWithdrawerAuthority",
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  111,
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
                   110,
-                  100,
-                  115,
+                  116,
                   95,
                   97,
                   117,
@@ -181,44 +98,39 @@ export type ValidatorBonds = {
                   116,
                   121
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "config"
               }
             ]
           }
         },
         {
-          "name": "rentCollector",
-          "writable": true,
-          "relations": [
-            "settlement"
-          ]
-        },
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptConfigAuthority",
+      "discriminator": [
+        138,
+        34,
+        130,
+        16,
+        252,
+        207,
+        24,
+        90
+      ],
+      "accounts": [
         {
-          "name": "splitRentCollector",
+          "name": "config",
           "writable": true
         },
         {
-          "name": "splitRentRefundAccount",
+          "name": "newAuthority",
           "docs": [
-            "The stake account is funded to the settlement and credited to the bond's validator vote account.",
-            "The lamports are utilized to pay back the rent exemption of the split_stake_account"
+            "authority proposed by the admin to take the role"
           ],
-          "writable": true
-        },
-        {
-          "name": "clock",
-          "address": "SysvarC1ock11111111111111111111111111111111"
-        },
-        {
-          "name": "stakeProgram",
-          "address": "Stake11111111111111111111111111111111111111"
-        },
-        {
-          "name": "stakeHistory",
-          "address": "SysvarStakeHistory1111111111111111111111111"
+          "signer": true
         },
         {
           "name": "eventAuthority",
@@ -256,16 +168,16 @@ export type ValidatorBonds = {
       "args": []
     },
     {
-      "name": "cancelWithdrawRequest",
+      "name": "cancelBondAuthority",
       "discriminator": [
-        167,
-        100,
-        110,
-        128,
-        113,
-        154,
-        224,
-        77
+        159,
+        94,
+        42,
+        75,
+        149,
+        152,
+        28,
+        144
       ],
       "accounts": [
         {
@@ -276,6 +188,7 @@ export type ValidatorBonds = {
         },
         {
           "name": "bond",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -304,61 +217,83 @@ export type ValidatorBonds = {
                 "path": "voteAccount"
               }
             ]
-          },
-          "relations": [
-            "withdrawRequest"
-          ]
-        },
-        {
-          "name": "voteAccount",
-          "relations": [
-            "bond"
-          ]
+          }
         },
         {
           "name": "authority",
           "docs": [
-            "validator vote account validator identity or bond authority may ask for cancelling"
+            "validator vote account validator identity or bond authority may cancel the proposal"
           ],
           "signer": true
         },
         {
-          "name": "withdrawRequest",
-          "writable": true,
+          "name": "voteAccount",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  119,
-                  105,
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
                   116,
-                  104,
-                  100,
-                  114,
-                  97,
-                  119,
                   95,
                   97,
-                  99,
-                  99,
-                  111,
                   117,
-                  110,
-                  116
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "bond"
               }
             ]
           }
         },
         {
-          "name": "rentCollector",
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelConfigAuthority",
+      "discriminator": [
+        13,
+        66,
+        89,
+        171,
+        84,
+        217,
+        93,
+        155
+      ],
+      "accounts": [
+        {
+          "name": "config",
           "writable": true
         },
+        {
+          "name": "adminAuthority",
+          "docs": [
+            "only the admin authority can cancel the proposals"
+          ],
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
         {
           "name": "eventAuthority",
           "pda": {
@@ -395,48 +330,90 @@ export type ValidatorBonds = {
       "args": []
     },
     {
-      "name": "claimSettlementV1",
+      "name": "cancelQueuedConfig",
       "discriminator": [
-        0,
-        13,
-        213,
-        236,
-        10,
-        199,
-        94,
-        48
-      ],
+        80,
+        4,
+        93,
+        16,
+        203,
+        95,
+        15,
+        147
+      ],
       "accounts": [
         {
-          "name": "settlementClaimV1"
+          "name": "config",
+          "writable": true
+        },
+        {
+          "name": "adminAuthority",
+          "docs": [
+            "only the admin authority can cancel the queued change"
+          ],
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
         }
       ],
       "args": []
     },
     {
-      "name": "claimSettlementV2",
+      "name": "cancelSettlement",
       "discriminator": [
-        188,
-        53,
-        132,
-        151,
-        88,
-        50,
-        52,
-        238
+        33,
+        241,
+        96,
+        62,
+        228,
+        178,
+        1,
+        120
       ],
       "accounts": [
         {
           "name": "config",
-          "docs": [
-            "the config account under which the settlement was created"
-          ],
+          "writable": true,
           "relations": [
             "bond"
           ]
         },
         {
           "name": "bond",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -473,6 +450,9 @@ export type ValidatorBonds = {
         },
         {
           "name": "settlement",
+          "docs": [
+            "settlement to close whenever the operator decides"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
@@ -505,8 +485,7 @@ export type ValidatorBonds = {
               },
               {
                 "kind": "account",
-                "path": "settlement.merkle_root",
-                "account": "settlement"
+                "path": "settlement"
               },
               {
                 "kind": "account",
@@ -521,9 +500,6 @@ export type ValidatorBonds = {
         },
         {
           "name": "settlementClaims",
-          "docs": [
-            "deduplication, merkle tree record cannot be claimed twice"
-          ],
           "writable": true,
           "pda": {
             "seeds": [
@@ -554,24 +530,14 @@ export type ValidatorBonds = {
           }
         },
         {
-          "name": "stakeAccountFrom",
-          "docs": [
-            "a stake account that will be withdrawn"
-          ],
-          "writable": true
-        },
-        {
-          "name": "stakeAccountTo",
+          "name": "authority",
           "docs": [
-            "a stake account that will receive the funds"
+            "Cancelling is permitted only to emergency or operator authority"
           ],
-          "writable": true
+          "signer": true
         },
         {
           "name": "bondsWithdrawerAuthority",
-          "docs": [
-            "authority that manages (owns == by being withdrawer authority) all stakes account under the bonds program"
-          ],
           "pda": {
             "seeds": [
               {
@@ -602,8 +568,23 @@ export type ValidatorBonds = {
           }
         },
         {
-          "name": "stakeHistory",
-          "address": "SysvarStakeHistory1111111111111111111111111"
+          "name": "rentCollector",
+          "writable": true,
+          "relations": [
+            "settlement"
+          ]
+        },
+        {
+          "name": "splitRentCollector",
+          "writable": true
+        },
+        {
+          "name": "splitRentRefundAccount",
+          "docs": [
+            "The stake account is funded to the settlement and credited to the bond's validator vote account.",
+            "The lamports are utilized to pay back the rent exemption of the split_stake_account"
+          ],
+          "writable": true
         },
         {
           "name": "clock",
@@ -613,6 +594,10 @@ export type ValidatorBonds = {
           "name": "stakeProgram",
           "address": "Stake11111111111111111111111111111111111111"
         },
+        {
+          "name": "stakeHistory",
+          "address": "SysvarStakeHistory1111111111111111111111111"
+        },
         {
           "name": "eventAuthority",
           "pda": {
@@ -646,41 +631,30 @@ export type ValidatorBonds = {
           "name": "program"
         }
       ],
-      "args": [
-        {
-          "name": "claimSettlementArgs",
-          "type": {
-            "defined": {
-              "name": "claimSettlementV2Args"
-            }
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "claimWithdrawRequest",
+      "name": "cancelWithdrawRequest",
       "discriminator": [
-        48,
-        232,
-        23,
-        52,
-        20,
-        134,
-        122,
-        118
+        167,
+        100,
+        110,
+        128,
+        113,
+        154,
+        224,
+        77
       ],
       "accounts": [
         {
           "name": "config",
-          "docs": [
-            "the config root configuration account"
-          ],
           "relations": [
             "bond"
           ]
         },
         {
           "name": "bond",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -717,14 +691,13 @@ export type ValidatorBonds = {
         {
           "name": "voteAccount",
           "relations": [
-            "bond",
-            "withdrawRequest"
+            "bond"
           ]
         },
         {
           "name": "authority",
           "docs": [
-            "validator vote account node identity or bond authority may claim"
+            "validator vote account validator identity or bond authority may ask for cancelling"
           ],
           "signer": true
         },
@@ -757,89 +730,18 @@ export type ValidatorBonds = {
               {
                 "kind": "account",
                 "path": "bond"
-              }
-            ]
-          }
-        },
-        {
-          "name": "bondsWithdrawerAuthority",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  98,
-                  111,
-                  110,
-                  100,
-                  115,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
               },
               {
                 "kind": "account",
-                "path": "config"
+                "path": "withdrawRequest"
               }
             ]
           }
         },
         {
-          "name": "stakeAccount",
-          "docs": [
-            "stake account to be used to withdraw the funds",
-            "this stake account has to be delegated to the validator vote account associated to the bond"
-          ],
+          "name": "rentCollector",
           "writable": true
         },
-        {
-          "name": "withdrawer",
-          "docs": [
-            "New owner of the stake account, it will be accounted to the withdrawer authority"
-          ]
-        },
-        {
-          "name": "splitStakeAccount",
-          "docs": [
-            "this is a whatever address that does not exist",
-            "when withdrawing needs to split the provided account this will be used as a new stake account"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "splitStakeRentPayer",
-          "docs": [
-            "when the split_stake_account is created the rent for creation is taken from here",
-            "when the split_stake_account is not created then no rent is paid"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "stakeProgram",
-          "address": "Stake11111111111111111111111111111111111111"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "stakeHistory",
-          "address": "SysvarStakeHistory1111111111111111111111111"
-        },
-        {
-          "name": "clock",
-          "address": "SysvarC1ock11111111111111111111111111111111"
-        },
         {
           "name": "eventAuthority",
           "pda": {
@@ -876,20 +778,23 @@ export type ValidatorBonds = {
       "args": []
     },
     {
-      "name": "closeSettlementV2",
+      "name": "claimSettlementBatch",
       "discriminator": [
-        125,
-        212,
-        89,
-        37,
-        31,
-        244,
-        191,
-        179
+        92,
+        117,
+        167,
+        75,
+        110,
+        98,
+        18,
+        220
       ],
       "accounts": [
         {
           "name": "config",
+          "docs": [
+            "the config account under which the settlement was created"
+          ],
           "relations": [
             "bond"
           ]
@@ -932,9 +837,6 @@ export type ValidatorBonds = {
         },
         {
           "name": "settlement",
-          "docs": [
-            "settlement to close when expired"
-          ],
           "writable": true,
           "pda": {
             "seeds": [
@@ -967,8 +869,7 @@ export type ValidatorBonds = {
               },
               {
                 "kind": "account",
-                "path": "settlement.merkle_root",
-                "account": "settlement"
+                "path": "settlement"
               },
               {
                 "kind": "account",
@@ -983,6 +884,10 @@ export type ValidatorBonds = {
         },
         {
           "name": "settlementClaims",
+          "docs": [
+            "deduplication, merkle tree record cannot be claimed twice",
+            "(for chunked bitmap the chunk account covering the claimed index)"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
@@ -1008,12 +913,33 @@ export type ValidatorBonds = {
               {
                 "kind": "account",
                 "path": "settlement"
-              }
+              },
+              {
+                "kind": "account",
+                "path": "settlementClaims"
+              }
             ]
           }
         },
+        {
+          "name": "stakeAccountFrom",
+          "docs": [
+            "a stake account that will be withdrawn"
+          ],
+          "writable": true
+        },
+        {
+          "name": "stakeAccountTo",
+          "docs": [
+            "a stake account that will receive the funds"
+          ],
+          "writable": true
+        },
         {
           "name": "bondsWithdrawerAuthority",
+          "docs": [
+            "authority that manages (owns == by being withdrawer authority) all stakes account under the bonds program"
+          ],
           "pda": {
             "seeds": [
               {
@@ -1044,23 +970,8 @@ export type ValidatorBonds = {
           }
         },
         {
-          "name": "rentCollector",
-          "writable": true,
-          "relations": [
-            "settlement"
-          ]
-        },
-        {
-          "name": "splitRentCollector",
-          "writable": true
-        },
-        {
-          "name": "splitRentRefundAccount",
-          "docs": [
-            "The stake account is funded to the settlement and credited to the bond's validator vote account.",
-            "The lamports are utilized to pay back the rent exemption of the split_stake_account, which can be created upon funding the settlement."
-          ],
-          "writable": true
+          "name": "stakeHistory",
+          "address": "SysvarStakeHistory1111111111111111111111111"
         },
         {
           "name": "clock",
@@ -1070,10 +981,6 @@ export type ValidatorBonds = {
           "name": "stakeProgram",
           "address": "Stake11111111111111111111111111111111111111"
         },
-        {
-          "name": "stakeHistory",
-          "address": "SysvarStakeHistory1111111111111111111111111"
-        },
         {
           "name": "eventAuthority",
           "pda": {
@@ -1107,30 +1014,41 @@ export type ValidatorBonds = {
           "name": "program"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "claimSettlementBatchArgs",
+          "type": {
+            "defined": {
+              "name": "claimSettlementBatchArgs"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "configureBond",
+      "name": "claimSettlementRedirect",
       "discriminator": [
-        228,
-        108,
-        79,
-        242,
-        82,
-        54,
-        105,
-        65
+        142,
+        241,
+        148,
+        5,
+        31,
+        150,
+        245,
+        206
       ],
       "accounts": [
         {
           "name": "config",
+          "docs": [
+            "the config account under which the settlement was created"
+          ],
           "relations": [
             "bond"
           ]
         },
         {
           "name": "bond",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1156,98 +1074,80 @@ export type ValidatorBonds = {
               },
               {
                 "kind": "account",
-                "path": "voteAccount"
+                "path": "bond.vote_account",
+                "account": "bond"
               }
             ]
-          }
-        },
-        {
-          "name": "authority",
-          "docs": [
-            "validator vote account validator identity or bond authority may change the account"
-          ],
-          "signer": true
-        },
-        {
-          "name": "voteAccount",
+          },
           "relations": [
-            "bond"
+            "settlement"
           ]
         },
         {
-          "name": "eventAuthority",
+          "name": "settlement",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  95,
-                  95,
+                  115,
                   101,
-                  118,
+                  116,
+                  116,
+                  108,
+                  101,
+                  109,
                   101,
                   110,
                   116,
                   95,
                   97,
-                  117,
-                  116,
-                  104,
+                  99,
+                  99,
                   111,
-                  114,
-                  105,
-                  116,
-                  121
+                  117,
+                  110,
+                  116
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "path": "settlement.epoch_created_for",
+                "account": "settlement"
               }
             ]
-          }
-        },
-        {
-          "name": "program"
-        }
-      ],
-      "args": [
-        {
-          "name": "configureBondArgs",
-          "type": {
-            "defined": {
-              "name": "configureBondArgs"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "configureBondProduct",
-      "discriminator": [
-        167,
-        119,
-        141,
-        123,
-        76,
-        109,
-        241,
-        133
-      ],
-      "accounts": [
-        {
-          "name": "config",
+          },
           "relations": [
-            "bond"
+            "settlementClaims"
           ]
         },
         {
-          "name": "bond",
+          "name": "settlementClaims",
+          "docs": [
+            "deduplication, merkle tree record cannot be claimed twice",
+            "(for chunked bitmap the chunk account covering the claimed index)"
+          ],
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  111,
-                  110,
-                  100,
+                  99,
+                  108,
+                  97,
+                  105,
+                  109,
+                  115,
                   95,
                   97,
                   99,
@@ -1260,27 +1160,41 @@ export type ValidatorBonds = {
               },
               {
                 "kind": "account",
-                "path": "config"
+                "path": "settlement"
               },
               {
                 "kind": "account",
-                "path": "voteAccount"
+                "path": "settlementClaims"
               }
             ]
-          },
-          "relations": [
-            "bondProduct"
-          ]
+          }
         },
         {
-          "name": "voteAccount",
-          "relations": [
-            "bond"
-          ]
+          "name": "stakeAccountFrom",
+          "docs": [
+            "a stake account that will be withdrawn"
+          ],
+          "writable": true
         },
         {
-          "name": "bondProduct",
-          "writable": true,
+          "name": "withdrawAuthority",
+          "docs": [
+            "withdraw authority of the merkle tree node; merkle root verification"
+          ],
+          "signer": true
+        },
+        {
+          "name": "claimTo",
+          "docs": [
+            "an account that will receive the funds"
+          ],
+          "writable": true
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "docs": [
+            "authority that manages (owns == by being withdrawer authority) all stakes account under the bonds program"
+          ],
           "pda": {
             "seeds": [
               {
@@ -1290,34 +1204,37 @@ export type ValidatorBonds = {
                   111,
                   110,
                   100,
+                  115,
                   95,
-                  112,
-                  114,
-                  111,
-                  100,
+                  97,
                   117,
-                  99,
-                  116
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
                 ]
               },
               {
                 "kind": "account",
-                "path": "bond"
-              },
-              {
-                "kind": "account",
-                "path": "bond_product.product_type",
-                "account": "bondProduct"
+                "path": "config"
               }
             ]
           }
         },
         {
-          "name": "authority",
-          "docs": [
-            "validator vote account validator identity or bond authority may change the account"
-          ],
-          "signer": true
+          "name": "stakeHistory",
+          "address": "SysvarStakeHistory1111111111111111111111111"
+        },
+        {
+          "name": "clock",
+          "address": "SysvarC1ock11111111111111111111111111111111"
+        },
+        {
+          "name": "stakeProgram",
+          "address": "Stake11111111111111111111111111111111111111"
         },
         {
           "name": "eventAuthority",
@@ -1354,37 +1271,58 @@ export type ValidatorBonds = {
       ],
       "args": [
         {
-          "name": "configureBondProductArgs",
+          "name": "claimSettlementArgs",
           "type": {
             "defined": {
-              "name": "configureBondProductArgs"
+              "name": "claimSettlementV2Args"
             }
           }
         }
       ]
     },
     {
-      "name": "configureBondWithMint",
+      "name": "claimSettlementV1",
       "discriminator": [
-        48,
-        189,
-        230,
-        39,
-        112,
-        33,
-        227,
-        8
+        0,
+        13,
+        213,
+        236,
+        10,
+        199,
+        94,
+        48
+      ],
+      "accounts": [
+        {
+          "name": "settlementClaimV1"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimSettlementV2",
+      "discriminator": [
+        188,
+        53,
+        132,
+        151,
+        88,
+        50,
+        52,
+        238
       ],
       "accounts": [
         {
           "name": "config",
+          "docs": [
+            "the config account under which the settlement was created"
+          ],
           "relations": [
             "bond"
           ]
         },
         {
           "name": "bond",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1410,26 +1348,39 @@ export type ValidatorBonds = {
               },
               {
                 "kind": "account",
-                "path": "voteAccount"
+                "path": "bond.vote_account",
+                "account": "bond"
               }
             ]
-          }
+          },
+          "relations": [
+            "settlement"
+          ]
         },
         {
-          "name": "mint",
+          "name": "settlement",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  111,
+                  115,
+                  101,
+                  116,
+                  116,
+                  108,
+                  101,
+                  109,
+                  101,
                   110,
-                  100,
+                  116,
                   95,
-                  109,
-                  105,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
                   110,
                   116
                 ]
@@ -1439,47 +1390,88 @@ export type ValidatorBonds = {
                 "path": "bond"
               },
               {
-                "kind": "arg",
-                "path": "params.validator_identity"
+                "kind": "account",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "path": "settlement.epoch_created_for",
+                "account": "settlement"
               }
             ]
-          }
-        },
-        {
-          "name": "voteAccount",
+          },
           "relations": [
-            "bond"
+            "settlementClaims"
           ]
         },
         {
-          "name": "tokenAccount",
+          "name": "settlementClaims",
           "docs": [
-            "token account to burn bond mint configuration tokens from"
+            "deduplication, merkle tree record cannot be claimed twice",
+            "(for chunked bitmap the chunk account covering the claimed index)"
           ],
-          "writable": true
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  108,
+                  97,
+                  105,
+                  109,
+                  115,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "path": "settlementClaims"
+              }
+            ]
+          }
         },
         {
-          "name": "tokenAuthority",
-          "signer": true
+          "name": "stakeAccountFrom",
+          "docs": [
+            "a stake account that will be withdrawn"
+          ],
+          "writable": true
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "stakeAccountTo",
+          "docs": [
+            "a stake account that will receive the funds"
+          ],
+          "writable": true
         },
         {
-          "name": "eventAuthority",
+          "name": "bondsWithdrawerAuthority",
+          "docs": [
+            "authority that manages (owns == by being withdrawer authority) all stakes account under the bonds program"
+          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  95,
-                  95,
-                  101,
-                  118,
-                  101,
+                  98,
+                  111,
                   110,
-                  116,
+                  100,
+                  115,
                   95,
                   97,
                   117,
@@ -1491,51 +1483,25 @@ export type ValidatorBonds = {
                   116,
                   121
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
               }
             ]
           }
         },
         {
-          "name": "program"
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": {
-              "name": "configureBondWithMintArgs"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "configureConfig",
-      "discriminator": [
-        198,
-        98,
-        161,
-        165,
-        137,
-        200,
-        230,
-        203
-      ],
-      "accounts": [
+          "name": "stakeHistory",
+          "address": "SysvarStakeHistory1111111111111111111111111"
+        },
         {
-          "name": "config",
-          "writable": true
+          "name": "clock",
+          "address": "SysvarC1ock11111111111111111111111111111111"
         },
         {
-          "name": "adminAuthority",
-          "docs": [
-            "only the admin authority can change the config params"
-          ],
-          "signer": true,
-          "relations": [
-            "config"
-          ]
+          "name": "stakeProgram",
+          "address": "Stake11111111111111111111111111111111111111"
         },
         {
           "name": "eventAuthority",
@@ -1572,166 +1538,102 @@ export type ValidatorBonds = {
       ],
       "args": [
         {
-          "name": "configureConfigArgs",
+          "name": "claimSettlementArgs",
           "type": {
             "defined": {
-              "name": "configureConfigArgs"
+              "name": "claimSettlementV2Args"
             }
           }
         }
       ]
     },
     {
-      "name": "emergencyPause",
+      "name": "claimWithdrawRequest",
       "discriminator": [
-        21,
-        143,
-        27,
-        142,
-        200,
-        181,
-        210,
-        255
+        48,
+        232,
+        23,
+        52,
+        20,
+        134,
+        122,
+        118
       ],
       "accounts": [
         {
           "name": "config",
-          "writable": true
-        },
-        {
-          "name": "pauseAuthority",
-          "signer": true,
+          "docs": [
+            "the config root configuration account"
+          ],
           "relations": [
-            "config"
+            "bond"
           ]
         },
         {
-          "name": "eventAuthority",
+          "name": "bond",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  95,
-                  95,
-                  101,
-                  118,
-                  101,
+                  98,
+                  111,
                   110,
-                  116,
+                  100,
                   95,
                   97,
-                  117,
-                  116,
-                  104,
+                  99,
+                  99,
                   111,
-                  114,
-                  105,
-                  116,
-                  121
+                  117,
+                  110,
+                  116
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "voteAccount"
               }
             ]
-          }
-        },
-        {
-          "name": "program"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "emergencyResume",
-      "discriminator": [
-        0,
-        243,
-        48,
-        185,
-        6,
-        73,
-        190,
-        83
-      ],
-      "accounts": [
-        {
-          "name": "config",
-          "writable": true
-        },
-        {
-          "name": "pauseAuthority",
-          "signer": true,
+          },
           "relations": [
-            "config"
+            "withdrawRequest"
           ]
         },
         {
-          "name": "eventAuthority",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  95,
-                  95,
-                  101,
-                  118,
-                  101,
-                  110,
-                  116,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "program"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "fundBond",
-      "discriminator": [
-        58,
-        44,
-        212,
-        175,
-        30,
-        17,
-        68,
-        62
-      ],
-      "accounts": [
-        {
-          "name": "config",
+          "name": "voteAccount",
           "relations": [
-            "bond"
+            "bond",
+            "withdrawRequest"
           ]
         },
         {
-          "name": "bond",
+          "name": "authority",
           "docs": [
-            "bond account to be deposited to with the provided stake account"
+            "validator vote account node identity or bond authority may claim"
           ],
+          "signer": true
+        },
+        {
+          "name": "withdrawRequest",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  111,
-                  110,
+                  119,
+                  105,
+                  116,
+                  104,
                   100,
+                  114,
+                  97,
+                  119,
                   95,
                   97,
                   99,
@@ -1744,21 +1646,17 @@ export type ValidatorBonds = {
               },
               {
                 "kind": "account",
-                "path": "config"
+                "path": "bond"
               },
               {
                 "kind": "account",
-                "path": "bond.vote_account",
-                "account": "bond"
+                "path": "withdrawRequest"
               }
             ]
           }
         },
         {
           "name": "bondsWithdrawerAuthority",
-          "docs": [
-            "new owner of the stake_account, it's the bonds withdrawer authority"
-          ],
           "pda": {
             "seeds": [
               {
@@ -1791,28 +1689,50 @@ export type ValidatorBonds = {
         {
           "name": "stakeAccount",
           "docs": [
-            "stake account to be deposited"
+            "stake account to be used to withdraw the funds",
+            "this stake account has to be delegated to the validator vote account associated to the bond"
           ],
           "writable": true
         },
         {
-          "name": "stakeAuthority",
+          "name": "withdrawer",
           "docs": [
-            "authority signature permitting to change the stake_account authorities"
+            "New owner of the stake account, it will be accounted to the withdrawer authority"
+          ]
+        },
+        {
+          "name": "splitStakeAccount",
+          "docs": [
+            "this is a whatever address that does not exist",
+            "when withdrawing needs to split the provided account this will be used as a new stake account"
           ],
+          "writable": true,
           "signer": true
         },
         {
-          "name": "clock",
-          "address": "SysvarC1ock11111111111111111111111111111111"
+          "name": "splitStakeRentPayer",
+          "docs": [
+            "when the split_stake_account is created the rent for creation is taken from here",
+            "when the split_stake_account is not created then no rent is paid"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "stakeProgram",
+          "address": "Stake11111111111111111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
           "name": "stakeHistory",
           "address": "SysvarStakeHistory1111111111111111111111111"
         },
         {
-          "name": "stakeProgram",
-          "address": "Stake11111111111111111111111111111111111111"
+          "name": "clock",
+          "address": "SysvarC1ock11111111111111111111111111111111"
         },
         {
           "name": "eventAuthority",
@@ -1850,16 +1770,16 @@ export type ValidatorBonds = {
       "args": []
     },
     {
-      "name": "fundSettlement",
+      "name": "closeBond",
       "discriminator": [
-        179,
-        146,
-        113,
-        34,
-        30,
-        92,
-        26,
-        19
+        47,
+        18,
+        254,
+        114,
+        33,
+        116,
+        141,
+        41
       ],
       "accounts": [
         {
@@ -1870,6 +1790,7 @@ export type ValidatorBonds = {
         },
         {
           "name": "bond",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1898,10 +1819,7 @@ export type ValidatorBonds = {
                 "path": "voteAccount"
               }
             ]
-          },
-          "relations": [
-            "settlement"
-          ]
+          }
         },
         {
           "name": "voteAccount",
@@ -1910,23 +1828,27 @@ export type ValidatorBonds = {
           ]
         },
         {
-          "name": "settlement",
-          "writable": true,
+          "name": "authority",
+          "docs": [
+            "validator vote account validator identity or bond authority may close the bond"
+          ],
+          "signer": true
+        },
+        {
+          "name": "withdrawRequest",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  115,
-                  101,
-                  116,
-                  116,
-                  108,
-                  101,
-                  109,
-                  101,
-                  110,
+                  119,
+                  105,
                   116,
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
                   95,
                   97,
                   99,
@@ -1940,168 +1862,52 @@ export type ValidatorBonds = {
               {
                 "kind": "account",
                 "path": "bond"
-              },
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondLedger",
+          "pda": {
+            "seeds": [
               {
-                "kind": "account",
-                "path": "settlement.merkle_root",
-                "account": "settlement"
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101,
+                  114
+                ]
               },
               {
                 "kind": "account",
-                "path": "settlement.epoch_created_for",
-                "account": "settlement"
+                "path": "bond"
               }
             ]
           }
         },
         {
-          "name": "operatorAuthority",
-          "docs": [
-            "operator signer authority is allowed to fund the settlement account"
-          ],
-          "signer": true,
-          "relations": [
-            "config"
-          ]
-        },
-        {
-          "name": "stakeAccount",
-          "docs": [
-            "stake account to be funded into the settlement"
-          ],
+          "name": "rentCollector",
           "writable": true
         },
         {
-          "name": "settlementStakerAuthority",
-          "docs": [
-            "the settlement stake authority differentiates between deposited and funded stake accounts",
-            "deposited accounts have the bonds_withdrawer_authority, while funded accounts have the settlement_staker_authority"
-          ],
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  115,
-                  101,
-                  116,
-                  116,
-                  108,
+                  95,
+                  95,
                   101,
-                  109,
-                  101,
-                  110,
-                  116,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "settlement"
-              }
-            ]
-          }
-        },
-        {
-          "name": "bondsWithdrawerAuthority",
-          "docs": [
-            "authority that manages (owns) all stakes account under the bonds program"
-          ],
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  98,
-                  111,
-                  110,
-                  100,
-                  115,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "config"
-              }
-            ]
-          }
-        },
-        {
-          "name": "splitStakeAccount",
-          "docs": [
-            "if an account that does not exist is provided, it will be initialized as a stake account (with the necessary signature)",
-            "the split_stake_account is required when the provided stake_account contains more lamports than necessary to fund the settlement",
-            "in this case, the excess lamports from the stake account are split into the new split_stake_account,",
-            "if the split_stake_account is not needed, the rent payer is refunded back within tx"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "splitStakeRentPayer",
-          "docs": [
-            "the rent exempt payer of the split_stake_account creation",
-            "if the split_stake_account is not needed (no leftover lamports on funding), then the rent payer is refunded",
-            "if the split_stake_account is needed to spill out over funding of the settlement,",
-            "then the rent payer is refunded when the settlement is closed"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "stakeHistory",
-          "address": "SysvarStakeHistory1111111111111111111111111"
-        },
-        {
-          "name": "clock",
-          "address": "SysvarC1ock11111111111111111111111111111111"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
-        },
-        {
-          "name": "stakeProgram",
-          "address": "Stake11111111111111111111111111111111111111"
-        },
-        {
-          "name": "stakeConfig",
-          "address": "StakeConfig11111111111111111111111111111111"
-        },
-        {
-          "name": "eventAuthority",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  95,
-                  95,
-                  101,
-                  118,
+                  118,
                   101,
                   110,
                   116,
@@ -2127,36 +1933,24 @@ export type ValidatorBonds = {
       "args": []
     },
     {
-      "name": "initBond",
+      "name": "closeBondLedger",
       "discriminator": [
-        95,
-        93,
-        93,
-        181,
-        221,
-        36,
-        126,
-        64
+        227,
+        51,
+        44,
+        113,
+        137,
+        175,
+        59,
+        107
       ],
       "accounts": [
         {
           "name": "config",
-          "docs": [
-            "the config account under which the bond is created"
+          "relations": [
+            "bond"
           ]
         },
-        {
-          "name": "voteAccount"
-        },
-        {
-          "name": "validatorIdentity",
-          "docs": [
-            "permission-ed: the validator identity signs the instruction, InitBondArgs applied",
-            "permission-less: no signature, default init bond configuration"
-          ],
-          "signer": true,
-          "optional": true
-        },
         {
           "name": "bond",
           "writable": true,
@@ -2188,19 +1982,55 @@ export type ValidatorBonds = {
                 "path": "voteAccount"
               }
             ]
+          },
+          "relations": [
+            "bondLedger"
+          ]
+        },
+        {
+          "name": "voteAccount",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "bondLedger",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              }
+            ]
           }
         },
         {
-          "name": "rentPayer",
+          "name": "authority",
           "docs": [
-            "rent exempt payer of validator bond account creation"
+            "validator vote account validator identity or bond authority may close the bond ledger"
           ],
-          "writable": true,
           "signer": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "rentCollector",
+          "writable": true
         },
         {
           "name": "eventAuthority",
@@ -2235,35 +2065,23 @@ export type ValidatorBonds = {
           "name": "program"
         }
       ],
-      "args": [
-        {
-          "name": "initBondArgs",
-          "type": {
-            "defined": {
-              "name": "initBondArgs"
-            }
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "initBondProduct",
+      "name": "closeBondProduct",
       "discriminator": [
-        228,
-        35,
-        205,
-        119,
-        63,
-        209,
-        72,
-        56
+        1,
+        244,
+        85,
+        158,
+        191,
+        117,
+        201,
+        79
       ],
       "accounts": [
         {
           "name": "config",
-          "docs": [
-            "The config account that the bond belongs to"
-          ],
           "relations": [
             "bond"
           ]
@@ -2299,7 +2117,10 @@ export type ValidatorBonds = {
                 "path": "voteAccount"
               }
             ]
-          }
+          },
+          "relations": [
+            "bondProduct"
+          ]
         },
         {
           "name": "voteAccount",
@@ -2309,9 +2130,6 @@ export type ValidatorBonds = {
         },
         {
           "name": "bondProduct",
-          "docs": [
-            "The bond product account being created"
-          ],
           "writable": true,
           "pda": {
             "seeds": [
@@ -2337,8 +2155,9 @@ export type ValidatorBonds = {
                 "path": "bond"
               },
               {
-                "kind": "arg",
-                "path": "args.product_type"
+                "kind": "account",
+                "path": "bond_product.product_type",
+                "account": "bondProduct"
               }
             ]
           }
@@ -2346,23 +2165,13 @@ export type ValidatorBonds = {
         {
           "name": "authority",
           "docs": [
-            "permission-ed: the validator identity or bond authority signs the instruction, InitBondProductArgs applied",
-            "permission-less: no signature, default configuration for the bond product"
-          ],
-          "signer": true,
-          "optional": true
-        },
-        {
-          "name": "rentPayer",
-          "docs": [
-            "Rent payer for the bond product account creation"
+            "validator vote account validator identity or bond authority may close the bond product"
           ],
-          "writable": true,
           "signer": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "rentCollector",
+          "writable": true
         },
         {
           "name": "eventAuthority",
@@ -2397,102 +2206,19 @@ export type ValidatorBonds = {
           "name": "program"
         }
       ],
-      "args": [
-        {
-          "name": "initBondProductArgs",
-          "type": {
-            "defined": {
-              "name": "initBondProductArgs"
-            }
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "initConfig",
+      "name": "closeSettlementV2",
       "discriminator": [
-        23,
-        235,
-        115,
-        232,
-        168,
-        96,
-        1,
-        231
-      ],
-      "accounts": [
-        {
-          "name": "config",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "rentPayer",
-          "docs": [
-            "rent exempt payer for the config account"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "eventAuthority",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  95,
-                  95,
-                  101,
-                  118,
-                  101,
-                  110,
-                  116,
-                  95,
-                  97,
-                  117,
-                  116,
-                  104,
-                  111,
-                  114,
-                  105,
-                  116,
-                  121
-                ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "program"
-        }
-      ],
-      "args": [
-        {
-          "name": "initConfigArgs",
-          "type": {
-            "defined": {
-              "name": "initConfigArgs"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "initSettlement",
-      "discriminator": [
-        152,
-        178,
-        0,
-        65,
-        52,
-        210,
-        247,
-        58
+        125,
+        212,
+        89,
+        37,
+        31,
+        244,
+        191,
+        179
       ],
       "accounts": [
         {
@@ -2503,6 +2229,7 @@ export type ValidatorBonds = {
         },
         {
           "name": "bond",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -2532,10 +2259,16 @@ export type ValidatorBonds = {
                 "account": "bond"
               }
             ]
-          }
+          },
+          "relations": [
+            "settlement"
+          ]
         },
         {
           "name": "settlement",
+          "docs": [
+            "settlement to close when expired or fully claimed"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
@@ -2567,15 +2300,19 @@ export type ValidatorBonds = {
                 "path": "bond"
               },
               {
-                "kind": "arg",
-                "path": "params.merkle_root"
+                "kind": "account",
+                "path": "settlement"
               },
               {
-                "kind": "arg",
-                "path": "params.epoch"
+                "kind": "account",
+                "path": "settlement.epoch_created_for",
+                "account": "settlement"
               }
             ]
-          }
+          },
+          "relations": [
+            "settlementClaims"
+          ]
         },
         {
           "name": "settlementClaims",
@@ -2609,26 +2346,66 @@ export type ValidatorBonds = {
           }
         },
         {
-          "name": "operatorAuthority",
-          "docs": [
-            "operator signer authority that is allowed to create the settlement account"
-          ],
-          "signer": true,
+          "name": "bondsWithdrawerAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  115,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentCollector",
+          "writable": true,
           "relations": [
-            "config"
+            "settlement"
           ]
         },
         {
-          "name": "rentPayer",
+          "name": "splitRentCollector",
+          "writable": true
+        },
+        {
+          "name": "splitRentRefundAccount",
           "docs": [
-            "rent exempt payer of account creation"
+            "The stake account is funded to the settlement and credited to the bond's validator vote account.",
+            "The lamports are utilized to pay back the rent exemption of the split_stake_account, which can be created upon funding the settlement."
           ],
-          "writable": true,
-          "signer": true
+          "writable": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "clock",
+          "address": "SysvarC1ock11111111111111111111111111111111"
+        },
+        {
+          "name": "stakeProgram",
+          "address": "Stake11111111111111111111111111111111111111"
+        },
+        {
+          "name": "stakeHistory",
+          "address": "SysvarStakeHistory1111111111111111111111111"
         },
         {
           "name": "eventAuthority",
@@ -2663,41 +2440,30 @@ export type ValidatorBonds = {
           "name": "program"
         }
       ],
-      "args": [
-        {
-          "name": "initSettlementArgs",
-          "type": {
-            "defined": {
-              "name": "initSettlementArgs"
-            }
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "initWithdrawRequest",
+      "name": "configureBond",
       "discriminator": [
-        142,
-        31,
-        222,
-        215,
-        83,
+        228,
+        108,
         79,
-        34,
-        49
+        242,
+        82,
+        54,
+        105,
+        65
       ],
       "accounts": [
         {
           "name": "config",
-          "docs": [
-            "the config account under which the bond was created"
-          ],
           "relations": [
             "bond"
           ]
         },
         {
           "name": "bond",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -2728,63 +2494,18 @@ export type ValidatorBonds = {
             ]
           }
         },
-        {
-          "name": "voteAccount",
-          "relations": [
-            "bond"
-          ]
-        },
         {
           "name": "authority",
           "docs": [
-            "validator vote account node identity or bond authority may ask for the withdrawal"
-          ],
-          "signer": true
-        },
-        {
-          "name": "withdrawRequest",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  119,
-                  105,
-                  116,
-                  104,
-                  100,
-                  114,
-                  97,
-                  119,
-                  95,
-                  97,
-                  99,
-                  99,
-                  111,
-                  117,
-                  110,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "bond"
-              }
-            ]
-          }
-        },
-        {
-          "name": "rentPayer",
-          "docs": [
-            "rent exempt payer of withdraw request account creation"
+            "validator vote account validator identity or bond authority may change the account"
           ],
-          "writable": true,
           "signer": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "voteAccount",
+          "relations": [
+            "bond"
+          ]
         },
         {
           "name": "eventAuthority",
@@ -2821,59 +2542,125 @@ export type ValidatorBonds = {
       ],
       "args": [
         {
-          "name": "createWithdrawRequestArgs",
+          "name": "configureBondArgs",
           "type": {
             "defined": {
-              "name": "initWithdrawRequestArgs"
+              "name": "configureBondArgs"
             }
           }
         }
       ]
     },
     {
-      "name": "mergeStake",
+      "name": "configureBondProduct",
       "discriminator": [
-        14,
-        3,
-        146,
-        23,
-        163,
-        105,
-        246,
-        99
+        167,
+        119,
+        141,
+        123,
+        76,
+        109,
+        241,
+        133
       ],
       "accounts": [
         {
           "name": "config",
-          "docs": [
-            "the config account under which the bond was created"
+          "relations": [
+            "bond"
           ]
         },
         {
-          "name": "sourceStake",
-          "writable": true
-        },
-        {
-          "name": "destinationStake",
-          "writable": true
+          "name": "bond",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "voteAccount"
+              }
+            ]
+          },
+          "relations": [
+            "bondProduct"
+          ]
         },
         {
-          "name": "stakerAuthority",
-          "docs": [
-            "bonds program authority PDA address: settlement staker or bonds withdrawer"
+          "name": "voteAccount",
+          "relations": [
+            "bond"
           ]
         },
         {
-          "name": "stakeHistory",
-          "address": "SysvarStakeHistory1111111111111111111111111"
+          "name": "bondProduct",
+          "docs": [
+            "the account is enlarged when the stored configurations do not fit, e.g., the product created",
+            "before the pending config was introduced"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  112,
+                  114,
+                  111,
+                  100,
+                  117,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "path": "bond_product.product_type",
+                "account": "bondProduct"
+              }
+            ]
+          }
         },
         {
-          "name": "clock",
-          "address": "SysvarC1ock11111111111111111111111111111111"
+          "name": "authority",
+          "docs": [
+            "validator vote account validator identity or bond authority may change the account,",
+            "pays for the bond product account reallocation"
+          ],
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "stakeProgram",
-          "address": "Stake11111111111111111111111111111111111111"
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         },
         {
           "name": "eventAuthority",
@@ -2910,26 +2697,26 @@ export type ValidatorBonds = {
       ],
       "args": [
         {
-          "name": "mergeArgs",
+          "name": "configureBondProductArgs",
           "type": {
             "defined": {
-              "name": "mergeStakeArgs"
+              "name": "configureBondProductArgs"
             }
           }
         }
       ]
     },
     {
-      "name": "mintBond",
+      "name": "configureBondWithMint",
       "discriminator": [
-        234,
-        94,
-        85,
-        225,
-        167,
-        102,
-        169,
-        32
+        48,
+        189,
+        230,
+        39,
+        112,
+        33,
+        227,
+        8
       ],
       "accounts": [
         {
@@ -2940,6 +2727,7 @@ export type ValidatorBonds = {
         },
         {
           "name": "bond",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -2994,105 +2782,12 @@ export type ValidatorBonds = {
                 "path": "bond"
               },
               {
-                "kind": "account",
-                "path": "validatorIdentity"
+                "kind": "arg",
+                "path": "params.validator_identity"
               }
             ]
           }
         },
-        {
-          "name": "validatorIdentity"
-        },
-        {
-          "name": "validatorIdentityTokenAccount",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "validatorIdentity"
-              },
-              {
-                "kind": "const",
-                "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
-                  95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "mint"
-              }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
-          }
-        },
         {
           "name": "voteAccount",
           "relations": [
@@ -3100,37 +2795,20 @@ export type ValidatorBonds = {
           ]
         },
         {
-          "name": "metadata",
-          "writable": true
-        },
-        {
-          "name": "rentPayer",
+          "name": "tokenAccount",
           "docs": [
-            "rent exempt payer of account creation"
+            "token account to burn bond mint configuration tokens from"
           ],
-          "writable": true,
-          "signer": true
+          "writable": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "tokenAuthority",
+          "signer": true
         },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
-        {
-          "name": "metadataProgram",
-          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
-        },
         {
           "name": "eventAuthority",
           "pda": {
@@ -3164,47 +2842,67 @@ export type ValidatorBonds = {
           "name": "program"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": {
+              "name": "configureBondWithMintArgs"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "resetStake",
+      "name": "configureCollateralMint",
       "discriminator": [
-        183,
-        37,
-        69,
-        159,
-        163,
-        139,
-        212,
-        235
+        180,
+        196,
+        20,
+        2,
+        253,
+        84,
+        187,
+        39
       ],
       "accounts": [
         {
           "name": "config",
+          "relations": [
+            "collateralMint"
+          ]
+        },
+        {
+          "name": "adminAuthority",
           "docs": [
-            "the config account under which the bond was created"
+            "only the admin authority can change the collateral mint params"
           ],
+          "signer": true,
           "relations": [
-            "bond"
+            "config"
           ]
         },
         {
-          "name": "bond",
+          "name": "collateralMint",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  111,
-                  110,
-                  100,
-                  95,
-                  97,
-                  99,
                   99,
                   111,
-                  117,
+                  108,
+                  108,
+                  97,
+                  116,
+                  101,
+                  114,
+                  97,
+                  108,
+                  95,
+                  109,
+                  105,
                   110,
                   116
                 ]
@@ -3215,40 +2913,26 @@ export type ValidatorBonds = {
               },
               {
                 "kind": "account",
-                "path": "voteAccount"
+                "path": "collateral_mint.mint",
+                "account": "collateralMint"
               }
             ]
           }
         },
         {
-          "name": "settlement",
-          "docs": [
-            "cannot exist; used to derive settlement authority"
-          ]
-        },
-        {
-          "name": "stakeAccount",
-          "docs": [
-            "stake account belonging under the settlement by staker authority"
-          ],
-          "writable": true
-        },
-        {
-          "name": "bondsWithdrawerAuthority",
-          "docs": [
-            "bonds withdrawer authority",
-            "to cancel settlement funding of the stake account changing staker authority to address"
-          ],
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  111,
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
                   110,
-                  100,
-                  115,
+                  116,
                   95,
                   97,
                   117,
@@ -3260,38 +2944,51 @@ export type ValidatorBonds = {
                   116,
                   121
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "config"
               }
             ]
           }
         },
         {
-          "name": "voteAccount",
-          "docs": [
-            "It may be an orphaned system account if the original vote account was removed from the chain."
-          ],
-          "relations": [
-            "bond"
-          ]
-        },
-        {
-          "name": "stakeHistory",
-          "address": "SysvarStakeHistory1111111111111111111111111"
-        },
+          "name": "program"
+        }
+      ],
+      "args": [
         {
-          "name": "stakeConfig",
-          "address": "StakeConfig11111111111111111111111111111111"
-        },
+          "name": "configureCollateralMintArgs",
+          "type": {
+            "defined": {
+              "name": "configureCollateralMintArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "configureConfig",
+      "discriminator": [
+        198,
+        98,
+        161,
+        165,
+        137,
+        200,
+        230,
+        203
+      ],
+      "accounts": [
         {
-          "name": "clock",
-          "address": "SysvarC1ock11111111111111111111111111111111"
+          "name": "config",
+          "writable": true
         },
         {
-          "name": "stakeProgram",
-          "address": "Stake11111111111111111111111111111111111111"
+          "name": "adminAuthority",
+          "docs": [
+            "only the admin authority can change the config params"
+          ],
+          "signer": true,
+          "relations": [
+            "config"
+          ]
         },
         {
           "name": "eventAuthority",
@@ -3326,35 +3023,48 @@ export type ValidatorBonds = {
           "name": "program"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "configureConfigArgs",
+          "type": {
+            "defined": {
+              "name": "configureConfigArgs"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "upsizeSettlementClaims",
+      "name": "countBondAccounts",
       "discriminator": [
-        207,
-        46,
-        34,
-        88,
-        141,
-        36,
-        63,
-        132
+        215,
+        230,
+        244,
+        103,
+        1,
+        223,
+        252,
+        116
       ],
       "accounts": [
         {
-          "name": "settlementClaims",
+          "name": "config",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "bond",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
-                  108,
-                  97,
-                  105,
-                  109,
-                  115,
+                  98,
+                  111,
+                  110,
+                  100,
                   95,
                   97,
                   99,
@@ -3367,50 +3077,20 @@ export type ValidatorBonds = {
               },
               {
                 "kind": "account",
-                "path": "settlement_claims.settlement",
-                "account": "settlementClaims"
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "bond.vote_account",
+                "account": "bond"
               }
             ]
           }
         },
-        {
-          "name": "rentPayer",
-          "docs": [
-            "rent exempt payer of account reallocation"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "withdrawStake",
-      "discriminator": [
-        153,
-        8,
-        22,
-        138,
-        105,
-        176,
-        87,
-        66
-      ],
-      "accounts": [
-        {
-          "name": "config",
-          "docs": [
-            "the config account under which the bond was created"
-          ]
-        },
         {
           "name": "operatorAuthority",
           "docs": [
-            "operator authority is allowed to reset the non-delegated stake accounts"
+            "operator signer authority that is allowed to count the bond accounts"
           ],
           "signer": true,
           "relations": [
@@ -3418,67 +3098,63 @@ export type ValidatorBonds = {
           ]
         },
         {
-          "name": "settlement",
-          "docs": [
-            "cannot exist; used to derive settlement authority"
-          ]
-        },
-        {
-          "name": "stakeAccount",
-          "docs": [
-            "stake account where staker authority is derived from settlement"
-          ],
-          "writable": true
-        },
-        {
-          "name": "bondsWithdrawerAuthority",
-          "docs": [
-            "bonds authority to withdraw the stake account"
-          ],
+          "name": "eventAuthority",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  98,
-                  111,
-                  110,
-                  100,
-                  115,
                   95,
-                  97,
-                  117,
-                  116,
-                  104,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
                   111,
                   114,
                   105,
                   116,
                   121
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "config"
               }
             ]
           }
         },
         {
-          "name": "withdrawTo",
-          "writable": true
-        },
-        {
-          "name": "stakeHistory",
-          "address": "SysvarStakeHistory1111111111111111111111111"
-        },
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "emergencyPause",
+      "discriminator": [
+        21,
+        143,
+        27,
+        142,
+        200,
+        181,
+        210,
+        255
+      ],
+      "accounts": [
         {
-          "name": "clock",
-          "address": "SysvarC1ock11111111111111111111111111111111"
+          "name": "config",
+          "writable": true
         },
         {
-          "name": "stakeProgram",
-          "address": "Stake11111111111111111111111111111111111111"
+          "name": "pauseAuthority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
         },
         {
           "name": "eventAuthority",
@@ -3513,153 +3189,4483 @@ export type ValidatorBonds = {
           "name": "program"
         }
       ],
-      "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "bond",
-      "discriminator": [
-        224,
-        128,
-        48,
-        251,
-        182,
-        246,
-        111,
-        196
-      ]
-    },
-    {
-      "name": "bondProduct",
-      "discriminator": [
-        135,
-        68,
-        116,
-        146,
-        5,
-        75,
-        127,
-        143
-      ]
-    },
-    {
-      "name": "config",
-      "discriminator": [
-        155,
-        12,
-        170,
-        224,
-        30,
-        250,
-        204,
-        130
-      ]
-    },
-    {
-      "name": "settlement",
-      "discriminator": [
-        55,
-        11,
-        219,
-        33,
-        36,
-        136,
-        40,
-        182
-      ]
-    },
-    {
-      "name": "settlementClaim",
-      "discriminator": [
-        216,
-        103,
-        231,
-        246,
-        171,
-        99,
-        124,
-        133
-      ]
-    },
-    {
-      "name": "settlementClaims",
-      "discriminator": [
-        32,
-        130,
-        62,
-        175,
-        231,
-        54,
-        170,
-        114
+      "args": [
+        {
+          "name": "emergencyPauseArgs",
+          "type": {
+            "defined": {
+              "name": "emergencyPauseResumeArgs"
+            }
+          }
+        }
       ]
     },
     {
-      "name": "withdrawRequest",
-      "discriminator": [
-        186,
-        239,
-        174,
-        191,
-        189,
-        13,
-        47,
-        196
-      ]
-    }
-  ],
-  "events": [
-    {
-      "name": "cancelSettlementEvent",
+      "name": "emergencyResume",
       "discriminator": [
-        80,
+        0,
+        243,
+        48,
+        185,
+        6,
+        73,
         190,
-        161,
-        61,
-        97,
-        7,
-        242,
-        92
+        83
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true
+        },
+        {
+          "name": "pauseAuthority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "emergencyResumeArgs",
+          "type": {
+            "defined": {
+              "name": "emergencyPauseResumeArgs"
+            }
+          }
+        }
       ]
     },
     {
-      "name": "cancelWithdrawRequestEvent",
+      "name": "executeConfigureConfig",
       "discriminator": [
-        221,
-        97,
+        113,
         104,
-        35,
-        19,
-        137,
         248,
-        246
-      ]
-    },
-    {
-      "name": "claimSettlementEvent",
-      "discriminator": [
-        135,
-        253,
-        145,
-        233,
-        227,
-        29,
-        188,
-        141
-      ]
-    },
-    {
-      "name": "claimSettlementV2Event",
-      "discriminator": [
-        114,
-        201,
-        131,
-        134,
-        182,
-        165,
-        237,
-        47
+        96,
+        212,
+        185,
+        86,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "extendSettlement",
+      "discriminator": [
+        37,
+        213,
+        212,
+        44,
+        107,
+        158,
+        247,
+        111
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "bond",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "bond.vote_account",
+                "account": "bond"
+              }
+            ]
+          },
+          "relations": [
+            "settlement"
+          ]
+        },
+        {
+          "name": "settlement",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  116,
+                  116,
+                  108,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "path": "settlement.epoch_created_for",
+                "account": "settlement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "operatorAuthority",
+          "docs": [
+            "operator signer authority that is allowed to extend the settlement"
+          ],
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "extendSettlementArgs",
+          "type": {
+            "defined": {
+              "name": "extendSettlementArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "fundBond",
+      "discriminator": [
+        58,
+        44,
+        212,
+        175,
+        30,
+        17,
+        68,
+        62
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "bond",
+          "docs": [
+            "bond account to be deposited to with the provided stake account"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "bond.vote_account",
+                "account": "bond"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "docs": [
+            "new owner of the stake_account, it's the bonds withdrawer authority"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  115,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakeAccount",
+          "docs": [
+            "stake account to be deposited"
+          ],
+          "writable": true
+        },
+        {
+          "name": "stakeAuthority",
+          "docs": [
+            "authority signature permitting to change the stake_account authorities"
+          ],
+          "signer": true
+        },
+        {
+          "name": "clock",
+          "address": "SysvarC1ock11111111111111111111111111111111"
+        },
+        {
+          "name": "stakeHistory",
+          "address": "SysvarStakeHistory1111111111111111111111111"
+        },
+        {
+          "name": "stakeProgram",
+          "address": "Stake11111111111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "fundBondToken",
+      "discriminator": [
+        221,
+        54,
+        6,
+        194,
+        100,
+        122,
+        14,
+        242
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "relations": [
+            "bond",
+            "collateralMint"
+          ]
+        },
+        {
+          "name": "bond",
+          "docs": [
+            "bond account to be deposited to with the provided tokens"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "bond.vote_account",
+                "account": "bond"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  97,
+                  116,
+                  101,
+                  114,
+                  97,
+                  108,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "collateralMint"
+          ]
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "docs": [
+            "owner of the bond token vault, it's the bonds withdrawer authority"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  115,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondTokenVault",
+          "docs": [
+            "token account of the bond for the particular mint, created on the first funding"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "depositorTokenAccount",
+          "docs": [
+            "token account the tokens are deposited from"
+          ],
+          "writable": true
+        },
+        {
+          "name": "depositorAuthority",
+          "docs": [
+            "owner (or delegate) of the depositor token account"
+          ],
+          "signer": true
+        },
+        {
+          "name": "rentPayer",
+          "docs": [
+            "rent exempt payer of the bond token vault account creation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "fundBondTokenArgs",
+          "type": {
+            "defined": {
+              "name": "fundBondTokenArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "fundSettlement",
+      "discriminator": [
+        179,
+        146,
+        113,
+        34,
+        30,
+        92,
+        26,
+        19
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "bond",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "voteAccount"
+              }
+            ]
+          },
+          "relations": [
+            "settlement"
+          ]
+        },
+        {
+          "name": "voteAccount",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "settlement",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  116,
+                  116,
+                  108,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "path": "settlement.epoch_created_for",
+                "account": "settlement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "operatorAuthority",
+          "docs": [
+            "operator signer authority is allowed to fund the settlement account"
+          ],
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "stakeAccount",
+          "docs": [
+            "stake account to be funded into the settlement"
+          ],
+          "writable": true
+        },
+        {
+          "name": "settlementStakerAuthority",
+          "docs": [
+            "the settlement stake authority differentiates between deposited and funded stake accounts",
+            "deposited accounts have the bonds_withdrawer_authority, while funded accounts have the settlement_staker_authority"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  116,
+                  116,
+                  108,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "settlement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "docs": [
+            "authority that manages (owns) all stakes account under the bonds program"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  115,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "splitStakeAccount",
+          "docs": [
+            "if an account that does not exist is provided, it will be initialized as a stake account (with the necessary signature)",
+            "the split_stake_account is required when the provided stake_account contains more lamports than necessary to fund the settlement",
+            "in this case, the excess lamports from the stake account are split into the new split_stake_account,",
+            "if the split_stake_account is not needed, the rent payer is refunded back within tx"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "splitStakeRentPayer",
+          "docs": [
+            "the rent exempt payer of the split_stake_account creation",
+            "if the split_stake_account is not needed (no leftover lamports on funding), then the rent payer is refunded",
+            "if the split_stake_account is needed to spill out over funding of the settlement,",
+            "then the rent payer is refunded when the settlement is closed"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "stakeHistory",
+          "address": "SysvarStakeHistory1111111111111111111111111"
+        },
+        {
+          "name": "clock",
+          "address": "SysvarC1ock11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        },
+        {
+          "name": "stakeProgram",
+          "address": "Stake11111111111111111111111111111111111111"
+        },
+        {
+          "name": "stakeConfig",
+          "address": "StakeConfig11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "fundSettlementToken",
+      "discriminator": [
+        38,
+        23,
+        9,
+        206,
+        205,
+        218,
+        113,
+        120
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "relations": [
+            "bond",
+            "collateralMint"
+          ]
+        },
+        {
+          "name": "bond",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "voteAccount"
+              }
+            ]
+          },
+          "relations": [
+            "settlement"
+          ]
+        },
+        {
+          "name": "voteAccount",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "settlement",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  116,
+                  116,
+                  108,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "path": "settlement.epoch_created_for",
+                "account": "settlement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "operatorAuthority",
+          "docs": [
+            "operator signer authority is allowed to fund the settlement account"
+          ],
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "stakeAccount",
+          "docs": [
+            "stake account of the liquidator to be funded into the settlement"
+          ],
+          "writable": true
+        },
+        {
+          "name": "stakeAuthority",
+          "docs": [
+            "staker and withdrawer authority of the stake account, signature permits to change the authorities"
+          ],
+          "signer": true
+        },
+        {
+          "name": "settlementStakerAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  116,
+                  116,
+                  108,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "settlement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "docs": [
+            "authority that manages (owns) all stakes account and token vaults under the bonds program"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  115,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "disabled collateral mint still permits funding the settlement"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  97,
+                  116,
+                  101,
+                  114,
+                  97,
+                  108,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "collateralMint"
+          ]
+        },
+        {
+          "name": "bondTokenVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "liquidatorTokenAccount",
+          "docs": [
+            "token account of the liquidator receiving the bond collateral tokens"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "clock",
+          "address": "SysvarC1ock11111111111111111111111111111111"
+        },
+        {
+          "name": "stakeProgram",
+          "address": "Stake11111111111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initBond",
+      "discriminator": [
+        95,
+        93,
+        93,
+        181,
+        221,
+        36,
+        126,
+        64
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "the config account under which the bond is created"
+          ]
+        },
+        {
+          "name": "voteAccount"
+        },
+        {
+          "name": "validatorIdentity",
+          "docs": [
+            "permission-ed: the validator identity signs the instruction, InitBondArgs applied",
+            "permission-less: no signature, default init bond configuration"
+          ],
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "bond",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "voteAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "docs": [
+            "rent exempt payer of validator bond account creation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "initBondArgs",
+          "type": {
+            "defined": {
+              "name": "initBondArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initBondLedger",
+      "discriminator": [
+        53,
+        16,
+        119,
+        164,
+        236,
+        203,
+        106,
+        117
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "the config account that the bond belongs to"
+          ],
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "bond",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "bond.vote_account",
+                "account": "bond"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondLedger",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "docs": [
+            "rent exempt payer of account creation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initBondProduct",
+      "discriminator": [
+        228,
+        35,
+        205,
+        119,
+        63,
+        209,
+        72,
+        56
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "The config account that the bond belongs to"
+          ],
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "bond",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "voteAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteAccount",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "bondProduct",
+          "docs": [
+            "The bond product account being created"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  112,
+                  114,
+                  111,
+                  100,
+                  117,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              },
+              {
+                "kind": "arg",
+                "path": "args.product_type"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "permission-ed: the validator identity or bond authority signs the instruction, InitBondProductArgs applied",
+            "permission-less: no signature, default configuration for the bond product"
+          ],
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "rentPayer",
+          "docs": [
+            "Rent payer for the bond product account creation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "initBondProductArgs",
+          "type": {
+            "defined": {
+              "name": "initBondProductArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initCollateralMint",
+      "discriminator": [
+        250,
+        198,
+        99,
+        196,
+        44,
+        40,
+        109,
+        251
+      ],
+      "accounts": [
+        {
+          "name": "config"
+        },
+        {
+          "name": "adminAuthority",
+          "docs": [
+            "only the admin authority can permit a new collateral mint"
+          ],
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "collateralMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  97,
+                  116,
+                  101,
+                  114,
+                  97,
+                  108,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "docs": [
+            "rent exempt payer of the collateral mint account creation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "initCollateralMintArgs",
+          "type": {
+            "defined": {
+              "name": "initCollateralMintArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initConfig",
+      "discriminator": [
+        23,
+        235,
+        115,
+        232,
+        168,
+        96,
+        1,
+        231
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "rentPayer",
+          "docs": [
+            "rent exempt payer for the config account"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "initConfigArgs",
+          "type": {
+            "defined": {
+              "name": "initConfigArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initIndexedWithdrawRequest",
+      "discriminator": [
+        130,
+        47,
+        219,
+        137,
+        72,
+        147,
+        29,
+        45
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "the config account under which the bond was created"
+          ],
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "bond",
+          "docs": [
+            "bond account keeping the index of the last created withdraw request"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "voteAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteAccount",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "validator vote account node identity or bond authority may ask for the withdrawal"
+          ],
+          "signer": true
+        },
+        {
+          "name": "withdrawRequest",
+          "writable": true
+        },
+        {
+          "name": "rentPayer",
+          "docs": [
+            "rent exempt payer of withdraw request account creation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "createWithdrawRequestArgs",
+          "type": {
+            "defined": {
+              "name": "initWithdrawRequestArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initReplaceableSettlement",
+      "discriminator": [
+        133,
+        236,
+        182,
+        16,
+        119,
+        75,
+        199,
+        89
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "bond",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "bond.vote_account",
+                "account": "bond"
+              }
+            ]
+          }
+        },
+        {
+          "name": "settlement",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  116,
+                  116,
+                  108,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              },
+              {
+                "kind": "arg",
+                "path": "params.replaceable_seed"
+              },
+              {
+                "kind": "arg",
+                "path": "params.epoch"
+              }
+            ]
+          }
+        },
+        {
+          "name": "settlementClaims",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  108,
+                  97,
+                  105,
+                  109,
+                  115,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "settlement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "operatorAuthority",
+          "docs": [
+            "operator signer authority that is allowed to create the settlement account"
+          ],
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "docs": [
+            "rent exempt payer of account creation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "initReplaceableSettlementArgs",
+          "type": {
+            "defined": {
+              "name": "initReplaceableSettlementArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initSettlement",
+      "discriminator": [
+        152,
+        178,
+        0,
+        65,
+        52,
+        210,
+        247,
+        58
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "bond",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "bond.vote_account",
+                "account": "bond"
+              }
+            ]
+          }
+        },
+        {
+          "name": "settlement",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  116,
+                  116,
+                  108,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              },
+              {
+                "kind": "arg",
+                "path": "params.merkle_root"
+              },
+              {
+                "kind": "arg",
+                "path": "params.epoch"
+              }
+            ]
+          }
+        },
+        {
+          "name": "settlementClaims",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  108,
+                  97,
+                  105,
+                  109,
+                  115,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "settlement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "operatorAuthority",
+          "docs": [
+            "operator signer authority that is allowed to create the settlement account"
+          ],
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "docs": [
+            "rent exempt payer of account creation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "initSettlementArgs",
+          "type": {
+            "defined": {
+              "name": "initSettlementArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initSettlementClaimsChunk",
+      "discriminator": [
+        191,
+        124,
+        205,
+        18,
+        181,
+        168,
+        208,
+        114
+      ],
+      "accounts": [
+        {
+          "name": "settlementClaims",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  108,
+                  97,
+                  105,
+                  109,
+                  115,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "settlement_claims.settlement",
+                "account": "settlementClaims"
+              }
+            ]
+          }
+        },
+        {
+          "name": "settlementClaimsChunk",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  108,
+                  97,
+                  105,
+                  109,
+                  115,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "settlement_claims.settlement",
+                "account": "settlementClaims"
+              },
+              {
+                "kind": "arg",
+                "path": "params.chunk_index"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "docs": [
+            "rent exempt payer of account creation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "initSettlementClaimsChunkArgs",
+          "type": {
+            "defined": {
+              "name": "initSettlementClaimsChunkArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initWithdrawRequest",
+      "discriminator": [
+        142,
+        31,
+        222,
+        215,
+        83,
+        79,
+        34,
+        49
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "the config account under which the bond was created"
+          ],
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "bond",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "voteAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteAccount",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "validator vote account node identity or bond authority may ask for the withdrawal"
+          ],
+          "signer": true
+        },
+        {
+          "name": "withdrawRequest",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  105,
+                  116,
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "docs": [
+            "rent exempt payer of withdraw request account creation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "createWithdrawRequestArgs",
+          "type": {
+            "defined": {
+              "name": "initWithdrawRequestArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "mergeStake",
+      "discriminator": [
+        14,
+        3,
+        146,
+        23,
+        163,
+        105,
+        246,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "the config account under which the bond was created"
+          ]
+        },
+        {
+          "name": "sourceStake",
+          "writable": true
+        },
+        {
+          "name": "destinationStake",
+          "writable": true
+        },
+        {
+          "name": "stakerAuthority",
+          "docs": [
+            "bonds program authority PDA address: settlement staker or bonds withdrawer"
+          ]
+        },
+        {
+          "name": "stakeHistory",
+          "address": "SysvarStakeHistory1111111111111111111111111"
+        },
+        {
+          "name": "clock",
+          "address": "SysvarC1ock11111111111111111111111111111111"
+        },
+        {
+          "name": "stakeProgram",
+          "address": "Stake11111111111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "mergeArgs",
+          "type": {
+            "defined": {
+              "name": "mergeStakeArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "mintBond",
+      "discriminator": [
+        234,
+        94,
+        85,
+        225,
+        167,
+        102,
+        169,
+        32
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "bond",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "voteAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "path": "validatorIdentity"
+              }
+            ]
+          }
+        },
+        {
+          "name": "validatorIdentity"
+        },
+        {
+          "name": "validatorIdentityTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "validatorIdentity"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "voteAccount",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "metadata",
+          "writable": true
+        },
+        {
+          "name": "rentPayer",
+          "docs": [
+            "rent exempt payer of account creation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "metadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "proposeConfigAuthority",
+      "discriminator": [
+        31,
+        229,
+        9,
+        231,
+        45,
+        153,
+        123,
+        235
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true
+        },
+        {
+          "name": "adminAuthority",
+          "docs": [
+            "only the admin authority can propose new authorities"
+          ],
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "proposeConfigAuthorityArgs",
+          "type": {
+            "defined": {
+              "name": "proposeConfigAuthorityArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "queueConfigureConfig",
+      "discriminator": [
+        43,
+        130,
+        2,
+        73,
+        70,
+        194,
+        160,
+        153
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true
+        },
+        {
+          "name": "adminAuthority",
+          "docs": [
+            "only the admin authority can queue the config change"
+          ],
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "queueConfigureConfigArgs",
+          "type": {
+            "defined": {
+              "name": "queueConfigureConfigArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "replaceSettlementRoot",
+      "discriminator": [
+        218,
+        222,
+        98,
+        229,
+        238,
+        112,
+        112,
+        155
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "bond",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "bond.vote_account",
+                "account": "bond"
+              }
+            ]
+          },
+          "relations": [
+            "settlement"
+          ]
+        },
+        {
+          "name": "settlement",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  116,
+                  116,
+                  108,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "path": "settlement.epoch_created_for",
+                "account": "settlement"
+              }
+            ]
+          },
+          "relations": [
+            "settlementClaims"
+          ]
+        },
+        {
+          "name": "settlementClaims",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  108,
+                  97,
+                  105,
+                  109,
+                  115,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "settlement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "operatorAuthority",
+          "docs": [
+            "operator signer authority that is allowed to replace the settlement merkle root"
+          ],
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "docs": [
+            "rent exempt payer of the claims bitmap reallocation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "clock",
+          "address": "SysvarC1ock11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "replaceSettlementRootArgs",
+          "type": {
+            "defined": {
+              "name": "replaceSettlementRootArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "resetStake",
+      "discriminator": [
+        183,
+        37,
+        69,
+        159,
+        163,
+        139,
+        212,
+        235
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "the config account under which the bond was created"
+          ],
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "bond",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "voteAccount"
+              }
+            ]
+          }
+        },
+        {
+          "name": "settlement",
+          "docs": [
+            "cannot exist; used to derive settlement authority"
+          ]
+        },
+        {
+          "name": "stakeAccount",
+          "docs": [
+            "stake account belonging under the settlement by staker authority"
+          ],
+          "writable": true
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "docs": [
+            "bonds withdrawer authority",
+            "to cancel settlement funding of the stake account changing staker authority to address"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  115,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteAccount",
+          "docs": [
+            "It may be an orphaned system account if the original vote account was removed from the chain."
+          ],
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "stakeHistory",
+          "address": "SysvarStakeHistory1111111111111111111111111"
+        },
+        {
+          "name": "stakeConfig",
+          "address": "StakeConfig11111111111111111111111111111111"
+        },
+        {
+          "name": "clock",
+          "address": "SysvarC1ock11111111111111111111111111111111"
+        },
+        {
+          "name": "stakeProgram",
+          "address": "Stake11111111111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "resizeWithdrawRequest",
+      "discriminator": [
+        32,
+        156,
+        94,
+        127,
+        214,
+        139,
+        117,
+        231
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "bond",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "voteAccount"
+              }
+            ]
+          },
+          "relations": [
+            "withdrawRequest"
+          ]
+        },
+        {
+          "name": "voteAccount",
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "validator vote account validator identity or bond authority may ask for resizing"
+          ],
+          "signer": true
+        },
+        {
+          "name": "withdrawRequest",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  105,
+                  116,
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "path": "withdrawRequest"
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "resizeWithdrawRequestArgs",
+          "type": {
+            "defined": {
+              "name": "resizeWithdrawRequestArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateCollateralExchangeRate",
+      "discriminator": [
+        166,
+        1,
+        184,
+        60,
+        22,
+        123,
+        46,
+        47
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "relations": [
+            "collateralMint"
+          ]
+        },
+        {
+          "name": "operatorAuthority",
+          "docs": [
+            "operator signer authority is allowed to update the exchange rate"
+          ],
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  97,
+                  116,
+                  101,
+                  114,
+                  97,
+                  108,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "collateral_mint.mint",
+                "account": "collateralMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "updateCollateralExchangeRateArgs",
+          "type": {
+            "defined": {
+              "name": "updateCollateralExchangeRateArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "upsizeSettlementClaims",
+      "discriminator": [
+        207,
+        46,
+        34,
+        88,
+        141,
+        36,
+        63,
+        132
+      ],
+      "accounts": [
+        {
+          "name": "settlementClaims",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  108,
+                  97,
+                  105,
+                  109,
+                  115,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "settlement_claims.settlement",
+                "account": "settlementClaims"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "docs": [
+            "rent exempt payer of account reallocation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawBondToken",
+      "discriminator": [
+        79,
+        127,
+        161,
+        141,
+        139,
+        7,
+        199,
+        175
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "relations": [
+            "bond",
+            "collateralMint"
+          ]
+        },
+        {
+          "name": "bond",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "voteAccount"
+              }
+            ]
+          },
+          "relations": [
+            "withdrawRequest"
+          ]
+        },
+        {
+          "name": "voteAccount",
+          "relations": [
+            "bond",
+            "withdrawRequest"
+          ]
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "validator vote account node identity or bond authority may withdraw"
+          ],
+          "signer": true
+        },
+        {
+          "name": "withdrawRequest",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  105,
+                  116,
+                  104,
+                  100,
+                  114,
+                  97,
+                  119,
+                  95,
+                  97,
+                  99,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "path": "withdrawRequest"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "docs": [
+            "disabled collateral mint still permits withdrawing"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  108,
+                  108,
+                  97,
+                  116,
+                  101,
+                  114,
+                  97,
+                  108,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "relations": [
+            "collateralMint"
+          ]
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  115,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondTokenVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  95,
+                  116,
+                  111,
+                  107,
+                  101,
+                  110,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "withdrawerTokenAccount",
+          "docs": [
+            "whatever token account of the mint, authority signature states his intention to withdraw the tokens there"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "clock",
+          "address": "SysvarC1ock11111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": [
+        {
+          "name": "withdrawBondTokenArgs",
+          "type": {
+            "defined": {
+              "name": "withdrawBondTokenArgs"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawStake",
+      "discriminator": [
+        153,
+        8,
+        22,
+        138,
+        105,
+        176,
+        87,
+        66
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "docs": [
+            "the config account under which the bond was created"
+          ]
+        },
+        {
+          "name": "operatorAuthority",
+          "docs": [
+            "operator authority is allowed to reset the non-delegated stake accounts"
+          ],
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "settlement",
+          "docs": [
+            "cannot exist; used to derive settlement authority"
+          ]
+        },
+        {
+          "name": "stakeAccount",
+          "docs": [
+            "stake account where staker authority is derived from settlement"
+          ],
+          "writable": true
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "docs": [
+            "bonds authority to withdraw the stake account"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  111,
+                  110,
+                  100,
+                  115,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "withdrawTo",
+          "writable": true
+        },
+        {
+          "name": "stakeHistory",
+          "address": "SysvarStakeHistory1111111111111111111111111"
+        },
+        {
+          "name": "clock",
+          "address": "SysvarC1ock11111111111111111111111111111111"
+        },
+        {
+          "name": "stakeProgram",
+          "address": "Stake11111111111111111111111111111111111111"
+        },
+        {
+          "name": "eventAuthority",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  95,
+                  95,
+                  101,
+                  118,
+                  101,
+                  110,
+                  116,
+                  95,
+                  97,
+                  117,
+                  116,
+                  104,
+                  111,
+                  114,
+                  105,
+                  116,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program"
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "bond",
+      "discriminator": [
+        224,
+        128,
+        48,
+        251,
+        182,
+        246,
+        111,
+        196
+      ]
+    },
+    {
+      "name": "bondLedger",
+      "discriminator": [
+        41,
+        28,
+        79,
+        33,
+        224,
+        24,
+        139,
+        43
+      ]
+    },
+    {
+      "name": "bondProduct",
+      "discriminator": [
+        135,
+        68,
+        116,
+        146,
+        5,
+        75,
+        127,
+        143
+      ]
+    },
+    {
+      "name": "collateralMint",
+      "discriminator": [
+        23,
+        108,
+        66,
+        172,
+        255,
+        130,
+        141,
+        197
+      ]
+    },
+    {
+      "name": "config",
+      "discriminator": [
+        155,
+        12,
+        170,
+        224,
+        30,
+        250,
+        204,
+        130
+      ]
+    },
+    {
+      "name": "settlement",
+      "discriminator": [
+        55,
+        11,
+        219,
+        33,
+        36,
+        136,
+        40,
+        182
+      ]
+    },
+    {
+      "name": "settlementClaim",
+      "discriminator": [
+        216,
+        103,
+        231,
+        246,
+        171,
+        99,
+        124,
+        133
+      ]
+    },
+    {
+      "name": "settlementClaims",
+      "discriminator": [
+        32,
+        130,
+        62,
+        175,
+        231,
+        54,
+        170,
+        114
+      ]
+    },
+    {
+      "name": "withdrawRequest",
+      "discriminator": [
+        186,
+        239,
+        174,
+        191,
+        189,
+        13,
+        47,
+        196
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "cancelBondAuthorityEvent",
+      "discriminator": [
+        70,
+        94,
+        206,
+        23,
+        113,
+        207,
+        123,
+        27
+      ]
+    },
+    {
+      "name": "cancelConfigAuthorityEvent",
+      "discriminator": [
+        195,
+        187,
+        168,
+        72,
+        185,
+        209,
+        183,
+        44
+      ]
+    },
+    {
+      "name": "cancelQueuedConfigEvent",
+      "discriminator": [
+        207,
+        192,
+        85,
+        224,
+        184,
+        73,
+        180,
+        128
+      ]
+    },
+    {
+      "name": "cancelSettlementEvent",
+      "discriminator": [
+        80,
+        190,
+        161,
+        61,
+        97,
+        7,
+        242,
+        92
+      ]
+    },
+    {
+      "name": "cancelWithdrawRequestEvent",
+      "discriminator": [
+        221,
+        97,
+        104,
+        35,
+        19,
+        137,
+        248,
+        246
+      ]
+    },
+    {
+      "name": "claimSettlementBatchEvent",
+      "discriminator": [
+        31,
+        242,
+        163,
+        205,
+        242,
+        5,
+        199,
+        6
+      ]
+    },
+    {
+      "name": "claimSettlementEvent",
+      "discriminator": [
+        135,
+        253,
+        145,
+        233,
+        227,
+        29,
+        188,
+        141
+      ]
+    },
+    {
+      "name": "claimSettlementRedirectEvent",
+      "discriminator": [
+        86,
+        75,
+        24,
+        5,
+        49,
+        134,
+        10,
+        155
+      ]
+    },
+    {
+      "name": "claimSettlementV2Event",
+      "discriminator": [
+        114,
+        201,
+        131,
+        134,
+        182,
+        165,
+        237,
+        47
       ]
     },
     {
@@ -3675,6 +7681,45 @@ export type ValidatorBonds = {
         58
       ]
     },
+    {
+      "name": "closeBondEvent",
+      "discriminator": [
+        94,
+        233,
+        21,
+        208,
+        1,
+        166,
+        227,
+        66
+      ]
+    },
+    {
+      "name": "closeBondLedgerEvent",
+      "discriminator": [
+        218,
+        49,
+        113,
+        137,
+        220,
+        58,
+        230,
+        247
+      ]
+    },
+    {
+      "name": "closeBondProductEvent",
+      "discriminator": [
+        117,
+        192,
+        253,
+        32,
+        164,
+        213,
+        237,
+        146
+      ]
+    },
     {
       "name": "closeSettlementEvent",
       "discriminator": [
@@ -3727,6 +7772,19 @@ export type ValidatorBonds = {
         96
       ]
     },
+    {
+      "name": "configureCollateralMintEvent",
+      "discriminator": [
+        49,
+        31,
+        132,
+        133,
+        81,
+        64,
+        131,
+        4
+      ]
+    },
     {
       "name": "configureConfigEvent",
       "discriminator": [
@@ -3740,6 +7798,19 @@ export type ValidatorBonds = {
         122
       ]
     },
+    {
+      "name": "countBondAccountsEvent",
+      "discriminator": [
+        49,
+        32,
+        229,
+        208,
+        177,
+        9,
+        200,
+        54
+      ]
+    },
     {
       "name": "emergencyPauseEvent",
       "discriminator": [
@@ -3766,6 +7837,19 @@ export type ValidatorBonds = {
         200
       ]
     },
+    {
+      "name": "extendSettlementEvent",
+      "discriminator": [
+        7,
+        217,
+        155,
+        102,
+        128,
+        212,
+        199,
+        243
+      ]
+    },
     {
       "name": "fundBondEvent",
       "discriminator": [
@@ -3779,6 +7863,19 @@ export type ValidatorBonds = {
         110
       ]
     },
+    {
+      "name": "fundBondTokenEvent",
+      "discriminator": [
+        199,
+        195,
+        105,
+        100,
+        120,
+        234,
+        132,
+        246
+      ]
+    },
     {
       "name": "fundSettlementEvent",
       "discriminator": [
@@ -3792,6 +7889,19 @@ export type ValidatorBonds = {
         114
       ]
     },
+    {
+      "name": "fundSettlementTokenEvent",
+      "discriminator": [
+        155,
+        18,
+        216,
+        236,
+        154,
+        189,
+        40,
+        31
+      ]
+    },
     {
       "name": "initBondEvent",
       "discriminator": [
@@ -3805,6 +7915,19 @@ export type ValidatorBonds = {
         200
       ]
     },
+    {
+      "name": "initBondLedgerEvent",
+      "discriminator": [
+        206,
+        204,
+        18,
+        145,
+        8,
+        224,
+        52,
+        187
+      ]
+    },
     {
       "name": "initBondProductEvent",
       "discriminator": [
@@ -3818,6 +7941,19 @@ export type ValidatorBonds = {
         161
       ]
     },
+    {
+      "name": "initCollateralMintEvent",
+      "discriminator": [
+        117,
+        77,
+        38,
+        137,
+        42,
+        212,
+        129,
+        93
+      ]
+    },
     {
       "name": "initConfigEvent",
       "discriminator": [
@@ -3884,16 +8020,94 @@ export type ValidatorBonds = {
       ]
     },
     {
-      "name": "resetStakeEvent",
+      "name": "proposeConfigAuthorityEvent",
+      "discriminator": [
+        80,
+        224,
+        6,
+        54,
+        55,
+        178,
+        63,
+        57
+      ]
+    },
+    {
+      "name": "queueConfigureConfigEvent",
+      "discriminator": [
+        106,
+        94,
+        253,
+        249,
+        238,
+        3,
+        47,
+        156
+      ]
+    },
+    {
+      "name": "replaceSettlementRootEvent",
+      "discriminator": [
+        186,
+        107,
+        41,
+        15,
+        89,
+        67,
+        119,
+        99
+      ]
+    },
+    {
+      "name": "resetStakeEvent",
+      "discriminator": [
+        255,
+        49,
+        219,
+        199,
+        119,
+        10,
+        195,
+        177
+      ]
+    },
+    {
+      "name": "resizeWithdrawRequestEvent",
+      "discriminator": [
+        27,
+        37,
+        198,
+        75,
+        145,
+        105,
+        33,
+        16
+      ]
+    },
+    {
+      "name": "updateCollateralExchangeRateEvent",
+      "discriminator": [
+        178,
+        206,
+        57,
+        198,
+        100,
+        86,
+        83,
+        135
+      ]
+    },
+    {
+      "name": "withdrawBondTokenEvent",
       "discriminator": [
-        255,
-        49,
-        219,
-        199,
-        119,
-        10,
-        195,
-        177
+        103,
+        139,
+        218,
+        109,
+        58,
+        174,
+        45,
+        234
       ]
     },
     {
@@ -4137,340 +8351,2140 @@ export type ValidatorBonds = {
       "msg": "Stake account's staker does not match with the provided authority"
     },
     {
-      "code": 6045,
-      "name": "nonBondStakeAuthorities",
-      "msg": "One or both stake authorities does not belong to bonds program"
+      "code": 6045,
+      "name": "nonBondStakeAuthorities",
+      "msg": "One or both stake authorities does not belong to bonds program"
+    },
+    {
+      "code": 6046,
+      "name": "settlementAuthorityMismatch",
+      "msg": "Stake account staker authority mismatches with the settlement authority"
+    },
+    {
+      "code": 6047,
+      "name": "stakeDelegationMismatch",
+      "msg": "Delegation of provided stake account mismatches"
+    },
+    {
+      "code": 6048,
+      "name": "withdrawRequestAmountTooSmall",
+      "msg": "Too small non-withdrawn withdraw request amount, cancel and init new one"
+    },
+    {
+      "code": 6049,
+      "name": "withdrawRequestAlreadyFulfilled",
+      "msg": "Withdraw request has been already fulfilled"
+    },
+    {
+      "code": 6050,
+      "name": "claimSettlementMerkleTreeNodeMismatch",
+      "msg": "Claim settlement merkle tree node mismatch"
+    },
+    {
+      "code": 6051,
+      "name": "wrongStakeAccountStaker",
+      "msg": "Wrong staker authority of the stake account"
+    },
+    {
+      "code": 6052,
+      "name": "alreadyPaused",
+      "msg": "Requested pause and already Paused"
+    },
+    {
+      "code": 6053,
+      "name": "notPaused",
+      "msg": "Requested resume, but not Paused"
+    },
+    {
+      "code": 6054,
+      "name": "programIsPaused",
+      "msg": "Emergency Pause is Active"
+    },
+    {
+      "code": 6055,
+      "name": "invalidPauseAuthority",
+      "msg": "Invalid pause authority"
+    },
+    {
+      "code": 6056,
+      "name": "mergeMismatchSameSourceDestination",
+      "msg": "Source and destination cannot be the same for merge operation"
+    },
+    {
+      "code": 6057,
+      "name": "wrongStakeAccountState",
+      "msg": "Wrong state of the stake account"
+    },
+    {
+      "code": 6058,
+      "name": "validatorIdentityBondMintMismatch",
+      "msg": "Validator identity mismatch for bond mint"
+    },
+    {
+      "code": 6059,
+      "name": "invalidBondMintSupply",
+      "msg": "Bond mint permits only a single token to exist"
+    },
+    {
+      "code": 6060,
+      "name": "operatorAndPauseAuthorityMismatch",
+      "msg": "Operation permitted only to operator or pause authority"
+    },
+    {
+      "code": 6061,
+      "name": "settlementNotReadyForClaiming",
+      "msg": "Settlement slots to start claiming not expired yet"
+    },
+    {
+      "code": 6062,
+      "name": "invalidVoteAccountType",
+      "msg": "Unsupported vote account type to deserialize"
+    },
+    {
+      "code": 6063,
+      "name": "maxStakeWantedTooLow",
+      "msg": "Max stake wanted value is lower to minimum configured value"
+    },
+    {
+      "code": 6064,
+      "name": "noStakeOrNotActivatingOrActivated",
+      "msg": "Stake account is not activating or activated"
+    },
+    {
+      "code": 6065,
+      "name": "bitmapSizeMismatch",
+      "msg": "Data size mismatch for the bitmap"
+    },
+    {
+      "code": 6066,
+      "name": "bitmapIndexOutOfBonds",
+      "msg": "Bitmap index out of bounds"
+    },
+    {
+      "code": 6067,
+      "name": "settlementClaimsNotInitialized",
+      "msg": "SettlementClaims account not fully initialized, missing data size"
+    },
+    {
+      "code": 6068,
+      "name": "settlementClaimsTooManyRecords",
+      "msg": "SettlementClaims records exceed maximum to fit Solana account size"
+    },
+    {
+      "code": 6069,
+      "name": "settlementClaimsAlreadyInitialized",
+      "msg": "SettlementClaims already initialized, no need to increase account size"
+    },
+    {
+      "code": 6070,
+      "name": "settlementAlreadyClaimed",
+      "msg": "Settlement has been already claimed"
+    },
+    {
+      "code": 6071,
+      "name": "invalidStakeHistoryEntry",
+      "msg": "Failed to obtain stake history entry"
+    },
+    {
+      "code": 6072,
+      "name": "invalidProductType",
+      "msg": "Invalid Bond Product type"
+    },
+    {
+      "code": 6073,
+      "name": "invalidBondProductData",
+      "msg": "Invalid data for Bond Product"
+    },
+    {
+      "code": 6074,
+      "name": "bondProductSerializationError",
+      "msg": "Failed to serialize Bond Product"
+    },
+    {
+      "code": 6075,
+      "name": "bondProductTypeMismatch",
+      "msg": "Failed to serialize Bond Product"
+    },
+    {
+      "code": 6076,
+      "name": "bondProductSetupNotPermitted",
+      "msg": "Wrong authority to setup the validator bond product account"
+    },
+    {
+      "code": 6077,
+      "name": "invalidBondProductAddress",
+      "msg": "Fail to create program address for Bond Product"
+    },
+    {
+      "code": 6078,
+      "name": "productTypeConfigValidationFailure",
+      "msg": "Fail to validate ProductConfig value"
+    },
+    {
+      "code": 6079,
+      "name": "withdrawRequestResizeNotLowering",
+      "msg": "Withdraw request resize permits only lowering the requested amount"
+    },
+    {
+      "code": 6080,
+      "name": "withdrawRequestResizeBelowWithdrawn",
+      "msg": "Withdraw request cannot be resized below the already withdrawn amount"
+    },
+    {
+      "code": 6081,
+      "name": "collateralMintHaircutTooHigh",
+      "msg": "Collateral mint haircut has to be lower than 10000 basis points"
+    },
+    {
+      "code": 6082,
+      "name": "collateralMintDecimalsMismatch",
+      "msg": "Collateral mint has to have the same number of decimals as SOL"
+    },
+    {
+      "code": 6083,
+      "name": "collateralMintDisabled",
+      "msg": "Collateral mint is not enabled for funding bonds"
+    },
+    {
+      "code": 6084,
+      "name": "invalidCollateralMintAddress",
+      "msg": "Fail to create program address for CollateralMint"
+    },
+    {
+      "code": 6085,
+      "name": "withdrawRequestAmountExceeded",
+      "msg": "Withdrawing value exceeds the amount remaining in the withdraw request"
+    },
+    {
+      "code": 6086,
+      "name": "insufficientBondTokenCollateral",
+      "msg": "Bond token vault has not enough collateral"
+    },
+    {
+      "code": 6087,
+      "name": "settlementFundingStakeMismatch",
+      "msg": "Stake account lamports do not fit the settlement funding"
+    },
+    {
+      "code": 6088,
+      "name": "invalidPendingAuthority",
+      "msg": "Signer is not the pending authority proposed to be accepted"
+    },
+    {
+      "code": 6089,
+      "name": "configAuthorityChangeNotProposed",
+      "msg": "Config authorities can be changed only by proposing and accepting them"
+    },
+    {
+      "code": 6090,
+      "name": "configChangeRequiresTimelock",
+      "msg": "Lockup-sensitive config parameters can be changed only by queueing the change"
+    },
+    {
+      "code": 6091,
+      "name": "configChangeActivationTooEarly",
+      "msg": "Queued config change activation epoch is earlier than the withdraw lockup permits"
+    },
+    {
+      "code": 6092,
+      "name": "noQueuedConfigChange",
+      "msg": "No config change is queued"
+    },
+    {
+      "code": 6093,
+      "name": "queuedConfigChangeNotActive",
+      "msg": "Queued config change activation epoch has not been reached yet"
+    },
+    {
+      "code": 6094,
+      "name": "invalidPauseOperations",
+      "msg": "Invalid bitmask of pause operation groups"
+    },
+    {
+      "code": 6095,
+      "name": "bondHasOpenSettlements",
+      "msg": "Bond cannot be closed while settlements are open"
+    },
+    {
+      "code": 6096,
+      "name": "bondHasOutstandingWithdrawRequest",
+      "msg": "Bond cannot be closed while a withdraw request is outstanding"
+    },
+    {
+      "code": 6097,
+      "name": "bondProductEffectiveEpochTooEarly",
+      "msg": "Bond product config effective epoch has to be in the future"
+    },
+    {
+      "code": 6098,
+      "name": "invalidClaimToAccount",
+      "msg": "Claim can be redirected to a system account or a stake account of the withdraw authority only"
+    },
+    {
+      "code": 6099,
+      "name": "emptyClaimBatch",
+      "msg": "Batch of settlement claims is empty"
+    },
+    {
+      "code": 6100,
+      "name": "settlementExpirationNotExtended",
+      "msg": "Settlement expiration epoch has to be extended beyond the current expiration and epoch"
+    },
+    {
+      "code": 6101,
+      "name": "invalidReplaceableSettlementSeed",
+      "msg": "Replaceable settlement seed cannot be zeroed"
+    },
+    {
+      "code": 6102,
+      "name": "settlementRootNotReplaceable",
+      "msg": "Settlement merkle root is a seed of the settlement address and cannot be replaced"
+    },
+    {
+      "code": 6103,
+      "name": "settlementClaimingStarted",
+      "msg": "Settlement claiming has already started"
+    },
+    {
+      "code": 6104,
+      "name": "settlementClaimsChunkMismatch",
+      "msg": "Settlement claims chunk does not match the claim record index"
+    },
+    {
+      "code": 6105,
+      "name": "settlementClaimsNotChunkable",
+      "msg": "Settlement claims account cannot be split into chunks"
+    },
+    {
+      "code": 6106,
+      "name": "settlementClaimsChunksMismatch",
+      "msg": "Settlement claims chunk accounts do not match the initialized chunks"
+    },
+    {
+      "code": 6107,
+      "name": "settlementClaimsChunksExceeded",
+      "msg": "Settlement claims chunks cannot store the number of merkle nodes"
+    },
+    {
+      "code": 6108,
+      "name": "bondLedgerMismatch",
+      "msg": "Bond ledger account does not belong to the bond"
+    },
+    {
+      "code": 6109,
+      "name": "invalidCollateralExchangeRate",
+      "msg": "Collateral mint exchange rate has to be positive"
+    },
+    {
+      "code": 6110,
+      "name": "collateralExchangeRateStale",
+      "msg": "Collateral mint exchange rate is not updated for the current epoch"
+    },
+    {
+      "code": 6111,
+      "name": "bondCounterUnderflow",
+      "msg": "Bond counter of the open accounts cannot be decreased below zero"
+    },
+    {
+      "code": 6112,
+      "name": "bondHasDelegatedStake",
+      "msg": "Bond cannot be closed while a stake account is delegated to the vote account"
     },
     {
-      "code": 6046,
-      "name": "settlementAuthorityMismatch",
-      "msg": "Stake account staker authority mismatches with the settlement authority"
+      "code": 6113,
+      "name": "bondHasBondProducts",
+      "msg": "Bond cannot be closed while bond products exist"
     },
     {
-      "code": 6047,
-      "name": "stakeDelegationMismatch",
-      "msg": "Delegation of provided stake account mismatches"
+      "code": 6114,
+      "name": "bondHasTokenCollateral",
+      "msg": "Bond cannot be closed while a bond token vault holds collateral"
     },
     {
-      "code": 6048,
-      "name": "withdrawRequestAmountTooSmall",
-      "msg": "Too small non-withdrawn withdraw request amount, cancel and init new one"
+      "code": 6115,
+      "name": "bondHasBondLedger",
+      "msg": "Bond cannot be closed while the bond ledger exists"
     },
     {
-      "code": 6049,
-      "name": "withdrawRequestAlreadyFulfilled",
-      "msg": "Withdraw request has been already fulfilled"
+      "code": 6116,
+      "name": "bondLedgerMissing",
+      "msg": "Bond ledger account is required as the last remaining account"
     },
     {
-      "code": 6050,
-      "name": "claimSettlementMerkleTreeNodeMismatch",
-      "msg": "Claim settlement merkle tree node mismatch"
+      "code": 6117,
+      "name": "bondAccountsNotCounted",
+      "msg": "Bond cannot be closed before its accounts are counted by the operator"
+    }
+  ],
+  "types": [
+    {
+      "name": "bond",
+      "docs": [
+        "Bond account for a validator vote address"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "docs": [
+              "Program root config address. Validator bond is created for this config as PDA"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "voteAccount",
+            "docs": [
+              "Validator vote address that this bond account is crated for",
+              "INVARIANTS:",
+              "- one bond account per validator vote address",
+              "- this program does NOT change stake account delegation voter_pubkey to any other validator vote account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "Authority that may close the bond or withdraw stake accounts associated with the bond",
+              "The same powers has got the owner of the validator vote account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "cpmpe",
+            "docs": [
+              "Cost per mille per epoch.",
+              "This field represents the bid the bond (vote) account owner is willing to pay",
+              "for up to the `max_stake_wanted` being delegated.",
+              "The bid is in cost per mille per epoch similar to Google ads cpm system.",
+              "---",
+              "The actual amount of lamports deducted from the bond account for the processed bid",
+              "is based on the actual delegated lamports during the epoch."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA Bond address bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "maxStakeWanted",
+            "docs": [
+              "Maximum stake (in lamports) that the bond (vote) account owner requests.",
+              "This is the maximum stake that will be distributed to the vote account",
+              "when all other constraints are fulfilled (managed off-chain).",
+              "The vote account owner then goes to auction to obtain up to that maximum.",
+              "Use the `cpmpe` field to define the bid for this purpose."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "withdrawRequestIndex",
+            "docs": [
+              "Index of the last created indexed withdraw request.",
+              "The index 0 is occupied by the (legacy) withdraw request that is derived without the index seed."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pendingAuthority",
+            "docs": [
+              "Proposed bond authority that has to accept the role; default pubkey when nothing is proposed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "openSettlements",
+            "docs": [
+              "Number of settlements created for the bond and not closed yet.",
+              "Settlements created before the counter was introduced are not counted."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "openWithdrawRequests",
+            "docs": [
+              "Number of withdraw requests created for the bond and not cancelled yet.",
+              "Withdraw requests created before the counter was introduced are not counted."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "openBondProducts",
+            "docs": [
+              "Number of bond products created for the bond and not closed yet.",
+              "Bond products created before the counter was introduced are not counted."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "fundedTokenVaults",
+            "docs": [
+              "Number of bond token vaults holding a collateral.",
+              "An emptied vault account is left behind and is reused when the bond is re-created by init_bond."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bondLedgerBump",
+            "docs": [
+              "PDA bump of the bond ledger account, set while the bond ledger exists.",
+              "The instructions updating the bond ledger require it as the last remaining account then."
+            ],
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "accountsCounted",
+            "docs": [
+              "All open settlements, withdraw requests and bond products of the bond are counted in the counters.",
+              "Set by init_bond; a bond created before the counters were introduced has it set",
+              "by the operator with count_bond_accounts."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserve space for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                59
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6051,
-      "name": "wrongStakeAccountStaker",
-      "msg": "Wrong staker authority of the stake account"
+      "name": "bondLedger",
+      "docs": [
+        "History of the bond operations for the last BOND_LEDGER_EPOCHS epochs.",
+        "The records are stored in a ring buffer indexed by the epoch,",
+        "a record of an older epoch is overwritten when the epoch's slot is used again."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bond",
+            "docs": [
+              "Bond account the ledger is created for"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bond ledger address bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "records",
+            "docs": [
+              "Records of the ring buffer, the record of an epoch is at index `epoch % BOND_LEDGER_EPOCHS`"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "bondLedgerRecord"
+                  }
+                },
+                32
+              ]
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserve space for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6052,
-      "name": "alreadyPaused",
-      "msg": "Requested pause and already Paused"
+      "name": "bondLedgerRecord",
+      "docs": [
+        "Counters of the bond operations within one epoch"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "docs": [
+              "epoch the counters are collected for"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "fundedLamports",
+            "docs": [
+              "lamports of the bond stake accounts funded to settlements"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimedLamports",
+            "docs": [
+              "lamports claimed from the bond settlements"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "withdrawnLamports",
+            "docs": [
+              "lamports withdrawn from the bond by claimed withdraw requests"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "closedSettlements",
+            "docs": [
+              "number of the bond settlements closed"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bondProduct",
+      "docs": [
+        "Bond products configuration.",
+        "Validator configures different products on a bond to offer various staking services."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "docs": [
+              "The bond config account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bond",
+            "docs": [
+              "The bond this product belongs to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "voteAccount",
+            "docs": [
+              "Validator vote account this product is associated with"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "productType",
+            "docs": [
+              "Product type discriminator"
+            ],
+            "type": {
+              "defined": {
+                "name": "productType"
+              }
+            }
+          },
+          {
+            "name": "configData",
+            "docs": [
+              "Type-specific configuration data"
+            ],
+            "type": {
+              "defined": {
+                "name": "productTypeConfig"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pendingConfig",
+            "docs": [
+              "Configuration that replaces `config_data` from its effective epoch on"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "pendingProductConfig"
+                }
+              }
+            }
+          },
+          {
+            "name": "counted",
+            "docs": [
+              "Bond product is counted in the bond `open_bond_products`;",
+              "not set for bond products created before the counter was introduced"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6053,
-      "name": "notPaused",
-      "msg": "Requested resume, but not Paused"
+      "name": "boolValueChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "old",
+            "type": "bool"
+          },
+          {
+            "name": "new",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6054,
-      "name": "programIsPaused",
-      "msg": "Emergency Pause is Active"
+      "name": "bumps",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pda",
+            "type": "u8"
+          },
+          {
+            "name": "stakerAuthority",
+            "type": "u8"
+          },
+          {
+            "name": "settlementClaims",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6055,
-      "name": "invalidPauseAuthority",
-      "msg": "Invalid pause authority"
+      "name": "cancelBondAuthorityEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bond",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6056,
-      "name": "mergeMismatchSameSourceDestination",
-      "msg": "Source and destination cannot be the same for merge operation"
+      "name": "cancelConfigAuthorityEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdminAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingOperatorAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingPauseAuthority",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6057,
-      "name": "wrongStakeAccountState",
-      "msg": "Wrong state of the stake account"
+      "name": "cancelQueuedConfigEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "epochsToClaimSettlement",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "withdrawLockupEpochs",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "minimumStakeLamports",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "slotsToStartSettlementClaiming",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "activationEpoch",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6058,
-      "name": "validatorIdentityBondMintMismatch",
-      "msg": "Validator identity mismatch for bond mint"
+      "name": "cancelSettlementEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bond",
+            "type": "pubkey"
+          },
+          {
+            "name": "settlement",
+            "type": "pubkey"
+          },
+          {
+            "name": "merkleRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "maxTotalClaim",
+            "type": "u64"
+          },
+          {
+            "name": "maxMerkleNodes",
+            "type": "u64"
+          },
+          {
+            "name": "lamportsFunded",
+            "type": "u64"
+          },
+          {
+            "name": "lamportsClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "merkleNodesClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "splitRentCollector",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "splitRentRefund",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "rentCollector",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6059,
-      "name": "invalidBondMintSupply",
-      "msg": "Bond mint permits only a single token to exist"
+      "name": "cancelWithdrawRequestEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "withdrawRequest",
+            "type": "pubkey"
+          },
+          {
+            "name": "bond",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "requestedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "withdrawnAmount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6060,
-      "name": "operatorAndPauseAuthorityMismatch",
-      "msg": "Operation permitted only to operator or pause authority"
+      "name": "claimSettlementBatchArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proof",
+            "docs": [
+              "multi-proof that all the claims are appropriate, siblings shared by the claims are included once"
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "stakeAccountStaker",
+            "docs": [
+              "staker authority of the stake_account_to; merkle root verification of all claims"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "stakeAccountWithdrawer",
+            "docs": [
+              "withdrawer authority of the stake_account_to; merkle root verification of all claims"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "claims",
+            "docs": [
+              "claims of the stake and withdraw authorities paid into the stake_account_to"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "claimSettlementBatchItem"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6061,
-      "name": "settlementNotReadyForClaiming",
-      "msg": "Settlement slots to start claiming not expired yet"
+      "name": "claimSettlementBatchEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "settlement",
+            "type": "pubkey"
+          },
+          {
+            "name": "settlementLamportsClaimed",
+            "type": {
+              "defined": {
+                "name": "u64ValueChange"
+              }
+            }
+          },
+          {
+            "name": "settlementMerkleNodesClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "stakeAccountTo",
+            "type": "pubkey"
+          },
+          {
+            "name": "stakeAccountWithdrawer",
+            "type": "pubkey"
+          },
+          {
+            "name": "stakeAccountStaker",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "sum of the claims"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "indexes",
+            "docs": [
+              "indexes of the claimed merkle tree nodes"
+            ],
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6062,
-      "name": "invalidVoteAccountType",
-      "msg": "Unsupported vote account type to deserialize"
+      "name": "claimSettlementBatchItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "claim",
+            "docs": [
+              "claim amount; merkle root verification"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "index, ordered claim record in the settlement list; merkle root verification"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6063,
-      "name": "maxStakeWantedTooLow",
-      "msg": "Max stake wanted value is lower to minimum configured value"
+      "name": "claimSettlementEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "settlementClaim",
+            "type": "pubkey"
+          },
+          {
+            "name": "settlement",
+            "type": "pubkey"
+          },
+          {
+            "name": "settlementLamportsClaimed",
+            "type": {
+              "defined": {
+                "name": "u64ValueChange"
+              }
+            }
+          },
+          {
+            "name": "settlementMerkleNodesClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "stakeAccountTo",
+            "type": "pubkey"
+          },
+          {
+            "name": "stakeAccountWithdrawer",
+            "type": "pubkey"
+          },
+          {
+            "name": "stakeAccountStaker",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "rentCollector",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6064,
-      "name": "noStakeOrNotActivatingOrActivated",
-      "msg": "Stake account is not activating or activated"
+      "name": "claimSettlementRedirectEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "settlement",
+            "type": "pubkey"
+          },
+          {
+            "name": "settlementLamportsClaimed",
+            "type": {
+              "defined": {
+                "name": "u64ValueChange"
+              }
+            }
+          },
+          {
+            "name": "settlementMerkleNodesClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "claimTo",
+            "type": "pubkey"
+          },
+          {
+            "name": "stakeAccountWithdrawer",
+            "type": "pubkey"
+          },
+          {
+            "name": "stakeAccountStaker",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6065,
-      "name": "bitmapSizeMismatch",
-      "msg": "Data size mismatch for the bitmap"
+      "name": "claimSettlementV2Args",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proof",
+            "docs": [
+              "proof that the claim is appropriate"
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "treeNodeHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "stakeAccountStaker",
+            "docs": [
+              "staker authority of the stake_account_to; merkle root verification"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "stakeAccountWithdrawer",
+            "docs": [
+              "withdrawer authority of the stake_account_to; merkle root verification"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "claim",
+            "docs": [
+              "claim amount; merkle root verification"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "index, ordered claim record in the settlement list; merkle root verification"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6066,
-      "name": "bitmapIndexOutOfBonds",
-      "msg": "Bitmap index out of bounds"
+      "name": "claimSettlementV2Event",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "settlement",
+            "type": "pubkey"
+          },
+          {
+            "name": "settlementLamportsClaimed",
+            "type": {
+              "defined": {
+                "name": "u64ValueChange"
+              }
+            }
+          },
+          {
+            "name": "settlementMerkleNodesClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "stakeAccountTo",
+            "type": "pubkey"
+          },
+          {
+            "name": "stakeAccountWithdrawer",
+            "type": "pubkey"
+          },
+          {
+            "name": "stakeAccountStaker",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6067,
-      "name": "settlementClaimsNotInitialized",
-      "msg": "SettlementClaims account not fully initialized, missing data size"
+      "name": "claimWithdrawRequestEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "withdrawRequest",
+            "type": "pubkey"
+          },
+          {
+            "name": "bond",
+            "type": "pubkey"
+          },
+          {
+            "name": "voteAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "stakeAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "splitStake",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "splitStakeData"
+                }
+              }
+            }
+          },
+          {
+            "name": "newStakeAccountOwner",
+            "type": "pubkey"
+          },
+          {
+            "name": "withdrawingAmount",
+            "type": "u64"
+          },
+          {
+            "name": "withdrawnAmount",
+            "type": {
+              "defined": {
+                "name": "u64ValueChange"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6068,
-      "name": "settlementClaimsTooManyRecords",
-      "msg": "SettlementClaims records exceed maximum to fit Solana account size"
+      "name": "closeBondEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bond",
+            "type": "pubkey"
+          },
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "voteAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "rentCollector",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6069,
-      "name": "settlementClaimsAlreadyInitialized",
-      "msg": "SettlementClaims already initialized, no need to increase account size"
+      "name": "closeBondLedgerEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondLedger",
+            "type": "pubkey"
+          },
+          {
+            "name": "bond",
+            "type": "pubkey"
+          },
+          {
+            "name": "voteAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "rentCollector",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6070,
-      "name": "settlementAlreadyClaimed",
-      "msg": "Settlement has been already claimed"
+      "name": "closeBondProductEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondProduct",
+            "type": "pubkey"
+          },
+          {
+            "name": "bond",
+            "type": "pubkey"
+          },
+          {
+            "name": "voteAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "productType",
+            "type": {
+              "defined": {
+                "name": "productType"
+              }
+            }
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "rentCollector",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6071,
-      "name": "invalidStakeHistoryEntry",
-      "msg": "Failed to obtain stake history entry"
+      "name": "closeSettlementEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bond",
+            "type": "pubkey"
+          },
+          {
+            "name": "settlement",
+            "type": "pubkey"
+          },
+          {
+            "name": "merkleRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "maxTotalClaim",
+            "type": "u64"
+          },
+          {
+            "name": "maxMerkleNodes",
+            "type": "u64"
+          },
+          {
+            "name": "lamportsFunded",
+            "type": "u64"
+          },
+          {
+            "name": "lamportsClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "merkleNodesClaimed",
+            "type": "u64"
+          },
+          {
+            "name": "splitRentCollector",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "splitRentRefund",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "rentCollector",
+            "type": "pubkey"
+          },
+          {
+            "name": "expirationEpoch",
+            "type": "u64"
+          },
+          {
+            "name": "currentEpoch",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6072,
-      "name": "invalidProductType",
-      "msg": "Invalid Bond Product type"
+      "name": "collateralMint",
+      "docs": [
+        "Token mint permitted by the config admin to be used as a bond collateral (e.g., wSOL, LST).",
+        "The token amount is valued in lamports by the exchange rate lowered by the haircut,",
+        "the haircut covers the risk of the token price deviating from the exchange rate."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "docs": [
+              "Config account the collateral mint is permitted for"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "SPL token mint of the collateral"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "haircutBps",
+            "docs": [
+              "Haircut applied on the token amount when valued in lamports (basis points)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "enabled",
+            "docs": [
+              "When disabled, the bonds cannot be funded with the mint anymore,",
+              "while withdrawing and funding settlements is still permitted"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA account bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "exchangeRate",
+            "docs": [
+              "Lamports value of one token (EXCHANGE_RATE_SCALE of token base units), updated by the operator"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "exchangeRateEpoch",
+            "docs": [
+              "Epoch of the last exchange rate update, settlement funding requires the rate of the current epoch"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved space for future changes"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                48
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6073,
-      "name": "invalidBondProductData",
-      "msg": "Invalid data for Bond Product"
+      "name": "commissionProductConfig",
+      "docs": [
+        "Configuration of commissions. The commission is permitted to be negative to allow for subsidies."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "inflationBps",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "mevBps",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "blockBps",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6074,
-      "name": "bondProductSerializationError",
-      "msg": "Failed to serialize Bond Product"
+      "name": "config",
+      "docs": [
+        "Root account that configures the validator bonds program"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "adminAuthority",
+            "docs": [
+              "Admin authority that can update the config"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "operatorAuthority",
+            "docs": [
+              "Operator authority (bot hot wallet)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "epochsToClaimSettlement",
+            "docs": [
+              "How many epochs permitting to claim the settlement"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "withdrawLockupEpochs",
+            "docs": [
+              "How many epochs before withdraw is allowed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minimumStakeLamports",
+            "docs": [
+              "Minimum amount of lamports to be considered for a stake account operations (e.g., split)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bondsWithdrawerAuthorityBump",
+            "docs": [
+              "PDA bonds stake accounts authority bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pauseAuthority",
+            "docs": [
+              "Authority that can pause the program in case of emergency"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Bitmask of the paused operation groups (see PAUSE_* constants), legacy value 1 pauses all of them"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "slotsToStartSettlementClaiming",
+            "docs": [
+              "How many slots to wait before settlement is permitted to be claimed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minBondMaxStakeWanted",
+            "docs": [
+              "Minimum value of max_stake_wanted to be configured by vote account owners at bond."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pendingAdminAuthority",
+            "docs": [
+              "Proposed admin authority that has to accept the role; default pubkey when nothing is proposed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingOperatorAuthority",
+            "docs": [
+              "Proposed operator authority that has to accept the role; default pubkey when nothing is proposed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingPauseAuthority",
+            "docs": [
+              "Proposed pause authority that has to accept the role; default pubkey when nothing is proposed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "queuedChange",
+            "docs": [
+              "Change of the lockup-sensitive parameters waiting for its activation epoch"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "queuedConfigChange"
+                }
+              }
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved space for future changes"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                322
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6075,
-      "name": "bondProductTypeMismatch",
-      "msg": "Failed to serialize Bond Product"
+      "name": "configureBondArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondAuthority",
+            "docs": [
+              "New bond authority proposed to manage the bond account.",
+              "The proposed authority takes the role after signing accept_bond_authority."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "cpmpe",
+            "docs": [
+              "New `cpmpe` value (cost per mille per epoch).",
+              "It defines the bid for the validator to get delegated up to `max_stake_wanted` lamports."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxStakeWanted",
+            "docs": [
+              "New `max_stake_wanted` value that the vote account owner declares",
+              "as the maximum delegated stake desired."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6076,
-      "name": "bondProductSetupNotPermitted",
-      "msg": "Wrong authority to setup the validator bond product account"
+      "name": "configureBondEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondAuthority",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "pubkeyValueChange"
+                }
+              }
+            }
+          },
+          {
+            "name": "cpmpe",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "u64ValueChange"
+                }
+              }
+            }
+          },
+          {
+            "name": "maxStakeWanted",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "u64ValueChange"
+                }
+              }
+            }
+          },
+          {
+            "name": "pendingBondAuthority",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6077,
-      "name": "invalidBondProductAddress",
-      "msg": "Fail to create program address for Bond Product"
+      "name": "configureBondProductArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "configData",
+            "type": {
+              "defined": {
+                "name": "productTypeConfig"
+              }
+            }
+          },
+          {
+            "name": "effectiveFromEpoch",
+            "docs": [
+              "`None` applies the config immediately, discarding any pending config;",
+              "the epoch (in the future) schedules the config as pending"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6078,
-      "name": "productTypeConfigValidationFailure",
-      "msg": "Fail to validate ProductConfig value"
-    }
-  ],
-  "types": [
-    {
-      "name": "bond",
-      "docs": [
-        "Bond account for a validator vote address"
-      ],
+      "name": "configureBondProductEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "config",
-            "docs": [
-              "Program root config address. Validator bond is created for this config as PDA"
-            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bondProduct",
+            "type": "pubkey"
+          },
+          {
+            "name": "bond",
             "type": "pubkey"
           },
           {
             "name": "voteAccount",
-            "docs": [
-              "Validator vote address that this bond account is crated for",
-              "INVARIANTS:",
-              "- one bond account per validator vote address",
-              "- this program does NOT change stake account delegation voter_pubkey to any other validator vote account"
-            ],
             "type": "pubkey"
           },
           {
-            "name": "authority",
+            "name": "productType",
+            "type": {
+              "defined": {
+                "name": "productType"
+              }
+            }
+          },
+          {
+            "name": "oldConfigData",
+            "type": {
+              "defined": {
+                "name": "productTypeConfig"
+              }
+            }
+          },
+          {
+            "name": "newConfigData",
+            "type": {
+              "defined": {
+                "name": "productTypeConfig"
+              }
+            }
+          },
+          {
+            "name": "effectiveFromEpoch",
             "docs": [
-              "Authority that may close the bond or withdraw stake accounts associated with the bond",
-              "The same powers has got the owner of the validator vote account"
+              "`None` when the new config applies immediately"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "configureBondWithMintArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "validatorIdentity",
+            "docs": [
+              "Validator identity configured within the vote account."
             ],
             "type": "pubkey"
           },
           {
-            "name": "cpmpe",
+            "name": "bondAuthority",
             "docs": [
-              "Cost per mille per epoch.",
-              "This field represents the bid the bond (vote) account owner is willing to pay",
-              "for up to the `max_stake_wanted` being delegated.",
-              "The bid is in cost per mille per epoch similar to Google ads cpm system.",
-              "---",
-              "The actual amount of lamports deducted from the bond account for the processed bid",
-              "is based on the actual delegated lamports during the epoch."
+              "New bond authority proposed to manage the bond account.",
+              "The proposed authority takes the role after signing accept_bond_authority."
             ],
-            "type": "u64"
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "bump",
+            "name": "cpmpe",
             "docs": [
-              "PDA Bond address bump seed"
+              "New `cpmpe` value (cost per mille per epoch).",
+              "It defines the bid for the validator to get delegated up to `max_stake_wanted` lamports."
             ],
-            "type": "u8"
+            "type": {
+              "option": "u64"
+            }
           },
           {
             "name": "maxStakeWanted",
             "docs": [
-              "Maximum stake (in lamports) that the bond (vote) account owner requests.",
-              "This is the maximum stake that will be distributed to the vote account",
-              "when all other constraints are fulfilled (managed off-chain).",
-              "The vote account owner then goes to auction to obtain up to that maximum.",
-              "Use the `cpmpe` field to define the bid for this purpose."
+              "new max_stake_wanted value that vote account owner declares",
+              "as the maximum delegated stake wanted"
             ],
-            "type": "u64"
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "configureBondWithMintEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "validatorIdentity",
+            "type": "pubkey"
           },
           {
-            "name": "reserved",
-            "docs": [
-              "reserve space for future extensions"
-            ],
+            "name": "bondAuthority",
             "type": {
-              "array": [
-                "u8",
-                134
-              ]
+              "option": {
+                "defined": {
+                  "name": "pubkeyValueChange"
+                }
+              }
+            }
+          },
+          {
+            "name": "cpmpe",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "u64ValueChange"
+                }
+              }
+            }
+          },
+          {
+            "name": "maxStakeWanted",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "u64ValueChange"
+                }
+              }
+            }
+          },
+          {
+            "name": "pendingBondAuthority",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "configureCollateralMintArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "haircutBps",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "enabled",
+            "type": {
+              "option": "bool"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "configureCollateralMintEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collateralMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "haircutBps",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "u64ValueChange"
+                }
+              }
+            }
+          },
+          {
+            "name": "enabled",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "boolValueChange"
+                }
+              }
             }
           }
         ]
       }
     },
     {
-      "name": "bondProduct",
-      "docs": [
-        "Bond products configuration.",
-        "Validator configures different products on a bond to offer various staking services."
-      ],
+      "name": "configureConfigArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "config",
+            "name": "admin",
             "docs": [
-              "The bond config account"
+              "authorities are not permitted to be changed directly, see propose_config_authority"
             ],
-            "type": "pubkey"
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "bond",
-            "docs": [
-              "The bond this product belongs to"
-            ],
-            "type": "pubkey"
+            "name": "operator",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "voteAccount",
-            "docs": [
-              "Validator vote account this product is associated with"
-            ],
-            "type": "pubkey"
+            "name": "pauseAuthority",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "productType",
+            "name": "epochsToClaimSettlement",
             "docs": [
-              "Product type discriminator"
+              "lockup-sensitive params are not permitted to be changed directly, see queue_configure_config"
             ],
             "type": {
-              "defined": {
-                "name": "productType"
-              }
+              "option": "u64"
             }
           },
           {
-            "name": "configData",
-            "docs": [
-              "Type-specific configuration data"
-            ],
+            "name": "withdrawLockupEpochs",
             "type": {
-              "defined": {
-                "name": "productTypeConfig"
-              }
+              "option": "u64"
             }
           },
           {
-            "name": "bump",
-            "docs": [
-              "Bump seed"
-            ],
-            "type": "u8"
+            "name": "minimumStakeLamports",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "slotsToStartSettlementClaiming",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "minBondMaxStakeWanted",
+            "type": {
+              "option": "u64"
+            }
           }
         ]
       }
     },
     {
-      "name": "bumps",
+      "name": "configureConfigEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "pda",
-            "type": "u8"
+            "name": "adminAuthority",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "pubkeyValueChange"
+                }
+              }
+            }
           },
           {
-            "name": "stakerAuthority",
-            "type": "u8"
+            "name": "operatorAuthority",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "pubkeyValueChange"
+                }
+              }
+            }
           },
           {
-            "name": "settlementClaims",
-            "type": "u8"
+            "name": "pauseAuthority",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "pubkeyValueChange"
+                }
+              }
+            }
+          },
+          {
+            "name": "epochsToClaimSettlement",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "u64ValueChange"
+                }
+              }
+            }
+          },
+          {
+            "name": "minimumStakeLamports",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "u64ValueChange"
+                }
+              }
+            }
+          },
+          {
+            "name": "withdrawLockupEpochs",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "u64ValueChange"
+                }
+              }
+            }
+          },
+          {
+            "name": "slotsToStartSettlementClaiming",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "u64ValueChange"
+                }
+              }
+            }
+          },
+          {
+            "name": "minBondMaxStakeWanted",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "u64ValueChange"
+                }
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "cancelSettlementEvent",
+      "name": "countBondAccountsEvent",
       "type": {
         "kind": "struct",
         "fields": [
@@ -4479,190 +10493,185 @@ export type ValidatorBonds = {
             "type": "pubkey"
           },
           {
-            "name": "settlement",
+            "name": "operatorAuthority",
             "type": "pubkey"
           },
           {
-            "name": "merkleRoot",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "maxTotalClaim",
+            "name": "countedSettlements",
             "type": "u64"
           },
           {
-            "name": "maxMerkleNodes",
+            "name": "countedWithdrawRequests",
             "type": "u64"
           },
           {
-            "name": "lamportsFunded",
+            "name": "countedBondProducts",
             "type": "u64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "delegationInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "lamportsClaimed",
-            "type": "u64"
+            "name": "voterPubkey",
+            "docs": [
+              "to whom the stake is delegated"
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "merkleNodesClaimed",
+            "name": "stake",
+            "docs": [
+              "activated stake amount, set at delegate() time"
+            ],
             "type": "u64"
           },
           {
-            "name": "splitRentCollector",
-            "type": {
-              "option": "pubkey"
-            }
-          },
-          {
-            "name": "splitRentRefund",
-            "type": {
-              "option": "pubkey"
-            }
-          },
-          {
-            "name": "rentCollector",
-            "type": "pubkey"
+            "name": "activationEpoch",
+            "docs": [
+              "epoch at which this stake was activated, std::Epoch::MAX if is a bootstrap stake"
+            ],
+            "type": "u64"
           },
           {
-            "name": "authority",
-            "type": "pubkey"
+            "name": "deactivationEpoch",
+            "docs": [
+              "epoch the stake was deactivated, std::Epoch::MAX if not deactivated"
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "cancelWithdrawRequestEvent",
+      "name": "emergencyPauseEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "withdrawRequest",
+            "name": "config",
             "type": "pubkey"
           },
           {
-            "name": "bond",
+            "name": "adminAuthority",
             "type": "pubkey"
           },
           {
-            "name": "authority",
+            "name": "operatorAuthority",
             "type": "pubkey"
           },
           {
-            "name": "requestedAmount",
+            "name": "epochsToClaimSettlement",
             "type": "u64"
           },
           {
-            "name": "withdrawnAmount",
+            "name": "withdrawLockupEpochs",
+            "type": "u64"
+          },
+          {
+            "name": "minimumStakeLamports",
             "type": "u64"
+          },
+          {
+            "name": "pauseAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "changedOperations",
+            "docs": [
+              "operation groups whose pause state was changed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pausedOperations",
+            "docs": [
+              "all operation groups paused after the change"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "emergencyPauseResumeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "operations",
+            "docs": [
+              "bitmask of operation groups to be paused or resumed (see PAUSE_* constants)"
+            ],
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "claimSettlementEvent",
+      "name": "emergencyResumeEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "settlementClaim",
+            "name": "config",
             "type": "pubkey"
           },
           {
-            "name": "settlement",
+            "name": "adminAuthority",
             "type": "pubkey"
           },
           {
-            "name": "settlementLamportsClaimed",
-            "type": {
-              "defined": {
-                "name": "u64ValueChange"
-              }
-            }
+            "name": "operatorAuthority",
+            "type": "pubkey"
           },
           {
-            "name": "settlementMerkleNodesClaimed",
+            "name": "epochsToClaimSettlement",
             "type": "u64"
           },
           {
-            "name": "stakeAccountTo",
-            "type": "pubkey"
+            "name": "withdrawLockupEpochs",
+            "type": "u64"
           },
           {
-            "name": "stakeAccountWithdrawer",
-            "type": "pubkey"
+            "name": "minimumStakeLamports",
+            "type": "u64"
           },
           {
-            "name": "stakeAccountStaker",
+            "name": "pauseAuthority",
             "type": "pubkey"
           },
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "changedOperations",
+            "docs": [
+              "operation groups whose pause state was changed"
+            ],
+            "type": "u8"
           },
           {
-            "name": "rentCollector",
-            "type": "pubkey"
+            "name": "pausedOperations",
+            "docs": [
+              "all operation groups paused after the change"
+            ],
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "claimSettlementV2Args",
+      "name": "extendSettlementArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "proof",
-            "docs": [
-              "proof that the claim is appropriate"
-            ],
-            "type": {
-              "vec": {
-                "array": [
-                  "u8",
-                  32
-                ]
-              }
-            }
-          },
-          {
-            "name": "treeNodeHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "stakeAccountStaker",
-            "docs": [
-              "staker authority of the stake_account_to; merkle root verification"
-            ],
-            "type": "pubkey"
-          },
-          {
-            "name": "stakeAccountWithdrawer",
-            "docs": [
-              "withdrawer authority of the stake_account_to; merkle root verification"
-            ],
-            "type": "pubkey"
-          },
-          {
-            "name": "claim",
-            "docs": [
-              "claim amount; merkle root verification"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "index",
+            "name": "expirationEpoch",
             "docs": [
-              "index, ordered claim record in the settlement list; merkle root verification"
+              "the last epoch when the settlement can be claimed,",
+              "it has to be later than the current expiration epoch of the settlement"
             ],
             "type": "u64"
           }
@@ -4670,58 +10679,81 @@ export type ValidatorBonds = {
       }
     },
     {
-      "name": "claimSettlementV2Event",
+      "name": "extendSettlementEvent",
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "bond",
+            "type": "pubkey"
+          },
           {
             "name": "settlement",
             "type": "pubkey"
           },
           {
-            "name": "settlementLamportsClaimed",
+            "name": "operatorAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "expirationEpoch",
             "type": {
               "defined": {
                 "name": "u64ValueChange"
               }
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "fundBondEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "settlementMerkleNodesClaimed",
-            "type": "u64"
+            "name": "bond",
+            "type": "pubkey"
           },
           {
-            "name": "stakeAccountTo",
+            "name": "voteAccount",
             "type": "pubkey"
           },
           {
-            "name": "stakeAccountWithdrawer",
+            "name": "stakeAccount",
             "type": "pubkey"
           },
           {
-            "name": "stakeAccountStaker",
+            "name": "stakeAuthoritySigner",
             "type": "pubkey"
           },
           {
-            "name": "amount",
+            "name": "depositedAmount",
             "type": "u64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "fundBondTokenArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "index",
+            "name": "amount",
+            "docs": [
+              "amount of tokens to be deposited to the bond token vault"
+            ],
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "claimWithdrawRequestEvent",
+      "name": "fundBondTokenEvent",
       "type": {
         "kind": "struct",
         "fields": [
-          {
-            "name": "withdrawRequest",
-            "type": "pubkey"
-          },
           {
             "name": "bond",
             "type": "pubkey"
@@ -4731,40 +10763,30 @@ export type ValidatorBonds = {
             "type": "pubkey"
           },
           {
-            "name": "stakeAccount",
+            "name": "mint",
             "type": "pubkey"
           },
           {
-            "name": "splitStake",
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "splitStakeData"
-                }
-              }
-            }
+            "name": "bondTokenVault",
+            "type": "pubkey"
           },
           {
-            "name": "newStakeAccountOwner",
+            "name": "depositor",
             "type": "pubkey"
           },
           {
-            "name": "withdrawingAmount",
+            "name": "amount",
             "type": "u64"
           },
           {
-            "name": "withdrawnAmount",
-            "type": {
-              "defined": {
-                "name": "u64ValueChange"
-              }
-            }
+            "name": "lamportsValue",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "closeSettlementEvent",
+      "name": "fundSettlementEvent",
       "type": {
         "kind": "struct",
         "fields": [
@@ -4777,21 +10799,12 @@ export type ValidatorBonds = {
             "type": "pubkey"
           },
           {
-            "name": "merkleRoot",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "maxTotalClaim",
+            "name": "fundingAmount",
             "type": "u64"
           },
           {
-            "name": "maxMerkleNodes",
-            "type": "u64"
+            "name": "stakeAccount",
+            "type": "pubkey"
           },
           {
             "name": "lamportsFunded",
@@ -4806,231 +10819,161 @@ export type ValidatorBonds = {
             "type": "u64"
           },
           {
-            "name": "splitRentCollector",
+            "name": "splitStakeAccount",
             "type": {
-              "option": "pubkey"
+              "option": {
+                "defined": {
+                  "name": "splitStakeData"
+                }
+              }
             }
           },
           {
-            "name": "splitRentRefund",
+            "name": "splitRentCollector",
             "type": {
               "option": "pubkey"
             }
           },
           {
-            "name": "rentCollector",
-            "type": "pubkey"
-          },
-          {
-            "name": "expirationEpoch",
-            "type": "u64"
-          },
-          {
-            "name": "currentEpoch",
+            "name": "splitRentAmount",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "commissionProductConfig",
-      "docs": [
-        "Configuration of commissions. The commission is permitted to be negative to allow for subsidies."
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "inflationBps",
-            "type": {
-              "option": "i64"
-            }
-          },
-          {
-            "name": "mevBps",
-            "type": {
-              "option": "i64"
-            }
-          },
-          {
-            "name": "blockBps",
-            "type": {
-              "option": "i64"
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "config",
-      "docs": [
-        "Root account that configures the validator bonds program"
-      ],
+      "name": "fundSettlementTokenEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "adminAuthority",
-            "docs": [
-              "Admin authority that can update the config"
-            ],
+            "name": "bond",
             "type": "pubkey"
           },
           {
-            "name": "operatorAuthority",
-            "docs": [
-              "Operator authority (bot hot wallet)"
-            ],
+            "name": "settlement",
             "type": "pubkey"
           },
           {
-            "name": "epochsToClaimSettlement",
-            "docs": [
-              "How many epochs permitting to claim the settlement"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "withdrawLockupEpochs",
-            "docs": [
-              "How many epochs before withdraw is allowed"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "minimumStakeLamports",
-            "docs": [
-              "Minimum amount of lamports to be considered for a stake account operations (e.g., split)"
-            ],
+            "name": "fundingAmount",
             "type": "u64"
           },
           {
-            "name": "bondsWithdrawerAuthorityBump",
-            "docs": [
-              "PDA bonds stake accounts authority bump seed"
-            ],
-            "type": "u8"
+            "name": "stakeAccount",
+            "type": "pubkey"
           },
           {
-            "name": "pauseAuthority",
-            "docs": [
-              "Authority that can pause the program in case of emergency"
-            ],
+            "name": "mint",
             "type": "pubkey"
           },
           {
-            "name": "paused",
-            "type": "bool"
+            "name": "tokenAmount",
+            "type": "u64"
           },
           {
-            "name": "slotsToStartSettlementClaiming",
-            "docs": [
-              "How many slots to wait before settlement is permitted to be claimed"
-            ],
+            "name": "exchangeRate",
             "type": "u64"
           },
           {
-            "name": "minBondMaxStakeWanted",
-            "docs": [
-              "Minimum value of max_stake_wanted to be configured by vote account owners at bond."
-            ],
-            "type": "u64"
+            "name": "liquidatorTokenAccount",
+            "type": "pubkey"
           },
           {
-            "name": "reserved",
-            "docs": [
-              "reserved space for future changes"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                463
-              ]
-            }
+            "name": "lamportsFunded",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "configureBondArgs",
+      "name": "initBondArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "bondAuthority",
-            "docs": [
-              "New bond authority that can manage the bond account."
-            ],
-            "type": {
-              "option": "pubkey"
-            }
+            "type": "pubkey"
           },
           {
             "name": "cpmpe",
-            "docs": [
-              "New `cpmpe` value (cost per mille per epoch).",
-              "It defines the bid for the validator to get delegated up to `max_stake_wanted` lamports."
-            ],
-            "type": {
-              "option": "u64"
-            }
+            "type": "u64"
           },
           {
             "name": "maxStakeWanted",
-            "docs": [
-              "New `max_stake_wanted` value that the vote account owner declares",
-              "as the maximum delegated stake desired."
-            ],
-            "type": {
-              "option": "u64"
-            }
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "configureBondEvent",
+      "name": "initBondEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bondAuthority",
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "pubkeyValueChange"
-                }
-              }
-            }
+            "name": "bond",
+            "type": "pubkey"
+          },
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "voteAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "validatorIdentity",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
           },
           {
             "name": "cpmpe",
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "u64ValueChange"
-                }
-              }
-            }
+            "type": "u64"
           },
           {
             "name": "maxStakeWanted",
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "u64ValueChange"
-                }
-              }
-            }
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "configureBondProductArgs",
+      "name": "initBondLedgerEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondLedger",
+            "type": "pubkey"
+          },
+          {
+            "name": "bond",
+            "type": "pubkey"
+          },
+          {
+            "name": "voteAccount",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "initBondProductArgs",
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "productType",
+            "type": {
+              "defined": {
+                "name": "productType"
+              }
+            }
+          },
           {
             "name": "configData",
             "type": {
@@ -5043,16 +10986,16 @@ export type ValidatorBonds = {
       }
     },
     {
-      "name": "configureBondProductEvent",
+      "name": "initBondProductEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "config",
+            "name": "bondProduct",
             "type": "pubkey"
           },
           {
-            "name": "bondProduct",
+            "name": "config",
             "type": "pubkey"
           },
           {
@@ -5072,328 +11015,368 @@ export type ValidatorBonds = {
             }
           },
           {
-            "name": "oldConfigData",
-            "type": {
-              "defined": {
-                "name": "productTypeConfig"
-              }
-            }
-          },
-          {
-            "name": "newConfigData",
+            "name": "authority",
             "type": {
-              "defined": {
-                "name": "productTypeConfig"
-              }
+              "option": "pubkey"
             }
           }
         ]
       }
     },
     {
-      "name": "configureBondWithMintArgs",
+      "name": "initCollateralMintArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "validatorIdentity",
+            "name": "haircutBps",
             "docs": [
-              "Validator identity configured within the vote account."
+              "haircut applied on the token amount when valued in lamports (basis points)"
             ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "initCollateralMintEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collateralMint",
             "type": "pubkey"
           },
           {
-            "name": "bondAuthority",
-            "docs": [
-              "New bond authority that can manage the bond account."
-            ],
-            "type": {
-              "option": "pubkey"
-            }
+            "name": "config",
+            "type": "pubkey"
           },
           {
-            "name": "cpmpe",
-            "docs": [
-              "New `cpmpe` value (cost per mille per epoch).",
-              "It defines the bid for the validator to get delegated up to `max_stake_wanted` lamports."
-            ],
-            "type": {
-              "option": "u64"
-            }
+            "name": "mint",
+            "type": "pubkey"
           },
           {
-            "name": "maxStakeWanted",
-            "docs": [
-              "new max_stake_wanted value that vote account owner declares",
-              "as the maximum delegated stake wanted"
-            ],
-            "type": {
-              "option": "u64"
-            }
+            "name": "haircutBps",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "configureBondWithMintEvent",
+      "name": "initConfigArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "validatorIdentity",
+            "name": "adminAuthority",
             "type": "pubkey"
           },
           {
-            "name": "bondAuthority",
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "pubkeyValueChange"
-                }
-              }
-            }
+            "name": "operatorAuthority",
+            "type": "pubkey"
           },
           {
-            "name": "cpmpe",
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "u64ValueChange"
-                }
-              }
-            }
+            "name": "epochsToClaimSettlement",
+            "type": "u64"
+          },
+          {
+            "name": "withdrawLockupEpochs",
+            "type": "u64"
+          },
+          {
+            "name": "slotsToStartSettlementClaiming",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "initConfigEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "type": "pubkey"
+          },
+          {
+            "name": "adminAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "operatorAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "withdrawLockupEpochs",
+            "type": "u64"
+          },
+          {
+            "name": "epochsToClaimSettlement",
+            "type": "u64"
+          },
+          {
+            "name": "minimumStakeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "bondsWithdrawerAuthority",
+            "type": "pubkey"
           },
           {
-            "name": "maxStakeWanted",
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "u64ValueChange"
-                }
-              }
-            }
+            "name": "slotsToStartSettlementClaiming",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "configureConfigArgs",
+      "name": "initReplaceableSettlementArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "admin",
+            "name": "replaceableSeed",
+            "docs": [
+              "PDA seed of the settlement instead of the merkle root, it has to be non-zero"
+            ],
             "type": {
-              "option": "pubkey"
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
           {
-            "name": "operator",
+            "name": "merkleRoot",
+            "docs": [
+              "merkle root for this settlement, it can be replaced until claiming starts"
+            ],
             "type": {
-              "option": "pubkey"
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
           {
-            "name": "pauseAuthority",
-            "type": {
-              "option": "pubkey"
-            }
+            "name": "maxTotalClaim",
+            "docs": [
+              "maximal number of lamports that can be claimed from this settlement"
+            ],
+            "type": "u64"
           },
           {
-            "name": "epochsToClaimSettlement",
-            "type": {
-              "option": "u64"
-            }
+            "name": "maxMerkleNodes",
+            "docs": [
+              "maximal number of merkle tree nodes that can be claimed from this settlement"
+            ],
+            "type": "u64"
           },
           {
-            "name": "withdrawLockupEpochs",
-            "type": {
-              "option": "u64"
-            }
+            "name": "rentCollector",
+            "docs": [
+              "collects the rent exempt from the settlement account when closed"
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "minimumStakeLamports",
+            "name": "epoch",
+            "docs": [
+              "epoch that the settlement is created for"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "initSettlementArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "merkleRoot",
+            "docs": [
+              "merkle root for this settlement, multiple settlements can be created with the same merkle root,",
+              "settlements will be distinguished by the vote_account"
+            ],
             "type": {
-              "option": "u64"
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
           {
-            "name": "slotsToStartSettlementClaiming",
-            "type": {
-              "option": "u64"
-            }
+            "name": "maxTotalClaim",
+            "docs": [
+              "maximal number of lamports that can be claimed from this settlement"
+            ],
+            "type": "u64"
           },
           {
-            "name": "minBondMaxStakeWanted",
-            "type": {
-              "option": "u64"
-            }
+            "name": "maxMerkleNodes",
+            "docs": [
+              "maximal number of merkle tree nodes that can be claimed from this settlement"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rentCollector",
+            "docs": [
+              "collects the rent exempt from the settlement account when closed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "epoch",
+            "docs": [
+              "epoch that the settlement is created for"
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "configureConfigEvent",
+      "name": "initSettlementClaimsChunkArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "adminAuthority",
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "pubkeyValueChange"
-                }
-              }
-            }
+            "name": "chunkIndex",
+            "docs": [
+              "index of the chunk to create, the chunk 0 is the settlement claims account"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "initSettlementEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bond",
+            "type": "pubkey"
           },
           {
-            "name": "operatorAuthority",
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "pubkeyValueChange"
-                }
-              }
-            }
+            "name": "settlement",
+            "type": "pubkey"
           },
           {
-            "name": "pauseAuthority",
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "pubkeyValueChange"
-                }
-              }
-            }
+            "name": "voteAccount",
+            "type": "pubkey"
           },
           {
-            "name": "epochsToClaimSettlement",
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "u64ValueChange"
-                }
-              }
-            }
+            "name": "stakerAuthority",
+            "type": "pubkey"
           },
           {
-            "name": "minimumStakeLamports",
+            "name": "merkleRoot",
             "type": {
-              "option": {
-                "defined": {
-                  "name": "u64ValueChange"
-                }
-              }
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
           {
-            "name": "withdrawLockupEpochs",
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "u64ValueChange"
-                }
-              }
-            }
+            "name": "maxTotalClaim",
+            "type": "u64"
           },
           {
-            "name": "slotsToStartSettlementClaiming",
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "u64ValueChange"
-                }
-              }
-            }
+            "name": "maxMerkleNodes",
+            "type": "u64"
           },
           {
-            "name": "minBondMaxStakeWanted",
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "u64ValueChange"
-                }
-              }
-            }
+            "name": "epochCreatedFor",
+            "type": "u64"
+          },
+          {
+            "name": "slotCreatedAt",
+            "type": "u64"
+          },
+          {
+            "name": "rentCollector",
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "delegationInfo",
+      "name": "initWithdrawRequestArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "voterPubkey",
-            "docs": [
-              "to whom the stake is delegated"
-            ],
-            "type": "pubkey"
-          },
-          {
-            "name": "stake",
-            "docs": [
-              "activated stake amount, set at delegate() time"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "activationEpoch",
-            "docs": [
-              "epoch at which this stake was activated, std::Epoch::MAX if is a bootstrap stake"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "deactivationEpoch",
-            "docs": [
-              "epoch the stake was deactivated, std::Epoch::MAX if not deactivated"
-            ],
+            "name": "amount",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "emergencyPauseEvent",
+      "name": "initWithdrawRequestEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "config",
+            "name": "withdrawRequest",
             "type": "pubkey"
           },
           {
-            "name": "adminAuthority",
+            "name": "bond",
             "type": "pubkey"
           },
           {
-            "name": "operatorAuthority",
+            "name": "voteAccount",
             "type": "pubkey"
           },
           {
-            "name": "epochsToClaimSettlement",
+            "name": "epoch",
             "type": "u64"
           },
           {
-            "name": "withdrawLockupEpochs",
+            "name": "requestedAmount",
             "type": "u64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "maxStakeCapProductConfig",
+      "docs": [
+        "Maximum stake (in lamports) the validator accepts to be covered by the bond.",
+        "The stake above the cap is not protected by the bond."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "minimumStakeLamports",
+            "name": "lamports",
             "type": "u64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "mergeStakeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "pauseAuthority",
+            "name": "settlement",
             "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "emergencyResumeEvent",
+      "name": "mergeStakeEvent",
       "type": {
         "kind": "struct",
         "fields": [
@@ -5402,34 +11385,42 @@ export type ValidatorBonds = {
             "type": "pubkey"
           },
           {
-            "name": "adminAuthority",
+            "name": "stakerAuthority",
             "type": "pubkey"
           },
           {
-            "name": "operatorAuthority",
+            "name": "destinationStake",
             "type": "pubkey"
           },
           {
-            "name": "epochsToClaimSettlement",
-            "type": "u64"
-          },
-          {
-            "name": "withdrawLockupEpochs",
-            "type": "u64"
+            "name": "destinationDelegation",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "delegationInfo"
+                }
+              }
+            }
           },
           {
-            "name": "minimumStakeLamports",
-            "type": "u64"
+            "name": "sourceStake",
+            "type": "pubkey"
           },
           {
-            "name": "pauseAuthority",
-            "type": "pubkey"
+            "name": "sourceDelegation",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "delegationInfo"
+                }
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "fundBondEvent",
+      "name": "mintBondEvent",
       "type": {
         "kind": "struct",
         "fields": [
@@ -5438,276 +11429,320 @@ export type ValidatorBonds = {
             "type": "pubkey"
           },
           {
-            "name": "voteAccount",
+            "name": "validatorIdentity",
             "type": "pubkey"
           },
           {
-            "name": "stakeAccount",
+            "name": "validatorIdentityTokenAccount",
             "type": "pubkey"
           },
           {
-            "name": "stakeAuthoritySigner",
+            "name": "tokenMetadata",
             "type": "pubkey"
-          },
-          {
-            "name": "depositedAmount",
-            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "fundSettlementEvent",
+      "name": "pendingProductConfig",
+      "docs": [
+        "Product configuration scheduled to replace the current one from the epoch on."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bond",
-            "type": "pubkey"
-          },
-          {
-            "name": "settlement",
-            "type": "pubkey"
+            "name": "configData",
+            "type": {
+              "defined": {
+                "name": "productTypeConfig"
+              }
+            }
           },
           {
-            "name": "fundingAmount",
+            "name": "effectiveFromEpoch",
             "type": "u64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "productType",
+      "docs": [
+        "Product type discriminator"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "stakeAccount",
-            "type": "pubkey"
+            "name": "commission"
           },
           {
-            "name": "lamportsFunded",
-            "type": "u64"
+            "name": "custom",
+            "fields": [
+              "string"
+            ]
           },
           {
-            "name": "lamportsClaimed",
-            "type": "u64"
+            "name": "uptimeGuarantee"
           },
           {
-            "name": "merkleNodesClaimed",
-            "type": "u64"
-          },
+            "name": "maxStakeCap"
+          }
+        ]
+      }
+    },
+    {
+      "name": "productTypeConfig",
+      "docs": [
+        "Discriminated union for different product configurations"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "splitStakeAccount",
-            "type": {
-              "option": {
+            "name": "commission",
+            "fields": [
+              {
                 "defined": {
-                  "name": "splitStakeData"
+                  "name": "commissionProductConfig"
                 }
               }
-            }
+            ]
           },
           {
-            "name": "splitRentCollector",
-            "type": {
-              "option": "pubkey"
-            }
+            "name": "custom",
+            "fields": [
+              "bytes"
+            ]
           },
           {
-            "name": "splitRentAmount",
-            "type": "u64"
+            "name": "uptimeGuarantee",
+            "fields": [
+              {
+                "defined": {
+                  "name": "uptimeGuaranteeProductConfig"
+                }
+              }
+            ]
+          },
+          {
+            "name": "maxStakeCap",
+            "fields": [
+              {
+                "defined": {
+                  "name": "maxStakeCapProductConfig"
+                }
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "initBondArgs",
+      "name": "proposeConfigAuthorityArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bondAuthority",
-            "type": "pubkey"
+            "name": "admin",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "cpmpe",
-            "type": "u64"
+            "name": "operator",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "maxStakeWanted",
-            "type": "u64"
+            "name": "pauseAuthority",
+            "type": {
+              "option": "pubkey"
+            }
           }
         ]
       }
     },
     {
-      "name": "initBondEvent",
+      "name": "proposeConfigAuthorityEvent",
       "type": {
         "kind": "struct",
         "fields": [
-          {
-            "name": "bond",
-            "type": "pubkey"
-          },
           {
             "name": "config",
             "type": "pubkey"
           },
           {
-            "name": "voteAccount",
-            "type": "pubkey"
-          },
-          {
-            "name": "validatorIdentity",
-            "type": "pubkey"
-          },
-          {
-            "name": "authority",
-            "type": "pubkey"
+            "name": "pendingAdminAuthority",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "cpmpe",
-            "type": "u64"
+            "name": "pendingOperatorAuthority",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "maxStakeWanted",
-            "type": "u64"
+            "name": "pendingPauseAuthority",
+            "type": {
+              "option": "pubkey"
+            }
           }
         ]
       }
     },
     {
-      "name": "initBondProductArgs",
+      "name": "pubkeyValueChange",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "productType",
-            "type": {
-              "defined": {
-                "name": "productType"
-              }
-            }
+            "name": "old",
+            "type": "pubkey"
           },
           {
-            "name": "configData",
-            "type": {
-              "defined": {
-                "name": "productTypeConfig"
-              }
-            }
+            "name": "new",
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "initBondProductEvent",
+      "name": "queueConfigureConfigArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bondProduct",
-            "type": "pubkey"
-          },
-          {
-            "name": "config",
-            "type": "pubkey"
-          },
-          {
-            "name": "bond",
-            "type": "pubkey"
+            "name": "epochsToClaimSettlement",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "voteAccount",
-            "type": "pubkey"
+            "name": "withdrawLockupEpochs",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "productType",
+            "name": "minimumStakeLamports",
             "type": {
-              "defined": {
-                "name": "productType"
-              }
+              "option": "u64"
             }
           },
           {
-            "name": "authority",
+            "name": "slotsToStartSettlementClaiming",
             "type": {
-              "option": "pubkey"
+              "option": "u64"
             }
+          },
+          {
+            "name": "activationEpoch",
+            "docs": [
+              "epoch when the change may be executed, at least the withdraw lockup epochs ahead"
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "initConfigArgs",
+      "name": "queueConfigureConfigEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "adminAuthority",
-            "type": "pubkey"
-          },
-          {
-            "name": "operatorAuthority",
+            "name": "config",
             "type": "pubkey"
           },
           {
             "name": "epochsToClaimSettlement",
-            "type": "u64"
+            "type": {
+              "option": "u64"
+            }
           },
           {
             "name": "withdrawLockupEpochs",
-            "type": "u64"
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "minimumStakeLamports",
+            "type": {
+              "option": "u64"
+            }
           },
           {
             "name": "slotsToStartSettlementClaiming",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "activationEpoch",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "initConfigEvent",
+      "name": "queuedConfigChange",
+      "docs": [
+        "Config parameters change that can be executed once the activation epoch is reached.",
+        "These parameters define the terms the validators agreed to when funding the bond,",
+        "the activation is delayed to give them time to withdraw under the current terms."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "config",
-            "type": "pubkey"
-          },
-          {
-            "name": "adminAuthority",
-            "type": "pubkey"
-          },
-          {
-            "name": "operatorAuthority",
-            "type": "pubkey"
+            "name": "epochsToClaimSettlement",
+            "type": {
+              "option": "u64"
+            }
           },
           {
             "name": "withdrawLockupEpochs",
-            "type": "u64"
-          },
-          {
-            "name": "epochsToClaimSettlement",
-            "type": "u64"
+            "type": {
+              "option": "u64"
+            }
           },
           {
             "name": "minimumStakeLamports",
-            "type": "u64"
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "bondsWithdrawerAuthority",
-            "type": "pubkey"
+            "name": "slotsToStartSettlementClaiming",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "slotsToStartSettlementClaiming",
+            "name": "activationEpoch",
+            "docs": [
+              "Epoch from which the change is permitted to be executed"
+            ],
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "initSettlementArgs",
+      "name": "replaceSettlementRootArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "merkleRoot",
             "docs": [
-              "merkle root for this settlement, multiple settlements can be created with the same merkle root,",
-              "settlements will be distinguished by the vote_account"
+              "new merkle root for the settlement"
             ],
             "type": {
               "array": [
@@ -5719,28 +11754,14 @@ export type ValidatorBonds = {
           {
             "name": "maxTotalClaim",
             "docs": [
-              "maximal number of lamports that can be claimed from this settlement"
+              "maximal number of lamports that can be claimed from the new merkle tree"
             ],
             "type": "u64"
           },
           {
             "name": "maxMerkleNodes",
             "docs": [
-              "maximal number of merkle tree nodes that can be claimed from this settlement"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "rentCollector",
-            "docs": [
-              "collects the rent exempt from the settlement account when closed"
-            ],
-            "type": "pubkey"
-          },
-          {
-            "name": "epoch",
-            "docs": [
-              "epoch that the settlement is created for"
+              "maximal number of merkle tree nodes that can be claimed from the new merkle tree"
             ],
             "type": "u64"
           }
@@ -5748,7 +11769,7 @@ export type ValidatorBonds = {
       }
     },
     {
-      "name": "initSettlementEvent",
+      "name": "replaceSettlementRootEvent",
       "type": {
         "kind": "struct",
         "fields": [
@@ -5761,15 +11782,20 @@ export type ValidatorBonds = {
             "type": "pubkey"
           },
           {
-            "name": "voteAccount",
+            "name": "operatorAuthority",
             "type": "pubkey"
           },
           {
-            "name": "stakerAuthority",
-            "type": "pubkey"
+            "name": "oldMerkleRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "merkleRoot",
+            "name": "newMerkleRoot",
             "type": {
               "array": [
                 "u8",
@@ -5779,117 +11805,17 @@ export type ValidatorBonds = {
           },
           {
             "name": "maxTotalClaim",
-            "type": "u64"
-          },
-          {
-            "name": "maxMerkleNodes",
-            "type": "u64"
-          },
-          {
-            "name": "epochCreatedFor",
-            "type": "u64"
-          },
-          {
-            "name": "slotCreatedAt",
-            "type": "u64"
-          },
-          {
-            "name": "rentCollector",
-            "type": "pubkey"
-          }
-        ]
-      }
-    },
-    {
-      "name": "initWithdrawRequestArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "amount",
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "initWithdrawRequestEvent",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "withdrawRequest",
-            "type": "pubkey"
-          },
-          {
-            "name": "bond",
-            "type": "pubkey"
-          },
-          {
-            "name": "voteAccount",
-            "type": "pubkey"
-          },
-          {
-            "name": "epoch",
-            "type": "u64"
-          },
-          {
-            "name": "requestedAmount",
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "mergeStakeArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "settlement",
-            "type": "pubkey"
-          }
-        ]
-      }
-    },
-    {
-      "name": "mergeStakeEvent",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "config",
-            "type": "pubkey"
-          },
-          {
-            "name": "stakerAuthority",
-            "type": "pubkey"
-          },
-          {
-            "name": "destinationStake",
-            "type": "pubkey"
-          },
-          {
-            "name": "destinationDelegation",
             "type": {
-              "option": {
-                "defined": {
-                  "name": "delegationInfo"
-                }
+              "defined": {
+                "name": "u64ValueChange"
               }
             }
           },
           {
-            "name": "sourceStake",
-            "type": "pubkey"
-          },
-          {
-            "name": "sourceDelegation",
+            "name": "maxMerkleNodes",
             "type": {
-              "option": {
-                "defined": {
-                  "name": "delegationInfo"
-                }
+              "defined": {
+                "name": "u64ValueChange"
               }
             }
           }
@@ -5897,99 +11823,60 @@ export type ValidatorBonds = {
       }
     },
     {
-      "name": "mintBondEvent",
+      "name": "resetStakeEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bond",
+            "name": "config",
             "type": "pubkey"
           },
           {
-            "name": "validatorIdentity",
+            "name": "bond",
             "type": "pubkey"
           },
           {
-            "name": "validatorIdentityTokenAccount",
+            "name": "settlement",
             "type": "pubkey"
           },
           {
-            "name": "tokenMetadata",
+            "name": "stakeAccount",
             "type": "pubkey"
-          }
-        ]
-      }
-    },
-    {
-      "name": "productType",
-      "docs": [
-        "Product type discriminator"
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "commission"
-          },
-          {
-            "name": "custom",
-            "fields": [
-              "string"
-            ]
-          }
-        ]
-      }
-    },
-    {
-      "name": "productTypeConfig",
-      "docs": [
-        "Discriminated union for different product configurations"
-      ],
-      "type": {
-        "kind": "enum",
-        "variants": [
+          },
           {
-            "name": "commission",
-            "fields": [
-              {
-                "defined": {
-                  "name": "commissionProductConfig"
-                }
-              }
-            ]
+            "name": "voteAccount",
+            "type": "pubkey"
           },
           {
-            "name": "custom",
-            "fields": [
-              "bytes"
-            ]
+            "name": "settlementStakerAuthority",
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "pubkeyValueChange",
+      "name": "resizeWithdrawRequestArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "old",
-            "type": "pubkey"
-          },
-          {
-            "name": "new",
-            "type": "pubkey"
+            "name": "amount",
+            "docs": [
+              "new requested amount, it has to be lower than the current one",
+              "and not lower than the already withdrawn amount"
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "resetStakeEvent",
+      "name": "resizeWithdrawRequestEvent",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "config",
+            "name": "withdrawRequest",
             "type": "pubkey"
           },
           {
@@ -5997,20 +11884,20 @@ export type ValidatorBonds = {
             "type": "pubkey"
           },
           {
-            "name": "settlement",
-            "type": "pubkey"
-          },
-          {
-            "name": "stakeAccount",
+            "name": "authority",
             "type": "pubkey"
           },
           {
-            "name": "voteAccount",
-            "type": "pubkey"
+            "name": "requestedAmount",
+            "type": {
+              "defined": {
+                "name": "u64ValueChange"
+              }
+            }
           },
           {
-            "name": "settlementStakerAuthority",
-            "type": "pubkey"
+            "name": "withdrawnAmount",
+            "type": "u64"
           }
         ]
       }
@@ -6134,6 +12021,35 @@ export type ValidatorBonds = {
               }
             }
           },
+          {
+            "name": "expirationEpochOverride",
+            "docs": [
+              "Epoch after which the settlement expires when set by the operator;",
+              "0 means the expiration is derived from the config `epochs_to_claim_settlement`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "replaceableSeed",
+            "docs": [
+              "PDA seed of settlements created by `init_replaceable_settlement` that permits to replace the merkle root;",
+              "zeroed when the settlement PDA is seeded by the merkle root"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "counted",
+            "docs": [
+              "Settlement is counted in the bond `open_settlements`;",
+              "not set for settlements created before the counter was introduced"
+            ],
+            "type": "bool"
+          },
           {
             "name": "reserved",
             "docs": [
@@ -6142,7 +12058,7 @@ export type ValidatorBonds = {
             "type": {
               "array": [
                 "u8",
-                90
+                49
               ]
             }
           }
@@ -6241,6 +12157,34 @@ export type ValidatorBonds = {
           {
             "name": "maxRecords",
             "type": "u64"
+          },
+          {
+            "name": "chunkIndex",
+            "docs": [
+              "index of the chunk the account stores bitmap for, 0 for the settlement claims account"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "chunks",
+            "docs": [
+              "number of chunks the bitmap is split into, 0 when the bitmap is not chunked"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "chunksInitialized",
+            "docs": [
+              "number of chunk accounts created by 'init_settlement_claims_chunk', set at chunk 0 only"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump of the chunk account, the chunk 0 uses the settlement bumps"
+            ],
+            "type": "u8"
           }
         ]
       }
@@ -6277,6 +12221,129 @@ export type ValidatorBonds = {
         ]
       }
     },
+    {
+      "name": "updateCollateralExchangeRateArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "exchangeRate",
+            "docs": [
+              "lamports value of one token (EXCHANGE_RATE_SCALE of token base units)"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "updateCollateralExchangeRateEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collateralMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "exchangeRate",
+            "type": {
+              "defined": {
+                "name": "u64ValueChange"
+              }
+            }
+          },
+          {
+            "name": "epoch",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "uptimeGuaranteeProductConfig",
+      "docs": [
+        "Uptime guaranteed by the validator, in basis points of the vote credits",
+        "the validator is expected to earn within an epoch.",
+        "Stakers are compensated from the bond when the uptime drops below the guarantee."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minUptimeBps",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "withdrawBondTokenArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "docs": [
+              "amount of tokens to be withdrawn from the bond token vault"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "withdrawBondTokenEvent",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bond",
+            "type": "pubkey"
+          },
+          {
+            "name": "voteAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "withdrawRequest",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "bondTokenVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "withdrawerTokenAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "lamportsValue",
+            "type": "u64"
+          },
+          {
+            "name": "withdrawnAmount",
+            "type": {
+              "defined": {
+                "name": "u64ValueChange"
+              }
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "withdrawRequest",
       "docs": [
@@ -6327,6 +12394,22 @@ export type ValidatorBonds = {
             ],
             "type": "u8"
           },
+          {
+            "name": "index",
+            "docs": [
+              "Index of the withdraw request within the bond.",
+              "Index 0 is the (legacy) withdraw request whose PDA is derived without the index seed."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "counted",
+            "docs": [
+              "Withdraw request is counted in the bond `open_withdraw_requests`;",
+              "not set for withdraw requests created before the counter was introduced"
+            ],
+            "type": "bool"
+          },
           {
             "name": "reserved",
             "docs": [
@@ -6335,7 +12418,7 @@ export type ValidatorBonds = {
             "type": {
               "array": [
                 "u8",
-                93
+                84
               ]
             }
           }
@@ -6385,6 +12468,19 @@ export type ValidatorBonds = {
       "type": "bytes",
       "value": "[98, 111, 110, 100, 115, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]"
     },
+    {
+      "name": "bondLedgerEpochs",
+      "docs": [
+        "Number of epochs the bond ledger keeps the counters for"
+      ],
+      "type": "u64",
+      "value": "32"
+    },
+    {
+      "name": "bondLedgerSeed",
+      "type": "bytes",
+      "value": "[98, 111, 110, 100, 95, 108, 101, 100, 103, 101, 114]"
+    },
     {
       "name": "bondMintSeed",
       "type": "bytes",
@@ -6400,16 +12496,93 @@ export type ValidatorBonds = {
       "type": "bytes",
       "value": "[98, 111, 110, 100, 95, 97, 99, 99, 111, 117, 110, 116]"
     },
+    {
+      "name": "bondTokenVaultSeed",
+      "type": "bytes",
+      "value": "[98, 111, 110, 100, 95, 116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]"
+    },
+    {
+      "name": "collateralMintSeed",
+      "type": "bytes",
+      "value": "[99, 111, 108, 108, 97, 116, 101, 114, 97, 108, 95, 109, 105, 110, 116]"
+    },
+    {
+      "name": "pauseAll",
+      "type": "u8",
+      "value": "62"
+    },
+    {
+      "name": "pauseClaim",
+      "docs": [
+        "Claiming settlements"
+      ],
+      "type": "u8",
+      "value": "16"
+    },
+    {
+      "name": "pauseFunding",
+      "docs": [
+        "Bond creation, configuration and funding (stake accounts, tokens)"
+      ],
+      "type": "u8",
+      "value": "2"
+    },
+    {
+      "name": "pauseLegacyAll",
+      "docs": [
+        "Value stored by the former boolean `paused` config field, it means all operation groups are paused"
+      ],
+      "type": "u8",
+      "value": "1"
+    },
+    {
+      "name": "pauseSettlement",
+      "docs": [
+        "Settlement creation, funding and closing"
+      ],
+      "type": "u8",
+      "value": "8"
+    },
+    {
+      "name": "pauseStakeManagement",
+      "docs": [
+        "Merging, resetting and withdrawing stake accounts by operator"
+      ],
+      "type": "u8",
+      "value": "32"
+    },
+    {
+      "name": "pauseWithdraw",
+      "docs": [
+        "Withdraw requests and withdrawing bond stake accounts and tokens"
+      ],
+      "type": "u8",
+      "value": "4"
+    },
     {
       "name": "programId",
       "type": "string",
       "value": "\"vBoNdEvzMrSai7is21XgVYik65mqtaKXuSdMBJ1xkW4\""
     },
+    {
+      "name": "replaceableSettlementSeed",
+      "type": "bytes",
+      "value": "[114, 101, 112, 108, 97, 99, 101, 97, 98, 108, 101, 95, 115, 101, 116, 116, 108, 101, 109, 101, 110, 116]"
+    },
     {
       "name": "settlementClaimsAnchorHeaderSize",
       "type": "u8",
       "value": "56"
     },
+    {
+      "name": "settlementClaimsChunkMaxRecords",
+      "docs": [
+        "Number of records of one SettlementClaims chunk account,",
+        "the chunk account size is then exactly the maximum allocation size of one instruction (10KB)"
+      ],
+      "type": "u64",
+      "value": "81472"
+    },
     {
       "name": "settlementClaimsSeed",
       "type": "bytes",
@@ -16,12 +16,16 @@ import BN from 'bn.js'
 import { ProductTypes } from './productBond'
 import {
   bondAddress,
-  withdrawRequestAddress,
+  bondLedgerAddress,
+  withdrawRequestAddresses,
   settlementAddress,
   uintToBuffer,
   bondsWithdrawerAuthority,
   settlementClaimsAddress,
+  settlementClaimsChunkAddress,
+  settlementClaimsChunkIndex,
   bondProductAddress,
+  SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS,
   BOND_ACCOUNT_DISCRIMINATOR,
   SETTLEMENT_ACCOUNT_DISCRIMINATOR,
   BOND_PRODUCT_ACCOUNT_DISCRIMINATOR,
@@ -35,10 +39,12 @@ import type {
   ValidatorBondsProgram,
   Config,
   Bond,
+  BondLedger,
   WithdrawRequest,
   Settlement,
   BondProduct,
   ProductType,
+  CollateralMint,
 } from './sdk'
 import type { SettlementClaimsBitmap } from './settlementClaims'
 import type { StakeAccountParsed } from './web3.js'
@@ -51,6 +57,7 @@ import type {
 } from '@marinade.finance/web3js-1x'
 import type {
   AccountInfo,
+  AccountMeta,
   EpochInfo,
   GetProgramAccountsFilter,
 } from '@solana/web3.js'
@@ -103,6 +110,35 @@ export async function getBond(
   return program.account.bond.fetch(address)
 }
 
+export async function getBondLedger(
+  program: ValidatorBondsProgram,
+  address: PublicKey,
+): Promise<BondLedger> {
+  return program.account.bondLedger.fetch(address)
+}
+
+/**
+ * The bond ledger is required as the last remaining account of the instructions updating it
+ * when the bond ledger was created for the bond, otherwise no account is returned.
+ */
+export async function bondLedgerRemainingAccounts(
+  program: ValidatorBondsProgram,
+  bond: PublicKey,
+  bondData?: Bond,
+): Promise<AccountMeta[]> {
+  bondData = bondData ?? (await getBond(program, bond))
+  if (bondData.bondLedgerBump === null) {
+    return []
+  }
+  return [
+    {
+      pubkey: bondLedgerAddress(bond, program.programId)[0],
+      isSigner: false,
+      isWritable: true,
+    },
+  ]
+}
+
 export async function getMultipleBonds({
   program,
   addresses,
@@ -218,17 +254,17 @@ export async function findWithdrawRequests({
   epoch?: EpochInfo | number | BN | bigint
 }): Promise<ProgramAccount<WithdrawRequest>[]> {
   if (bond) {
-    const [withdrawRequestAccount] = withdrawRequestAddress(
+    // the bond tracks the last used index of the indexed withdraw requests,
+    // when the bond does not exist only the legacy (index 0) withdraw request may exist
+    const bondData = await program.account.bond.fetchNullable(bond)
+    const addresses = withdrawRequestAddresses(
       bond,
+      bondData?.withdrawRequestIndex ?? 0,
       program.programId,
     )
-    const withdrawRequestData =
-      await program.account.withdrawRequest.fetchNullable(
-        withdrawRequestAccount,
-      )
-    return withdrawRequestData
-      ? [{ publicKey: withdrawRequestAccount, account: withdrawRequestData }]
-      : []
+    return (await getMultipleWithdrawRequests({ program, addresses }))
+      .filter(d => d.account !== null)
+      .map(d => d as ProgramAccount<WithdrawRequest>)
   }
   const filters = []
   if (voteAccount) {
@@ -262,6 +298,13 @@ export async function findWithdrawRequests({
     .map(d => d as ProgramAccount<WithdrawRequest>)
 }
 
+export async function getCollateralMint(
+  program: ValidatorBondsProgram,
+  address: PublicKey,
+): Promise<CollateralMint> {
+  return program.account.collateralMint.fetch(address)
+}
+
 export async function getSettlement(
   program: ValidatorBondsProgram,
   address: PublicKey,
@@ -373,7 +416,57 @@ export async function isClaimed(
     program,
     settlement,
   )
-  return settlementClaims.bitmap.isSet(index)
+  if (settlementClaims.account.chunks === 0) {
+    return settlementClaims.bitmap.isSet(index)
+  }
+  // chunked bitmap, every chunk account stores its own range of the indexes
+  const chunkIndex = settlementClaimsChunkIndex(index)
+  const chunk =
+    chunkIndex === 0
+      ? settlementClaims
+      : await getSettlementClaims(
+          program,
+          settlementClaimsChunkAddress(
+            settlement,
+            chunkIndex,
+            program.programId,
+          )[0],
+        )
+  return chunk.bitmap.isSet(
+    new BN(index).subn(chunkIndex * SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS),
+  )
+}
+
+/**
+ * Addresses of the created SettlementClaims chunk accounts (chunk index 1 and higher)
+ * ordered by the chunk index, as expected by the instructions closing the settlement.
+ * No chunk is returned when the settlement claims account does not exist.
+ */
+export async function getSettlementClaimsChunkAddresses(
+  program: ValidatorBondsProgram,
+  settlement: PublicKey,
+): Promise<PublicKey[]> {
+  const [settlementClaimsAddr] = settlementClaimsAddress(
+    settlement,
+    program.programId,
+  )
+  const accountInfo =
+    await program.provider.connection.getAccountInfo(settlementClaimsAddr)
+  if (accountInfo === null) {
+    return []
+  }
+  const settlementClaims = decodeSettlementClaimsData(program, accountInfo)
+  if (settlementClaims.account.chunksInitialized === 0) {
+    return []
+  }
+  const addresses = Array.from(
+    { length: settlementClaims.account.chunks - 1 },
+    (_, i) =>
+      settlementClaimsChunkAddress(settlement, i + 1, program.programId)[0],
+  )
+  return (await getMultiAccounts({ program, addresses }))
+    .filter(({ account }) => account !== null)
+    .map(({ publicKey }) => publicKey)
 }
 
 export async function getMultipleSettlementClaims({
@@ -666,6 +759,7 @@ export type BondDataWithFunding = {
   amountToWithdraw: BN
   epochsToElapseToWithdraw: BN | undefined
   withdrawRequest: ProgramAccount<WithdrawRequest> | undefined
+  withdrawRequests: ProgramAccount<WithdrawRequest>[]
   amountFundedAtBond: BN
   bondFundedStakeAccounts: ProgramAccountInfo<StakeAccountParsed>[]
   settlementFundedStakeAccounts: ProgramAccountInfo<StakeAccountParsed>[]
@@ -723,7 +817,8 @@ export async function getBondsFunding({
     string,
     {
       voteAccount: PublicKey
-      withdrawRequest?: ProgramAccount<WithdrawRequest>
+      withdrawRequestIndex: BN
+      withdrawRequests: ProgramAccount<WithdrawRequest>[]
     }
   > = new Map()
   // derive all bond addresses
@@ -743,37 +838,48 @@ export async function getBondsFunding({
     )
     inputData.set(bondAccountAddress.toBase58(), {
       voteAccount: voteAccountAddress ?? PublicKey.default,
+      withdrawRequestIndex: ZERO_BN,
+      withdrawRequests: [],
     })
   }
 
-  // searching for all vote accounts for each bond
-  const bondsWithUnknownVoteAccount = Array.from(inputData.entries())
-    .filter(([, { voteAccount }]) => voteAccount.equals(PublicKey.default))
-    .map(([bondAccount]) => new PublicKey(bondAccount))
-  const bondsDataWithUnknownVoteAccount = await getMultipleBonds({
+  // searching for vote accounts and withdraw request indexes for each bond
+  const bondsData = await getMultipleBonds({
     program,
-    addresses: bondsWithUnknownVoteAccount,
+    addresses: Array.from(inputData.keys()).map(
+      bondAccount => new PublicKey(bondAccount),
+    ),
   })
-  for (const bondData of bondsDataWithUnknownVoteAccount) {
-    if (bondData.account === null) {
-      throw new Error(
-        'getBondsFunding: cannot get bond data for bond address ' +
-          bondData.publicKey.toBase58() +
-          '; required for vote account derivation',
-      )
-    }
+  for (const bondData of bondsData) {
     const bondInnerSetData = inputData.get(bondData.publicKey.toBase58())
     assert(bondInnerSetData !== undefined, 'bondInnerSetData is known here')
+    if (bondData.account === null) {
+      if (bondInnerSetData.voteAccount.equals(PublicKey.default)) {
+        throw new Error(
+          'getBondsFunding: cannot get bond data for bond address ' +
+            bondData.publicKey.toBase58() +
+            '; required for vote account derivation',
+        )
+      }
+      continue
+    }
     bondInnerSetData.voteAccount = bondData.account.voteAccount
+    bondInnerSetData.withdrawRequestIndex =
+      bondData.account.withdrawRequestIndex
   }
-  // getting info on withdraw requests for each bond (maybe it does not exist)
-  const withdrawRequestAddresses = Array.from(inputData.keys()).map(
-    bondAccount =>
-      withdrawRequestAddress(new PublicKey(bondAccount), program.programId)[0],
+  // getting info on withdraw requests for each bond (legacy and indexed ones, maybe none exists)
+  const bondsWithdrawRequestAddresses = Array.from(
+    inputData.entries(),
+  ).flatMap(([bondAccount, { withdrawRequestIndex }]) =>
+    withdrawRequestAddresses(
+      new PublicKey(bondAccount),
+      withdrawRequestIndex,
+      program.programId,
+    ),
   )
   const withdrawRequestsData = await getMultipleWithdrawRequests({
     program,
-    addresses: withdrawRequestAddresses,
+    addresses: bondsWithdrawRequestAddresses,
   })
   for (const withdrawRequestData of withdrawRequestsData) {
     if (
@@ -787,10 +893,10 @@ export async function getBondsFunding({
     )
     assert(bondInnerSetData !== undefined, 'bondInnerSetData is known here')
     // we know the account is not null, i.e., we can set it as ProgramAccount instead of ProgramAccountNullable
-    bondInnerSetData.withdrawRequest = {
+    bondInnerSetData.withdrawRequests.push({
       publicKey: withdrawRequestData.publicKey,
       account: withdrawRequestData.account,
-    }
+    })
   }
   currentEpoch =
     currentEpoch ?? (await program.provider.connection.getEpochInfo()).epoch
@@ -831,7 +937,7 @@ export async function getBondsFunding({
   const withdrawLockupEpochs = configData.withdrawLockupEpochs
 
   return Array.from(inputData.entries()).map(
-    ([bondAccount, { voteAccount, withdrawRequest }]) => {
+    ([bondAccount, { voteAccount, withdrawRequests }]) => {
       const bondFunded =
         bondFundedStakeAccountsMap.get(voteAccount.toBase58()) ??
         ([] as ProgramAccountInfo<StakeAccountParsed>[])
@@ -843,9 +949,12 @@ export async function getBondsFunding({
       const { amount: amountAtSettlements } =
         calculateFundedAmount(settlementFunded)
       const amountOwned = amountFundedAtBond.add(amountAtSettlements)
-      const withdrawalRequestedAmount = (
-        withdrawRequest?.account.requestedAmount ?? ZERO_BN
-      ).sub(withdrawRequest?.account.withdrawnAmount ?? ZERO_BN)
+      // all withdraw requests of the bond (legacy and indexed) are summed up
+      const withdrawalRequestedAmount = withdrawRequests
+        .map(({ account }) =>
+          account.requestedAmount.sub(account.withdrawnAmount),
+        )
+        .reduce((sum, amount) => sum.add(amount), ZERO_BN)
       const amountActive = amountFundedAtBond.sub(withdrawalRequestedAmount)
 
       let amountToWithdraw: BN
@@ -862,17 +971,31 @@ export async function getBondsFunding({
         amountToWithdraw = withdrawalRequestedAmount
       }
       let epochsToElapseToWithdraw: BN | undefined = undefined
-      if (
-        withdrawRequest !== undefined &&
-        currentEpoch.lte(
-          withdrawRequest.account.epoch.add(withdrawLockupEpochs),
-        )
-      ) {
-        // withdraw request is locked as withdrawLockupEpochs have not elapsed yet
-        epochsToElapseToWithdraw = withdrawRequest.account.epoch
-          .add(withdrawLockupEpochs)
-          .addn(1)
-          .sub(currentEpoch)
+      const isUnlocked = (request: WithdrawRequest) =>
+        currentEpoch.gt(request.epoch.add(withdrawLockupEpochs))
+      const anyUnlockedToWithdraw = withdrawRequests.some(
+        ({ account }) =>
+          isUnlocked(account) &&
+          account.requestedAmount.gt(account.withdrawnAmount),
+      )
+      if (!anyUnlockedToWithdraw) {
+        // withdraw requests are locked as withdrawLockupEpochs have not elapsed yet,
+        //   the earliest unlocking one defines the epochs to elapse
+        for (const { account } of withdrawRequests) {
+          if (isUnlocked(account)) {
+            continue
+          }
+          const epochsToElapse = account.epoch
+            .add(withdrawLockupEpochs)
+            .addn(1)
+            .sub(currentEpoch)
+          if (
+            epochsToElapseToWithdraw === undefined ||
+            epochsToElapse.lt(epochsToElapseToWithdraw)
+          ) {
+            epochsToElapseToWithdraw = epochsToElapse
+          }
+        }
       }
 
       return {
@@ -885,7 +1008,9 @@ export async function getBondsFunding({
         numberSettlementStakeAccounts: settlementFunded.length,
         amountToWithdraw,
         epochsToElapseToWithdraw,
-        withdrawRequest,
+        // the first found one, i.e., the legacy withdraw request when it exists
+        withdrawRequest: withdrawRequests[0],
+        withdrawRequests,
         amountFundedAtBond,
         bondFundedStakeAccounts: bondFunded,
         settlementFundedStakeAccounts: settlementFunded,
@@ -0,0 +1,55 @@
+import { PublicKey } from '@solana/web3.js'
+
+import { getBond } from '../api'
+import { anchorProgramWalletPubkey, checkAndGetBondAddress } from '../utils'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to accept the bond authority role proposed to the signer
+ * by the configure bond instruction.
+ */
+export async function acceptBondAuthorityInstruction({
+  program,
+  bondAccount,
+  configAccount,
+  voteAccount,
+  newAuthority = anchorProgramWalletPubkey(program),
+}: {
+  program: ValidatorBondsProgram
+  bondAccount?: PublicKey
+  configAccount?: PublicKey
+  voteAccount?: PublicKey
+  newAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+}): Promise<{
+  bondAccount: PublicKey
+  instruction: TransactionInstruction
+}> {
+  bondAccount = checkAndGetBondAddress({
+    bond: bondAccount,
+    config: configAccount,
+    voteAccount,
+    programId: program.programId,
+  })
+  if (configAccount === undefined) {
+    const bondData = await getBond(program, bondAccount)
+    configAccount = bondData.config
+  }
+  newAuthority =
+    newAuthority instanceof PublicKey ? newAuthority : newAuthority.publicKey
+
+  const instruction = await program.methods
+    .acceptBondAuthority()
+    .accountsPartial({
+      config: configAccount,
+      bond: bondAccount,
+      newAuthority,
+    })
+    .instruction()
+  return {
+    bondAccount,
+    instruction,
+  }
+}
@@ -0,0 +1,50 @@
+import { logWarn } from '@marinade.finance/ts-common'
+import { PublicKey } from '@solana/web3.js'
+
+import { MARINADE_CONFIG_ADDRESS } from '../sdk'
+import { anchorProgramWalletPubkey } from '../utils'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { LoggerPlaceholder } from '@marinade.finance/ts-common'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to accept the config authority roles proposed to the signer.
+ * When the signer is proposed for multiple roles, all of them are accepted at once.
+ */
+export async function acceptConfigAuthorityInstruction({
+  program,
+  configAccount,
+  newAuthority = anchorProgramWalletPubkey(program),
+  logger,
+}: {
+  program: ValidatorBondsProgram
+  configAccount?: PublicKey
+  newAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  logger?: LoggerPlaceholder
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  if (configAccount === undefined) {
+    logWarn(
+      logger,
+      'acceptConfigAuthority SDK: config is not provided, using default config address: ' +
+        MARINADE_CONFIG_ADDRESS.toBase58(),
+    )
+    configAccount = MARINADE_CONFIG_ADDRESS
+  }
+  newAuthority =
+    newAuthority instanceof PublicKey ? newAuthority : newAuthority.publicKey
+
+  const instruction = await program.methods
+    .acceptConfigAuthority()
+    .accountsPartial({
+      config: configAccount,
+      newAuthority,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
@@ -0,0 +1,56 @@
+import { logWarn } from '@marinade.finance/ts-common'
+import { PublicKey } from '@solana/web3.js'
+
+import { getConfig } from '../api'
+import { MARINADE_CONFIG_ADDRESS } from '../sdk'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { LoggerPlaceholder } from '@marinade.finance/ts-common'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to cancel the queued (not yet executed) change of the lockup-sensitive config params.
+ * Available for admin authority.
+ */
+export async function cancelQueuedConfigInstruction({
+  program,
+  configAccount,
+  adminAuthority,
+  logger,
+}: {
+  program: ValidatorBondsProgram
+  configAccount?: PublicKey
+  adminAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  logger?: LoggerPlaceholder
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  if (configAccount === undefined) {
+    logWarn(
+      logger,
+      'cancelQueuedConfig SDK: config is not provided, using default config address: ' +
+        MARINADE_CONFIG_ADDRESS.toBase58(),
+    )
+    configAccount = MARINADE_CONFIG_ADDRESS
+  }
+  if (adminAuthority === undefined) {
+    const configData = await getConfig(program, configAccount)
+    adminAuthority = configData.adminAuthority
+  }
+  adminAuthority =
+    adminAuthority instanceof PublicKey
+      ? adminAuthority
+      : adminAuthority.publicKey
+
+  const instruction = await program.methods
+    .cancelQueuedConfig()
+    .accountsPartial({
+      config: configAccount,
+      adminAuthority,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
@@ -40,6 +40,7 @@ export async function cancelSettlementInstruction(
     bondsAuth,
     splitRentCollector,
     splitRentRefundAccount,
+    settlementClaimsChunks,
   } = await getCloseSettlementAccounts(params)
 
   const instruction = await params.program.methods
@@ -61,6 +62,13 @@ export async function cancelSettlementInstruction(
       stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
       clock: SYSVAR_CLOCK_PUBKEY,
     })
+    .remainingAccounts(
+      settlementClaimsChunks.map(pubkey => ({
+        pubkey,
+        isSigner: false,
+        isWritable: true,
+      })),
+    )
     .instruction()
   return {
     instruction,
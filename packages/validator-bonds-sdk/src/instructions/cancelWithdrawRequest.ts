@@ -4,7 +4,7 @@ import { PublicKey } from '@solana/web3.js'
 import { getBond, getWithdrawRequest } from '../api'
 import {
   bondAddress,
-  withdrawRequestAddress,
+  indexedWithdrawRequestAddress,
   MARINADE_CONFIG_ADDRESS,
 } from '../sdk'
 import { anchorProgramWalletPubkey, checkAndGetBondAddress } from '../utils'
@@ -13,6 +13,7 @@ import type { ValidatorBondsProgram, WithdrawRequest } from '../sdk'
 import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
 import type { LoggerPlaceholder } from '@marinade.finance/ts-common'
 import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+import type BN from 'bn.js'
 
 /**
  * Generate instruction to cancel withdraw request for bond account.
@@ -22,6 +23,7 @@ import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
 export async function cancelWithdrawRequestInstruction({
   program,
   withdrawRequestAccount,
+  withdrawRequestIndex = 0,
   bondAccount,
   configAccount,
   voteAccount,
@@ -31,6 +33,7 @@ export async function cancelWithdrawRequestInstruction({
 }: {
   program: ValidatorBondsProgram
   withdrawRequestAccount?: PublicKey
+  withdrawRequestIndex?: number | BN // used to derive the withdraw request from the bond, 0 is the legacy one
   bondAccount?: PublicKey
   configAccount?: PublicKey
   voteAccount?: PublicKey
@@ -62,8 +65,9 @@ export async function cancelWithdrawRequestInstruction({
     bondAccount = bondAddress(configAccount, voteAccount, program.programId)[0]
   }
   if (bondAccount !== undefined && withdrawRequestAccount === undefined) {
-    withdrawRequestAccount = withdrawRequestAddress(
+    withdrawRequestAccount = indexedWithdrawRequestAddress(
       bondAccount,
+      withdrawRequestIndex,
       program.programId,
     )[0]
   }
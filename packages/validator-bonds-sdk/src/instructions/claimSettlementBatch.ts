@@ -0,0 +1,162 @@
+import {
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_STAKE_HISTORY_PUBKEY,
+  StakeProgram,
+} from '@solana/web3.js'
+import BN from 'bn.js'
+
+import { bondLedgerRemainingAccounts, getBond, getSettlement } from '../api'
+import { multiProofFromProofs } from '../merkleTree'
+import {
+  bondAddress,
+  bondsWithdrawerAuthority,
+  settlementAddress,
+  settlementClaimsAddress,
+} from '../sdk'
+import { getStakeAccount } from '../web3.js'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type {
+  EpochInfo,
+  PublicKey,
+  TransactionInstruction,
+} from '@solana/web3.js'
+
+/**
+ * Generate instruction to claim several merkle tree nodes of a settlement at once.
+ * All the claimed tree nodes have to share the staker and withdrawer authorities
+ * of the stakeAccountTo. The single node proofs are composed into a multi-proof.
+ * Permission-less operation.
+ */
+export async function claimSettlementBatchInstruction({
+  program,
+  claims,
+  stakeAccountFrom,
+  stakeAccountTo,
+  stakeAccountStaker,
+  stakeAccountWithdrawer,
+  settlementAccount,
+  settlementClaimsAccount,
+  settlementMerkleRoot,
+  settlementEpoch,
+  configAccount,
+  bondAccount,
+  voteAccount,
+}: {
+  program: ValidatorBondsProgram
+  claims: {
+    claimAmount: number | BN
+    index: number | BN
+    merkleProof: (number[] | Uint8Array | Buffer)[]
+  }[]
+  stakeAccountFrom: PublicKey
+  stakeAccountTo: PublicKey
+  stakeAccountStaker?: PublicKey
+  stakeAccountWithdrawer?: PublicKey
+  settlementAccount?: PublicKey
+  settlementClaimsAccount?: PublicKey // chunk of the claims bitmap covering the indexes, when chunked
+  settlementMerkleRoot?: number[] | Uint8Array | Buffer
+  settlementEpoch?: number | BN | EpochInfo
+  configAccount?: PublicKey
+  bondAccount?: PublicKey
+  voteAccount?: PublicKey
+}): Promise<{
+  instruction: TransactionInstruction
+  settlementAccount: PublicKey
+  settlementClaimsAccount: PublicKey
+}> {
+  if (settlementAccount !== undefined && bondAccount === undefined) {
+    bondAccount = (await getSettlement(program, settlementAccount)).bond
+  }
+  if (
+    voteAccount !== undefined &&
+    configAccount !== undefined &&
+    bondAccount === undefined
+  ) {
+    ;[bondAccount] = bondAddress(configAccount, voteAccount, program.programId)
+  }
+  if (bondAccount === undefined) {
+    throw new Error(
+      'Either [configAccount+voteAccount] or [bondAccount] must be provided',
+    )
+  }
+  if (configAccount === undefined) {
+    configAccount = (await getBond(program, bondAccount)).config
+  }
+
+  if (
+    settlementAccount === undefined &&
+    settlementMerkleRoot !== undefined &&
+    settlementEpoch !== undefined
+  ) {
+    ;[settlementAccount] = settlementAddress(
+      bondAccount,
+      settlementMerkleRoot,
+      settlementEpoch,
+      program.programId,
+    )
+  }
+  if (settlementAccount === undefined) {
+    throw new Error(
+      '[settlementAccount] must be provided or needed to have [bondAccount, merkleProof] to derive the address',
+    )
+  }
+  settlementClaimsAccount =
+    settlementClaimsAccount ??
+    settlementClaimsAddress(settlementAccount, program.programId)[0]
+
+  if (
+    stakeAccountStaker === undefined ||
+    stakeAccountWithdrawer === undefined
+  ) {
+    const stakeAccountToData = await getStakeAccount(program, stakeAccountTo, 0)
+    if (
+      stakeAccountToData.staker === null ||
+      stakeAccountToData.withdrawer === null
+    ) {
+      throw new Error(
+        'stakeAccountTo must be activated with staker and withdrawer defined',
+      )
+    }
+    stakeAccountStaker = stakeAccountStaker || stakeAccountToData.staker
+    stakeAccountWithdrawer =
+      stakeAccountWithdrawer || stakeAccountToData.withdrawer
+  }
+
+  const proof = multiProofFromProofs(
+    claims.map(({ index, merkleProof }) => ({ index, proof: merkleProof })),
+  )
+
+  const instruction = await program.methods
+    .claimSettlementBatch({
+      proof,
+      stakeAccountStaker,
+      stakeAccountWithdrawer,
+      claims: claims.map(({ claimAmount, index }) => ({
+        claim: new BN(claimAmount),
+        index: new BN(index),
+      })),
+    })
+    .accountsPartial({
+      config: configAccount,
+      bond: bondAccount,
+      settlement: settlementAccount,
+      settlementClaims: settlementClaimsAccount,
+      stakeAccountFrom,
+      stakeAccountTo,
+      bondsWithdrawerAuthority: bondsWithdrawerAuthority(
+        configAccount,
+        program.programId,
+      )[0],
+      stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+      clock: SYSVAR_CLOCK_PUBKEY,
+      stakeProgram: StakeProgram.programId,
+    })
+    .remainingAccounts(await bondLedgerRemainingAccounts(program, bondAccount))
+    .instruction()
+  return {
+    instruction,
+    settlementAccount,
+    settlementClaimsAccount,
+  }
+}
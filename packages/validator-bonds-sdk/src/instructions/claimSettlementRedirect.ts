@@ -0,0 +1,158 @@
+import {
+  PublicKey,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_STAKE_HISTORY_PUBKEY,
+  StakeProgram,
+} from '@solana/web3.js'
+import BN from 'bn.js'
+
+import { bondLedgerRemainingAccounts, getBond, getSettlement } from '../api'
+import { MerkleTreeNode } from '../merkleTree'
+import {
+  bondAddress,
+  bondsWithdrawerAuthority,
+  settlementAddress,
+  settlementClaimsAddress,
+} from '../sdk'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type {
+  EpochInfo,
+  Keypair,
+  Signer,
+  TransactionInstruction,
+} from '@solana/web3.js'
+
+/**
+ * Generate instruction to claim from settlement on behalf of the merkle tree node withdraw authority.
+ * The withdraw authority signs and the claim is paid to any system account
+ * or to any stake account the withdraw authority is the withdrawer of.
+ */
+export async function claimSettlementRedirectInstruction({
+  program,
+  claimAmount,
+  index,
+  merkleProof,
+  stakeAccountFrom,
+  claimTo,
+  stakeAccountStaker,
+  withdrawAuthority,
+  settlementAccount,
+  settlementClaimsAccount,
+  settlementMerkleRoot,
+  settlementEpoch,
+  configAccount,
+  bondAccount,
+  voteAccount,
+}: {
+  program: ValidatorBondsProgram
+  claimAmount: number | BN
+  index: number | BN
+  merkleProof: (number[] | Uint8Array | Buffer)[]
+  stakeAccountFrom: PublicKey
+  claimTo: PublicKey
+  stakeAccountStaker: PublicKey
+  withdrawAuthority: PublicKey | Keypair | Signer | WalletInterface // signer
+  settlementAccount?: PublicKey
+  settlementClaimsAccount?: PublicKey // chunk of the claims bitmap covering the index, when chunked
+  settlementMerkleRoot?: number[] | Uint8Array | Buffer
+  settlementEpoch?: number | BN | EpochInfo
+  configAccount?: PublicKey
+  bondAccount?: PublicKey
+  voteAccount?: PublicKey
+}): Promise<{
+  instruction: TransactionInstruction
+  settlementAccount: PublicKey
+  settlementClaimsAccount: PublicKey
+}> {
+  if (settlementAccount !== undefined && bondAccount === undefined) {
+    bondAccount = (await getSettlement(program, settlementAccount)).bond
+  }
+  if (
+    voteAccount !== undefined &&
+    configAccount !== undefined &&
+    bondAccount === undefined
+  ) {
+    ;[bondAccount] = bondAddress(configAccount, voteAccount, program.programId)
+  }
+  if (bondAccount === undefined) {
+    throw new Error(
+      'Either [configAccount+voteAccount] or [bondAccount] must be provided',
+    )
+  }
+  if (configAccount === undefined) {
+    configAccount = (await getBond(program, bondAccount)).config
+  }
+
+  if (
+    settlementAccount === undefined &&
+    settlementMerkleRoot !== undefined &&
+    settlementEpoch !== undefined
+  ) {
+    ;[settlementAccount] = settlementAddress(
+      bondAccount,
+      settlementMerkleRoot,
+      settlementEpoch,
+      program.programId,
+    )
+  }
+  if (settlementAccount === undefined) {
+    throw new Error(
+      '[settlementAccount] must be provided or needed to have [bondAccount, merkleProof] to derive the address',
+    )
+  }
+  settlementClaimsAccount =
+    settlementClaimsAccount ??
+    settlementClaimsAddress(settlementAccount, program.programId)[0]
+
+  const merkleProofNumbers = merkleProof.map(proofPathRecord =>
+    Array.isArray(proofPathRecord)
+      ? proofPathRecord
+      : Array.from(proofPathRecord),
+  )
+  const stakeAccountWithdrawer =
+    withdrawAuthority instanceof PublicKey
+      ? withdrawAuthority
+      : withdrawAuthority.publicKey
+
+  const treeNodeHash = MerkleTreeNode.hash({
+    stakeAuthority: stakeAccountStaker,
+    withdrawAuthority: stakeAccountWithdrawer,
+    claim: claimAmount,
+    index,
+  }).words
+
+  const instruction = await program.methods
+    .claimSettlementRedirect({
+      proof: merkleProofNumbers,
+      treeNodeHash,
+      claim: new BN(claimAmount),
+      stakeAccountStaker,
+      stakeAccountWithdrawer,
+      index: new BN(index),
+    })
+    .accountsPartial({
+      config: configAccount,
+      bond: bondAccount,
+      settlement: settlementAccount,
+      settlementClaims: settlementClaimsAccount,
+      stakeAccountFrom,
+      withdrawAuthority: stakeAccountWithdrawer,
+      claimTo,
+      bondsWithdrawerAuthority: bondsWithdrawerAuthority(
+        configAccount,
+        program.programId,
+      )[0],
+      stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+      clock: SYSVAR_CLOCK_PUBKEY,
+      stakeProgram: StakeProgram.programId,
+    })
+    .remainingAccounts(await bondLedgerRemainingAccounts(program, bondAccount))
+    .instruction()
+  return {
+    instruction,
+    settlementAccount,
+    settlementClaimsAccount,
+  }
+}
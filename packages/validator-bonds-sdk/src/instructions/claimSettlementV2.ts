@@ -5,7 +5,7 @@ import {
 } from '@solana/web3.js'
 import BN from 'bn.js'
 
-import { getBond, getSettlement } from '../api'
+import { bondLedgerRemainingAccounts, getBond, getSettlement } from '../api'
 import { MerkleTreeNode } from '../merkleTree'
 import {
   bondAddress,
@@ -37,6 +37,7 @@ export async function claimSettlementV2Instruction({
   stakeAccountStaker,
   stakeAccountWithdrawer,
   settlementAccount,
+  settlementClaimsAccount,
   settlementMerkleRoot,
   settlementEpoch,
   configAccount,
@@ -52,6 +53,7 @@ export async function claimSettlementV2Instruction({
   stakeAccountWithdrawer?: PublicKey
   stakeAccountStaker?: PublicKey
   settlementAccount?: PublicKey
+  settlementClaimsAccount?: PublicKey // chunk of the claims bitmap covering the index, when chunked
   settlementMerkleRoot?: number[] | Uint8Array | Buffer
   settlementEpoch?: number | BN | EpochInfo
   configAccount?: PublicKey
@@ -104,10 +106,9 @@ export async function claimSettlementV2Instruction({
     )
   }
 
-  const [settlementClaimsAccount] = settlementClaimsAddress(
-    settlementAccount,
-    program.programId,
-  )
+  settlementClaimsAccount =
+    settlementClaimsAccount ??
+    settlementClaimsAddress(settlementAccount, program.programId)[0]
 
   const merkleProofNumbers = merkleProof.map(proofPathRecord => {
     if (Array.isArray(proofPathRecord)) {
@@ -166,6 +167,7 @@ export async function claimSettlementV2Instruction({
       clock: SYSVAR_CLOCK_PUBKEY,
       stakeProgram: StakeProgram.programId,
     })
+    .remainingAccounts(await bondLedgerRemainingAccounts(program, bondAccount))
     .instruction()
   return {
     instruction,
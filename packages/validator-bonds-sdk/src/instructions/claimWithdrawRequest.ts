@@ -9,10 +9,14 @@ import {
   SystemProgram,
 } from '@solana/web3.js'
 
-import { getBond, getWithdrawRequest } from '../api'
+import {
+  bondLedgerRemainingAccounts,
+  getBond,
+  getWithdrawRequest,
+} from '../api'
 import {
   bondAddress,
-  withdrawRequestAddress,
+  indexedWithdrawRequestAddress,
   MARINADE_CONFIG_ADDRESS,
   bondsWithdrawerAuthority,
 } from '../sdk'
@@ -22,6 +26,7 @@ import type { ValidatorBondsProgram, WithdrawRequest } from '../sdk'
 import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
 import type { LoggerPlaceholder } from '@marinade.finance/ts-common'
 import type { Signer, TransactionInstruction } from '@solana/web3.js'
+import type BN from 'bn.js'
 
 /**
  * Generate instruction to withdraw amount defined within the withdraw request.
@@ -35,6 +40,7 @@ import type { Signer, TransactionInstruction } from '@solana/web3.js'
 export async function claimWithdrawRequestInstruction({
   program,
   withdrawRequestAccount,
+  withdrawRequestIndex = 0,
   bondAccount,
   configAccount,
   voteAccount,
@@ -46,6 +52,7 @@ export async function claimWithdrawRequestInstruction({
 }: {
   program: ValidatorBondsProgram
   withdrawRequestAccount?: PublicKey
+  withdrawRequestIndex?: number | BN // used to derive the withdraw request from the bond, 0 is the legacy one
   bondAccount?: PublicKey
   configAccount?: PublicKey
   voteAccount?: PublicKey
@@ -81,8 +88,9 @@ export async function claimWithdrawRequestInstruction({
     voteAccount = voteAccount ?? withdrawRequestData.voteAccount
   }
   if (bondAccount !== undefined && withdrawRequestAccount === undefined) {
-    withdrawRequestAccount = withdrawRequestAddress(
+    withdrawRequestAccount = indexedWithdrawRequestAddress(
       bondAccount,
+      withdrawRequestIndex,
       program.programId,
     )[0]
   }
@@ -160,6 +168,7 @@ export async function claimWithdrawRequestInstruction({
       systemProgram: SystemProgram.programId,
       clock: SYSVAR_CLOCK_PUBKEY,
     })
+    .remainingAccounts(await bondLedgerRemainingAccounts(program, bondAccount))
     .instruction()
   return {
     instruction,
@@ -0,0 +1,85 @@
+import { PublicKey } from '@solana/web3.js'
+
+import { findBondStakeAccounts, getBond } from '../api'
+import { anchorProgramWalletPubkey, checkAndGetBondAddress } from '../utils'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to close bond account when the validator leaves the program.
+ * Signature of validator identity of vote account voter pubkey OR bond authority is required.
+ * The bond must not have any open settlement, withdraw request, bond product, bond ledger
+ * nor bond token vault holding collateral. A bond created before the counters of the open accounts
+ * were introduced has to be counted first (see countBondAccountsInstruction).
+ * The stake accounts of the bond are passed to the instruction to verify none is delegated to the vote account,
+ * when not provided they are loaded with getProgramAccounts call.
+ * The program cannot enumerate the stake accounts, a stake account not passed stays under the bonds
+ * withdrawer authority and it is accessible again when the bond is re-created.
+ */
+export async function closeBondInstruction({
+  program,
+  bondAccount,
+  configAccount,
+  voteAccount,
+  authority = anchorProgramWalletPubkey(program),
+  stakeAccounts,
+  rentCollector = anchorProgramWalletPubkey(program),
+}: {
+  program: ValidatorBondsProgram
+  bondAccount?: PublicKey
+  configAccount?: PublicKey
+  voteAccount?: PublicKey
+  authority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  stakeAccounts?: PublicKey[]
+  rentCollector?: PublicKey
+}): Promise<{
+  bondAccount: PublicKey
+  instruction: TransactionInstruction
+}> {
+  bondAccount = checkAndGetBondAddress({
+    bond: bondAccount,
+    config: configAccount,
+    voteAccount,
+    programId: program.programId,
+  })
+  if (voteAccount === undefined || configAccount === undefined) {
+    const bondData = await getBond(program, bondAccount)
+    voteAccount = bondData.voteAccount
+    configAccount = bondData.config
+  }
+  authority = authority instanceof PublicKey ? authority : authority.publicKey
+  if (stakeAccounts === undefined) {
+    stakeAccounts = (
+      await findBondStakeAccounts({
+        program,
+        configAccount,
+        bondAccount,
+        voteAccount,
+      })
+    ).map(stakeAccount => stakeAccount.publicKey)
+  }
+
+  const instruction = await program.methods
+    .closeBond()
+    .accountsPartial({
+      config: configAccount,
+      bond: bondAccount,
+      voteAccount,
+      authority,
+      rentCollector,
+    })
+    .remainingAccounts(
+      stakeAccounts.map(pubkey => ({
+        pubkey,
+        isSigner: false,
+        isWritable: false,
+      })),
+    )
+    .instruction()
+  return {
+    bondAccount,
+    instruction,
+  }
+}
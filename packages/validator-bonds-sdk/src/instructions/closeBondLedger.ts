@@ -0,0 +1,59 @@
+import { PublicKey } from '@solana/web3.js'
+
+import { getBond } from '../api'
+import { anchorProgramWalletPubkey, checkAndGetBondAddress } from '../utils'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to close the bond ledger account, the bond ledger has to be closed before closing the bond.
+ * Signature of validator identity of vote account voter pubkey OR bond authority is required.
+ */
+export async function closeBondLedgerInstruction({
+  program,
+  bondAccount,
+  configAccount,
+  voteAccount,
+  authority = anchorProgramWalletPubkey(program),
+  rentCollector = anchorProgramWalletPubkey(program),
+}: {
+  program: ValidatorBondsProgram
+  bondAccount?: PublicKey
+  configAccount?: PublicKey
+  voteAccount?: PublicKey
+  authority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  rentCollector?: PublicKey
+}): Promise<{
+  bondAccount: PublicKey
+  instruction: TransactionInstruction
+}> {
+  bondAccount = checkAndGetBondAddress({
+    bond: bondAccount,
+    config: configAccount,
+    voteAccount,
+    programId: program.programId,
+  })
+  if (voteAccount === undefined || configAccount === undefined) {
+    const bondData = await getBond(program, bondAccount)
+    voteAccount = bondData.voteAccount
+    configAccount = bondData.config
+  }
+  authority = authority instanceof PublicKey ? authority : authority.publicKey
+
+  const instruction = await program.methods
+    .closeBondLedger()
+    .accountsPartial({
+      config: configAccount,
+      bond: bondAccount,
+      voteAccount,
+      authority,
+      rentCollector,
+    })
+    .instruction()
+  return {
+    bondAccount,
+    instruction,
+  }
+}
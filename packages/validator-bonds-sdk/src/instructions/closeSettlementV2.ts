@@ -6,7 +6,12 @@ import {
   Keypair,
 } from '@solana/web3.js'
 
-import { getBond, getSettlement } from '../api'
+import {
+  bondLedgerRemainingAccounts,
+  getBond,
+  getSettlement,
+  getSettlementClaimsChunkAddresses,
+} from '../api'
 import {
   bondAddress,
   settlementStakerAuthority,
@@ -28,13 +33,16 @@ export type CloseSettlementParams = {
   rentCollector?: PublicKey
   splitRentCollector?: PublicKey | null
   splitRentRefundAccount?: PublicKey
+  settlementClaimsChunks?: PublicKey[]
   logger?: LoggerPlaceholder
 }
 
 /**
  * Generate instruction to close settlement.
  * This is a permission-less operation,
- * the settlement can be closed when timeout elapses (configured in config).
+ * the settlement can be closed when timeout elapses (configured in config)
+ * or when all the merkle tree nodes were claimed.
+ * The created SettlementClaims chunk accounts are closed together with the settlement.
  */
 export async function closeSettlementV2Instruction(
   params: CloseSettlementParams,
@@ -49,6 +57,7 @@ export async function closeSettlementV2Instruction(
     bondsAuth,
     splitRentCollector,
     splitRentRefundAccount,
+    settlementClaimsChunks,
   } = await getCloseSettlementAccounts(params)
 
   const instruction = await params.program.methods
@@ -69,6 +78,15 @@ export async function closeSettlementV2Instruction(
       stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
       clock: SYSVAR_CLOCK_PUBKEY,
     })
+    .remainingAccounts([
+      ...settlementClaimsChunks.map(pubkey => ({
+        pubkey,
+        isSigner: false,
+        isWritable: true,
+      })),
+      // the bond ledger is expected as the last remaining account
+      ...(await bondLedgerRemainingAccounts(params.program, bondAccount)),
+    ])
     .instruction()
   return {
     instruction,
@@ -84,6 +102,7 @@ export async function getCloseSettlementAccounts({
   rentCollector,
   splitRentCollector,
   splitRentRefundAccount,
+  settlementClaimsChunks,
   logger,
 }: CloseSettlementParams): Promise<{
   configAccount: PublicKey
@@ -93,6 +112,7 @@ export async function getCloseSettlementAccounts({
   bondsAuth: PublicKey
   splitRentCollector: PublicKey
   splitRentRefundAccount: PublicKey
+  settlementClaimsChunks: PublicKey[]
   logger?: LoggerPlaceholder
 }> {
   if (
@@ -156,6 +176,10 @@ export async function getCloseSettlementAccounts({
     )
   }
 
+  settlementClaimsChunks =
+    settlementClaimsChunks ??
+    (await getSettlementClaimsChunkAddresses(program, settlementAccount))
+
   return {
     configAccount,
     bondAccount,
@@ -164,5 +188,6 @@ export async function getCloseSettlementAccounts({
     bondsAuth,
     splitRentCollector,
     splitRentRefundAccount,
+    settlementClaimsChunks,
   }
 }
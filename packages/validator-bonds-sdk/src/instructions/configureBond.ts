@@ -11,6 +11,7 @@ import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
 /**
  * Generate instruction to configure bond account. Signature of validator identity of vote account
  * voter pubkey OR bond authority is required.
+ * The new bond authority is only proposed, it takes the role after signing the accept bond authority instruction.
  */
 export async function configureBondInstruction({
   program,
@@ -38,6 +38,7 @@ export async function configureCommissionProductInstruction({
   mevBps,
   blockBps,
   uniformBps = undefined,
+  effectiveFromEpoch = null,
 }: {
   program: ValidatorBondsProgram
   bondProductAccount?: PublicKey
@@ -49,6 +50,7 @@ export async function configureCommissionProductInstruction({
   mevBps?: BN | number | null
   blockBps?: BN | number | null
   uniformBps?: BN | number | null
+  effectiveFromEpoch?: BN | number | null
 }): Promise<{
   instruction: TransactionInstruction
   bondProduct: PublicKey
@@ -109,6 +111,7 @@ export async function configureCommissionProductInstruction({
   const configData = getCommissionData(commissionConfig)
   const args: ConfigureBondProductArgs = {
     configData,
+    effectiveFromEpoch: toBNPreserve(effectiveFromEpoch),
   }
 
   const instruction = await program.methods
@@ -139,6 +142,7 @@ export async function configureCustomProductInstruction({
   authority = anchorProgramWalletPubkey(program),
   customName,
   customProductData,
+  effectiveFromEpoch = null,
 }: {
   program: ValidatorBondsProgram
   bondProductAccount?: PublicKey
@@ -148,6 +152,7 @@ export async function configureCustomProductInstruction({
   authority?: PublicKey | Keypair | Signer | WalletInterface // signer
   customName?: string
   customProductData: Buffer | Uint8Array | number[]
+  effectiveFromEpoch?: BN | number | null
 }): Promise<{
   instruction: TransactionInstruction
   bondProduct: PublicKey
@@ -175,6 +180,7 @@ export async function configureCustomProductInstruction({
   const configData = getCustomData(customProductData)
   const args: ConfigureBondProductArgs = {
     configData,
+    effectiveFromEpoch: toBNPreserve(effectiveFromEpoch),
   }
 
   const instruction = await program.methods
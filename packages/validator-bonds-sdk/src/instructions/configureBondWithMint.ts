@@ -15,6 +15,7 @@ import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
 /**
  * Generate instruction to configure bond account with ownership of bond minted token.
  * Owner of the token has to sign the transaction and the token is burnt.
+ * The new bond authority is only proposed, it takes the role after signing the accept bond authority instruction.
  */
 export async function configureBondWithMintInstruction({
   program,
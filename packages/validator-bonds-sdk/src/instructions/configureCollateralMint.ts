@@ -0,0 +1,62 @@
+import { PublicKey } from '@solana/web3.js'
+import BN from 'bn.js'
+
+import { getCollateralMint, getConfig } from '../api'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to configure the haircut of the collateral mint
+ * or to disable (enable) funding the bonds with the mint.
+ * Admin only operation.
+ */
+export async function configureCollateralMintInstruction({
+  program,
+  collateralMintAccount,
+  configAccount,
+  adminAuthority,
+  newHaircutBps,
+  enabled,
+}: {
+  program: ValidatorBondsProgram
+  collateralMintAccount: PublicKey
+  configAccount?: PublicKey
+  adminAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  newHaircutBps?: number | BN
+  enabled?: boolean
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  if (configAccount === undefined) {
+    const collateralMintData = await getCollateralMint(
+      program,
+      collateralMintAccount,
+    )
+    configAccount = collateralMintData.config
+  }
+  if (adminAuthority === undefined) {
+    const configData = await getConfig(program, configAccount)
+    adminAuthority = configData.adminAuthority
+  }
+  adminAuthority =
+    adminAuthority instanceof PublicKey
+      ? adminAuthority
+      : adminAuthority.publicKey
+
+  const instruction = await program.methods
+    .configureCollateralMint({
+      haircutBps: newHaircutBps === undefined ? null : new BN(newHaircutBps),
+      enabled: enabled ?? null,
+    })
+    .accountsPartial({
+      config: configAccount,
+      adminAuthority,
+      collateralMint: collateralMintAccount,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
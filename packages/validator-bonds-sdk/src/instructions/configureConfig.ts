@@ -12,18 +12,14 @@ import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
 
 /**
  * Generate instruction to configure config account. Available for admin authority.
+ * The authorities are changed with the two-step propose and accept config authority instructions.
+ * The lockup-sensitive params are changed with the queue and execute configure config instructions.
  *
  * @type {Object} args - Arguments on instruction creation
  * @param param {ValidatorBondsProgram} args.program - anchor program instance
  * @param param {PublicKey} args.configAccount - config account to configure (default: MARINADE config address)
  * @param param {PublicKey} args.adminAuthority [SIGNER] - admin authority (default: provider wallet address)
- * @param param {PublicKey} args.newAdmin - admin authority that will be set when field is used
- * @param param {PublicKey} args.newOperator - operator authority that will be set when field is used
- * @param param {PublicKey} args.newPauseAuthority - pause authority that will be set when field is used
- * @param param {PublicKey} args.newEpochsToClaimSettlement - number of epochs before settlement claiming timeouts that will be set when field is used
- * @param param {PublicKey} args.newSlotsToStartSettlementClaiming - number of slots that has to expire until when settlement can be claimed
- * @param param {PublicKey} args.newWithdrawLockupEpochs - number of epochs after which withdraw can be executed that will be set when field is used
- * @param param {PublicKey} args.newMinimumStakeLamports - number of lamports as minimum stake account size that will be set when field is used
+ * @param param {PublicKey} args.newMinBondMaxStakeWanted - minimum of max stake wanted configured at bond that will be set when field is used
  * @type {Object} return - Return data of generated instruction
  * @return {TransactionInstruction} return.instruction - Instruction to configure config
  */
@@ -31,26 +27,12 @@ export async function configureConfigInstruction({
   program,
   configAccount,
   adminAuthority,
-  newAdmin,
-  newOperator,
-  newPauseAuthority,
-  newEpochsToClaimSettlement,
-  newSlotsToStartSettlementClaiming,
-  newWithdrawLockupEpochs,
-  newMinimumStakeLamports,
   newMinBondMaxStakeWanted,
   logger,
 }: {
   program: ValidatorBondsProgram
   configAccount?: PublicKey
   adminAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
-  newAdmin?: PublicKey
-  newOperator?: PublicKey
-  newPauseAuthority?: PublicKey
-  newEpochsToClaimSettlement?: BN | number
-  newSlotsToStartSettlementClaiming?: BN | number
-  newWithdrawLockupEpochs?: BN | number
-  newMinimumStakeLamports?: BN | number
   newMinBondMaxStakeWanted?: BN | number
   logger?: LoggerPlaceholder
 }): Promise<{
@@ -74,24 +56,13 @@ export async function configureConfigInstruction({
       : adminAuthority.publicKey
 
   const args: ConfigureConfigArgs = {
-    admin: newAdmin ?? null,
-    operator: newOperator ?? null,
-    pauseAuthority: newPauseAuthority ?? null,
-    epochsToClaimSettlement: newEpochsToClaimSettlement
-      ? new BN(newEpochsToClaimSettlement)
-      : null,
-    slotsToStartSettlementClaiming:
-      newSlotsToStartSettlementClaiming !== undefined
-        ? new BN(newSlotsToStartSettlementClaiming)
-        : null,
-    withdrawLockupEpochs:
-      newWithdrawLockupEpochs !== undefined
-        ? new BN(newWithdrawLockupEpochs)
-        : null,
-    minimumStakeLamports:
-      newMinimumStakeLamports !== undefined
-        ? new BN(newMinimumStakeLamports)
-        : null,
+    admin: null,
+    operator: null,
+    pauseAuthority: null,
+    epochsToClaimSettlement: null,
+    slotsToStartSettlementClaiming: null,
+    withdrawLockupEpochs: null,
+    minimumStakeLamports: null,
     minBondMaxStakeWanted:
       newMinBondMaxStakeWanted !== undefined
         ? new BN(newMinBondMaxStakeWanted)
@@ -0,0 +1,88 @@
+import { PublicKey } from '@solana/web3.js'
+
+import {
+  findBondProducts,
+  findSettlements,
+  findWithdrawRequests,
+  getBond,
+  getConfig,
+} from '../api'
+import { checkAndGetBondAddress } from '../utils'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to count the open accounts of a bond created before the counters
+ * of the open accounts were introduced. The bond cannot be closed until it is counted.
+ * The not counted settlements, withdraw requests and bond products of the bond are passed
+ * to the instruction, when not provided they are loaded with getProgramAccounts calls.
+ * It is permission-ed operation for operator authority.
+ */
+export async function countBondAccountsInstruction({
+  program,
+  bondAccount,
+  configAccount,
+  voteAccount,
+  operatorAuthority,
+  accounts,
+}: {
+  program: ValidatorBondsProgram
+  bondAccount?: PublicKey
+  configAccount?: PublicKey
+  voteAccount?: PublicKey
+  operatorAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  accounts?: PublicKey[]
+}): Promise<{
+  bondAccount: PublicKey
+  instruction: TransactionInstruction
+}> {
+  bondAccount = checkAndGetBondAddress({
+    bond: bondAccount,
+    config: configAccount,
+    voteAccount,
+    programId: program.programId,
+  })
+  if (configAccount === undefined) {
+    configAccount = (await getBond(program, bondAccount)).config
+  }
+  if (operatorAuthority === undefined) {
+    operatorAuthority = (await getConfig(program, configAccount))
+      .operatorAuthority
+  }
+  const operatorAuthorityPubkey =
+    operatorAuthority instanceof PublicKey
+      ? operatorAuthority
+      : operatorAuthority.publicKey
+  if (accounts === undefined) {
+    const bond = bondAccount
+    accounts = [
+      ...(await findSettlements({ program, bond })),
+      ...(await findWithdrawRequests({ program, bond })),
+      ...(await findBondProducts({ program, bond })),
+    ]
+      .filter(({ account }) => !account.counted)
+      .map(({ publicKey }) => publicKey)
+  }
+
+  const instruction = await program.methods
+    .countBondAccounts()
+    .accountsPartial({
+      config: configAccount,
+      bond: bondAccount,
+      operatorAuthority: operatorAuthorityPubkey,
+    })
+    .remainingAccounts(
+      accounts.map(pubkey => ({
+        pubkey,
+        isSigner: false,
+        isWritable: true,
+      })),
+    )
+    .instruction()
+  return {
+    bondAccount,
+    instruction,
+  }
+}
@@ -2,7 +2,7 @@ import { logWarn } from '@marinade.finance/ts-common'
 import { PublicKey } from '@solana/web3.js'
 
 import { getConfig } from '../api'
-import { MARINADE_CONFIG_ADDRESS } from '../sdk'
+import { MARINADE_CONFIG_ADDRESS, PAUSE_ALL } from '../sdk'
 
 import type { ValidatorBondsProgram } from '../sdk'
 import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
@@ -10,18 +10,21 @@ import type { LoggerPlaceholder } from '@marinade.finance/ts-common'
 import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
 
 /**
- * Generate instruction to pause program.
- * Admin only operation.
+ * Generate instruction to pause program operation groups.
+ * Pause authority only operation.
+ * The operations is a bitmask of the PAUSE_* constants (default: PAUSE_ALL).
  */
 export async function emergencyPauseInstruction({
   program,
   configAccount,
   pauseAuthority,
+  operations = PAUSE_ALL,
   logger,
 }: {
   program: ValidatorBondsProgram
   configAccount?: PublicKey
   pauseAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  operations?: number
   logger?: LoggerPlaceholder
 }): Promise<{
   instruction: TransactionInstruction
@@ -44,7 +47,7 @@ export async function emergencyPauseInstruction({
       : pauseAuthority.publicKey
 
   const instruction = await program.methods
-    .emergencyPause()
+    .emergencyPause({ operations })
     .accountsPartial({
       config: configAccount,
       pauseAuthority,
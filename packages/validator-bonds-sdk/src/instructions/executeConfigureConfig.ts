@@ -0,0 +1,42 @@
+import { logWarn } from '@marinade.finance/ts-common'
+
+import { MARINADE_CONFIG_ADDRESS } from '../sdk'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { LoggerPlaceholder } from '@marinade.finance/ts-common'
+import type { PublicKey, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to apply the queued config change once its activation epoch is reached.
+ * Permission-less operation, the change content was defined by admin authority when queueing.
+ */
+export async function executeConfigureConfigInstruction({
+  program,
+  configAccount,
+  logger,
+}: {
+  program: ValidatorBondsProgram
+  configAccount?: PublicKey
+  logger?: LoggerPlaceholder
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  if (configAccount === undefined) {
+    logWarn(
+      logger,
+      'executeConfigureConfig SDK: config is not provided, using default config address: ' +
+        MARINADE_CONFIG_ADDRESS.toBase58(),
+    )
+    configAccount = MARINADE_CONFIG_ADDRESS
+  }
+
+  const instruction = await program.methods
+    .executeConfigureConfig()
+    .accountsPartial({
+      config: configAccount,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
@@ -0,0 +1,62 @@
+import { PublicKey } from '@solana/web3.js'
+import BN from 'bn.js'
+
+import { getBond, getConfig, getSettlement } from '../api'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to extend the claiming window of the settlement.
+ * The expiration epoch has to be later than the current settlement expiration
+ * and not earlier than the current epoch.
+ * It is permission-ed operation for operator authority.
+ */
+export async function extendSettlementInstruction({
+  program,
+  settlementAccount,
+  expirationEpoch,
+  operatorAuthority,
+  configAccount,
+  bondAccount,
+}: {
+  program: ValidatorBondsProgram
+  settlementAccount: PublicKey
+  expirationEpoch: number | BN
+  operatorAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  configAccount?: PublicKey
+  bondAccount?: PublicKey
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  if (bondAccount === undefined) {
+    bondAccount = (await getSettlement(program, settlementAccount)).bond
+  }
+  if (configAccount === undefined) {
+    configAccount = (await getBond(program, bondAccount)).config
+  }
+  if (operatorAuthority === undefined) {
+    operatorAuthority = (await getConfig(program, configAccount))
+      .operatorAuthority
+  }
+  const operatorAuthorityPubkey =
+    operatorAuthority instanceof PublicKey
+      ? operatorAuthority
+      : operatorAuthority.publicKey
+
+  const instruction = await program.methods
+    .extendSettlement({
+      expirationEpoch: new BN(expirationEpoch),
+    })
+    .accountsPartial({
+      config: configAccount,
+      bond: bondAccount,
+      settlement: settlementAccount,
+      operatorAuthority: operatorAuthorityPubkey,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
@@ -0,0 +1,123 @@
+import { logWarn } from '@marinade.finance/ts-common'
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { PublicKey, SystemProgram } from '@solana/web3.js'
+import BN from 'bn.js'
+import { getAssociatedTokenAddressSync } from 'solana-spl-token-modern'
+
+import { getBond } from '../api'
+import {
+  MARINADE_CONFIG_ADDRESS,
+  bondTokenVaultAddress,
+  bondsWithdrawerAuthority,
+  collateralMintAddress,
+} from '../sdk'
+import { anchorProgramWalletPubkey, checkAndGetBondAddress } from '../utils'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { LoggerPlaceholder } from '@marinade.finance/ts-common'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to fund bond with tokens of a permitted collateral mint.
+ * Permission-less operation, signature of the depositor token account owner is required.
+ * The tokens are deposited to the bond token vault, created on the first funding.
+ * By default the tokens are taken from the associated token account of the depositor.
+ */
+export async function fundBondTokenInstruction({
+  program,
+  bondAccount,
+  configAccount,
+  voteAccount,
+  mint,
+  amount,
+  depositorAuthority = anchorProgramWalletPubkey(program),
+  depositorTokenAccount,
+  rentPayer = anchorProgramWalletPubkey(program),
+  tokenProgram = TOKEN_PROGRAM_ID,
+  logger,
+}: {
+  program: ValidatorBondsProgram
+  bondAccount?: PublicKey
+  configAccount?: PublicKey
+  voteAccount?: PublicKey
+  mint: PublicKey
+  amount: number | BN
+  depositorAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  depositorTokenAccount?: PublicKey
+  rentPayer?: PublicKey | Keypair | Signer | WalletInterface // signer
+  tokenProgram?: PublicKey
+  logger?: LoggerPlaceholder
+}): Promise<{
+  instruction: TransactionInstruction
+  bondAccount: PublicKey
+  bondTokenVault: PublicKey
+}> {
+  if (!bondAccount && !configAccount && voteAccount) {
+    logWarn(
+      logger,
+      'fundBondToken SDK: config is not provided, using default address: ' +
+        MARINADE_CONFIG_ADDRESS.toBase58(),
+    )
+    configAccount = MARINADE_CONFIG_ADDRESS
+  }
+  bondAccount = checkAndGetBondAddress({
+    bond: bondAccount,
+    config: configAccount,
+    voteAccount,
+    programId: program.programId,
+  })
+  if (configAccount === undefined) {
+    const bondData = await getBond(program, bondAccount)
+    configAccount = bondData.config
+  }
+  depositorAuthority =
+    depositorAuthority instanceof PublicKey
+      ? depositorAuthority
+      : depositorAuthority.publicKey
+  rentPayer = rentPayer instanceof PublicKey ? rentPayer : rentPayer.publicKey
+  depositorTokenAccount =
+    depositorTokenAccount ??
+    getAssociatedTokenAddressSync(
+      mint,
+      depositorAuthority,
+      true,
+      tokenProgram,
+    )
+
+  const [bondTokenVault] = bondTokenVaultAddress(
+    bondAccount,
+    mint,
+    program.programId,
+  )
+  const instruction = await program.methods
+    .fundBondToken({
+      amount: new BN(amount),
+    })
+    .accountsPartial({
+      config: configAccount,
+      bond: bondAccount,
+      collateralMint: collateralMintAddress(
+        configAccount,
+        mint,
+        program.programId,
+      )[0],
+      mint,
+      bondsWithdrawerAuthority: bondsWithdrawerAuthority(
+        configAccount,
+        program.programId,
+      )[0],
+      bondTokenVault,
+      depositorTokenAccount,
+      depositorAuthority,
+      rentPayer,
+      tokenProgram,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction()
+  return {
+    instruction,
+    bondAccount,
+    bondTokenVault,
+  }
+}
@@ -9,7 +9,12 @@ import {
   SystemProgram,
 } from '@solana/web3.js'
 
-import { getBond, getConfig, getSettlement } from '../api'
+import {
+  bondLedgerRemainingAccounts,
+  getBond,
+  getConfig,
+  getSettlement,
+} from '../api'
 import {
   bondAddress,
   bondsWithdrawerAuthority,
@@ -114,6 +119,7 @@ export async function fundSettlementInstruction({
       stakeConfig: STAKE_CONFIG_ID,
       systemProgram: SystemProgram.programId,
     })
+    .remainingAccounts(await bondLedgerRemainingAccounts(program, bondAccount))
     .instruction()
   return {
     instruction,
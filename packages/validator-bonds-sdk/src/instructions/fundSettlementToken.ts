@@ -0,0 +1,135 @@
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { PublicKey, StakeProgram, SYSVAR_CLOCK_PUBKEY } from '@solana/web3.js'
+import { getAssociatedTokenAddressSync } from 'solana-spl-token-modern'
+
+import {
+  bondLedgerRemainingAccounts,
+  getBond,
+  getConfig,
+  getSettlement,
+} from '../api'
+import {
+  bondAddress,
+  bondTokenVaultAddress,
+  bondsWithdrawerAuthority,
+  collateralMintAddress,
+  settlementStakerAuthority,
+} from '../sdk'
+import { anchorProgramWalletPubkey } from '../utils'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to fund settlement from the bond token collateral.
+ * Only operator authority is permitted to do this.
+ * The liquidator provides a stake account delegated to the bond vote account
+ * that is moved under the settlement, in exchange the liquidator receives
+ * the bond collateral tokens by the exchange rate of the current epoch
+ * without the haircut deducted.
+ *
+ * By default the tokens are sent to the associated token account of the stake authority.
+ */
+export async function fundSettlementTokenInstruction({
+  program,
+  settlementAccount,
+  stakeAccount,
+  mint,
+  configAccount,
+  bondAccount,
+  voteAccount,
+  operatorAuthority,
+  stakeAuthority = anchorProgramWalletPubkey(program),
+  liquidatorTokenAccount,
+  tokenProgram = TOKEN_PROGRAM_ID,
+}: {
+  program: ValidatorBondsProgram
+  settlementAccount: PublicKey
+  stakeAccount: PublicKey
+  mint: PublicKey
+  configAccount?: PublicKey
+  bondAccount?: PublicKey
+  voteAccount?: PublicKey
+  operatorAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  stakeAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  liquidatorTokenAccount?: PublicKey
+  tokenProgram?: PublicKey
+}): Promise<{
+  instruction: TransactionInstruction
+  liquidatorTokenAccount: PublicKey
+}> {
+  if (
+    voteAccount !== undefined &&
+    configAccount !== undefined &&
+    bondAccount === undefined
+  ) {
+    ;[bondAccount] = bondAddress(configAccount, voteAccount, program.programId)
+  }
+  if (bondAccount === undefined) {
+    const settlementData = await getSettlement(program, settlementAccount)
+    bondAccount = settlementData.bond
+  }
+  if (configAccount === undefined || voteAccount === undefined) {
+    const bondData = await getBond(program, bondAccount)
+    configAccount = bondData.config
+    voteAccount = bondData.voteAccount
+  }
+
+  if (operatorAuthority === undefined) {
+    const configData = await getConfig(program, configAccount)
+    operatorAuthority = configData.operatorAuthority
+  }
+  operatorAuthority =
+    operatorAuthority instanceof PublicKey
+      ? operatorAuthority
+      : operatorAuthority.publicKey
+  stakeAuthority =
+    stakeAuthority instanceof PublicKey
+      ? stakeAuthority
+      : stakeAuthority.publicKey
+  liquidatorTokenAccount =
+    liquidatorTokenAccount ??
+    getAssociatedTokenAddressSync(mint, stakeAuthority, true, tokenProgram)
+
+  const instruction = await program.methods
+    .fundSettlementToken()
+    .accountsPartial({
+      config: configAccount,
+      bond: bondAccount,
+      voteAccount,
+      settlement: settlementAccount,
+      operatorAuthority,
+      stakeAccount,
+      stakeAuthority,
+      settlementStakerAuthority: settlementStakerAuthority(
+        settlementAccount,
+        program.programId,
+      )[0],
+      bondsWithdrawerAuthority: bondsWithdrawerAuthority(
+        configAccount,
+        program.programId,
+      )[0],
+      collateralMint: collateralMintAddress(
+        configAccount,
+        mint,
+        program.programId,
+      )[0],
+      mint,
+      bondTokenVault: bondTokenVaultAddress(
+        bondAccount,
+        mint,
+        program.programId,
+      )[0],
+      liquidatorTokenAccount,
+      tokenProgram,
+      clock: SYSVAR_CLOCK_PUBKEY,
+      stakeProgram: StakeProgram.programId,
+    })
+    .remainingAccounts(await bondLedgerRemainingAccounts(program, bondAccount))
+    .instruction()
+  return {
+    instruction,
+    liquidatorTokenAccount,
+  }
+}
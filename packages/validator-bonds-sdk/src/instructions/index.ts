@@ -1,24 +1,47 @@
 export * from './cancelWithdrawRequest'
 export * from './configureConfig'
+export * from './queueConfigureConfig'
+export * from './executeConfigureConfig'
+export * from './cancelQueuedConfig'
+export * from './proposeConfigAuthority'
+export * from './acceptConfigAuthority'
+export * from './cancelConfigAuthority'
 export * from './configureBond'
+export * from './acceptBondAuthority'
+export * from './cancelBondAuthority'
 export * from './configureBondWithMint'
 export * from './fundBond'
 export * from './initBond'
 export * from './mintBond'
+export * from './closeBond'
+export * from './countBondAccounts'
+export * from './initBondLedger'
+export * from './closeBondLedger'
 export * from './initBondProduct'
 export * from './configureBondProduct'
 export * from './initConfig'
 export * from './initWithdrawRequest'
+export * from './initIndexedWithdrawRequest'
 export * from './mergeStake'
 export * from './resetStake'
 export * from './withdrawStake'
 export * from './initSettlement'
 export * from './upsizeSettlementClaims'
+export * from './initSettlementClaimsChunk'
 export * from './closeSettlementV2'
 export * from './cancelSettlement'
+export * from './extendSettlement'
 export * from './fundSettlement'
 export * from './claimSettlementV2'
+export * from './claimSettlementBatch'
+export * from './claimSettlementRedirect'
 export * from './emergencyPause'
 export * from './emergencyResume'
 export * from './claimWithdrawRequest'
+export * from './initCollateralMint'
+export * from './configureCollateralMint'
+export * from './updateCollateralExchangeRate'
+export * from './fundBondToken'
+export * from './withdrawBondToken'
+export * from './fundSettlementToken'
 export * from './v1'
@@ -0,0 +1,60 @@
+import { PublicKey, SystemProgram } from '@solana/web3.js'
+
+import { getBond } from '../api'
+import { bondLedgerAddress } from '../sdk'
+import { anchorProgramWalletPubkey, checkAndGetBondAddress } from '../utils'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to create the bond ledger account keeping history of the bond operations.
+ * Permission-less operation. Since the bond ledger is created the instructions updating it
+ * require the bond ledger account, the SDK instruction wrappers pass it automatically.
+ */
+export async function initBondLedgerInstruction({
+  program,
+  bondAccount,
+  configAccount,
+  voteAccount,
+  rentPayer = anchorProgramWalletPubkey(program),
+}: {
+  program: ValidatorBondsProgram
+  bondAccount?: PublicKey
+  configAccount?: PublicKey
+  voteAccount?: PublicKey
+  rentPayer?: PublicKey | Keypair | Signer | WalletInterface // signer
+}): Promise<{
+  bondAccount: PublicKey
+  bondLedgerAccount: PublicKey
+  instruction: TransactionInstruction
+}> {
+  bondAccount = checkAndGetBondAddress({
+    bond: bondAccount,
+    config: configAccount,
+    voteAccount,
+    programId: program.programId,
+  })
+  if (configAccount === undefined) {
+    configAccount = (await getBond(program, bondAccount)).config
+  }
+  rentPayer = rentPayer instanceof PublicKey ? rentPayer : rentPayer.publicKey
+  const [bondLedgerAccount] = bondLedgerAddress(bondAccount, program.programId)
+
+  const instruction = await program.methods
+    .initBondLedger()
+    .accountsPartial({
+      config: configAccount,
+      bond: bondAccount,
+      bondLedger: bondLedgerAccount,
+      rentPayer,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction()
+  return {
+    bondAccount,
+    bondLedgerAccount,
+    instruction,
+  }
+}
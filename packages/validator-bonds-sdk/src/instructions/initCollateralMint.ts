@@ -0,0 +1,78 @@
+import { logWarn } from '@marinade.finance/ts-common'
+import { PublicKey, SystemProgram } from '@solana/web3.js'
+import BN from 'bn.js'
+
+import { getConfig } from '../api'
+import { MARINADE_CONFIG_ADDRESS, collateralMintAddress } from '../sdk'
+import { anchorProgramWalletPubkey } from '../utils'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { LoggerPlaceholder } from '@marinade.finance/ts-common'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to permit the token mint to be used as a bond collateral.
+ * Admin only operation.
+ */
+export async function initCollateralMintInstruction({
+  program,
+  configAccount,
+  mint,
+  haircutBps,
+  adminAuthority,
+  rentPayer = anchorProgramWalletPubkey(program),
+  logger,
+}: {
+  program: ValidatorBondsProgram
+  configAccount?: PublicKey
+  mint: PublicKey
+  haircutBps: number | BN
+  adminAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  rentPayer?: PublicKey | Keypair | Signer | WalletInterface // signer
+  logger?: LoggerPlaceholder
+}): Promise<{
+  instruction: TransactionInstruction
+  collateralMintAccount: PublicKey
+}> {
+  if (configAccount === undefined) {
+    logWarn(
+      logger,
+      'initCollateralMint SDK: config is not provided, using default address: ' +
+        MARINADE_CONFIG_ADDRESS.toBase58(),
+    )
+    configAccount = MARINADE_CONFIG_ADDRESS
+  }
+  if (adminAuthority === undefined) {
+    const configData = await getConfig(program, configAccount)
+    adminAuthority = configData.adminAuthority
+  }
+  adminAuthority =
+    adminAuthority instanceof PublicKey
+      ? adminAuthority
+      : adminAuthority.publicKey
+  rentPayer = rentPayer instanceof PublicKey ? rentPayer : rentPayer.publicKey
+
+  const [collateralMintAccount] = collateralMintAddress(
+    configAccount,
+    mint,
+    program.programId,
+  )
+  const instruction = await program.methods
+    .initCollateralMint({
+      haircutBps: new BN(haircutBps),
+    })
+    .accountsPartial({
+      config: configAccount,
+      adminAuthority,
+      mint,
+      collateralMint: collateralMintAccount,
+      rentPayer,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction()
+  return {
+    instruction,
+    collateralMintAccount,
+  }
+}
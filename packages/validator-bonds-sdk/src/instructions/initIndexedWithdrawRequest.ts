@@ -0,0 +1,92 @@
+import { logWarn } from '@marinade.finance/ts-common'
+import { PublicKey, SystemProgram } from '@solana/web3.js'
+import BN from 'bn.js'
+
+import { getBond } from '../api'
+import { MARINADE_CONFIG_ADDRESS, indexedWithdrawRequestAddress } from '../sdk'
+import { anchorProgramWalletPubkey, checkAndGetBondAddress } from '../utils'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { LoggerPlaceholder } from '@marinade.finance/ts-common'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to create an additional (indexed) withdraw request for bond account.
+ * Only bond authority or validator identity of vote account voter pubkey can create this request.
+ * The withdraw request is created at the next index tracked by the bond
+ * and its amount can be withdrawn when its own lockup time elapses (configured in config).
+ */
+export async function initIndexedWithdrawRequestInstruction({
+  program,
+  bondAccount,
+  configAccount,
+  voteAccount,
+  authority = anchorProgramWalletPubkey(program),
+  rentPayer = anchorProgramWalletPubkey(program),
+  amount,
+  logger,
+}: {
+  program: ValidatorBondsProgram
+  bondAccount?: PublicKey
+  configAccount?: PublicKey
+  voteAccount?: PublicKey
+  authority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  rentPayer?: PublicKey | Keypair | Signer | WalletInterface // signer
+  amount: BN | number
+  logger?: LoggerPlaceholder
+}): Promise<{
+  instruction: TransactionInstruction
+  bondAccount: PublicKey
+  withdrawRequestAccount: PublicKey
+  withdrawRequestIndex: BN
+}> {
+  if (!bondAccount && !configAccount && voteAccount) {
+    logWarn(
+      logger,
+      'initIndexedWithdrawRequest SDK: config is not provided, using default address: ' +
+        MARINADE_CONFIG_ADDRESS.toBase58(),
+    )
+    configAccount = MARINADE_CONFIG_ADDRESS
+  }
+  bondAccount = checkAndGetBondAddress({
+    bond: bondAccount,
+    config: configAccount,
+    voteAccount,
+    programId: program.programId,
+  })
+  // the bond data is required to know the index of the next withdraw request
+  const bondData = await getBond(program, bondAccount)
+  voteAccount = voteAccount ?? bondData.voteAccount
+  configAccount = configAccount ?? bondData.config
+
+  authority = authority instanceof PublicKey ? authority : authority.publicKey
+  rentPayer = rentPayer instanceof PublicKey ? rentPayer : rentPayer.publicKey
+  const withdrawRequestIndex = bondData.withdrawRequestIndex.addn(1)
+  const [withdrawRequest] = indexedWithdrawRequestAddress(
+    bondAccount,
+    withdrawRequestIndex,
+    program.programId,
+  )
+
+  const instruction = await program.methods
+    .initIndexedWithdrawRequest({
+      amount: new BN(amount),
+    })
+    .accountsPartial({
+      config: configAccount,
+      bond: bondAccount,
+      voteAccount,
+      withdrawRequest,
+      authority,
+      rentPayer,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction()
+  return {
+    bondAccount,
+    withdrawRequestAccount: withdrawRequest,
+    withdrawRequestIndex,
+    instruction,
+  }
+}
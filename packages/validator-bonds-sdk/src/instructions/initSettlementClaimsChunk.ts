@@ -0,0 +1,53 @@
+import { PublicKey, SystemProgram } from '@solana/web3.js'
+
+import { settlementClaimsAddress, settlementClaimsChunkAddress } from '../sdk'
+import { anchorProgramWalletPubkey } from '../utils'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to create a SettlementClaims chunk account storing a part of the claims bitmap.
+ * The chunks of a big settlement may be created in parallel instead of serial upsizing
+ * of the SettlementClaims account. The chunk index 0 is the settlement claims account itself.
+ */
+export async function initSettlementClaimsChunkInstruction({
+  program,
+  settlementAccount,
+  chunkIndex,
+  rentPayer = anchorProgramWalletPubkey(program),
+}: {
+  program: ValidatorBondsProgram
+  settlementAccount: PublicKey
+  chunkIndex: number
+  rentPayer?: PublicKey | Keypair | Signer | WalletInterface // signer
+}): Promise<{
+  instruction: TransactionInstruction
+  settlementClaimsChunkAccount: PublicKey
+}> {
+  const rentPayerPubkey =
+    rentPayer instanceof PublicKey ? rentPayer : rentPayer.publicKey
+  const [settlementClaimsChunkAccount] = settlementClaimsChunkAddress(
+    settlementAccount,
+    chunkIndex,
+    program.programId,
+  )
+
+  const instruction = await program.methods
+    .initSettlementClaimsChunk({ chunkIndex })
+    .accountsPartial({
+      settlementClaims: settlementClaimsAddress(
+        settlementAccount,
+        program.programId,
+      )[0],
+      settlementClaimsChunk: settlementClaimsChunkAccount,
+      rentPayer: rentPayerPubkey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction()
+  return {
+    instruction,
+    settlementClaimsChunkAccount,
+  }
+}
@@ -0,0 +1,85 @@
+import { logWarn } from '@marinade.finance/ts-common'
+import { PublicKey } from '@solana/web3.js'
+
+import { getConfig } from '../api'
+import { MARINADE_CONFIG_ADDRESS } from '../sdk'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { LoggerPlaceholder } from '@marinade.finance/ts-common'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to propose new config authorities. Available for admin authority.
+ * The proposed authority takes the role only after signing the accept config authority instruction.
+ *
+ * @type {Object} args - Arguments on instruction creation
+ * @param param {ValidatorBondsProgram} args.program - anchor program instance
+ * @param param {PublicKey} args.configAccount - config account to configure (default: MARINADE config address)
+ * @param param {PublicKey} args.adminAuthority [SIGNER] - admin authority (default: admin authority from config)
+ * @param param {PublicKey} args.newAdmin - admin authority proposed when field is used
+ * @param param {PublicKey} args.newOperator - operator authority proposed when field is used
+ * @param param {PublicKey} args.newPauseAuthority - pause authority proposed when field is used
+ * @type {Object} return - Return data of generated instruction
+ * @return {TransactionInstruction} return.instruction - Instruction to propose config authorities
+ */
+export async function proposeConfigAuthorityInstruction({
+  program,
+  configAccount,
+  adminAuthority,
+  newAdmin,
+  newOperator,
+  newPauseAuthority,
+  logger,
+}: {
+  program: ValidatorBondsProgram
+  configAccount?: PublicKey
+  adminAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  newAdmin?: PublicKey
+  newOperator?: PublicKey
+  newPauseAuthority?: PublicKey
+  logger?: LoggerPlaceholder
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  if (
+    newAdmin === undefined &&
+    newOperator === undefined &&
+    newPauseAuthority === undefined
+  ) {
+    throw new Error(
+      'proposeConfigAuthorityInstruction: method parameters provided no authority to propose',
+    )
+  }
+  if (configAccount === undefined) {
+    logWarn(
+      logger,
+      'proposeConfigAuthority SDK: config is not provided, using default config address: ' +
+        MARINADE_CONFIG_ADDRESS.toBase58(),
+    )
+    configAccount = MARINADE_CONFIG_ADDRESS
+  }
+  if (adminAuthority === undefined) {
+    const configData = await getConfig(program, configAccount)
+    adminAuthority = configData.adminAuthority
+  }
+  adminAuthority =
+    adminAuthority instanceof PublicKey
+      ? adminAuthority
+      : adminAuthority.publicKey
+
+  const instruction = await program.methods
+    .proposeConfigAuthority({
+      admin: newAdmin ?? null,
+      operator: newOperator ?? null,
+      pauseAuthority: newPauseAuthority ?? null,
+    })
+    .accountsPartial({
+      config: configAccount,
+      adminAuthority,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
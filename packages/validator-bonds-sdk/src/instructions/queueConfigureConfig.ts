@@ -0,0 +1,112 @@
+import { logWarn } from '@marinade.finance/ts-common'
+import { PublicKey } from '@solana/web3.js'
+import BN from 'bn.js'
+
+import { getConfig } from '../api'
+import { MARINADE_CONFIG_ADDRESS } from '../sdk'
+
+import type { QueueConfigureConfigArgs, ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { LoggerPlaceholder } from '@marinade.finance/ts-common'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to queue change of the lockup-sensitive config params. Available for admin authority.
+ * The change is applied with the execute configure config instruction once the activation epoch is reached.
+ * The activation epoch has to be at least withdraw lockup epochs + 1 ahead of the current epoch.
+ * Queueing a new change replaces the previously queued one.
+ *
+ * @type {Object} args - Arguments on instruction creation
+ * @param param {ValidatorBondsProgram} args.program - anchor program instance
+ * @param param {PublicKey} args.configAccount - config account to configure (default: MARINADE config address)
+ * @param param {PublicKey} args.adminAuthority [SIGNER] - admin authority (default: admin authority from config)
+ * @param param {BN | number} args.activationEpoch - epoch when the change may be executed
+ * @param param {BN | number} args.newEpochsToClaimSettlement - number of epochs before settlement claiming timeouts that will be set when field is used
+ * @param param {BN | number} args.newSlotsToStartSettlementClaiming - number of slots that has to expire until when settlement can be claimed
+ * @param param {BN | number} args.newWithdrawLockupEpochs - number of epochs after which withdraw can be executed that will be set when field is used
+ * @param param {BN | number} args.newMinimumStakeLamports - number of lamports as minimum stake account size that will be set when field is used
+ * @type {Object} return - Return data of generated instruction
+ * @return {TransactionInstruction} return.instruction - Instruction to queue the config change
+ */
+export async function queueConfigureConfigInstruction({
+  program,
+  configAccount,
+  adminAuthority,
+  activationEpoch,
+  newEpochsToClaimSettlement,
+  newSlotsToStartSettlementClaiming,
+  newWithdrawLockupEpochs,
+  newMinimumStakeLamports,
+  logger,
+}: {
+  program: ValidatorBondsProgram
+  configAccount?: PublicKey
+  adminAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  activationEpoch: BN | number | bigint
+  newEpochsToClaimSettlement?: BN | number
+  newSlotsToStartSettlementClaiming?: BN | number
+  newWithdrawLockupEpochs?: BN | number
+  newMinimumStakeLamports?: BN | number
+  logger?: LoggerPlaceholder
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  if (configAccount === undefined) {
+    logWarn(
+      logger,
+      'queueConfigureConfig SDK: config is not provided, using default config address: ' +
+        MARINADE_CONFIG_ADDRESS.toBase58(),
+    )
+    configAccount = MARINADE_CONFIG_ADDRESS
+  }
+  if (adminAuthority === undefined) {
+    const configData = await getConfig(program, configAccount)
+    adminAuthority = configData.adminAuthority
+  }
+  adminAuthority =
+    adminAuthority instanceof PublicKey
+      ? adminAuthority
+      : adminAuthority.publicKey
+
+  const args: QueueConfigureConfigArgs = {
+    epochsToClaimSettlement:
+      newEpochsToClaimSettlement !== undefined
+        ? new BN(newEpochsToClaimSettlement)
+        : null,
+    slotsToStartSettlementClaiming:
+      newSlotsToStartSettlementClaiming !== undefined
+        ? new BN(newSlotsToStartSettlementClaiming)
+        : null,
+    withdrawLockupEpochs:
+      newWithdrawLockupEpochs !== undefined
+        ? new BN(newWithdrawLockupEpochs)
+        : null,
+    minimumStakeLamports:
+      newMinimumStakeLamports !== undefined
+        ? new BN(newMinimumStakeLamports)
+        : null,
+    activationEpoch: new BN(activationEpoch.toString()),
+  }
+
+  if (
+    args.epochsToClaimSettlement === null &&
+    args.slotsToStartSettlementClaiming === null &&
+    args.withdrawLockupEpochs === null &&
+    args.minimumStakeLamports === null
+  ) {
+    throw new Error(
+      'queueConfigureConfigInstruction: method parameters provided no new property to configure',
+    )
+  }
+
+  const instruction = await program.methods
+    .queueConfigureConfig(args)
+    .accountsPartial({
+      adminAuthority,
+      config: configAccount,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
@@ -0,0 +1,60 @@
+import { PublicKey } from '@solana/web3.js'
+import BN from 'bn.js'
+
+import { getCollateralMint, getConfig } from '../api'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to update the exchange rate of the collateral token to SOL
+ * for the current epoch. The rate is the lamports value of one token
+ * (i.e., of EXCHANGE_RATE_SCALE token base units).
+ * Only operator authority is permitted to do this.
+ */
+export async function updateCollateralExchangeRateInstruction({
+  program,
+  collateralMintAccount,
+  exchangeRate,
+  configAccount,
+  operatorAuthority,
+}: {
+  program: ValidatorBondsProgram
+  collateralMintAccount: PublicKey
+  exchangeRate: number | BN
+  configAccount?: PublicKey
+  operatorAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  if (configAccount === undefined) {
+    const collateralMintData = await getCollateralMint(
+      program,
+      collateralMintAccount,
+    )
+    configAccount = collateralMintData.config
+  }
+  if (operatorAuthority === undefined) {
+    const configData = await getConfig(program, configAccount)
+    operatorAuthority = configData.operatorAuthority
+  }
+  operatorAuthority =
+    operatorAuthority instanceof PublicKey
+      ? operatorAuthority
+      : operatorAuthority.publicKey
+
+  const instruction = await program.methods
+    .updateCollateralExchangeRate({
+      exchangeRate: new BN(exchangeRate),
+    })
+    .accountsPartial({
+      config: configAccount,
+      operatorAuthority,
+      collateralMint: collateralMintAccount,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
@@ -0,0 +1,148 @@
+import { logWarn } from '@marinade.finance/ts-common'
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { PublicKey, SYSVAR_CLOCK_PUBKEY } from '@solana/web3.js'
+import BN from 'bn.js'
+import { getAssociatedTokenAddressSync } from 'solana-spl-token-modern'
+
+import { getBond } from '../api'
+import {
+  MARINADE_CONFIG_ADDRESS,
+  bondAddress,
+  bondTokenVaultAddress,
+  bondsWithdrawerAuthority,
+  collateralMintAddress,
+  indexedWithdrawRequestAddress,
+} from '../sdk'
+import { anchorProgramWalletPubkey, checkAndGetBondAddress } from '../utils'
+
+import type { ValidatorBondsProgram } from '../sdk'
+import type { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import type { LoggerPlaceholder } from '@marinade.finance/ts-common'
+import type { Keypair, Signer, TransactionInstruction } from '@solana/web3.js'
+
+/**
+ * Generate instruction to withdraw tokens from the bond token vault.
+ * Only bond authority or validator identity of vote account voter pubkey can do this.
+ * Withdrawing requires a withdraw request with elapsed lockup, the lamports value
+ * of the withdrawn tokens (by the exchange rate of the current epoch) is accounted
+ * as withdrawn within the withdraw request.
+ *
+ * By default the tokens are withdrawn to the associated token account of the authority.
+ */
+export async function withdrawBondTokenInstruction({
+  program,
+  bondAccount,
+  configAccount,
+  voteAccount,
+  withdrawRequestAccount,
+  withdrawRequestIndex = 0,
+  mint,
+  amount,
+  authority = anchorProgramWalletPubkey(program),
+  withdrawerTokenAccount,
+  tokenProgram = TOKEN_PROGRAM_ID,
+  logger,
+}: {
+  program: ValidatorBondsProgram
+  bondAccount?: PublicKey
+  configAccount?: PublicKey
+  voteAccount?: PublicKey
+  withdrawRequestAccount?: PublicKey
+  withdrawRequestIndex?: number | BN // used to derive the withdraw request from the bond, 0 is the legacy one
+  mint: PublicKey
+  amount: number | BN
+  authority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  withdrawerTokenAccount?: PublicKey
+  tokenProgram?: PublicKey
+  logger?: LoggerPlaceholder
+}): Promise<{
+  instruction: TransactionInstruction
+  bondAccount: PublicKey
+  withdrawRequestAccount: PublicKey
+  withdrawerTokenAccount: PublicKey
+}> {
+  if (
+    configAccount !== undefined &&
+    voteAccount !== undefined &&
+    bondAccount === undefined
+  ) {
+    bondAccount = bondAddress(configAccount, voteAccount, program.programId)[0]
+  }
+  if (
+    bondAccount !== undefined &&
+    (voteAccount === undefined || configAccount === undefined)
+  ) {
+    const bondData = await getBond(program, bondAccount)
+    voteAccount = voteAccount ?? bondData.voteAccount
+    configAccount = configAccount ?? bondData.config
+  }
+  if (voteAccount === undefined) {
+    throw new Error(
+      'withdrawBondToken: ' +
+        'voteAccount not provided and could not be derived from other parameters',
+    )
+  }
+  if (!configAccount) {
+    logWarn(
+      logger,
+      'withdrawBondToken SDK: config is not provided, using default config address: ' +
+        MARINADE_CONFIG_ADDRESS.toBase58(),
+    )
+    configAccount = MARINADE_CONFIG_ADDRESS
+  }
+  bondAccount = checkAndGetBondAddress({
+    bond: bondAccount,
+    config: configAccount,
+    voteAccount,
+    programId: program.programId,
+  })
+  withdrawRequestAccount =
+    withdrawRequestAccount ??
+    indexedWithdrawRequestAddress(
+      bondAccount,
+      withdrawRequestIndex,
+      program.programId,
+    )[0]
+
+  authority = authority instanceof PublicKey ? authority : authority.publicKey
+  withdrawerTokenAccount =
+    withdrawerTokenAccount ??
+    getAssociatedTokenAddressSync(mint, authority, true, tokenProgram)
+
+  const instruction = await program.methods
+    .withdrawBondToken({
+      amount: new BN(amount),
+    })
+    .accountsPartial({
+      config: configAccount,
+      bond: bondAccount,
+      voteAccount,
+      authority,
+      withdrawRequest: withdrawRequestAccount,
+      collateralMint: collateralMintAddress(
+        configAccount,
+        mint,
+        program.programId,
+      )[0],
+      mint,
+      bondsWithdrawerAuthority: bondsWithdrawerAuthority(
+        configAccount,
+        program.programId,
+      )[0],
+      bondTokenVault: bondTokenVaultAddress(
+        bondAccount,
+        mint,
+        program.programId,
+      )[0],
+      withdrawerTokenAccount,
+      tokenProgram,
+      clock: SYSVAR_CLOCK_PUBKEY,
+    })
+    .instruction()
+  return {
+    instruction,
+    bondAccount,
+    withdrawRequestAccount,
+    withdrawerTokenAccount,
+  }
+}
@@ -188,3 +188,53 @@ export function toWordArray(bytes: number[]) {
 export function pubkeyToWordArray(pubkey: PublicKey) {
   return toWordArray(Array.from(pubkey.toBuffer()))
 }
+
+/**
+ * Multi-proof of several tree nodes composed from the single node proofs
+ * (sibling hashes from the leaf up to the root) of the same merkle tree.
+ * The siblings shared by the node paths are included only once,
+ * see merkle-tree `multi_proof_from_proofs`.
+ */
+export function multiProofFromProofs(
+  leaves: {
+    index: number | BN
+    proof: (number[] | Uint8Array | Buffer)[]
+  }[],
+): number[][] {
+  const first = leaves[0]
+  if (first === undefined) {
+    throw new Error('At least one proof is required for the multi-proof')
+  }
+  const depth = first.proof.length
+  if (leaves.some(({ proof }) => proof.length !== depth)) {
+    throw new Error('Proofs of the multi-proof have to be of the same depth')
+  }
+  const indexed = leaves.map(({ index, proof }) => ({
+    index: new BN(index).toNumber(),
+    proof,
+  }))
+  let known = Array.from(new Set(indexed.map(({ index }) => index))).sort(
+    (a, b) => a - b,
+  )
+  const multiProof: number[][] = []
+  for (let level = 0; level < depth; level++) {
+    const nextKnown: number[] = []
+    let i = 0
+    while (i < known.length) {
+      const index = known[i] as number
+      if (index % 2 === 0 && known[i + 1] === index + 1) {
+        i += 2
+      } else {
+        const sibling = indexed.find(
+          leaf => Math.floor(leaf.index / 2 ** level) === index,
+        )?.proof[level]
+        assert(sibling !== undefined, `No proof sibling for index ${index}`)
+        multiProof.push(Array.from(sibling))
+        i += 1
+      }
+      nextKnown.push(Math.floor(index / 2))
+    }
+    known = nextKnown
+  }
+  return multiProof
+}
@@ -9,7 +9,7 @@ import { mergeStakeInstruction } from '../instructions/mergeStake'
 import {
   bondAddress,
   bondsWithdrawerAuthority,
-  withdrawRequestAddress,
+  indexedWithdrawRequestAddress,
 } from '../sdk'
 import { anchorProgramWalletPubkey } from '../utils'
 import { findStakeAccounts } from '../web3.js'
@@ -33,6 +33,7 @@ import type {
 export async function orchestrateWithdrawDeposit({
   program,
   withdrawRequestAccount,
+  withdrawRequestIndex = 0,
   bondAccount,
   configAccount,
   voteAccount,
@@ -43,6 +44,7 @@ export async function orchestrateWithdrawDeposit({
 }: {
   program: ValidatorBondsProgram
   withdrawRequestAccount?: PublicKey
+  withdrawRequestIndex?: number | BN // used to derive the withdraw request from the bond, 0 is the legacy one
   bondAccount?: PublicKey
   configAccount?: PublicKey
   voteAccount?: PublicKey
@@ -85,8 +87,9 @@ export async function orchestrateWithdrawDeposit({
     bondAccount !== undefined &&
     withdrawRequestAccount === undefined
   ) {
-    withdrawRequestAccount = withdrawRequestAddress(
+    withdrawRequestAccount = indexedWithdrawRequestAddress(
       bondAccount,
+      withdrawRequestIndex,
       program.programId,
     )[0]
   }
@@ -40,9 +40,11 @@ export type ValidatorBondsProgram = Program<ValidatorBonds>
 export type Config = IdlAccounts<ValidatorBonds>['config']
 export type Bond = IdlAccounts<ValidatorBonds>['bond']
 export type BondProduct = IdlAccounts<ValidatorBonds>['bondProduct']
+export type BondLedger = IdlAccounts<ValidatorBonds>['bondLedger']
 export type SettlementClaims = IdlAccounts<ValidatorBonds>['settlementClaims']
 export type Settlement = IdlAccounts<ValidatorBonds>['settlement']
 export type WithdrawRequest = IdlAccounts<ValidatorBonds>['withdrawRequest']
+export type CollateralMint = IdlAccounts<ValidatorBonds>['collateralMint']
 
 // --- TYPES ---
 export type InitBondArgs = IdlTypes<ValidatorBonds>['initBondArgs']
@@ -51,6 +53,10 @@ export type InitBondProductArgs =
 export type InitConfigArgs = IdlTypes<ValidatorBonds>['initConfigArgs']
 export type ConfigureConfigArgs =
   IdlTypes<ValidatorBonds>['configureConfigArgs']
+export type QueueConfigureConfigArgs =
+  IdlTypes<ValidatorBonds>['queueConfigureConfigArgs']
+export type EmergencyPauseResumeArgs =
+  IdlTypes<ValidatorBonds>['emergencyPauseResumeArgs']
 export type ConfigureBondArgs = IdlTypes<ValidatorBonds>['configureBondArgs']
 export type ConfigureBondProductArgs =
   IdlTypes<ValidatorBonds>['configureBondProductArgs']
@@ -83,6 +89,8 @@ export const SETTLEMENT_CLAIMS_ACCOUNT_DISCRIMINATOR =
 export const SETTLEMENT_ACCOUNT_DISCRIMINATOR = discriminator('Settlement')
 export const WITHDRAW_REQUEST_ACCOUNT_DISCRIMINATOR =
   discriminator('WithdrawRequest')
+export const COLLATERAL_MINT_ACCOUNT_DISCRIMINATOR =
+  discriminator('CollateralMint')
 
 // --- CONSTANTS ---
 function fromConstants(constantName: string): string {
@@ -104,6 +112,7 @@ export function seedFromConstants(seedName: string): Uint8Array {
 export const BOND_SEED = seedFromConstants('BOND_SEED')
 export const BOND_PRODUCT_SEED = seedFromConstants('BOND_PRODUCT_SEED')
 export const BOND_MINT_SEED = seedFromConstants('BOND_MINT_SEED')
+export const BOND_LEDGER_SEED = seedFromConstants('BOND_LEDGER_SEED')
 export const SETTLEMENT_SEED = seedFromConstants('SETTLEMENT_SEED')
 export const WITHDRAW_REQUEST_SEED = seedFromConstants('WITHDRAW_REQUEST_SEED')
 export const SETTLEMENT_CLAIMS_SEED = seedFromConstants(
@@ -118,8 +127,31 @@ export const SETTLEMENT_STAKER_AUTHORITY_SEED = seedFromConstants(
 export const SETTLEMENT_CLAIMS_ANCHOR_HEADER_SIZE = Number(
   fromConstants('SETTLEMENT_CLAIMS_ANCHOR_HEADER_SIZE'),
 )
+export const SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS = Number(
+  fromConstants('SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS'),
+)
+export const COLLATERAL_MINT_SEED = seedFromConstants('COLLATERAL_MINT_SEED')
+export const BOND_TOKEN_VAULT_SEED = seedFromConstants('BOND_TOKEN_VAULT_SEED')
 export const EVENT_AUTHORITY_SEED_STRING = '__event_authority'
 
+// operation groups to be paused, the config paused field is a bitmask of them
+export const PAUSE_LEGACY_ALL = Number(fromConstants('PAUSE_LEGACY_ALL'))
+export const PAUSE_FUNDING = Number(fromConstants('PAUSE_FUNDING'))
+export const PAUSE_WITHDRAW = Number(fromConstants('PAUSE_WITHDRAW'))
+export const PAUSE_SETTLEMENT = Number(fromConstants('PAUSE_SETTLEMENT'))
+export const PAUSE_CLAIM = Number(fromConstants('PAUSE_CLAIM'))
+export const PAUSE_STAKE_MANAGEMENT = Number(
+  fromConstants('PAUSE_STAKE_MANAGEMENT'),
+)
+export const PAUSE_ALL = Number(fromConstants('PAUSE_ALL'))
+
+/**
+ * Bitmask of the paused operation groups, the legacy paused value stands for all of them.
+ */
+export function pausedOperations(paused: number): number {
+  return (paused & PAUSE_LEGACY_ALL) !== 0 ? PAUSE_ALL : paused
+}
+
 // --- EVENTS ---
 export const INIT_CONFIG_EVENT = 'initConfigEvent'
 export type InitConfigEvent =
@@ -129,6 +161,22 @@ export const CONFIGURE_CONFIG_EVENT = 'configureConfigEvent'
 export type ConfigureConfigEvent =
   IdlEvents<ValidatorBonds>[typeof CONFIGURE_CONFIG_EVENT]
 
+export const PROPOSE_CONFIG_AUTHORITY_EVENT = 'proposeConfigAuthorityEvent'
+export type ProposeConfigAuthorityEvent =
+  IdlEvents<ValidatorBonds>[typeof PROPOSE_CONFIG_AUTHORITY_EVENT]
+
+export const CANCEL_CONFIG_AUTHORITY_EVENT = 'cancelConfigAuthorityEvent'
+export type CancelConfigAuthorityEvent =
+  IdlEvents<ValidatorBonds>[typeof CANCEL_CONFIG_AUTHORITY_EVENT]
+
+export const QUEUE_CONFIGURE_CONFIG_EVENT = 'queueConfigureConfigEvent'
+export type QueueConfigureConfigEvent =
+  IdlEvents<ValidatorBonds>[typeof QUEUE_CONFIGURE_CONFIG_EVENT]
+
+export const CANCEL_QUEUED_CONFIG_EVENT = 'cancelQueuedConfigEvent'
+export type CancelQueuedConfigEvent =
+  IdlEvents<ValidatorBonds>[typeof CANCEL_QUEUED_CONFIG_EVENT]
+
 export const INIT_BOND_EVENT = 'initBondEvent'
 export type InitBondEvent = IdlEvents<ValidatorBonds>[typeof INIT_BOND_EVENT]
 
@@ -136,6 +184,10 @@ export const CONFIGURE_BOND_EVENT = 'configureBondEvent'
 export type ConfigureBondEvent =
   IdlEvents<ValidatorBonds>[typeof CONFIGURE_BOND_EVENT]
 
+export const CANCEL_BOND_AUTHORITY_EVENT = 'cancelBondAuthorityEvent'
+export type CancelBondAuthorityEvent =
+  IdlEvents<ValidatorBonds>[typeof CANCEL_BOND_AUTHORITY_EVENT]
+
 export const CONFIGURE_BOND_WITH_MINT_EVENT = 'configureBondWithMintEvent'
 export type ConfigureBondWithMintEvent =
   IdlEvents<ValidatorBonds>[typeof CONFIGURE_BOND_WITH_MINT_EVENT]
@@ -148,6 +200,21 @@ export const CONFIGURE_BOND_PRODUCT_EVENT = 'configureBondProductEvent'
 export type ConfigureBondProductEvent =
   IdlEvents<ValidatorBonds>[typeof CONFIGURE_BOND_PRODUCT_EVENT]
 
+export const CLOSE_BOND_EVENT = 'closeBondEvent'
+export type CloseBondEvent = IdlEvents<ValidatorBonds>[typeof CLOSE_BOND_EVENT]
+
+export const COUNT_BOND_ACCOUNTS_EVENT = 'countBondAccountsEvent'
+export type CountBondAccountsEvent =
+  IdlEvents<ValidatorBonds>[typeof COUNT_BOND_ACCOUNTS_EVENT]
+
+export const INIT_BOND_LEDGER_EVENT = 'initBondLedgerEvent'
+export type InitBondLedgerEvent =
+  IdlEvents<ValidatorBonds>[typeof INIT_BOND_LEDGER_EVENT]
+
+export const CLOSE_BOND_LEDGER_EVENT = 'closeBondLedgerEvent'
+export type CloseBondLedgerEvent =
+  IdlEvents<ValidatorBonds>[typeof CLOSE_BOND_LEDGER_EVENT]
+
 export const MINT_BOND_EVENT = 'mintBondEvent'
 export type MintBondEvent = IdlEvents<ValidatorBonds>[typeof MINT_BOND_EVENT]
 
@@ -162,6 +229,18 @@ export const CLAIM_SETTLEMENT_V2_EVENT = 'claimSettlementV2Event'
 export type ClaimSettlementV2Event =
   IdlEvents<ValidatorBonds>[typeof CLAIM_SETTLEMENT_V2_EVENT]
 
+export const CLAIM_SETTLEMENT_BATCH_EVENT = 'claimSettlementBatchEvent'
+export type ClaimSettlementBatchEvent =
+  IdlEvents<ValidatorBonds>[typeof CLAIM_SETTLEMENT_BATCH_EVENT]
+
+export const CLAIM_SETTLEMENT_REDIRECT_EVENT = 'claimSettlementRedirectEvent'
+export type ClaimSettlementRedirectEvent =
+  IdlEvents<ValidatorBonds>[typeof CLAIM_SETTLEMENT_REDIRECT_EVENT]
+
+export const EXTEND_SETTLEMENT_EVENT = 'extendSettlementEvent'
+export type ExtendSettlementEvent =
+  IdlEvents<ValidatorBonds>[typeof EXTEND_SETTLEMENT_EVENT]
+
 export const INIT_SETTLEMENT_EVENT = 'initSettlementEvent'
 export type InitSettlementEvent =
   IdlEvents<ValidatorBonds>[typeof INIT_SETTLEMENT_EVENT]
@@ -263,6 +342,16 @@ export function bondAddress(
   )
 }
 
+export function bondLedgerAddress(
+  bond: PublicKey,
+  validatorBondsProgramId: PublicKey = VALIDATOR_BONDS_PROGRAM_ID,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [BOND_LEDGER_SEED, bond.toBytes()],
+    validatorBondsProgramId,
+  )
+}
+
 export function bondProductAddress(
   bond: PublicKey,
   productType: ProductType,
@@ -332,6 +421,33 @@ export function settlementClaimsAddress(
   )
 }
 
+/**
+ * Address of a SettlementClaims chunk account,
+ * the chunk 0 is the settlement claims account itself.
+ */
+export function settlementClaimsChunkAddress(
+  settlement: PublicKey,
+  chunkIndex: number,
+  validatorBondsProgramId: PublicKey = VALIDATOR_BONDS_PROGRAM_ID,
+): [PublicKey, number] {
+  if (chunkIndex === 0) {
+    return settlementClaimsAddress(settlement, validatorBondsProgramId)
+  }
+  const chunkIndexBuffer = Buffer.alloc(2)
+  chunkIndexBuffer.writeUint16LE(chunkIndex)
+  return PublicKey.findProgramAddressSync(
+    [SETTLEMENT_CLAIMS_SEED, settlement.toBytes(), chunkIndexBuffer],
+    validatorBondsProgramId,
+  )
+}
+
+/**
+ * Index of the SettlementClaims chunk account storing the claim record of the merkle tree node index.
+ */
+export function settlementClaimsChunkIndex(index: number | BN): number {
+  return new BN(index).divn(SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS).toNumber()
+}
+
 export function withdrawRequestAddress(
   bond: PublicKey,
   validatorBondsProgramId: PublicKey = VALIDATOR_BONDS_PROGRAM_ID,
@@ -342,6 +458,46 @@ export function withdrawRequestAddress(
   )
 }
 
+/**
+ * Seed of the withdraw request index, index 0 is the legacy (non-indexed) withdraw request
+ * that is derived without any index seed.
+ */
+export function withdrawRequestIndexSeed(index: number | BN | bigint): Buffer {
+  return BigInt(index.toString()) === BigInt(0)
+    ? Buffer.alloc(0)
+    : uintToBuffer(index)
+}
+
+export function indexedWithdrawRequestAddress(
+  bond: PublicKey,
+  index: number | BN | bigint,
+  validatorBondsProgramId: PublicKey = VALIDATOR_BONDS_PROGRAM_ID,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [WITHDRAW_REQUEST_SEED, bond.toBytes(), withdrawRequestIndexSeed(index)],
+    validatorBondsProgramId,
+  )
+}
+
+/**
+ * All withdraw request addresses that may exist for the bond,
+ * i.e., the legacy one (index 0) and the indexed ones up to the bond's `withdrawRequestIndex`.
+ */
+export function withdrawRequestAddresses(
+  bond: PublicKey,
+  withdrawRequestIndex: number | BN | bigint,
+  validatorBondsProgramId: PublicKey = VALIDATOR_BONDS_PROGRAM_ID,
+): PublicKey[] {
+  const lastIndex = Number(withdrawRequestIndex.toString())
+  const addresses: PublicKey[] = []
+  for (let index = 0; index <= lastIndex; index++) {
+    addresses.push(
+      indexedWithdrawRequestAddress(bond, index, validatorBondsProgramId)[0],
+    )
+  }
+  return addresses
+}
+
 export function bondMintAddress(
   bond: PublicKey,
   validatorIdentity: PublicKey,
@@ -353,6 +509,28 @@ export function bondMintAddress(
   )
 }
 
+export function collateralMintAddress(
+  config: PublicKey,
+  mint: PublicKey,
+  validatorBondsProgramId: PublicKey = VALIDATOR_BONDS_PROGRAM_ID,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [COLLATERAL_MINT_SEED, config.toBytes(), mint.toBytes()],
+    validatorBondsProgramId,
+  )
+}
+
+export function bondTokenVaultAddress(
+  bond: PublicKey,
+  mint: PublicKey,
+  validatorBondsProgramId: PublicKey = VALIDATOR_BONDS_PROGRAM_ID,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [BOND_TOKEN_VAULT_SEED, bond.toBytes(), mint.toBytes()],
+    validatorBondsProgramId,
+  )
+}
+
 export function eventAuthorityAddress(
   validatorBondsProgramId: PublicKey = VALIDATOR_BONDS_PROGRAM_ID,
 ): [PublicKey, number] {
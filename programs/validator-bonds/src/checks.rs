@@ -261,7 +261,15 @@ mod tests {
             cpmpe: 0,
             bump: 0,
             max_stake_wanted: 0,
-            reserved: [0; 134],
+            withdraw_request_index: 0,
+            pending_authority: Pubkey::default(),
+            open_settlements: 0,
+            open_withdraw_requests: 0,
+            open_bond_products: 0,
+            funded_token_vaults: 0,
+            bond_ledger_bump: None,
+            accounts_counted: true,
+            reserved: [0; 59],
         }
     }
 
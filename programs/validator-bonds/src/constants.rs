@@ -15,9 +15,15 @@ pub const BOND_MINT_SEED: &[u8] = b"bond_mint";
 #[constant]
 pub const BOND_PRODUCT_SEED: &[u8] = b"bond_product";
 
+#[constant]
+pub const BOND_LEDGER_SEED: &[u8] = b"bond_ledger";
+
 #[constant]
 pub const SETTLEMENT_SEED: &[u8] = b"settlement_account";
 
+#[constant]
+pub const REPLACEABLE_SETTLEMENT_SEED: &[u8] = b"replaceable_settlement";
+
 #[constant]
 pub const WITHDRAW_REQUEST_SEED: &[u8] = b"withdraw_account";
 
@@ -30,9 +36,54 @@ pub const SETTLEMENT_STAKER_AUTHORITY_SEED: &[u8] = b"settlement_authority";
 #[constant]
 pub const SETTLEMENT_CLAIMS_SEED: &[u8] = b"claims_account";
 
+#[constant]
+pub const COLLATERAL_MINT_SEED: &[u8] = b"collateral_mint";
+
+#[constant]
+pub const BOND_TOKEN_VAULT_SEED: &[u8] = b"bond_token_vault";
+
+/// Value stored by the former boolean `paused` config field, it means all operation groups are paused
+#[constant]
+pub const PAUSE_LEGACY_ALL: u8 = 1 << 0;
+
+/// Bond creation, configuration and funding (stake accounts, tokens)
+#[constant]
+pub const PAUSE_FUNDING: u8 = 1 << 1;
+
+/// Withdraw requests and withdrawing bond stake accounts and tokens
+#[constant]
+pub const PAUSE_WITHDRAW: u8 = 1 << 2;
+
+/// Settlement creation, funding and closing
+#[constant]
+pub const PAUSE_SETTLEMENT: u8 = 1 << 3;
+
+/// Claiming settlements
+#[constant]
+pub const PAUSE_CLAIM: u8 = 1 << 4;
+
+/// Merging, resetting and withdrawing stake accounts by operator
+#[constant]
+pub const PAUSE_STAKE_MANAGEMENT: u8 = 1 << 5;
+
+#[constant]
+pub const PAUSE_ALL: u8 =
+    PAUSE_FUNDING | PAUSE_WITHDRAW | PAUSE_SETTLEMENT | PAUSE_CLAIM | PAUSE_STAKE_MANAGEMENT;
+
 pub const MIN_STAKE_LAMPORTS: u64 = 1_000_000_000;
 
 // 8 + mem::size_of::<SettlementClaims>(): 8 + 32 + 1 + 8 = 49 bytes
 // Anchor aligns to 8 bytes, so data part that Anchor uses for saving data is 56 bytes
+// (the chunk fields 2 + 2 + 2 + 1 = 7 bytes were added later into the aligned space)
 #[constant]
 pub const SETTLEMENT_CLAIMS_ANCHOR_HEADER_SIZE: u8 = 56;
+
+/// Number of records of one SettlementClaims chunk account,
+/// the chunk account size is then exactly the maximum allocation size of one instruction (10KB)
+#[constant]
+pub const SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS: u64 =
+    (10 * 1024 - SETTLEMENT_CLAIMS_ANCHOR_HEADER_SIZE as u64) * 8;
+
+/// Number of epochs the bond ledger keeps the counters for
+#[constant]
+pub const BOND_LEDGER_EPOCHS: u64 = 32;
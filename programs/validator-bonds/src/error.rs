@@ -242,4 +242,123 @@ pub enum ErrorCode {
 
     #[msg("Fail to validate ProductConfig value")]
     ProductTypeConfigValidationFailure, // 6078 0x17be
+
+    #[msg("Withdraw request resize permits only lowering the requested amount")]
+    WithdrawRequestResizeNotLowering, // 6079 0x17bf
+
+    #[msg("Withdraw request cannot be resized below the already withdrawn amount")]
+    WithdrawRequestResizeBelowWithdrawn, // 6080 0x17c0
+
+    #[msg("Collateral mint haircut has to be lower than 10000 basis points")]
+    CollateralMintHaircutTooHigh, // 6081 0x17c1
+
+    #[msg("Collateral mint has to have the same number of decimals as SOL")]
+    CollateralMintDecimalsMismatch, // 6082 0x17c2
+
+    #[msg("Collateral mint is not enabled for funding bonds")]
+    CollateralMintDisabled, // 6083 0x17c3
+
+    #[msg("Fail to create program address for CollateralMint")]
+    InvalidCollateralMintAddress, // 6084 0x17c4
+
+    #[msg("Withdrawing value exceeds the amount remaining in the withdraw request")]
+    WithdrawRequestAmountExceeded, // 6085 0x17c5
+
+    #[msg("Bond token vault has not enough collateral")]
+    InsufficientBondTokenCollateral, // 6086 0x17c6
+
+    #[msg("Stake account lamports do not fit the settlement funding")]
+    SettlementFundingStakeMismatch, // 6087 0x17c7
+
+    #[msg("Signer is not the pending authority proposed to be accepted")]
+    InvalidPendingAuthority, // 6088 0x17c8
+
+    #[msg("Config authorities can be changed only by proposing and accepting them")]
+    ConfigAuthorityChangeNotProposed, // 6089 0x17c9
+
+    #[msg("Lockup-sensitive config parameters can be changed only by queueing the change")]
+    ConfigChangeRequiresTimelock, // 6090 0x17ca
+
+    #[msg("Queued config change activation epoch is earlier than the withdraw lockup permits")]
+    ConfigChangeActivationTooEarly, // 6091 0x17cb
+
+    #[msg("No config change is queued")]
+    NoQueuedConfigChange, // 6092 0x17cc
+
+    #[msg("Queued config change activation epoch has not been reached yet")]
+    QueuedConfigChangeNotActive, // 6093 0x17cd
+
+    #[msg("Invalid bitmask of pause operation groups")]
+    InvalidPauseOperations, // 6094 0x17ce
+
+    #[msg("Bond cannot be closed while settlements are open")]
+    BondHasOpenSettlements, // 6095 0x17cf
+
+    #[msg("Bond cannot be closed while a withdraw request is outstanding")]
+    BondHasOutstandingWithdrawRequest, // 6096 0x17d0
+
+    #[msg("Bond product config effective epoch has to be in the future")]
+    BondProductEffectiveEpochTooEarly, // 6097 0x17d1
+
+    #[msg("Claim can be redirected to a system account or a stake account of the withdraw authority only")]
+    InvalidClaimToAccount, // 6098 0x17d2
+
+    #[msg("Batch of settlement claims is empty")]
+    EmptyClaimBatch, // 6099 0x17d3
+
+    #[msg(
+        "Settlement expiration epoch has to be extended beyond the current expiration and epoch"
+    )]
+    SettlementExpirationNotExtended, // 6100 0x17d4
+
+    #[msg("Replaceable settlement seed cannot be zeroed")]
+    InvalidReplaceableSettlementSeed, // 6101 0x17d5
+
+    #[msg("Settlement merkle root is a seed of the settlement address and cannot be replaced")]
+    SettlementRootNotReplaceable, // 6102 0x17d6
+
+    #[msg("Settlement claiming has already started")]
+    SettlementClaimingStarted, // 6103 0x17d7
+
+    #[msg("Settlement claims chunk does not match the claim record index")]
+    SettlementClaimsChunkMismatch, // 6104 0x17d8
+
+    #[msg("Settlement claims account cannot be split into chunks")]
+    SettlementClaimsNotChunkable, // 6105 0x17d9
+
+    #[msg("Settlement claims chunk accounts do not match the initialized chunks")]
+    SettlementClaimsChunksMismatch, // 6106 0x17da
+
+    #[msg("Settlement claims chunks cannot store the number of merkle nodes")]
+    SettlementClaimsChunksExceeded, // 6107 0x17db
+
+    #[msg("Bond ledger account does not belong to the bond")]
+    BondLedgerMismatch, // 6108 0x17dc
+
+    #[msg("Collateral mint exchange rate has to be positive")]
+    InvalidCollateralExchangeRate, // 6109 0x17dd
+
+    #[msg("Collateral mint exchange rate is not updated for the current epoch")]
+    CollateralExchangeRateStale, // 6110 0x17de
+
+    #[msg("Bond counter of the open accounts cannot be decreased below zero")]
+    BondCounterUnderflow, // 6111 0x17df
+
+    #[msg("Bond cannot be closed while a stake account is delegated to the vote account")]
+    BondHasDelegatedStake, // 6112 0x17e0
+
+    #[msg("Bond cannot be closed while bond products exist")]
+    BondHasBondProducts, // 6113 0x17e1
+
+    #[msg("Bond cannot be closed while a bond token vault holds collateral")]
+    BondHasTokenCollateral, // 6114 0x17e2
+
+    #[msg("Bond cannot be closed while the bond ledger exists")]
+    BondHasBondLedger, // 6115 0x17e3
+
+    #[msg("Bond ledger account is required as the last remaining account")]
+    BondLedgerMissing, // 6116 0x17e4
+
+    #[msg("Bond cannot be closed before its accounts are counted by the operator")]
+    BondAccountsNotCounted, // 6117 0x17e5
 }
@@ -17,6 +17,7 @@ pub struct ConfigureBondEvent {
     pub bond_authority: Option<PubkeyValueChange>,
     pub cpmpe: Option<U64ValueChange>,
     pub max_stake_wanted: Option<U64ValueChange>,
+    pub pending_bond_authority: Option<Pubkey>,
 }
 
 #[event]
@@ -25,6 +26,14 @@ pub struct ConfigureBondWithMintEvent {
     pub bond_authority: Option<PubkeyValueChange>,
     pub cpmpe: Option<U64ValueChange>,
     pub max_stake_wanted: Option<U64ValueChange>,
+    pub pending_bond_authority: Option<Pubkey>,
+}
+
+#[event]
+pub struct CancelBondAuthorityEvent {
+    pub bond: Pubkey,
+    pub pending_authority: Pubkey,
+    pub authority: Pubkey,
 }
 
 #[event]
@@ -43,3 +52,37 @@ pub struct MintBondEvent {
     pub validator_identity_token_account: Pubkey,
     pub token_metadata: Pubkey,
 }
+
+#[event]
+pub struct CloseBondEvent {
+    pub bond: Pubkey,
+    pub config: Pubkey,
+    pub vote_account: Pubkey,
+    pub authority: Pubkey,
+    pub rent_collector: Pubkey,
+}
+
+#[event]
+pub struct CountBondAccountsEvent {
+    pub bond: Pubkey,
+    pub operator_authority: Pubkey,
+    pub counted_settlements: u64,
+    pub counted_withdraw_requests: u64,
+    pub counted_bond_products: u64,
+}
+
+#[event]
+pub struct InitBondLedgerEvent {
+    pub bond_ledger: Pubkey,
+    pub bond: Pubkey,
+    pub vote_account: Pubkey,
+}
+
+#[event]
+pub struct CloseBondLedgerEvent {
+    pub bond_ledger: Pubkey,
+    pub bond: Pubkey,
+    pub vote_account: Pubkey,
+    pub authority: Pubkey,
+    pub rent_collector: Pubkey,
+}
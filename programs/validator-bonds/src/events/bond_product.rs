@@ -20,4 +20,16 @@ pub struct ConfigureBondProductEvent {
     pub product_type: ProductType,
     pub old_config_data: ProductTypeConfig,
     pub new_config_data: ProductTypeConfig,
+    /// `None` when the new config applies immediately
+    pub effective_from_epoch: Option<u64>,
+}
+
+#[event]
+pub struct CloseBondProductEvent {
+    pub bond_product: Pubkey,
+    pub bond: Pubkey,
+    pub vote_account: Pubkey,
+    pub product_type: ProductType,
+    pub authority: Pubkey,
+    pub rent_collector: Pubkey,
 }
@@ -0,0 +1,63 @@
+use crate::events::{BoolValueChange, U64ValueChange};
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct InitCollateralMintEvent {
+    pub collateral_mint: Pubkey,
+    pub config: Pubkey,
+    pub mint: Pubkey,
+    pub haircut_bps: u64,
+}
+
+#[event]
+pub struct ConfigureCollateralMintEvent {
+    pub collateral_mint: Pubkey,
+    pub mint: Pubkey,
+    pub haircut_bps: Option<U64ValueChange>,
+    pub enabled: Option<BoolValueChange>,
+}
+
+#[event]
+pub struct UpdateCollateralExchangeRateEvent {
+    pub collateral_mint: Pubkey,
+    pub mint: Pubkey,
+    pub exchange_rate: U64ValueChange,
+    pub epoch: u64,
+}
+
+#[event]
+pub struct FundBondTokenEvent {
+    pub bond: Pubkey,
+    pub vote_account: Pubkey,
+    pub mint: Pubkey,
+    pub bond_token_vault: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub lamports_value: u64,
+}
+
+#[event]
+pub struct WithdrawBondTokenEvent {
+    pub bond: Pubkey,
+    pub vote_account: Pubkey,
+    pub withdraw_request: Pubkey,
+    pub mint: Pubkey,
+    pub bond_token_vault: Pubkey,
+    pub withdrawer_token_account: Pubkey,
+    pub amount: u64,
+    pub lamports_value: u64,
+    pub withdrawn_amount: U64ValueChange,
+}
+
+#[event]
+pub struct FundSettlementTokenEvent {
+    pub bond: Pubkey,
+    pub settlement: Pubkey,
+    pub funding_amount: u64,
+    pub stake_account: Pubkey,
+    pub mint: Pubkey,
+    pub token_amount: u64,
+    pub exchange_rate: u64,
+    pub liquidator_token_account: Pubkey,
+    pub lamports_funded: u64,
+}
@@ -34,6 +34,10 @@ pub struct EmergencyPauseEvent {
     pub withdraw_lockup_epochs: u64,
     pub minimum_stake_lamports: u64,
     pub pause_authority: Pubkey,
+    /// operation groups whose pause state was changed
+    pub changed_operations: u8,
+    /// all operation groups paused after the change
+    pub paused_operations: u8,
 }
 
 #[event]
@@ -45,4 +49,44 @@ pub struct EmergencyResumeEvent {
     pub withdraw_lockup_epochs: u64,
     pub minimum_stake_lamports: u64,
     pub pause_authority: Pubkey,
+    /// operation groups whose pause state was changed
+    pub changed_operations: u8,
+    /// all operation groups paused after the change
+    pub paused_operations: u8,
+}
+
+#[event]
+pub struct ProposeConfigAuthorityEvent {
+    pub config: Pubkey,
+    pub pending_admin_authority: Option<Pubkey>,
+    pub pending_operator_authority: Option<Pubkey>,
+    pub pending_pause_authority: Option<Pubkey>,
+}
+
+#[event]
+pub struct CancelConfigAuthorityEvent {
+    pub config: Pubkey,
+    pub pending_admin_authority: Pubkey,
+    pub pending_operator_authority: Pubkey,
+    pub pending_pause_authority: Pubkey,
+}
+
+#[event]
+pub struct QueueConfigureConfigEvent {
+    pub config: Pubkey,
+    pub epochs_to_claim_settlement: Option<u64>,
+    pub withdraw_lockup_epochs: Option<u64>,
+    pub minimum_stake_lamports: Option<u64>,
+    pub slots_to_start_settlement_claiming: Option<u64>,
+    pub activation_epoch: u64,
+}
+
+#[event]
+pub struct CancelQueuedConfigEvent {
+    pub config: Pubkey,
+    pub epochs_to_claim_settlement: Option<u64>,
+    pub withdraw_lockup_epochs: Option<u64>,
+    pub minimum_stake_lamports: Option<u64>,
+    pub slots_to_start_settlement_claiming: Option<u64>,
+    pub activation_epoch: u64,
 }
@@ -3,6 +3,7 @@ use anchor_lang::solana_program::stake::state::Delegation;
 
 pub mod bond;
 pub mod bond_product;
+pub mod collateral;
 pub mod config;
 pub mod settlement;
 pub mod settlement_claim;
@@ -21,6 +22,12 @@ pub struct U64ValueChange {
     pub new: u64,
 }
 
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct BoolValueChange {
+    pub old: bool,
+    pub new: bool,
+}
+
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct DelegationInfo {
     /// to whom the stake is delegated
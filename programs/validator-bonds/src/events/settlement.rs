@@ -1,4 +1,4 @@
-use crate::events::SplitStakeData;
+use crate::events::{SplitStakeData, U64ValueChange};
 
 use anchor_lang::prelude::*;
 
@@ -49,6 +49,25 @@ pub struct CancelSettlementEvent {
     pub authority: Pubkey,
 }
 
+#[event]
+pub struct ExtendSettlementEvent {
+    pub bond: Pubkey,
+    pub settlement: Pubkey,
+    pub operator_authority: Pubkey,
+    pub expiration_epoch: U64ValueChange,
+}
+
+#[event]
+pub struct ReplaceSettlementRootEvent {
+    pub bond: Pubkey,
+    pub settlement: Pubkey,
+    pub operator_authority: Pubkey,
+    pub old_merkle_root: [u8; 32],
+    pub new_merkle_root: [u8; 32],
+    pub max_total_claim: U64ValueChange,
+    pub max_merkle_nodes: U64ValueChange,
+}
+
 #[event]
 pub struct FundSettlementEvent {
     pub bond: Pubkey,
@@ -12,3 +12,29 @@ pub struct ClaimSettlementV2Event {
     pub amount: u64,
     pub index: u64,
 }
+
+#[event]
+pub struct ClaimSettlementRedirectEvent {
+    pub settlement: Pubkey,
+    pub settlement_lamports_claimed: U64ValueChange,
+    pub settlement_merkle_nodes_claimed: u64,
+    pub claim_to: Pubkey,
+    pub stake_account_withdrawer: Pubkey,
+    pub stake_account_staker: Pubkey,
+    pub amount: u64,
+    pub index: u64,
+}
+
+#[event]
+pub struct ClaimSettlementBatchEvent {
+    pub settlement: Pubkey,
+    pub settlement_lamports_claimed: U64ValueChange,
+    pub settlement_merkle_nodes_claimed: u64,
+    pub stake_account_to: Pubkey,
+    pub stake_account_withdrawer: Pubkey,
+    pub stake_account_staker: Pubkey,
+    /// sum of the claims
+    pub amount: u64,
+    /// indexes of the claimed merkle tree nodes
+    pub indexes: Vec<u64>,
+}
@@ -30,3 +30,12 @@ pub struct ClaimWithdrawRequestEvent {
     pub withdrawing_amount: u64,
     pub withdrawn_amount: U64ValueChange,
 }
+
+#[event]
+pub struct ResizeWithdrawRequestEvent {
+    pub withdraw_request: Pubkey,
+    pub bond: Pubkey,
+    pub authority: Pubkey,
+    pub requested_amount: U64ValueChange,
+    pub withdrawn_amount: u64,
+}
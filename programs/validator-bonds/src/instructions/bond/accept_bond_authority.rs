@@ -0,0 +1,56 @@
+use crate::constants::PAUSE_FUNDING;
+use crate::error::ErrorCode;
+use crate::events::{bond::ConfigureBondEvent, PubkeyValueChange};
+use crate::state::bond::Bond;
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+
+/// Accepts the bond authority role proposed to the signer by configure_bond.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptBondAuthority<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        constraint = bond.pending_authority != Pubkey::default() @ ErrorCode::InvalidPendingAuthority,
+        constraint = bond.pending_authority == new_authority.key() @ ErrorCode::InvalidPendingAuthority,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    /// authority proposed to take the bond authority role
+    pub new_authority: Signer<'info>,
+}
+
+impl AcceptBondAuthority<'_> {
+    pub fn process(ctx: Context<AcceptBondAuthority>) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_FUNDING),
+            ErrorCode::ProgramIsPaused
+        );
+
+        let bond = &mut ctx.accounts.bond;
+        let old = bond.authority;
+        bond.authority = bond.pending_authority;
+        bond.pending_authority = Pubkey::default();
+
+        emit_cpi!(ConfigureBondEvent {
+            bond_authority: Some(PubkeyValueChange {
+                old,
+                new: ctx.accounts.bond.authority,
+            }),
+            cpmpe: None,
+            max_stake_wanted: None,
+            pending_bond_authority: None,
+        });
+
+        Ok(())
+    }
+}
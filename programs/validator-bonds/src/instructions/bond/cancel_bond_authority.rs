@@ -0,0 +1,66 @@
+use crate::checks::check_bond_authority;
+use crate::constants::PAUSE_FUNDING;
+use crate::error::ErrorCode;
+use crate::events::bond::CancelBondAuthorityEvent;
+use crate::state::bond::Bond;
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::vote::program::ID as vote_program_id;
+
+/// Cancels the pending (not yet accepted) bond authority proposal.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelBondAuthority<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        has_one = vote_account @ ErrorCode::VoteAccountMismatch,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            vote_account.key().as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    /// validator vote account validator identity or bond authority may cancel the proposal
+    pub authority: Signer<'info>,
+
+    /// CHECK: check&deserialize the vote account in the code
+    #[account(
+        owner = vote_program_id @ ErrorCode::InvalidVoteAccountProgramId,
+    )]
+    pub vote_account: UncheckedAccount<'info>,
+}
+
+impl CancelBondAuthority<'_> {
+    pub fn process(ctx: Context<CancelBondAuthority>) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_FUNDING),
+            ErrorCode::ProgramIsPaused
+        );
+
+        require!(
+            check_bond_authority(
+                &ctx.accounts.authority.key(),
+                &ctx.accounts.bond,
+                &ctx.accounts.vote_account
+            ),
+            ErrorCode::BondChangeNotPermitted
+        );
+
+        let pending_authority = ctx.accounts.bond.pending_authority;
+        ctx.accounts.bond.pending_authority = Pubkey::default();
+
+        emit_cpi!(CancelBondAuthorityEvent {
+            bond: ctx.accounts.bond.key(),
+            pending_authority,
+            authority: ctx.accounts.authority.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use crate::checks::{check_bond_authority, get_delegation};
+use crate::constants::PAUSE_FUNDING;
+use crate::error::ErrorCode;
+use crate::events::bond::CloseBondEvent;
+use crate::state::bond::Bond;
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::stake::program::ID as stake_program_id;
+use anchor_lang::solana_program::vote::program::ID as vote_program_id;
+use anchor_spl::stake::StakeAccount;
+
+/// Closes the validator bond account when the validator leaves the program.
+/// The bond must not have any open settlement, outstanding withdraw request, bond product,
+/// bond ledger nor bond token vault holding collateral. A bond created before the counters
+/// of the open accounts were introduced has to be counted by count_bond_accounts first.
+/// The passed remaining stake accounts are checked not to be delegated to the bond vote account.
+// The stake accounts cannot be enumerated on-chain, closing does not guarantee no stake is left
+// under the bonds withdrawer authority. Such a stake account is not lost, it is accessible again
+// when the bond is re-created by init_bond.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseBond<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = rent_collector,
+        has_one = vote_account @ ErrorCode::VoteAccountMismatch,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        constraint = bond.open_settlements == 0 @ ErrorCode::BondHasOpenSettlements,
+        constraint = bond.open_withdraw_requests == 0 @ ErrorCode::BondHasOutstandingWithdrawRequest,
+        constraint = bond.open_bond_products == 0 @ ErrorCode::BondHasBondProducts,
+        constraint = bond.funded_token_vaults == 0 @ ErrorCode::BondHasTokenCollateral,
+        constraint = bond.accounts_counted @ ErrorCode::BondAccountsNotCounted,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            vote_account.key().as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    /// CHECK: check&deserialize the vote account in the code
+    #[account(
+        owner = vote_program_id @ ErrorCode::InvalidVoteAccountProgramId,
+    )]
+    pub vote_account: UncheckedAccount<'info>,
+
+    /// validator vote account validator identity or bond authority may close the bond
+    pub authority: Signer<'info>,
+
+    /// CHECK: the (legacy) withdraw request address without index that must not exist
+    #[account(
+        constraint = withdraw_request.data_is_empty() @ ErrorCode::BondHasOutstandingWithdrawRequest,
+        seeds = [
+            b"withdraw_account",
+            bond.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub withdraw_request: UncheckedAccount<'info>,
+
+    /// CHECK: the bond ledger address that must not exist
+    #[account(
+        constraint = bond_ledger.data_is_empty() @ ErrorCode::BondHasBondLedger,
+        seeds = [
+            b"bond_ledger",
+            bond.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub bond_ledger: UncheckedAccount<'info>,
+
+    /// CHECK: caller may define SystemAccount or any other
+    #[account(mut)]
+    pub rent_collector: UncheckedAccount<'info>,
+}
+
+impl<'info> CloseBond<'info> {
+    pub fn process(ctx: Context<'_, '_, '_, 'info, CloseBond<'info>>) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_FUNDING),
+            ErrorCode::ProgramIsPaused
+        );
+
+        require!(
+            check_bond_authority(
+                &ctx.accounts.authority.key(),
+                &ctx.accounts.bond,
+                &ctx.accounts.vote_account
+            ),
+            ErrorCode::BondChangeNotPermitted
+        );
+
+        let bonds_withdrawer_authority = ctx
+            .accounts
+            .config
+            .bonds_withdrawer_authority(&ctx.accounts.config.key())?;
+        for stake_account_info in ctx.remaining_accounts.iter() {
+            require_keys_eq!(
+                *stake_account_info.owner,
+                stake_program_id,
+                ErrorCode::InvalidStakeAccountProgramId
+            );
+            let stake_account =
+                StakeAccount::try_deserialize(&mut stake_account_info.data.borrow().as_ref())?;
+            let is_bond_withdrawer = stake_account
+                .meta()
+                .is_some_and(|meta| meta.authorized.withdrawer == bonds_withdrawer_authority);
+            let is_delegated_to_bond = get_delegation(&stake_account)?.is_some_and(|delegation| {
+                delegation.voter_pubkey == ctx.accounts.vote_account.key()
+            });
+            if is_bond_withdrawer && is_delegated_to_bond {
+                return Err(error!(ErrorCode::BondHasDelegatedStake)
+                    .with_pubkeys((stake_account_info.key(), ctx.accounts.vote_account.key())));
+            }
+        }
+
+        emit_cpi!(CloseBondEvent {
+            bond: ctx.accounts.bond.key(),
+            config: ctx.accounts.config.key(),
+            vote_account: ctx.accounts.vote_account.key(),
+            authority: ctx.accounts.authority.key(),
+            rent_collector: ctx.accounts.rent_collector.key(),
+        });
+
+        Ok(())
+    }
+}
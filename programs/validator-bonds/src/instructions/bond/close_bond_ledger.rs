@@ -0,0 +1,84 @@
+use crate::checks::check_bond_authority;
+use crate::constants::PAUSE_FUNDING;
+use crate::error::ErrorCode;
+use crate::events::bond::CloseBondLedgerEvent;
+use crate::state::bond::Bond;
+use crate::state::bond_ledger::BondLedger;
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::vote::program::ID as vote_program_id;
+
+/// Closes the bond ledger account, rent is returned to the rent collector.
+/// The bond ledger has to be closed before the bond can be closed.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseBondLedger<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        has_one = vote_account @ ErrorCode::VoteAccountMismatch,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            vote_account.key().as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    /// CHECK: check&deserialize the vote account in the code
+    #[account(
+        owner = vote_program_id @ ErrorCode::InvalidVoteAccountProgramId,
+    )]
+    pub vote_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_collector,
+        has_one = bond @ ErrorCode::BondLedgerMismatch,
+        seeds = [
+            b"bond_ledger",
+            bond.key().as_ref(),
+        ],
+        bump = bond_ledger.bump,
+    )]
+    pub bond_ledger: Box<Account<'info, BondLedger>>,
+
+    /// validator vote account validator identity or bond authority may close the bond ledger
+    pub authority: Signer<'info>,
+
+    /// CHECK: caller may define SystemAccount or any other
+    #[account(mut)]
+    pub rent_collector: UncheckedAccount<'info>,
+}
+
+impl CloseBondLedger<'_> {
+    pub fn process(ctx: Context<CloseBondLedger>) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_FUNDING),
+            ErrorCode::ProgramIsPaused
+        );
+
+        require!(
+            check_bond_authority(
+                &ctx.accounts.authority.key(),
+                &ctx.accounts.bond,
+                &ctx.accounts.vote_account
+            ),
+            ErrorCode::BondChangeNotPermitted
+        );
+        ctx.accounts.bond.bond_ledger_bump = None;
+
+        emit_cpi!(CloseBondLedgerEvent {
+            bond_ledger: ctx.accounts.bond_ledger.key(),
+            bond: ctx.accounts.bond.key(),
+            vote_account: ctx.accounts.vote_account.key(),
+            authority: ctx.accounts.authority.key(),
+            rent_collector: ctx.accounts.rent_collector.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use crate::checks::check_bond_authority;
+use crate::constants::PAUSE_FUNDING;
 use crate::error::ErrorCode;
-use crate::events::{bond::ConfigureBondEvent, PubkeyValueChange, U64ValueChange};
+use crate::events::{bond::ConfigureBondEvent, U64ValueChange};
 use crate::instructions::verify_max_stake_wanted;
 use crate::state::bond::Bond;
 use crate::state::config::Config;
@@ -9,7 +10,8 @@ use anchor_lang::solana_program::vote::program::ID as vote_program_id;
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct ConfigureBondArgs {
-    /// New bond authority that can manage the bond account.
+    /// New bond authority proposed to manage the bond account.
+    /// The proposed authority takes the role after signing accept_bond_authority.
     pub bond_authority: Option<Pubkey>,
     /// New `cpmpe` value (cost per mille per epoch).
     /// It defines the bid for the validator to get delegated up to `max_stake_wanted` lamports.
@@ -53,7 +55,10 @@ impl ConfigureBond<'_> {
         ctx: Context<ConfigureBond>,
         configure_bond_args: ConfigureBondArgs,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_FUNDING),
+            ErrorCode::ProgramIsPaused
+        );
 
         require!(
             check_bond_authority(
@@ -65,7 +70,7 @@ impl ConfigureBond<'_> {
         );
 
         let ConfigureBondChanges {
-            bond_authority_change,
+            pending_bond_authority,
             cpmpe_change,
             max_stake_wanted_change,
         } = configure_bond(
@@ -75,9 +80,10 @@ impl ConfigureBond<'_> {
         )?;
 
         emit_cpi!(ConfigureBondEvent {
-            bond_authority: bond_authority_change,
+            bond_authority: None,
             cpmpe: cpmpe_change,
             max_stake_wanted: max_stake_wanted_change,
+            pending_bond_authority,
         });
 
         Ok(())
@@ -85,7 +91,7 @@ impl ConfigureBond<'_> {
 }
 
 pub struct ConfigureBondChanges {
-    pub bond_authority_change: Option<PubkeyValueChange>,
+    pub pending_bond_authority: Option<Pubkey>,
     pub cpmpe_change: Option<U64ValueChange>,
     pub max_stake_wanted_change: Option<U64ValueChange>,
 }
@@ -95,14 +101,10 @@ pub(crate) fn configure_bond(
     min_bond_max_stake_wanted: u64,
     configure_args: ConfigureBondArgs,
 ) -> Result<ConfigureBondChanges> {
-    let bond_authority_change = configure_args.bond_authority.map(|authority| {
-        let old = bond.authority;
-        bond.authority = authority;
-        PubkeyValueChange {
-            old,
-            new: authority,
-        }
-    });
+    // the bond authority is changed only when the proposed authority accepts it
+    let pending_bond_authority = configure_args
+        .bond_authority
+        .inspect(|authority| bond.pending_authority = *authority);
     let cpmpe_change = configure_args.cpmpe.map(|new_cpmpe| {
         let old = bond.cpmpe;
         bond.cpmpe = new_cpmpe;
@@ -122,7 +124,7 @@ pub(crate) fn configure_bond(
     verify_max_stake_wanted(bond.max_stake_wanted, min_bond_max_stake_wanted)?;
 
     Ok(ConfigureBondChanges {
-        bond_authority_change,
+        pending_bond_authority,
         cpmpe_change,
         max_stake_wanted_change,
     })
@@ -1,4 +1,5 @@
 use crate::checks::get_validator_vote_account_validator_identity;
+use crate::constants::PAUSE_FUNDING;
 use crate::error::ErrorCode;
 use crate::events::bond::ConfigureBondWithMintEvent;
 use crate::instructions::{configure_bond, ConfigureBondArgs, ConfigureBondChanges};
@@ -13,7 +14,8 @@ use anchor_spl::token::{burn, Burn, Token, TokenAccount};
 pub struct ConfigureBondWithMintArgs {
     /// Validator identity configured within the vote account.
     pub validator_identity: Pubkey,
-    /// New bond authority that can manage the bond account.
+    /// New bond authority proposed to manage the bond account.
+    /// The proposed authority takes the role after signing accept_bond_authority.
     pub bond_authority: Option<Pubkey>,
     /// New `cpmpe` value (cost per mille per epoch).
     /// It defines the bid for the validator to get delegated up to `max_stake_wanted` lamports.
@@ -79,7 +81,10 @@ impl ConfigureBondWithMint<'_> {
         ctx: Context<ConfigureBondWithMint>,
         configure_bond_mint_args: ConfigureBondWithMintArgs,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_FUNDING),
+            ErrorCode::ProgramIsPaused
+        );
 
         let validator_identity_vote_account =
             get_validator_vote_account_validator_identity(&ctx.accounts.vote_account)?;
@@ -90,7 +95,7 @@ impl ConfigureBondWithMint<'_> {
         );
 
         let ConfigureBondChanges {
-            bond_authority_change,
+            pending_bond_authority,
             cpmpe_change,
             max_stake_wanted_change,
         } = configure_bond(
@@ -117,9 +122,10 @@ impl ConfigureBondWithMint<'_> {
 
         emit_cpi!(ConfigureBondWithMintEvent {
             validator_identity: configure_bond_mint_args.validator_identity,
-            bond_authority: bond_authority_change,
+            bond_authority: None,
             cpmpe: cpmpe_change,
             max_stake_wanted: max_stake_wanted_change,
+            pending_bond_authority,
         });
 
         Ok(())
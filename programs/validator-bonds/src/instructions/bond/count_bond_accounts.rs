@@ -0,0 +1,132 @@
+use crate::error::ErrorCode;
+use crate::events::bond::CountBondAccountsEvent;
+use crate::state::bond::Bond;
+use crate::state::bond_product::BondProduct;
+use crate::state::config::Config;
+use crate::state::settlement::Settlement;
+use crate::state::withdraw_request::WithdrawRequest;
+use crate::ID;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// Counts the open accounts of a bond created before the counters of the open accounts were introduced.
+/// Permission-ed for operator authority that passes all the not counted settlements, withdraw requests
+/// and bond products of the bond as remaining accounts (found off-chain, e.g., by getProgramAccounts).
+/// The bond is marked as counted then and it can be closed when the counters are at zero.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CountBondAccounts<'info> {
+    #[account(
+        has_one = operator_authority @ ErrorCode::InvalidOperatorAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    /// operator signer authority that is allowed to count the bond accounts
+    pub operator_authority: Signer<'info>,
+}
+
+impl<'info> CountBondAccounts<'info> {
+    pub fn process(ctx: Context<'_, '_, '_, 'info, CountBondAccounts<'info>>) -> Result<()> {
+        let bond_key = ctx.accounts.bond.key();
+        let mut counted_settlements = 0_u64;
+        let mut counted_withdraw_requests = 0_u64;
+        let mut counted_bond_products = 0_u64;
+        for account in ctx.remaining_accounts.iter() {
+            require_keys_eq!(
+                *account.owner,
+                ID,
+                anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+            );
+            require!(
+                account.is_writable,
+                anchor_lang::error::ErrorCode::ConstraintMut
+            );
+            let data = account.try_borrow_data()?;
+            if data.starts_with(Settlement::DISCRIMINATOR) {
+                drop(data);
+                if count_account::<Settlement>(
+                    account,
+                    &bond_key,
+                    |settlement| settlement.bond,
+                    |settlement| &mut settlement.counted,
+                )? {
+                    counted_settlements += 1;
+                }
+            } else if data.starts_with(WithdrawRequest::DISCRIMINATOR) {
+                drop(data);
+                if count_account::<WithdrawRequest>(
+                    account,
+                    &bond_key,
+                    |withdraw_request| withdraw_request.bond,
+                    |withdraw_request| &mut withdraw_request.counted,
+                )? {
+                    counted_withdraw_requests += 1;
+                }
+            } else if data.starts_with(BondProduct::DISCRIMINATOR) {
+                drop(data);
+                if count_account::<BondProduct>(
+                    account,
+                    &bond_key,
+                    |bond_product| bond_product.bond,
+                    |bond_product| &mut bond_product.counted,
+                )? {
+                    counted_bond_products += 1;
+                }
+            } else {
+                return Err(
+                    error!(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch)
+                        .with_pubkeys((account.key(), bond_key)),
+                );
+            }
+        }
+
+        let bond = &mut ctx.accounts.bond;
+        bond.open_settlements += counted_settlements;
+        bond.open_withdraw_requests += counted_withdraw_requests;
+        bond.open_bond_products += counted_bond_products;
+        bond.accounts_counted = true;
+
+        emit_cpi!(CountBondAccountsEvent {
+            bond: bond_key,
+            operator_authority: ctx.accounts.operator_authority.key(),
+            counted_settlements,
+            counted_withdraw_requests,
+            counted_bond_products,
+        });
+
+        Ok(())
+    }
+}
+
+/// Marks the account of the bond as counted, returns false when it has been counted already.
+fn count_account<T: AccountSerialize + AccountDeserialize>(
+    account: &AccountInfo,
+    bond: &Pubkey,
+    account_bond: impl Fn(&T) -> Pubkey,
+    counted: impl Fn(&mut T) -> &mut bool,
+) -> Result<bool> {
+    let mut data = T::try_deserialize(&mut &account.data.borrow()[..])?;
+    if account_bond(&data) != *bond {
+        return Err(
+            error!(ErrorCode::BondAccountMismatch).with_pubkeys((account_bond(&data), *bond))
+        );
+    }
+    if *counted(&mut data) {
+        return Ok(false);
+    }
+    *counted(&mut data) = true;
+    data.try_serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(true)
+}
@@ -3,6 +3,7 @@ use crate::checks::{
     check_stake_is_initialized_with_withdrawer_authority, check_stake_is_not_locked,
     check_stake_valid_delegation,
 };
+use crate::constants::PAUSE_FUNDING;
 use crate::error::ErrorCode;
 use crate::events::bond::FundBondEvent;
 use crate::state::bond::Bond;
@@ -59,7 +60,10 @@ pub struct FundBond<'info> {
 
 impl FundBond<'_> {
     pub fn process(ctx: Context<FundBond>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_FUNDING),
+            ErrorCode::ProgramIsPaused
+        );
 
         // check current stake account withdrawer authority with permission to authorize
         check_stake_is_initialized_with_withdrawer_authority(
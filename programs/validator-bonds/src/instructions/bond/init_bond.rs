@@ -1,6 +1,7 @@
 use crate::checks::{
     check_vote_account_validator_identity, get_validator_vote_account_validator_identity,
 };
+use crate::constants::PAUSE_FUNDING;
 use crate::error::ErrorCode;
 use crate::events::bond::InitBondEvent;
 use crate::state::bond::Bond;
@@ -64,7 +65,10 @@ impl InitBond<'_> {
             max_stake_wanted,
         }: InitBondArgs,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_FUNDING),
+            ErrorCode::ProgramIsPaused
+        );
 
         let mut cpmpe = cpmpe;
         let mut bond_authority = bond_authority;
@@ -98,7 +102,15 @@ impl InitBond<'_> {
             cpmpe,
             max_stake_wanted,
             bump: ctx.bumps.bond,
-            reserved: [0; 134],
+            withdraw_request_index: 0,
+            pending_authority: Pubkey::default(),
+            open_settlements: 0,
+            open_withdraw_requests: 0,
+            open_bond_products: 0,
+            funded_token_vaults: 0,
+            bond_ledger_bump: None,
+            accounts_counted: true,
+            reserved: [0; 59],
         });
         emit_cpi!(InitBondEvent {
             bond: ctx.accounts.bond.key(),
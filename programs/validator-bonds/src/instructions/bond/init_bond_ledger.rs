@@ -0,0 +1,74 @@
+use crate::constants::{BOND_LEDGER_EPOCHS, PAUSE_FUNDING};
+use crate::error::ErrorCode;
+use crate::events::bond::InitBondLedgerEvent;
+use crate::state::bond::Bond;
+use crate::state::bond_ledger::{BondLedger, BondLedgerRecord};
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+
+/// Creates the bond ledger account keeping history of the bond operations.
+/// Permission-less, since then the instructions updating the ledger require it as the last remaining account.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitBondLedger<'info> {
+    /// the config account that the bond belongs to
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<BondLedger>(),
+        seeds = [
+            b"bond_ledger",
+            bond.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub bond_ledger: Box<Account<'info, BondLedger>>,
+
+    /// rent exempt payer of account creation
+    #[account(
+        mut,
+        owner = system_program.key(),
+    )]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl InitBondLedger<'_> {
+    pub fn process(ctx: Context<InitBondLedger>) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_FUNDING),
+            ErrorCode::ProgramIsPaused
+        );
+
+        ctx.accounts.bond_ledger.set_inner(BondLedger {
+            bond: ctx.accounts.bond.key(),
+            bump: ctx.bumps.bond_ledger,
+            records: [BondLedgerRecord::default(); BOND_LEDGER_EPOCHS as usize],
+            reserved: [0; 64],
+        });
+        ctx.accounts.bond.bond_ledger_bump = Some(ctx.bumps.bond_ledger);
+
+        emit_cpi!(InitBondLedgerEvent {
+            bond_ledger: ctx.accounts.bond_ledger.key(),
+            bond: ctx.accounts.bond.key(),
+            vote_account: ctx.accounts.bond.vote_account,
+        });
+
+        Ok(())
+    }
+}
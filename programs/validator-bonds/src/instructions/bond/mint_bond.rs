@@ -1,5 +1,5 @@
 use crate::checks::get_validator_vote_account_validator_identity;
-use crate::constants::BOND_MINT_SEED;
+use crate::constants::{BOND_MINT_SEED, PAUSE_FUNDING};
 use crate::error::ErrorCode;
 use crate::events::bond::MintBondEvent;
 use crate::state::bond::Bond;
@@ -91,7 +91,10 @@ pub struct MintBond<'info> {
 
 impl MintBond<'_> {
     pub fn process(ctx: Context<MintBond>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_FUNDING),
+            ErrorCode::ProgramIsPaused
+        );
 
         let validator_identity_vote_account =
             get_validator_vote_account_validator_identity(&ctx.accounts.vote_account)?;
@@ -1,12 +1,24 @@
 pub mod init_bond;
 
+pub mod accept_bond_authority;
+pub mod cancel_bond_authority;
+pub mod close_bond;
+pub mod close_bond_ledger;
 pub mod configure_bond;
 pub mod configure_bond_with_mint;
+pub mod count_bond_accounts;
 pub mod fund_bond;
+pub mod init_bond_ledger;
 pub mod mint_bond;
 
+pub use accept_bond_authority::*;
+pub use cancel_bond_authority::*;
+pub use close_bond::*;
+pub use close_bond_ledger::*;
 pub use configure_bond::*;
 pub use configure_bond_with_mint::*;
+pub use count_bond_accounts::*;
 pub use fund_bond::*;
 pub use init_bond::*;
+pub use init_bond_ledger::*;
 pub use mint_bond::*;
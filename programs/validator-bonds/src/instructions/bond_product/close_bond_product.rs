@@ -0,0 +1,93 @@
+use crate::checks::check_bond_authority;
+use crate::constants::PAUSE_FUNDING;
+use crate::error::ErrorCode;
+use crate::events::bond_product::CloseBondProductEvent;
+use crate::state::bond::Bond;
+use crate::state::bond_product::BondProduct;
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::vote::program::ID as vote_program_id;
+
+/// Closes the bond product account, rent is returned to the rent collector.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseBondProduct<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        has_one = vote_account @ ErrorCode::VoteAccountMismatch,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            vote_account.key().as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    /// CHECK: check&deserialize the vote account in the code
+    #[account(
+        owner = vote_program_id @ ErrorCode::InvalidVoteAccountProgramId,
+    )]
+    pub vote_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_collector,
+        has_one = bond @ ErrorCode::BondAccountMismatch,
+        seeds = [
+            b"bond_product",
+            bond.key().as_ref(),
+            bond_product.product_type.to_seed(),
+        ],
+        bump = bond_product.bump,
+    )]
+    pub bond_product: Account<'info, BondProduct>,
+
+    /// validator vote account validator identity or bond authority may close the bond product
+    pub authority: Signer<'info>,
+
+    /// CHECK: caller may define SystemAccount or any other
+    #[account(mut)]
+    pub rent_collector: UncheckedAccount<'info>,
+}
+
+impl CloseBondProduct<'_> {
+    pub fn process(ctx: Context<CloseBondProduct>) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_FUNDING),
+            ErrorCode::ProgramIsPaused
+        );
+
+        require!(
+            check_bond_authority(
+                &ctx.accounts.authority.key(),
+                &ctx.accounts.bond,
+                &ctx.accounts.vote_account
+            ),
+            ErrorCode::BondProductSetupNotPermitted
+        );
+
+        if ctx.accounts.bond_product.counted {
+            ctx.accounts.bond.open_bond_products = ctx
+                .accounts
+                .bond
+                .open_bond_products
+                .checked_sub(1)
+                .ok_or(ErrorCode::BondCounterUnderflow)?;
+        }
+
+        emit_cpi!(CloseBondProductEvent {
+            bond_product: ctx.accounts.bond_product.key(),
+            bond: ctx.accounts.bond.key(),
+            vote_account: ctx.accounts.vote_account.key(),
+            product_type: ctx.accounts.bond_product.product_type.clone(),
+            authority: ctx.accounts.authority.key(),
+            rent_collector: ctx.accounts.rent_collector.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -1,9 +1,10 @@
 use crate::checks::check_bond_authority;
+use crate::constants::PAUSE_FUNDING;
 use crate::error::ErrorCode;
 use crate::events::bond_product::ConfigureBondProductEvent;
 use crate::state::bond::Bond;
 use crate::state::bond_product::{
-    BondProduct, ProductType, ProductTypeConfig, ValidateProductTypeConfig,
+    BondProduct, PendingProductConfig, ProductType, ProductTypeConfig, ValidateProductTypeConfig,
 };
 use crate::state::config::Config;
 use anchor_lang::prelude::*;
@@ -12,10 +13,14 @@ use anchor_lang::solana_program::vote::program::ID as vote_program_id;
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct ConfigureBondProductArgs {
     pub config_data: ProductTypeConfig,
+    /// `None` applies the config immediately, discarding any pending config;
+    /// the epoch (in the future) schedules the config as pending
+    pub effective_from_epoch: Option<u64>,
 }
 
 #[event_cpi]
 #[derive(Accounts)]
+#[instruction(args: ConfigureBondProductArgs)]
 pub struct ConfigureBondProduct<'info> {
     pub config: Account<'info, Config>,
 
@@ -37,9 +42,14 @@ pub struct ConfigureBondProduct<'info> {
     )]
     pub vote_account: UncheckedAccount<'info>,
 
+    /// the account is enlarged when the stored configurations do not fit, e.g., the product created
+    /// before the pending config was introduced
     #[account(
         mut,
         has_one = bond @ ErrorCode::BondAccountMismatch,
+        realloc = configure_space(&bond_product, &args.config_data),
+        realloc::payer = authority,
+        realloc::zero = false,
         seeds = [
             b"bond_product",
             bond.key().as_ref(),
@@ -49,8 +59,12 @@ pub struct ConfigureBondProduct<'info> {
     )]
     pub bond_product: Account<'info, BondProduct>,
 
-    /// validator vote account validator identity or bond authority may change the account
+    /// validator vote account validator identity or bond authority may change the account,
+    /// pays for the bond product account reallocation
+    #[account(mut)]
     pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 impl ConfigureBondProduct<'_> {
@@ -58,7 +72,10 @@ impl ConfigureBondProduct<'_> {
         ctx: Context<ConfigureBondProduct>,
         args: ConfigureBondProductArgs,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_FUNDING),
+            ErrorCode::ProgramIsPaused
+        );
 
         require!(
             check_bond_authority(
@@ -74,9 +91,25 @@ impl ConfigureBondProduct<'_> {
         validate_product_config_match(&bond_product.product_type, &args.config_data)?;
         args.config_data.validate()?;
 
+        let clock = Clock::get()?;
+        // a pending config that already took effect becomes the current one
+        bond_product.apply_pending_config(clock.epoch);
         let old_config_data = bond_product.config_data.clone();
 
-        bond_product.config_data = args.config_data.clone();
+        if let Some(effective_from_epoch) = args.effective_from_epoch {
+            require_gt!(
+                effective_from_epoch,
+                clock.epoch,
+                ErrorCode::BondProductEffectiveEpochTooEarly
+            );
+            bond_product.pending_config = Some(PendingProductConfig {
+                config_data: args.config_data.clone(),
+                effective_from_epoch,
+            });
+        } else {
+            bond_product.config_data = args.config_data.clone();
+            bond_product.pending_config = None;
+        }
 
         emit_cpi!(ConfigureBondProductEvent {
             config: ctx.accounts.config.key(),
@@ -86,6 +119,7 @@ impl ConfigureBondProduct<'_> {
             product_type: bond_product.product_type.clone(),
             old_config_data,
             new_config_data: args.config_data,
+            effective_from_epoch: args.effective_from_epoch,
         });
 
         Ok(())
@@ -99,8 +133,29 @@ fn validate_product_config_match(
     match (product_type, config_data) {
         (ProductType::Commission, ProductTypeConfig::Commission(_)) => Ok(()),
         (ProductType::Custom(_), ProductTypeConfig::Custom(_)) => Ok(()),
+        (ProductType::UptimeGuarantee, ProductTypeConfig::UptimeGuarantee(_)) => Ok(()),
+        (ProductType::MaxStakeCap, ProductTypeConfig::MaxStakeCap(_)) => Ok(()),
         _ => Err(error!(ErrorCode::BondProductTypeMismatch)
             .with_values(("product_type", format!("{product_type:?}")))
             .with_values(("config_data", format!("{config_data:?}")))),
     }
 }
+
+/// Space to store any of the current, the pending and the new configuration as both the current and the pending one.
+/// The account is never shrunk.
+fn configure_space(
+    bond_product: &Account<BondProduct>,
+    new_config_data: &ProductTypeConfig,
+) -> usize {
+    let product_type = &bond_product.product_type;
+    let mut space = BondProduct::calculate_space(product_type, new_config_data).max(
+        BondProduct::calculate_space(product_type, &bond_product.config_data),
+    );
+    if let Some(pending) = &bond_product.pending_config {
+        space = space.max(BondProduct::calculate_space(
+            product_type,
+            &pending.config_data,
+        ));
+    }
+    space.max(bond_product.to_account_info().data_len())
+}
@@ -1,4 +1,5 @@
 use crate::checks::check_bond_authority;
+use crate::constants::PAUSE_FUNDING;
 use crate::error::ErrorCode;
 use crate::events::bond_product::InitBondProductEvent;
 use crate::state::bond::Bond;
@@ -72,7 +73,12 @@ pub struct InitBondProduct<'info> {
 
 impl InitBondProduct<'_> {
     pub fn process(ctx: Context<InitBondProduct>, args: InitBondProductArgs) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_FUNDING),
+            ErrorCode::ProgramIsPaused
+        );
+
+        ctx.accounts.bond.open_bond_products += 1;
 
         let bond_product = &mut ctx.accounts.bond_product;
         bond_product.config = ctx.accounts.config.key();
@@ -80,6 +86,8 @@ impl InitBondProduct<'_> {
         bond_product.bond = ctx.accounts.bond.key();
         bond_product.product_type = args.product_type.clone();
         bond_product.bump = ctx.bumps.bond_product;
+        bond_product.pending_config = None;
+        bond_product.counted = true;
 
         let authority = if let Some(authority) = &ctx.accounts.authority {
             // permission-ed: authority is signer, configuration is possible
@@ -120,6 +128,11 @@ fn calculate_space(product_type: &ProductType, config_data: &ProductTypeConfig)
         (product_type, config_data),
         (ProductType::Commission, ProductTypeConfig::Commission(_))
             | (ProductType::Custom(_), ProductTypeConfig::Custom(_))
+            | (
+                ProductType::UptimeGuarantee,
+                ProductTypeConfig::UptimeGuarantee(_)
+            )
+            | (ProductType::MaxStakeCap, ProductTypeConfig::MaxStakeCap(_))
     );
 
     if !valid {
@@ -1,5 +1,7 @@
+pub mod close_bond_product;
 pub mod configure_bond_product;
 pub mod init_bond_product;
 
+pub use close_bond_product::*;
 pub use configure_bond_product::*;
 pub use init_bond_product::*;
@@ -0,0 +1,78 @@
+use crate::error::ErrorCode;
+use crate::events::collateral::ConfigureCollateralMintEvent;
+use crate::events::{BoolValueChange, U64ValueChange};
+use crate::state::collateral_mint::{CollateralMint, MAX_HAIRCUT_BPS};
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ConfigureCollateralMintArgs {
+    pub haircut_bps: Option<u64>,
+    pub enabled: Option<bool>,
+}
+
+/// Configures the haircut of the collateral mint or disables funding the bonds with it.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureCollateralMint<'info> {
+    #[account(
+        has_one = admin_authority @ ErrorCode::InvalidAdminAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// only the admin authority can change the collateral mint params
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"collateral_mint",
+            config.key().as_ref(),
+            collateral_mint.mint.as_ref(),
+        ],
+        bump = collateral_mint.bump,
+    )]
+    pub collateral_mint: Account<'info, CollateralMint>,
+}
+
+impl ConfigureCollateralMint<'_> {
+    pub fn process(
+        ctx: Context<ConfigureCollateralMint>,
+        ConfigureCollateralMintArgs {
+            haircut_bps,
+            enabled,
+        }: ConfigureCollateralMintArgs,
+    ) -> Result<()> {
+        let haircut_bps_change = if let Some(haircut_bps) = haircut_bps {
+            require_gt!(
+                MAX_HAIRCUT_BPS,
+                haircut_bps,
+                ErrorCode::CollateralMintHaircutTooHigh
+            );
+            let old = ctx.accounts.collateral_mint.haircut_bps;
+            ctx.accounts.collateral_mint.haircut_bps = haircut_bps;
+            Some(U64ValueChange {
+                old,
+                new: haircut_bps,
+            })
+        } else {
+            None
+        };
+
+        let enabled_change = enabled.map(|enabled| {
+            let old = ctx.accounts.collateral_mint.enabled;
+            ctx.accounts.collateral_mint.enabled = enabled;
+            BoolValueChange { old, new: enabled }
+        });
+
+        emit_cpi!(ConfigureCollateralMintEvent {
+            collateral_mint: ctx.accounts.collateral_mint.key(),
+            mint: ctx.accounts.collateral_mint.mint,
+            haircut_bps: haircut_bps_change,
+            enabled: enabled_change,
+        });
+
+        Ok(())
+    }
+}
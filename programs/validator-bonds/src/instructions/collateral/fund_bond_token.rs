@@ -0,0 +1,144 @@
+use crate::constants::PAUSE_FUNDING;
+use crate::error::ErrorCode;
+use crate::events::collateral::FundBondTokenEvent;
+use crate::state::bond::Bond;
+use crate::state::collateral_mint::CollateralMint;
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct FundBondTokenArgs {
+    /// amount of tokens to be deposited to the bond token vault
+    pub amount: u64,
+}
+
+/// Funds the bond with tokens of a permitted collateral mint.
+/// The tokens are deposited to the bond token vault owned by the bonds withdrawer authority.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundBondToken<'info> {
+    pub config: Account<'info, Config>,
+
+    /// bond account to be deposited to with the provided tokens
+    #[account(
+        mut,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref()
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        has_one = mint @ ErrorCode::CollateralMintDisabled,
+        constraint = collateral_mint.enabled @ ErrorCode::CollateralMintDisabled,
+        seeds = [
+            b"collateral_mint",
+            config.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump = collateral_mint.bump,
+    )]
+    pub collateral_mint: Account<'info, CollateralMint>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: PDA
+    /// owner of the bond token vault, it's the bonds withdrawer authority
+    #[account(
+        seeds = [
+            b"bonds_authority",
+            config.key().as_ref(),
+        ],
+        bump = config.bonds_withdrawer_authority_bump,
+    )]
+    pub bonds_withdrawer_authority: UncheckedAccount<'info>,
+
+    /// token account of the bond for the particular mint, created on the first funding
+    #[account(
+        init_if_needed,
+        payer = rent_payer,
+        seeds = [
+            b"bond_token_vault",
+            bond.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = bonds_withdrawer_authority,
+        token::token_program = token_program,
+    )]
+    pub bond_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// token account the tokens are deposited from
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub depositor_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// owner (or delegate) of the depositor token account
+    pub depositor_authority: Signer<'info>,
+
+    /// rent exempt payer of the bond token vault account creation
+    #[account(
+        mut,
+        owner = system_program.key()
+    )]
+    pub rent_payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl FundBondToken<'_> {
+    pub fn process(
+        ctx: Context<FundBondToken>,
+        FundBondTokenArgs { amount }: FundBondTokenArgs,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_FUNDING),
+            ErrorCode::ProgramIsPaused
+        );
+
+        let old_vault_amount = ctx.accounts.bond_token_vault.amount;
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.depositor_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.bond_token_vault.to_account_info(),
+                    authority: ctx.accounts.depositor_authority.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+        ctx.accounts.bond_token_vault.reload()?;
+        ctx.accounts
+            .bond
+            .update_funded_token_vaults(old_vault_amount, ctx.accounts.bond_token_vault.amount);
+
+        emit_cpi!(FundBondTokenEvent {
+            bond: ctx.accounts.bond.key(),
+            vote_account: ctx.accounts.bond.vote_account,
+            mint: ctx.accounts.mint.key(),
+            bond_token_vault: ctx.accounts.bond_token_vault.key(),
+            depositor: ctx.accounts.depositor_authority.key(),
+            amount,
+            lamports_value: ctx.accounts.collateral_mint.lamports_value(amount),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use crate::error::ErrorCode;
+use crate::events::collateral::InitCollateralMintEvent;
+use crate::state::collateral_mint::{
+    CollateralMint, COLLATERAL_MINT_DECIMALS, EXCHANGE_RATE_SCALE, MAX_HAIRCUT_BPS,
+};
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct InitCollateralMintArgs {
+    /// haircut applied on the token amount when valued in lamports (basis points)
+    pub haircut_bps: u64,
+}
+
+/// Permits the token mint to be used as a collateral of the bonds under the config.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitCollateralMint<'info> {
+    #[account(
+        has_one = admin_authority @ ErrorCode::InvalidAdminAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// only the admin authority can permit a new collateral mint
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        constraint = mint.decimals == COLLATERAL_MINT_DECIMALS @ ErrorCode::CollateralMintDecimalsMismatch,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<CollateralMint>(),
+        seeds = [
+            b"collateral_mint",
+            config.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub collateral_mint: Account<'info, CollateralMint>,
+
+    /// rent exempt payer of the collateral mint account creation
+    #[account(
+        mut,
+        owner = system_program.key()
+    )]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl InitCollateralMint<'_> {
+    pub fn process(
+        ctx: Context<InitCollateralMint>,
+        InitCollateralMintArgs { haircut_bps }: InitCollateralMintArgs,
+    ) -> Result<()> {
+        require_gt!(
+            MAX_HAIRCUT_BPS,
+            haircut_bps,
+            ErrorCode::CollateralMintHaircutTooHigh
+        );
+
+        ctx.accounts.collateral_mint.set_inner(CollateralMint {
+            config: ctx.accounts.config.key(),
+            mint: ctx.accounts.mint.key(),
+            haircut_bps,
+            enabled: true,
+            bump: ctx.bumps.collateral_mint,
+            // 1:1 until the operator updates the rate, never updated rate does not permit settlement funding
+            exchange_rate: EXCHANGE_RATE_SCALE,
+            exchange_rate_epoch: 0,
+            reserved: [0; 48],
+        });
+
+        emit_cpi!(InitCollateralMintEvent {
+            collateral_mint: ctx.accounts.collateral_mint.key(),
+            config: ctx.accounts.config.key(),
+            mint: ctx.accounts.mint.key(),
+            haircut_bps,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+pub mod configure_collateral_mint;
+pub mod fund_bond_token;
+pub mod init_collateral_mint;
+pub mod update_collateral_exchange_rate;
+pub mod withdraw_bond_token;
+
+pub use configure_collateral_mint::*;
+pub use fund_bond_token::*;
+pub use init_collateral_mint::*;
+pub use update_collateral_exchange_rate::*;
+pub use withdraw_bond_token::*;
@@ -0,0 +1,65 @@
+use crate::error::ErrorCode;
+use crate::events::collateral::UpdateCollateralExchangeRateEvent;
+use crate::events::U64ValueChange;
+use crate::state::collateral_mint::CollateralMint;
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateCollateralExchangeRateArgs {
+    /// lamports value of one token (EXCHANGE_RATE_SCALE of token base units)
+    pub exchange_rate: u64,
+}
+
+/// Updates the exchange rate of the collateral token to SOL for the current epoch.
+/// The settlement funding from the token collateral requires the rate of the current epoch.
+/// Permission-ed to operator authority.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateCollateralExchangeRate<'info> {
+    #[account(
+        has_one = operator_authority @ ErrorCode::InvalidOperatorAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// operator signer authority is allowed to update the exchange rate
+    pub operator_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"collateral_mint",
+            config.key().as_ref(),
+            collateral_mint.mint.as_ref(),
+        ],
+        bump = collateral_mint.bump,
+    )]
+    pub collateral_mint: Account<'info, CollateralMint>,
+}
+
+impl UpdateCollateralExchangeRate<'_> {
+    pub fn process(
+        ctx: Context<UpdateCollateralExchangeRate>,
+        UpdateCollateralExchangeRateArgs { exchange_rate }: UpdateCollateralExchangeRateArgs,
+    ) -> Result<()> {
+        require_gt!(exchange_rate, 0, ErrorCode::InvalidCollateralExchangeRate);
+
+        let clock = Clock::get()?;
+        let old_exchange_rate = ctx.accounts.collateral_mint.exchange_rate;
+        ctx.accounts.collateral_mint.exchange_rate = exchange_rate;
+        ctx.accounts.collateral_mint.exchange_rate_epoch = clock.epoch;
+
+        emit_cpi!(UpdateCollateralExchangeRateEvent {
+            collateral_mint: ctx.accounts.collateral_mint.key(),
+            mint: ctx.accounts.collateral_mint.mint,
+            exchange_rate: U64ValueChange {
+                old: old_exchange_rate,
+                new: exchange_rate,
+            },
+            epoch: clock.epoch,
+        });
+
+        Ok(())
+    }
+}
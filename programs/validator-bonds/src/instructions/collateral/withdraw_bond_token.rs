@@ -0,0 +1,213 @@
+use crate::checks::check_bond_authority;
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, PAUSE_WITHDRAW};
+use crate::error::ErrorCode;
+use crate::events::collateral::WithdrawBondTokenEvent;
+use crate::events::U64ValueChange;
+use crate::state::bond::Bond;
+use crate::state::collateral_mint::CollateralMint;
+use crate::state::config::Config;
+use crate::state::withdraw_request::WithdrawRequest;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::vote::program::ID as vote_program_id;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct WithdrawBondTokenArgs {
+    /// amount of tokens to be withdrawn from the bond token vault
+    pub amount: u64,
+}
+
+/// Withdrawing tokens from the bond token vault requires a withdraw request with elapsed lockup,
+/// the same way as for the stake accounts. The lamports value of the withdrawn tokens
+/// (i.e., by the exchange rate of the current epoch after the haircut, rounded up) is accounted as withdrawn
+/// within the withdraw request.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawBondToken<'info> {
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        has_one = vote_account @ ErrorCode::VoteAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            vote_account.key().as_ref()
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Box<Account<'info, Bond>>,
+
+    /// CHECK: deserialization of the vote account in the code
+    #[account(
+        owner = vote_program_id @ ErrorCode::InvalidVoteAccountProgramId,
+    )]
+    pub vote_account: UncheckedAccount<'info>,
+
+    /// validator vote account node identity or bond authority may withdraw
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = vote_account @ ErrorCode::WithdrawRequestVoteAccountMismatch,
+        has_one = bond @ ErrorCode::BondAccountMismatch,
+        constraint = withdraw_request.epoch + config.withdraw_lockup_epochs < clock.epoch @ ErrorCode::WithdrawRequestNotReady,
+        seeds = [
+            b"withdraw_account",
+            bond.key().as_ref(),
+            withdraw_request.index_seed().as_ref(),
+        ],
+        bump = withdraw_request.bump
+    )]
+    pub withdraw_request: Box<Account<'info, WithdrawRequest>>,
+
+    /// disabled collateral mint still permits withdrawing
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        has_one = mint @ ErrorCode::CollateralMintDisabled,
+        seeds = [
+            b"collateral_mint",
+            config.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump = collateral_mint.bump,
+    )]
+    pub collateral_mint: Box<Account<'info, CollateralMint>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            b"bonds_authority",
+            config.key().as_ref(),
+        ],
+        bump = config.bonds_withdrawer_authority_bump
+    )]
+    pub bonds_withdrawer_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bond_token_vault",
+            bond.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = bonds_withdrawer_authority,
+        token::token_program = token_program,
+    )]
+    pub bond_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// whatever token account of the mint, authority signature states his intention to withdraw the tokens there
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub withdrawer_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl WithdrawBondToken<'_> {
+    pub fn process(
+        ctx: Context<WithdrawBondToken>,
+        WithdrawBondTokenArgs { amount }: WithdrawBondTokenArgs,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_WITHDRAW),
+            ErrorCode::ProgramIsPaused
+        );
+
+        require!(
+            check_bond_authority(
+                &ctx.accounts.authority.key(),
+                &ctx.accounts.bond,
+                &ctx.accounts.vote_account
+            ),
+            ErrorCode::InvalidWithdrawRequestAuthority
+        );
+
+        require_gte!(
+            ctx.accounts.bond_token_vault.amount,
+            amount,
+            ErrorCode::InsufficientBondTokenCollateral
+        );
+        require_eq!(
+            ctx.accounts.collateral_mint.exchange_rate_epoch,
+            ctx.accounts.clock.epoch,
+            ErrorCode::CollateralExchangeRateStale
+        );
+
+        let lamports_value = ctx.accounts.collateral_mint.lamports_value_ceil(amount);
+        let old_withdrawn_amount = ctx.accounts.withdraw_request.withdrawn_amount;
+        let amount_to_withdraw = ctx
+            .accounts
+            .withdraw_request
+            .requested_amount
+            .saturating_sub(old_withdrawn_amount);
+        if lamports_value > amount_to_withdraw {
+            return Err(error!(ErrorCode::WithdrawRequestAmountExceeded)
+                .with_account_name("withdraw_request")
+                .with_values((
+                    "lamports_value > requested_amount - withdrawn_amount",
+                    format!(
+                        "{} > {} - {}",
+                        lamports_value,
+                        ctx.accounts.withdraw_request.requested_amount,
+                        old_withdrawn_amount
+                    ),
+                )));
+        }
+
+        let old_vault_amount = ctx.accounts.bond_token_vault.amount;
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.bond_token_vault.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.withdrawer_token_account.to_account_info(),
+                    authority: ctx.accounts.bonds_withdrawer_authority.to_account_info(),
+                },
+                &[&[
+                    BONDS_WITHDRAWER_AUTHORITY_SEED,
+                    ctx.accounts.config.key().as_ref(),
+                    &[ctx.accounts.config.bonds_withdrawer_authority_bump],
+                ]],
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+        ctx.accounts.bond_token_vault.reload()?;
+        ctx.accounts
+            .bond
+            .update_funded_token_vaults(old_vault_amount, ctx.accounts.bond_token_vault.amount);
+
+        ctx.accounts.withdraw_request.withdrawn_amount += lamports_value;
+
+        emit_cpi!(WithdrawBondTokenEvent {
+            bond: ctx.accounts.bond.key(),
+            vote_account: ctx.accounts.vote_account.key(),
+            withdraw_request: ctx.accounts.withdraw_request.key(),
+            mint: ctx.accounts.mint.key(),
+            bond_token_vault: ctx.accounts.bond_token_vault.key(),
+            withdrawer_token_account: ctx.accounts.withdrawer_token_account.key(),
+            amount,
+            lamports_value,
+            withdrawn_amount: U64ValueChange {
+                old: old_withdrawn_amount,
+                new: ctx.accounts.withdraw_request.withdrawn_amount,
+            },
+        });
+
+        Ok(())
+    }
+}
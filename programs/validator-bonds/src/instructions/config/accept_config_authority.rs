@@ -0,0 +1,78 @@
+use crate::error::ErrorCode;
+use crate::events::{config::ConfigureConfigEvent, PubkeyValueChange};
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+
+/// Accepts the config authority roles proposed to the signer.
+/// When the signer is proposed for multiple roles, all of them are accepted at once.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptConfigAuthority<'info> {
+    #[account(mut)]
+    pub config: Account<'info, Config>,
+
+    /// authority proposed by the admin to take the role
+    pub new_authority: Signer<'info>,
+}
+
+impl AcceptConfigAuthority<'_> {
+    pub fn process(ctx: Context<AcceptConfigAuthority>) -> Result<()> {
+        let new_authority = ctx.accounts.new_authority.key();
+        let config: &mut Config = &mut ctx.accounts.config;
+
+        let admin_authority_change = accept_pending(
+            &mut config.admin_authority,
+            &mut config.pending_admin_authority,
+            &new_authority,
+        );
+        let operator_authority_change = accept_pending(
+            &mut config.operator_authority,
+            &mut config.pending_operator_authority,
+            &new_authority,
+        );
+        let pause_authority_change = accept_pending(
+            &mut config.pause_authority,
+            &mut config.pending_pause_authority,
+            &new_authority,
+        );
+
+        if admin_authority_change.is_none()
+            && operator_authority_change.is_none()
+            && pause_authority_change.is_none()
+        {
+            return Err(error!(ErrorCode::InvalidPendingAuthority)
+                .with_account_name("new_authority")
+                .with_values(("new_authority", new_authority)));
+        }
+
+        emit_cpi!(ConfigureConfigEvent {
+            admin_authority: admin_authority_change,
+            operator_authority: operator_authority_change,
+            pause_authority: pause_authority_change,
+            epochs_to_claim_settlement: None,
+            minimum_stake_lamports: None,
+            withdraw_lockup_epochs: None,
+            slots_to_start_settlement_claiming: None,
+            min_bond_max_stake_wanted: None,
+        });
+
+        Ok(())
+    }
+}
+
+fn accept_pending(
+    authority: &mut Pubkey,
+    pending_authority: &mut Pubkey,
+    new_authority: &Pubkey,
+) -> Option<PubkeyValueChange> {
+    if *pending_authority == Pubkey::default() || pending_authority != new_authority {
+        return None;
+    }
+    let old = *authority;
+    *authority = *new_authority;
+    *pending_authority = Pubkey::default();
+    Some(PubkeyValueChange {
+        old,
+        new: *new_authority,
+    })
+}
@@ -0,0 +1,37 @@
+use crate::error::ErrorCode;
+use crate::events::config::CancelConfigAuthorityEvent;
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+
+/// Cancels all pending (not yet accepted) config authority proposals.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelConfigAuthority<'info> {
+    #[account(
+        mut,
+        has_one = admin_authority @ ErrorCode::InvalidAdminAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// only the admin authority can cancel the proposals
+    pub admin_authority: Signer<'info>,
+}
+
+impl CancelConfigAuthority<'_> {
+    pub fn process(ctx: Context<CancelConfigAuthority>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        emit_cpi!(CancelConfigAuthorityEvent {
+            config: config.key(),
+            pending_admin_authority: config.pending_admin_authority,
+            pending_operator_authority: config.pending_operator_authority,
+            pending_pause_authority: config.pending_pause_authority,
+        });
+
+        config.pending_admin_authority = Pubkey::default();
+        config.pending_operator_authority = Pubkey::default();
+        config.pending_pause_authority = Pubkey::default();
+
+        Ok(())
+    }
+}
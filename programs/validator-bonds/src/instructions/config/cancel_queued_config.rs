@@ -0,0 +1,40 @@
+use crate::error::ErrorCode;
+use crate::events::config::CancelQueuedConfigEvent;
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+
+/// Cancels the queued (not yet executed) change of the lockup-sensitive config params.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelQueuedConfig<'info> {
+    #[account(
+        mut,
+        has_one = admin_authority @ ErrorCode::InvalidAdminAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// only the admin authority can cancel the queued change
+    pub admin_authority: Signer<'info>,
+}
+
+impl CancelQueuedConfig<'_> {
+    pub fn process(ctx: Context<CancelQueuedConfig>) -> Result<()> {
+        let queued_change = ctx
+            .accounts
+            .config
+            .queued_change
+            .take()
+            .ok_or(ErrorCode::NoQueuedConfigChange)?;
+
+        emit_cpi!(CancelQueuedConfigEvent {
+            config: ctx.accounts.config.key(),
+            epochs_to_claim_settlement: queued_change.epochs_to_claim_settlement,
+            withdraw_lockup_epochs: queued_change.withdraw_lockup_epochs,
+            minimum_stake_lamports: queued_change.minimum_stake_lamports,
+            slots_to_start_settlement_claiming: queued_change.slots_to_start_settlement_claiming,
+            activation_epoch: queued_change.activation_epoch,
+        });
+
+        Ok(())
+    }
+}
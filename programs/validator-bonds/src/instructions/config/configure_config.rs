@@ -1,13 +1,15 @@
 use crate::error::ErrorCode;
-use crate::events::{config::ConfigureConfigEvent, PubkeyValueChange, U64ValueChange};
+use crate::events::{config::ConfigureConfigEvent, U64ValueChange};
 use crate::state::config::Config;
 use anchor_lang::prelude::*;
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct ConfigureConfigArgs {
+    /// authorities are not permitted to be changed directly, see propose_config_authority
     pub admin: Option<Pubkey>,
     pub operator: Option<Pubkey>,
     pub pause_authority: Option<Pubkey>,
+    /// lockup-sensitive params are not permitted to be changed directly, see queue_configure_config
     pub epochs_to_claim_settlement: Option<u64>,
     pub withdraw_lockup_epochs: Option<u64>,
     pub minimum_stake_lamports: Option<u64>,
@@ -43,64 +45,19 @@ impl ConfigureConfig<'_> {
             min_bond_max_stake_wanted,
         }: ConfigureConfigArgs,
     ) -> Result<()> {
-        let admin_authority_change = admin.map(|admin| {
-            let old = ctx.accounts.config.admin_authority;
-            ctx.accounts.config.admin_authority = admin;
-            PubkeyValueChange { old, new: admin }
-        });
-
-        let operator_authority_change = operator.map(|operator| {
-            let old = ctx.accounts.config.operator_authority;
-            ctx.accounts.config.operator_authority = operator;
-            PubkeyValueChange { old, new: operator }
-        });
-
-        let pause_authority_change = pause_authority.map(|pause_authority| {
-            let old = ctx.accounts.config.pause_authority;
-            ctx.accounts.config.pause_authority = pause_authority;
-            PubkeyValueChange {
-                old,
-                new: pause_authority,
-            }
-        });
-
-        let epochs_to_claim_settlement_change =
-            epochs_to_claim_settlement.map(|claim_settlement| {
-                let old = ctx.accounts.config.epochs_to_claim_settlement;
-                ctx.accounts.config.epochs_to_claim_settlement = claim_settlement;
-                U64ValueChange {
-                    old,
-                    new: claim_settlement,
-                }
-            });
-
-        let withdraw_lockup_epochs_change = withdraw_lockup_epochs.map(|withdraw_lockup| {
-            let old = ctx.accounts.config.withdraw_lockup_epochs;
-            ctx.accounts.config.withdraw_lockup_epochs = withdraw_lockup;
-            U64ValueChange {
-                old,
-                new: withdraw_lockup,
-            }
-        });
-
-        let minimum_stake_lamports_change = minimum_stake_lamports.map(|minimum_stake| {
-            let old = ctx.accounts.config.minimum_stake_lamports;
-            ctx.accounts.config.minimum_stake_lamports = minimum_stake;
-            U64ValueChange {
-                old,
-                new: minimum_stake,
-            }
-        });
+        // authorities are changed with two-step propose_config_authority and accept_config_authority
+        if admin.is_some() || operator.is_some() || pause_authority.is_some() {
+            return err!(ErrorCode::ConfigAuthorityChangeNotProposed);
+        }
 
-        let slots_to_start_settlement_claiming_change =
-            slots_to_start_settlement_claiming.map(|slots_to_start| {
-                let old = ctx.accounts.config.slots_to_start_settlement_claiming;
-                ctx.accounts.config.slots_to_start_settlement_claiming = slots_to_start;
-                U64ValueChange {
-                    old,
-                    new: slots_to_start,
-                }
-            });
+        // lockup-sensitive params are changed with queue_configure_config and execute_configure_config
+        if epochs_to_claim_settlement.is_some()
+            || withdraw_lockup_epochs.is_some()
+            || minimum_stake_lamports.is_some()
+            || slots_to_start_settlement_claiming.is_some()
+        {
+            return err!(ErrorCode::ConfigChangeRequiresTimelock);
+        }
 
         let min_bond_max_stake_wanted_change =
             min_bond_max_stake_wanted.map(|min_bond_max_stake_wanted| {
@@ -113,13 +70,13 @@ impl ConfigureConfig<'_> {
             });
 
         emit_cpi!(ConfigureConfigEvent {
-            admin_authority: admin_authority_change,
-            operator_authority: operator_authority_change,
-            pause_authority: pause_authority_change,
-            epochs_to_claim_settlement: epochs_to_claim_settlement_change,
-            withdraw_lockup_epochs: withdraw_lockup_epochs_change,
-            minimum_stake_lamports: minimum_stake_lamports_change,
-            slots_to_start_settlement_claiming: slots_to_start_settlement_claiming_change,
+            admin_authority: None,
+            operator_authority: None,
+            pause_authority: None,
+            epochs_to_claim_settlement: None,
+            withdraw_lockup_epochs: None,
+            minimum_stake_lamports: None,
+            slots_to_start_settlement_claiming: None,
             min_bond_max_stake_wanted: min_bond_max_stake_wanted_change,
         });
 
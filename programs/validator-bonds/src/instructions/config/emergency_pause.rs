@@ -1,9 +1,16 @@
+use crate::constants::PAUSE_ALL;
 use crate::error::ErrorCode;
 use crate::events::config::{EmergencyPauseEvent, EmergencyResumeEvent};
 use crate::state::config::Config;
 use anchor_lang::prelude::*;
 
-/// The program can be paused in case of an emergency
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct EmergencyPauseResumeArgs {
+    /// bitmask of operation groups to be paused or resumed (see PAUSE_* constants)
+    pub operations: u8,
+}
+
+/// The program operation groups can be paused in case of an emergency
 #[event_cpi]
 #[derive(Accounts)]
 pub struct EmergencyPauseResume<'info> {
@@ -17,9 +24,17 @@ pub struct EmergencyPauseResume<'info> {
 }
 
 impl EmergencyPauseResume<'_> {
-    pub fn pause(ctx: Context<EmergencyPauseResume>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::AlreadyPaused);
-        ctx.accounts.config.paused = true;
+    pub fn pause(
+        ctx: Context<EmergencyPauseResume>,
+        EmergencyPauseResumeArgs { operations }: EmergencyPauseResumeArgs,
+    ) -> Result<()> {
+        check_operations(operations)?;
+        // only groups that are not paused yet are changed
+        let paused_operations = ctx.accounts.config.paused_operations();
+        let changed_operations = operations & !paused_operations;
+        require!(changed_operations != 0, ErrorCode::AlreadyPaused);
+        // the legacy paused flag is expanded to the operation groups on the first change
+        ctx.accounts.config.paused = paused_operations | operations;
         emit_cpi!(EmergencyPauseEvent {
             config: ctx.accounts.config.key(),
             pause_authority: ctx.accounts.pause_authority.key(),
@@ -28,14 +43,23 @@ impl EmergencyPauseResume<'_> {
             epochs_to_claim_settlement: ctx.accounts.config.epochs_to_claim_settlement,
             withdraw_lockup_epochs: ctx.accounts.config.withdraw_lockup_epochs,
             minimum_stake_lamports: ctx.accounts.config.minimum_stake_lamports,
+            changed_operations,
+            paused_operations: ctx.accounts.config.paused,
         });
 
         Ok(())
     }
 
-    pub fn resume(ctx: Context<EmergencyPauseResume>) -> Result<()> {
-        require!(ctx.accounts.config.paused, ErrorCode::NotPaused);
-        ctx.accounts.config.paused = false;
+    pub fn resume(
+        ctx: Context<EmergencyPauseResume>,
+        EmergencyPauseResumeArgs { operations }: EmergencyPauseResumeArgs,
+    ) -> Result<()> {
+        check_operations(operations)?;
+        // only groups that are paused are changed
+        let paused_operations = ctx.accounts.config.paused_operations();
+        let changed_operations = operations & paused_operations;
+        require!(changed_operations != 0, ErrorCode::NotPaused);
+        ctx.accounts.config.paused = paused_operations & !operations;
         emit_cpi!(EmergencyResumeEvent {
             config: ctx.accounts.config.key(),
             pause_authority: ctx.accounts.pause_authority.key(),
@@ -44,7 +68,17 @@ impl EmergencyPauseResume<'_> {
             epochs_to_claim_settlement: ctx.accounts.config.epochs_to_claim_settlement,
             withdraw_lockup_epochs: ctx.accounts.config.withdraw_lockup_epochs,
             minimum_stake_lamports: ctx.accounts.config.minimum_stake_lamports,
+            changed_operations,
+            paused_operations: ctx.accounts.config.paused,
         });
         Ok(())
     }
 }
+
+fn check_operations(operations: u8) -> Result<()> {
+    if operations == 0 || operations & !PAUSE_ALL != 0 {
+        return Err(error!(ErrorCode::InvalidPauseOperations)
+            .with_values(("operations", format!("{operations:#010b}"))));
+    }
+    Ok(())
+}
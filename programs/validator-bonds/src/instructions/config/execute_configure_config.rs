@@ -0,0 +1,67 @@
+use crate::error::ErrorCode;
+use crate::events::{config::ConfigureConfigEvent, U64ValueChange};
+use crate::state::config::{Config, QueuedConfigChange};
+use anchor_lang::prelude::*;
+
+/// Applies the queued config change once its activation epoch is reached.
+/// Permission-less, the change content was defined by the admin on queueing.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteConfigureConfig<'info> {
+    #[account(mut)]
+    pub config: Account<'info, Config>,
+}
+
+impl ExecuteConfigureConfig<'_> {
+    pub fn process(ctx: Context<ExecuteConfigureConfig>) -> Result<()> {
+        let QueuedConfigChange {
+            epochs_to_claim_settlement,
+            withdraw_lockup_epochs,
+            minimum_stake_lamports,
+            slots_to_start_settlement_claiming,
+            activation_epoch,
+        } = ctx
+            .accounts
+            .config
+            .queued_change
+            .clone()
+            .ok_or(ErrorCode::NoQueuedConfigChange)?;
+
+        let clock = Clock::get()?;
+        require_gte!(
+            clock.epoch,
+            activation_epoch,
+            ErrorCode::QueuedConfigChangeNotActive
+        );
+
+        let config: &mut Config = &mut ctx.accounts.config;
+        config.queued_change = None;
+        let epochs_to_claim_settlement_change = epochs_to_claim_settlement
+            .map(|new| apply_change(&mut config.epochs_to_claim_settlement, new));
+        let withdraw_lockup_epochs_change =
+            withdraw_lockup_epochs.map(|new| apply_change(&mut config.withdraw_lockup_epochs, new));
+        let minimum_stake_lamports_change =
+            minimum_stake_lamports.map(|new| apply_change(&mut config.minimum_stake_lamports, new));
+        let slots_to_start_settlement_claiming_change = slots_to_start_settlement_claiming
+            .map(|new| apply_change(&mut config.slots_to_start_settlement_claiming, new));
+
+        emit_cpi!(ConfigureConfigEvent {
+            admin_authority: None,
+            operator_authority: None,
+            pause_authority: None,
+            epochs_to_claim_settlement: epochs_to_claim_settlement_change,
+            withdraw_lockup_epochs: withdraw_lockup_epochs_change,
+            minimum_stake_lamports: minimum_stake_lamports_change,
+            slots_to_start_settlement_claiming: slots_to_start_settlement_claiming_change,
+            min_bond_max_stake_wanted: None,
+        });
+
+        Ok(())
+    }
+}
+
+fn apply_change(value: &mut u64, new: u64) -> U64ValueChange {
+    let old = *value;
+    *value = new;
+    U64ValueChange { old, new }
+}
@@ -54,10 +54,14 @@ impl InitConfig<'_> {
             minimum_stake_lamports: MIN_STAKE_LAMPORTS,
             bonds_withdrawer_authority_bump,
             pause_authority: admin_authority,
-            paused: false,
+            paused: 0,
             slots_to_start_settlement_claiming,
             min_bond_max_stake_wanted: 0,
-            reserved: [0; 463],
+            pending_admin_authority: Pubkey::default(),
+            pending_operator_authority: Pubkey::default(),
+            pending_pause_authority: Pubkey::default(),
+            queued_change: None,
+            reserved: [0; 322],
         });
 
         emit_cpi!(InitConfigEvent {
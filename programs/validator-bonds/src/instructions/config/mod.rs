@@ -1,7 +1,19 @@
+pub mod accept_config_authority;
+pub mod cancel_config_authority;
+pub mod cancel_queued_config;
 pub mod configure_config;
 pub mod emergency_pause;
+pub mod execute_configure_config;
 pub mod init_config;
+pub mod propose_config_authority;
+pub mod queue_configure_config;
 
+pub use accept_config_authority::*;
+pub use cancel_config_authority::*;
+pub use cancel_queued_config::*;
 pub use configure_config::*;
 pub use emergency_pause::*;
+pub use execute_configure_config::*;
 pub use init_config::*;
+pub use propose_config_authority::*;
+pub use queue_configure_config::*;
@@ -0,0 +1,57 @@
+use crate::error::ErrorCode;
+use crate::events::config::ProposeConfigAuthorityEvent;
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ProposeConfigAuthorityArgs {
+    pub admin: Option<Pubkey>,
+    pub operator: Option<Pubkey>,
+    pub pause_authority: Option<Pubkey>,
+}
+
+/// Proposes new config authorities. A proposed authority takes the role
+/// only after accepting it by signing accept_config_authority.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeConfigAuthority<'info> {
+    #[account(
+        mut,
+        has_one = admin_authority @ ErrorCode::InvalidAdminAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// only the admin authority can propose new authorities
+    pub admin_authority: Signer<'info>,
+}
+
+impl ProposeConfigAuthority<'_> {
+    pub fn process(
+        ctx: Context<ProposeConfigAuthority>,
+        ProposeConfigAuthorityArgs {
+            admin,
+            operator,
+            pause_authority,
+        }: ProposeConfigAuthorityArgs,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        if let Some(admin) = admin {
+            config.pending_admin_authority = admin;
+        }
+        if let Some(operator) = operator {
+            config.pending_operator_authority = operator;
+        }
+        if let Some(pause_authority) = pause_authority {
+            config.pending_pause_authority = pause_authority;
+        }
+
+        emit_cpi!(ProposeConfigAuthorityEvent {
+            config: ctx.accounts.config.key(),
+            pending_admin_authority: admin,
+            pending_operator_authority: operator,
+            pending_pause_authority: pause_authority,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use crate::error::ErrorCode;
+use crate::events::config::QueueConfigureConfigEvent;
+use crate::state::config::{Config, QueuedConfigChange};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct QueueConfigureConfigArgs {
+    pub epochs_to_claim_settlement: Option<u64>,
+    pub withdraw_lockup_epochs: Option<u64>,
+    pub minimum_stake_lamports: Option<u64>,
+    pub slots_to_start_settlement_claiming: Option<u64>,
+    /// epoch when the change may be executed, at least the withdraw lockup epochs ahead
+    pub activation_epoch: u64,
+}
+
+/// Queues change of the config params that define the terms the validators agreed to.
+/// The activation epoch has to be far enough to let validators withdraw under the current terms.
+/// Queueing a new change replaces the previously queued one.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct QueueConfigureConfig<'info> {
+    #[account(
+        mut,
+        has_one = admin_authority @ ErrorCode::InvalidAdminAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// only the admin authority can queue the config change
+    pub admin_authority: Signer<'info>,
+}
+
+impl QueueConfigureConfig<'_> {
+    pub fn process(
+        ctx: Context<QueueConfigureConfig>,
+        QueueConfigureConfigArgs {
+            epochs_to_claim_settlement,
+            withdraw_lockup_epochs,
+            minimum_stake_lamports,
+            slots_to_start_settlement_claiming,
+            activation_epoch,
+        }: QueueConfigureConfigArgs,
+    ) -> Result<()> {
+        if epochs_to_claim_settlement.is_none()
+            && withdraw_lockup_epochs.is_none()
+            && minimum_stake_lamports.is_none()
+            && slots_to_start_settlement_claiming.is_none()
+        {
+            return err!(ErrorCode::NoQueuedConfigChange);
+        }
+
+        // withdraw request created in the current epoch has to be claimable before the activation
+        let clock = Clock::get()?;
+        let min_activation_epoch = clock.epoch + ctx.accounts.config.withdraw_lockup_epochs + 1;
+        if activation_epoch < min_activation_epoch {
+            return Err(
+                error!(ErrorCode::ConfigChangeActivationTooEarly).with_values((
+                    "activation_epoch < current_epoch + withdraw_lockup_epochs + 1",
+                    format!(
+                        "{} < {} + {} + 1",
+                        activation_epoch, clock.epoch, ctx.accounts.config.withdraw_lockup_epochs
+                    ),
+                )),
+            );
+        }
+
+        ctx.accounts.config.queued_change = Some(QueuedConfigChange {
+            epochs_to_claim_settlement,
+            withdraw_lockup_epochs,
+            minimum_stake_lamports,
+            slots_to_start_settlement_claiming,
+            activation_epoch,
+        });
+
+        emit_cpi!(QueueConfigureConfigEvent {
+            config: ctx.accounts.config.key(),
+            epochs_to_claim_settlement,
+            withdraw_lockup_epochs,
+            minimum_stake_lamports,
+            slots_to_start_settlement_claiming,
+            activation_epoch,
+        });
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 pub mod bond;
 pub mod bond_product;
+pub mod collateral;
 pub mod config;
 pub mod settlement;
 pub mod stake;
@@ -9,6 +10,7 @@ pub mod v1;
 
 pub use bond::*;
 pub use bond_product::*;
+pub use collateral::*;
 pub use config::*;
 pub use settlement::*;
 pub use stake::*;
@@ -1,16 +1,18 @@
+use crate::constants::PAUSE_SETTLEMENT;
 use crate::error::ErrorCode;
 use crate::events::settlement::CancelSettlementEvent;
 use crate::instructions::withdraw_refund_stake_account;
 use crate::state::bond::Bond;
 use crate::state::config::Config;
 use crate::state::settlement::Settlement;
-use crate::state::settlement_claims::SettlementClaims;
+use crate::state::settlement_claims::{close_settlement_claims_chunks, SettlementClaims};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::stake_history;
 use anchor_spl::stake::Stake;
 
 /// Cancel Settlement by closing the settlement account.
 /// Whoever can close Settlement by 'close_settlement' when the epoch expires.
+/// The SettlementClaims chunk accounts, when created, have to be passed as remaining accounts.
 #[event_cpi]
 #[derive(Accounts)]
 pub struct CancelSettlement<'info> {
@@ -22,6 +24,7 @@ pub struct CancelSettlement<'info> {
     pub config: Account<'info, Config>,
 
     #[account(
+        mut,
         has_one = config @ ErrorCode::ConfigAccountMismatch,
         seeds = [
             b"bond_account",
@@ -41,7 +44,7 @@ pub struct CancelSettlement<'info> {
         seeds = [
             b"settlement_account",
             bond.key().as_ref(),
-            settlement.merkle_root.as_ref(),
+            settlement.pda_seed().as_ref(),
             settlement.epoch_created_for.to_le_bytes().as_ref(),
         ],
         bump = settlement.bumps.pda,
@@ -96,9 +99,28 @@ pub struct CancelSettlement<'info> {
     pub stake_history: UncheckedAccount<'info>,
 }
 
-impl CancelSettlement<'_> {
-    pub fn process(ctx: Context<CancelSettlement>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+impl<'info> CancelSettlement<'info> {
+    pub fn process(ctx: Context<'_, '_, '_, 'info, CancelSettlement<'info>>) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
+
+        if ctx.accounts.settlement.counted {
+            ctx.accounts.bond.open_settlements = ctx
+                .accounts
+                .bond
+                .open_settlements
+                .checked_sub(1)
+                .ok_or(ErrorCode::BondCounterUnderflow)?;
+        }
+
+        // chunks of the claims bitmap are passed as remaining accounts
+        close_settlement_claims_chunks(
+            &ctx.accounts.settlement_claims,
+            ctx.remaining_accounts,
+            &ctx.accounts.rent_collector,
+        )?;
 
         if ctx.accounts.settlement.split_rent_collector.is_some() {
             withdraw_refund_stake_account(
@@ -1,17 +1,19 @@
 use crate::checks::{
     check_stake_is_initialized_with_withdrawer_authority, check_stake_is_not_locked,
 };
-use crate::constants::BONDS_WITHDRAWER_AUTHORITY_SEED;
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, PAUSE_CLAIM};
 use crate::error::ErrorCode;
 use crate::events::settlement_claim::ClaimSettlementV2Event;
 use crate::events::U64ValueChange;
 use crate::state::bond::Bond;
+use crate::state::bond_ledger::{bond_ledger_account, update_bond_ledger};
 use crate::state::config::Config;
 use crate::state::settlement::Settlement;
 use crate::state::settlement_claims::{SettlementClaims, SettlementClaimsWrapped};
 use crate::utils::{merkle_proof, minimal_size_stake_account};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::stake::state::Meta;
 use anchor_lang::solana_program::sysvar::stake_history;
 use anchor_spl::stake::{withdraw, Stake, StakeAccount, Withdraw};
 use merkle_tree::psr_claim::TreeNode;
@@ -36,6 +38,7 @@ pub struct ClaimSettlementV2Args {
 /// Claims a settlement by withdrawing settlement funded stake account
 /// Note that stakes-etl depends on the name of this ix during parsing
 /// Details: https://github.com/marinade-finance/stakes-etl/pull/37/files#diff-ad9542b32b7cdc67ba56f59d71997f611c0ba3ad23bfb024822e5c52304145f7R32
+/// The optional bond ledger may be passed as the remaining account to record the claimed lamports.
 #[event_cpi]
 #[derive(Accounts)]
 #[instruction(params: ClaimSettlementV2Args)]
@@ -57,12 +60,12 @@ pub struct ClaimSettlementV2<'info> {
     #[account(
         mut,
         has_one = bond @ ErrorCode::BondAccountMismatch,
-        constraint = settlement.epoch_created_for + config.epochs_to_claim_settlement >= clock.epoch @ ErrorCode::SettlementExpired,
+        constraint = settlement.expiration_epoch(&config) >= clock.epoch @ ErrorCode::SettlementExpired,
         constraint = settlement.slot_created_at + config.slots_to_start_settlement_claiming <= clock.slot @ ErrorCode::SettlementNotReadyForClaiming,
         seeds = [
             b"settlement_account",
             bond.key().as_ref(),
-            settlement.merkle_root.as_ref(),
+            settlement.pda_seed().as_ref(),
             settlement.epoch_created_for.to_le_bytes().as_ref(),
         ],
         bump = settlement.bumps.pda,
@@ -70,14 +73,16 @@ pub struct ClaimSettlementV2<'info> {
     pub settlement: Account<'info, Settlement>,
 
     /// deduplication, merkle tree record cannot be claimed twice
+    /// (for chunked bitmap the chunk account covering the claimed index)
     #[account(
         mut,
         has_one = settlement @ ErrorCode::BondAccountMismatch,
         seeds = [
             b"claims_account",
             settlement.key().as_ref(),
+            settlement_claims.chunk_seed().as_ref(),
         ],
-        bump = settlement.bumps.settlement_claims,
+        bump = settlement_claims.pda_bump(&settlement),
     )]
     pub settlement_claims: Account<'info, SettlementClaims>,
 
@@ -124,7 +129,10 @@ impl ClaimSettlementV2<'_> {
             index,
         }: ClaimSettlementV2Args,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_CLAIM),
+            ErrorCode::ProgramIsPaused
+        );
 
         let mut settlement_claims = SettlementClaimsWrapped::new(&ctx.accounts.settlement_claims)?;
         require!(
@@ -132,7 +140,6 @@ impl ClaimSettlementV2<'_> {
             ErrorCode::SettlementAlreadyClaimed
         );
 
-        // settlement_claim PDA address verification
         let tree_node = TreeNode {
             stake_authority: stake_account_staker,
             withdraw_authority: stake_account_withdrawer,
@@ -140,45 +147,8 @@ impl ClaimSettlementV2<'_> {
             index,
             proof: None,
         };
-        let tree_node_bytes = tree_node.hash().to_bytes();
-        if tree_node_bytes != tree_node_hash_args {
-            return Err(
-                error!(ErrorCode::ClaimSettlementMerkleTreeNodeMismatch).with_values((
-                    "tree_node_bytes vs. tree_node_hash_args",
-                    format!("'{tree_node_bytes:?}' vs. '{tree_node_hash_args:?}'"),
-                )),
-            );
-        }
-
-        if ctx.accounts.settlement.lamports_claimed + claim
-            > ctx.accounts.settlement.max_total_claim
-        {
-            return Err(error!(ErrorCode::ClaimAmountExceedsMaxTotalClaim)
-                .with_account_name("settlement")
-                .with_values((
-                    "lamports_claimed + claim > max_total_claim",
-                    format!(
-                        "{} + {} <= {}",
-                        ctx.accounts.settlement.lamports_claimed,
-                        claim,
-                        ctx.accounts.settlement.max_total_claim
-                    ),
-                )));
-        }
-        if ctx.accounts.settlement.merkle_nodes_claimed + 1
-            > ctx.accounts.settlement.max_merkle_nodes
-        {
-            return Err(error!(ErrorCode::ClaimCountExceedsMaxMerkleNodes)
-                .with_account_name("settlement")
-                .with_values((
-                    "merkle_nodes_claimed + 1 > max_merkle_nodes",
-                    format!(
-                        "{} + 1 > {}",
-                        ctx.accounts.settlement.merkle_nodes_claimed,
-                        ctx.accounts.settlement.max_merkle_nodes
-                    ),
-                )));
-        }
+        check_tree_node_hash(&tree_node, tree_node_hash_args)?;
+        check_settlement_claim_limits(&ctx.accounts.settlement, claim, 1)?;
 
         // stake account is managed by bonds program
         let stake_from_meta = check_stake_is_initialized_with_withdrawer_authority(
@@ -212,39 +182,13 @@ impl ClaimSettlementV2<'_> {
             "stake_account_to",
         )?;
 
-        // The provided stake account must be sufficiently large to cover the claim while remaining valid.
-        // It is the caller's responsibility to merge stake accounts if necessary.
-        // - The invariant is that the stake account will always be rent-exempt and of minimum size.
-        //   This must be ensured by the fund_settlement instruction.
-        if ctx.accounts.stake_account_from.get_lamports()
-            < claim + minimal_size_stake_account(&stake_from_meta, &ctx.accounts.config) &&
-            // on perfect match when stake account lamports is equal to the claim amount we can withdraw all
-            ctx.accounts.stake_account_from.get_lamports() != claim
-        {
-            return Err(error!(ErrorCode::ClaimingStakeAccountLamportsInsufficient)
-                .with_account_name("stake_account_from")
-                .with_values((
-                    "stake_account_from_lamports < claim_amount + minimal_size_stake_account",
-                    format!(
-                        "{} < {} + {}",
-                        ctx.accounts.stake_account_from.get_lamports(),
-                        claim,
-                        minimal_size_stake_account(&stake_from_meta, &ctx.accounts.config)
-                    ),
-                )));
-        }
-
-        let tree_node_hash = tree_node.hash().to_bytes();
-        if !merkle_proof::verify(
-            proof,
-            ctx.accounts.settlement.merkle_root,
-            hash_leaf!(tree_node_hash).to_bytes(),
-        ) {
-            return Err(error!(ErrorCode::ClaimSettlementProofFailed).with_values((
-                "Merkle proof verification failed",
-                format!("Tree node: {:?}, hash: {}", tree_node, tree_node.hash()),
-            )));
-        }
+        check_stake_account_from_covers_claim(
+            &ctx.accounts.stake_account_from,
+            &stake_from_meta,
+            &ctx.accounts.config,
+            claim,
+        )?;
+        verify_settlement_claim_proof(proof, &ctx.accounts.settlement, &tree_node)?;
 
         withdraw(
             CpiContext::new_with_signer(
@@ -268,6 +212,12 @@ impl ClaimSettlementV2<'_> {
 
         ctx.accounts.settlement.lamports_claimed += claim;
         ctx.accounts.settlement.merkle_nodes_claimed += 1;
+        update_bond_ledger(
+            bond_ledger_account(ctx.remaining_accounts, &ctx.accounts.bond)?,
+            &ctx.accounts.bond.key(),
+            ctx.accounts.clock.epoch,
+            |record| record.claimed_lamports += claim,
+        )?;
 
         emit_cpi!(ClaimSettlementV2Event {
             settlement: ctx.accounts.settlement.key(),
@@ -286,3 +236,95 @@ impl ClaimSettlementV2<'_> {
         Ok(())
     }
 }
+
+/// settlement_claim PDA address verification
+pub fn check_tree_node_hash(tree_node: &TreeNode, tree_node_hash_args: [u8; 32]) -> Result<()> {
+    let tree_node_bytes = tree_node.hash().to_bytes();
+    if tree_node_bytes != tree_node_hash_args {
+        return Err(
+            error!(ErrorCode::ClaimSettlementMerkleTreeNodeMismatch).with_values((
+                "tree_node_bytes vs. tree_node_hash_args",
+                format!("'{tree_node_bytes:?}' vs. '{tree_node_hash_args:?}'"),
+            )),
+        );
+    }
+    Ok(())
+}
+
+pub fn check_settlement_claim_limits(
+    settlement: &Settlement,
+    claim: u64,
+    merkle_nodes: u64,
+) -> Result<()> {
+    if settlement.lamports_claimed + claim > settlement.max_total_claim {
+        return Err(error!(ErrorCode::ClaimAmountExceedsMaxTotalClaim)
+            .with_account_name("settlement")
+            .with_values((
+                "lamports_claimed + claim > max_total_claim",
+                format!(
+                    "{} + {} <= {}",
+                    settlement.lamports_claimed, claim, settlement.max_total_claim
+                ),
+            )));
+    }
+    if settlement.merkle_nodes_claimed + merkle_nodes > settlement.max_merkle_nodes {
+        return Err(error!(ErrorCode::ClaimCountExceedsMaxMerkleNodes)
+            .with_account_name("settlement")
+            .with_values((
+                "merkle_nodes_claimed + merkle_nodes > max_merkle_nodes",
+                format!(
+                    "{} + {} > {}",
+                    settlement.merkle_nodes_claimed, merkle_nodes, settlement.max_merkle_nodes
+                ),
+            )));
+    }
+    Ok(())
+}
+
+/// The provided stake account must be sufficiently large to cover the claim while remaining valid.
+/// It is the caller's responsibility to merge stake accounts if necessary.
+/// - The invariant is that the stake account will always be rent-exempt and of minimum size.
+///   This must be ensured by the fund_settlement instruction.
+pub fn check_stake_account_from_covers_claim(
+    stake_account_from: &Account<StakeAccount>,
+    stake_from_meta: &Meta,
+    config: &Config,
+    claim: u64,
+) -> Result<()> {
+    if stake_account_from.get_lamports() < claim + minimal_size_stake_account(stake_from_meta, config) &&
+        // on perfect match when stake account lamports is equal to the claim amount we can withdraw all
+        stake_account_from.get_lamports() != claim
+    {
+        return Err(error!(ErrorCode::ClaimingStakeAccountLamportsInsufficient)
+            .with_account_name("stake_account_from")
+            .with_values((
+                "stake_account_from_lamports < claim_amount + minimal_size_stake_account",
+                format!(
+                    "{} < {} + {}",
+                    stake_account_from.get_lamports(),
+                    claim,
+                    minimal_size_stake_account(stake_from_meta, config)
+                ),
+            )));
+    }
+    Ok(())
+}
+
+pub fn verify_settlement_claim_proof(
+    proof: Vec<[u8; 32]>,
+    settlement: &Settlement,
+    tree_node: &TreeNode,
+) -> Result<()> {
+    let tree_node_hash = tree_node.hash().to_bytes();
+    if !merkle_proof::verify(
+        proof,
+        settlement.merkle_root,
+        hash_leaf!(tree_node_hash).to_bytes(),
+    ) {
+        return Err(error!(ErrorCode::ClaimSettlementProofFailed).with_values((
+            "Merkle proof verification failed",
+            format!("Tree node: {:?}, hash: {}", tree_node, tree_node.hash()),
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,257 @@
+use crate::checks::{
+    check_stake_is_initialized_with_withdrawer_authority, check_stake_is_not_locked,
+};
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, PAUSE_CLAIM};
+use crate::error::ErrorCode;
+use crate::events::settlement_claim::ClaimSettlementBatchEvent;
+use crate::events::U64ValueChange;
+use crate::instructions::{check_settlement_claim_limits, check_stake_account_from_covers_claim};
+use crate::state::bond::Bond;
+use crate::state::bond_ledger::{bond_ledger_account, update_bond_ledger};
+use crate::state::config::Config;
+use crate::state::settlement::Settlement;
+use crate::state::settlement_claims::{SettlementClaims, SettlementClaimsWrapped};
+use crate::utils::merkle_proof;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::sysvar::stake_history;
+use anchor_spl::stake::{withdraw, Stake, StakeAccount, Withdraw};
+use merkle_tree::psr_claim::TreeNode;
+use merkle_tree::{hash_leaf, LEAF_PREFIX};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ClaimSettlementBatchItem {
+    /// claim amount; merkle root verification
+    pub claim: u64,
+    /// index, ordered claim record in the settlement list; merkle root verification
+    pub index: u64,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ClaimSettlementBatchArgs {
+    /// multi-proof that all the claims are appropriate, siblings shared by the claims are included once
+    pub proof: Vec<[u8; 32]>,
+    /// staker authority of the stake_account_to; merkle root verification of all claims
+    pub stake_account_staker: Pubkey,
+    /// withdrawer authority of the stake_account_to; merkle root verification of all claims
+    pub stake_account_withdrawer: Pubkey,
+    /// claims of the stake and withdraw authorities paid into the stake_account_to
+    pub claims: Vec<ClaimSettlementBatchItem>,
+}
+
+/// Claims several merkle tree nodes of a settlement by one stake account withdrawal.
+/// All the claims are paid into the same stake account, i.e., the tree nodes share
+/// the stake and withdraw authorities of the stake_account_to.
+/// The optional bond ledger may be passed as the remaining account to record the claimed lamports.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimSettlementBatch<'info> {
+    /// the config account under which the settlement was created
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        has_one = bond @ ErrorCode::BondAccountMismatch,
+        constraint = settlement.expiration_epoch(&config) >= clock.epoch @ ErrorCode::SettlementExpired,
+        constraint = settlement.slot_created_at + config.slots_to_start_settlement_claiming <= clock.slot @ ErrorCode::SettlementNotReadyForClaiming,
+        seeds = [
+            b"settlement_account",
+            bond.key().as_ref(),
+            settlement.pda_seed().as_ref(),
+            settlement.epoch_created_for.to_le_bytes().as_ref(),
+        ],
+        bump = settlement.bumps.pda,
+    )]
+    pub settlement: Account<'info, Settlement>,
+
+    /// deduplication, merkle tree record cannot be claimed twice
+    /// (for chunked bitmap the chunk account covering the claimed index)
+    #[account(
+        mut,
+        has_one = settlement @ ErrorCode::BondAccountMismatch,
+        seeds = [
+            b"claims_account",
+            settlement.key().as_ref(),
+            settlement_claims.chunk_seed().as_ref(),
+        ],
+        bump = settlement_claims.pda_bump(&settlement),
+    )]
+    pub settlement_claims: Account<'info, SettlementClaims>,
+
+    /// a stake account that will be withdrawn
+    #[account(mut)]
+    pub stake_account_from: Box<Account<'info, StakeAccount>>,
+
+    /// a stake account that will receive the funds
+    #[account(
+        mut,
+        constraint = stake_account_from.key() != stake_account_to.key() @ ErrorCode::MergeMismatchSameSourceDestination
+    )]
+    pub stake_account_to: Box<Account<'info, StakeAccount>>,
+
+    /// CHECK: PDA
+    /// authority that manages (owns == by being withdrawer authority) all stakes account under the bonds program
+    #[account(
+        seeds = [
+            b"bonds_authority",
+            config.key().as_ref(),
+        ],
+        bump = config.bonds_withdrawer_authority_bump
+    )]
+    pub bonds_withdrawer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: have no CPU budget to parse
+    #[account(address = stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    pub stake_program: Program<'info, Stake>,
+}
+
+impl ClaimSettlementBatch<'_> {
+    pub fn process(
+        ctx: Context<ClaimSettlementBatch>,
+        ClaimSettlementBatchArgs {
+            proof,
+            stake_account_staker,
+            stake_account_withdrawer,
+            claims,
+        }: ClaimSettlementBatchArgs,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_CLAIM),
+            ErrorCode::ProgramIsPaused
+        );
+        require!(!claims.is_empty(), ErrorCode::EmptyClaimBatch);
+
+        let mut total_claim: u64 = 0;
+        let mut indexes: Vec<u64> = Vec::with_capacity(claims.len());
+        let mut leaves: Vec<(u64, [u8; 32])> = Vec::with_capacity(claims.len());
+        {
+            let mut settlement_claims =
+                SettlementClaimsWrapped::new(&ctx.accounts.settlement_claims)?;
+            for ClaimSettlementBatchItem { claim, index } in claims {
+                require!(
+                    settlement_claims.try_to_set(index)?,
+                    ErrorCode::SettlementAlreadyClaimed
+                );
+                let tree_node_hash = TreeNode {
+                    stake_authority: stake_account_staker,
+                    withdraw_authority: stake_account_withdrawer,
+                    claim,
+                    index,
+                    proof: None,
+                }
+                .hash();
+                leaves.push((index, hash_leaf!(tree_node_hash).to_bytes()));
+                total_claim = total_claim
+                    .checked_add(claim)
+                    .ok_or(ErrorCode::ClaimAmountExceedsMaxTotalClaim)?;
+                indexes.push(index);
+            }
+        }
+        if !merkle_proof::verify_multi(proof, ctx.accounts.settlement.merkle_root, leaves) {
+            return Err(error!(ErrorCode::ClaimSettlementProofFailed).with_values((
+                "Merkle multi-proof verification failed",
+                format!("Tree node indexes: {indexes:?}"),
+            )));
+        }
+        check_settlement_claim_limits(&ctx.accounts.settlement, total_claim, indexes.len() as u64)?;
+
+        // stake account is managed by bonds program
+        let stake_from_meta = check_stake_is_initialized_with_withdrawer_authority(
+            &ctx.accounts.stake_account_from,
+            &ctx.accounts.bonds_withdrawer_authority.key(),
+            "stake_account_from",
+        )?;
+        // provided stake account "from" must be funded; staker == settlement staker authority
+        require_keys_eq!(
+            stake_from_meta.authorized.staker,
+            ctx.accounts.settlement.staker_authority,
+            ErrorCode::StakeAccountNotFundedToSettlement,
+        );
+
+        // stake account "to" for withdrawing funds to has to match merkle proof data
+        let stake_to_meta = check_stake_is_initialized_with_withdrawer_authority(
+            &ctx.accounts.stake_account_to,
+            &stake_account_withdrawer,
+            "stake_account_to",
+        )?;
+        require_keys_eq!(
+            stake_to_meta.authorized.staker,
+            stake_account_staker,
+            ErrorCode::WrongStakeAccountStaker,
+        );
+        // an attacker could create a locked stake account with the victims stake/withdraw authorities,
+        // then claiming the settlement, and extort the victim to unlock the stake account
+        check_stake_is_not_locked(
+            &ctx.accounts.stake_account_to,
+            &ctx.accounts.clock,
+            "stake_account_to",
+        )?;
+
+        check_stake_account_from_covers_claim(
+            &ctx.accounts.stake_account_from,
+            &stake_from_meta,
+            &ctx.accounts.config,
+            total_claim,
+        )?;
+
+        withdraw(
+            CpiContext::new_with_signer(
+                ctx.accounts.stake_program.to_account_info(),
+                Withdraw {
+                    stake: ctx.accounts.stake_account_from.to_account_info(),
+                    withdrawer: ctx.accounts.bonds_withdrawer_authority.to_account_info(),
+                    to: ctx.accounts.stake_account_to.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                    stake_history: ctx.accounts.stake_history.to_account_info(),
+                },
+                &[&[
+                    BONDS_WITHDRAWER_AUTHORITY_SEED,
+                    ctx.accounts.config.key().as_ref(),
+                    &[ctx.accounts.config.bonds_withdrawer_authority_bump],
+                ]],
+            ),
+            total_claim,
+            None,
+        )?;
+
+        ctx.accounts.settlement.lamports_claimed += total_claim;
+        ctx.accounts.settlement.merkle_nodes_claimed += indexes.len() as u64;
+        update_bond_ledger(
+            bond_ledger_account(ctx.remaining_accounts, &ctx.accounts.bond)?,
+            &ctx.accounts.bond.key(),
+            ctx.accounts.clock.epoch,
+            |record| record.claimed_lamports += total_claim,
+        )?;
+
+        emit_cpi!(ClaimSettlementBatchEvent {
+            settlement: ctx.accounts.settlement.key(),
+            stake_account_to: ctx.accounts.stake_account_to.key(),
+            settlement_lamports_claimed: U64ValueChange {
+                old: ctx.accounts.settlement.lamports_claimed - total_claim,
+                new: ctx.accounts.settlement.lamports_claimed
+            },
+            settlement_merkle_nodes_claimed: ctx.accounts.settlement.merkle_nodes_claimed,
+            stake_account_staker,
+            stake_account_withdrawer,
+            amount: total_claim,
+            indexes,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,230 @@
+use crate::checks::{
+    check_stake_is_initialized_with_withdrawer_authority, deserialize_stake_account,
+};
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, PAUSE_CLAIM};
+use crate::error::ErrorCode;
+use crate::events::settlement_claim::ClaimSettlementRedirectEvent;
+use crate::events::U64ValueChange;
+use crate::instructions::{
+    check_settlement_claim_limits, check_stake_account_from_covers_claim, check_tree_node_hash,
+    verify_settlement_claim_proof, ClaimSettlementV2Args,
+};
+use crate::state::bond::Bond;
+use crate::state::bond_ledger::{bond_ledger_account, update_bond_ledger};
+use crate::state::config::Config;
+use crate::state::settlement::Settlement;
+use crate::state::settlement_claims::{SettlementClaims, SettlementClaimsWrapped};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::stake::program::ID as stake_program_id;
+use anchor_lang::solana_program::system_program::ID as system_program_id;
+use anchor_lang::solana_program::sysvar::stake_history;
+use anchor_spl::stake::{withdraw, Stake, StakeAccount, Withdraw};
+use merkle_tree::psr_claim::TreeNode;
+
+/// Claims a settlement on behalf of the merkle tree node withdraw authority.
+/// The withdraw authority signs and the claim is paid to any system account
+/// or to any stake account the withdraw authority is the withdrawer of.
+/// The merkle tree node is marked as claimed in the same way as with [ClaimSettlementV2].
+/// The optional bond ledger may be passed as the remaining account to record the claimed lamports.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: ClaimSettlementV2Args)]
+pub struct ClaimSettlementRedirect<'info> {
+    /// the config account under which the settlement was created
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        has_one = bond @ ErrorCode::BondAccountMismatch,
+        constraint = settlement.expiration_epoch(&config) >= clock.epoch @ ErrorCode::SettlementExpired,
+        constraint = settlement.slot_created_at + config.slots_to_start_settlement_claiming <= clock.slot @ ErrorCode::SettlementNotReadyForClaiming,
+        seeds = [
+            b"settlement_account",
+            bond.key().as_ref(),
+            settlement.pda_seed().as_ref(),
+            settlement.epoch_created_for.to_le_bytes().as_ref(),
+        ],
+        bump = settlement.bumps.pda,
+    )]
+    pub settlement: Account<'info, Settlement>,
+
+    /// deduplication, merkle tree record cannot be claimed twice
+    /// (for chunked bitmap the chunk account covering the claimed index)
+    #[account(
+        mut,
+        has_one = settlement @ ErrorCode::BondAccountMismatch,
+        seeds = [
+            b"claims_account",
+            settlement.key().as_ref(),
+            settlement_claims.chunk_seed().as_ref(),
+        ],
+        bump = settlement_claims.pda_bump(&settlement),
+    )]
+    pub settlement_claims: Account<'info, SettlementClaims>,
+
+    /// a stake account that will be withdrawn
+    #[account(mut)]
+    pub stake_account_from: Box<Account<'info, StakeAccount>>,
+
+    /// withdraw authority of the merkle tree node; merkle root verification
+    #[account(
+        address = params.stake_account_withdrawer @ ErrorCode::WrongStakeAccountWithdrawer,
+    )]
+    pub withdraw_authority: Signer<'info>,
+
+    /// CHECK: system account or stake account (withdrawer == withdraw_authority), verified in the code
+    /// an account that will receive the funds
+    #[account(
+        mut,
+        constraint = stake_account_from.key() != claim_to.key() @ ErrorCode::MergeMismatchSameSourceDestination
+    )]
+    pub claim_to: UncheckedAccount<'info>,
+
+    /// CHECK: PDA
+    /// authority that manages (owns == by being withdrawer authority) all stakes account under the bonds program
+    #[account(
+        seeds = [
+            b"bonds_authority",
+            config.key().as_ref(),
+        ],
+        bump = config.bonds_withdrawer_authority_bump
+    )]
+    pub bonds_withdrawer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: have no CPU budget to parse
+    #[account(address = stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    pub stake_program: Program<'info, Stake>,
+}
+
+impl ClaimSettlementRedirect<'_> {
+    pub fn process(
+        ctx: Context<ClaimSettlementRedirect>,
+        ClaimSettlementV2Args {
+            proof,
+            tree_node_hash: tree_node_hash_args,
+            claim,
+            stake_account_staker,
+            stake_account_withdrawer,
+            index,
+        }: ClaimSettlementV2Args,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_CLAIM),
+            ErrorCode::ProgramIsPaused
+        );
+
+        let mut settlement_claims = SettlementClaimsWrapped::new(&ctx.accounts.settlement_claims)?;
+        require!(
+            settlement_claims.try_to_set(index)?,
+            ErrorCode::SettlementAlreadyClaimed
+        );
+
+        let tree_node = TreeNode {
+            stake_authority: stake_account_staker,
+            withdraw_authority: stake_account_withdrawer,
+            claim,
+            index,
+            proof: None,
+        };
+        check_tree_node_hash(&tree_node, tree_node_hash_args)?;
+        check_settlement_claim_limits(&ctx.accounts.settlement, claim, 1)?;
+
+        // stake account is managed by bonds program
+        let stake_from_meta = check_stake_is_initialized_with_withdrawer_authority(
+            &ctx.accounts.stake_account_from,
+            &ctx.accounts.bonds_withdrawer_authority.key(),
+            "stake_account_from",
+        )?;
+        // provided stake account "from" must be funded; staker == settlement staker authority
+        require_keys_eq!(
+            stake_from_meta.authorized.staker,
+            ctx.accounts.settlement.staker_authority,
+            ErrorCode::StakeAccountNotFundedToSettlement,
+        );
+
+        // the claim goes to a system account or to a stake account controlled by the withdraw authority;
+        // the withdraw authority chooses the account by signing, a lockup of the stake account is up to it
+        if *ctx.accounts.claim_to.owner == stake_program_id {
+            let claim_to_stake = deserialize_stake_account(&ctx.accounts.claim_to)?;
+            check_stake_is_initialized_with_withdrawer_authority(
+                &claim_to_stake,
+                &stake_account_withdrawer,
+                "claim_to",
+            )?;
+        } else {
+            require_keys_eq!(
+                *ctx.accounts.claim_to.owner,
+                system_program_id,
+                ErrorCode::InvalidClaimToAccount,
+            );
+        }
+
+        check_stake_account_from_covers_claim(
+            &ctx.accounts.stake_account_from,
+            &stake_from_meta,
+            &ctx.accounts.config,
+            claim,
+        )?;
+        verify_settlement_claim_proof(proof, &ctx.accounts.settlement, &tree_node)?;
+
+        withdraw(
+            CpiContext::new_with_signer(
+                ctx.accounts.stake_program.to_account_info(),
+                Withdraw {
+                    stake: ctx.accounts.stake_account_from.to_account_info(),
+                    withdrawer: ctx.accounts.bonds_withdrawer_authority.to_account_info(),
+                    to: ctx.accounts.claim_to.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                    stake_history: ctx.accounts.stake_history.to_account_info(),
+                },
+                &[&[
+                    BONDS_WITHDRAWER_AUTHORITY_SEED,
+                    ctx.accounts.config.key().as_ref(),
+                    &[ctx.accounts.config.bonds_withdrawer_authority_bump],
+                ]],
+            ),
+            claim,
+            None,
+        )?;
+
+        ctx.accounts.settlement.lamports_claimed += claim;
+        ctx.accounts.settlement.merkle_nodes_claimed += 1;
+        update_bond_ledger(
+            bond_ledger_account(ctx.remaining_accounts, &ctx.accounts.bond)?,
+            &ctx.accounts.bond.key(),
+            ctx.accounts.clock.epoch,
+            |record| record.claimed_lamports += claim,
+        )?;
+
+        emit_cpi!(ClaimSettlementRedirectEvent {
+            settlement: ctx.accounts.settlement.key(),
+            claim_to: ctx.accounts.claim_to.key(),
+            settlement_lamports_claimed: U64ValueChange {
+                old: ctx.accounts.settlement.lamports_claimed - claim,
+                new: ctx.accounts.settlement.lamports_claimed
+            },
+            settlement_merkle_nodes_claimed: ctx.accounts.settlement.merkle_nodes_claimed,
+            stake_account_staker,
+            stake_account_withdrawer,
+            amount: claim,
+            index,
+        });
+
+        Ok(())
+    }
+}
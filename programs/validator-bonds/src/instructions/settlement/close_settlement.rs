@@ -2,24 +2,29 @@ use crate::checks::{
     check_stake_is_initialized_with_withdrawer_authority, check_stake_valid_delegation,
     deserialize_stake_account,
 };
-use crate::constants::BONDS_WITHDRAWER_AUTHORITY_SEED;
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, PAUSE_SETTLEMENT};
 use crate::error::ErrorCode;
 use crate::events::settlement::CloseSettlementEvent;
 use crate::state::bond::Bond;
+use crate::state::bond_ledger::{split_bond_ledger, update_bond_ledger};
 use crate::state::config::Config;
 use crate::state::settlement::Settlement;
-use crate::state::settlement_claims::SettlementClaims;
+use crate::state::settlement_claims::{close_settlement_claims_chunks, SettlementClaims};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::stake_history;
 use anchor_spl::stake::{withdraw, Stake, Withdraw};
 
 /// Closes the settlement account, whoever can close it when the epoch expires
+/// or when all merkle nodes of the settlement were claimed.
+/// The SettlementClaims chunk accounts, when created, have to be passed as remaining accounts,
+/// the optional bond ledger is passed as the last remaining account.
 #[event_cpi]
 #[derive(Accounts)]
 pub struct CloseSettlementV2<'info> {
     pub config: Account<'info, Config>,
 
     #[account(
+        mut,
         has_one = config @ ErrorCode::ConfigAccountMismatch,
         seeds = [
             b"bond_account",
@@ -30,18 +35,19 @@ pub struct CloseSettlementV2<'info> {
     )]
     pub bond: Account<'info, Bond>,
 
-    /// settlement to close when expired
+    /// settlement to close when expired or fully claimed
     #[account(
         mut,
         close = rent_collector,
         has_one = bond @ ErrorCode::BondAccountMismatch,
         has_one = rent_collector @ ErrorCode::RentCollectorMismatch,
         constraint = (settlement.split_rent_collector.is_none() || settlement.split_rent_collector.unwrap() == split_rent_collector.key()) @ ErrorCode::RentCollectorMismatch,
-        constraint = settlement.epoch_created_for + config.epochs_to_claim_settlement < clock.epoch @ ErrorCode::SettlementNotExpired,
+        constraint = settlement.expiration_epoch(&config) < clock.epoch ||
+                     settlement.merkle_nodes_claimed >= settlement.max_merkle_nodes @ ErrorCode::SettlementNotExpired,
         seeds = [
             b"settlement_account",
             bond.key().as_ref(),
-            settlement.merkle_root.as_ref(),
+            settlement.pda_seed().as_ref(),
             settlement.epoch_created_for.to_le_bytes().as_ref(),
         ],
         bump = settlement.bumps.pda,
@@ -93,9 +99,36 @@ pub struct CloseSettlementV2<'info> {
     pub stake_history: UncheckedAccount<'info>,
 }
 
-impl CloseSettlementV2<'_> {
-    pub fn process(ctx: Context<CloseSettlementV2>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+impl<'info> CloseSettlementV2<'info> {
+    pub fn process(ctx: Context<'_, '_, '_, 'info, CloseSettlementV2<'info>>) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
+
+        if ctx.accounts.settlement.counted {
+            ctx.accounts.bond.open_settlements = ctx
+                .accounts
+                .bond
+                .open_settlements
+                .checked_sub(1)
+                .ok_or(ErrorCode::BondCounterUnderflow)?;
+        }
+
+        // chunks of the claims bitmap and the bond ledger are passed as remaining accounts
+        let (settlement_claims_chunks, bond_ledger) =
+            split_bond_ledger(ctx.remaining_accounts, &ctx.accounts.bond)?;
+        close_settlement_claims_chunks(
+            &ctx.accounts.settlement_claims,
+            settlement_claims_chunks,
+            &ctx.accounts.rent_collector,
+        )?;
+        update_bond_ledger(
+            bond_ledger,
+            &ctx.accounts.bond.key(),
+            ctx.accounts.clock.epoch,
+            |record| record.closed_settlements += 1,
+        )?;
 
         if ctx.accounts.settlement.split_rent_collector.is_some() {
             withdraw_refund_stake_account(
@@ -127,8 +160,10 @@ impl CloseSettlementV2<'_> {
                 .settlement
                 .split_rent_collector
                 .map(|_| ctx.accounts.split_rent_refund_account.key()),
-            expiration_epoch: ctx.accounts.settlement.epoch_created_for
-                + ctx.accounts.config.epochs_to_claim_settlement,
+            expiration_epoch: ctx
+                .accounts
+                .settlement
+                .expiration_epoch(&ctx.accounts.config),
             current_epoch: ctx.accounts.clock.epoch,
         });
 
@@ -0,0 +1,95 @@
+use crate::constants::PAUSE_SETTLEMENT;
+use crate::error::ErrorCode;
+use crate::events::settlement::ExtendSettlementEvent;
+use crate::events::U64ValueChange;
+use crate::state::bond::Bond;
+use crate::state::config::Config;
+use crate::state::settlement::Settlement;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ExtendSettlementArgs {
+    /// the last epoch when the settlement can be claimed,
+    /// it has to be later than the current expiration epoch of the settlement
+    pub expiration_epoch: u64,
+}
+
+/// Extends the claiming window of the settlement.
+/// Permission-ed for operator authority, used when claiming was blocked (e.g., by a pause)
+/// and stakers would lose the payout when the settlement is closed.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExtendSettlement<'info> {
+    #[account(
+        has_one = operator_authority @ ErrorCode::InvalidOperatorAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        has_one = bond @ ErrorCode::BondAccountMismatch,
+        seeds = [
+            b"settlement_account",
+            bond.key().as_ref(),
+            settlement.pda_seed().as_ref(),
+            settlement.epoch_created_for.to_le_bytes().as_ref(),
+        ],
+        bump = settlement.bumps.pda,
+    )]
+    pub settlement: Account<'info, Settlement>,
+
+    /// operator signer authority that is allowed to extend the settlement
+    pub operator_authority: Signer<'info>,
+}
+
+impl ExtendSettlement<'_> {
+    pub fn process(
+        ctx: Context<ExtendSettlement>,
+        ExtendSettlementArgs { expiration_epoch }: ExtendSettlementArgs,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
+
+        let old_expiration_epoch = ctx
+            .accounts
+            .settlement
+            .expiration_epoch(&ctx.accounts.config);
+        require_gt!(
+            expiration_epoch,
+            old_expiration_epoch,
+            ErrorCode::SettlementExpirationNotExtended
+        );
+        require_gte!(
+            expiration_epoch,
+            Clock::get()?.epoch,
+            ErrorCode::SettlementExpirationNotExtended
+        );
+
+        ctx.accounts.settlement.expiration_epoch_override = expiration_epoch;
+
+        emit_cpi!(ExtendSettlementEvent {
+            bond: ctx.accounts.bond.key(),
+            settlement: ctx.accounts.settlement.key(),
+            operator_authority: ctx.accounts.operator_authority.key(),
+            expiration_epoch: U64ValueChange {
+                old: old_expiration_epoch,
+                new: expiration_epoch,
+            },
+        });
+
+        Ok(())
+    }
+}
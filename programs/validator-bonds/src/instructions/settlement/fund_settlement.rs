@@ -2,11 +2,12 @@ use crate::checks::{
     check_stake_is_initialized_with_withdrawer_authority, check_stake_is_not_locked,
     check_stake_valid_delegation,
 };
-use crate::constants::BONDS_WITHDRAWER_AUTHORITY_SEED;
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, PAUSE_SETTLEMENT};
 use crate::error::ErrorCode;
 use crate::events::settlement::FundSettlementEvent;
 use crate::events::SplitStakeData;
 use crate::state::bond::Bond;
+use crate::state::bond_ledger::{bond_ledger_account, update_bond_ledger};
 use crate::state::config::Config;
 use crate::state::settlement::Settlement;
 use crate::utils::{minimal_size_stake_account, return_unused_split_stake_account_rent};
@@ -26,6 +27,7 @@ use anchor_spl::stake::{
 /// Funding the settlement by providing a stake account delegated to a particular validator vote account based on the Merkle proof.
 /// The settlement has been previously created by the operator to fulfill some protected event (e.g., slashing).
 /// Permission-ed to operator authority.
+/// The optional bond ledger may be passed as the remaining account to record the funded lamports.
 #[event_cpi]
 #[derive(Accounts)]
 pub struct FundSettlement<'info> {
@@ -59,7 +61,7 @@ pub struct FundSettlement<'info> {
         seeds = [
             b"settlement_account",
             bond.key().as_ref(),
-            settlement.merkle_root.as_ref(),
+            settlement.pda_seed().as_ref(),
             settlement.epoch_created_for.to_le_bytes().as_ref(),
         ],
         bump = settlement.bumps.pda,
@@ -137,7 +139,11 @@ pub struct FundSettlement<'info> {
 
 impl FundSettlement<'_> {
     pub fn process(ctx: Context<FundSettlement>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
+        let bond_ledger = bond_ledger_account(ctx.remaining_accounts, &ctx.accounts.bond)?;
 
         if ctx.accounts.settlement.lamports_funded >= ctx.accounts.settlement.max_total_claim {
             msg!("Settlement is already fully funded");
@@ -398,6 +404,12 @@ impl FundSettlement<'_> {
         )?;
 
         ctx.accounts.settlement.lamports_funded += funding_amount;
+        update_bond_ledger(
+            bond_ledger,
+            &ctx.accounts.bond.key(),
+            ctx.accounts.clock.epoch,
+            |record| record.funded_lamports += funding_amount,
+        )?;
 
         emit_cpi!(FundSettlementEvent {
             bond: ctx.accounts.bond.key(),
@@ -0,0 +1,305 @@
+use crate::checks::{
+    check_stake_is_initialized_with_withdrawer_authority, check_stake_is_not_locked,
+    check_stake_valid_delegation,
+};
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, PAUSE_SETTLEMENT};
+use crate::error::ErrorCode;
+use crate::events::collateral::FundSettlementTokenEvent;
+use crate::state::bond::Bond;
+use crate::state::bond_ledger::{bond_ledger_account, update_bond_ledger};
+use crate::state::collateral_mint::CollateralMint;
+use crate::state::config::Config;
+use crate::state::settlement::Settlement;
+use crate::utils::minimal_size_stake_account;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::stake::state::StakeAuthorize;
+use anchor_lang::solana_program::vote::program::ID as vote_program_id;
+use anchor_spl::stake::{
+    authorize, deactivate_stake, Authorize, DeactivateStake, Stake, StakeAccount,
+};
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+/// Funding the settlement from the bond token collateral when the bond stake accounts are insufficient.
+/// The settlement is funded with a stake account provided by a liquidator (delegated to the bond vote account),
+/// in exchange the liquidator receives the bond collateral tokens by the exchange rate of the current epoch
+/// (rounded up), the haircut is not applied on the liquidator.
+/// Claims of the settlement are then paid from the funded stake account as usual.
+/// The optional bond ledger may be passed as the remaining account to record the funded lamports.
+/// Permission-ed to operator authority.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundSettlementToken<'info> {
+    #[account(
+        has_one = operator_authority @ ErrorCode::InvalidOperatorAuthority,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        has_one = vote_account @ ErrorCode::VoteAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            vote_account.key().as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Box<Account<'info, Bond>>,
+
+    /// CHECK: the validator vote account to which the stake account is delegated, linked to bond
+    #[account(
+        owner = vote_program_id @ ErrorCode::InvalidVoteAccountProgramId,
+    )]
+    pub vote_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = bond @ ErrorCode::BondAccountMismatch,
+        constraint = settlement.staker_authority == settlement_staker_authority.key() @ ErrorCode::SettlementAuthorityMismatch,
+        seeds = [
+            b"settlement_account",
+            bond.key().as_ref(),
+            settlement.pda_seed().as_ref(),
+            settlement.epoch_created_for.to_le_bytes().as_ref(),
+        ],
+        bump = settlement.bumps.pda,
+    )]
+    pub settlement: Box<Account<'info, Settlement>>,
+
+    /// operator signer authority is allowed to fund the settlement account
+    pub operator_authority: Signer<'info>,
+
+    /// stake account of the liquidator to be funded into the settlement
+    #[account(mut)]
+    pub stake_account: Box<Account<'info, StakeAccount>>,
+
+    /// staker and withdrawer authority of the stake account, signature permits to change the authorities
+    pub stake_authority: Signer<'info>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            b"settlement_authority",
+            settlement.key().as_ref(),
+        ],
+        bump = settlement.bumps.staker_authority,
+    )]
+    pub settlement_staker_authority: UncheckedAccount<'info>,
+
+    /// CHECK: PDA
+    /// authority that manages (owns) all stakes account and token vaults under the bonds program
+    #[account(
+        seeds = [
+            b"bonds_authority",
+            config.key().as_ref(),
+        ],
+        bump = config.bonds_withdrawer_authority_bump
+    )]
+    pub bonds_withdrawer_authority: UncheckedAccount<'info>,
+
+    /// disabled collateral mint still permits funding the settlement
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        has_one = mint @ ErrorCode::CollateralMintDisabled,
+        seeds = [
+            b"collateral_mint",
+            config.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump = collateral_mint.bump,
+    )]
+    pub collateral_mint: Box<Account<'info, CollateralMint>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"bond_token_vault",
+            bond.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = bonds_withdrawer_authority,
+        token::token_program = token_program,
+    )]
+    pub bond_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// token account of the liquidator receiving the bond collateral tokens
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub liquidator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    pub stake_program: Program<'info, Stake>,
+}
+
+impl FundSettlementToken<'_> {
+    pub fn process(ctx: Context<FundSettlementToken>) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
+
+        let bond_ledger = bond_ledger_account(ctx.remaining_accounts, &ctx.accounts.bond)?;
+
+        if ctx.accounts.settlement.lamports_funded >= ctx.accounts.settlement.max_total_claim {
+            msg!("Settlement is already fully funded");
+            return Ok(());
+        }
+
+        let stake_meta = check_stake_is_initialized_with_withdrawer_authority(
+            &ctx.accounts.stake_account,
+            &ctx.accounts.stake_authority.key(),
+            "stake_account",
+        )?;
+        require_keys_eq!(
+            stake_meta.authorized.staker,
+            ctx.accounts.stake_authority.key(),
+            ErrorCode::WrongStakeAccountStaker,
+        );
+        // delegated to the bond vote account to be returned to the bond when not claimed
+        let stake_delegation = check_stake_valid_delegation(
+            &ctx.accounts.stake_account,
+            &ctx.accounts.bond.vote_account,
+        )?;
+        check_stake_is_not_locked(
+            &ctx.accounts.stake_account,
+            &ctx.accounts.clock,
+            "stake_account",
+        )?;
+
+        // no splitting, the liquidator is expected to provide the stake account of the right size
+        let stake_account_min_size = minimal_size_stake_account(&stake_meta, &ctx.accounts.config);
+        let amount_needed =
+            ctx.accounts.settlement.max_total_claim - ctx.accounts.settlement.lamports_funded;
+        let funding_amount = ctx
+            .accounts
+            .stake_account
+            .get_lamports()
+            .saturating_sub(stake_account_min_size);
+        if funding_amount == 0 || funding_amount > amount_needed {
+            return Err(error!(ErrorCode::SettlementFundingStakeMismatch)
+                .with_account_name("stake_account")
+                .with_values((
+                    "stake_account_lamports - minimal_size_stake_account not in (0, amount_needed]",
+                    format!(
+                        "{} - {} not in (0, {}]",
+                        ctx.accounts.stake_account.get_lamports(),
+                        stake_account_min_size,
+                        amount_needed
+                    ),
+                )));
+        }
+
+        require_eq!(
+            ctx.accounts.collateral_mint.exchange_rate_epoch,
+            ctx.accounts.clock.epoch,
+            ErrorCode::CollateralExchangeRateStale
+        );
+        let token_amount = ctx
+            .accounts
+            .collateral_mint
+            .liquidation_token_amount(funding_amount)?;
+        require_gte!(
+            ctx.accounts.bond_token_vault.amount,
+            token_amount,
+            ErrorCode::InsufficientBondTokenCollateral
+        );
+
+        // deactivating stake to be withdraw-able on claim_settlement instruction
+        if stake_delegation.deactivation_epoch == u64::MAX {
+            deactivate_stake(CpiContext::new(
+                ctx.accounts.stake_program.to_account_info(),
+                DeactivateStake {
+                    stake: ctx.accounts.stake_account.to_account_info(),
+                    staker: ctx.accounts.stake_authority.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                },
+            ))?;
+        }
+        // moving the stake account under the settlement, the same state as funded by fund_settlement
+        authorize(
+            CpiContext::new(
+                ctx.accounts.stake_program.to_account_info(),
+                Authorize {
+                    stake: ctx.accounts.stake_account.to_account_info(),
+                    authorized: ctx.accounts.stake_authority.to_account_info(),
+                    new_authorized: ctx.accounts.settlement_staker_authority.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                },
+            ),
+            StakeAuthorize::Staker,
+            None,
+        )?;
+        authorize(
+            CpiContext::new(
+                ctx.accounts.stake_program.to_account_info(),
+                Authorize {
+                    stake: ctx.accounts.stake_account.to_account_info(),
+                    authorized: ctx.accounts.stake_authority.to_account_info(),
+                    new_authorized: ctx.accounts.bonds_withdrawer_authority.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                },
+            ),
+            StakeAuthorize::Withdrawer,
+            None,
+        )?;
+
+        let old_vault_amount = ctx.accounts.bond_token_vault.amount;
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.bond_token_vault.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.liquidator_token_account.to_account_info(),
+                    authority: ctx.accounts.bonds_withdrawer_authority.to_account_info(),
+                },
+                &[&[
+                    BONDS_WITHDRAWER_AUTHORITY_SEED,
+                    ctx.accounts.config.key().as_ref(),
+                    &[ctx.accounts.config.bonds_withdrawer_authority_bump],
+                ]],
+            ),
+            token_amount,
+            ctx.accounts.mint.decimals,
+        )?;
+        ctx.accounts.bond_token_vault.reload()?;
+        ctx.accounts
+            .bond
+            .update_funded_token_vaults(old_vault_amount, ctx.accounts.bond_token_vault.amount);
+
+        ctx.accounts.settlement.lamports_funded += funding_amount;
+        update_bond_ledger(
+            bond_ledger,
+            &ctx.accounts.bond.key(),
+            ctx.accounts.clock.epoch,
+            |record| record.funded_lamports += funding_amount,
+        )?;
+
+        emit_cpi!(FundSettlementTokenEvent {
+            bond: ctx.accounts.bond.key(),
+            settlement: ctx.accounts.settlement.key(),
+            funding_amount,
+            stake_account: ctx.accounts.stake_account.key(),
+            mint: ctx.accounts.mint.key(),
+            token_amount,
+            exchange_rate: ctx.accounts.collateral_mint.exchange_rate,
+            liquidator_token_account: ctx.accounts.liquidator_token_account.key(),
+            lamports_funded: ctx.accounts.settlement.lamports_funded,
+        });
+
+        Ok(())
+    }
+}
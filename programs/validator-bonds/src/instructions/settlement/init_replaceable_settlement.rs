@@ -0,0 +1,172 @@
+use crate::constants::PAUSE_SETTLEMENT;
+use crate::error::ErrorCode;
+use crate::events::settlement::InitSettlementEvent;
+use crate::state::bond::Bond;
+use crate::state::config::Config;
+use crate::state::settlement::{find_settlement_staker_authority, Bumps, Settlement};
+use crate::state::settlement_claims::{account_initialization_size, SettlementClaims};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct InitReplaceableSettlementArgs {
+    /// PDA seed of the settlement instead of the merkle root, it has to be non-zero
+    pub replaceable_seed: [u8; 32],
+    /// merkle root for this settlement, it can be replaced until claiming starts
+    pub merkle_root: [u8; 32],
+    /// maximal number of lamports that can be claimed from this settlement
+    pub max_total_claim: u64,
+    /// maximal number of merkle tree nodes that can be claimed from this settlement
+    pub max_merkle_nodes: u64,
+    /// collects the rent exempt from the settlement account when closed
+    pub rent_collector: Pubkey,
+    /// epoch that the settlement is created for
+    pub epoch: u64,
+}
+
+/// Creates settlement account for the bond that is not seeded by the merkle root,
+/// the merkle root can be replaced with 'replace_settlement_root' before the claiming starts.
+/// Permission-ed for operator authority.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: InitReplaceableSettlementArgs)]
+pub struct InitReplaceableSettlement<'info> {
+    #[account(
+        has_one = operator_authority @ ErrorCode::InvalidOperatorAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref()
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<Settlement>(),
+        seeds = [
+            b"settlement_account",
+            bond.key().as_ref(),
+            params.replaceable_seed.as_ref(),
+            params.epoch.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub settlement: Account<'info, Settlement>,
+
+    // Solana maximum allocation size in one instruction is 10KB (~80K records)
+    #[account(
+        init,
+        payer = rent_payer,
+        space = account_initialization_size(params.max_merkle_nodes)?,
+        seeds = [
+            b"claims_account",
+            settlement.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub settlement_claims: Box<Account<'info, SettlementClaims>>,
+
+    /// operator signer authority that is allowed to create the settlement account
+    pub operator_authority: Signer<'info>,
+
+    /// rent exempt payer of account creation
+    #[account(
+        mut,
+        owner = system_program.key(),
+    )]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl InitReplaceableSettlement<'_> {
+    pub fn process(
+        ctx: Context<InitReplaceableSettlement>,
+        InitReplaceableSettlementArgs {
+            replaceable_seed,
+            merkle_root,
+            rent_collector,
+            max_total_claim,
+            max_merkle_nodes,
+            epoch,
+        }: InitReplaceableSettlementArgs,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
+
+        require!(
+            replaceable_seed != [0; 32],
+            ErrorCode::InvalidReplaceableSettlementSeed
+        );
+        if max_total_claim == 0 || max_merkle_nodes == 0 {
+            return Err(error!(ErrorCode::EmptySettlementMerkleTree).with_values((
+                "max_total_claim, max_merkle_nodes",
+                format!("{max_total_claim}, {max_merkle_nodes}"),
+            )));
+        }
+
+        let clock = Clock::get()?;
+
+        ctx.accounts.bond.open_settlements += 1;
+
+        let (authority, authority_bump) =
+            find_settlement_staker_authority(&ctx.accounts.settlement.key());
+        ctx.accounts.settlement.set_inner(Settlement {
+            bond: ctx.accounts.bond.key(),
+            staker_authority: authority,
+            merkle_root,
+            max_total_claim,
+            max_merkle_nodes,
+            lamports_funded: 0,
+            lamports_claimed: 0,
+            merkle_nodes_claimed: 0,
+            epoch_created_for: epoch,
+            slot_created_at: clock.slot,
+            rent_collector,
+            split_rent_collector: None,
+            split_rent_amount: 0,
+            bumps: Bumps {
+                pda: ctx.bumps.settlement,
+                staker_authority: authority_bump,
+                settlement_claims: ctx.bumps.settlement_claims,
+            },
+            expiration_epoch_override: 0,
+            replaceable_seed,
+            counted: true,
+            reserved: [0; 49],
+        });
+        ctx.accounts.settlement_claims.set_inner(SettlementClaims {
+            settlement: ctx.accounts.settlement.key(),
+            version: 0,
+            max_records: max_merkle_nodes,
+            chunk_index: 0,
+            chunks: 0,
+            chunks_initialized: 0,
+            bump: 0,
+        });
+        emit_cpi!(InitSettlementEvent {
+            settlement: ctx.accounts.settlement.key(),
+            bond: ctx.accounts.settlement.bond,
+            vote_account: ctx.accounts.bond.vote_account,
+            staker_authority: ctx.accounts.settlement.staker_authority,
+            merkle_root: ctx.accounts.settlement.merkle_root,
+            max_total_claim: ctx.accounts.settlement.max_total_claim,
+            max_merkle_nodes: ctx.accounts.settlement.max_merkle_nodes,
+            epoch_created_for: ctx.accounts.settlement.epoch_created_for,
+            slot_created_at: ctx.accounts.settlement.slot_created_at,
+            rent_collector: ctx.accounts.settlement.rent_collector,
+        });
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::constants::PAUSE_SETTLEMENT;
 use crate::error::ErrorCode;
 use crate::events::settlement::InitSettlementEvent;
 use crate::state::bond::Bond;
@@ -33,6 +34,7 @@ pub struct InitSettlement<'info> {
     pub config: Account<'info, Config>,
 
     #[account(
+        mut,
         has_one = config @ ErrorCode::ConfigAccountMismatch,
         seeds = [
             b"bond_account",
@@ -94,7 +96,10 @@ impl InitSettlement<'_> {
             epoch,
         }: InitSettlementArgs,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
 
         if max_total_claim == 0 || max_merkle_nodes == 0 {
             return Err(error!(ErrorCode::EmptySettlementMerkleTree).with_values((
@@ -105,6 +110,8 @@ impl InitSettlement<'_> {
 
         let clock = Clock::get()?;
 
+        ctx.accounts.bond.open_settlements += 1;
+
         let (authority, authority_bump) =
             find_settlement_staker_authority(&ctx.accounts.settlement.key());
         ctx.accounts.settlement.set_inner(Settlement {
@@ -126,12 +133,19 @@ impl InitSettlement<'_> {
                 staker_authority: authority_bump,
                 settlement_claims: ctx.bumps.settlement_claims,
             },
-            reserved: [0; 90],
+            expiration_epoch_override: 0,
+            replaceable_seed: [0; 32],
+            counted: true,
+            reserved: [0; 49],
         });
         ctx.accounts.settlement_claims.set_inner(SettlementClaims {
             settlement: ctx.accounts.settlement.key(),
             version: 0,
             max_records: max_merkle_nodes,
+            chunk_index: 0,
+            chunks: 0,
+            chunks_initialized: 0,
+            bump: 0,
         });
         emit_cpi!(InitSettlementEvent {
             settlement: ctx.accounts.settlement.key(),
@@ -0,0 +1,110 @@
+use crate::constants::SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS;
+use crate::error::ErrorCode;
+use crate::state::settlement_claims::{account_size, SettlementClaims};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct InitSettlementClaimsChunkArgs {
+    /// index of the chunk to create, the chunk 0 is the settlement claims account
+    pub chunk_index: u16,
+}
+
+/// Creates a SettlementClaims chunk account storing a part of the claims bitmap.
+/// The first call splits the settlement claims account into chunks
+/// when its bitmap does not fit into the initial 10KB allocation and it was not upsized yet.
+/// The chunks may be created in parallel instead of serial calls of 'upsize_settlement_claims'.
+#[derive(Accounts)]
+#[instruction(params: InitSettlementClaimsChunkArgs)]
+pub struct InitSettlementClaimsChunk<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"claims_account",
+            settlement_claims.settlement.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub settlement_claims: Account<'info, SettlementClaims>,
+
+    // chunk account size is the maximum allocation size in one instruction (10KB)
+    #[account(
+        init,
+        payer = rent_payer,
+        space = account_size(SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS),
+        seeds = [
+            b"claims_account",
+            settlement_claims.settlement.key().as_ref(),
+            params.chunk_index.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub settlement_claims_chunk: Account<'info, SettlementClaims>,
+
+    /// rent exempt payer of account creation
+    #[account(
+        mut,
+        owner = system_program.key(),
+    )]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl InitSettlementClaimsChunk<'_> {
+    pub fn process(
+        ctx: Context<InitSettlementClaimsChunk>,
+        InitSettlementClaimsChunkArgs { chunk_index }: InitSettlementClaimsChunkArgs,
+    ) -> Result<()> {
+        // NOTE: intentionally not considering pause state here,
+        //       as the chunk creation is a benign operation, the same as the account upsizing
+
+        let settlement_claims = &mut ctx.accounts.settlement_claims;
+        if settlement_claims.chunks == 0 {
+            let current_size = settlement_claims.to_account_info().data_len();
+            if settlement_claims.max_records <= SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS
+                || current_size != account_size(SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS)
+            {
+                return Err(
+                    error!(ErrorCode::SettlementClaimsNotChunkable).with_values((
+                        "max_records, current_size",
+                        format!("{}, {}", settlement_claims.max_records, current_size),
+                    )),
+                );
+            }
+            let chunks = settlement_claims
+                .max_records
+                .div_ceil(SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS);
+            settlement_claims.chunks =
+                u16::try_from(chunks).map_err(|_| ErrorCode::SettlementClaimsTooManyRecords)?;
+            settlement_claims.max_records = SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS;
+            msg!(
+                "SettlementClaims of settlement {} split into {} chunks",
+                settlement_claims.settlement,
+                chunks
+            );
+        }
+        if chunk_index == 0 || chunk_index >= settlement_claims.chunks {
+            return Err(
+                error!(ErrorCode::SettlementClaimsChunkMismatch).with_values((
+                    "chunk_index vs. chunks",
+                    format!("{} vs. {}", chunk_index, settlement_claims.chunks),
+                )),
+            );
+        }
+        settlement_claims.chunks_initialized += 1;
+
+        ctx.accounts
+            .settlement_claims_chunk
+            .set_inner(SettlementClaims {
+                settlement: settlement_claims.settlement,
+                version: 0,
+                max_records: SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS,
+                chunk_index,
+                chunks: settlement_claims.chunks,
+                chunks_initialized: 0,
+                bump: ctx.bumps.settlement_claims_chunk,
+            });
+
+        Ok(())
+    }
+}
@@ -1,13 +1,27 @@
 pub mod cancel_settlement;
 pub mod claim_settlement;
+pub mod claim_settlement_batch;
+pub mod claim_settlement_redirect;
 pub mod close_settlement;
+pub mod extend_settlement;
 pub mod fund_settlement;
+pub mod fund_settlement_token;
+pub mod init_replaceable_settlement;
 pub mod init_settlement;
+pub mod init_settlement_claims_chunk;
+pub mod replace_settlement_root;
 pub mod upsize_settlement_claims;
 
 pub use cancel_settlement::*;
 pub use claim_settlement::*;
+pub use claim_settlement_batch::*;
+pub use claim_settlement_redirect::*;
 pub use close_settlement::*;
+pub use extend_settlement::*;
 pub use fund_settlement::*;
+pub use fund_settlement_token::*;
+pub use init_replaceable_settlement::*;
 pub use init_settlement::*;
+pub use init_settlement_claims_chunk::*;
+pub use replace_settlement_root::*;
 pub use upsize_settlement_claims::*;
@@ -0,0 +1,174 @@
+use crate::constants::{PAUSE_SETTLEMENT, SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS};
+use crate::error::ErrorCode;
+use crate::events::settlement::ReplaceSettlementRootEvent;
+use crate::events::U64ValueChange;
+use crate::state::bond::Bond;
+use crate::state::config::Config;
+use crate::state::settlement::Settlement;
+use crate::state::settlement_claims::{
+    account_initialization_size, account_size, SettlementClaims, MAX_PERMITTED_DATA_INCREASE,
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ReplaceSettlementRootArgs {
+    /// new merkle root for the settlement
+    pub merkle_root: [u8; 32],
+    /// maximal number of lamports that can be claimed from the new merkle tree
+    pub max_total_claim: u64,
+    /// maximal number of merkle tree nodes that can be claimed from the new merkle tree
+    pub max_merkle_nodes: u64,
+}
+
+/// Replaces the merkle root of a settlement created by 'init_replaceable_settlement'.
+/// Possible only before the claiming starts, the funded stake accounts are kept for the settlement.
+/// When the number of merkle nodes grows, the claims bitmap is reallocated up to the max permitted
+/// increase of one instruction, a bigger bitmap has to be finished with 'upsize_settlement_claims'.
+/// For claims bitmap split into chunks the new merkle nodes have to fit into the existing chunks.
+/// Permission-ed for operator authority.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: ReplaceSettlementRootArgs)]
+pub struct ReplaceSettlementRoot<'info> {
+    #[account(
+        has_one = operator_authority @ ErrorCode::InvalidOperatorAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        has_one = bond @ ErrorCode::BondAccountMismatch,
+        constraint = settlement.is_root_replaceable() @ ErrorCode::SettlementRootNotReplaceable,
+        constraint = settlement.slot_created_at + config.slots_to_start_settlement_claiming > clock.slot @ ErrorCode::SettlementClaimingStarted,
+        seeds = [
+            b"settlement_account",
+            bond.key().as_ref(),
+            settlement.pda_seed().as_ref(),
+            settlement.epoch_created_for.to_le_bytes().as_ref(),
+        ],
+        bump = settlement.bumps.pda,
+    )]
+    pub settlement: Account<'info, Settlement>,
+
+    #[account(
+        mut,
+        has_one = settlement @ ErrorCode::BondAccountMismatch,
+        realloc = replaced_claims_size(&settlement_claims, params.max_merkle_nodes),
+        realloc::payer = rent_payer,
+        realloc::zero = true,
+        seeds = [
+            b"claims_account",
+            settlement.key().as_ref(),
+        ],
+        bump = settlement.bumps.settlement_claims,
+    )]
+    pub settlement_claims: Account<'info, SettlementClaims>,
+
+    /// operator signer authority that is allowed to replace the settlement merkle root
+    pub operator_authority: Signer<'info>,
+
+    /// rent exempt payer of the claims bitmap reallocation
+    #[account(
+        mut,
+        owner = system_program.key(),
+    )]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Size of the claims bitmap account for the new number of merkle nodes, never shrinking the account.
+/// The chunked bitmap is not reallocated, the chunks are verified to fit the merkle nodes.
+fn replaced_claims_size(
+    settlement_claims: &Account<SettlementClaims>,
+    max_merkle_nodes: u64,
+) -> usize {
+    let current_size = settlement_claims.to_account_info().data_len();
+    if settlement_claims.chunks > 0 {
+        return current_size;
+    }
+    account_size(max_merkle_nodes)
+        .max(current_size)
+        .min(current_size + MAX_PERMITTED_DATA_INCREASE)
+}
+
+impl ReplaceSettlementRoot<'_> {
+    pub fn process(
+        ctx: Context<ReplaceSettlementRoot>,
+        ReplaceSettlementRootArgs {
+            merkle_root,
+            max_total_claim,
+            max_merkle_nodes,
+        }: ReplaceSettlementRootArgs,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
+
+        if max_total_claim == 0 || max_merkle_nodes == 0 {
+            return Err(error!(ErrorCode::EmptySettlementMerkleTree).with_values((
+                "max_total_claim, max_merkle_nodes",
+                format!("{max_total_claim}, {max_merkle_nodes}"),
+            )));
+        }
+
+        let settlement_claims = &mut ctx.accounts.settlement_claims;
+        if settlement_claims.chunks > 0 {
+            let chunks_max_records =
+                settlement_claims.chunks as u64 * SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS;
+            if max_merkle_nodes > chunks_max_records {
+                return Err(
+                    error!(ErrorCode::SettlementClaimsChunksExceeded).with_values((
+                        "max_merkle_nodes vs. chunks max records",
+                        format!("{max_merkle_nodes} vs. {chunks_max_records}"),
+                    )),
+                );
+            }
+        } else {
+            // verifies the bitmap fits the max account size, the account was reallocated in the constraint
+            account_initialization_size(max_merkle_nodes)?;
+            // no claim could be done before claiming starts, the bitmap is empty
+            settlement_claims.max_records = max_merkle_nodes;
+        }
+
+        let settlement = &mut ctx.accounts.settlement;
+        let old_merkle_root = settlement.merkle_root;
+        let old_max_total_claim = settlement.max_total_claim;
+        let old_max_merkle_nodes = settlement.max_merkle_nodes;
+        settlement.merkle_root = merkle_root;
+        settlement.max_total_claim = max_total_claim;
+        settlement.max_merkle_nodes = max_merkle_nodes;
+
+        emit_cpi!(ReplaceSettlementRootEvent {
+            bond: ctx.accounts.bond.key(),
+            settlement: ctx.accounts.settlement.key(),
+            operator_authority: ctx.accounts.operator_authority.key(),
+            old_merkle_root,
+            new_merkle_root: merkle_root,
+            max_total_claim: U64ValueChange {
+                old: old_max_total_claim,
+                new: max_total_claim,
+            },
+            max_merkle_nodes: U64ValueChange {
+                old: old_max_merkle_nodes,
+                new: max_merkle_nodes,
+            },
+        });
+
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 use crate::checks::get_delegation;
-use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, SETTLEMENT_STAKER_AUTHORITY_SEED};
+use crate::constants::{
+    BONDS_WITHDRAWER_AUTHORITY_SEED, PAUSE_STAKE_MANAGEMENT, SETTLEMENT_STAKER_AUTHORITY_SEED,
+};
 use crate::error::ErrorCode;
 use crate::events::stake::MergeStakeEvent;
 use crate::state::config::{find_bonds_withdrawer_authority, Config};
@@ -49,7 +51,10 @@ impl MergeStake<'_> {
         ctx: Context<MergeStake>,
         MergeStakeArgs { settlement }: MergeStakeArgs,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_STAKE_MANAGEMENT),
+            ErrorCode::ProgramIsPaused
+        );
 
         let destination_meta =
             ctx.accounts.destination_stake.meta().ok_or(
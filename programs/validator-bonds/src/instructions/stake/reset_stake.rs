@@ -1,7 +1,7 @@
 use crate::checks::{
     check_stake_is_initialized_with_withdrawer_authority, check_stake_valid_delegation, is_closed,
 };
-use crate::constants::BONDS_WITHDRAWER_AUTHORITY_SEED;
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, PAUSE_STAKE_MANAGEMENT};
 use crate::error::ErrorCode;
 use crate::events::stake::ResetStakeEvent;
 use crate::state::bond::Bond;
@@ -77,7 +77,10 @@ pub struct ResetStake<'info> {
 
 impl ResetStake<'_> {
     pub fn process(ctx: Context<ResetStake>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_STAKE_MANAGEMENT),
+            ErrorCode::ProgramIsPaused
+        );
 
         // The rule stipulates to reset only when the settlement does exist.
         require!(
@@ -2,7 +2,7 @@
 // allowing deprecation as anchor 0.29.0 works with old version of StakeState struct
 
 use crate::checks::{check_stake_is_initialized_with_withdrawer_authority, is_closed};
-use crate::constants::BONDS_WITHDRAWER_AUTHORITY_SEED;
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, PAUSE_STAKE_MANAGEMENT};
 use crate::error::ErrorCode;
 use crate::events::stake::WithdrawStakeEvent;
 use crate::state::config::Config;
@@ -60,7 +60,10 @@ pub struct WithdrawStake<'info> {
 
 impl WithdrawStake<'_> {
     pub fn process(ctx: Context<WithdrawStake>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_STAKE_MANAGEMENT),
+            ErrorCode::ProgramIsPaused
+        );
 
         // The rule stipulates to withdraw only when the settlement does exist.
         require!(
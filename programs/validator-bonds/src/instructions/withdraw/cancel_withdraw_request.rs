@@ -1,4 +1,5 @@
 use crate::checks::check_bond_authority;
+use crate::constants::PAUSE_WITHDRAW;
 use crate::error::ErrorCode;
 use crate::events::withdraw::CancelWithdrawRequestEvent;
 use crate::state::bond::Bond;
@@ -8,14 +9,16 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::vote::program::ID as vote_program_id;
 
 /// Cancelling a validator bond withdrawal request.
-/// Only one withdrawal request per bond is permitted.
+/// Only one non-indexed withdrawal request per bond is permitted.
 /// Cancelling makes way for a new request with a new amount.
+/// Indexed withdrawal requests are cancelled one by one, not affecting the others.
 #[event_cpi]
 #[derive(Accounts)]
 pub struct CancelWithdrawRequest<'info> {
     pub config: Account<'info, Config>,
 
     #[account(
+        mut,
         has_one = vote_account @ ErrorCode::VoteAccountMismatch,
         has_one = config @ ErrorCode::ConfigAccountMismatch,
         seeds = [
@@ -43,6 +46,7 @@ pub struct CancelWithdrawRequest<'info> {
         seeds = [
             b"withdraw_account",
             bond.key().as_ref(),
+            withdraw_request.index_seed().as_ref(),
         ],
         bump = withdraw_request.bump
     )]
@@ -55,7 +59,10 @@ pub struct CancelWithdrawRequest<'info> {
 
 impl CancelWithdrawRequest<'_> {
     pub fn process(ctx: Context<CancelWithdrawRequest>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_WITHDRAW),
+            ErrorCode::ProgramIsPaused
+        );
 
         require!(
             check_bond_authority(
@@ -66,6 +73,15 @@ impl CancelWithdrawRequest<'_> {
             ErrorCode::InvalidWithdrawRequestAuthority
         );
 
+        if ctx.accounts.withdraw_request.counted {
+            ctx.accounts.bond.open_withdraw_requests = ctx
+                .accounts
+                .bond
+                .open_withdraw_requests
+                .checked_sub(1)
+                .ok_or(ErrorCode::BondCounterUnderflow)?;
+        }
+
         emit_cpi!(CancelWithdrawRequestEvent {
             withdraw_request: ctx.accounts.withdraw_request.key(),
             bond: ctx.accounts.bond.key(),
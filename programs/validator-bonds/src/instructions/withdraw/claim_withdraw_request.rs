@@ -2,11 +2,12 @@ use crate::checks::{
     check_bond_authority, check_stake_is_initialized_with_withdrawer_authority,
     check_stake_valid_delegation,
 };
-use crate::constants::BONDS_WITHDRAWER_AUTHORITY_SEED;
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, PAUSE_WITHDRAW};
 use crate::error::ErrorCode;
 use crate::events::withdraw::ClaimWithdrawRequestEvent;
 use crate::events::{SplitStakeData, U64ValueChange};
 use crate::state::bond::Bond;
+use crate::state::bond_ledger::{bond_ledger_account, update_bond_ledger};
 use crate::state::config::Config;
 use crate::state::withdraw_request::WithdrawRequest;
 use crate::utils::{minimal_size_stake_account, return_unused_split_stake_account_rent};
@@ -20,6 +21,7 @@ use anchor_spl::stake::{authorize, Authorize, Stake, StakeAccount};
 /// The withdrawal process involves taking a StakeAccount associated with the bonds program
 /// and changing its owner (withdrawer and staker authorities) to the withdrawer account provided in this instruction
 /// (no check is performed against the vote account's authorized withdrawer).
+/// The optional bond ledger may be passed as the remaining account to record the withdrawn lamports.
 #[event_cpi]
 #[derive(Accounts)]
 pub struct ClaimWithdrawRequest<'info> {
@@ -55,6 +57,7 @@ pub struct ClaimWithdrawRequest<'info> {
         seeds = [
             b"withdraw_account",
             bond.key().as_ref(),
+            withdraw_request.index_seed().as_ref(),
         ],
         bump = withdraw_request.bump
     )]
@@ -108,7 +111,10 @@ pub struct ClaimWithdrawRequest<'info> {
 
 impl ClaimWithdrawRequest<'_> {
     pub fn process(ctx: Context<ClaimWithdrawRequest>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_WITHDRAW),
+            ErrorCode::ProgramIsPaused
+        );
 
         require_gt!(
             ctx.accounts
@@ -229,6 +235,12 @@ impl ClaimWithdrawRequest<'_> {
             .withdraw_request
             .withdrawn_amount
             .saturating_add(withdrawing_amount);
+        update_bond_ledger(
+            bond_ledger_account(ctx.remaining_accounts, &ctx.accounts.bond)?,
+            &ctx.accounts.bond.key(),
+            ctx.accounts.clock.epoch,
+            |record| record.withdrawn_lamports += withdrawing_amount,
+        )?;
 
         // changing owner of the stake account to entity defined in this ix (via withdraw request)
         authorize(
@@ -0,0 +1,112 @@
+use crate::checks::check_bond_authority;
+use crate::constants::PAUSE_WITHDRAW;
+use crate::error::ErrorCode;
+use crate::events::withdraw::InitWithdrawRequestEvent;
+use crate::instructions::InitWithdrawRequestArgs;
+use crate::state::bond::Bond;
+use crate::state::config::Config;
+use crate::state::withdraw_request::{withdraw_request_index_seed, WithdrawRequest};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::vote::program::ID as vote_program_id;
+
+/// Creates an additional withdrawal request when validator wants to withdraw the bond.
+/// Every indexed withdraw request runs its own lockup period,
+/// independently of the other withdraw requests created for the bond.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitIndexedWithdrawRequest<'info> {
+    /// the config account under which the bond was created
+    pub config: Account<'info, Config>,
+
+    /// bond account keeping the index of the last created withdraw request
+    #[account(
+        mut,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        has_one = vote_account @ ErrorCode::VoteAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            vote_account.key().as_ref()
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    /// CHECK: check&deserialize of the validator vote account in the code
+    #[account(
+        owner = vote_program_id @ ErrorCode::InvalidVoteAccountProgramId,
+    )]
+    pub vote_account: UncheckedAccount<'info>,
+
+    /// validator vote account node identity or bond authority may ask for the withdrawal
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<WithdrawRequest>(),
+        seeds = [
+            b"withdraw_account",
+            bond.key().as_ref(),
+            withdraw_request_index_seed(bond.withdraw_request_index + 1).as_ref(),
+        ],
+        bump,
+    )]
+    pub withdraw_request: Account<'info, WithdrawRequest>,
+
+    /// rent exempt payer of withdraw request account creation
+    #[account(
+        mut,
+        owner = system_program.key()
+    )]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl InitIndexedWithdrawRequest<'_> {
+    pub fn process(
+        ctx: Context<InitIndexedWithdrawRequest>,
+        InitWithdrawRequestArgs { amount }: InitWithdrawRequestArgs,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_WITHDRAW),
+            ErrorCode::ProgramIsPaused
+        );
+
+        require!(
+            check_bond_authority(
+                &ctx.accounts.authority.key(),
+                &ctx.accounts.bond,
+                &ctx.accounts.vote_account
+            ),
+            ErrorCode::InvalidWithdrawRequestAuthority
+        );
+
+        let index = ctx.accounts.bond.withdraw_request_index + 1;
+        ctx.accounts.bond.withdraw_request_index = index;
+        ctx.accounts.bond.open_withdraw_requests += 1;
+
+        let clock = Clock::get()?;
+        ctx.accounts.withdraw_request.set_inner(WithdrawRequest {
+            bond: ctx.accounts.bond.key(),
+            vote_account: ctx.accounts.bond.vote_account.key(),
+            bump: ctx.bumps.withdraw_request,
+            epoch: clock.epoch,
+            withdrawn_amount: 0,
+            requested_amount: amount,
+            index,
+            counted: true,
+            reserved: [0; 84],
+        });
+        emit_cpi!(InitWithdrawRequestEvent {
+            withdraw_request: ctx.accounts.withdraw_request.key(),
+            bond: ctx.accounts.withdraw_request.bond.key(),
+            vote_account: ctx.accounts.withdraw_request.vote_account.key(),
+            requested_amount: ctx.accounts.withdraw_request.requested_amount,
+            epoch: ctx.accounts.withdraw_request.epoch,
+        });
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use crate::checks::check_bond_authority;
+use crate::constants::PAUSE_WITHDRAW;
 use crate::error::ErrorCode;
 use crate::events::withdraw::InitWithdrawRequestEvent;
 use crate::state::bond::Bond;
@@ -20,6 +21,7 @@ pub struct InitWithdrawRequest<'info> {
     pub config: Account<'info, Config>,
 
     #[account(
+        mut,
         has_one = config @ ErrorCode::ConfigAccountMismatch,
         has_one = vote_account @ ErrorCode::VoteAccountMismatch,
         seeds = [
@@ -67,7 +69,10 @@ impl InitWithdrawRequest<'_> {
         ctx: Context<InitWithdrawRequest>,
         InitWithdrawRequestArgs { amount }: InitWithdrawRequestArgs,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_WITHDRAW),
+            ErrorCode::ProgramIsPaused
+        );
 
         require!(
             check_bond_authority(
@@ -78,6 +83,8 @@ impl InitWithdrawRequest<'_> {
             ErrorCode::InvalidWithdrawRequestAuthority
         );
 
+        ctx.accounts.bond.open_withdraw_requests += 1;
+
         let clock = Clock::get()?;
         ctx.accounts.withdraw_request.set_inner(WithdrawRequest {
             bond: ctx.accounts.bond.key(),
@@ -86,7 +93,9 @@ impl InitWithdrawRequest<'_> {
             epoch: clock.epoch,
             withdrawn_amount: 0,
             requested_amount: amount,
-            reserved: [0; 93],
+            index: 0,
+            counted: true,
+            reserved: [0; 84],
         });
         emit_cpi!(InitWithdrawRequestEvent {
             withdraw_request: ctx.accounts.withdraw_request.key(),
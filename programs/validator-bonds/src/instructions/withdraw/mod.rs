@@ -1,7 +1,11 @@
 pub mod cancel_withdraw_request;
 pub mod claim_withdraw_request;
+pub mod init_indexed_withdraw_request;
 pub mod init_withdraw_request;
+pub mod resize_withdraw_request;
 
 pub use cancel_withdraw_request::*;
 pub use claim_withdraw_request::*;
+pub use init_indexed_withdraw_request::*;
 pub use init_withdraw_request::*;
+pub use resize_withdraw_request::*;
@@ -0,0 +1,106 @@
+use crate::checks::check_bond_authority;
+use crate::constants::PAUSE_WITHDRAW;
+use crate::error::ErrorCode;
+use crate::events::withdraw::ResizeWithdrawRequestEvent;
+use crate::events::U64ValueChange;
+use crate::state::bond::Bond;
+use crate::state::config::Config;
+use crate::state::withdraw_request::WithdrawRequest;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::vote::program::ID as vote_program_id;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ResizeWithdrawRequestArgs {
+    /// new requested amount, it has to be lower than the current one
+    /// and not lower than the already withdrawn amount
+    pub amount: u64,
+}
+
+/// Lowering the requested amount of a validator bond withdrawal request.
+/// The withdraw request keeps its creation epoch, i.e., the lockup period is not restarted.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ResizeWithdrawRequest<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        has_one = vote_account @ ErrorCode::VoteAccountMismatch,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            vote_account.key().as_ref()
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    /// CHECK: check&deserialize of the vote account in the code
+    #[account(
+        owner = vote_program_id @ ErrorCode::InvalidVoteAccountProgramId,
+    )]
+    pub vote_account: UncheckedAccount<'info>,
+
+    /// validator vote account validator identity or bond authority may ask for resizing
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = bond @ ErrorCode::BondAccountMismatch,
+        seeds = [
+            b"withdraw_account",
+            bond.key().as_ref(),
+            withdraw_request.index_seed().as_ref(),
+        ],
+        bump = withdraw_request.bump
+    )]
+    pub withdraw_request: Account<'info, WithdrawRequest>,
+}
+
+impl ResizeWithdrawRequest<'_> {
+    pub fn process(
+        ctx: Context<ResizeWithdrawRequest>,
+        ResizeWithdrawRequestArgs { amount }: ResizeWithdrawRequestArgs,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(PAUSE_WITHDRAW),
+            ErrorCode::ProgramIsPaused
+        );
+
+        require!(
+            check_bond_authority(
+                &ctx.accounts.authority.key(),
+                &ctx.accounts.bond,
+                &ctx.accounts.vote_account
+            ),
+            ErrorCode::InvalidWithdrawRequestAuthority
+        );
+
+        let old_requested_amount = ctx.accounts.withdraw_request.requested_amount;
+        require_gt!(
+            old_requested_amount,
+            amount,
+            ErrorCode::WithdrawRequestResizeNotLowering
+        );
+        require_gte!(
+            amount,
+            ctx.accounts.withdraw_request.withdrawn_amount,
+            ErrorCode::WithdrawRequestResizeBelowWithdrawn
+        );
+
+        ctx.accounts.withdraw_request.requested_amount = amount;
+
+        emit_cpi!(ResizeWithdrawRequestEvent {
+            withdraw_request: ctx.accounts.withdraw_request.key(),
+            bond: ctx.accounts.bond.key(),
+            authority: ctx.accounts.authority.key(),
+            requested_amount: U64ValueChange {
+                old: old_requested_amount,
+                new: amount,
+            },
+            withdrawn_amount: ctx.accounts.withdraw_request.withdrawn_amount,
+        });
+
+        Ok(())
+    }
+}
@@ -38,9 +38,7 @@ security_txt! {
 declare_id!("vBoNdEvzMrSai7is21XgVYik65mqtaKXuSdMBJ1xkW4");
 
 fn check_context<T: Bumps>(ctx: &Context<T>) -> Result<()> {
-    if !check_id(ctx.program_id) {
-        return err!(ErrorCode::InvalidProgramId);
-    }
+    check_program_id(ctx)?;
     // make sure there are no extra accounts
     if !ctx.remaining_accounts.is_empty() {
         return err!(ErrorCode::UnexpectedRemainingAccounts);
@@ -48,6 +46,15 @@ fn check_context<T: Bumps>(ctx: &Context<T>) -> Result<()> {
 
     Ok(())
 }
+
+/// Instructions accepting remaining accounts, i.e., the settlement claims chunks and the bond ledger
+fn check_program_id<T: Bumps>(ctx: &Context<T>) -> Result<()> {
+    if !check_id(ctx.program_id) {
+        return err!(ErrorCode::InvalidProgramId);
+    }
+    Ok(())
+}
+
 #[program]
 pub mod validator_bonds {
     use super::*;
@@ -65,6 +72,42 @@ pub mod validator_bonds {
         ConfigureConfig::process(ctx, configure_config_args)
     }
 
+    pub fn queue_configure_config(
+        ctx: Context<QueueConfigureConfig>,
+        queue_configure_config_args: QueueConfigureConfigArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        QueueConfigureConfig::process(ctx, queue_configure_config_args)
+    }
+
+    pub fn execute_configure_config(ctx: Context<ExecuteConfigureConfig>) -> Result<()> {
+        check_context(&ctx)?;
+        ExecuteConfigureConfig::process(ctx)
+    }
+
+    pub fn cancel_queued_config(ctx: Context<CancelQueuedConfig>) -> Result<()> {
+        check_context(&ctx)?;
+        CancelQueuedConfig::process(ctx)
+    }
+
+    pub fn propose_config_authority(
+        ctx: Context<ProposeConfigAuthority>,
+        propose_config_authority_args: ProposeConfigAuthorityArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ProposeConfigAuthority::process(ctx, propose_config_authority_args)
+    }
+
+    pub fn accept_config_authority(ctx: Context<AcceptConfigAuthority>) -> Result<()> {
+        check_context(&ctx)?;
+        AcceptConfigAuthority::process(ctx)
+    }
+
+    pub fn cancel_config_authority(ctx: Context<CancelConfigAuthority>) -> Result<()> {
+        check_context(&ctx)?;
+        CancelConfigAuthority::process(ctx)
+    }
+
     pub fn init_bond(ctx: Context<InitBond>, init_bond_args: InitBondArgs) -> Result<()> {
         check_context(&ctx)?;
         InitBond::process(ctx, init_bond_args)
@@ -86,6 +129,16 @@ pub mod validator_bonds {
         ConfigureBondWithMint::process(ctx, args)
     }
 
+    pub fn accept_bond_authority(ctx: Context<AcceptBondAuthority>) -> Result<()> {
+        check_context(&ctx)?;
+        AcceptBondAuthority::process(ctx)
+    }
+
+    pub fn cancel_bond_authority(ctx: Context<CancelBondAuthority>) -> Result<()> {
+        check_context(&ctx)?;
+        CancelBondAuthority::process(ctx)
+    }
+
     pub fn init_bond_product(
         ctx: Context<InitBondProduct>,
         init_bond_product_args: InitBondProductArgs,
@@ -102,16 +155,75 @@ pub mod validator_bonds {
         ConfigureBondProduct::process(ctx, configure_bond_product_args)
     }
 
+    pub fn close_bond_product(ctx: Context<CloseBondProduct>) -> Result<()> {
+        check_context(&ctx)?;
+        CloseBondProduct::process(ctx)
+    }
+
+    pub fn close_bond<'info>(ctx: Context<'_, '_, '_, 'info, CloseBond<'info>>) -> Result<()> {
+        check_program_id(&ctx)?;
+        CloseBond::process(ctx)
+    }
+
+    pub fn count_bond_accounts<'info>(
+        ctx: Context<'_, '_, '_, 'info, CountBondAccounts<'info>>,
+    ) -> Result<()> {
+        check_program_id(&ctx)?;
+        CountBondAccounts::process(ctx)
+    }
+
     pub fn mint_bond(ctx: Context<MintBond>) -> Result<()> {
         check_context(&ctx)?;
         MintBond::process(ctx)
     }
 
+    pub fn init_bond_ledger(ctx: Context<InitBondLedger>) -> Result<()> {
+        check_context(&ctx)?;
+        InitBondLedger::process(ctx)
+    }
+
+    pub fn close_bond_ledger(ctx: Context<CloseBondLedger>) -> Result<()> {
+        check_context(&ctx)?;
+        CloseBondLedger::process(ctx)
+    }
+
     pub fn fund_bond(ctx: Context<FundBond>) -> Result<()> {
         check_context(&ctx)?;
         FundBond::process(ctx)
     }
 
+    pub fn init_collateral_mint(
+        ctx: Context<InitCollateralMint>,
+        init_collateral_mint_args: InitCollateralMintArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        InitCollateralMint::process(ctx, init_collateral_mint_args)
+    }
+
+    pub fn configure_collateral_mint(
+        ctx: Context<ConfigureCollateralMint>,
+        configure_collateral_mint_args: ConfigureCollateralMintArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ConfigureCollateralMint::process(ctx, configure_collateral_mint_args)
+    }
+
+    pub fn update_collateral_exchange_rate(
+        ctx: Context<UpdateCollateralExchangeRate>,
+        update_collateral_exchange_rate_args: UpdateCollateralExchangeRateArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        UpdateCollateralExchangeRate::process(ctx, update_collateral_exchange_rate_args)
+    }
+
+    pub fn fund_bond_token(
+        ctx: Context<FundBondToken>,
+        fund_bond_token_args: FundBondTokenArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        FundBondToken::process(ctx, fund_bond_token_args)
+    }
+
     pub fn init_withdraw_request(
         ctx: Context<InitWithdrawRequest>,
         create_withdraw_request_args: InitWithdrawRequestArgs,
@@ -120,16 +232,40 @@ pub mod validator_bonds {
         InitWithdrawRequest::process(ctx, create_withdraw_request_args)
     }
 
+    pub fn init_indexed_withdraw_request(
+        ctx: Context<InitIndexedWithdrawRequest>,
+        create_withdraw_request_args: InitWithdrawRequestArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        InitIndexedWithdrawRequest::process(ctx, create_withdraw_request_args)
+    }
+
     pub fn cancel_withdraw_request(ctx: Context<CancelWithdrawRequest>) -> Result<()> {
         check_context(&ctx)?;
         CancelWithdrawRequest::process(ctx)
     }
 
-    pub fn claim_withdraw_request(ctx: Context<ClaimWithdrawRequest>) -> Result<()> {
+    pub fn resize_withdraw_request(
+        ctx: Context<ResizeWithdrawRequest>,
+        resize_withdraw_request_args: ResizeWithdrawRequestArgs,
+    ) -> Result<()> {
         check_context(&ctx)?;
+        ResizeWithdrawRequest::process(ctx, resize_withdraw_request_args)
+    }
+
+    pub fn claim_withdraw_request(ctx: Context<ClaimWithdrawRequest>) -> Result<()> {
+        check_program_id(&ctx)?;
         ClaimWithdrawRequest::process(ctx)
     }
 
+    pub fn withdraw_bond_token(
+        ctx: Context<WithdrawBondToken>,
+        withdraw_bond_token_args: WithdrawBondTokenArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        WithdrawBondToken::process(ctx, withdraw_bond_token_args)
+    }
+
     pub fn init_settlement(
         ctx: Context<InitSettlement>,
         init_settlement_args: InitSettlementArgs,
@@ -138,21 +274,60 @@ pub mod validator_bonds {
         InitSettlement::process(ctx, init_settlement_args)
     }
 
+    pub fn init_replaceable_settlement(
+        ctx: Context<InitReplaceableSettlement>,
+        init_replaceable_settlement_args: InitReplaceableSettlementArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        InitReplaceableSettlement::process(ctx, init_replaceable_settlement_args)
+    }
+
+    pub fn replace_settlement_root(
+        ctx: Context<ReplaceSettlementRoot>,
+        replace_settlement_root_args: ReplaceSettlementRootArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ReplaceSettlementRoot::process(ctx, replace_settlement_root_args)
+    }
+
     pub fn upsize_settlement_claims(ctx: Context<UpsizeSettlementClaims>) -> Result<()> {
         check_context(&ctx)?;
         UpsizeSettlementClaims::process(ctx)
     }
 
-    pub fn cancel_settlement(ctx: Context<CancelSettlement>) -> Result<()> {
+    pub fn init_settlement_claims_chunk(
+        ctx: Context<InitSettlementClaimsChunk>,
+        init_settlement_claims_chunk_args: InitSettlementClaimsChunkArgs,
+    ) -> Result<()> {
         check_context(&ctx)?;
+        InitSettlementClaimsChunk::process(ctx, init_settlement_claims_chunk_args)
+    }
+
+    pub fn cancel_settlement<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelSettlement<'info>>,
+    ) -> Result<()> {
+        check_program_id(&ctx)?;
         CancelSettlement::process(ctx)
     }
 
-    pub fn fund_settlement(ctx: Context<FundSettlement>) -> Result<()> {
+    pub fn extend_settlement(
+        ctx: Context<ExtendSettlement>,
+        extend_settlement_args: ExtendSettlementArgs,
+    ) -> Result<()> {
         check_context(&ctx)?;
+        ExtendSettlement::process(ctx, extend_settlement_args)
+    }
+
+    pub fn fund_settlement(ctx: Context<FundSettlement>) -> Result<()> {
+        check_program_id(&ctx)?;
         FundSettlement::process(ctx)
     }
 
+    pub fn fund_settlement_token(ctx: Context<FundSettlementToken>) -> Result<()> {
+        check_program_id(&ctx)?;
+        FundSettlementToken::process(ctx)
+    }
+
     pub fn merge_stake(ctx: Context<MergeStake>, merge_args: MergeStakeArgs) -> Result<()> {
         check_context(&ctx)?;
         MergeStake::process(ctx, merge_args)
@@ -168,18 +343,26 @@ pub mod validator_bonds {
         WithdrawStake::process(ctx)
     }
 
-    pub fn emergency_pause(ctx: Context<EmergencyPauseResume>) -> Result<()> {
+    pub fn emergency_pause(
+        ctx: Context<EmergencyPauseResume>,
+        emergency_pause_args: EmergencyPauseResumeArgs,
+    ) -> Result<()> {
         check_context(&ctx)?;
-        EmergencyPauseResume::pause(ctx)
+        EmergencyPauseResume::pause(ctx, emergency_pause_args)
     }
 
-    pub fn emergency_resume(ctx: Context<EmergencyPauseResume>) -> Result<()> {
+    pub fn emergency_resume(
+        ctx: Context<EmergencyPauseResume>,
+        emergency_resume_args: EmergencyPauseResumeArgs,
+    ) -> Result<()> {
         check_context(&ctx)?;
-        EmergencyPauseResume::resume(ctx)
+        EmergencyPauseResume::resume(ctx, emergency_resume_args)
     }
 
-    pub fn close_settlement_v2(ctx: Context<CloseSettlementV2>) -> Result<()> {
-        check_context(&ctx)?;
+    pub fn close_settlement_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseSettlementV2<'info>>,
+    ) -> Result<()> {
+        check_program_id(&ctx)?;
         CloseSettlementV2::process(ctx)
     }
 
@@ -187,10 +370,26 @@ pub mod validator_bonds {
         ctx: Context<ClaimSettlementV2>,
         claim_settlement_args: ClaimSettlementV2Args,
     ) -> Result<()> {
-        check_context(&ctx)?;
+        check_program_id(&ctx)?;
         ClaimSettlementV2::process(ctx, claim_settlement_args)
     }
 
+    pub fn claim_settlement_batch(
+        ctx: Context<ClaimSettlementBatch>,
+        claim_settlement_batch_args: ClaimSettlementBatchArgs,
+    ) -> Result<()> {
+        check_program_id(&ctx)?;
+        ClaimSettlementBatch::process(ctx, claim_settlement_batch_args)
+    }
+
+    pub fn claim_settlement_redirect(
+        ctx: Context<ClaimSettlementRedirect>,
+        claim_settlement_args: ClaimSettlementV2Args,
+    ) -> Result<()> {
+        check_program_id(&ctx)?;
+        ClaimSettlementRedirect::process(ctx, claim_settlement_args)
+    }
+
     // // Enable to force IDL to include ClaimSettlementV1
     // // Per Anchor changes (0.31.0) the account is included in the IDL only if used in the program code
     // pub fn claim_settlement_v1(ctx: Context<ClaimSettlementV1>) -> Result<()> {
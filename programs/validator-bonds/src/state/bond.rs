@@ -35,8 +35,32 @@ pub struct Bond {
     /// The vote account owner then goes to auction to obtain up to that maximum.
     /// Use the `cpmpe` field to define the bid for this purpose.
     pub max_stake_wanted: u64,
+    /// Index of the last created indexed withdraw request.
+    /// The index 0 is occupied by the (legacy) withdraw request that is derived without the index seed.
+    pub withdraw_request_index: u64,
+    /// Proposed bond authority that has to accept the role; default pubkey when nothing is proposed
+    pub pending_authority: Pubkey,
+    /// Number of settlements created for the bond and not closed yet.
+    /// Settlements created before the counter was introduced are not counted.
+    pub open_settlements: u64,
+    /// Number of withdraw requests created for the bond and not cancelled yet.
+    /// Withdraw requests created before the counter was introduced are not counted.
+    pub open_withdraw_requests: u64,
+    /// Number of bond products created for the bond and not closed yet.
+    /// Bond products created before the counter was introduced are not counted.
+    pub open_bond_products: u64,
+    /// Number of bond token vaults holding a collateral.
+    /// An emptied vault account is left behind and is reused when the bond is re-created by init_bond.
+    pub funded_token_vaults: u64,
+    /// PDA bump of the bond ledger account, set while the bond ledger exists.
+    /// The instructions updating the bond ledger require it as the last remaining account then.
+    pub bond_ledger_bump: Option<u8>,
+    /// All open settlements, withdraw requests and bond products of the bond are counted in the counters.
+    /// Set by init_bond; a bond created before the counters were introduced has it set
+    /// by the operator with count_bond_accounts.
+    pub accounts_counted: bool,
     /// reserve space for future extensions
-    pub reserved: [u8; 134],
+    pub reserved: [u8; 59],
 }
 
 impl Bond {
@@ -52,6 +76,16 @@ impl Bond {
         )
         .map_err(|_| ErrorCode::InvalidBondAddress.into())
     }
+
+    /// Counts the bond token vault as funded when its balance changes from zero and vice versa.
+    // Tokens transferred to the vault directly are not counted, the counter does not go below zero then.
+    pub fn update_funded_token_vaults(&mut self, old_amount: u64, new_amount: u64) {
+        if old_amount == 0 && new_amount > 0 {
+            self.funded_token_vaults += 1;
+        } else if old_amount > 0 && new_amount == 0 {
+            self.funded_token_vaults = self.funded_token_vaults.saturating_sub(1);
+        }
+    }
 }
 
 pub fn find_bond_address(config: &Pubkey, vote_account: &Pubkey) -> (Pubkey, u8) {
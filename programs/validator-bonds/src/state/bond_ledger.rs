@@ -0,0 +1,167 @@
+use crate::constants::{BOND_LEDGER_EPOCHS, BOND_LEDGER_SEED};
+use crate::error::ErrorCode;
+use crate::state::bond::Bond;
+use crate::ID;
+use anchor_lang::prelude::*;
+
+/// Counters of the bond operations within one epoch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BondLedgerRecord {
+    /// epoch the counters are collected for
+    pub epoch: u64,
+    /// lamports of the bond stake accounts funded to settlements
+    pub funded_lamports: u64,
+    /// lamports claimed from the bond settlements
+    pub claimed_lamports: u64,
+    /// lamports withdrawn from the bond by claimed withdraw requests
+    pub withdrawn_lamports: u64,
+    /// number of the bond settlements closed
+    pub closed_settlements: u32,
+}
+
+/// History of the bond operations for the last BOND_LEDGER_EPOCHS epochs.
+/// The records are stored in a ring buffer indexed by the epoch,
+/// a record of an older epoch is overwritten when the epoch's slot is used again.
+#[account]
+#[derive(Debug)]
+pub struct BondLedger {
+    /// Bond account the ledger is created for
+    pub bond: Pubkey,
+    /// PDA bond ledger address bump seed
+    pub bump: u8,
+    /// Records of the ring buffer, the record of an epoch is at index `epoch % BOND_LEDGER_EPOCHS`
+    pub records: [BondLedgerRecord; BOND_LEDGER_EPOCHS as usize],
+    /// reserve space for future extensions
+    pub reserved: [u8; 64],
+}
+
+impl BondLedger {
+    pub fn address(&self) -> Result<Pubkey> {
+        Pubkey::create_program_address(&[BOND_LEDGER_SEED, self.bond.as_ref(), &[self.bump]], &ID)
+            .map_err(|_| ErrorCode::BondLedgerMismatch.into())
+    }
+
+    /// Record of the epoch when still kept in the ring buffer
+    pub fn record(&self, epoch: u64) -> Option<&BondLedgerRecord> {
+        let record = &self.records[(epoch % BOND_LEDGER_EPOCHS) as usize];
+        (record.epoch == epoch).then_some(record)
+    }
+
+    /// Record of the epoch to be updated, the record of an older epoch is reset
+    pub fn record_mut(&mut self, epoch: u64) -> &mut BondLedgerRecord {
+        let record = &mut self.records[(epoch % BOND_LEDGER_EPOCHS) as usize];
+        if record.epoch != epoch {
+            *record = BondLedgerRecord {
+                epoch,
+                ..BondLedgerRecord::default()
+            };
+        }
+        record
+    }
+}
+
+pub fn find_bond_ledger_address(bond: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BOND_LEDGER_SEED, bond.as_ref()], &ID)
+}
+
+/// The bond ledger is passed as the last of the remaining accounts, it is required when the bond ledger
+/// was created for the bond. Returns the remaining accounts without the bond ledger and the bond ledger account.
+pub fn split_bond_ledger<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    bond: &Account<Bond>,
+) -> Result<(&'a [AccountInfo<'info>], Option<&'a AccountInfo<'info>>)> {
+    let bond_ledger_bump = if let Some(bump) = bond.bond_ledger_bump {
+        bump
+    } else {
+        return Ok((remaining_accounts, None));
+    };
+    let bond_ledger_address = Pubkey::create_program_address(
+        &[BOND_LEDGER_SEED, bond.key().as_ref(), &[bond_ledger_bump]],
+        &ID,
+    )
+    .map_err(|_| ErrorCode::BondLedgerMismatch)?;
+    match remaining_accounts.split_last() {
+        Some((last, rest)) if last.key() == bond_ledger_address => Ok((rest, Some(last))),
+        _ => Err(error!(ErrorCode::BondLedgerMissing)
+            .with_account_name("bond_ledger")
+            .with_pubkeys((bond.key(), bond_ledger_address))),
+    }
+}
+
+/// Bond ledger passed as the only remaining account, no other remaining account is permitted
+pub fn bond_ledger_account<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    bond: &Account<Bond>,
+) -> Result<Option<&'a AccountInfo<'info>>> {
+    let (other_accounts, bond_ledger) = split_bond_ledger(remaining_accounts, bond)?;
+    if !other_accounts.is_empty() {
+        return err!(ErrorCode::UnexpectedRemainingAccounts);
+    }
+    Ok(bond_ledger)
+}
+
+/// Updates the bond ledger record of the epoch, no-op when the bond has no bond ledger
+pub fn update_bond_ledger(
+    bond_ledger_account: Option<&AccountInfo>,
+    bond: &Pubkey,
+    epoch: u64,
+    update: impl FnOnce(&mut BondLedgerRecord),
+) -> Result<()> {
+    let bond_ledger_account = if let Some(bond_ledger_account) = bond_ledger_account {
+        bond_ledger_account
+    } else {
+        return Ok(());
+    };
+    require!(
+        bond_ledger_account.is_writable,
+        anchor_lang::error::ErrorCode::ConstraintMut
+    );
+    let mut bond_ledger = BondLedger::try_deserialize(&mut &bond_ledger_account.data.borrow()[..])?;
+    require_keys_eq!(bond_ledger.bond, *bond, ErrorCode::BondLedgerMismatch);
+    update(bond_ledger.record_mut(epoch));
+    bond_ledger.try_serialize(&mut &mut bond_ledger_account.data.borrow_mut()[..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ring_buffer_records() {
+        let mut bond_ledger = BondLedger {
+            bond: Pubkey::default(),
+            bump: 0,
+            records: [BondLedgerRecord::default(); BOND_LEDGER_EPOCHS as usize],
+            reserved: [0; 64],
+        };
+        bond_ledger.record_mut(700).funded_lamports += 10;
+        bond_ledger.record_mut(700).claimed_lamports += 5;
+        bond_ledger.record_mut(701).closed_settlements += 1;
+        assert_eq!(
+            bond_ledger.record(700),
+            Some(&BondLedgerRecord {
+                epoch: 700,
+                funded_lamports: 10,
+                claimed_lamports: 5,
+                withdrawn_lamports: 0,
+                closed_settlements: 0,
+            })
+        );
+        assert_eq!(bond_ledger.record(701).unwrap().closed_settlements, 1);
+        assert_eq!(bond_ledger.record(702), None);
+
+        // the same ring buffer slot is reused after BOND_LEDGER_EPOCHS epochs
+        bond_ledger
+            .record_mut(700 + BOND_LEDGER_EPOCHS)
+            .withdrawn_lamports += 3;
+        assert_eq!(bond_ledger.record(700), None);
+        assert_eq!(
+            bond_ledger
+                .record(700 + BOND_LEDGER_EPOCHS)
+                .unwrap()
+                .withdrawn_lamports,
+            3
+        );
+        assert_eq!(bond_ledger.record(701).unwrap().closed_settlements, 1);
+    }
+}
@@ -6,6 +6,7 @@ use anchor_lang::prelude::*;
 // considering reasonable to allocate some additional space for future upgrades of data structs
 pub const ADDITIONAL_ACCOUNT_INIT_SPACE: usize = 100;
 const MAX_BASIS_POINTS: i64 = 10_000;
+const MAX_UPTIME_BASIS_POINTS: u64 = 10_000;
 
 /// Product type discriminator
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
@@ -13,6 +14,8 @@ pub enum ProductType {
     Commission,
     /// for future extensibility without program upgrade
     Custom(String),
+    UptimeGuarantee,
+    MaxStakeCap,
 }
 
 impl ProductType {
@@ -20,6 +23,8 @@ impl ProductType {
         match self {
             ProductType::Commission => b"commission",
             ProductType::Custom(name) => name.as_bytes(),
+            ProductType::UptimeGuarantee => b"uptime_guarantee",
+            ProductType::MaxStakeCap => b"max_stake_cap",
         }
     }
 }
@@ -37,6 +42,8 @@ pub enum ProductTypeConfig {
     Commission(CommissionProductConfig),
     /// Raw bytes for custom/future product types
     Custom(Vec<u8>),
+    UptimeGuarantee(UptimeGuaranteeProductConfig),
+    MaxStakeCap(MaxStakeCapProductConfig),
 }
 
 impl ValidateProductTypeConfig for ProductTypeConfig {
@@ -44,6 +51,8 @@ impl ValidateProductTypeConfig for ProductTypeConfig {
         match self {
             ProductTypeConfig::Commission(config) => config.validate(),
             ProductTypeConfig::Custom(_) => Ok(()),
+            ProductTypeConfig::UptimeGuarantee(config) => config.validate(),
+            ProductTypeConfig::MaxStakeCap(config) => config.validate(),
         }
     }
 }
@@ -56,6 +65,12 @@ impl ProductTypeConfig {
             )),
             ProductType::Custom(_) => Err(error!(ErrorCode::ProductTypeConfigValidationFailure)
                 .with_values(("reason", "No default for custom product type"))),
+            ProductType::UptimeGuarantee => {
+                Err(error!(ErrorCode::ProductTypeConfigValidationFailure)
+                    .with_values(("reason", "No default for uptime guarantee product type")))
+            }
+            ProductType::MaxStakeCap => Err(error!(ErrorCode::ProductTypeConfigValidationFailure)
+                .with_values(("reason", "No default for max stake cap product type"))),
         }
     }
 }
@@ -95,6 +110,50 @@ impl ValidateProductTypeConfig for CommissionProductConfig {
     }
 }
 
+/// Uptime guaranteed by the validator, in basis points of the vote credits
+/// the validator is expected to earn within an epoch.
+/// Stakers are compensated from the bond when the uptime drops below the guarantee.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct UptimeGuaranteeProductConfig {
+    pub min_uptime_bps: u64,
+}
+
+impl ValidateProductTypeConfig for UptimeGuaranteeProductConfig {
+    fn validate(&self) -> Result<()> {
+        if self.min_uptime_bps > MAX_UPTIME_BASIS_POINTS {
+            return Err(error!(ErrorCode::ProductTypeConfigValidationFailure)
+                .with_values(("min_uptime_bps", self.min_uptime_bps))
+                .with_values(("max", MAX_UPTIME_BASIS_POINTS)));
+        }
+        Ok(())
+    }
+}
+
+/// Maximum stake (in lamports) the validator accepts to be covered by the bond.
+/// The stake above the cap is not protected by the bond.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct MaxStakeCapProductConfig {
+    pub lamports: u64,
+}
+
+impl ValidateProductTypeConfig for MaxStakeCapProductConfig {
+    fn validate(&self) -> Result<()> {
+        if self.lamports == 0 {
+            return Err(error!(ErrorCode::ProductTypeConfigValidationFailure)
+                .with_values(("lamports", self.lamports))
+                .with_values(("reason", "max stake cap has to be positive")));
+        }
+        Ok(())
+    }
+}
+
+/// Product configuration scheduled to replace the current one from the epoch on.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PendingProductConfig {
+    pub config_data: ProductTypeConfig,
+    pub effective_from_epoch: u64,
+}
+
 /// Bond products configuration.
 /// Validator configures different products on a bond to offer various staking services.
 #[account]
@@ -112,6 +171,11 @@ pub struct BondProduct {
     pub config_data: ProductTypeConfig,
     /// Bump seed
     pub bump: u8,
+    /// Configuration that replaces `config_data` from its effective epoch on
+    pub pending_config: Option<PendingProductConfig>,
+    /// Bond product is counted in the bond `open_bond_products`;
+    /// not set for bond products created before the counter was introduced
+    pub counted: bool,
 }
 
 impl BondProduct {
@@ -127,6 +191,24 @@ impl BondProduct {
         )
         .map_err(|_| crate::error::ErrorCode::InvalidBondProductAddress.into())
     }
+
+    /// Configuration applied in the epoch.
+    /// The pending configuration is applied from its effective epoch on, the current one before it.
+    pub fn config_for_epoch(&self, epoch: u64) -> &ProductTypeConfig {
+        match &self.pending_config {
+            Some(pending) if epoch >= pending.effective_from_epoch => &pending.config_data,
+            _ => &self.config_data,
+        }
+    }
+
+    /// Moves the pending configuration to the current one when its effective epoch is reached.
+    pub fn apply_pending_config(&mut self, epoch: u64) {
+        if matches!(&self.pending_config, Some(pending) if epoch >= pending.effective_from_epoch) {
+            if let Some(pending) = self.pending_config.take() {
+                self.config_data = pending.config_data;
+            }
+        }
+    }
 }
 
 impl BondProduct {
@@ -136,9 +218,10 @@ impl BondProduct {
     /// Calculate total space needed for this product
     pub fn calculate_space(product_type: &ProductType, config_data: &ProductTypeConfig) -> usize {
         Self::DISCRIMINATOR_LEN
-            + 32 + 32 + 32 + 1 // pubkeys + bump
+            + 32 + 32 + 32 + 1 + 1 // pubkeys + bump + counted
             + Self::product_type_size(product_type)
             + Self::config_data_size(config_data)
+            + Self::pending_config_size(config_data)
             + ADDITIONAL_ACCOUNT_INIT_SPACE
     }
 
@@ -149,9 +232,15 @@ impl BondProduct {
                 ProductType::Custom(name) => {
                     4 + name.len() // string length (u32) + string bytes
                 }
+                ProductType::UptimeGuarantee | ProductType::MaxStakeCap => 0,
             }
     }
 
+    /// Space for a pending configuration of the same size as the current one
+    fn pending_config_size(config_data: &ProductTypeConfig) -> usize {
+        1 + Self::config_data_size(config_data) + 8 // option discriminator + config data + effective epoch
+    }
+
     fn config_data_size(config_data: &ProductTypeConfig) -> usize {
         Self::ENUM_DISCRIMINATOR_LEN
             + match config_data {
@@ -161,6 +250,12 @@ impl BondProduct {
                     // "we often end up reserving 4 or 8 bytes for an enum discriminant"
                     8 + 4 + bytes.len() // nested enum serialization reserve + vec length prefix (u32) + data bytes
                 }
+                ProductTypeConfig::UptimeGuarantee(_) => {
+                    std::mem::size_of::<UptimeGuaranteeProductConfig>()
+                }
+                ProductTypeConfig::MaxStakeCap(_) => {
+                    std::mem::size_of::<MaxStakeCapProductConfig>()
+                }
             }
     }
 }
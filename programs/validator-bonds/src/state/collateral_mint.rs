@@ -0,0 +1,202 @@
+use crate::constants::{BOND_TOKEN_VAULT_SEED, COLLATERAL_MINT_SEED};
+use crate::error::ErrorCode;
+use crate::ID;
+use anchor_lang::prelude::*;
+
+pub const MAX_HAIRCUT_BPS: u64 = 10_000;
+
+/// Number of decimals of SOL, the collateral mint is required to have the same decimals.
+pub const COLLATERAL_MINT_DECIMALS: u8 = 9;
+
+/// Exchange rate is the lamports value of this amount of token base units (i.e., of one token).
+pub const EXCHANGE_RATE_SCALE: u64 = 1_000_000_000;
+
+/// Token mint permitted by the config admin to be used as a bond collateral (e.g., wSOL, LST).
+/// The token amount is valued in lamports by the exchange rate lowered by the haircut,
+/// the haircut covers the risk of the token price deviating from the exchange rate.
+#[account]
+#[derive(Debug)]
+pub struct CollateralMint {
+    /// Config account the collateral mint is permitted for
+    pub config: Pubkey,
+    /// SPL token mint of the collateral
+    pub mint: Pubkey,
+    /// Haircut applied on the token amount when valued in lamports (basis points)
+    pub haircut_bps: u64,
+    /// When disabled, the bonds cannot be funded with the mint anymore,
+    /// while withdrawing and funding settlements is still permitted
+    pub enabled: bool,
+    /// PDA account bump
+    pub bump: u8,
+    /// Lamports value of one token (EXCHANGE_RATE_SCALE of token base units), updated by the operator
+    pub exchange_rate: u64,
+    /// Epoch of the last exchange rate update, settlement funding requires the rate of the current epoch
+    pub exchange_rate_epoch: u64,
+    /// reserved space for future changes
+    pub reserved: [u8; 48],
+}
+
+impl CollateralMint {
+    pub fn address(&self) -> Result<Pubkey> {
+        Pubkey::create_program_address(
+            &[
+                COLLATERAL_MINT_SEED,
+                self.config.as_ref(),
+                self.mint.as_ref(),
+                &[self.bump],
+            ],
+            &ID,
+        )
+        .map_err(|_| ErrorCode::InvalidCollateralMintAddress.into())
+    }
+
+    /// Lamports value of the token amount by the exchange rate after the haircut is applied (rounded down).
+    pub fn lamports_value(&self, token_amount: u64) -> u64 {
+        let value = token_amount as u128 * self.exchange_rate as u128 / EXCHANGE_RATE_SCALE as u128;
+        let value = value * self.value_bps() / MAX_HAIRCUT_BPS as u128;
+        value.min(u64::MAX as u128) as u64
+    }
+
+    /// Lamports value of the token amount by the exchange rate after the haircut is applied (rounded up).
+    /// Used to account the withdrawn tokens, any withdrawn token base unit is accounted.
+    pub fn lamports_value_ceil(&self, token_amount: u64) -> u64 {
+        let value = (token_amount as u128 * self.exchange_rate as u128)
+            .div_ceil(EXCHANGE_RATE_SCALE as u128);
+        let value = (value * self.value_bps()).div_ceil(MAX_HAIRCUT_BPS as u128);
+        value.min(u64::MAX as u128) as u64
+    }
+
+    /// Token amount the liquidator receives for the lamports funded into a settlement.
+    /// The lamports are exchanged by the exchange rate without the haircut (rounded up),
+    /// i.e., the liquidator receives at least the exchange rate value of the funded lamports.
+    pub fn liquidation_token_amount(&self, lamports: u64) -> Result<u64> {
+        require_gt!(
+            self.exchange_rate,
+            0,
+            ErrorCode::InvalidCollateralExchangeRate
+        );
+        let token_amount =
+            (lamports as u128 * EXCHANGE_RATE_SCALE as u128).div_ceil(self.exchange_rate as u128);
+        u64::try_from(token_amount).map_err(|_| ErrorCode::InsufficientBondTokenCollateral.into())
+    }
+
+    fn value_bps(&self) -> u128 {
+        MAX_HAIRCUT_BPS.saturating_sub(self.haircut_bps) as u128
+    }
+}
+
+pub fn find_collateral_mint_address(config: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COLLATERAL_MINT_SEED, config.as_ref(), mint.as_ref()], &ID)
+}
+
+pub fn find_bond_token_vault_address(bond: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BOND_TOKEN_VAULT_SEED, bond.as_ref(), mint.as_ref()], &ID)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collateral_mint(haircut_bps: u64, exchange_rate: u64) -> CollateralMint {
+        CollateralMint {
+            config: Pubkey::default(),
+            mint: Pubkey::default(),
+            haircut_bps,
+            enabled: true,
+            bump: 0,
+            exchange_rate,
+            exchange_rate_epoch: 0,
+            reserved: [0; 48],
+        }
+    }
+
+    #[test]
+    fn lamports_value_with_haircut() {
+        assert_eq!(
+            collateral_mint(0, EXCHANGE_RATE_SCALE).lamports_value(1_000),
+            1_000
+        );
+        assert_eq!(
+            collateral_mint(500, EXCHANGE_RATE_SCALE).lamports_value(1_000),
+            950
+        );
+        assert_eq!(
+            collateral_mint(333, EXCHANGE_RATE_SCALE).lamports_value(10),
+            9
+        );
+        assert_eq!(
+            collateral_mint(0, EXCHANGE_RATE_SCALE).lamports_value(u64::MAX),
+            u64::MAX,
+            "no overflow on full value"
+        );
+    }
+
+    #[test]
+    fn lamports_value_by_exchange_rate() {
+        // 1 token = 1.2 SOL
+        let collateral_mint = collateral_mint(500, 1_200_000_000);
+        assert_eq!(collateral_mint.lamports_value(1_000), 1_140);
+        assert_eq!(collateral_mint.lamports_value(1), 0);
+        assert_eq!(collateral_mint.lamports_value_ceil(1), 2);
+        assert_eq!(
+            collateral_mint.lamports_value(u64::MAX),
+            u64::MAX,
+            "value above u64 is capped"
+        );
+    }
+
+    #[test]
+    fn withdrawn_tokens_are_never_accounted_as_zero() {
+        for haircut_bps in [0, 1, 333, 9_999] {
+            for exchange_rate in [1, 1_000_000_000, 1_200_000_000] {
+                let collateral_mint = collateral_mint(haircut_bps, exchange_rate);
+                for token_amount in [1, 7, 1_000_000_001] {
+                    assert!(collateral_mint.lamports_value_ceil(token_amount) > 0);
+                    assert!(
+                        collateral_mint.lamports_value_ceil(token_amount)
+                            >= collateral_mint.lamports_value(token_amount)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn liquidator_receives_the_exchange_rate_value() {
+        assert_eq!(
+            collateral_mint(0, EXCHANGE_RATE_SCALE)
+                .liquidation_token_amount(1_000)
+                .unwrap(),
+            1_000
+        );
+        assert_eq!(
+            collateral_mint(500, EXCHANGE_RATE_SCALE)
+                .liquidation_token_amount(1_000)
+                .unwrap(),
+            1_000,
+            "haircut is not deducted from the liquidator"
+        );
+        // 1 token = 1.25 SOL, 1000 lamports = 800 tokens
+        assert_eq!(
+            collateral_mint(500, 1_250_000_000)
+                .liquidation_token_amount(1_000)
+                .unwrap(),
+            800
+        );
+        for haircut_bps in [1, 333, 500, 9_999] {
+            let collateral_mint = collateral_mint(haircut_bps, 1_100_000_000);
+            for lamports in [1, 7, 1_000_000_001] {
+                let token_amount = collateral_mint.liquidation_token_amount(lamports).unwrap();
+                // the liquidator never receives tokens of a lower value than funded
+                assert!(
+                    token_amount as u128 * 1_100_000_000 / EXCHANGE_RATE_SCALE as u128
+                        >= lamports as u128
+                );
+            }
+        }
+        assert!(collateral_mint(0, 0).liquidation_token_amount(1).is_err());
+        assert!(collateral_mint(0, 1)
+            .liquidation_token_amount(u64::MAX)
+            .is_err());
+    }
+}
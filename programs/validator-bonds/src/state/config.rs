@@ -1,4 +1,4 @@
-use crate::constants::BONDS_WITHDRAWER_AUTHORITY_SEED;
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, PAUSE_ALL, PAUSE_LEGACY_ALL};
 use crate::error::ErrorCode;
 use crate::ID;
 use anchor_lang::prelude::*;
@@ -21,17 +21,52 @@ pub struct Config {
     pub bonds_withdrawer_authority_bump: u8,
     /// Authority that can pause the program in case of emergency
     pub pause_authority: Pubkey,
-    // Defines if the program is paused
-    pub paused: bool,
+    /// Bitmask of the paused operation groups (see PAUSE_* constants), legacy value 1 pauses all of them
+    pub paused: u8,
     /// How many slots to wait before settlement is permitted to be claimed
     pub slots_to_start_settlement_claiming: u64,
     /// Minimum value of max_stake_wanted to be configured by vote account owners at bond.
     pub min_bond_max_stake_wanted: u64,
+    /// Proposed admin authority that has to accept the role; default pubkey when nothing is proposed
+    pub pending_admin_authority: Pubkey,
+    /// Proposed operator authority that has to accept the role; default pubkey when nothing is proposed
+    pub pending_operator_authority: Pubkey,
+    /// Proposed pause authority that has to accept the role; default pubkey when nothing is proposed
+    pub pending_pause_authority: Pubkey,
+    /// Change of the lockup-sensitive parameters waiting for its activation epoch
+    pub queued_change: Option<QueuedConfigChange>,
     /// reserved space for future changes
-    pub reserved: [u8; 463],
+    pub reserved: [u8; 322],
+}
+
+/// Config parameters change that can be executed once the activation epoch is reached.
+/// These parameters define the terms the validators agreed to when funding the bond,
+/// the activation is delayed to give them time to withdraw under the current terms.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct QueuedConfigChange {
+    pub epochs_to_claim_settlement: Option<u64>,
+    pub withdraw_lockup_epochs: Option<u64>,
+    pub minimum_stake_lamports: Option<u64>,
+    pub slots_to_start_settlement_claiming: Option<u64>,
+    /// Epoch from which the change is permitted to be executed
+    pub activation_epoch: u64,
 }
 
 impl Config {
+    /// Checks if any of the operation groups is paused
+    pub fn is_paused(&self, operations: u8) -> bool {
+        self.paused_operations() & operations != 0
+    }
+
+    /// Bitmask of the paused operation groups, the legacy paused flag stands for all of them
+    pub fn paused_operations(&self) -> u8 {
+        if self.paused & PAUSE_LEGACY_ALL != 0 {
+            PAUSE_ALL
+        } else {
+            self.paused
+        }
+    }
+
     pub fn bonds_withdrawer_authority(&self, config_address: &Pubkey) -> Result<Pubkey> {
         Pubkey::create_program_address(
             &[
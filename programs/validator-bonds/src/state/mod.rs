@@ -1,5 +1,7 @@
 pub mod bond;
+pub mod bond_ledger;
 pub mod bond_product;
+pub mod collateral_mint;
 pub mod config;
 pub mod settlement;
 pub mod settlement_claims;
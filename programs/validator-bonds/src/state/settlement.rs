@@ -1,7 +1,12 @@
-use crate::constants::{SETTLEMENT_CLAIMS_SEED, SETTLEMENT_SEED, SETTLEMENT_STAKER_AUTHORITY_SEED};
+use crate::constants::{
+    REPLACEABLE_SETTLEMENT_SEED, SETTLEMENT_CLAIMS_SEED, SETTLEMENT_SEED,
+    SETTLEMENT_STAKER_AUTHORITY_SEED,
+};
 use crate::error::ErrorCode;
+use crate::state::config::Config;
 use crate::ID;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 use std::fmt::Debug;
 
 /// Settlement account for a particular config and merkle root
@@ -38,8 +43,17 @@ pub struct Settlement {
     pub split_rent_amount: u64,
     /// PDA bumps
     pub bumps: Bumps,
+    /// Epoch after which the settlement expires when set by the operator;
+    /// 0 means the expiration is derived from the config `epochs_to_claim_settlement`
+    pub expiration_epoch_override: u64,
+    /// PDA seed of settlements created by `init_replaceable_settlement` that permits to replace the merkle root;
+    /// zeroed when the settlement PDA is seeded by the merkle root
+    pub replaceable_seed: [u8; 32],
+    /// Settlement is counted in the bond `open_settlements`;
+    /// not set for settlements created before the counter was introduced
+    pub counted: bool,
     /// reserve space for future extensions
-    pub reserved: [u8; 90],
+    pub reserved: [u8; 49],
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug, Default)]
@@ -50,12 +64,35 @@ pub struct Bumps {
 }
 
 impl Settlement {
+    /// The last epoch when the settlement can be claimed, after this epoch the settlement may be closed
+    pub fn expiration_epoch(&self, config: &Config) -> u64 {
+        if self.expiration_epoch_override > 0 {
+            self.expiration_epoch_override
+        } else {
+            self.epoch_created_for + config.epochs_to_claim_settlement
+        }
+    }
+
+    /// The settlement merkle root can be replaced only when it is not part of the PDA seeds
+    pub fn is_root_replaceable(&self) -> bool {
+        self.replaceable_seed != [0; 32]
+    }
+
+    /// Seed of the settlement PDA, the merkle root for settlements that are not replaceable
+    pub fn pda_seed(&self) -> &[u8; 32] {
+        if self.is_root_replaceable() {
+            &self.replaceable_seed
+        } else {
+            &self.merkle_root
+        }
+    }
+
     pub fn address(&self) -> Result<Pubkey> {
         Pubkey::create_program_address(
             &[
                 SETTLEMENT_SEED,
                 self.bond.key().as_ref(),
-                &self.merkle_root,
+                self.pda_seed(),
                 &self.epoch_created_for.to_le_bytes(),
                 &[self.bumps.pda],
             ],
@@ -82,18 +119,27 @@ impl Settlement {
     }
 }
 
-pub fn find_settlement_address(bond: &Pubkey, merkle_root: &[u8; 32], epoch: u64) -> (Pubkey, u8) {
+/// Settlement address seeded by the merkle root or by the replaceable seed
+/// for settlements created with `init_replaceable_settlement`
+pub fn find_settlement_address(bond: &Pubkey, pda_seed: &[u8; 32], epoch: u64) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[
             SETTLEMENT_SEED,
             bond.as_ref(),
-            merkle_root,
+            pda_seed,
             &epoch.to_le_bytes(),
         ],
         &ID,
     )
 }
 
+/// Seed of the settlement PDA for `init_replaceable_settlement`, derived from a `label`
+/// distinguishing settlements of the same bond and epoch (e.g., the settlement funder).
+/// The settlement address is then known before the merkle root is final.
+pub fn replaceable_settlement_seed(label: &[u8]) -> [u8; 32] {
+    hashv(&[REPLACEABLE_SETTLEMENT_SEED, label]).to_bytes()
+}
+
 pub fn find_settlement_staker_authority(settlement_address: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[
@@ -107,3 +153,21 @@ pub fn find_settlement_staker_authority(settlement_address: &Pubkey) -> (Pubkey,
 pub fn find_settlement_claims_address(settlement_address: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[SETTLEMENT_CLAIMS_SEED, settlement_address.as_ref()], &ID)
 }
+
+/// Address of a SettlementClaims chunk account, the chunk 0 is the settlement claims account itself
+pub fn find_settlement_claims_chunk_address(
+    settlement_address: &Pubkey,
+    chunk_index: u16,
+) -> (Pubkey, u8) {
+    if chunk_index == 0 {
+        return find_settlement_claims_address(settlement_address);
+    }
+    Pubkey::find_program_address(
+        &[
+            SETTLEMENT_CLAIMS_SEED,
+            settlement_address.as_ref(),
+            &chunk_index.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
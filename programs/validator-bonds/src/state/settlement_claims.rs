@@ -1,9 +1,12 @@
-use crate::constants::SETTLEMENT_CLAIMS_ANCHOR_HEADER_SIZE;
+use crate::constants::{SETTLEMENT_CLAIMS_ANCHOR_HEADER_SIZE, SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS};
+use crate::state::settlement::Settlement;
 use crate::utils::BitmapProjection;
+use crate::ID;
 use anchor_lang::prelude::Pubkey;
 use anchor_lang::prelude::*;
 pub use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
 use anchor_lang::solana_program::system_instruction::MAX_PERMITTED_DATA_LENGTH;
+use anchor_lang::system_program;
 use std::fmt::Debug;
 
 /// Account serving to deduplicate claiming, consists of anchor data as metadata header and bitmap in the remaining space.
@@ -17,6 +20,14 @@ pub struct SettlementClaims {
     pub settlement: Pubkey,
     pub version: u8,
     pub max_records: u64,
+    /// index of the chunk the account stores bitmap for, 0 for the settlement claims account
+    pub chunk_index: u16,
+    /// number of chunks the bitmap is split into, 0 when the bitmap is not chunked
+    pub chunks: u16,
+    /// number of chunk accounts created by 'init_settlement_claims_chunk', set at chunk 0 only
+    pub chunks_initialized: u16,
+    /// PDA bump of the chunk account, the chunk 0 uses the settlement bumps
+    pub bump: u8,
     // data are remaining space in Account, not touched by Anchor to not exceed 32KB on heap data
     // https://github.com/solana-developers/anchor-zero-copy-example/tree/main?tab=readme-ov-file#explanation-of-solana-memory-and-zero-copy
     // data: &mut [u8],
@@ -24,6 +35,43 @@ pub struct SettlementClaims {
     // Implementation WARNING: When adding new fields, make sure to update SETTLEMENT_CLAIMS_ANCHOR_HEADER_SIZE
 }
 
+impl SettlementClaims {
+    /// PDA seed distinguishing the chunk accounts, empty for the settlement claims account (chunk 0)
+    pub fn chunk_seed(&self) -> Vec<u8> {
+        if self.chunk_index == 0 {
+            vec![]
+        } else {
+            self.chunk_index.to_le_bytes().to_vec()
+        }
+    }
+
+    pub fn pda_bump(&self, settlement: &Settlement) -> u8 {
+        if self.chunk_index == 0 {
+            settlement.bumps.settlement_claims
+        } else {
+            self.bump
+        }
+    }
+
+    /// Position of the claim record index within the bitmap of this account.
+    /// For chunked bitmap the index has to belong to the chunk.
+    pub fn bitmap_index(&self, index: u64) -> Result<u64> {
+        if self.chunks == 0 {
+            return Ok(index);
+        }
+        let chunk_index = index / SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS;
+        if chunk_index != self.chunk_index as u64 {
+            return Err(
+                error!(crate::ErrorCode::SettlementClaimsChunkMismatch).with_values((
+                    "index, expected chunk vs. provided chunk",
+                    format!("{}, {} vs. {}", index, chunk_index, self.chunk_index),
+                )),
+            );
+        }
+        Ok(index % SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS)
+    }
+}
+
 /// Size of Solana account that stores thee SettlementClaims data from Anchor + number of records in the bitmap.
 /// (bitmap data is stored in the remaining space of the account data after data loaded by Anchor)
 pub fn account_size(max_records: u64) -> usize {
@@ -71,6 +119,57 @@ pub fn account_increase_size(settlement_claims: &Account<'_, SettlementClaims>)
     }
 }
 
+/// Closes the SettlementClaims chunk accounts (chunk index 1 and higher) of the settlement
+/// that are expected to be passed in order of the chunk index.
+pub fn close_settlement_claims_chunks<'info>(
+    settlement_claims: &SettlementClaims,
+    chunk_accounts: &[AccountInfo<'info>],
+    rent_collector: &AccountInfo<'info>,
+) -> Result<()> {
+    if chunk_accounts.len() != settlement_claims.chunks_initialized as usize {
+        return Err(
+            error!(crate::ErrorCode::SettlementClaimsChunksMismatch).with_values((
+                "initialized chunks vs. provided chunk accounts",
+                format!(
+                    "{} vs. {}",
+                    settlement_claims.chunks_initialized,
+                    chunk_accounts.len()
+                ),
+            )),
+        );
+    }
+    let mut last_chunk_index = 0;
+    for chunk_account in chunk_accounts {
+        require_keys_eq!(
+            *chunk_account.owner,
+            ID,
+            crate::ErrorCode::SettlementClaimsChunksMismatch
+        );
+        let chunk = SettlementClaims::try_deserialize(&mut &chunk_account.data.borrow()[..])?;
+        require_keys_eq!(
+            chunk.settlement,
+            settlement_claims.settlement,
+            crate::ErrorCode::SettlementClaimsChunksMismatch
+        );
+        // strictly ascending to not close one account twice
+        require_gt!(
+            chunk.chunk_index,
+            last_chunk_index,
+            crate::ErrorCode::SettlementClaimsChunksMismatch
+        );
+        last_chunk_index = chunk.chunk_index;
+        // closing the same way as Anchor 'close' constraint does
+        let rent_collector_lamports = rent_collector.lamports();
+        **rent_collector.lamports.borrow_mut() = rent_collector_lamports
+            .checked_add(chunk_account.lamports())
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        **chunk_account.lamports.borrow_mut() = 0;
+        chunk_account.assign(&system_program::ID);
+        chunk_account.realloc(0, false)?;
+    }
+    Ok(())
+}
+
 /// An helper wrapper structure that stores (only) references to the account data of SettlementClaims account.
 /// It provides utility methods to work with the bitmap data stored in the account after data loaded by Anchor.
 pub struct SettlementClaimsWrapped<'info: 'a, 'a> {
@@ -112,8 +211,9 @@ impl<'info, 'a> SettlementClaimsWrapped<'info, 'a> {
     }
 
     pub fn try_to_set(&mut self, index: u64) -> Result<bool> {
+        let bitmap_index = self.account.bitmap_index(index)?;
         self.bitmap_projection.try_to_set(
-            index,
+            bitmap_index,
             &mut self.account_info.data.borrow_mut()
                 [SETTLEMENT_CLAIMS_ANCHOR_HEADER_SIZE as usize..],
         )
@@ -134,3 +234,65 @@ impl Debug for SettlementClaimsWrapped<'_, '_> {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settlement_claims(chunk_index: u16, chunks: u16) -> SettlementClaims {
+        SettlementClaims {
+            settlement: Pubkey::default(),
+            version: 0,
+            max_records: SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS,
+            chunk_index,
+            chunks,
+            chunks_initialized: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn header_fits_anchor_header_size() {
+        let mut data = vec![];
+        settlement_claims(1, 2).try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), SETTLEMENT_CLAIMS_ANCHOR_HEADER_SIZE as usize);
+        assert_eq!(
+            account_size(SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS),
+            MAX_PERMITTED_DATA_INCREASE
+        );
+    }
+
+    #[test]
+    fn bitmap_index_of_chunk() {
+        let not_chunked = settlement_claims(0, 0);
+        assert_eq!(
+            not_chunked
+                .bitmap_index(SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS + 5)
+                .unwrap(),
+            SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS + 5
+        );
+
+        let first_chunk = settlement_claims(0, 3);
+        assert_eq!(first_chunk.bitmap_index(5).unwrap(), 5);
+        assert!(first_chunk
+            .bitmap_index(SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS)
+            .is_err());
+
+        let second_chunk = settlement_claims(1, 3);
+        assert!(second_chunk.bitmap_index(5).is_err());
+        assert_eq!(
+            second_chunk
+                .bitmap_index(SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            second_chunk
+                .bitmap_index(2 * SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS - 1)
+                .unwrap(),
+            SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS - 1
+        );
+        assert_eq!(second_chunk.chunk_seed(), 1u16.to_le_bytes().to_vec());
+        assert!(first_chunk.chunk_seed().is_empty());
+    }
+}
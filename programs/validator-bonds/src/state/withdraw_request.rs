@@ -19,16 +19,30 @@ pub struct WithdrawRequest {
     pub withdrawn_amount: u64,
     /// PDA account bump
     pub bump: u8,
+    /// Index of the withdraw request within the bond.
+    /// Index 0 is the (legacy) withdraw request whose PDA is derived without the index seed.
+    pub index: u64,
+    /// Withdraw request is counted in the bond `open_withdraw_requests`;
+    /// not set for withdraw requests created before the counter was introduced
+    pub counted: bool,
     /// reserve space for future extensions
-    pub reserved: [u8; 93],
+    pub reserved: [u8; 84],
 }
 
 impl WithdrawRequest {
+    /// PDA seed part that distinguishes multiple withdraw requests of the same bond.
+    /// For index 0 the seed is empty; an empty seed does not change the derived address,
+    /// i.e., the address is the same as for the withdraw request created without the index.
+    pub fn index_seed(&self) -> Vec<u8> {
+        withdraw_request_index_seed(self.index)
+    }
+
     pub fn address(&self) -> Result<Pubkey> {
         Pubkey::create_program_address(
             &[
                 WITHDRAW_REQUEST_SEED,
                 self.bond.key().as_ref(),
+                self.index_seed().as_ref(),
                 &[self.bump],
             ],
             &ID,
@@ -37,6 +51,25 @@ impl WithdrawRequest {
     }
 }
 
+pub fn withdraw_request_index_seed(index: u64) -> Vec<u8> {
+    if index == 0 {
+        vec![]
+    } else {
+        index.to_le_bytes().to_vec()
+    }
+}
+
 pub fn find_withdraw_request_address(bond: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[WITHDRAW_REQUEST_SEED, bond.as_ref()], &ID)
 }
+
+pub fn find_indexed_withdraw_request_address(bond: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            WITHDRAW_REQUEST_SEED,
+            bond.as_ref(),
+            withdraw_request_index_seed(index).as_ref(),
+        ],
+        &ID,
+    )
+}
@@ -23,12 +23,58 @@ pub fn verify(proof: Vec<[u8; 32]>, root: [u8; 32], leaf: [u8; 32]) -> bool {
     computed_hash == root
 }
 
+/// Verification of a multi-proof that proves several `leaves` (index, leaf hash) of the tree at once,
+/// the sibling hashes shared by the leaf paths are part of the `proof` only once.
+/// The `proof` contains siblings that cannot be computed from the leaves, ordered level by level
+/// and by index within the level, see `merkle_tree::multi_proof`.
+/// The pairs of pre-images are assumed to be sorted as for the single leaf `verify`.
+pub fn verify_multi(
+    proof: Vec<[u8; 32]>,
+    root: [u8; 32],
+    mut leaves: Vec<(u64, [u8; 32])>,
+) -> bool {
+    leaves.sort_unstable_by_key(|(index, _)| *index);
+    if leaves.is_empty() || leaves.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+        return false;
+    }
+    let mut proof = proof.into_iter();
+    loop {
+        if leaves.len() == 1 && leaves[0].0 == 0 && proof.len() == 0 {
+            // Check if the computed hash (root) is equal to the provided root
+            return leaves[0].1 == root;
+        }
+        let mut parents = Vec::with_capacity(leaves.len());
+        let mut i = 0;
+        while i < leaves.len() {
+            let (index, computed_hash) = leaves[i];
+            let sibling = match leaves.get(i + 1) {
+                Some((next_index, next_hash)) if index % 2 == 0 && *next_index == index + 1 => {
+                    i += 1;
+                    *next_hash
+                }
+                _ => match proof.next() {
+                    Some(proof_element) => proof_element,
+                    None => return false,
+                },
+            };
+            i += 1;
+            let parent_hash = if computed_hash <= sibling {
+                hash_intermediate!(computed_hash, sibling).to_bytes()
+            } else {
+                hash_intermediate!(sibling, computed_hash).to_bytes()
+            };
+            parents.push((index / 2, parent_hash));
+        }
+        leaves = parents;
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::utils::verify;
+    use crate::utils::{verify, verify_multi};
     use anchor_lang::solana_program::{hash::hashv, pubkey::Pubkey};
     use merkle_tree::psr_claim::TreeNode;
-    use merkle_tree::{hash_leaf, LEAF_PREFIX};
+    use merkle_tree::{hash_leaf, MerkleTree, LEAF_PREFIX};
     use std::str::FromStr;
 
     // TODO: probably good to download test data from gcloud when available
@@ -68,9 +114,61 @@ mod tests {
         }
         .hash();
         assert!(verify(
-            proof,
+            proof.clone(),
             merkle_root,
             hash_leaf!(tree_node_hash).to_bytes()
         ));
+        // multi-proof of a single leaf is the single leaf proof
+        assert!(verify_multi(
+            proof,
+            merkle_root,
+            vec![(index, hash_leaf!(tree_node_hash).to_bytes())]
+        ));
+    }
+
+    #[test]
+    pub fn claim_merkle_multi_proof_check() {
+        let tree_nodes: Vec<TreeNode> = (0..13)
+            .map(|index| TreeNode {
+                stake_authority: Pubkey::new_unique(),
+                withdraw_authority: Pubkey::new_unique(),
+                claim: 1_000 + index,
+                index,
+                proof: None,
+            })
+            .collect();
+        let hashed_nodes: Vec<[u8; 32]> = tree_nodes.iter().map(|n| n.hash().to_bytes()).collect();
+        let merkle_tree = MerkleTree::new(&hashed_nodes[..], true);
+        let merkle_root = merkle_tree.get_root().unwrap().to_bytes();
+        let leaf = |index: usize| {
+            let tree_node_hash = hashed_nodes[index];
+            (index as u64, hash_leaf!(tree_node_hash).to_bytes())
+        };
+
+        let indexes = [1, 2, 3, 8, 12];
+        let proof: Vec<[u8; 32]> = merkle_tree
+            .find_multi_proof(&indexes)
+            .unwrap()
+            .into_iter()
+            .map(|hash| hash.to_bytes())
+            .collect();
+        let leaves: Vec<(u64, [u8; 32])> = indexes.iter().map(|index| leaf(*index)).collect();
+        assert!(verify_multi(proof.clone(), merkle_root, leaves.clone()));
+        assert!(!verify_multi(
+            proof.clone(),
+            merkle_root,
+            leaves[1..].to_vec()
+        ));
+        assert!(!verify_multi(
+            proof.clone(),
+            merkle_root,
+            vec![leaf(1), leaf(2), leaf(3), leaf(8), leaf(11)]
+        ));
+        assert!(!verify_multi(
+            proof[1..].to_vec(),
+            merkle_root,
+            leaves.clone()
+        ));
+        assert!(!verify_multi(proof, merkle_root, vec![]));
     }
 }
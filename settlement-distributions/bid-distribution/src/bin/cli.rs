@@ -13,6 +13,8 @@ use bid_distribution::sam_meta::{SamAuctionResult, ValidatorSamMeta};
 use bid_distribution::settlement_config::BidDistributionConfig;
 use env_logger::{Builder, Env};
 use rust_decimal::Decimal;
+use settlement_common::bond_products_meta::{bond_products_by_vote_account, BondProductsMeta};
+use settlement_common::file_format::write_to_file;
 use settlement_common::protected_events::generate_protected_event_collection;
 use settlement_common::revenue_expectation_meta::RevenueExpectationMetaCollection;
 use settlement_common::settlement_collection::SettlementCollection;
@@ -23,7 +25,7 @@ use settlement_common::utils::{
 use snapshot_parser_validator_cli::stake_meta::StakeMetaCollection;
 use snapshot_parser_validator_cli::validator_meta::ValidatorMetaCollection;
 use solana_sdk::native_token::LAMPORTS_PER_SOL;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use {clap::Parser, log::info};
 
@@ -65,6 +67,11 @@ struct Args {
     #[arg(long, env)]
     revenue_expectation_collection: Option<String>,
 
+    /// Bond products collection (YAML/JSON) of `bonds-collector collect-bond-products` (for PSR).
+    /// Validators' UptimeGuarantee and MaxStakeCap products are considered when provided.
+    #[arg(long, env)]
+    bond_products_collection: Option<String>,
+
     // ===== Outputs =====
     /// Output path for combined settlement collection JSON
     #[arg(long, env)]
@@ -146,10 +153,20 @@ fn main() -> anyhow::Result<()> {
             read_from_json_file(revenue_path)
                 .map_err(file_error("revenue-expectation-collection", revenue_path))?;
 
+        let bond_products_map = if let Some(bond_products_path) = &args.bond_products_collection {
+            info!("Loading bond products collection...");
+            let bond_products: Vec<BondProductsMeta> = read_from_yaml_file(bond_products_path)
+                .map_err(file_error("bond-products-collection", bond_products_path))?;
+            bond_products_by_vote_account(&bond_products)?
+        } else {
+            HashMap::new()
+        };
+
         info!("Generating protected event collection...");
         let protected_event_collection = generate_protected_event_collection(
             validator_meta_collection,
             revenue_expectation_meta_collection,
+            &bond_products_map,
         );
 
         // Output protected events if requested
@@ -165,6 +182,7 @@ fn main() -> anyhow::Result<()> {
             &protected_event_collection,
             &stake_authority_filter,
             &psr_configs,
+            &bond_products_map,
         )?;
         info!("Generated {} PSR settlements", psr_settlements.len());
         total_staker_psr_settlements = calculate_total_psr_staker_claims(&psr_settlements);
@@ -173,8 +191,9 @@ fn main() -> anyhow::Result<()> {
         // No PSR configs — fail if PSR inputs were partially provided (likely a mistake)
         anyhow::ensure!(
             args.validator_meta_collection.is_none()
-                && args.revenue_expectation_collection.is_none(),
-            "PSR inputs (--validator-meta-collection, --revenue-expectation-collection) provided but no PSR settlement configs found in config file"
+                && args.revenue_expectation_collection.is_none()
+                && args.bond_products_collection.is_none(),
+            "PSR inputs (--validator-meta-collection, --revenue-expectation-collection, --bond-products-collection) provided but no PSR settlement configs found in config file"
         );
     }
 
@@ -393,7 +412,7 @@ fn main() -> anyhow::Result<()> {
         "Writing settlement collection to {}",
         &args.output_settlement_collection
     );
-    write_to_json_file(&settlement_collection, &args.output_settlement_collection).map_err(
+    write_to_file(&settlement_collection, &args.output_settlement_collection).map_err(
         file_error(
             "output-settlement-collection",
             &args.output_settlement_collection,
@@ -1,6 +1,7 @@
 use anyhow::ensure;
 use log::{debug, info};
 use rust_decimal::Decimal;
+use settlement_common::bond_products_meta::BondProductsMeta;
 use settlement_common::protected_events::ProtectedEventCollection;
 use settlement_common::settlement_collection::{
     Settlement, SettlementClaim, SettlementFunder, SettlementReason,
@@ -24,12 +25,16 @@ pub fn calculate_total_psr_staker_claims(settlements: &[Settlement]) -> Decimal
 }
 
 /// Generates PSR settlements for protected events.
-/// Takes settlement configs that match the ProtectedEvent types (DowntimeRevenueImpact, CommissionSamIncrease).
+/// Takes settlement configs that match the ProtectedEvent types (DowntimeRevenueImpact, CommissionSamIncrease, UptimeGuaranteeBreach).
+/// When the validator caps the protected stake with the MaxStakeCap bond product,
+/// claims are computed from the capped stake, split pro rata among all the stakers of the validator
+/// (the stakers excluded by the stake authority filter keep their share unclaimed).
 pub fn generate_psr_settlements(
     stake_meta_index: &StakeMetaIndex,
     protected_event_collection: &ProtectedEventCollection,
     stake_authority_filter: &dyn Fn(&Pubkey) -> bool,
     settlement_configs: &[PsrSettlementConfig],
+    bond_products_map: &HashMap<Pubkey, BondProductsMeta>,
 ) -> anyhow::Result<Vec<Settlement>> {
     ensure!(
         stake_meta_index.stake_meta_collection.epoch == protected_event_collection.epoch,
@@ -51,16 +56,28 @@ pub fn generate_psr_settlements(
             protected_event_collection,
             stake_authority_filter,
             settlement_config,
+            bond_products_map,
         )?);
     }
     Ok(settlements)
 }
 
+/// Part of the staker's stake that is protected when the validator caps the protected stake.
+fn capped_stake(active_stake: u64, total_active_stake: u64, max_stake_cap: Option<u64>) -> u64 {
+    match max_stake_cap {
+        Some(cap) if cap < total_active_stake => {
+            (active_stake as u128 * cap as u128 / total_active_stake as u128) as u64
+        }
+        _ => active_stake,
+    }
+}
+
 fn generate_psr_settlements_for_config(
     stake_meta_index: &StakeMetaIndex,
     protected_event_collection: &ProtectedEventCollection,
     stake_authority_filter: &dyn Fn(&Pubkey) -> bool,
     settlement_config: &PsrSettlementConfig,
+    bond_products_map: &HashMap<Pubkey, BondProductsMeta>,
 ) -> anyhow::Result<Vec<Settlement>> {
     info!("Generating settlement claim collection type {settlement_config:?}...");
 
@@ -76,26 +93,52 @@ fn generate_psr_settlements_for_config(
         if let Some(grouped_stake_metas) =
             stake_meta_index.iter_grouped_stake_metas(protected_event.vote_account())
         {
-            let mut claims = vec![];
-            let mut claims_amount = 0;
+            let mut staker_stakes = vec![];
+            // the stake cap is pro-rated over the whole validator stake, not only over the filtered stakers
+            let mut total_active_stake = 0_u64;
             for ((withdraw_authority, stake_authority), stake_metas) in grouped_stake_metas {
-                if !stake_authority_filter(stake_authority) {
-                    continue;
-                }
-
                 let stake_accounts: HashMap<_, _> = stake_metas
                     .iter()
                     .map(|s| (s.pubkey, s.active_delegation_lamports))
                     .collect();
-                let active_stake = stake_accounts.values().sum();
+                let active_stake: u64 = stake_accounts.values().sum();
+                total_active_stake += active_stake;
+                if !stake_authority_filter(stake_authority) {
+                    continue;
+                }
+
+                staker_stakes.push((
+                    **withdraw_authority,
+                    **stake_authority,
+                    stake_accounts,
+                    active_stake,
+                ));
+            }
 
-                let claim_amount =
-                    protected_event.claim_amount_in_loss_range(settlement_config, active_stake)?;
+            let max_stake_cap = bond_products_map
+                .get(protected_event.vote_account())
+                .and_then(|bond_products| bond_products.max_stake_cap_lamports);
+            if let Some(cap) = max_stake_cap.filter(|cap| *cap < total_active_stake) {
+                debug!(
+                    "Vote account {} caps protected stake at {} lamports out of {}",
+                    protected_event.vote_account(),
+                    cap,
+                    total_active_stake
+                );
+            }
+
+            let mut claims = vec![];
+            let mut claims_amount = 0;
+            for (withdraw_authority, stake_authority, stake_accounts, active_stake) in staker_stakes
+            {
+                let protected_stake = capped_stake(active_stake, total_active_stake, max_stake_cap);
+                let claim_amount = protected_event
+                    .claim_amount_in_loss_range(settlement_config, protected_stake)?;
 
                 if active_stake > 0 && claim_amount > 0 {
                     claims.push(SettlementClaim::staker_payout(
-                        **withdraw_authority,
-                        **stake_authority,
+                        withdraw_authority,
+                        stake_authority,
                         active_stake,
                         0,
                         claim_amount,
@@ -13,6 +13,7 @@ use crate::settlement_config::{
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use serde_json::json;
+use settlement_common::bond_products_meta::BondProductsMeta;
 use settlement_common::protected_events::{ProtectedEvent, ProtectedEventCollection};
 use settlement_common::settlement_collection::{
     ClaimDetail, Settlement, SettlementFunder, SettlementMeta, SettlementReason,
@@ -2601,6 +2602,7 @@ fn test_generate_psr_downtime_basic() {
         &protected_event_collection,
         &accept_all,
         &[settlement_config],
+        &HashMap::new(),
     )
     .unwrap();
 
@@ -2684,6 +2686,7 @@ fn test_generate_psr_downtime_marinade_funder_adds_null_claim() {
         &protected_event_collection,
         &accept_all,
         &[settlement_config],
+        &HashMap::new(),
     )
     .unwrap();
 
@@ -2756,6 +2759,7 @@ fn test_generate_psr_downtime_below_grace_period() {
         &protected_event_collection,
         &accept_all,
         &[settlement_config],
+        &HashMap::new(),
     )
     .unwrap();
 
@@ -2817,6 +2821,7 @@ fn test_generate_psr_downtime_below_min_settlement() {
         &protected_event_collection,
         &accept_all,
         &[settlement_config],
+        &HashMap::new(),
     )
     .unwrap();
 
@@ -2827,6 +2832,255 @@ fn test_generate_psr_downtime_below_min_settlement() {
     );
 }
 
+#[test]
+fn test_generate_psr_max_stake_cap_splits_capped_stake_pro_rata() {
+    let epoch = 100;
+    let slot = 1000;
+    let vote_account = test_vote_account(1);
+
+    // 100 SOL + 300 SOL of stake, the validator protects 200 SOL only
+    let stake_meta_collection = StakeMetaCollection {
+        epoch,
+        slot,
+        stake_metas: vec![
+            create_stake_meta(
+                test_stake_account(1),
+                vote_account,
+                test_withdraw_authority(1),
+                test_stake_authority(1),
+                100 * LAMPORTS_PER_SOL,
+            ),
+            create_stake_meta(
+                test_stake_account(2),
+                vote_account,
+                test_withdraw_authority(2),
+                test_stake_authority(2),
+                300 * LAMPORTS_PER_SOL,
+            ),
+        ],
+    };
+    let stake_meta_index = StakeMetaIndex::new(&stake_meta_collection);
+
+    let protected_event_collection = ProtectedEventCollection {
+        epoch,
+        slot,
+        events: vec![ProtectedEvent::DowntimeRevenueImpact {
+            vote_account,
+            actual_credits: 5000,
+            expected_credits: 10000,
+            expected_epr: Decimal::from_str("0.001").unwrap(),
+            actual_epr: Decimal::from_str("0.0005").unwrap(),
+            epr_loss_bps: 5000,
+            stake: 400 * LAMPORTS_PER_SOL,
+        }],
+    };
+
+    let settlement_config = PsrSettlementConfig {
+        meta: SettlementMeta {
+            funder: SettlementFunder::ValidatorBond,
+        },
+        kind: PsrSettlementConfigKind::DowntimeRevenueImpactSettlement {
+            min_settlement_lamports: 0,
+            grace_downtime_bps: None,
+            covered_range_bps: [0, 5000],
+        },
+    };
+    let bond_products_map = HashMap::from([(
+        vote_account,
+        BondProductsMeta {
+            vote_account,
+            uptime_guarantee_bps: None,
+            max_stake_cap_lamports: Some(200 * LAMPORTS_PER_SOL),
+        },
+    )]);
+
+    let settlements = generate_psr_settlements(
+        &stake_meta_index,
+        &protected_event_collection,
+        &accept_all,
+        &[settlement_config],
+        &bond_products_map,
+    )
+    .unwrap();
+
+    assert_eq!(settlements.len(), 1);
+    // claim_per_stake = 0.0005, protected stake 200 SOL -> 0.1 SOL in total
+    assert_eq!(settlements[0].claims_amount, 100_000_000);
+    let claim_of = |stake_authority: Pubkey| {
+        settlements[0]
+            .claims
+            .iter()
+            .find(|c| c.stake_authority == stake_authority)
+            .map(|c| (c.active_stake().unwrap(), c.claim_amount))
+            .unwrap()
+    };
+    // stakers keep their full active stake in the claim, the claim is computed from the capped part
+    assert_eq!(
+        claim_of(test_stake_authority(1)),
+        (100 * LAMPORTS_PER_SOL, 25_000_000)
+    );
+    assert_eq!(
+        claim_of(test_stake_authority(2)),
+        (300 * LAMPORTS_PER_SOL, 75_000_000)
+    );
+}
+
+#[test]
+fn test_generate_psr_max_stake_cap_is_pro_rated_over_unfiltered_stake() {
+    let epoch = 100;
+    let slot = 1000;
+    let vote_account = test_vote_account(1);
+
+    // 100 SOL + 300 SOL of stake, the validator protects 200 SOL only
+    let stake_meta_collection = StakeMetaCollection {
+        epoch,
+        slot,
+        stake_metas: vec![
+            create_stake_meta(
+                test_stake_account(1),
+                vote_account,
+                test_withdraw_authority(1),
+                test_stake_authority(1),
+                100 * LAMPORTS_PER_SOL,
+            ),
+            create_stake_meta(
+                test_stake_account(2),
+                vote_account,
+                test_withdraw_authority(2),
+                test_stake_authority(2),
+                300 * LAMPORTS_PER_SOL,
+            ),
+        ],
+    };
+    let stake_meta_index = StakeMetaIndex::new(&stake_meta_collection);
+
+    let protected_event_collection = ProtectedEventCollection {
+        epoch,
+        slot,
+        events: vec![ProtectedEvent::DowntimeRevenueImpact {
+            vote_account,
+            actual_credits: 5000,
+            expected_credits: 10000,
+            expected_epr: Decimal::from_str("0.001").unwrap(),
+            actual_epr: Decimal::from_str("0.0005").unwrap(),
+            epr_loss_bps: 5000,
+            stake: 400 * LAMPORTS_PER_SOL,
+        }],
+    };
+
+    let settlement_config = PsrSettlementConfig {
+        meta: SettlementMeta {
+            funder: SettlementFunder::ValidatorBond,
+        },
+        kind: PsrSettlementConfigKind::DowntimeRevenueImpactSettlement {
+            min_settlement_lamports: 0,
+            grace_downtime_bps: None,
+            covered_range_bps: [0, 5000],
+        },
+    };
+    let bond_products_map = HashMap::from([(
+        vote_account,
+        BondProductsMeta {
+            vote_account,
+            uptime_guarantee_bps: None,
+            max_stake_cap_lamports: Some(200 * LAMPORTS_PER_SOL),
+        },
+    )]);
+
+    let settlements = generate_psr_settlements(
+        &stake_meta_index,
+        &protected_event_collection,
+        &only(test_stake_authority(1)),
+        &[settlement_config],
+        &bond_products_map,
+    )
+    .unwrap();
+
+    assert_eq!(settlements.len(), 1);
+    assert_eq!(settlements[0].claims.len(), 1);
+    let claim = &settlements[0].claims[0];
+    assert_eq!(claim.stake_authority, test_stake_authority(1));
+    // the filtered staker gets its 1/4 share of the 200 SOL cap (50 SOL protected),
+    // not the full 100 SOL that would fit under the cap when counting the filtered stakers only
+    assert_eq!(claim.active_stake().unwrap(), 100 * LAMPORTS_PER_SOL);
+    assert_eq!(claim.claim_amount, 25_000_000);
+    assert_eq!(settlements[0].claims_amount, 25_000_000);
+}
+
+#[test]
+fn test_generate_psr_uptime_guarantee_breach() {
+    let epoch = 100;
+    let slot = 1000;
+    let vote_account = test_vote_account(1);
+    let stake_lamports = 100 * LAMPORTS_PER_SOL;
+
+    let stake_meta_collection = StakeMetaCollection {
+        epoch,
+        slot,
+        stake_metas: vec![create_stake_meta(
+            test_stake_account(1),
+            vote_account,
+            test_withdraw_authority(1),
+            test_stake_authority(1),
+            stake_lamports,
+        )],
+    };
+    let stake_meta_index = StakeMetaIndex::new(&stake_meta_collection);
+
+    // guaranteed 99 % uptime, the validator was up for 90 % of the epoch
+    let protected_event_collection = ProtectedEventCollection {
+        epoch,
+        slot,
+        events: vec![ProtectedEvent::UptimeGuaranteeBreach {
+            vote_account,
+            actual_credits: 9000,
+            expected_credits: 10000,
+            min_uptime_bps: 9900,
+            actual_uptime_bps: 9000,
+            expected_epr: Decimal::from_str("0.00099").unwrap(),
+            actual_epr: Decimal::from_str("0.0009").unwrap(),
+            epr_loss_bps: 909,
+            stake: stake_lamports,
+        }],
+    };
+
+    let uptime_config = PsrSettlementConfig {
+        meta: SettlementMeta {
+            funder: SettlementFunder::ValidatorBond,
+        },
+        kind: PsrSettlementConfigKind::UptimeGuaranteeSettlement {
+            min_settlement_lamports: 0,
+            grace_shortfall_bps: None,
+            covered_range_bps: [0, 10000],
+        },
+    };
+    // downtime config does not match the uptime guarantee event
+    let downtime_config = PsrSettlementConfig {
+        meta: SettlementMeta {
+            funder: SettlementFunder::ValidatorBond,
+        },
+        kind: PsrSettlementConfigKind::DowntimeRevenueImpactSettlement {
+            min_settlement_lamports: 0,
+            grace_downtime_bps: None,
+            covered_range_bps: [0, 10000],
+        },
+    };
+
+    let settlements = generate_psr_settlements(
+        &stake_meta_index,
+        &protected_event_collection,
+        &accept_all,
+        &[uptime_config, downtime_config],
+        &HashMap::new(),
+    )
+    .unwrap();
+
+    assert_eq!(settlements.len(), 1);
+    // claim_per_stake = 0.00099 - 0.0009 = 0.00009
+    // claim = 100_000_000_000 * 0.00009 = 9_000_000
+    assert_eq!(settlements[0].claims_amount, 9_000_000);
+}
+
 #[test]
 fn test_generate_psr_commission_increase_basic() {
     let epoch = 100;
@@ -2885,6 +3139,7 @@ fn test_generate_psr_commission_increase_basic() {
         &protected_event_collection,
         &accept_all,
         &[settlement_config],
+        &HashMap::new(),
     )
     .unwrap();
 
@@ -2963,6 +3218,7 @@ fn test_generate_psr_stake_authority_filter() {
         &protected_event_collection,
         &filter,
         &[settlement_config],
+        &HashMap::new(),
     )
     .unwrap();
 
@@ -3041,6 +3297,7 @@ fn test_generate_psr_null_claim_deterministic_sorting() {
         &protected_event_collection,
         &accept_all,
         &[settlement_config],
+        &HashMap::new(),
     )
     .unwrap();
 
@@ -3462,6 +3719,7 @@ fn test_psr_missing_vote_account_in_stake_index_is_skipped() {
         &protected_event_collection,
         &accept_all,
         &[settlement_config],
+        &HashMap::new(),
     )
     .unwrap();
 
@@ -3870,6 +4128,7 @@ fn test_psr_epoch_mismatch_returns_error() {
         &protected_event_collection,
         &accept_all,
         &[settlement_config],
+        &HashMap::new(),
     );
 
     assert!(result.is_err(), "epoch mismatch must return an error");
@@ -4,6 +4,7 @@ use institutional_distribution::settlement_config::{
     ConfigParams, InstitutionalDistributionConfig,
 };
 use institutional_distribution::settlement_generator::generate_institutional_settlement_collection;
+use settlement_common::file_format::write_to_file;
 use settlement_common::utils::{file_error, read_from_json_file, write_to_json_file};
 use solana_sdk::pubkey::Pubkey;
 use {clap::Parser, log::info};
@@ -69,7 +70,7 @@ fn main() -> anyhow::Result<()> {
     info!("Generating Institutional Payout Settlement collection...");
     let settlement_collection =
         generate_institutional_settlement_collection(&config, &institutional_payouts);
-    write_to_json_file(&settlement_collection, &args.output_settlement_collection).map_err(
+    write_to_file(&settlement_collection, &args.output_settlement_collection).map_err(
         file_error(
             "output-settlement-collection",
             &args.output_settlement_collection,
@@ -1,36 +1,326 @@
-use anyhow::anyhow;
-use clap::Parser;
+use anyhow::{anyhow, bail};
+use clap::{Parser, Subcommand, ValueEnum};
 use log::info;
+use merkle_generator::claim_index::ClaimIndex;
+use merkle_generator::diff::diff_merkle_tree_collections;
 use merkle_generator::{generate_merkle_tree_collection, load_settlement_files, GeneratorConfig};
+use settlement_common::file_format::{read_from_file, write_to_file};
+use settlement_common::merkle_tree_collection::MerkleTreeCollection;
+use settlement_common::settlement_collection::SettlementCollection;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::pubkey::Pubkey;
-use std::path::PathBuf;
+use std::io;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::Arc;
 
 #[derive(Parser, Debug)]
 #[command(
     author,
     version,
-    about = "Generate unified merkle trees from multiple settlement sources"
+    about = "Generate unified merkle trees from multiple settlement sources",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
 )]
 struct Args {
-    /// Comma-separated list of input settlement JSON files
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    generate: GenerateArgs,
+}
+
+/// File format of the input and output files is derived from the file extension:
+/// `.json`, `.json.zst`, `.msgpack`, `.msgpack.zst` (JSON for unknown extensions).
+#[derive(clap::Args, Debug)]
+struct GenerateArgs {
+    /// Comma-separated list of input settlement collection files
     #[arg(long, value_delimiter = ',', required = true)]
     input_settlement_files: Vec<PathBuf>,
 
-    /// Output path for unified merkle trees JSON
-    #[arg(long)]
-    output_merkle_trees: PathBuf,
+    /// Output path for unified merkle trees collection
+    #[arg(long, required = true)]
+    output_merkle_trees: Option<PathBuf>,
 
     /// Validator bonds config pubkey (can also be set via VALIDATOR_BONDS_CONFIG env var)
-    #[arg(long, env = "VALIDATOR_BONDS_CONFIG")]
-    validator_bonds_config: String,
+    #[arg(long, env = "VALIDATOR_BONDS_CONFIG", required = true)]
+    validator_bonds_config: Option<String>,
+
+    /// Settlements are seeded by the funder instead of the merkle root,
+    /// the pipeline then creates replaceable settlements whose merkle root can be replaced
+    /// before claiming starts
+    #[arg(long)]
+    replaceable_settlements: bool,
 }
 
-fn main() -> anyhow::Result<()> {
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Converts a collection file between formats, the formats are derived from the file extensions
+    Convert {
+        /// Input collection file
+        #[arg(long)]
+        input: PathBuf,
+
+        /// Output collection file
+        #[arg(long)]
+        output: PathBuf,
+
+        /// Type of the collection stored in the input file
+        #[arg(long, value_enum, default_value_t = CollectionType::MerkleTrees)]
+        collection: CollectionType,
+    },
+
+    /// Builds per-staker claim index (stake/withdraw authority -> settlement, index, claim, proof)
+    /// from merkle tree collection files
+    ClaimIndex {
+        /// Comma-separated list of input merkle tree collection files
+        #[arg(long, value_delimiter = ',', required = true)]
+        merkle_tree_files: Vec<PathBuf>,
+
+        /// Output path for the claim index
+        #[arg(long)]
+        output: PathBuf,
+
+        /// Validator bonds config to derive settlement addresses,
+        /// when not provided the pre-computed addresses of the collections are used
+        #[arg(long)]
+        validator_bonds_config: Option<Pubkey>,
+
+        /// When provided the entries are marked as claimed or open based on the on-chain data
+        #[arg(long, env)]
+        rpc_url: Option<String>,
+    },
+
+    /// Compares two merkle tree collections (or merkle trees generated from two sets
+    /// of settlement collections) and reports added, removed and changed settlements
+    /// with per-claim changes and the reasons of the merkle root change
+    Diff {
+        /// Old collection file; comma-separated list of files for settlement collections
+        #[arg(long, value_delimiter = ',', required = true)]
+        old: Vec<PathBuf>,
+
+        /// New collection file; comma-separated list of files for settlement collections
+        #[arg(long, value_delimiter = ',', required = true)]
+        new: Vec<PathBuf>,
+
+        /// Type of the collections stored in the input files
+        #[arg(long, value_enum, default_value_t = CollectionType::MerkleTrees)]
+        collection: CollectionType,
+
+        /// Validator bonds config used to generate merkle trees from settlement collections
+        #[arg(long, env = "VALIDATOR_BONDS_CONFIG")]
+        validator_bonds_config: Option<Pubkey>,
+
+        /// Output path for the JSON diff report, printed to stdout when not provided
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Prints claim index entries of a stake or withdraw authority as JSON
+    FindClaims {
+        /// Claim index file created by the `claim-index` command
+        #[arg(long)]
+        claim_index: PathBuf,
+
+        /// Stake or withdraw authority to search claims for
+        #[arg(long)]
+        authority: Pubkey,
+
+        /// When provided the entries are marked as claimed or open based on the on-chain data
+        #[arg(long, env)]
+        rpc_url: Option<String>,
+    },
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum CollectionType {
+    MerkleTrees,
+    Settlements,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
     env_logger::init();
 
     let args = Args::parse();
 
+    match args.command {
+        Some(Command::Convert {
+            input,
+            output,
+            collection,
+        }) => convert(&input, &output, collection),
+        Some(Command::ClaimIndex {
+            merkle_tree_files,
+            output,
+            validator_bonds_config,
+            rpc_url,
+        }) => build_claim_index(&merkle_tree_files, &output, validator_bonds_config, rpc_url).await,
+        Some(Command::FindClaims {
+            claim_index,
+            authority,
+            rpc_url,
+        }) => find_claims(&claim_index, &authority, rpc_url).await,
+        Some(Command::Diff {
+            old,
+            new,
+            collection,
+            validator_bonds_config,
+            output,
+        }) => diff(&old, &new, collection, validator_bonds_config, output),
+        None => generate(args.generate),
+    }
+}
+
+fn get_rpc_client(rpc_url: String) -> Arc<RpcClient> {
+    Arc::new(RpcClient::new_with_commitment(
+        rpc_url,
+        CommitmentConfig::confirmed(),
+    ))
+}
+
+async fn build_claim_index(
+    merkle_tree_files: &[PathBuf],
+    output: &Path,
+    validator_bonds_config: Option<Pubkey>,
+    rpc_url: Option<String>,
+) -> anyhow::Result<()> {
+    let collections = merkle_tree_files
+        .iter()
+        .map(|path| {
+            info!("Loading merkle tree collection from {}", path.display());
+            read_from_file(path).map_err(|e| anyhow!("Failed to load {}: {e}", path.display()))
+        })
+        .collect::<anyhow::Result<Vec<MerkleTreeCollection>>>()?;
+
+    let mut claim_index = ClaimIndex::build(&collections, validator_bonds_config)?;
+    if let Some(rpc_url) = rpc_url {
+        claim_index
+            .refresh_claim_statuses(get_rpc_client(rpc_url), None)
+            .await?;
+    }
+
+    info!("Writing claim index to {}", output.display());
+    claim_index.save(&output)
+}
+
+async fn find_claims(
+    claim_index_path: &Path,
+    authority: &Pubkey,
+    rpc_url: Option<String>,
+) -> anyhow::Result<()> {
+    let mut claim_index = ClaimIndex::load(&claim_index_path)?;
+    if let Some(rpc_url) = rpc_url {
+        claim_index
+            .refresh_claim_statuses(get_rpc_client(rpc_url), Some(authority))
+            .await?;
+    }
+
+    let entries = claim_index.find_by_authority(authority);
+    info!("Found {} claims of authority {authority}", entries.len());
+    serde_json::to_writer_pretty(io::stdout(), &entries)?;
+
+    Ok(())
+}
+
+fn load_merkle_tree_collection_to_diff(
+    files: &[PathBuf],
+    collection: CollectionType,
+    validator_bonds_config: Option<Pubkey>,
+) -> anyhow::Result<MerkleTreeCollection> {
+    match collection {
+        CollectionType::MerkleTrees => {
+            let [path] = files else {
+                bail!("Exactly one merkle tree collection file expected, got {files:?}");
+            };
+            read_from_file(path).map_err(|e| anyhow!("Failed to load {}: {e}", path.display()))
+        }
+        CollectionType::Settlements => {
+            let config = GeneratorConfig {
+                // settlement addresses depend on the config, merkle roots do not
+                validator_bonds_config: validator_bonds_config.unwrap_or_default(),
+                replaceable_settlements: false,
+            };
+            generate_merkle_tree_collection(load_settlement_files(files)?, &config)
+        }
+    }
+}
+
+fn diff(
+    old: &[PathBuf],
+    new: &[PathBuf],
+    collection: CollectionType,
+    validator_bonds_config: Option<Pubkey>,
+    output: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let old_collection =
+        load_merkle_tree_collection_to_diff(old, collection, validator_bonds_config)?;
+    let new_collection =
+        load_merkle_tree_collection_to_diff(new, collection, validator_bonds_config)?;
+
+    let diff = diff_merkle_tree_collections(&old_collection, &new_collection);
+    info!(
+        "Merkle trees diff: {} added, {} removed, {} changed, {} unchanged",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.changed.len(),
+        diff.unchanged_count
+    );
+    for tree_diff in &diff.changed {
+        info!(
+            "  vote account {} ({:?}): root {:?} -> {:?}, reasons: {:?}",
+            tree_diff.vote_account,
+            tree_diff.funder,
+            tree_diff.old_merkle_root,
+            tree_diff.new_merkle_root,
+            tree_diff.root_change_reasons
+        );
+    }
+
+    match output {
+        Some(output) => {
+            info!("Writing diff report to {}", output.display());
+            write_to_file(&diff, &output)
+        }
+        None => {
+            serde_json::to_writer_pretty(io::stdout(), &diff)?;
+            Ok(())
+        }
+    }
+}
+
+fn convert(input: &Path, output: &Path, collection: CollectionType) -> anyhow::Result<()> {
+    info!(
+        "Converting {collection:?} collection {} to {}",
+        input.display(),
+        output.display()
+    );
+    match collection {
+        CollectionType::MerkleTrees => {
+            let merkle_tree_collection: MerkleTreeCollection = read_from_file(input)
+                .map_err(|e| anyhow!("Failed to load {}: {e}", input.display()))?;
+            write_to_file(&merkle_tree_collection, output)?;
+        }
+        CollectionType::Settlements => {
+            let settlement_collection: SettlementCollection = read_from_file(input)
+                .map_err(|e| anyhow!("Failed to load {}: {e}", input.display()))?;
+            write_to_file(&settlement_collection, output)?;
+        }
+    }
+    info!("Conversion completed successfully");
+
+    Ok(())
+}
+
+fn generate(args: GenerateArgs) -> anyhow::Result<()> {
+    let output_merkle_trees = args
+        .output_merkle_trees
+        .ok_or_else(|| anyhow!("Missing --output-merkle-trees"))?;
+    let validator_bonds_config = args
+        .validator_bonds_config
+        .ok_or_else(|| anyhow!("Missing --validator-bonds-config"))?;
+
     info!("Merkle Generator CLI starting");
     info!(
         "Input files: {:?}",
@@ -40,13 +330,16 @@ fn main() -> anyhow::Result<()> {
             .collect::<Vec<_>>()
     );
 
-    let validator_bonds_config = Pubkey::from_str(&args.validator_bonds_config)
+    let validator_bonds_config = Pubkey::from_str(&validator_bonds_config)
         .map_err(|e| anyhow!("Invalid validator_bonds_config pubkey: {e}"))?;
 
     info!("Validator bonds config: {validator_bonds_config}");
 
+    info!("Replaceable settlements: {}", args.replaceable_settlements);
+
     let config = GeneratorConfig {
         validator_bonds_config,
+        replaceable_settlements: args.replaceable_settlements,
     };
 
     // Load all settlement files
@@ -58,14 +351,9 @@ fn main() -> anyhow::Result<()> {
     // Write output
     info!(
         "Writing unified merkle trees to {}",
-        args.output_merkle_trees.display()
+        output_merkle_trees.display()
     );
-    settlement_common::utils::write_to_json_file(
-        &merkle_tree_collection,
-        args.output_merkle_trees
-            .to_str()
-            .ok_or_else(|| anyhow!("Invalid output path"))?,
-    )?;
+    write_to_file(&merkle_tree_collection, &output_merkle_trees)?;
 
     info!("Merkle generator completed successfully");
     info!(
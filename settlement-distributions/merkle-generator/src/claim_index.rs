@@ -0,0 +1,491 @@
+//! Per-staker index of settlement claims built from merkle tree collections.
+//!
+//! The index answers which settlements a stake or withdraw authority may claim
+//! and with what proof, without scanning all the merkle tree collection files.
+//! Entries may be cross-checked against the on-chain `SettlementClaims` bitmaps
+//! to find out whether the claim was already executed.
+
+use anyhow::{anyhow, bail};
+use log::{debug, info, warn};
+use merkle_tree::serde_serialize::pubkey_string_conversion;
+use merkle_tree::MerkleTree;
+use serde::{Deserialize, Serialize};
+use settlement_common::file_format::{read_from_file, write_to_file};
+use settlement_common::merkle_tree_collection::{get_proof, MerkleTreeCollection};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+use validator_bonds::state::bond::find_bond_address;
+use validator_bonds::state::settlement::find_settlement_address;
+use validator_bonds_common::settlement_claims::SettlementClaimsBitmap;
+use validator_bonds_common::settlements::{
+    get_settlement_claims_for_settlement_pubkeys, get_settlements_for_pubkeys,
+};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ClaimStatus {
+    /// not cross-checked against the on-chain data
+    #[default]
+    Unchecked,
+    /// the claim can be executed
+    Open,
+    /// the claim was already executed, the claims bitmap has the index set
+    Claimed,
+    /// no settlement (or its claims account) on-chain, not created yet or already closed
+    SettlementNotFound,
+    /// the on-chain settlement was created with a different merkle root (e.g., replaced)
+    MerkleRootMismatch,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ClaimIndexEntry {
+    pub epoch: u64,
+    #[serde(with = "pubkey_string_conversion")]
+    pub vote_account: Pubkey,
+    #[serde(with = "pubkey_string_conversion")]
+    pub bond_account: Pubkey,
+    #[serde(with = "pubkey_string_conversion")]
+    pub settlement_account: Pubkey,
+    pub merkle_root: Hash,
+    #[serde(with = "pubkey_string_conversion")]
+    pub stake_authority: Pubkey,
+    #[serde(with = "pubkey_string_conversion")]
+    pub withdraw_authority: Pubkey,
+    pub index: u64,
+    pub claim: u64,
+    pub proof: Vec<[u8; 32]>,
+    #[serde(default)]
+    pub status: ClaimStatus,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ClaimIndex {
+    /// epochs of the merkle tree collections the index was built from
+    pub epochs: Vec<u64>,
+    pub entries: Vec<ClaimIndexEntry>,
+    /// stake and withdraw authority -> positions in `entries`
+    #[serde(skip)]
+    by_authority: HashMap<Pubkey, Vec<usize>>,
+}
+
+impl ClaimIndex {
+    /// Builds the index from merkle tree collections.
+    /// Settlement addresses are derived from `config_override` when provided,
+    /// otherwise pre-computed addresses of the collection are used.
+    pub fn build(
+        collections: &[MerkleTreeCollection],
+        config_override: Option<Pubkey>,
+    ) -> anyhow::Result<Self> {
+        let mut epochs = Vec::with_capacity(collections.len());
+        let mut entries = vec![];
+        for collection in collections {
+            let config = config_override.unwrap_or(collection.validator_bonds_config);
+            epochs.push(collection.epoch);
+            for merkle_tree in &collection.merkle_trees {
+                let merkle_root = if let Some(merkle_root) = merkle_tree.merkle_root {
+                    merkle_root
+                } else {
+                    debug!(
+                        "Skipping merkle tree without root for vote account {} epoch {}",
+                        merkle_tree.vote_account, collection.epoch
+                    );
+                    continue;
+                };
+                let (bond_account, settlement_account) = if config_override.is_none()
+                    && merkle_tree.settlement_account != Pubkey::default()
+                {
+                    (merkle_tree.bond_account, merkle_tree.settlement_account)
+                } else {
+                    if config == Pubkey::default() {
+                        bail!(
+                            "No validator bonds config to derive settlement of vote account {} epoch {}",
+                            merkle_tree.vote_account,
+                            collection.epoch
+                        );
+                    }
+                    let bond_account = find_bond_address(&config, &merkle_tree.vote_account).0;
+                    let settlement_account = find_settlement_address(
+                        &bond_account,
+                        &merkle_tree
+                            .replaceable_seed
+                            .unwrap_or(merkle_root)
+                            .to_bytes(),
+                        collection.epoch,
+                    )
+                    .0;
+                    (bond_account, settlement_account)
+                };
+
+                // proofs are not stored in all collection files, rebuilding the tree when missing
+                let rebuilt_tree = if merkle_tree.tree_nodes.iter().any(|n| n.proof.is_none()) {
+                    let hashed_nodes: Vec<[u8; 32]> = merkle_tree
+                        .tree_nodes
+                        .iter()
+                        .map(|n| n.hash().to_bytes())
+                        .collect();
+                    let rebuilt_tree = MerkleTree::new(&hashed_nodes[..], true);
+                    if rebuilt_tree.get_root() != Some(&merkle_root) {
+                        bail!(
+                            "Merkle root {merkle_root} does not match tree nodes of settlement {settlement_account}"
+                        );
+                    }
+                    Some(rebuilt_tree)
+                } else {
+                    None
+                };
+
+                for (i, tree_node) in merkle_tree.tree_nodes.iter().enumerate() {
+                    let proof = match (&tree_node.proof, &rebuilt_tree) {
+                        (Some(proof), _) => proof.clone(),
+                        (None, Some(rebuilt_tree)) => get_proof(rebuilt_tree, i),
+                        (None, None) => unreachable!("tree is rebuilt when a proof is missing"),
+                    };
+                    entries.push(ClaimIndexEntry {
+                        epoch: collection.epoch,
+                        vote_account: merkle_tree.vote_account,
+                        bond_account,
+                        settlement_account,
+                        merkle_root,
+                        stake_authority: tree_node.stake_authority,
+                        withdraw_authority: tree_node.withdraw_authority,
+                        index: tree_node.index,
+                        claim: tree_node.claim,
+                        proof,
+                        status: ClaimStatus::Unchecked,
+                    });
+                }
+            }
+        }
+        epochs.sort_unstable();
+        epochs.dedup();
+
+        let mut claim_index = Self {
+            epochs,
+            entries,
+            by_authority: HashMap::new(),
+        };
+        claim_index.rebuild_lookup();
+        info!(
+            "Built claim index of {} entries for {} authorities",
+            claim_index.entries.len(),
+            claim_index.by_authority.len()
+        );
+        Ok(claim_index)
+    }
+
+    pub fn load<P: AsRef<Path>>(in_path: &P) -> anyhow::Result<Self> {
+        let mut claim_index: Self = read_from_file(in_path).map_err(|e| {
+            anyhow!(
+                "Failed to load claim index from {}: {e}",
+                in_path.as_ref().display()
+            )
+        })?;
+        claim_index.rebuild_lookup();
+        Ok(claim_index)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, out_path: &P) -> anyhow::Result<()> {
+        write_to_file(self, out_path)
+    }
+
+    fn rebuild_lookup(&mut self) {
+        let mut by_authority: HashMap<Pubkey, Vec<usize>> = HashMap::new();
+        for (position, entry) in self.entries.iter().enumerate() {
+            by_authority
+                .entry(entry.stake_authority)
+                .or_default()
+                .push(position);
+            if entry.withdraw_authority != entry.stake_authority {
+                by_authority
+                    .entry(entry.withdraw_authority)
+                    .or_default()
+                    .push(position);
+            }
+        }
+        self.by_authority = by_authority;
+    }
+
+    /// Entries where the `authority` is the stake or the withdraw authority.
+    pub fn find_by_authority(&self, authority: &Pubkey) -> Vec<&ClaimIndexEntry> {
+        self.by_authority
+            .get(authority)
+            .map(|positions| positions.iter().map(|p| &self.entries[*p]).collect())
+            .unwrap_or_default()
+    }
+
+    /// Cross-checks the entries (of the `authority` when provided, otherwise all entries)
+    /// against the on-chain settlements and their claims bitmaps.
+    pub async fn refresh_claim_statuses(
+        &mut self,
+        rpc_client: Arc<RpcClient>,
+        authority: Option<&Pubkey>,
+    ) -> anyhow::Result<()> {
+        let positions: Vec<usize> = match authority {
+            Some(authority) => self
+                .by_authority
+                .get(authority)
+                .cloned()
+                .unwrap_or_default(),
+            None => (0..self.entries.len()).collect(),
+        };
+        let settlement_pubkeys: Vec<Pubkey> = positions
+            .iter()
+            .map(|p| self.entries[*p].settlement_account)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        let settlements = get_settlements_for_pubkeys(rpc_client.clone(), &settlement_pubkeys)
+            .await?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+        let settlement_claims =
+            get_settlement_claims_for_settlement_pubkeys(rpc_client, &settlement_pubkeys)
+                .await?
+                .into_iter()
+                .map(|(settlement, _, bitmap)| (settlement, bitmap))
+                .collect::<HashMap<_, _>>();
+
+        for position in positions {
+            let entry = &mut self.entries[position];
+            let on_chain_merkle_root = settlements
+                .get(&entry.settlement_account)
+                .and_then(|s| s.as_ref())
+                .map(|s| s.merkle_root);
+            let bitmap = settlement_claims
+                .get(&entry.settlement_account)
+                .and_then(|b| b.as_ref());
+            entry.status = claim_status(entry, on_chain_merkle_root, bitmap);
+        }
+        Ok(())
+    }
+}
+
+/// Status of the claim based on the on-chain settlement merkle root and claims bitmap.
+pub fn claim_status(
+    entry: &ClaimIndexEntry,
+    on_chain_merkle_root: Option<[u8; 32]>,
+    bitmap: Option<&SettlementClaimsBitmap>,
+) -> ClaimStatus {
+    let (on_chain_merkle_root, bitmap) = match (on_chain_merkle_root, bitmap) {
+        (Some(merkle_root), Some(bitmap)) => (merkle_root, bitmap),
+        _ => return ClaimStatus::SettlementNotFound,
+    };
+    if on_chain_merkle_root != entry.merkle_root.to_bytes() {
+        return ClaimStatus::MerkleRootMismatch;
+    }
+    if entry.index >= bitmap.max_records() {
+        warn!(
+            "Claim index {} out of {} records of settlement {}",
+            entry.index,
+            bitmap.max_records(),
+            entry.settlement_account
+        );
+        return ClaimStatus::MerkleRootMismatch;
+    }
+    if bitmap.is_set(entry.index) {
+        ClaimStatus::Claimed
+    } else {
+        ClaimStatus::Open
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::AccountSerialize;
+    use merkle_tree::multi_proof::verify_multi_proof;
+    use merkle_tree::psr_claim::TreeNode;
+    use merkle_tree::{hash_leaf, LEAF_PREFIX};
+    use settlement_common::merkle_tree_collection::MerkleTreeMeta;
+    use solana_sdk::account::Account;
+    use solana_sdk::hash::hashv;
+    use validator_bonds::constants::SETTLEMENT_CLAIMS_ANCHOR_HEADER_SIZE;
+    use validator_bonds::state::settlement_claims::SettlementClaims;
+
+    fn collection(
+        config: Pubkey,
+        stakers: &[Pubkey],
+        with_proofs: bool,
+    ) -> (MerkleTreeCollection, Pubkey) {
+        let mut tree_nodes: Vec<TreeNode> = stakers
+            .iter()
+            .enumerate()
+            .map(|(index, staker)| TreeNode {
+                stake_authority: *staker,
+                withdraw_authority: Pubkey::new_unique(),
+                claim: 1000 + index as u64,
+                index: index as u64,
+                proof: None,
+            })
+            .collect();
+        let hashed_nodes: Vec<[u8; 32]> = tree_nodes.iter().map(|n| n.hash().to_bytes()).collect();
+        let merkle_tree = MerkleTree::new(&hashed_nodes[..], true);
+        if with_proofs {
+            for (i, tree_node) in tree_nodes.iter_mut().enumerate() {
+                tree_node.proof = Some(get_proof(&merkle_tree, i));
+            }
+        }
+        let vote_account = Pubkey::new_unique();
+        (
+            MerkleTreeCollection {
+                epoch: 700,
+                slot: 42,
+                validator_bonds_config: config,
+                sources: vec![],
+                merkle_trees: vec![MerkleTreeMeta {
+                    merkle_root: merkle_tree.get_root().cloned(),
+                    max_total_claim_sum: tree_nodes.iter().map(|n| n.claim).sum(),
+                    max_total_claims: tree_nodes.len(),
+                    vote_account,
+                    bond_account: Pubkey::default(),
+                    settlement_account: Pubkey::default(),
+                    funding_sources: HashMap::new(),
+                    replaceable_seed: None,
+                    tree_nodes,
+                }],
+            },
+            vote_account,
+        )
+    }
+
+    fn claims_bitmap(max_records: u64, set_indexes: &[u64]) -> SettlementClaimsBitmap {
+        let mut data = vec![];
+        SettlementClaims {
+            settlement: Pubkey::new_unique(),
+            version: 0,
+            max_records,
+            chunk_index: 0,
+            chunks: 0,
+            chunks_initialized: 0,
+            bump: 0,
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        data.resize(SETTLEMENT_CLAIMS_ANCHOR_HEADER_SIZE as usize, 0);
+        let mut bitmap = vec![0_u8; max_records.div_ceil(8) as usize];
+        for index in set_indexes {
+            bitmap[*index as usize / 8] |= 1 << (7 - index % 8);
+        }
+        data.extend(bitmap);
+        SettlementClaimsBitmap::new(Account {
+            data,
+            ..Account::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn build_and_find_by_authority() {
+        let config = Pubkey::new_unique();
+        let staker = Pubkey::new_unique();
+        for with_proofs in [true, false] {
+            let (collection, vote_account) = collection(
+                config,
+                &[staker, Pubkey::new_unique(), staker, Pubkey::new_unique()],
+                with_proofs,
+            );
+            let claim_index = ClaimIndex::build(&[collection], None).unwrap();
+            assert_eq!(claim_index.epochs, vec![700]);
+            assert_eq!(claim_index.entries.len(), 4);
+
+            let entries = claim_index.find_by_authority(&staker);
+            assert_eq!(entries.len(), 2);
+            let bond_account = find_bond_address(&config, &vote_account).0;
+            for entry in entries {
+                assert_eq!(entry.stake_authority, staker);
+                assert_eq!(entry.bond_account, bond_account);
+                assert_eq!(
+                    entry.settlement_account,
+                    find_settlement_address(&bond_account, &entry.merkle_root.to_bytes(), 700).0
+                );
+                let tree_node = TreeNode {
+                    stake_authority: entry.stake_authority,
+                    withdraw_authority: entry.withdraw_authority,
+                    claim: entry.claim,
+                    index: entry.index,
+                    proof: None,
+                };
+                let tree_node_hash = tree_node.hash().to_bytes();
+                let proof: Vec<Hash> = entry
+                    .proof
+                    .iter()
+                    .map(|hash| Hash::new_from_array(*hash))
+                    .collect();
+                // a single leaf proof is a multi-proof of one leaf
+                assert!(verify_multi_proof(
+                    &proof,
+                    &entry.merkle_root,
+                    &[(entry.index as usize, hash_leaf!(tree_node_hash))],
+                    true
+                ));
+            }
+            let withdrawer = claim_index.entries[1].withdraw_authority;
+            assert_eq!(claim_index.find_by_authority(&withdrawer).len(), 1);
+            assert!(claim_index
+                .find_by_authority(&Pubkey::new_unique())
+                .is_empty());
+        }
+    }
+
+    #[test]
+    fn build_requires_config() {
+        let (collection, _) = collection(Pubkey::default(), &[Pubkey::new_unique()], true);
+        assert!(ClaimIndex::build(&[collection.clone()], None).is_err());
+        assert!(ClaimIndex::build(&[collection], Some(Pubkey::new_unique())).is_ok());
+    }
+
+    #[test]
+    fn save_and_load() {
+        let staker = Pubkey::new_unique();
+        let (collection, _) = collection(Pubkey::new_unique(), &[staker, staker], true);
+        let claim_index = ClaimIndex::build(&[collection], None).unwrap();
+        let dir = std::env::temp_dir().join(format!("claim-index-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for file_name in ["claim-index.json", "claim-index.msgpack.zst"] {
+            let path = dir.join(file_name);
+            claim_index.save(&path).unwrap();
+            let loaded = ClaimIndex::load(&path).unwrap();
+            assert_eq!(loaded.entries.len(), 2);
+            assert_eq!(loaded.find_by_authority(&staker).len(), 2);
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn status_from_claims_bitmap() {
+        let (collection, _) = collection(
+            Pubkey::new_unique(),
+            &[Pubkey::new_unique(), Pubkey::new_unique()],
+            true,
+        );
+        let claim_index = ClaimIndex::build(&[collection], None).unwrap();
+        let entry = &claim_index.entries[1];
+        let merkle_root = Some(entry.merkle_root.to_bytes());
+        let bitmap = claims_bitmap(2, &[1]);
+
+        assert_eq!(
+            claim_status(entry, merkle_root, Some(&bitmap)),
+            ClaimStatus::Claimed
+        );
+        assert_eq!(
+            claim_status(&claim_index.entries[0], merkle_root, Some(&bitmap)),
+            ClaimStatus::Open
+        );
+        assert_eq!(
+            claim_status(entry, Some([1; 32]), Some(&bitmap)),
+            ClaimStatus::MerkleRootMismatch
+        );
+        assert_eq!(
+            claim_status(entry, merkle_root, Some(&claims_bitmap(1, &[]))),
+            ClaimStatus::MerkleRootMismatch
+        );
+        assert_eq!(
+            claim_status(entry, merkle_root, None),
+            ClaimStatus::SettlementNotFound
+        );
+    }
+}
@@ -0,0 +1,423 @@
+//! Comparison of two merkle tree collections explaining why the merkle roots differ.
+
+use merkle_tree::serde_serialize::pubkey_string_conversion;
+use merkle_tree::MerkleTree;
+use serde::Serialize;
+use settlement_common::merkle_tree_collection::{MerkleTreeCollection, MerkleTreeMeta};
+use settlement_common::settlement_collection::{SettlementFunder, SettlementKey};
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Merkle trees are matched by the vote account and the funder,
+/// the ordinal distinguishes trees of the same pair in legacy collections without funding sources.
+type MerkleTreeKey = (Pubkey, Option<SettlementFunder>, usize);
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub enum RootChangeReason {
+    /// number of claims present only in the new tree
+    ClaimsAdded(usize),
+    /// number of claims present only in the old tree
+    ClaimsRemoved(usize),
+    /// number of claims with different amount
+    ClaimAmountsChanged(usize),
+    /// the same claims are placed at different tree indexes
+    ClaimsReordered,
+    /// the stored old merkle root is not the root of the old tree nodes
+    OldRootNotMatchingTreeNodes,
+    /// the stored new merkle root is not the root of the new tree nodes
+    NewRootNotMatchingTreeNodes,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MerkleTreeSummary {
+    #[serde(with = "pubkey_string_conversion")]
+    pub vote_account: Pubkey,
+    pub funder: Option<SettlementFunder>,
+    pub merkle_root: Option<Hash>,
+    pub max_total_claim_sum: u64,
+    pub max_total_claims: usize,
+}
+
+/// Change of a single claim keyed by [SettlementKey], `None` when missing on the side.
+#[derive(Clone, Debug, Serialize)]
+pub struct ClaimChange {
+    #[serde(with = "pubkey_string_conversion")]
+    pub withdraw_authority: Pubkey,
+    #[serde(with = "pubkey_string_conversion")]
+    pub stake_authority: Pubkey,
+    pub old_claim: Option<u64>,
+    pub new_claim: Option<u64>,
+    pub old_index: Option<u64>,
+    pub new_index: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MerkleTreeDiff {
+    #[serde(with = "pubkey_string_conversion")]
+    pub vote_account: Pubkey,
+    pub funder: Option<SettlementFunder>,
+    pub old_merkle_root: Option<Hash>,
+    pub new_merkle_root: Option<Hash>,
+    #[serde(with = "pubkey_string_conversion")]
+    pub old_settlement_account: Pubkey,
+    #[serde(with = "pubkey_string_conversion")]
+    pub new_settlement_account: Pubkey,
+    pub old_max_total_claim_sum: u64,
+    pub new_max_total_claim_sum: u64,
+    pub root_change_reasons: Vec<RootChangeReason>,
+    pub claim_changes: Vec<ClaimChange>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MerkleTreeCollectionDiff {
+    pub old_epoch: u64,
+    pub new_epoch: u64,
+    pub old_slot: u64,
+    pub new_slot: u64,
+    #[serde(with = "pubkey_string_conversion")]
+    pub old_validator_bonds_config: Pubkey,
+    #[serde(with = "pubkey_string_conversion")]
+    pub new_validator_bonds_config: Pubkey,
+    pub added: Vec<MerkleTreeSummary>,
+    pub removed: Vec<MerkleTreeSummary>,
+    pub changed: Vec<MerkleTreeDiff>,
+    pub unchanged_count: usize,
+}
+
+impl MerkleTreeCollectionDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+fn merkle_tree_funder(merkle_tree: &MerkleTreeMeta) -> Option<SettlementFunder> {
+    // generated trees have exactly one funder, legacy trees have none
+    if merkle_tree.funding_sources.len() == 1 {
+        merkle_tree.funding_sources.keys().next().cloned()
+    } else {
+        None
+    }
+}
+
+fn merkle_trees_by_key(
+    collection: &MerkleTreeCollection,
+) -> BTreeMap<MerkleTreeKey, &MerkleTreeMeta> {
+    let mut ordinals: HashMap<(Pubkey, Option<SettlementFunder>), usize> = HashMap::new();
+    collection
+        .merkle_trees
+        .iter()
+        .map(|merkle_tree| {
+            let funder = merkle_tree_funder(merkle_tree);
+            let ordinal = ordinals
+                .entry((merkle_tree.vote_account, funder.clone()))
+                .or_default();
+            let key = (merkle_tree.vote_account, funder, *ordinal);
+            *ordinal += 1;
+            (key, merkle_tree)
+        })
+        .collect()
+}
+
+fn summary(merkle_tree: &MerkleTreeMeta) -> MerkleTreeSummary {
+    MerkleTreeSummary {
+        vote_account: merkle_tree.vote_account,
+        funder: merkle_tree_funder(merkle_tree),
+        merkle_root: merkle_tree.merkle_root,
+        max_total_claim_sum: merkle_tree.max_total_claim_sum,
+        max_total_claims: merkle_tree.max_total_claims,
+    }
+}
+
+fn root_matches_tree_nodes(merkle_tree: &MerkleTreeMeta) -> bool {
+    let hashed_nodes: Vec<[u8; 32]> = merkle_tree
+        .tree_nodes
+        .iter()
+        .map(|n| n.hash().to_bytes())
+        .collect();
+    MerkleTree::new(&hashed_nodes[..], true).get_root() == merkle_tree.merkle_root.as_ref()
+}
+
+fn claims_by_key(merkle_tree: &MerkleTreeMeta) -> HashMap<SettlementKey, (u64, u64)> {
+    merkle_tree
+        .tree_nodes
+        .iter()
+        .map(|n| {
+            (
+                SettlementKey {
+                    withdraw_authority: n.withdraw_authority,
+                    stake_authority: n.stake_authority,
+                },
+                (n.claim, n.index),
+            )
+        })
+        .collect()
+}
+
+fn diff_merkle_trees(old: &MerkleTreeMeta, new: &MerkleTreeMeta) -> Option<MerkleTreeDiff> {
+    let old_claims = claims_by_key(old);
+    let new_claims = claims_by_key(new);
+    let keys: HashSet<&SettlementKey> = old_claims.keys().chain(new_claims.keys()).collect();
+
+    let (mut added, mut removed, mut amounts_changed) = (0, 0, 0);
+    let mut claim_changes = vec![];
+    let mut index_changes = vec![];
+    for key in keys {
+        let old_claim = old_claims.get(key);
+        let new_claim = new_claims.get(key);
+        let claim_change = ClaimChange {
+            withdraw_authority: key.withdraw_authority,
+            stake_authority: key.stake_authority,
+            old_claim: old_claim.map(|(claim, _)| *claim),
+            new_claim: new_claim.map(|(claim, _)| *claim),
+            old_index: old_claim.map(|(_, index)| *index),
+            new_index: new_claim.map(|(_, index)| *index),
+        };
+        match (old_claim, new_claim) {
+            (None, Some(_)) => added += 1,
+            (Some(_), None) => removed += 1,
+            (Some((old_amount, _)), Some((new_amount, _))) if old_amount != new_amount => {
+                amounts_changed += 1
+            }
+            (Some((_, old_index)), Some((_, new_index))) => {
+                if old_index != new_index {
+                    index_changes.push(claim_change);
+                }
+                continue;
+            }
+            (None, None) => unreachable!("key is taken from one of the trees"),
+        }
+        claim_changes.push(claim_change);
+    }
+
+    let mut root_change_reasons = vec![];
+    if added > 0 {
+        root_change_reasons.push(RootChangeReason::ClaimsAdded(added));
+    }
+    if removed > 0 {
+        root_change_reasons.push(RootChangeReason::ClaimsRemoved(removed));
+    }
+    if amounts_changed > 0 {
+        root_change_reasons.push(RootChangeReason::ClaimAmountsChanged(amounts_changed));
+    }
+    // index changes are a consequence of added and removed claims, reported only on their own
+    if claim_changes.is_empty() && !index_changes.is_empty() {
+        root_change_reasons.push(RootChangeReason::ClaimsReordered);
+        claim_changes = index_changes;
+    }
+
+    let root_changed = old.merkle_root != new.merkle_root;
+    if root_changed {
+        if !root_matches_tree_nodes(old) {
+            root_change_reasons.push(RootChangeReason::OldRootNotMatchingTreeNodes);
+        }
+        if !root_matches_tree_nodes(new) {
+            root_change_reasons.push(RootChangeReason::NewRootNotMatchingTreeNodes);
+        }
+    }
+
+    if !root_changed
+        && claim_changes.is_empty()
+        && old.settlement_account == new.settlement_account
+        && old.max_total_claim_sum == new.max_total_claim_sum
+    {
+        return None;
+    }
+    claim_changes.sort_by_key(|c| (c.withdraw_authority, c.stake_authority));
+
+    Some(MerkleTreeDiff {
+        vote_account: new.vote_account,
+        funder: merkle_tree_funder(new),
+        old_merkle_root: old.merkle_root,
+        new_merkle_root: new.merkle_root,
+        old_settlement_account: old.settlement_account,
+        new_settlement_account: new.settlement_account,
+        old_max_total_claim_sum: old.max_total_claim_sum,
+        new_max_total_claim_sum: new.max_total_claim_sum,
+        root_change_reasons,
+        claim_changes,
+    })
+}
+
+/// Compares merkle trees of two collections matched by vote account and funder.
+pub fn diff_merkle_tree_collections(
+    old: &MerkleTreeCollection,
+    new: &MerkleTreeCollection,
+) -> MerkleTreeCollectionDiff {
+    let old_trees = merkle_trees_by_key(old);
+    let new_trees = merkle_trees_by_key(new);
+
+    let mut diff = MerkleTreeCollectionDiff {
+        old_epoch: old.epoch,
+        new_epoch: new.epoch,
+        old_slot: old.slot,
+        new_slot: new.slot,
+        old_validator_bonds_config: old.validator_bonds_config,
+        new_validator_bonds_config: new.validator_bonds_config,
+        added: vec![],
+        removed: vec![],
+        changed: vec![],
+        unchanged_count: 0,
+    };
+    for (key, old_tree) in &old_trees {
+        match new_trees.get(key) {
+            None => diff.removed.push(summary(old_tree)),
+            Some(new_tree) => match diff_merkle_trees(old_tree, new_tree) {
+                Some(tree_diff) => diff.changed.push(tree_diff),
+                None => diff.unchanged_count += 1,
+            },
+        }
+    }
+    for (key, new_tree) in &new_trees {
+        if !old_trees.contains_key(key) {
+            diff.added.push(summary(new_tree));
+        }
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merkle_tree::psr_claim::TreeNode;
+
+    fn merkle_tree(
+        vote_account: Pubkey,
+        funder: SettlementFunder,
+        claims: &[(Pubkey, u64)],
+    ) -> MerkleTreeMeta {
+        let tree_nodes: Vec<TreeNode> = claims
+            .iter()
+            .enumerate()
+            .map(|(index, (staker, claim))| TreeNode {
+                stake_authority: *staker,
+                withdraw_authority: *staker,
+                claim: *claim,
+                index: index as u64,
+                proof: None,
+            })
+            .collect();
+        let hashed_nodes: Vec<[u8; 32]> = tree_nodes.iter().map(|n| n.hash().to_bytes()).collect();
+        let max_total_claim_sum = tree_nodes.iter().map(|n| n.claim).sum();
+        MerkleTreeMeta {
+            merkle_root: MerkleTree::new(&hashed_nodes[..], true).get_root().cloned(),
+            max_total_claim_sum,
+            max_total_claims: tree_nodes.len(),
+            vote_account,
+            bond_account: Pubkey::default(),
+            settlement_account: Pubkey::default(),
+            funding_sources: HashMap::from([(funder, max_total_claim_sum)]),
+            replaceable_seed: None,
+            tree_nodes,
+        }
+    }
+
+    fn collection(merkle_trees: Vec<MerkleTreeMeta>) -> MerkleTreeCollection {
+        MerkleTreeCollection {
+            epoch: 700,
+            slot: 42,
+            validator_bonds_config: Pubkey::default(),
+            sources: vec![],
+            merkle_trees,
+        }
+    }
+
+    #[test]
+    fn diff_added_removed_unchanged() {
+        let (vote1, vote2) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let claims = [(Pubkey::new_unique(), 100), (Pubkey::new_unique(), 200)];
+        let old = collection(vec![
+            merkle_tree(vote1, SettlementFunder::ValidatorBond, &claims),
+            merkle_tree(vote2, SettlementFunder::ValidatorBond, &claims),
+        ]);
+        let new = collection(vec![
+            merkle_tree(vote1, SettlementFunder::ValidatorBond, &claims),
+            merkle_tree(vote2, SettlementFunder::Marinade, &claims),
+        ]);
+
+        assert!(diff_merkle_tree_collections(&old, &old).is_empty());
+        let diff = diff_merkle_tree_collections(&old, &new);
+        assert_eq!(diff.unchanged_count, 1);
+        assert!(diff.changed.is_empty());
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(
+            diff.removed[0].funder,
+            Some(SettlementFunder::ValidatorBond)
+        );
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].vote_account, vote2);
+        assert_eq!(diff.added[0].funder, Some(SettlementFunder::Marinade));
+    }
+
+    #[test]
+    fn diff_claim_changes() {
+        let vote = Pubkey::new_unique();
+        let (staker1, staker2, staker3) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let old = collection(vec![merkle_tree(
+            vote,
+            SettlementFunder::ValidatorBond,
+            &[(staker1, 100), (staker2, 200)],
+        )]);
+        let new = collection(vec![merkle_tree(
+            vote,
+            SettlementFunder::ValidatorBond,
+            &[(staker1, 150), (staker3, 300)],
+        )]);
+
+        let diff = diff_merkle_tree_collections(&old, &new);
+        assert_eq!(diff.changed.len(), 1);
+        let tree_diff = &diff.changed[0];
+        assert_eq!(
+            tree_diff.root_change_reasons,
+            vec![
+                RootChangeReason::ClaimsAdded(1),
+                RootChangeReason::ClaimsRemoved(1),
+                RootChangeReason::ClaimAmountsChanged(1),
+            ]
+        );
+        assert_eq!(tree_diff.claim_changes.len(), 3);
+        let staker1_change = tree_diff
+            .claim_changes
+            .iter()
+            .find(|c| c.stake_authority == staker1)
+            .unwrap();
+        assert_eq!(staker1_change.old_claim, Some(100));
+        assert_eq!(staker1_change.new_claim, Some(150));
+    }
+
+    #[test]
+    fn diff_reordered_and_stale_root() {
+        let vote = Pubkey::new_unique();
+        let claims = [(Pubkey::new_unique(), 100), (Pubkey::new_unique(), 200)];
+        let old = collection(vec![merkle_tree(
+            vote,
+            SettlementFunder::ValidatorBond,
+            &claims,
+        )]);
+        let new = collection(vec![merkle_tree(
+            vote,
+            SettlementFunder::ValidatorBond,
+            &[claims[1], claims[0]],
+        )]);
+
+        let diff = diff_merkle_tree_collections(&old, &new);
+        assert_eq!(
+            diff.changed[0].root_change_reasons,
+            vec![RootChangeReason::ClaimsReordered]
+        );
+        assert_eq!(diff.changed[0].claim_changes.len(), 2);
+
+        let mut stale = old.clone();
+        stale.merkle_trees[0].merkle_root = Some(Hash::new_unique());
+        let diff = diff_merkle_tree_collections(&old, &stale);
+        assert_eq!(
+            diff.changed[0].root_change_reasons,
+            vec![RootChangeReason::NewRootNotMatchingTreeNodes]
+        );
+        assert!(diff.changed[0].claim_changes.is_empty());
+    }
+}
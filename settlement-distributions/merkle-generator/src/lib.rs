@@ -1,3 +1,5 @@
+pub mod claim_index;
+pub mod diff;
 pub mod merkle_generator;
 
 pub use merkle_generator::{
@@ -7,16 +7,30 @@ use settlement_common::settlement_collection::{
     Settlement, SettlementClaim, SettlementCollection, SettlementFunder, SettlementKey,
 };
 use settlement_common::utils::sort_merged_claims_deterministically;
+use solana_sdk::hash::Hash;
 use solana_sdk::pubkey::Pubkey;
 use std::collections::HashMap;
 use std::path::Path;
 use validator_bonds::state::bond::find_bond_address;
-use validator_bonds::state::settlement::find_settlement_address;
+use validator_bonds::state::settlement::{find_settlement_address, replaceable_settlement_seed};
 
 /// Configuration for merkle tree generation
 pub struct GeneratorConfig {
     /// The validator bonds config pubkey used to derive bond accounts
     pub validator_bonds_config: Pubkey,
+    /// Settlements are seeded by the funder instead of the merkle root (`init_replaceable_settlement`),
+    /// the merkle root of such settlement can be replaced before claiming starts
+    pub replaceable_settlements: bool,
+}
+
+/// Replaceable settlement seed of the (vote_account, funder) merkle tree,
+/// the bond and epoch are part of the settlement address already
+pub fn replaceable_seed(funder: &SettlementFunder) -> Hash {
+    let label: &[u8] = match funder {
+        SettlementFunder::ValidatorBond => b"ValidatorBond",
+        SettlementFunder::Marinade => b"Marinade",
+    };
+    Hash::new_from_array(replaceable_settlement_seed(label))
 }
 
 /// Represents a source settlement file with its parsed content
@@ -39,7 +53,7 @@ pub fn load_settlement_files<P: AsRef<Path>>(paths: &[P]) -> anyhow::Result<Vec<
 
         info!("Loading settlement file: {}", path_ref.display());
         let collection: SettlementCollection =
-            settlement_common::utils::read_from_json_file(&path_ref)
+            settlement_common::file_format::read_from_file(&path_ref)
                 .map_err(|e| anyhow!("Failed to load {}: {}", path_ref.display(), e))?;
 
         sources.push(SettlementSource { name, collection });
@@ -186,7 +200,15 @@ pub fn generate_merkle_tree_collection(
                 "Merkle tree with claims must have a root for bond {bond_account} epoch {epoch}"
             )
         })?;
-        let settlement_account = find_settlement_address(&bond_account, &root.to_bytes(), epoch).0;
+        let replaceable_seed = config
+            .replaceable_settlements
+            .then(|| replaceable_seed(&funder));
+        let settlement_account = find_settlement_address(
+            &bond_account,
+            &replaceable_seed.unwrap_or(root).to_bytes(),
+            epoch,
+        )
+        .0;
 
         let funding_sources = HashMap::from([(funder, max_total_claim_sum)]);
 
@@ -202,6 +224,7 @@ pub fn generate_merkle_tree_collection(
             bond_account,
             settlement_account,
             funding_sources,
+            replaceable_seed,
             tree_nodes,
         });
     }
@@ -372,6 +395,7 @@ mod tests {
 
         let config = GeneratorConfig {
             validator_bonds_config: Pubkey::new_unique(),
+            replaceable_settlements: false,
         };
 
         let result = generate_merkle_tree_collection(vec![source1, source2], &config).unwrap();
@@ -439,6 +463,7 @@ mod tests {
 
         let config = GeneratorConfig {
             validator_bonds_config: Pubkey::new_unique(),
+            replaceable_settlements: false,
         };
 
         let result = generate_merkle_tree_collection(vec![source_a, source_b], &config).unwrap();
@@ -498,6 +523,7 @@ mod tests {
 
         let config = GeneratorConfig {
             validator_bonds_config: Pubkey::new_unique(),
+            replaceable_settlements: false,
         };
 
         let result = generate_merkle_tree_collection(vec![source], &config).unwrap();
@@ -530,4 +556,55 @@ mod tests {
             result.merkle_trees[1].merkle_root
         );
     }
+
+    #[test]
+    fn test_replaceable_settlement_address_independent_of_merkle_root() {
+        let vote_account = Pubkey::new_unique();
+        let withdraw = Pubkey::new_unique();
+        let stake = Pubkey::new_unique();
+        let config = GeneratorConfig {
+            validator_bonds_config: Pubkey::new_unique(),
+            replaceable_settlements: true,
+        };
+        let generate = |claim_amount: u64| {
+            let source = SettlementSource {
+                name: "settlements.json".to_string(),
+                collection: SettlementCollection {
+                    slot: 12345,
+                    epoch: 100,
+                    settlements: vec![create_test_settlement(
+                        vote_account,
+                        SettlementReason::Bidding,
+                        SettlementFunder::ValidatorBond,
+                        vec![create_test_claim(withdraw, stake, claim_amount, 1000)],
+                    )],
+                    ..Default::default()
+                },
+            };
+            generate_merkle_tree_collection(vec![source], &config).unwrap()
+        };
+
+        let first = &generate(100).merkle_trees[0];
+        let second = &generate(200).merkle_trees[0];
+        assert_ne!(first.merkle_root, second.merkle_root);
+        assert_eq!(
+            first.replaceable_seed,
+            Some(replaceable_seed(&SettlementFunder::ValidatorBond))
+        );
+        assert_eq!(first.settlement_pda_seed(), first.replaceable_seed);
+        assert_eq!(first.settlement_account, second.settlement_account);
+        assert_eq!(
+            first.settlement_account,
+            find_settlement_address(
+                &first.bond_account,
+                &replaceable_seed(&SettlementFunder::ValidatorBond).to_bytes(),
+                100
+            )
+            .0
+        );
+        assert_ne!(
+            replaceable_seed(&SettlementFunder::ValidatorBond),
+            replaceable_seed(&SettlementFunder::Marinade)
+        );
+    }
 }
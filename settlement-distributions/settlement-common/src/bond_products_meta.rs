@@ -0,0 +1,41 @@
+use solana_sdk::pubkey::Pubkey;
+
+use {
+    merkle_tree::serde_serialize::pubkey_string_conversion,
+    serde::{Deserialize, Serialize},
+    std::collections::HashMap,
+    std::fmt::Debug,
+};
+
+/// Typed bond products of a validator as configured on-chain (BondProduct accounts).
+/// The records are produced by `bonds-collector collect-bond-products`,
+/// fields of other bond record data are ignored on parsing.
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq, Default)]
+pub struct BondProductsMeta {
+    #[serde(with = "pubkey_string_conversion")]
+    pub vote_account: Pubkey,
+    /// uptime (in bps of expected vote credits) the validator guarantees to stakers
+    #[serde(default)]
+    pub uptime_guarantee_bps: Option<u64>,
+    /// maximum stake (in lamports) the validator protects with the bond
+    #[serde(default)]
+    pub max_stake_cap_lamports: Option<u64>,
+}
+
+pub fn bond_products_by_vote_account(
+    bond_products: &[BondProductsMeta],
+) -> anyhow::Result<HashMap<Pubkey, BondProductsMeta>> {
+    let mut bond_products_map = HashMap::with_capacity(bond_products.len());
+    for bond_products_meta in bond_products {
+        if bond_products_map
+            .insert(bond_products_meta.vote_account, bond_products_meta.clone())
+            .is_some()
+        {
+            anyhow::bail!(
+                "Bond products collection contains duplicate vote account {}",
+                bond_products_meta.vote_account
+            );
+        }
+    }
+    Ok(bond_products_map)
+}
@@ -0,0 +1,222 @@
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::Path;
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+};
+
+const ZSTD_EXTENSION: &str = ".zst";
+const MSGPACK_EXTENSION: &str = ".msgpack";
+const ZSTD_COMPRESSION_LEVEL: i32 = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    /// pretty printed JSON
+    Json,
+    /// MessagePack with named struct fields; compact binary format supporting the same serde
+    /// attributes (flatten, tagged enums, skipped fields) as JSON does
+    MessagePack,
+}
+
+/// Format of the collection files (MerkleTreeCollection, SettlementCollection, ...)
+/// derived from the file extension:
+/// `.json`, `.json.zst`, `.msgpack`, `.msgpack.zst`; unknown extensions default to JSON.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FileFormat {
+    pub encoding: Encoding,
+    pub zstd_compressed: bool,
+}
+
+impl FileFormat {
+    pub fn from_path<P: AsRef<Path>>(path: &P) -> Self {
+        let file_name = path
+            .as_ref()
+            .file_name()
+            .map(|name| name.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        let (file_name, zstd_compressed) = match file_name.strip_suffix(ZSTD_EXTENSION) {
+            Some(stripped) => (stripped, true),
+            None => (file_name.as_str(), false),
+        };
+        let encoding = if file_name.ends_with(MSGPACK_EXTENSION) {
+            Encoding::MessagePack
+        } else {
+            Encoding::Json
+        };
+        Self {
+            encoding,
+            zstd_compressed,
+        }
+    }
+}
+
+/// Writes data in the format derived from the `out_path` extension, see [`FileFormat`].
+pub fn write_to_file<T: Serialize, P: AsRef<Path>>(data: &T, out_path: &P) -> anyhow::Result<()> {
+    let format = FileFormat::from_path(out_path);
+    let writer = BufWriter::new(File::create(out_path)?);
+    if format.zstd_compressed {
+        let mut encoder = zstd::stream::write::Encoder::new(writer, ZSTD_COMPRESSION_LEVEL)?;
+        serialize_to_writer(data, format.encoding, &mut encoder)?;
+        encoder.finish()?.flush()?;
+    } else {
+        let mut writer = writer;
+        serialize_to_writer(data, format.encoding, &mut writer)?;
+        writer.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Reads data in the format derived from the `in_path` extension, see [`FileFormat`].
+pub fn read_from_file<P: AsRef<Path>, T: DeserializeOwned>(in_path: &P) -> anyhow::Result<T> {
+    let format = FileFormat::from_path(in_path);
+    let file = File::open(in_path)?;
+    if format.zstd_compressed {
+        deserialize_from_reader(format.encoding, zstd::stream::read::Decoder::new(file)?)
+    } else {
+        deserialize_from_reader(format.encoding, BufReader::new(file))
+    }
+}
+
+fn serialize_to_writer<T: Serialize, W: Write>(
+    data: &T,
+    encoding: Encoding,
+    writer: &mut W,
+) -> anyhow::Result<()> {
+    match encoding {
+        Encoding::Json => serde_json::to_writer_pretty(writer, data)?,
+        Encoding::MessagePack => rmp_serde::encode::write_named(writer, data)?,
+    }
+    Ok(())
+}
+
+fn deserialize_from_reader<T: DeserializeOwned, R: Read>(
+    encoding: Encoding,
+    reader: R,
+) -> anyhow::Result<T> {
+    let result = match encoding {
+        Encoding::Json => serde_json::from_reader(reader)?,
+        Encoding::MessagePack => rmp_serde::decode::from_read(reader)?,
+    };
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle_tree_collection::{MerkleTreeCollection, MerkleTreeMeta};
+    use crate::settlement_collection::{
+        Settlement, SettlementClaim, SettlementCollection, SettlementFunder, SettlementReason,
+    };
+    use merkle_tree::psr_claim::TreeNode;
+    use solana_sdk::pubkey::Pubkey;
+    use std::collections::HashMap;
+
+    #[test]
+    fn format_from_path() {
+        let format = |path: &str| FileFormat::from_path(&path);
+        assert_eq!(
+            format("merkle_trees.json"),
+            FileFormat {
+                encoding: Encoding::Json,
+                zstd_compressed: false
+            }
+        );
+        assert_eq!(
+            format("/tmp/merkle_trees.JSON.zst"),
+            FileFormat {
+                encoding: Encoding::Json,
+                zstd_compressed: true
+            }
+        );
+        assert_eq!(
+            format("merkle_trees.msgpack"),
+            FileFormat {
+                encoding: Encoding::MessagePack,
+                zstd_compressed: false
+            }
+        );
+        assert_eq!(
+            format("merkle_trees.msgpack.zst"),
+            FileFormat {
+                encoding: Encoding::MessagePack,
+                zstd_compressed: true
+            }
+        );
+        assert_eq!(format("merkle_trees").encoding, Encoding::Json);
+    }
+
+    #[test]
+    fn collections_round_trip() {
+        let vote_account = Pubkey::new_unique();
+        let settlement_collection = SettlementCollection {
+            slot: 42,
+            epoch: 7,
+            settlements: vec![Settlement {
+                reason: SettlementReason::Bidding,
+                funder: SettlementFunder::ValidatorBond,
+                vote_account,
+                claims_count: 1,
+                claims_amount: 9_007_199_254_740_993,
+                claims: vec![SettlementClaim::staker_payout(
+                    Pubkey::new_unique(),
+                    Pubkey::new_unique(),
+                    100,
+                    0,
+                    9_007_199_254_740_993,
+                    HashMap::from([(Pubkey::new_unique(), 100)]),
+                )],
+                details: None,
+            }],
+            adj_max_fee_bps: Some(100),
+            adj_min_fee_bps: None,
+            ssr_pmpe: None,
+        };
+        let merkle_tree_collection = MerkleTreeCollection {
+            epoch: 7,
+            slot: 42,
+            validator_bonds_config: Pubkey::new_unique(),
+            sources: vec![],
+            merkle_trees: vec![MerkleTreeMeta {
+                merkle_root: Some(solana_sdk::hash::Hash::new_unique()),
+                max_total_claim_sum: 9_007_199_254_740_993,
+                max_total_claims: 1,
+                vote_account,
+                bond_account: Pubkey::new_unique(),
+                settlement_account: Pubkey::new_unique(),
+                funding_sources: HashMap::from([(SettlementFunder::Marinade, 1)]),
+                replaceable_seed: None,
+                tree_nodes: vec![TreeNode {
+                    stake_authority: Pubkey::new_unique(),
+                    withdraw_authority: Pubkey::new_unique(),
+                    claim: 9_007_199_254_740_993,
+                    index: 0,
+                    proof: Some(vec![[7; 32], [255; 32]]),
+                }],
+            }],
+        };
+
+        let dir = std::env::temp_dir().join(format!("file-format-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for extension in ["json", "json.zst", "msgpack", "msgpack.zst"] {
+            let path = dir.join(format!("settlements.{extension}"));
+            write_to_file(&settlement_collection, &path).unwrap();
+            let loaded: SettlementCollection = read_from_file(&path).unwrap();
+            assert_eq!(
+                serde_json::to_value(&loaded).unwrap(),
+                serde_json::to_value(&settlement_collection).unwrap(),
+                "{extension}"
+            );
+
+            let path = dir.join(format!("merkle_trees.{extension}"));
+            write_to_file(&merkle_tree_collection, &path).unwrap();
+            let loaded: MerkleTreeCollection = read_from_file(&path).unwrap();
+            assert_eq!(
+                serde_json::to_value(&loaded).unwrap(),
+                serde_json::to_value(&merkle_tree_collection).unwrap(),
+                "{extension}"
+            );
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,3 +1,5 @@
+pub mod bond_products_meta;
+pub mod file_format;
 pub mod merkle_tree_collection;
 pub mod protected_events;
 pub mod revenue_expectation_meta;
@@ -27,9 +27,20 @@ pub struct MerkleTreeMeta {
     /// Per-funder funding amounts (e.g., ValidatorBond -> lamports, Marinade -> lamports)
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub funding_sources: HashMap<SettlementFunder, u64>,
+    /// Settlement PDA seed used instead of the merkle root for replaceable settlements
+    /// (`init_replaceable_settlement`), the merkle root can be then replaced before claiming starts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replaceable_seed: Option<Hash>,
     pub tree_nodes: Vec<TreeNode>,
 }
 
+impl MerkleTreeMeta {
+    /// Seed of the settlement PDA, the replaceable seed when defined, otherwise the merkle root
+    pub fn settlement_pda_seed(&self) -> Option<Hash> {
+        self.replaceable_seed.or(self.merkle_root)
+    }
+}
+
 #[derive(Clone, Deserialize, Serialize)]
 pub struct MerkleTreeCollection {
     pub epoch: u64,
@@ -63,6 +74,17 @@ pub fn get_proof(merkle_tree: &MerkleTree, i: usize) -> Vec<[u8; 32]> {
     proof
 }
 
+/// Extracts merkle multi-proof for tree nodes at `indices` from a merkle tree,
+/// the siblings shared by the paths of the tree nodes are included only once.
+pub fn get_multi_proof(merkle_tree: &MerkleTree, indices: &[usize]) -> Vec<[u8; 32]> {
+    merkle_tree
+        .find_multi_proof(indices)
+        .unwrap_or_else(|| panic!("multi-proof for indices {indices:?} not found in merkle tree"))
+        .into_iter()
+        .map(|hash| hash.to_bytes())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,4 @@
+use crate::bond_products_meta::BondProductsMeta;
 use crate::revenue_expectation_meta::{RevenueExpectationMeta, RevenueExpectationMetaCollection};
 use crate::settlement_config::SettlementConfig;
 use crate::utils::bps_decimal;
@@ -56,6 +57,22 @@ pub enum ProtectedEvent {
         epr_loss_bps: u64,
         stake: u64,
     },
+    /// validator uptime dropped under the uptime guaranteed by its UptimeGuarantee bond product
+    UptimeGuaranteeBreach {
+        #[serde(with = "pubkey_string_conversion")]
+        vote_account: Pubkey,
+        actual_credits: u64,
+        expected_credits: u64,
+        min_uptime_bps: u64,
+        actual_uptime_bps: u64,
+        /// how many lamports per 1 staked lamport was expected to be paid with the guaranteed uptime
+        #[schema(value_type = f64)]
+        expected_epr: Decimal,
+        #[schema(value_type = f64)]
+        actual_epr: Decimal,
+        epr_loss_bps: u64,
+        stake: u64,
+    },
 
     // V1 events (before SAM was introduced) for backward compatibility to parse JSONs
     CommissionIncrease {
@@ -92,6 +109,7 @@ impl ProtectedEvent {
         match self {
             ProtectedEvent::DowntimeRevenueImpact { vote_account, .. } => vote_account,
             ProtectedEvent::CommissionSamIncrease { vote_account, .. } => vote_account,
+            ProtectedEvent::UptimeGuaranteeBreach { vote_account, .. } => vote_account,
             ProtectedEvent::CommissionIncrease { vote_account, .. } => vote_account,
             ProtectedEvent::LowCredits { vote_account, .. } => vote_account,
         }
@@ -100,6 +118,7 @@ impl ProtectedEvent {
         *match self {
             ProtectedEvent::DowntimeRevenueImpact { expected_epr, .. } => expected_epr,
             ProtectedEvent::CommissionSamIncrease { expected_epr, .. } => expected_epr,
+            ProtectedEvent::UptimeGuaranteeBreach { expected_epr, .. } => expected_epr,
             ProtectedEvent::CommissionIncrease { expected_epr, .. } => expected_epr,
             ProtectedEvent::LowCredits { expected_epr, .. } => expected_epr,
         }
@@ -142,6 +161,11 @@ impl ProtectedEvent {
                 expected_epr,
                 actual_epr,
                 ..
+            }
+            | ProtectedEvent::UptimeGuaranteeBreach {
+                expected_epr,
+                actual_epr,
+                ..
             } => expected_epr - actual_epr,
             non_implemented => {
                 panic!("Claim per stake is not implemented for event {non_implemented:?}")
@@ -280,9 +304,69 @@ pub fn collect_downtime_revenue_impact_events(
         .collect()
 }
 
+/// Events for validators that guarantee uptime with the UptimeGuarantee bond product.
+/// Uptime is considered against the stake weighted average of credits, as for the downtime.
+/// The expected EPR is the revenue the staker gets when the validator runs at the guaranteed uptime.
+pub fn collect_uptime_guarantee_breach_events(
+    validator_meta_collection: &ValidatorMetaCollection,
+    revenue_expectation_map: &HashMap<Pubkey, RevenueExpectationMeta>,
+    bond_products_map: &HashMap<Pubkey, BondProductsMeta>,
+) -> Vec<ProtectedEvent> {
+    info!("Collecting uptime guarantee breach events...");
+    let total_stake_weighted_credits = validator_meta_collection.total_stake_weighted_credits();
+    let expected_credits =
+        (total_stake_weighted_credits / validator_meta_collection.total_stake() as u128) as u64;
+    if expected_credits == 0 {
+        debug!("No credits earned in the epoch, uptime cannot be evaluated");
+        return vec![];
+    }
+    validator_meta_collection
+        .validator_metas
+        .iter()
+        .filter(|v| v.stake > 0)
+        .cloned()
+        .filter_map(|ValidatorMeta {vote_account, credits, commission, stake, ..}| {
+            let min_uptime_bps = bond_products_map
+                .get(&vote_account)
+                .and_then(|bond_products| bond_products.uptime_guarantee_bps)?;
+            let revenue_expectation = revenue_expectation_map.get(&vote_account);
+            if let Some(revenue_expectation) = revenue_expectation {
+                let actual_uptime_bps = bps(credits.min(expected_credits), expected_credits);
+                if actual_uptime_bps < min_uptime_bps && commission < 100 {
+                    debug!("Validator {vote_account} breached uptime guarantee, uptime: {actual_uptime_bps} bps, guaranteed: {min_uptime_bps} bps");
+                    let full_uptime_epr = revenue_expectation.actual_non_bid_pmpe / dec!(1000);
+                    Some(
+                        ProtectedEvent::UptimeGuaranteeBreach {
+                            vote_account,
+                            actual_credits: credits,
+                            expected_credits,
+                            min_uptime_bps,
+                            actual_uptime_bps,
+                            expected_epr: full_uptime_epr * bps_to_fraction(min_uptime_bps),
+                            actual_epr: full_uptime_epr * bps_to_fraction(actual_uptime_bps),
+                            epr_loss_bps: bps(
+                                min_uptime_bps - actual_uptime_bps,
+                                min_uptime_bps
+                            ),
+                            stake,
+                        },
+                    )
+                } else {
+                    debug!("Validator {vote_account} keeps the uptime guarantee");
+                    None
+                }
+            } else {
+                debug!("Revenue expectation data not found for validator {vote_account}");
+                None
+            }
+        })
+        .collect()
+}
+
 pub fn generate_protected_event_collection(
     validator_meta_collection: ValidatorMetaCollection,
     revenue_expectation_meta_collection: RevenueExpectationMetaCollection,
+    bond_products_map: &HashMap<Pubkey, BondProductsMeta>,
 ) -> ProtectedEventCollection {
     assert_eq!(
         validator_meta_collection.epoch, revenue_expectation_meta_collection.epoch,
@@ -305,10 +389,16 @@ pub fn generate_protected_event_collection(
         &validator_meta_collection,
         &revenue_expectation_map,
     );
+    let uptime_guarantee_breach_events = collect_uptime_guarantee_breach_events(
+        &validator_meta_collection,
+        &revenue_expectation_map,
+        bond_products_map,
+    );
 
     let mut events: Vec<_> = Default::default();
     events.extend(commission_increase_events);
     events.extend(downtime_revenue_impact_events);
+    events.extend(uptime_guarantee_breach_events);
 
     ProtectedEventCollection {
         epoch: validator_meta_collection.epoch,
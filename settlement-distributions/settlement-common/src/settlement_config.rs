@@ -37,6 +37,15 @@ pub enum SettlementConfigKind {
         /// penalty settlement markup, in basis points, applied if EPR change is large
         penalty_markup_bps: u64,
     },
+    /// configuration for protected event [protected_events::ProtectedEvent::UptimeGuaranteeBreach]
+    UptimeGuaranteeSettlement {
+        /// when settlement sum of claims is under this value, it is not generated
+        min_settlement_lamports: u64,
+        /// when the uptime shortfall against the guarantee is lower to the grace period the settlement is not generated
+        grace_shortfall_bps: Option<u64>,
+        /// range of bps that are covered by the settlement, usually differentiated by type of funder
+        covered_range_bps: [u64; 2],
+    },
 }
 
 impl SettlementConfigKind {
@@ -48,6 +57,9 @@ impl SettlementConfigKind {
             SettlementConfigKind::CommissionSamIncreaseSettlement {
                 covered_range_bps, ..
             } => covered_range_bps,
+            SettlementConfigKind::UptimeGuaranteeSettlement {
+                covered_range_bps, ..
+            } => covered_range_bps,
         }
     }
     pub fn min_settlement_lamports(&self) -> u64 {
@@ -60,6 +72,10 @@ impl SettlementConfigKind {
                 min_settlement_lamports,
                 ..
             } => min_settlement_lamports,
+            SettlementConfigKind::UptimeGuaranteeSettlement {
+                min_settlement_lamports,
+                ..
+            } => min_settlement_lamports,
         }
     }
 }
@@ -105,6 +121,25 @@ pub fn build_protected_event_matcher(
                     false
                 }
             }
+            (
+                SettlementConfigKind::UptimeGuaranteeSettlement {
+                    grace_shortfall_bps,
+                    ..
+                },
+                ProtectedEvent::UptimeGuaranteeBreach { epr_loss_bps, .. },
+            ) => {
+                if *epr_loss_bps > grace_shortfall_bps.unwrap_or_default() {
+                    true
+                } else {
+                    debug!(
+                        "UptimeGuaranteeBreach event vote account {} with epr_loss_bps: {} is under grace period: {}",
+                        protected_event.vote_account(),
+                        epr_loss_bps,
+                        grace_shortfall_bps.unwrap_or_default()
+                    );
+                    false
+                }
+            }
             _ => false,
         }
     })
@@ -7,6 +7,7 @@ use serde::Serialize;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::commitment_config::{CommitmentConfig, CommitmentLevel};
 use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use solana_sdk::signer::null_signer::NullSigner;
 use solana_transaction_executor::{PriorityFeePolicy, TipPolicy};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -47,6 +48,30 @@ pub struct GlobalOpts {
     #[arg(long)]
     pub skip_preflight: bool,
 
+    /// Transactions are not executed but exported to the file to be signed offline,
+    /// then to be submitted with the 'submit-signed-transactions' command.
+    /// The operator authority may be provided as a pubkey for the export.
+    #[arg(long)]
+    pub export_unsigned_transactions: Option<PathBuf>,
+
+    /// Durable nonce accounts used for transactions instead of recent blockhashes.
+    /// The nonce authority has to be the fee payer, the number of accounts limits the parallel execution.
+    /// With '--export-unsigned-transactions' every exported transaction uses a different nonce account.
+    #[arg(long, value_delimiter = ' ', num_args(1..))]
+    pub nonce_accounts: Vec<Pubkey>,
+
+    /// JSON-lines file journaling every sent transaction with its signature and confirmation status.
+    /// On start the transactions left pending by a previous (crashed) run with the same journal
+    /// are reconciled with the chain before any new work is planned.
+    #[arg(long)]
+    pub journal: Option<PathBuf>,
+
+    /// Transactions are not executed but simulated, the report contains the planned instructions,
+    /// the lamport movements per settlement and the compute unit estimates.
+    /// The operator authority may be provided as a pubkey for the dry run.
+    #[arg(long, conflicts_with_all = ["export_unsigned_transactions", "journal"])]
+    pub dry_run: bool,
+
     /// URL to fetch institutional validators (e.g., https://institutional-staking.marinade.finance/v1/validators).
     /// When set, CLI reporting changes: errors for non-institutional validators are not treated as critical.
     #[arg(long, env)]
@@ -124,6 +149,21 @@ pub fn load_keypair(name: &str, s: &str) -> anyhow::Result<Arc<Keypair>> {
     Ok(Arc::new(k))
 }
 
+/// Loads the keypair, when not available the pubkey is loaded as a signer
+/// that does not sign (i.e., the signature is to be provided offline).
+pub fn load_signer_or_pubkey(name: &str, s: &str) -> anyhow::Result<Arc<dyn Signer>> {
+    match load_keypair(name, s) {
+        Ok(keypair) => Ok(keypair),
+        Err(keypair_err) => {
+            let pubkey = load_pubkey(s).map_err(|e| {
+                anyhow!("Could not load '{name}' as keypair ({keypair_err}) nor as pubkey ({e})")
+            })?;
+            debug!("Signer '{name}' loaded as pubkey {pubkey}, signature to be provided offline");
+            Ok(Arc::new(NullSigner::new(&pubkey)))
+        }
+    }
+}
+
 pub fn load_pubkey(s: &str) -> anyhow::Result<Pubkey> {
     let parsed_keypair_data = parse_keypair_as_json_data(s);
     if let Ok(keypair_data) = parsed_keypair_data {
@@ -184,7 +224,7 @@ pub fn to_priority_fee_policy(opts: &PriorityFeePolicyOpts) -> PriorityFeePolicy
 
 pub struct InitializedGlobalOpts {
     pub fee_payer: Arc<Keypair>,
-    pub operator_authority: Arc<Keypair>,
+    pub operator_authority: Arc<dyn Signer>,
     pub priority_fee_policy: PriorityFeePolicy,
     pub tip_policy: TipPolicy,
     pub rpc_client: Arc<RpcClient>,
@@ -220,9 +260,13 @@ pub fn init_from_opts(
             "Neither --fee-payer nor --keypair provided, no keypair to pay for transaction fees"
         ))?
     };
-    let operator_authority_keypair =
+    let operator_authority_keypair: Arc<dyn Signer> =
         if let Some(operator_authority) = global_opts.operator_authority.clone() {
-            load_keypair("--operator-authority", &operator_authority)?
+            if global_opts.export_unsigned_transactions.is_some() || global_opts.dry_run {
+                load_signer_or_pubkey("--operator-authority", &operator_authority)?
+            } else {
+                load_keypair("--operator-authority", &operator_authority)?
+            }
         } else {
             default_keypair.clone().ok_or(anyhow!(
                 "Neither --operator-authority nor --keypair provided, operator keypair required"
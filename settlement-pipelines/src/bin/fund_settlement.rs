@@ -1,64 +1,14 @@
-use anchor_client::anchor_lang::solana_program::stake::state::{Authorized, Lockup, StakeStateV2};
-use anchor_client::{DynSigner, Program};
 use clap::Parser;
-use log::{debug, error, info};
-use serde::Serialize;
-use settlement_pipelines::anchor::add_instruction_to_builder;
 use settlement_pipelines::arguments::{
-    init_from_opts, InitializedGlobalOpts, PriorityFeePolicyOpts, ReportOpts, TipPolicyOpts,
+    GlobalOpts, PriorityFeePolicyOpts, ReportOpts, TipPolicyOpts,
 };
-use settlement_pipelines::arguments::{load_keypair, GlobalOpts};
-use settlement_pipelines::executor::execute_in_sequence;
-use settlement_pipelines::init::{get_executor, init_log};
-use settlement_pipelines::institutional_validators::{fetch_validator_data, ValidatorsData};
-use settlement_pipelines::json_data::{
-    load_merkle_tree_collections, load_merkle_tree_with_on_chain,
-};
-use settlement_pipelines::reporting::ErrorEntry::{Generic, VoteAccount};
-use settlement_pipelines::reporting::{
-    with_reporting_ext, ErrorEntry, ErrorSeverity, PrintReportable, ReportHandler,
-    ReportSerializable,
-};
-use settlement_pipelines::reporting_data::{ReportingReasonSettlement, SettlementsReportData};
-use settlement_pipelines::settlement_data::{
-    reason_display, SettlementFunderMarinade, SettlementFunderType, SettlementFunderValidatorBond,
-    SettlementRecord,
-};
-use settlement_pipelines::stake_accounts::{
-    fund_settlement_split_underflow, get_delegated_amount, get_stake_state_type,
-    prepare_merge_instructions, settlement_funded_claimable_lamports, StakeAccountStateType,
-    STAKE_ACCOUNT_RENT_EXEMPTION,
-};
-use solana_cli_output::display::build_balance_message;
-use solana_client::nonblocking::rpc_client::RpcClient;
-use solana_sdk::clock::Clock;
-use solana_sdk::pubkey::Pubkey;
-use solana_sdk::signature::{Keypair, Signer};
-use solana_sdk::stake::config::ID as stake_config_id;
-use solana_sdk::stake::instruction::create_account as create_stake_account_instructions;
-use solana_sdk::stake::program::ID as stake_program_id;
-use solana_sdk::sysvar::{
-    clock::ID as clock_sysvar_id, rent::ID as rent_sysvar_id,
-    stake_history::ID as stake_history_sysvar_id,
-};
-use solana_sdk_ids::system_program;
-use solana_transaction_builder::TransactionBuilder;
-use solana_transaction_executor::{PriorityFeePolicy, TransactionExecutor};
-use std::cmp::Ordering;
-use std::collections::{HashMap, HashSet};
-use std::future::Future;
-use std::path::PathBuf;
-use std::pin::Pin;
-use std::sync::Arc;
-use validator_bonds::state::config::{find_bonds_withdrawer_authority, Config};
-use validator_bonds::ID as validator_bonds_id;
-use validator_bonds_common::cli_result::{CliError, CliResult};
-use validator_bonds_common::config::get_config;
-use validator_bonds_common::constants::find_event_authority;
-use validator_bonds_common::stake_accounts::{
-    collect_stake_accounts, obtain_delegated_stake_accounts, CollectedStakeAccount,
-    CollectedStakeAccounts, StakeActivation,
+use settlement_pipelines::init::init_log;
+use settlement_pipelines::pipeline_context::PipelineContext;
+use settlement_pipelines::reporting::{with_reporting_ext, ReportHandler};
+use settlement_pipelines::stages::fund_settlement::{
+    fund_settlement, FundSettlementOpts, FundSettlementsReport,
 };
+use validator_bonds_common::cli_result::CliResult;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -66,14 +16,8 @@ struct Args {
     #[clap(flatten)]
     global_opts: GlobalOpts,
 
-    /// Merkle tree collection JSON files.
-    /// Each file contains a self-contained MerkleTreeCollection with all necessary data.
-    #[arg(required = true, short = 'f', long, value_delimiter = ' ', num_args(1..))]
-    json_files: Vec<PathBuf>,
-
-    /// forcing epoch, overriding from the settlement collection
-    #[arg(long)]
-    epoch: Option<u64>,
+    #[clap(flatten)]
+    fund_settlement_opts: FundSettlementOpts,
 
     #[clap(flatten)]
     priority_fee_policy_opts: PriorityFeePolicyOpts,
@@ -81,14 +25,6 @@ struct Args {
     #[clap(flatten)]
     tip_policy_opts: TipPolicyOpts,
 
-    /// Marinade wallet that pays for Marinade type Settlements
-    #[clap(long)]
-    marinade_wallet: Option<String>,
-
-    /// keypair payer for rent of accounts, if not provided, fee payer keypair is used
-    #[arg(long)]
-    rent_payer: Option<String>,
-
     #[clap(flatten)]
     report_opts: ReportOpts,
 }
@@ -107,1278 +43,13 @@ async fn real_main(
 ) -> anyhow::Result<()> {
     init_log(&args.global_opts);
 
-    let InitializedGlobalOpts {
-        fee_payer,
-        operator_authority,
-        priority_fee_policy,
-        tip_policy,
-        rpc_client,
-        program,
-    } = init_from_opts(
+    let mut context = PipelineContext::new(
         &args.global_opts,
         &args.priority_fee_policy_opts,
         &args.tip_policy_opts,
-    )?;
-
-    let rent_payer = if let Some(rent_payer) = args.rent_payer.clone() {
-        load_keypair("--rent-payer", &rent_payer)?
-    } else {
-        fee_payer.clone()
-    };
-    let marinade_wallet = if let Some(marinade_wallet) = args.marinade_wallet.clone() {
-        load_keypair("--marinade-wallet", &marinade_wallet)?
-    } else {
-        fee_payer.clone()
-    };
-
-    let collections = load_merkle_tree_collections(&args.json_files, args.global_opts.config)?;
-    if collections.is_empty() {
-        anyhow::bail!("No merkle tree collections loaded from provided files");
-    }
-
-    // Resolve config address: from CLI or from merkle tree
-    let config_address = args.global_opts.config.unwrap_or_else(|| {
-        let config = collections[0].validator_bonds_config;
-        info!("Using config address from merkle tree: {config}");
-        config
-    });
-    info!("Funding settlements of validator-bonds config: {config_address}");
-
-    let config = get_config(rpc_client.clone(), config_address)
-        .await
-        .map_err(CliError::retry_able)?;
-
-    let mut settlement_records_per_epoch =
-        load_merkle_tree_with_on_chain(rpc_client.clone(), &collections, args.epoch).await?;
-
-    let transaction_executor = get_executor(rpc_client.clone(), tip_policy);
-
-    reporting
-        .reportable
-        .init(
-            &settlement_records_per_epoch,
-            args.global_opts.institutional_url.clone(),
-        )
-        .await;
-
-    prepare_funding(
-        &program,
-        rpc_client.clone(),
-        transaction_executor.clone(),
-        &mut settlement_records_per_epoch,
-        &config_address,
-        &config,
-        fee_payer.clone(),
-        operator_authority.clone(),
-        &priority_fee_policy,
-        reporting,
-    )
-    .await?;
-
-    fund_settlements(
-        &program,
-        rpc_client.clone(),
-        transaction_executor.clone(),
-        &settlement_records_per_epoch,
-        &config_address,
-        &config,
-        fee_payer.clone(),
-        operator_authority.clone(),
-        marinade_wallet.clone(),
-        rent_payer.clone(),
-        &priority_fee_policy,
-        reporting,
-    )
-    .await?;
-
-    Ok(())
-}
-
-#[allow(clippy::too_many_arguments)]
-async fn prepare_funding(
-    program: &Program<Arc<DynSigner>>,
-    rpc_client: Arc<RpcClient>,
-    transaction_executor: Arc<TransactionExecutor>,
-    settlement_records: &mut HashMap<u64, Vec<SettlementRecord>>,
-    config_address: &Pubkey,
-    config: &Config,
-    fee_payer: Arc<Keypair>,
-    operator_authority: Arc<Keypair>,
-    priority_fee_policy: &PriorityFeePolicy,
-    reporting: &mut ReportHandler<FundSettlementsReport>,
-) -> anyhow::Result<()> {
-    let mut transaction_builder = TransactionBuilder::limited(fee_payer.clone());
-    transaction_builder.add_signer_checked(&operator_authority);
-    let (withdrawer_authority, _) = find_bonds_withdrawer_authority(config_address);
-    let all_stake_accounts =
-        collect_stake_accounts(rpc_client.clone(), Some(&withdrawer_authority), None)
-            .await
-            .map_err(CliError::retry_able)?;
-
-    let stake_activation = StakeActivation::fetch(rpc_client.clone())
-        .await
-        .map_err(CliError::retry_able)?;
-    let minimal_stake_lamports = config.minimum_stake_lamports + STAKE_ACCOUNT_RENT_EXEMPTION;
-
-    let mut fund_bond_stake_accounts = get_on_chain_bond_stake_accounts(
-        &all_stake_accounts,
-        &withdrawer_authority,
-        &stake_activation.clock,
     )
     .await?;
+    reporting.set_executor(context.transaction_executor.clone());
 
-    let epochs_pending_init = epochs_pending_on_chain_init(settlement_records);
-
-    // Merging stake accounts to fit for validator bonds funding
-    for settlement_record in settlement_records
-        .iter_mut()
-        .flat_map(|(_, r)| r.iter_mut())
-    {
-        let epoch = settlement_record.epoch;
-
-        if settlement_record.settlement_account.is_none() {
-            let epoch_pending_init = epochs_pending_init.contains(&epoch);
-            let message = format!(
-                "Settlement {} (vote account {}, bond {}, epoch {}, reason {}) does not exist on-chain, cannot be funded{}",
-                settlement_record.settlement_address,
-                settlement_record.vote_account_address,
-                settlement_record.bond_address,
-                epoch,
-                reason_display(&settlement_record.reason),
-                if epoch_pending_init {
-                    ", no Settlement of the epoch exists on-chain yet (init-settlement pending for the epoch)"
-                } else {
-                    ""
-                },
-            );
-            if epoch_pending_init {
-                reporting.warning()
-            } else {
-                reporting.error()
-            }
-            .with_msg(message)
-            .with_vote(settlement_record.vote_account_address)
-            .add();
-            if epoch_pending_init {
-                reporting.reportable.mut_ref(epoch).pending_init_count += 1;
-            }
-            continue;
-        }
-        if epoch + config.epochs_to_claim_settlement < stake_activation.clock.epoch {
-            reporting.warning().with_msg(format!(
-                "Settlement {} (vote account {}, bond {}, epoch {}, reason {}) is too old to be funded, skipping funding",
-                settlement_record.settlement_address,
-                settlement_record.vote_account_address,
-                settlement_record.bond_address,
-                epoch,
-                reason_display(&settlement_record.reason),
-            )).with_vote(settlement_record.vote_account_address).add();
-            continue;
-        }
-        if settlement_record.bond_account.is_none() {
-            reporting.error().with_msg(format!(
-                "Settlement {} (vote account {}, bond {}, epoch {}, reason {}) funding skipped. Bond account does not exist.",
-                settlement_record.settlement_address,
-                settlement_record.vote_account_address,
-                settlement_record.bond_address,
-                epoch,
-                reason_display(&settlement_record.reason),
-            )).with_vote(settlement_record.vote_account_address).add();
-            reporting
-                .reportable
-                .mut_ref(epoch)
-                .not_funded_by_validator_bond_count += 1;
-            continue;
-        }
-
-        // Marinade funds a settlement by creating a stake account directly (no `fund_settlement`
-        // call), so `lamports_funded` stays 0 — deriving `amount_to_fund` from it would re-fund
-        // and duplicate the stake account every run. Reconstruct: claimed + claimable held in
-        // stake accounts.
-        let already_funded = match &settlement_record.funder {
-            SettlementFunderType::Marinade(_) => {
-                let lamports_claimed =
-                    settlement_record
-                        .settlement_account
-                        .as_ref()
-                        .map_or(0, |s| {
-                            assert_eq!(s.max_total_claim, settlement_record.max_total_claim_sum);
-                            s.lamports_claimed
-                        });
-                lamports_claimed
-                    + settlement_funded_claimable_lamports(
-                        &settlement_record.settlement_staker_authority,
-                        &all_stake_accounts,
-                        minimal_stake_lamports,
-                    )
-            }
-            SettlementFunderType::ValidatorBond(_) => settlement_record
-                .settlement_account
-                .as_ref()
-                .map_or(0, |s| {
-                    assert_eq!(s.max_total_claim, settlement_record.max_total_claim_sum);
-                    s.lamports_funded
-                }),
-        };
-        let settlement_amount_funded = already_funded;
-        let amount_to_fund = settlement_record
-            .max_total_claim_sum
-            .saturating_sub(already_funded);
-
-        if amount_to_fund == 0 {
-            info!(
-                "Settlement {} (vote account {}, epoch {}, funder {:?}) already funded by {}, skipping funding",
-                settlement_record.settlement_address,
-                settlement_record.vote_account_address,
-                settlement_record.epoch,
-                settlement_record.funder,
-                build_balance_message(settlement_amount_funded, false, false),
-            );
-            reporting
-                .reportable
-                .add_already_fully_funded_settlement(settlement_record);
-            continue;
-        }
-
-        match &mut settlement_record.funder {
-            SettlementFunderType::Marinade(_) => {
-                info!(
-                    "Settlement {} (vote account {}, bond {}, reason {}, max claim {} SOLs, epoch {}) is to be funded by Marinade from fee wallet by {} SOLs",
-                    settlement_record.settlement_address,
-                    settlement_record.vote_account_address,
-                    settlement_record.bond_address,
-                    reason_display(&settlement_record.reason),
-                    build_balance_message(settlement_record.max_total_claim_sum, false, false),
-                    epoch,
-                    build_balance_message(amount_to_fund, false, false)
-                );
-                settlement_record.funder =
-                    SettlementFunderType::Marinade(Some(SettlementFunderMarinade {
-                        amount_to_fund,
-                    }));
-                reporting
-                    .reportable
-                    .add_funded_settlement(settlement_record, amount_to_fund);
-            }
-            SettlementFunderType::ValidatorBond(validator_bonds_funders) => {
-                let mut empty_vec: Vec<FundBondStakeAccount> = vec![];
-                let funding_stake_accounts = fund_bond_stake_accounts
-                    .get_mut(&settlement_record.vote_account_address)
-                    .unwrap_or(&mut empty_vec);
-                let amount_needed = amount_to_fund + minimal_stake_lamports;
-                let underwater_stake_accounts = underwater_split_stake_accounts(
-                    funding_stake_accounts,
-                    amount_needed,
-                    minimal_stake_lamports,
-                );
-                for underwater in underwater_stake_accounts.iter() {
-                    reporting.warning().with_msg(format!(
-                        "Settlement {} (vote account {}, epoch {}, reason: {}): skipping stake account {} holding {} SOLs below its recorded delegation {} SOLs (deactivated with withdrawn lamports); funding it would split and underflow fund_settlement, awaiting reset",
-                        settlement_record.settlement_address,
-                        settlement_record.vote_account_address,
-                        epoch,
-                        reason_display(&settlement_record.reason),
-                        underwater.stake_account,
-                        build_balance_message(underwater.lamports, false, false),
-                        build_balance_message(underwater.delegation_stake, false, false),
-                    )).with_vote(settlement_record.vote_account_address).add();
-                }
-                // prioritize the biggest undelegated (inactive) amounts first
-                funding_stake_accounts.sort_by_cached_key(|account| {
-                    let delegated_amount = get_delegated_amount(&account.state, &stake_activation);
-                    account.lamports.saturating_sub(delegated_amount)
-                });
-                funding_stake_accounts.reverse();
-                let fundable_stake_accounts = funding_stake_accounts
-                    .iter()
-                    .filter(|account| {
-                        !is_underwater(&underwater_stake_accounts, &account.stake_account)
-                    })
-                    .collect::<Vec<&FundBondStakeAccount>>();
-                info!(
-                        "Settlement {} (vote account {}, bond {}, reason {}, max claim {} SOLS, epoch {}) is to be funded by validator by {} SOLs. Available {} stake accounts ({}) with {} SOLs.",
-                        settlement_record.settlement_address,
-                        settlement_record.vote_account_address,
-                        settlement_record.bond_address,
-                        reason_display(&settlement_record.reason),
-                        build_balance_message(settlement_record.max_total_claim_sum, false, false),
-                        epoch,
-                        build_balance_message(amount_to_fund, false, false),
-                        fundable_stake_accounts.len(),
-                    fundable_stake_accounts
-                        .iter()
-                        .map(|s| s.stake_account.to_string())
-                        .collect::<Vec<String>>()
-                        .join(","),
-                        build_balance_message(fundable_stake_accounts
-                        .iter()
-                        .map(|s| s.lamports)
-                        .sum::<u64>(), false, false)
-                    );
-                let mut stake_accounts_to_fund = take_stake_accounts_to_fund(
-                    funding_stake_accounts,
-                    &underwater_stake_accounts,
-                    amount_needed,
-                );
-                let funding_lamports_accumulated: u64 =
-                    stake_accounts_to_fund.iter().map(|s| s.lamports).sum();
-
-                // for the found and fitting stake accounts: taking first one and trying to merge other ones into it
-                let stake_account_to_fund: Option<(FundBondStakeAccount, StakeAccountStateType)> =
-                    if stake_accounts_to_fund.is_empty()
-                        || funding_lamports_accumulated <= minimal_stake_lamports
-                    {
-                        None
-                    } else {
-                        let account = stake_accounts_to_fund.remove(0);
-                        let stake_type = get_stake_state_type(&account.state, &stake_activation);
-                        Some((account, stake_type))
-                    };
-                if let Some((
-                    FundBondStakeAccount {
-                        stake_account: destination_stake,
-                        split_stake_account: destination_split_stake,
-                        state: destination_stake_state,
-                        ..
-                    },
-                    destination_stake_state_type,
-                )) = stake_account_to_fund
-                {
-                    let possible_to_merge = stake_accounts_to_fund
-                        .iter()
-                        .map(|f| f.into())
-                        .collect::<Vec<CollectedStakeAccount>>();
-                    // when possible to merge then merge transactions are added to the transaction builder
-                    // when non-mergeable stake account is found, it is directly funded to settlement
-                    let non_mergeable = prepare_merge_instructions(
-                        possible_to_merge.iter().collect(),
-                        destination_stake,
-                        destination_stake_state_type,
-                        &settlement_record.settlement_address,
-                        Some(&settlement_record.vote_account_address),
-                        program,
-                        config_address,
-                        &withdrawer_authority,
-                        &mut transaction_builder,
-                        &stake_activation,
-                    )
-                    .await?;
-
-                    let non_mergeable_lamports: u64 = non_mergeable
-                        .iter()
-                        .map(|address| {
-                            possible_to_merge
-                                .iter()
-                                .find(|(a, _, _)| a == address)
-                                .map_or(0, |(_, lamports, _)| *lamports)
-                        })
-                        .sum();
-                    let destination_merged_lamports =
-                        funding_lamports_accumulated.saturating_sub(non_mergeable_lamports);
-                    let fund_destination = destination_merged_lamports > minimal_stake_lamports;
-                    let mut published_funding_lamports: u64 = 0;
-                    if fund_destination {
-                        published_funding_lamports +=
-                            destination_merged_lamports.saturating_sub(minimal_stake_lamports);
-                        info!(
-                            "Settlement: {} will be funded with destination {} of merged size {} SOLs and {} non-mergeable stake accounts funded on their own",
-                            settlement_record.settlement_address,
-                            destination_stake,
-                            build_balance_message(destination_merged_lamports, false, false),
-                            non_mergeable.len(),
-                        );
-                        validator_bonds_funders.push(SettlementFunderValidatorBond {
-                            stake_account_to_fund: destination_stake,
-                        });
-                    } else {
-                        reporting.warning().with_msg(format!(
-                            "Settlement {} (vote account {}, epoch {}, reason: {}): skipping merge destination {} as its merged size {} SOLs does not exceed the minimum stake size {} SOLs required to fund",
-                            settlement_record.settlement_address,
-                            settlement_record.vote_account_address,
-                            epoch,
-                            reason_display(&settlement_record.reason),
-                            destination_stake,
-                            build_balance_message(destination_merged_lamports, false, false),
-                            build_balance_message(minimal_stake_lamports, false, false),
-                        )).with_vote(settlement_record.vote_account_address).add();
-                    }
-
-                    for stake_account_address in non_mergeable {
-                        let lamports = possible_to_merge
-                            .iter()
-                            .find(|(address, _, _)| *address == stake_account_address)
-                            .map_or(0, |(_, lamports, _)| *lamports);
-                        if lamports <= minimal_stake_lamports {
-                            reporting.warning().with_msg(format!(
-                                "Settlement {} (vote account {}, epoch {}, reason: {}): skipping non-mergeable stake account {} as its {} SOLs does not exceed the minimum stake size {} SOLs required to fund",
-                                settlement_record.settlement_address,
-                                settlement_record.vote_account_address,
-                                epoch,
-                                reason_display(&settlement_record.reason),
-                                stake_account_address,
-                                build_balance_message(lamports, false, false),
-                                build_balance_message(minimal_stake_lamports, false, false),
-                            )).with_vote(settlement_record.vote_account_address).add();
-                            continue;
-                        }
-                        published_funding_lamports +=
-                            lamports.saturating_sub(minimal_stake_lamports);
-                        validator_bonds_funders.push(SettlementFunderValidatorBond {
-                            stake_account_to_fund: stake_account_address,
-                        });
-                    }
-
-                    match funding_lamports_accumulated
-                        .cmp(&(amount_to_fund + minimal_stake_lamports))
-                    {
-                        Ordering::Less => {
-                            reporting.warning().with_msg( format!(
-                                "Cannot fully fund settlement {} (vote account {}, epoch {}, reason: {}, max claim {} SOLs, funder: ValidatorBond). To fund {} SOLs, to fund with min stake amount {}, only {} SOLs were found in stake accounts",
-                                settlement_record.settlement_address,
-                                settlement_record.vote_account_address,
-                                epoch,
-                                reason_display(&settlement_record.reason),
-                                build_balance_message(settlement_record.max_total_claim_sum, false, false),
-                                build_balance_message(amount_to_fund, false, false),
-                                build_balance_message(amount_to_fund + minimal_stake_lamports, false, false),
-                                build_balance_message(funding_lamports_accumulated, false, false)
-                            )).with_vote(settlement_record.vote_account_address).add();
-                        }
-                        Ordering::Equal => {}
-                        Ordering::Greater => {
-                            // the stake account has got (or having after merging) more lamports than needed for the settlement in the current for-loop,
-                            // the rest of lamports will be available in the split stake account
-                            // and that can be used as a source for funding of next settlement when vote account is part of multiple settlements
-                            // WARN: this REQUIRES that the merge stake transactions are executed in sequence!
-                            let lamports_available_after_split = destination_merged_lamports
-                                .saturating_sub(amount_to_fund)
-                                .saturating_sub(minimal_stake_lamports);
-                            if fund_destination
-                                && lamports_available_after_split >= minimal_stake_lamports
-                            {
-                                funding_stake_accounts.push(FundBondStakeAccount {
-                                    lamports: lamports_available_after_split,
-                                    stake_account: destination_split_stake.pubkey(),
-                                    split_stake_account: Arc::new(Keypair::new()),
-                                    state: destination_stake_state,
-                                });
-                            }
-                        }
-                    }
-                    let published_funding = published_funding_lamports.min(amount_to_fund);
-                    if published_funding > 0 {
-                        reporting
-                            .reportable
-                            .add_funded_settlement(settlement_record, published_funding);
-                    }
-                } else {
-                    reporting.warning().with_msg(format!(
-                        "Settlement {} (vote account {}, epoch {}, reason: {}, max claim {} SOLs, funder: ValidatorBond) not funded: available stake {} SOLs does not exceed the minimum stake size {} SOLs required to fund",
-                        settlement_record.settlement_address,
-                        settlement_record.vote_account_address,
-                        epoch,
-                        reason_display(&settlement_record.reason),
-                        build_balance_message(settlement_record.max_total_claim_sum, false, false),
-                        build_balance_message(funding_lamports_accumulated, false, false),
-                        build_balance_message(minimal_stake_lamports, false, false),
-                    )).with_vote(settlement_record.vote_account_address).add();
-                }
-                // we've got to place in code where we wanted to fund something
-                // it does not matter if it was successful or not (e.g., no stake account is available)
-                // we need to track how much was funded before this, the calculated 'amount_to_fund'
-                // reflects on how much is already funded, when subtracted from `max_total_claim_sum` then we get what has been already funded
-                reporting.reportable.add_already_funded_settlement(
-                    settlement_record,
-                    settlement_record
-                        .max_total_claim_sum
-                        .saturating_sub(amount_to_fund),
-                );
-            }
-        }
-    }
-
-    let execution_result_merging = execute_in_sequence(
-        rpc_client.clone(),
-        transaction_executor.clone(),
-        &mut transaction_builder,
-        priority_fee_policy,
-        false,
-    )
-    .await;
-    reporting.add_tx_execution_result(
-        execution_result_merging,
-        "Fund Settlement - Merge Stake Accounts",
-    );
-
-    Ok(())
-}
-
-/// No Settlement of the epoch on-chain means init-settlement has not run yet; a single one missing from an initialized epoch is an anomaly.
-fn epochs_pending_on_chain_init(
-    settlement_records: &HashMap<u64, Vec<SettlementRecord>>,
-) -> HashSet<u64> {
-    settlement_records
-        .iter()
-        .filter(|(_, records)| {
-            records
-                .iter()
-                .all(|record| record.settlement_account.is_none())
-        })
-        .map(|(epoch, _)| *epoch)
-        .collect()
-}
-
-fn is_for_funding(settlement_record: &SettlementRecord) -> bool {
-    match &settlement_record.funder {
-        SettlementFunderType::Marinade(data) => {
-            if let Some(SettlementFunderMarinade { amount_to_fund }) = data {
-                *amount_to_fund > 0
-            } else {
-                false
-            }
-        }
-        SettlementFunderType::ValidatorBond(data) => data.iter().any(
-            |SettlementFunderValidatorBond {
-                 stake_account_to_fund,
-             }| *stake_account_to_fund != Pubkey::default(),
-        ),
-    }
-}
-
-#[allow(clippy::too_many_arguments)]
-async fn fund_settlements(
-    program: &Program<Arc<DynSigner>>,
-    rpc_client: Arc<RpcClient>,
-    transaction_executor: Arc<TransactionExecutor>,
-    settlement_records: &HashMap<u64, Vec<SettlementRecord>>,
-    config_address: &Pubkey,
-    config: &Config,
-    fee_payer: Arc<Keypair>,
-    operator_authority: Arc<Keypair>,
-    marinade_wallet: Arc<Keypair>,
-    rent_payer: Arc<Keypair>,
-    priority_fee_policy: &PriorityFeePolicy,
-    reporting: &mut ReportHandler<FundSettlementsReport>,
-) -> anyhow::Result<()> {
-    let mut transaction_builder = TransactionBuilder::limited(fee_payer.clone());
-    transaction_builder.add_signer_checked(&operator_authority);
-    transaction_builder.add_signer_checked(&rent_payer);
-    transaction_builder.add_signer_checked(&marinade_wallet);
-
-    let (withdrawer_authority, _) = find_bonds_withdrawer_authority(config_address);
-    let minimal_stake_lamports = config.minimum_stake_lamports + STAKE_ACCOUNT_RENT_EXEMPTION;
-
-    // WARN: the prior processing REQUIRES that the fund bond transactions are executed in sequence (execute_in_sequence)
-    //       Funding works with ordered stake accounts where one stake account can be used for multiple settlements
-    //       and number of available SOLs needs to be reduced one by one in the planned order
-
-    for settlement_record in settlement_records.iter().flat_map(|(_, r)| r.iter()) {
-        if !is_for_funding(settlement_record) {
-            debug!(
-                "Settlement {} (vote account {}, bond {}, epoch {}, reason: {}, funder {:?}) is not planned for funding",
-                settlement_record.settlement_address,
-                settlement_record.vote_account_address,
-                settlement_record.bond_address,
-                settlement_record.epoch,
-                reason_display(&settlement_record.reason),
-                settlement_record.funder
-            );
-            continue;
-        }
-        match &settlement_record.funder {
-            SettlementFunderType::Marinade(Some(SettlementFunderMarinade { amount_to_fund })) => {
-                let new_stake_account_keypair = Arc::new(Keypair::new());
-                transaction_builder.add_signer_checked(&new_stake_account_keypair);
-                info!(
-                    "Settlement: {} (vote account {}, bond {}, epoch {}, reason: {}, funder: {}), creating Marinade stake account {}",
-                    settlement_record.settlement_address,
-                    settlement_record.vote_account_address,
-                    settlement_record.bond_address,
-                    settlement_record.epoch,
-                    reason_display(&settlement_record.reason),
-                    settlement_record.funder,
-                    new_stake_account_keypair.pubkey()
-                );
-                let instructions = create_stake_account_instructions(
-                    &marinade_wallet.pubkey(),
-                    &new_stake_account_keypair.pubkey(),
-                    &Authorized {
-                        withdrawer: withdrawer_authority,
-                        staker: settlement_record.settlement_staker_authority,
-                    },
-                    &Lockup {
-                        unix_timestamp: 0,
-                        epoch: 0,
-                        custodian: withdrawer_authority,
-                    },
-                    // after claiming the rest has to be still living stake account
-                    amount_to_fund + minimal_stake_lamports,
-                );
-                transaction_builder.add_instructions(instructions)?;
-                transaction_builder.finish_instruction_pack();
-            }
-            SettlementFunderType::ValidatorBond(validator_bonds_funders) => {
-                for SettlementFunderValidatorBond {
-                    stake_account_to_fund,
-                    ..
-                } in validator_bonds_funders
-                {
-                    // Settlement funding could be of two types: from validator bond or from operator wallet
-                    let split_stake_account_keypair = Arc::new(Keypair::new());
-                    let req = program
-                        .request()
-                        .accounts(validator_bonds::accounts::FundSettlement {
-                            config: *config_address,
-                            bond: settlement_record.bond_address,
-                            vote_account: settlement_record.vote_account_address,
-                            stake_account: *stake_account_to_fund,
-                            bonds_withdrawer_authority: withdrawer_authority,
-                            operator_authority: operator_authority.pubkey(),
-                            settlement: settlement_record.settlement_address,
-                            system_program: system_program::ID,
-                            settlement_staker_authority: settlement_record
-                                .settlement_staker_authority,
-                            rent: rent_sysvar_id,
-                            split_stake_account: split_stake_account_keypair.pubkey(),
-                            split_stake_rent_payer: rent_payer.pubkey(),
-                            stake_history: stake_history_sysvar_id,
-                            clock: clock_sysvar_id,
-                            stake_program: stake_program_id,
-                            stake_config: stake_config_id,
-                            program: validator_bonds_id,
-                            event_authority: find_event_authority().0,
-                        })
-                        .args(validator_bonds::instruction::FundSettlement {});
-                    transaction_builder.add_signer_checked(&split_stake_account_keypair);
-                    add_instruction_to_builder(
-                        &mut transaction_builder,
-                        &req,
-                        format!(
-                            "FundSettlement: {}, bond: {}, vote: {}, reason: {}, stake: {}",
-                            settlement_record.settlement_address,
-                            settlement_record.bond_address,
-                            settlement_record.vote_account_address,
-                            reason_display(&settlement_record.reason),
-                            stake_account_to_fund,
-                        ),
-                    )?;
-                }
-            }
-            _ => {
-                // reason should be already part of reporting and we don't want to double-add
-                error!(
-                    "Not possible to fund settlement {} (vote account {}, bond {}, epoch {}, reason {}, funder {:?})",
-                    settlement_record.settlement_address,
-                    settlement_record.vote_account_address,
-                    settlement_record.bond_address,
-                    settlement_record.epoch,
-                    reason_display(&settlement_record.reason),
-                    settlement_record.funder
-                );
-            }
-        }
-    }
-
-    let execute_result_funding = execute_in_sequence(
-        rpc_client.clone(),
-        transaction_executor.clone(),
-        &mut transaction_builder,
-        priority_fee_policy,
-        false,
-    )
-    .await;
-    reporting.add_tx_execution_result(execute_result_funding, "FundSettlements");
-
-    Ok(())
-}
-
-#[derive(Clone)]
-struct FundBondStakeAccount {
-    lamports: u64,
-    stake_account: Pubkey,
-    split_stake_account: Arc<Keypair>,
-    state: StakeStateV2,
-}
-
-impl From<&FundBondStakeAccount> for CollectedStakeAccount {
-    fn from(fund_bond_stake_account: &FundBondStakeAccount) -> Self {
-        (
-            fund_bond_stake_account.stake_account,
-            fund_bond_stake_account.lamports,
-            fund_bond_stake_account.state,
-        )
-    }
-}
-
-struct UnderwaterStakeAccount {
-    stake_account: Pubkey,
-    lamports: u64,
-    delegation_stake: u64,
-}
-
-fn underwater_split_stake_accounts(
-    stake_accounts: &[FundBondStakeAccount],
-    amount_needed: u64,
-    minimal_stake_lamports: u64,
-) -> Vec<UnderwaterStakeAccount> {
-    stake_accounts
-        .iter()
-        .filter_map(|account| {
-            fund_settlement_split_underflow(
-                &account.state,
-                account.lamports,
-                amount_needed,
-                minimal_stake_lamports,
-                STAKE_ACCOUNT_RENT_EXEMPTION,
-            )
-            .map(|delegation_stake| UnderwaterStakeAccount {
-                stake_account: account.stake_account,
-                lamports: account.lamports,
-                delegation_stake,
-            })
-        })
-        .collect()
-}
-
-fn is_underwater(underwater: &[UnderwaterStakeAccount], stake_account: &Pubkey) -> bool {
-    underwater
-        .iter()
-        .any(|account| &account.stake_account == stake_account)
-}
-
-fn take_stake_accounts_to_fund(
-    stake_accounts_pool: &mut Vec<FundBondStakeAccount>,
-    underwater: &[UnderwaterStakeAccount],
-    amount_needed: u64,
-) -> Vec<FundBondStakeAccount> {
-    let mut lamports_accumulated: u64 = 0;
-    let mut to_fund: Vec<FundBondStakeAccount> = vec![];
-    stake_accounts_pool.retain(|stake_account| {
-        // the split underflow depends on this settlement's amount, another one may fund the account safely
-        if is_underwater(underwater, &stake_account.stake_account)
-            || lamports_accumulated >= amount_needed
-        {
-            true // keep in pool, available for other settlements
-        } else {
-            lamports_accumulated += stake_account.lamports;
-            to_fund.push(stake_account.clone());
-            false // remove from pool, it will be used for funding
-        }
-    });
-    to_fund
-}
-
-/// Filtering stake accounts and creating a Map of vote account to stake accounts
-async fn get_on_chain_bond_stake_accounts(
-    stake_accounts: &CollectedStakeAccounts,
-    withdrawer_authority: &Pubkey,
-    clock: &Clock,
-) -> Result<HashMap<Pubkey, Vec<FundBondStakeAccount>>, CliError> {
-    let non_funded: CollectedStakeAccounts = stake_accounts
-        .clone()
-        .into_iter()
-        .filter(|(_, _, stake)| {
-            if let Some(authorized) = stake.authorized() {
-                authorized.staker == *withdrawer_authority
-                    && authorized.withdrawer == *withdrawer_authority
-            } else {
-                false
-            }
-        })
-        .collect();
-
-    let non_funded_delegated_stakes = obtain_delegated_stake_accounts(non_funded, clock)
-        .await
-        .map_err(CliError::retry_able)?;
-
-    // creating a map of vote account to stake accounts
-    let result_map = non_funded_delegated_stakes
-        .into_iter()
-        .map(|(vote_account, stake_accounts)| {
-            (
-                vote_account,
-                stake_accounts
-                    .into_iter()
-                    .map(|(stake_account, lamports, state)| FundBondStakeAccount {
-                        lamports,
-                        stake_account,
-                        split_stake_account: Arc::new(Keypair::new()),
-                        state,
-                    })
-                    .collect(),
-            )
-        })
-        .collect::<HashMap<Pubkey, Vec<FundBondStakeAccount>>>();
-    Ok(result_map)
-}
-
-#[derive(Default)]
-struct FundSettlementsReport {
-    settlements_per_epoch: HashMap<u64, FundSettlementReport>,
-    institutional_validators: Option<ValidatorsData>,
-}
-
-#[derive(Default)]
-struct FundSettlementReport {
-    json_loaded_settlements: HashSet<SettlementRecord>,
-    // settlements and amount funded for settlement
-    funded_settlements: HashMap<Pubkey, (SettlementRecord, u64)>,
-    already_funded_settlements: HashMap<Pubkey, (SettlementRecord, u64)>,
-    not_funded_by_validator_bond_count: u64,
-    pending_init_count: u64,
-}
-
-impl FundSettlementReport {
-    fn funded_amount(&self) -> u64 {
-        self.funded_settlements
-            .values()
-            .map(|(_, amount)| *amount)
-            .sum()
-    }
-
-    fn already_funded_amount(&self) -> u64 {
-        self.already_funded_settlements
-            .values()
-            .map(|(_, amount)| *amount)
-            .sum()
-    }
-}
-
-impl FundSettlementsReport {
-    fn report_handler() -> ReportHandler<Self> {
-        let fund_settlement_report = Self::default();
-        ReportHandler::new(fund_settlement_report)
-    }
-
-    async fn init(
-        &mut self,
-        json_loaded_data: &HashMap<u64, Vec<SettlementRecord>>,
-        institutional_url: Option<String>,
-    ) {
-        for (epoch, record) in json_loaded_data {
-            self.settlements_per_epoch.insert(
-                *epoch,
-                FundSettlementReport {
-                    json_loaded_settlements: record.iter().cloned().collect(),
-                    ..Default::default()
-                },
-            );
-        }
-        if let Some(institutional_url) = institutional_url {
-            self.institutional_validators = Some(fetch_validator_data(&institutional_url).await);
-        }
-    }
-
-    fn add_funded_settlement(&mut self, record: &SettlementRecord, funded_amount: u64) {
-        let report = self.mut_ref(record.epoch);
-        report
-            .funded_settlements
-            .entry(record.settlement_address)
-            .and_modify(|(_, amount)| *amount += funded_amount)
-            .or_insert_with(|| (record.clone(), funded_amount));
-    }
-
-    fn add_already_fully_funded_settlement(&mut self, record: &SettlementRecord) {
-        self.add_already_funded_settlement(record, record.max_total_claim_sum);
-    }
-
-    fn add_already_funded_settlement(&mut self, record: &SettlementRecord, funded_amount: u64) {
-        let report = self.mut_ref(record.epoch);
-        report
-            .already_funded_settlements
-            .entry(record.settlement_address)
-            .and_modify(|(_, amount)| *amount += funded_amount)
-            .or_insert_with(|| (record.clone(), funded_amount));
-    }
-
-    fn mut_ref(&mut self, epoch: u64) -> &mut FundSettlementReport {
-        self.settlements_per_epoch.entry(epoch).or_default()
-    }
-
-    fn add_reason_specific_report(
-        report: &mut Vec<String>,
-        reason: ReportingReasonSettlement,
-        funded_data: &FundSettlementReport,
-    ) {
-        let json_loaded = SettlementsReportData::calculate_for_reason(
-            &reason,
-            &funded_data.json_loaded_settlements,
-        );
-
-        let funded = SettlementsReportData::calculate_sum_amount_for_reason(
-            &reason,
-            &funded_data.funded_settlements,
-        );
-
-        let already_funded = SettlementsReportData::calculate_sum_amount_for_reason(
-            &reason,
-            &funded_data.already_funded_settlements,
-        );
-
-        if json_loaded.settlements_count > 0 {
-            report.push(format!(
-                "  - {}: funded {}/{} settlements with {}/{} SOLs (before this already funded {}/{} settlements with {}/{} SOLs)",
-                reason,
-                funded.0.settlements_count,
-                json_loaded.settlements_count,
-                build_balance_message(funded.1, false, false),
-                build_balance_message(json_loaded.settlements_max_claim_sum, false, false),
-                already_funded.0.settlements_count,
-                json_loaded.settlements_count,
-                build_balance_message(already_funded.1, false, false),
-                build_balance_message(json_loaded.settlements_max_claim_sum, false, false),
-            ));
-        }
-    }
-}
-
-#[derive(Debug, Clone, Serialize)]
-struct FundSettlementJsonSummary {
-    epochs: Vec<EpochFundingSummary>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-struct EpochFundingSummary {
-    epoch: u64,
-    funded_settlements: u64,
-    total_settlements: u64,
-    funded_amount_sol: f64,
-    total_amount_sol: f64,
-    settlements_pending_init: u64,
-    reasons: Vec<ReasonFundingSummary>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-struct ReasonFundingSummary {
-    reason: String,
-    funded_settlements: u64,
-    total_settlements: u64,
-    funded_amount_sol: f64,
-    total_amount_sol: f64,
-}
-
-impl PrintReportable for FundSettlementsReport {
-    fn get_report(&self) -> Pin<Box<dyn Future<Output = Vec<String>> + '_>> {
-        Box::pin(async {
-            let mut report = vec![];
-            let mut sorted_by_epoch = self
-                .settlements_per_epoch
-                .iter()
-                .collect::<Vec<(&u64, &FundSettlementReport)>>();
-            sorted_by_epoch.sort_by_key(|(a, _)| *a);
-            for (epoch, funded_data) in sorted_by_epoch {
-                let json_loaded = SettlementsReportData::calculate(
-                    &funded_data
-                        .json_loaded_settlements
-                        .iter()
-                        .collect::<Vec<&SettlementRecord>>(),
-                );
-                let already_funded_count = funded_data
-                    .already_funded_settlements
-                    .iter()
-                    .filter(|(_, (_, amount))| *amount > 0)
-                    .count();
-                report.push(format!(
-                    "Epoch {} funded {}/{} settlements with {}/{} SOLs (before this already funded {}/{} settlements with {}/{} SOLs)",
-                    epoch,
-                    funded_data.funded_settlements.len(),
-                    json_loaded.settlements_count,
-                    build_balance_message(funded_data.funded_amount(), false, false),
-                    build_balance_message(json_loaded.settlements_max_claim_sum, false, false),
-                    already_funded_count,
-                    json_loaded.settlements_count,
-                    build_balance_message(funded_data.already_funded_amount(), false, false),
-                    build_balance_message(json_loaded.settlements_max_claim_sum, false, false),
-                ));
-                for reason in ReportingReasonSettlement::items() {
-                    Self::add_reason_specific_report(&mut report, reason, funded_data);
-                }
-                if funded_data.not_funded_by_validator_bond_count > 0 {
-                    report.push(format!(
-                        "    Number of Settlements not funded because of non-existing Bond: {}",
-                        funded_data.not_funded_by_validator_bond_count
-                    ));
-                }
-                if funded_data.pending_init_count > 0 {
-                    report.push(format!(
-                        "    Number of Settlements not funded because not yet created on-chain (init-settlement pending): {}",
-                        funded_data.pending_init_count
-                    ));
-                }
-            }
-            report
-        })
-    }
-
-    fn transform_on_finalize(&self, entries: &mut Vec<ErrorEntry>) {
-        if let Some(institutional_validators) = &self.institutional_validators {
-            entries.iter_mut().for_each(|entry| {
-                match entry {
-                    Generic(_) => {
-                        // nothing
-                    }
-                    VoteAccount(vae) => {
-                        if institutional_validators
-                            .validators
-                            .iter()
-                            .all(|v| v.vote_pubkey != vae.vote_account)
-                        {
-                            vae.base.severity = ErrorSeverity::Info;
-                            vae.base.message =
-                                format!("(non-institutional validator) {}", vae.base.message);
-                        }
-                    }
-                }
-            });
-        }
-    }
-}
-
-impl ReportSerializable for FundSettlementsReport {
-    fn command_name(&self) -> &'static str {
-        "fund-settlement"
-    }
-
-    fn get_json_summary(&self) -> Pin<Box<dyn Future<Output = serde_json::Value> + '_>> {
-        Box::pin(async {
-            let lamports_to_sol = |lamports: u64| -> f64 { lamports as f64 / 1_000_000_000.0 };
-
-            let mut sorted_by_epoch = self
-                .settlements_per_epoch
-                .iter()
-                .collect::<Vec<(&u64, &FundSettlementReport)>>();
-            sorted_by_epoch.sort_by_key(|(a, _)| *a);
-
-            let epochs: Vec<EpochFundingSummary> = sorted_by_epoch
-                .iter()
-                .map(|(epoch, funded_data)| {
-                    let json_loaded = SettlementsReportData::calculate(
-                        &funded_data
-                            .json_loaded_settlements
-                            .iter()
-                            .collect::<Vec<&SettlementRecord>>(),
-                    );
-
-                    // Calculate totals including already funded
-                    let funded_count = funded_data.funded_settlements.len() as u64
-                        + funded_data
-                            .already_funded_settlements
-                            .iter()
-                            .filter(|(_, (_, amount))| *amount > 0)
-                            .count() as u64;
-                    let funded_amount =
-                        funded_data.funded_amount() + funded_data.already_funded_amount();
-
-                    // Build per-reason breakdown
-                    let reasons: Vec<ReasonFundingSummary> = ReportingReasonSettlement::items()
-                        .into_iter()
-                        .filter_map(|reason| {
-                            let json_loaded_for_reason =
-                                SettlementsReportData::calculate_for_reason(
-                                    &reason,
-                                    &funded_data.json_loaded_settlements,
-                                );
-
-                            // Skip reasons with no settlements
-                            if json_loaded_for_reason.settlements_count == 0 {
-                                return None;
-                            }
-
-                            let funded = SettlementsReportData::calculate_sum_amount_for_reason(
-                                &reason,
-                                &funded_data.funded_settlements,
-                            );
-                            let already_funded =
-                                SettlementsReportData::calculate_sum_amount_for_reason(
-                                    &reason,
-                                    &funded_data.already_funded_settlements,
-                                );
-
-                            let total_funded =
-                                funded.0.settlements_count + already_funded.0.settlements_count;
-                            let total_funded_amount = funded.1 + already_funded.1;
-
-                            Some(ReasonFundingSummary {
-                                reason: reason.to_string(),
-                                funded_settlements: total_funded,
-                                total_settlements: json_loaded_for_reason.settlements_count,
-                                funded_amount_sol: lamports_to_sol(total_funded_amount),
-                                total_amount_sol: lamports_to_sol(
-                                    json_loaded_for_reason.settlements_max_claim_sum,
-                                ),
-                            })
-                        })
-                        .collect();
-
-                    EpochFundingSummary {
-                        epoch: **epoch,
-                        funded_settlements: funded_count,
-                        total_settlements: json_loaded.settlements_count,
-                        funded_amount_sol: lamports_to_sol(funded_amount),
-                        total_amount_sol: lamports_to_sol(json_loaded.settlements_max_claim_sum),
-                        settlements_pending_init: funded_data.pending_init_count,
-                        reasons,
-                    }
-                })
-                .collect();
-
-            let summary = FundSettlementJsonSummary { epochs };
-
-            serde_json::to_value(summary)
-                .unwrap_or_else(|e| serde_json::json!({"error": e.to_string()}))
-        })
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use solana_sdk::stake::stake_flags::StakeFlags;
-    use solana_sdk::stake::state::{Delegation, Meta, Stake};
-    use validator_bonds::state::settlement::Settlement;
-
-    const SOL: u64 = 1_000_000_000;
-    const MIN: u64 = SOL + STAKE_ACCOUNT_RENT_EXEMPTION;
-
-    fn on_chain_settlement() -> Settlement {
-        Settlement {
-            bond: Pubkey::default(),
-            staker_authority: Pubkey::default(),
-            merkle_root: [0u8; 32],
-            max_total_claim: SOL,
-            max_merkle_nodes: 1,
-            lamports_funded: 0,
-            lamports_claimed: 0,
-            merkle_nodes_claimed: 0,
-            epoch_created_for: 0,
-            slot_created_at: 0,
-            rent_collector: Pubkey::default(),
-            split_rent_collector: None,
-            split_rent_amount: 0,
-            bumps: Default::default(),
-            reserved: [0u8; 90],
-        }
-    }
-
-    fn settlement_record(epoch: u64, exists_on_chain: bool) -> SettlementRecord {
-        SettlementRecord {
-            epoch,
-            vote_account_address: Pubkey::new_unique(),
-            bond_address: Pubkey::new_unique(),
-            bond_account: None,
-            settlement_address: Pubkey::new_unique(),
-            settlement_account: exists_on_chain.then(on_chain_settlement),
-            settlement_staker_authority: Pubkey::new_unique(),
-            merkle_root: [0u8; 32],
-            tree_nodes: vec![],
-            max_total_claim_sum: SOL,
-            max_total_claim: 1,
-            funder: SettlementFunderType::ValidatorBond(vec![]),
-            reason: None,
-            funding_sources: HashMap::new(),
-        }
-    }
-
-    #[test]
-    fn epoch_with_no_settlement_on_chain_is_pending_init() {
-        let records = HashMap::from([
-            (1009, vec![settlement_record(1009, false); 2]),
-            (1008, vec![settlement_record(1008, true)]),
-        ]);
-        assert_eq!(
-            epochs_pending_on_chain_init(&records),
-            HashSet::from([1009])
-        );
-    }
-
-    #[test]
-    fn partially_initialized_epoch_is_not_pending_init() {
-        let records = HashMap::from([(
-            1009,
-            vec![
-                settlement_record(1009, true),
-                settlement_record(1009, false),
-            ],
-        )]);
-        assert!(epochs_pending_on_chain_init(&records).is_empty());
-    }
-
-    fn stake_account(lamports: u64, delegation_stake: u64) -> FundBondStakeAccount {
-        FundBondStakeAccount {
-            lamports,
-            stake_account: Pubkey::new_unique(),
-            split_stake_account: Arc::new(Keypair::new()),
-            state: StakeStateV2::Stake(
-                Meta::default(),
-                Stake {
-                    delegation: Delegation {
-                        stake: delegation_stake,
-                        ..Delegation::default()
-                    },
-                    ..Stake::default()
-                },
-                StakeFlags::empty(),
-            ),
-        }
-    }
-
-    #[test]
-    fn underwater_account_skipped_for_splitting_settlement_stays_available_for_a_bigger_one() {
-        let underwater = stake_account(10 * SOL, 20 * SOL);
-        let healthy = stake_account(6 * SOL, 6 * SOL);
-        let (underwater_address, healthy_address) =
-            (underwater.stake_account, healthy.stake_account);
-        let mut pool = vec![underwater, healthy];
-
-        // 10 SOL available >= 3 + MIN + rent needed -> the program would split and underflow
-        let amount_needed_split = 3 * SOL;
-        let underwater_accounts = underwater_split_stake_accounts(&pool, amount_needed_split, MIN);
-        assert_eq!(underwater_accounts.len(), 1);
-        assert_eq!(underwater_accounts[0].stake_account, underwater_address);
-        assert_eq!(underwater_accounts[0].lamports, 10 * SOL);
-        assert_eq!(underwater_accounts[0].delegation_stake, 20 * SOL);
-
-        let to_fund =
-            take_stake_accounts_to_fund(&mut pool, &underwater_accounts, amount_needed_split);
-        assert_eq!(
-            to_fund.iter().map(|s| s.stake_account).collect::<Vec<_>>(),
-            vec![healthy_address]
-        );
-        assert_eq!(
-            pool.iter().map(|s| s.stake_account).collect::<Vec<_>>(),
-            vec![underwater_address]
-        );
-
-        // 10 SOL available < 10 + MIN + rent needed -> whole-account path, no underflow to avoid
-        let amount_needed_no_split = 10 * SOL;
-        let underwater_accounts =
-            underwater_split_stake_accounts(&pool, amount_needed_no_split, MIN);
-        assert!(underwater_accounts.is_empty());
-
-        let to_fund =
-            take_stake_accounts_to_fund(&mut pool, &underwater_accounts, amount_needed_no_split);
-        assert_eq!(
-            to_fund.iter().map(|s| s.stake_account).collect::<Vec<_>>(),
-            vec![underwater_address]
-        );
-        assert!(pool.is_empty());
-    }
-
-    #[test]
-    fn underwater_account_is_never_funded_even_as_the_only_candidate() {
-        let underwater = stake_account(10 * SOL, 20 * SOL);
-        let underwater_address = underwater.stake_account;
-        let mut pool = vec![underwater];
-
-        let amount_needed = 3 * SOL;
-        let underwater_accounts = underwater_split_stake_accounts(&pool, amount_needed, MIN);
-        assert_eq!(underwater_accounts.len(), 1);
-
-        let to_fund = take_stake_accounts_to_fund(&mut pool, &underwater_accounts, amount_needed);
-        assert!(to_fund.is_empty());
-        assert_eq!(
-            pool.iter().map(|s| s.stake_account).collect::<Vec<_>>(),
-            vec![underwater_address]
-        );
-    }
+    fund_settlement(reporting, &mut context, &args.fund_settlement_opts).await
 }
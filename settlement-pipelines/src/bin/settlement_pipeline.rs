@@ -0,0 +1,472 @@
+use anyhow::anyhow;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use log::info;
+use serde::Serialize;
+use settlement_pipelines::arguments::{
+    load_keypair, load_pubkey, GlobalOpts, PriorityFeePolicyOpts, ReportOpts, TipPolicyOpts,
+};
+use settlement_pipelines::init::init_log;
+use settlement_pipelines::pipeline_context::PipelineContext;
+use settlement_pipelines::reporting::{
+    with_reporting_ext, ErrorReportEntry, PrintReportable, ReportHandler, ReportSerializable,
+    ReportStatus,
+};
+use settlement_pipelines::stages::claim_settlement::{
+    claim_settlements, ClaimSettlementOpts, ClaimSettlementsReport,
+};
+use settlement_pipelines::stages::close_settlement::{
+    close_settlement, CloseSettlementOpts, CloseSettlementReport,
+};
+use settlement_pipelines::stages::fund_settlement::{
+    fund_settlement, FundSettlementOpts, FundSettlementsReport,
+};
+use settlement_pipelines::stages::init_settlement::{
+    init_settlement, InitSettlementOpts, InitSettlementReport,
+};
+use settlement_pipelines::stages::list_settlement::{
+    list_settlement, ListSettlementOpts, ListSettlementReport,
+};
+use settlement_pipelines::stages::merge_stakes::{merge_stakes, MergeConfigReport};
+use settlement_pipelines::stages::verify_settlement::{verify_settlement, VerifySettlementReport};
+use solana_sdk::signer::Signer;
+use std::fmt::{self, Display};
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use validator_bonds_common::cli_result::{CliError, CliResult};
+
+/// Settlement pipeline running the stages in one process,
+/// the stages share the loaded merkle tree collections, the fetched on-chain data
+/// that the pipeline transactions do not change and the transaction executor.
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Runs the stages from '--from-stage' to '--to-stage' in order,
+    /// a stage that fails to finish skips the following stages.
+    Run(RunArgs),
+}
+
+#[derive(Args, Debug)]
+struct RunArgs {
+    #[clap(flatten)]
+    global_opts: GlobalOpts,
+
+    /// Merkle tree collection JSON files.
+    /// Each file contains a self-contained MerkleTreeCollection with all necessary data.
+    #[arg(required = true, short = 'f', long, value_delimiter = ' ', num_args(1..))]
+    json_files: Vec<PathBuf>,
+
+    /// forcing epoch, overriding from the settlement collection
+    #[arg(long)]
+    epoch: Option<u64>,
+
+    /// Marinade wallet keypair that pays for Marinade type Settlements at the fund stage
+    /// (if not provided, fee payer keypair is used) and where the not claimed Marinade funded
+    /// Settlements are returned to at the close stage (required for the close stage)
+    #[clap(long)]
+    marinade_wallet: Option<String>,
+
+    /// keypair payer for rent of accounts, if not provided, fee payer keypair is used
+    #[arg(long)]
+    rent_payer: Option<String>,
+
+    /// Keypair of the liquidator funding the settlements at the fund stage with its own stake accounts
+    /// in exchange for the bond token collateral, when not provided the token collateral is not used
+    #[arg(long)]
+    liquidator: Option<String>,
+
+    /// File where the list stage writes the listed settlements to,
+    /// the close and verify stages load it when the list stage is not run
+    #[clap(long, short = 'p')]
+    listed_settlements: PathBuf,
+
+    /// First stage to run
+    #[arg(long, value_enum, default_value_t = Stage::List)]
+    from_stage: Stage,
+
+    /// Last stage to run
+    #[arg(long, value_enum, default_value_t = Stage::Verify)]
+    to_stage: Stage,
+
+    #[clap(flatten)]
+    priority_fee_policy_opts: PriorityFeePolicyOpts,
+
+    #[clap(flatten)]
+    tip_policy_opts: TipPolicyOpts,
+
+    #[clap(flatten)]
+    report_opts: ReportOpts,
+}
+
+/// Stages in the order of the run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Stage {
+    List,
+    Init,
+    Fund,
+    Merge,
+    Claim,
+    Close,
+    Verify,
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Stage::List => "list",
+            Stage::Init => "init",
+            Stage::Fund => "fund",
+            Stage::Merge => "merge",
+            Stage::Claim => "claim",
+            Stage::Close => "close",
+            Stage::Verify => "verify",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[tokio::main]
+async fn main() -> CliResult {
+    let cli: Cli = Cli::parse();
+    match cli.command {
+        Command::Run(args) => {
+            let mut reporting = SettlementPipelineReport::report_handler();
+            let result = real_main(&mut reporting, &args).await;
+            with_reporting_ext::<SettlementPipelineReport>(
+                &mut reporting,
+                result,
+                &args.report_opts,
+            )
+            .await
+        }
+    }
+}
+
+async fn real_main(
+    reporting: &mut ReportHandler<SettlementPipelineReport>,
+    args: &RunArgs,
+) -> anyhow::Result<()> {
+    init_log(&args.global_opts);
+
+    let stages = Stage::value_variants()
+        .iter()
+        .filter(|stage| args.from_stage <= **stage && **stage <= args.to_stage)
+        .copied()
+        .collect::<Vec<Stage>>();
+    if stages.is_empty() {
+        return Err(CliError::critical(anyhow!(
+            "--from-stage {} is after --to-stage {}",
+            args.from_stage,
+            args.to_stage
+        ))
+        .into());
+    }
+    // stages that are not able to resolve the config from the merkle tree collections
+    if let Some(stage) = stages
+        .iter()
+        .find(|stage| matches!(stage, Stage::Merge | Stage::Close | Stage::Verify))
+    {
+        if args.global_opts.config.is_none() {
+            return Err(
+                CliError::critical(anyhow!("--config is required for stage {stage}")).into(),
+            );
+        }
+    }
+    let close_marinade_wallet = if stages.contains(&Stage::Close) {
+        let marinade_wallet = args.marinade_wallet.as_ref().ok_or_else(|| {
+            CliError::critical(anyhow!("--marinade-wallet is required for stage close"))
+        })?;
+        // fund stage requires the keypair, close stage is fine with the pubkey
+        let marinade_wallet = match load_pubkey(marinade_wallet) {
+            Ok(pubkey) => pubkey,
+            Err(_) => load_keypair("--marinade-wallet", marinade_wallet)?.pubkey(),
+        };
+        Some(marinade_wallet.to_string())
+    } else {
+        None
+    };
+
+    let mut context = PipelineContext::new(
+        &args.global_opts,
+        &args.priority_fee_policy_opts,
+        &args.tip_policy_opts,
+    )
+    .await?;
+    reporting.set_executor(context.transaction_executor.clone());
+
+    let mut finished = true;
+    for stage in Stage::value_variants() {
+        if !finished || !stages.contains(stage) {
+            reporting.reportable.skip(*stage);
+            continue;
+        }
+        info!("Running settlement pipeline stage '{stage}'");
+        finished = match stage {
+            Stage::List => {
+                let mut stage_reporting = ListSettlementReport::report_handler();
+                let opts = ListSettlementOpts {
+                    json_files: args.json_files.clone(),
+                    listed_settlements: args.listed_settlements.clone(),
+                };
+                let result = list_settlement(&mut stage_reporting, &mut context, &opts);
+                add_stage_report(reporting, *stage, None, stage_reporting, result).await
+            }
+            Stage::Init => {
+                // init stage works with a single merkle tree collection
+                let mut finished = true;
+                for json_file in args.json_files.iter() {
+                    let mut stage_reporting = InitSettlementReport::report_handler();
+                    let opts = InitSettlementOpts {
+                        input_merkle_tree_collection: json_file.clone(),
+                        epoch: args.epoch,
+                        rent_payer: args.rent_payer.clone(),
+                    };
+                    let result = init_settlement(&mut stage_reporting, &mut context, &opts).await;
+                    finished = add_stage_report(
+                        reporting,
+                        *stage,
+                        Some(json_file.clone()),
+                        stage_reporting,
+                        result,
+                    )
+                    .await;
+                    if !finished {
+                        break;
+                    }
+                }
+                finished
+            }
+            Stage::Fund => {
+                let mut stage_reporting = FundSettlementsReport::report_handler();
+                let opts = FundSettlementOpts {
+                    json_files: args.json_files.clone(),
+                    epoch: args.epoch,
+                    marinade_wallet: args.marinade_wallet.clone(),
+                    rent_payer: args.rent_payer.clone(),
+                    liquidator: args.liquidator.clone(),
+                };
+                let result = fund_settlement(&mut stage_reporting, &mut context, &opts).await;
+                add_stage_report(reporting, *stage, None, stage_reporting, result).await
+            }
+            Stage::Merge => {
+                let mut stage_reporting = MergeConfigReport::report_handler();
+                let result = merge_stakes(&mut stage_reporting, &mut context).await;
+                add_stage_report(reporting, *stage, None, stage_reporting, result).await
+            }
+            Stage::Claim => {
+                let mut stage_reporting = ClaimSettlementsReport::report_handler();
+                let opts = ClaimSettlementOpts {
+                    json_files: args.json_files.clone(),
+                    epoch: args.epoch,
+                };
+                let result = claim_settlements(&mut stage_reporting, &mut context, &opts).await;
+                add_stage_report(reporting, *stage, None, stage_reporting, result).await
+            }
+            Stage::Close => {
+                let mut stage_reporting = CloseSettlementReport::report_handler();
+                let opts = CloseSettlementOpts {
+                    marinade_wallet: close_marinade_wallet
+                        .clone()
+                        .expect("marinade wallet resolved for the close stage"),
+                    listed_settlements: args.listed_settlements.clone(),
+                };
+                let result = close_settlement(&mut stage_reporting, &mut context, &opts).await;
+                add_stage_report(reporting, *stage, None, stage_reporting, result).await
+            }
+            Stage::Verify => {
+                let mut stage_reporting = VerifySettlementReport::report_handler();
+                let result = verify_stage(&mut stage_reporting, &mut context, args).await;
+                add_stage_report(reporting, *stage, None, stage_reporting, result).await
+            }
+        };
+    }
+
+    Ok(())
+}
+
+async fn verify_stage(
+    reporting: &mut ReportHandler<VerifySettlementReport>,
+    context: &mut PipelineContext,
+    args: &RunArgs,
+) -> anyhow::Result<()> {
+    let config_address = context.config_override.expect("--config is required");
+    let config = context
+        .config(&config_address)
+        .await
+        .map_err(CliError::retry_able)?;
+    let listed_settlements = context
+        .listed_settlements(&args.listed_settlements)
+        .map_err(CliError::critical)?;
+    verify_settlement(
+        reporting,
+        context.rpc_client.clone(),
+        &config_address,
+        &config,
+        &listed_settlements,
+    )
+    .await
+}
+
+/// Adds the stage report to the consolidated report,
+/// returns false when the stage failed to finish and the following stages are skipped.
+async fn add_stage_report<T: ReportSerializable>(
+    reporting: &mut ReportHandler<SettlementPipelineReport>,
+    stage: Stage,
+    input: Option<PathBuf>,
+    mut stage_reporting: ReportHandler<T>,
+    result: anyhow::Result<()>,
+) -> bool {
+    stage_reporting
+        .reportable
+        .transform_on_finalize(&mut stage_reporting.error_handler.entries);
+    let finished = match result {
+        Ok(_) => true,
+        Err(err) => {
+            match err.downcast::<CliError>() {
+                Ok(cli_error) => stage_reporting.add_cli_error(cli_error),
+                Err(err) => stage_reporting.error().with_err(err).add(),
+            }
+            false
+        }
+    };
+
+    let report_status = stage_reporting.error_handler.get_status();
+    let status = if !finished {
+        StageStatus::Aborted
+    } else if report_status.success {
+        StageStatus::Succeeded
+    } else {
+        StageStatus::Failed
+    };
+    reporting.reportable.stages.push(StageReport {
+        stage,
+        input,
+        status,
+        command: stage_reporting.reportable.command_name().to_string(),
+        report_status: Some(report_status),
+        summary: stage_reporting.reportable.get_json_summary().await,
+        errors: stage_reporting.error_handler.get_errors_as_json(),
+        warnings: stage_reporting.error_handler.get_warnings_as_json(),
+        text: stage_reporting.reportable.get_report().await,
+    });
+    reporting
+        .error_handler
+        .entries
+        .extend(stage_reporting.error_handler.entries);
+    finished
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum StageStatus {
+    /// finished without errors
+    Succeeded,
+    /// finished with errors reported
+    Failed,
+    /// not finished, the following stages are skipped
+    Aborted,
+    /// not run
+    Skipped,
+}
+
+impl Display for StageStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            StageStatus::Succeeded => "succeeded",
+            StageStatus::Failed => "failed",
+            StageStatus::Aborted => "aborted",
+            StageStatus::Skipped => "skipped",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct StageReport {
+    stage: Stage,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input: Option<PathBuf>,
+    status: StageStatus,
+    command: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    report_status: Option<ReportStatus>,
+    summary: serde_json::Value,
+    errors: Vec<ErrorReportEntry>,
+    warnings: Vec<ErrorReportEntry>,
+    #[serde(skip)]
+    text: Vec<String>,
+}
+
+struct SettlementPipelineReport {
+    stages: Vec<StageReport>,
+}
+
+impl SettlementPipelineReport {
+    fn report_handler() -> ReportHandler<Self> {
+        ReportHandler::new(Self { stages: vec![] })
+    }
+
+    fn skip(&mut self, stage: Stage) {
+        self.stages.push(StageReport {
+            stage,
+            input: None,
+            status: StageStatus::Skipped,
+            command: String::new(),
+            report_status: None,
+            summary: serde_json::Value::Null,
+            errors: vec![],
+            warnings: vec![],
+            text: vec![],
+        });
+    }
+}
+
+impl PrintReportable for SettlementPipelineReport {
+    fn get_report(&self) -> Pin<Box<dyn Future<Output = Vec<String>> + '_>> {
+        Box::pin(async {
+            let mut report = vec![];
+            for stage_report in self.stages.iter() {
+                let input = stage_report
+                    .input
+                    .as_ref()
+                    .map_or_else(String::new, |input| format!(" ({input:?})"));
+                report.push(format!(
+                    "Stage {}{}: {}",
+                    stage_report.stage, input, stage_report.status
+                ));
+                for line in stage_report.text.iter() {
+                    report.push(format!("  {line}"));
+                }
+            }
+            report
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct SettlementPipelineJsonSummary<'a> {
+    stages: &'a [StageReport],
+}
+
+impl ReportSerializable for SettlementPipelineReport {
+    fn command_name(&self) -> &'static str {
+        "settlement-pipeline"
+    }
+
+    fn get_json_summary(&self) -> Pin<Box<dyn Future<Output = serde_json::Value> + '_>> {
+        Box::pin(async {
+            let summary = SettlementPipelineJsonSummary {
+                stages: &self.stages,
+            };
+            serde_json::to_value(summary)
+                .unwrap_or_else(|e| serde_json::json!({"error": e.to_string()}))
+        })
+    }
+}
@@ -0,0 +1,177 @@
+use clap::Parser;
+use log::info;
+use serde::Serialize;
+use settlement_common::utils::read_from_json_file;
+use settlement_pipelines::arguments::{get_rpc_client, GlobalOpts, ReportOpts};
+use settlement_pipelines::executor::execute_signed_transactions;
+use settlement_pipelines::init::init_log;
+use settlement_pipelines::offline_signing::{
+    apply_offline_signatures, signatures_per_transaction, OfflineSignature,
+    UnsignedTransactionsExport,
+};
+use settlement_pipelines::reporting::{
+    with_reporting_ext, PrintReportable, ReportHandler, ReportSerializable,
+};
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use validator_bonds_common::cli_result::{CliError, CliResult};
+
+/// Submits the transactions exported by a pipeline run with '--export-unsigned-transactions'
+/// after the missing signatures were collected offline.
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[clap(flatten)]
+    global_opts: GlobalOpts,
+
+    /// File with the transactions exported by a pipeline run with '--export-unsigned-transactions'
+    #[arg(long)]
+    transactions: PathBuf,
+
+    /// JSON files with the list of offline signatures, items of format
+    /// {"tx_uuid": "<uuid>", "signer": "<pubkey>", "signature": "<base58 signature>"}
+    #[arg(long, value_delimiter = ' ', num_args(1..))]
+    signatures: Vec<PathBuf>,
+
+    #[clap(flatten)]
+    report_opts: ReportOpts,
+}
+
+#[tokio::main]
+async fn main() -> CliResult {
+    let args: Args = Args::parse();
+    let mut reporting = SubmitSignedTransactionsReport::report_handler();
+    let result = real_main(&mut reporting, &args).await;
+    with_reporting_ext::<SubmitSignedTransactionsReport>(&mut reporting, result, &args.report_opts)
+        .await
+}
+
+async fn real_main(
+    reporting: &mut ReportHandler<SubmitSignedTransactionsReport>,
+    args: &Args,
+) -> anyhow::Result<()> {
+    init_log(&args.global_opts);
+
+    let (rpc_client, _) = get_rpc_client(&args.global_opts)?;
+
+    let export: UnsignedTransactionsExport =
+        read_from_json_file(&args.transactions).map_err(CliError::critical)?;
+    let mut signatures: Vec<OfflineSignature> = vec![];
+    for signatures_file in args.signatures.iter() {
+        let file_signatures: Vec<OfflineSignature> =
+            read_from_json_file(signatures_file).map_err(CliError::critical)?;
+        signatures.extend(file_signatures);
+    }
+    let signatures = signatures_per_transaction(signatures);
+
+    // all transactions have to be fully signed before anything is submitted
+    let mut signed_batches = Vec::with_capacity(export.batches.len());
+    for batch in export.batches.iter() {
+        let mut transactions = Vec::with_capacity(batch.transactions.len());
+        for unsigned in batch.transactions.iter() {
+            let transaction_signatures = signatures
+                .get(&unsigned.tx_uuid)
+                .map_or(&[][..], |s| s.as_slice());
+            let transaction = apply_offline_signatures(unsigned, transaction_signatures)
+                .map_err(CliError::critical)?;
+            transactions.push((unsigned.tx_uuid.clone(), transaction));
+        }
+        signed_batches.push((batch.parallel, transactions));
+    }
+    reporting.reportable.batches = signed_batches.len();
+
+    for (batch_index, (parallel, transactions)) in signed_batches.into_iter().enumerate() {
+        info!(
+            "Submitting batch {}/{} of {} transactions",
+            batch_index + 1,
+            reporting.reportable.batches,
+            transactions.len()
+        );
+        let results = execute_signed_transactions(rpc_client.clone(), transactions, parallel).await;
+        let mut batch_failed = false;
+        for (tx_uuid, result) in results {
+            match result {
+                Ok(signature) => {
+                    info!("Transaction {tx_uuid} executed: {signature}");
+                    reporting.reportable.executed_transactions += 1;
+                }
+                Err(e) => {
+                    batch_failed = true;
+                    reporting.reportable.failed_transactions += 1;
+                    reporting.error().with_err(e).add();
+                }
+            }
+        }
+        if batch_failed {
+            // later batches were planned on the state expected after this batch
+            let remaining_batches = reporting.reportable.batches - batch_index - 1;
+            reporting
+                .error()
+                .with_msg(format!(
+                    "Batch {} failed, the remaining {remaining_batches} batches were not submitted",
+                    batch_index + 1,
+                ))
+                .add();
+            break;
+        }
+        reporting.reportable.executed_batches += 1;
+    }
+
+    Ok(())
+}
+
+#[derive(Default)]
+struct SubmitSignedTransactionsReport {
+    batches: usize,
+    executed_batches: usize,
+    executed_transactions: usize,
+    failed_transactions: usize,
+}
+
+impl SubmitSignedTransactionsReport {
+    fn report_handler() -> ReportHandler<Self> {
+        ReportHandler::new(Self::default())
+    }
+}
+
+impl PrintReportable for SubmitSignedTransactionsReport {
+    fn get_report(&self) -> Pin<Box<dyn Future<Output = Vec<String>> + '_>> {
+        Box::pin(async {
+            vec![format!(
+                "Submitted {}/{} batches, transactions executed: {}, failed: {}",
+                self.executed_batches,
+                self.batches,
+                self.executed_transactions,
+                self.failed_transactions
+            )]
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SubmitSignedTransactionsJsonSummary {
+    batches: u64,
+    executed_batches: u64,
+    executed_transactions: u64,
+    failed_transactions: u64,
+}
+
+impl ReportSerializable for SubmitSignedTransactionsReport {
+    fn command_name(&self) -> &'static str {
+        "submit-signed-transactions"
+    }
+
+    fn get_json_summary(&self) -> Pin<Box<dyn Future<Output = serde_json::Value> + '_>> {
+        Box::pin(async {
+            let summary = SubmitSignedTransactionsJsonSummary {
+                batches: self.batches as u64,
+                executed_batches: self.executed_batches as u64,
+                executed_transactions: self.executed_transactions as u64,
+                failed_transactions: self.failed_transactions as u64,
+            };
+            serde_json::to_value(summary)
+                .unwrap_or_else(|e| serde_json::json!({"error": e.to_string()}))
+        })
+    }
+}
@@ -0,0 +1,390 @@
+use crate::journal::{journal_instructions, JournalInstruction};
+use anchor_client::anchor_lang::Discriminator;
+use anyhow::anyhow;
+use futures::future::join_all;
+use serde::Serialize;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::{
+    RpcSimulateTransactionAccountsConfig, RpcSimulateTransactionConfig,
+};
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::Transaction;
+use solana_transaction_builder_executor::TransactionBuilderExecutionData;
+use std::collections::{BTreeMap, HashSet};
+use std::sync::{Arc, Mutex};
+use validator_bonds::state::settlement::Settlement;
+
+/// Maximal number of accounts fetched by one getMultipleAccounts call
+const MULTIPLE_ACCOUNTS_CHUNK: usize = 100;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedInstruction {
+    #[serde(flatten)]
+    pub instruction: JournalInstruction,
+    /// settlement account referenced by the instruction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settlement: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LamportChange {
+    pub account: String,
+    pub pre_lamports: u64,
+    pub post_lamports: u64,
+    pub change: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SimulatedTransaction {
+    pub tx_uuid: String,
+    pub instructions: Vec<PlannedInstruction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units_consumed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// writable accounts of the transaction with the lamports changed by the simulation
+    pub lamport_changes: Vec<LamportChange>,
+    #[serde(skip)]
+    settlement_lamport_changes: Vec<(Pubkey, LamportChange)>,
+    #[serde(skip)]
+    settlement_units: Vec<(Pubkey, u64)>,
+}
+
+/// Lamports moved and compute units consumed by the simulated transactions on behalf of a settlement.
+/// Accounts referenced by instructions of several settlements in one transaction (e.g., the fee payer)
+/// are not attributed to any settlement, the compute units are split by the instruction count.
+#[derive(Debug, Clone, Serialize)]
+pub struct SettlementMovements {
+    pub settlement: String,
+    pub instructions: u64,
+    pub units_consumed: u64,
+    pub lamport_changes: Vec<LamportChange>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DryRunSummary {
+    pub simulated_transactions: u64,
+    pub failed_simulations: u64,
+    pub units_consumed: u64,
+    pub settlements: Vec<SettlementMovements>,
+    pub transactions: Vec<SimulatedTransaction>,
+}
+
+/// Collector of the transactions simulated instead of being executed (`--dry-run`).
+/// Transactions are simulated against the current chain state without signature verification,
+/// the transactions depending on a not executed prior transaction are expected to fail the simulation.
+#[derive(Default)]
+pub struct DryRun {
+    simulations: Mutex<Vec<SimulatedTransaction>>,
+}
+
+impl DryRun {
+    pub async fn simulate(
+        &self,
+        rpc_client: Arc<RpcClient>,
+        execution_data: &[TransactionBuilderExecutionData],
+        parallel_execution_rate: Option<usize>,
+    ) -> anyhow::Result<()> {
+        let chunk_size = parallel_execution_rate.unwrap_or(1).max(1);
+        for chunk in execution_data.chunks(chunk_size) {
+            let simulations = join_all(chunk.iter().map(|data| {
+                simulate_transaction(
+                    rpc_client.clone(),
+                    data.tx_uuid.to_string(),
+                    &data.prepared_transaction.transaction,
+                )
+            }))
+            .await;
+            let mut collected = self
+                .simulations
+                .lock()
+                .map_err(|e| anyhow!("Dry run lock poisoned: {e}"))?;
+            for simulation in simulations {
+                collected.push(simulation?);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn summary(&self) -> DryRunSummary {
+        let transactions = self
+            .simulations
+            .lock()
+            .map(|simulations| simulations.clone())
+            .unwrap_or_default();
+        let mut settlements: BTreeMap<Pubkey, SettlementMovements> = BTreeMap::new();
+        for transaction in transactions.iter() {
+            for (settlement, units) in transaction.settlement_units.iter() {
+                let movements = settlement_movements(&mut settlements, settlement);
+                movements.units_consumed += units;
+            }
+            for instruction in transaction.instructions.iter() {
+                if let Some(settlement) = instruction.settlement.as_ref() {
+                    if let Ok(settlement) = settlement.parse::<Pubkey>() {
+                        settlement_movements(&mut settlements, &settlement).instructions += 1;
+                    }
+                }
+            }
+            for (settlement, change) in transaction.settlement_lamport_changes.iter() {
+                let movements = settlement_movements(&mut settlements, settlement);
+                match movements
+                    .lamport_changes
+                    .iter_mut()
+                    .find(|c| c.account == change.account)
+                {
+                    Some(existing) => {
+                        existing.post_lamports = change.post_lamports;
+                        existing.change += change.change;
+                    }
+                    None => movements.lamport_changes.push(change.clone()),
+                }
+            }
+        }
+        DryRunSummary {
+            simulated_transactions: transactions.len() as u64,
+            failed_simulations: transactions.iter().filter(|t| t.error.is_some()).count() as u64,
+            units_consumed: transactions.iter().filter_map(|t| t.units_consumed).sum(),
+            settlements: settlements.into_values().collect(),
+            transactions,
+        }
+    }
+}
+
+fn settlement_movements<'a>(
+    settlements: &'a mut BTreeMap<Pubkey, SettlementMovements>,
+    settlement: &Pubkey,
+) -> &'a mut SettlementMovements {
+    settlements
+        .entry(*settlement)
+        .or_insert_with(|| SettlementMovements {
+            settlement: settlement.to_string(),
+            instructions: 0,
+            units_consumed: 0,
+            lamport_changes: vec![],
+        })
+}
+
+async fn simulate_transaction(
+    rpc_client: Arc<RpcClient>,
+    tx_uuid: String,
+    transaction: &Transaction,
+) -> anyhow::Result<SimulatedTransaction> {
+    let message = &transaction.message;
+    let writable_accounts: Vec<Pubkey> = message
+        .account_keys
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| message.is_maybe_writable(*index, None))
+        .map(|(_, key)| *key)
+        .collect();
+
+    let mut pre_accounts: Vec<Option<Account>> = Vec::with_capacity(message.account_keys.len());
+    for keys in message.account_keys.chunks(MULTIPLE_ACCOUNTS_CHUNK) {
+        pre_accounts.extend(
+            rpc_client
+                .get_multiple_accounts(keys)
+                .await
+                .map_err(|e| anyhow!("Cannot fetch accounts of transaction {tx_uuid}: {e}"))?,
+        );
+    }
+
+    let simulation = rpc_client
+        .simulate_transaction_with_config(
+            transaction,
+            RpcSimulateTransactionConfig {
+                sig_verify: false,
+                replace_recent_blockhash: true,
+                commitment: Some(rpc_client.commitment()),
+                accounts: Some(RpcSimulateTransactionAccountsConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    addresses: writable_accounts.iter().map(|a| a.to_string()).collect(),
+                }),
+                ..RpcSimulateTransactionConfig::default()
+            },
+        )
+        .await
+        .map_err(|e| anyhow!("Cannot simulate transaction {tx_uuid}: {e}"))?
+        .value;
+    let post_accounts: Vec<Option<Account>> = simulation
+        .accounts
+        .unwrap_or_default()
+        .into_iter()
+        .map(|account| account.and_then(|a| a.decode::<Account>()))
+        .collect();
+
+    // settlement accounts exist before the simulation, or are created by it (init settlement)
+    let settlements: HashSet<Pubkey> = message
+        .account_keys
+        .iter()
+        .zip(pre_accounts.iter())
+        .filter_map(|(key, pre)| {
+            let post = writable_accounts
+                .iter()
+                .position(|w| w == key)
+                .and_then(|position| post_accounts.get(position).cloned().flatten());
+            let is_settlement = pre.iter().chain(post.iter()).any(is_settlement_account);
+            is_settlement.then_some(*key)
+        })
+        .collect();
+
+    let mut instructions = Vec::with_capacity(message.instructions.len());
+    let mut instruction_settlements: Vec<Option<Pubkey>> = vec![];
+    for (compiled, instruction) in message
+        .instructions
+        .iter()
+        .zip(journal_instructions(transaction))
+    {
+        let settlement = compiled
+            .accounts
+            .iter()
+            .map(|index| message.account_keys[*index as usize])
+            .find(|key| settlements.contains(key));
+        instruction_settlements.push(settlement);
+        instructions.push(PlannedInstruction {
+            instruction,
+            settlement: settlement.map(|s| s.to_string()),
+        });
+    }
+
+    let transaction_settlements = instruction_settlements
+        .iter()
+        .flatten()
+        .copied()
+        .collect::<HashSet<Pubkey>>();
+    let mut lamport_changes = vec![];
+    let mut settlement_lamport_changes = vec![];
+    for (position, account) in writable_accounts.iter().enumerate() {
+        let index = message
+            .account_keys
+            .iter()
+            .position(|key| key == account)
+            .expect("writable account is a key of the message");
+        let pre_lamports = pre_accounts[index].as_ref().map_or(0, |a| a.lamports);
+        let post_lamports = post_accounts
+            .get(position)
+            .and_then(|a| a.as_ref())
+            .map_or(0, |a| a.lamports);
+        if pre_lamports == post_lamports || simulation.err.is_some() {
+            continue;
+        }
+        let change = LamportChange {
+            account: account.to_string(),
+            pre_lamports,
+            post_lamports,
+            change: post_lamports as i64 - pre_lamports as i64,
+        };
+        let referencing_settlements = message
+            .instructions
+            .iter()
+            .zip(instruction_settlements.iter())
+            .filter(|(compiled, _)| compiled.accounts.contains(&(index as u8)))
+            .filter_map(|(_, settlement)| *settlement)
+            .collect::<HashSet<Pubkey>>();
+        let attributed_settlement = if transaction_settlements.len() == 1 {
+            transaction_settlements.iter().next()
+        } else if referencing_settlements.len() == 1 {
+            referencing_settlements.iter().next()
+        } else {
+            None
+        };
+        if let Some(settlement) = attributed_settlement {
+            settlement_lamport_changes.push((*settlement, change.clone()));
+        }
+        lamport_changes.push(change);
+    }
+
+    let mut settlement_units = vec![];
+    if let Some(units_consumed) = simulation.units_consumed {
+        let instruction_count = instruction_settlements.len().max(1) as u64;
+        let mut per_settlement: BTreeMap<Pubkey, u64> = BTreeMap::new();
+        for settlement in instruction_settlements.iter().flatten() {
+            *per_settlement.entry(*settlement).or_default() += 1;
+        }
+        settlement_units = per_settlement
+            .into_iter()
+            .map(|(settlement, count)| (settlement, units_consumed * count / instruction_count))
+            .collect();
+    }
+
+    Ok(SimulatedTransaction {
+        tx_uuid,
+        instructions,
+        units_consumed: simulation.units_consumed,
+        error: simulation.err.map(|err| {
+            let logs = simulation.logs.unwrap_or_default();
+            format!("{err}; logs: {}", logs.join(" | "))
+        }),
+        lamport_changes,
+        settlement_lamport_changes,
+        settlement_units,
+    })
+}
+
+fn is_settlement_account(account: &Account) -> bool {
+    account.owner == validator_bonds::ID && account.data.starts_with(Settlement::DISCRIMINATOR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lamport_change(account: &Pubkey, pre_lamports: u64, post_lamports: u64) -> LamportChange {
+        LamportChange {
+            account: account.to_string(),
+            pre_lamports,
+            post_lamports,
+            change: post_lamports as i64 - pre_lamports as i64,
+        }
+    }
+
+    fn planned_instruction(settlement: Option<&Pubkey>) -> PlannedInstruction {
+        PlannedInstruction {
+            instruction: JournalInstruction {
+                program_id: validator_bonds::ID.to_string(),
+                accounts: vec![],
+                data: String::new(),
+            },
+            settlement: settlement.map(|s| s.to_string()),
+        }
+    }
+
+    #[test]
+    fn summary_aggregates_per_settlement() {
+        let settlement = Pubkey::new_unique();
+        let stake_account = Pubkey::new_unique();
+        let simulated = |pre: u64, post: u64| SimulatedTransaction {
+            tx_uuid: "tx".to_string(),
+            instructions: vec![
+                planned_instruction(None),
+                planned_instruction(Some(&settlement)),
+            ],
+            units_consumed: Some(1_000),
+            error: None,
+            lamport_changes: vec![lamport_change(&stake_account, pre, post)],
+            settlement_lamport_changes: vec![(
+                settlement,
+                lamport_change(&stake_account, pre, post),
+            )],
+            settlement_units: vec![(settlement, 500)],
+        };
+        let dry_run = DryRun {
+            simulations: Mutex::new(vec![simulated(100, 50), simulated(50, 20)]),
+        };
+
+        let summary = dry_run.summary();
+        assert_eq!(summary.simulated_transactions, 2);
+        assert_eq!(summary.failed_simulations, 0);
+        assert_eq!(summary.units_consumed, 2_000);
+        assert_eq!(summary.settlements.len(), 1);
+        let movements = &summary.settlements[0];
+        assert_eq!(movements.settlement, settlement.to_string());
+        assert_eq!(movements.instructions, 2);
+        assert_eq!(movements.units_consumed, 1_000);
+        assert_eq!(movements.lamport_changes.len(), 1);
+        assert_eq!(movements.lamport_changes[0].pre_lamports, 100);
+        assert_eq!(movements.lamport_changes[0].post_lamports, 20);
+        assert_eq!(movements.lamport_changes[0].change, -80);
+    }
+}
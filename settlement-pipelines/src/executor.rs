@@ -1,5 +1,21 @@
-use log::debug;
+use crate::dry_run::{DryRun, DryRunSummary};
+use crate::journal::{Journal, JournalSummary};
+use crate::nonce_pool::{message_instructions, NoncePool};
+use crate::offline_signing::{
+    UnsignedTransaction, UnsignedTransactionsBatch, UnsignedTransactionsExport,
+};
+use anyhow::anyhow;
+use futures::future::join_all;
+use log::{debug, info};
+use settlement_common::utils::write_to_json_file;
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcSimulateTransactionConfig;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::message::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::Transaction;
 use solana_transaction_builder::TransactionBuilder;
 use solana_transaction_builder_executor::{
     builder_to_execution_data, execute_transaction_data_in_parallel,
@@ -7,16 +23,501 @@ use solana_transaction_builder_executor::{
     TransactionBuilderExecutionErrors,
 };
 use solana_transaction_executor::{PriorityFeePolicy, TransactionExecutor};
-use std::sync::Arc;
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 
 const PARALLEL_EXECUTION_RATE_DEFAULT: usize = 30;
 
+/// Maximal compute unit limit of a transaction
+const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// Percentage added to the simulated compute units when setting the compute unit limit
+const COMPUTE_UNIT_LIMIT_MARGIN_PERCENT: u64 = 10;
+
+#[derive(Debug)]
+pub struct PipelineExecutionError {
+    pub tx_uuid: String,
+    pub message: String,
+}
+
+impl Display for PipelineExecutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Errors of the failed transactions of one executor call.
+#[derive(Debug)]
+pub struct PipelineExecutionErrors(pub Vec<PipelineExecutionError>);
+
+impl PipelineExecutionErrors {
+    /// The error that is not bound to a single transaction fails all the transactions of the call.
+    fn for_all(execution_data: &[TransactionBuilderExecutionData], err: anyhow::Error) -> Self {
+        Self(
+            execution_data
+                .iter()
+                .map(|data| PipelineExecutionError {
+                    tx_uuid: data.tx_uuid.to_string(),
+                    message: format!("Transaction {}: {err:#}", data.tx_uuid),
+                })
+                .collect(),
+        )
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &PipelineExecutionError> {
+        self.0.iter()
+    }
+}
+
+impl IntoIterator for PipelineExecutionErrors {
+    type Item = PipelineExecutionError;
+    type IntoIter = std::vec::IntoIter<PipelineExecutionError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl Display for PipelineExecutionErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages = self
+            .0
+            .iter()
+            .map(|e| e.message.as_str())
+            .collect::<Vec<&str>>();
+        write!(f, "{}", messages.join("; "))
+    }
+}
+
+impl std::error::Error for PipelineExecutionErrors {}
+
+impl From<TransactionBuilderExecutionErrors> for PipelineExecutionErrors {
+    fn from(errors: TransactionBuilderExecutionErrors) -> Self {
+        Self(
+            errors
+                .into_iter()
+                .map(|error| PipelineExecutionError {
+                    tx_uuid: error.tx_uuid.to_string(),
+                    message: error.to_string(),
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Executor of the transactions prepared by the pipelines.
+/// When the unsigned export is configured the transactions are not executed,
+/// they are exported to the file to be signed offline (see [crate::offline_signing]).
+/// When the nonce pool is configured the transactions use durable nonces
+/// instead of the recent blockhashes (see [crate::nonce_pool]).
+/// When the journal is configured every sent transaction is recorded (see [crate::journal]).
+/// In the dry run the transactions are only simulated (see [crate::dry_run]).
+/// With nonces or the journal the transactions are sent by the pipeline itself,
+/// the compute budget is then set from the simulation and the minimal price of the priority fee policy.
+pub struct PipelineExecutor {
+    transaction_executor: Arc<TransactionExecutor>,
+    unsigned_export: Option<UnsignedExport>,
+    nonce_pool: Option<NoncePool>,
+    journal: Option<Journal>,
+    dry_run: Option<DryRun>,
+}
+
+struct UnsignedExport {
+    path: PathBuf,
+    export: Mutex<UnsignedTransactionsExport>,
+}
+
+impl PipelineExecutor {
+    pub fn new(
+        transaction_executor: Arc<TransactionExecutor>,
+        export_unsigned_transactions: Option<PathBuf>,
+        nonce_pool: Option<NoncePool>,
+        journal: Option<Journal>,
+        dry_run: bool,
+    ) -> Self {
+        Self {
+            transaction_executor,
+            unsigned_export: export_unsigned_transactions.map(|path| UnsignedExport {
+                path,
+                export: Mutex::new(UnsignedTransactionsExport::default()),
+            }),
+            nonce_pool,
+            journal,
+            dry_run: dry_run.then(DryRun::default),
+        }
+    }
+
+    pub fn journal_summary(&self) -> Option<JournalSummary> {
+        self.journal.as_ref().map(|journal| journal.summary())
+    }
+
+    pub fn dry_run_summary(&self) -> Option<DryRunSummary> {
+        self.dry_run.as_ref().map(|dry_run| dry_run.summary())
+    }
+
+    /// Executes the transactions in parallel when the rate is provided, otherwise in sequence.
+    async fn execute(
+        &self,
+        rpc_client: Arc<RpcClient>,
+        execution_data: &[TransactionBuilderExecutionData],
+        parallel_execution_rate: Option<usize>,
+        priority_fee_policy: &PriorityFeePolicy,
+    ) -> Result<(), PipelineExecutionErrors> {
+        if let Some(dry_run) = &self.dry_run {
+            return dry_run
+                .simulate(rpc_client, execution_data, parallel_execution_rate)
+                .await
+                .map_err(|e| PipelineExecutionErrors::for_all(execution_data, e));
+        }
+        if let Some(unsigned_export) = &self.unsigned_export {
+            return unsigned_export
+                .add_batch(
+                    rpc_client,
+                    self.nonce_pool.as_ref(),
+                    execution_data,
+                    parallel_execution_rate.is_some(),
+                )
+                .await
+                .map_err(|e| PipelineExecutionErrors::for_all(execution_data, e));
+        }
+        if self.nonce_pool.is_some() || self.journal.is_some() {
+            return execute_self_signed(
+                rpc_client,
+                self.nonce_pool.as_ref(),
+                self.journal.as_ref(),
+                execution_data,
+                parallel_execution_rate,
+                priority_fee_policy,
+            )
+            .await;
+        }
+        let execution_results = if let Some(parallel_execution_rate) = parallel_execution_rate {
+            execute_transaction_data_in_parallel(
+                self.transaction_executor.clone(),
+                execution_data,
+                Some(parallel_execution_rate),
+            )
+            .await
+        } else {
+            execute_transaction_data_in_sequence(
+                self.transaction_executor.clone(),
+                execution_data,
+                false,
+            )
+            .await
+        };
+        execution_results.map_err(PipelineExecutionErrors::from)
+    }
+}
+
+impl UnsignedExport {
+    /// The file is rewritten with every batch, then it contains all the batches of the pipeline run.
+    /// With the nonce pool every exported transaction uses a different nonce account,
+    /// the exported transactions are then valid until submitted.
+    async fn add_batch(
+        &self,
+        rpc_client: Arc<RpcClient>,
+        nonce_pool: Option<&NoncePool>,
+        execution_data: &[TransactionBuilderExecutionData],
+        parallel: bool,
+    ) -> anyhow::Result<()> {
+        let mut transactions = Vec::with_capacity(execution_data.len());
+        if let Some(nonce_pool) = nonce_pool {
+            let used_nonce_accounts = self.exported_transactions_count()?;
+            let nonce_accounts = nonce_pool
+                .nonce_accounts()
+                .get(used_nonce_accounts..used_nonce_accounts + execution_data.len())
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Not enough nonce accounts to export {} transactions, pool size: {}",
+                        used_nonce_accounts + execution_data.len(),
+                        nonce_pool.nonce_accounts().len()
+                    )
+                })?;
+            for (data, nonce_account) in execution_data.iter().zip(nonce_accounts) {
+                let (transaction, nonce) = nonce_pool
+                    .nonce_transaction(
+                        rpc_client.clone(),
+                        nonce_account,
+                        &data.prepared_transaction.transaction,
+                    )
+                    .await?;
+                transactions.push(UnsignedTransaction::from_execution_data(
+                    data,
+                    transaction,
+                    nonce,
+                )?);
+            }
+        } else {
+            // NOTE: transactions with a recent blockhash have to be signed and submitted
+            //       before the blockhash expires
+            let recent_blockhash = rpc_client.get_latest_blockhash().await?;
+            for data in execution_data {
+                transactions.push(UnsignedTransaction::from_execution_data(
+                    data,
+                    data.prepared_transaction.transaction.clone(),
+                    recent_blockhash,
+                )?);
+            }
+        }
+        let mut export = self
+            .export
+            .lock()
+            .map_err(|e| anyhow!("Unsigned transactions export lock poisoned: {e}"))?;
+        export.batches.push(UnsignedTransactionsBatch {
+            parallel,
+            transactions,
+        });
+        write_to_json_file(&*export, &self.path.to_string_lossy())?;
+        info!(
+            "Exported {} unsigned transactions to {:?}",
+            execution_data.len(),
+            self.path
+        );
+        Ok(())
+    }
+
+    fn exported_transactions_count(&self) -> anyhow::Result<usize> {
+        let export = self
+            .export
+            .lock()
+            .map_err(|e| anyhow!("Unsigned transactions export lock poisoned: {e}"))?;
+        Ok(export
+            .batches
+            .iter()
+            .map(|batch| batch.transactions.len())
+            .sum())
+    }
+}
+
+/// Signers of the prepared transaction that are required by the transaction message.
+pub(crate) fn transaction_signers(
+    execution_data: &TransactionBuilderExecutionData,
+    transaction: &Transaction,
+) -> Vec<Arc<dyn Signer>> {
+    let required_signers: &[Pubkey] = &transaction.message.account_keys
+        [..transaction.message.header.num_required_signatures as usize];
+    execution_data
+        .prepared_transaction
+        .signers
+        .iter()
+        .filter(|signer| required_signers.contains(&signer.pubkey()))
+        .cloned()
+        .collect()
+}
+
+/// Sending of the transaction failed and it is not known to be processed on-chain.
+#[derive(Debug)]
+pub(crate) struct NotLanded(pub String);
+
+impl Display for NotLanded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for NotLanded {}
+
+/// Transactions are signed and sent by the pipeline instead of the transaction executor.
+/// Every transaction of a round is assigned a nonce account of the pool when configured,
+/// the next round starts when all the transactions of the round are finished.
+async fn execute_self_signed(
+    rpc_client: Arc<RpcClient>,
+    nonce_pool: Option<&NoncePool>,
+    journal: Option<&Journal>,
+    execution_data: &[TransactionBuilderExecutionData],
+    parallel_execution_rate: Option<usize>,
+    priority_fee_policy: &PriorityFeePolicy,
+) -> Result<(), PipelineExecutionErrors> {
+    let mut round_size = parallel_execution_rate.unwrap_or(1);
+    if let Some(nonce_pool) = nonce_pool {
+        round_size = round_size.min(nonce_pool.nonce_accounts().len());
+    }
+    let mut errors: Vec<PipelineExecutionError> = vec![];
+    for (round_index, round) in execution_data.chunks(round_size).enumerate() {
+        let round_results = join_all(round.iter().enumerate().map(|(index, data)| {
+            let rpc_client = rpc_client.clone();
+            async move {
+                let result = execute_transaction(
+                    rpc_client,
+                    nonce_pool,
+                    index,
+                    journal,
+                    data,
+                    priority_fee_policy,
+                )
+                .await;
+                (data, result)
+            }
+        }))
+        .await;
+        for (data, result) in round_results {
+            match result {
+                Ok(signature) => debug!("Transaction {} executed: {signature}", data.tx_uuid),
+                Err(e) => errors.push(PipelineExecutionError {
+                    tx_uuid: data.tx_uuid.to_string(),
+                    message: format!("Transaction {}: {e:#}", data.tx_uuid),
+                }),
+            }
+        }
+        if parallel_execution_rate.is_none() && !errors.is_empty() {
+            // sequence execution stops at the first failure
+            for data in execution_data.iter().skip((round_index + 1) * round_size) {
+                errors.push(PipelineExecutionError {
+                    tx_uuid: data.tx_uuid.to_string(),
+                    message: format!(
+                        "Transaction {} not executed as a prior transaction in sequence failed",
+                        data.tx_uuid
+                    ),
+                });
+            }
+            break;
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(PipelineExecutionErrors(errors))
+    }
+}
+
+/// Executes one transaction with the nonce account at the round index when the pool is configured,
+/// otherwise with the latest blockhash. The transaction is journaled before it is sent.
+async fn execute_transaction(
+    rpc_client: Arc<RpcClient>,
+    nonce_pool: Option<&NoncePool>,
+    round_index: usize,
+    journal: Option<&Journal>,
+    data: &TransactionBuilderExecutionData,
+    priority_fee_policy: &PriorityFeePolicy,
+) -> anyhow::Result<Signature> {
+    let tx_uuid = data.tx_uuid.to_string();
+    let transaction = &data.prepared_transaction.transaction;
+    let signers = transaction_signers(data, transaction);
+    if let Some(journal) = journal {
+        journal.record_planned(&tx_uuid, transaction)?;
+    }
+    let result = async {
+        let transaction =
+            with_compute_budget(rpc_client.clone(), transaction, priority_fee_policy).await?;
+        if let Some(nonce_pool) = nonce_pool {
+            let nonce_account = &nonce_pool.nonce_accounts()[round_index];
+            nonce_pool
+                .execute(
+                    rpc_client,
+                    nonce_account,
+                    &transaction,
+                    &signers,
+                    journal.map(|journal| (journal, tx_uuid.as_str())),
+                )
+                .await
+        } else {
+            execute_with_blockhash(rpc_client, journal, &tx_uuid, &transaction, &signers).await
+        }
+    }
+    .await;
+    if let Some(journal) = journal {
+        // the transaction that did not land may still land, it is left as sent for the reconciliation
+        let not_landed = matches!(&result, Err(e) if e.downcast_ref::<NotLanded>().is_some());
+        if !not_landed {
+            journal.record_result(&tx_uuid, &result)?;
+        }
+    }
+    result
+}
+
+/// Prepends the compute budget instructions to the transaction.
+/// The compute unit limit is the simulated consumption with a margin,
+/// the compute unit price is the minimal price of the priority fee policy.
+async fn with_compute_budget(
+    rpc_client: Arc<RpcClient>,
+    transaction: &Transaction,
+    priority_fee_policy: &PriorityFeePolicy,
+) -> anyhow::Result<Transaction> {
+    let compute_unit_price = priority_fee_policy
+        .micro_lamports_per_cu_min
+        .min(priority_fee_policy.micro_lamports_per_cu_max);
+    let instructions = message_instructions(&transaction.message);
+    let fee_payer = transaction.message.account_keys.first();
+    let budget_transaction = |compute_unit_limit: u32| {
+        let mut budget_instructions = vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(compute_unit_limit),
+            ComputeBudgetInstruction::set_compute_unit_price(compute_unit_price),
+        ];
+        budget_instructions.extend(instructions.iter().cloned());
+        Transaction::new_unsigned(Message::new(&budget_instructions, fee_payer))
+    };
+
+    let simulation = rpc_client
+        .simulate_transaction_with_config(
+            &budget_transaction(MAX_COMPUTE_UNIT_LIMIT),
+            RpcSimulateTransactionConfig {
+                sig_verify: false,
+                replace_recent_blockhash: true,
+                commitment: Some(rpc_client.commitment()),
+                ..RpcSimulateTransactionConfig::default()
+            },
+        )
+        .await
+        .map_err(|e| anyhow!("Cannot simulate transaction: {e}"))?
+        .value;
+    if let Some(err) = simulation.err {
+        return Err(anyhow!(
+            "Transaction simulation failed: {err}, logs: {:?}",
+            simulation.logs.unwrap_or_default()
+        ));
+    }
+    let compute_unit_limit = simulation
+        .units_consumed
+        .map_or(MAX_COMPUTE_UNIT_LIMIT, |units| {
+            (units * (100 + COMPUTE_UNIT_LIMIT_MARGIN_PERCENT) / 100)
+                .min(MAX_COMPUTE_UNIT_LIMIT as u64) as u32
+        });
+    Ok(budget_transaction(compute_unit_limit))
+}
+
+async fn execute_with_blockhash(
+    rpc_client: Arc<RpcClient>,
+    journal: Option<&Journal>,
+    tx_uuid: &str,
+    transaction: &Transaction,
+    signers: &[Arc<dyn Signer>],
+) -> anyhow::Result<Signature> {
+    let recent_blockhash = rpc_client.get_latest_blockhash().await?;
+    let mut transaction = transaction.clone();
+    transaction.try_sign(signers, recent_blockhash)?;
+    let signature = transaction.signatures[0];
+    if let Some(journal) = journal {
+        journal.record_sent(tx_uuid, &transaction, None)?;
+    }
+    match rpc_client.send_and_confirm_transaction(&transaction).await {
+        Ok(signature) => Ok(signature),
+        Err(err) => match rpc_client.get_signature_status(&signature).await {
+            // landed despite the reported error
+            Ok(Some(Ok(()))) => Ok(signature),
+            Ok(Some(Err(tx_err))) => Err(anyhow!("Transaction {signature} failed: {tx_err}")),
+            _ => Err(anyhow::Error::new(NotLanded(format!(
+                "Transaction {signature} not executed: {err}"
+            )))),
+        },
+    }
+}
+
 pub async fn execute_parallel(
     rpc_client: Arc<RpcClient>,
-    executor: Arc<TransactionExecutor>,
+    executor: Arc<PipelineExecutor>,
     builder: &mut TransactionBuilder,
     priority_fee_policy: &PriorityFeePolicy,
-) -> Result<(usize, usize), TransactionBuilderExecutionErrors> {
+) -> Result<(usize, usize), PipelineExecutionErrors> {
     execute_parallel_with_rate(
         rpc_client,
         executor,
@@ -29,11 +530,11 @@ pub async fn execute_parallel(
 
 pub async fn execute_parallel_with_rate(
     rpc_client: Arc<RpcClient>,
-    executor: Arc<TransactionExecutor>,
+    executor: Arc<PipelineExecutor>,
     builder: &mut TransactionBuilder,
     priority_fee_policy: &PriorityFeePolicy,
     parallel_execution_rate: usize,
-) -> Result<(usize, usize), TransactionBuilderExecutionErrors> {
+) -> Result<(usize, usize), PipelineExecutionErrors> {
     let execution_data = builder_to_execution_data(
         rpc_client.url(),
         builder,
@@ -46,22 +547,24 @@ pub async fn execute_parallel_with_rate(
         0,
         "execute_parallel: expected to get all instructions from builder processed"
     );
-    let execution_results = execute_transaction_data_in_parallel(
-        executor.clone(),
-        &execution_data,
-        Some(parallel_execution_rate),
-    )
-    .await;
+    let execution_results = executor
+        .execute(
+            rpc_client.clone(),
+            &execution_data,
+            Some(parallel_execution_rate),
+            priority_fee_policy,
+        )
+        .await;
     handle_execution_results(&execution_data, execution_results)
 }
 
 pub async fn execute_in_sequence(
     rpc_client: Arc<RpcClient>,
-    executor: Arc<TransactionExecutor>,
+    executor: Arc<PipelineExecutor>,
     builder: &mut TransactionBuilder,
     priority_fee_policy: &PriorityFeePolicy,
     execute_one_by_one: bool,
-) -> Result<(usize, usize), TransactionBuilderExecutionErrors> {
+) -> Result<(usize, usize), PipelineExecutionErrors> {
     let execution_data = builder_to_execution_data(
         rpc_client.url(),
         builder,
@@ -74,18 +577,55 @@ pub async fn execute_in_sequence(
         0,
         "execute_in_sequence: expected to get all instructions from builder processed"
     );
-    let execution_results =
-        execute_transaction_data_in_sequence(executor.clone(), &execution_data, false).await;
+    let execution_results = executor
+        .execute(
+            rpc_client.clone(),
+            &execution_data,
+            None,
+            priority_fee_policy,
+        )
+        .await;
     handle_execution_results(&execution_data, execution_results)
 }
 
+/// Executes the transactions that were fully signed offline (see [crate::offline_signing]).
+/// The transactions are sent as they are, any change would invalidate the collected signatures.
+/// Returns the signature or the error for every transaction identified by its uuid.
+pub async fn execute_signed_transactions(
+    rpc_client: Arc<RpcClient>,
+    transactions: Vec<(String, Transaction)>,
+    parallel: bool,
+) -> Vec<(String, anyhow::Result<Signature>)> {
+    let chunk_size = if parallel {
+        PARALLEL_EXECUTION_RATE_DEFAULT
+    } else {
+        1
+    };
+    let mut results = Vec::with_capacity(transactions.len());
+    for chunk in transactions.chunks(chunk_size) {
+        let chunk_results = join_all(chunk.iter().map(|(tx_uuid, transaction)| {
+            let rpc_client = rpc_client.clone();
+            async move {
+                let result = rpc_client
+                    .send_and_confirm_transaction(transaction)
+                    .await
+                    .map_err(|e| anyhow!("Transaction {tx_uuid} failed: {e}"));
+                (tx_uuid.clone(), result)
+            }
+        }))
+        .await;
+        results.extend(chunk_results);
+    }
+    results
+}
+
 /// Method takes list of data that were about to be executed
 /// and the list of errors that came from that execution.
 /// It matches the execution data to the list of errors and returns the count of executed transactions and instructions.
 fn handle_execution_results(
     transaction_builder_execution_data: &[TransactionBuilderExecutionData],
-    executed_result: Result<(), TransactionBuilderExecutionErrors>,
-) -> Result<(usize, usize), TransactionBuilderExecutionErrors> {
+    executed_result: Result<(), PipelineExecutionErrors>,
+) -> Result<(usize, usize), PipelineExecutionErrors> {
     let to_execute_transaction_count = transaction_builder_execution_data.len();
     let to_execute_instruction_count: usize = transaction_builder_execution_data
         .iter()
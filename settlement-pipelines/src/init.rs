@@ -1,9 +1,12 @@
 use crate::arguments::GlobalOpts;
+use crate::executor::PipelineExecutor;
+use crate::journal::Journal;
+use crate::nonce_pool::NoncePool;
 use env_logger::{Builder, Env};
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::signature::Keypair;
 use solana_transaction_executor::{
-    SendTransactionWithGrowingTipProvider, TipPolicy, TransactionExecutor,
-    TransactionExecutorBuilder,
+    SendTransactionWithGrowingTipProvider, TipPolicy, TransactionExecutorBuilder,
 };
 use std::sync::Arc;
 
@@ -13,7 +16,19 @@ pub fn init_log(global_opts: &GlobalOpts) {
     builder.init();
 }
 
-pub fn get_executor(rpc_client: Arc<RpcClient>, tip_policy: TipPolicy) -> Arc<TransactionExecutor> {
+/// When the journal is configured, its pending transactions of a previous run are reconciled
+/// with the chain, the executor has to be created before the on-chain data is loaded.
+/// The fee payer is the authority of the nonce accounts, it advances the nonces of the pending transactions.
+pub async fn get_executor(
+    rpc_client: Arc<RpcClient>,
+    fee_payer: Arc<Keypair>,
+    tip_policy: TipPolicy,
+    global_opts: &GlobalOpts,
+) -> anyhow::Result<Arc<PipelineExecutor>> {
+    let journal = match &global_opts.journal {
+        Some(path) => Some(Journal::open(rpc_client.clone(), path, fee_payer).await?),
+        None => None,
+    };
     let transaction_executor_builder = TransactionExecutorBuilder::new()
         .with_default_providers(rpc_client.clone())
         .with_send_transaction_provider(SendTransactionWithGrowingTipProvider {
@@ -21,5 +36,11 @@ pub fn get_executor(rpc_client: Arc<RpcClient>, tip_policy: TipPolicy) -> Arc<Tr
             query_param: "tip".into(),
             tip_policy,
         });
-    Arc::new(transaction_executor_builder.build())
+    Ok(Arc::new(PipelineExecutor::new(
+        Arc::new(transaction_executor_builder.build()),
+        global_opts.export_unsigned_transactions.clone(),
+        NoncePool::new(global_opts.nonce_accounts.clone()),
+        journal,
+        global_opts.dry_run,
+    )))
 }
@@ -0,0 +1,575 @@
+use crate::nonce_pool::{advance_nonce, fetch_nonce_data};
+use anyhow::anyhow;
+use chrono::Utc;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature};
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::{Transaction, TransactionError};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Maximal number of signatures queried by one getSignatureStatuses call
+const SIGNATURE_STATUSES_CHUNK: usize = 256;
+
+/// Delay between the checks of the transactions that may still land on-chain
+const RECONCILE_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalStatus {
+    /// transaction was prepared, nothing was sent yet
+    Planned,
+    /// transaction was signed and sent, the confirmation is unknown
+    /// (also when sending failed and the transaction may still land)
+    Sent,
+    Confirmed,
+    /// transaction was executed on-chain and failed, or it could not be sent
+    Failed,
+    /// transaction did not land and it cannot land anymore (reconciled on restart)
+    Dropped,
+}
+
+impl JournalStatus {
+    fn is_pending(&self) -> bool {
+        matches!(self, JournalStatus::Planned | JournalStatus::Sent)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalInstruction {
+    pub program_id: String,
+    pub accounts: Vec<String>,
+    /// hex encoded instruction data
+    pub data: String,
+}
+
+/// One line of the journal file. Every status change of a transaction appends a new record,
+/// the last record of the transaction uuid is its current status.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalRecord {
+    pub timestamp: String,
+    pub tx_uuid: String,
+    pub status: JournalStatus,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub instructions: Vec<JournalInstruction>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    /// recent blockhash, or the nonce value when the nonce account is used
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recent_blockhash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce_account: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl JournalRecord {
+    fn new(tx_uuid: &str, status: JournalStatus) -> Self {
+        Self {
+            timestamp: Utc::now().to_rfc3339(),
+            tx_uuid: tx_uuid.to_string(),
+            status,
+            instructions: vec![],
+            signature: None,
+            recent_blockhash: None,
+            nonce_account: None,
+            error: None,
+        }
+    }
+
+    fn with_error(mut self, error: String) -> Self {
+        self.error = Some(error);
+        self
+    }
+}
+
+/// Counts of the journal records, the reconciled ones were left pending by a previous run
+/// and resolved against the chain state when the journal was opened.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct JournalSummary {
+    pub path: String,
+    pub reconciled_confirmed: u64,
+    pub reconciled_failed: u64,
+    pub reconciled_dropped: u64,
+    pub planned: u64,
+    pub confirmed: u64,
+    pub failed: u64,
+}
+
+/// Transaction left unresolved by a previous run with all its sent attempts.
+#[derive(Debug, Clone)]
+struct PendingTransaction {
+    tx_uuid: String,
+    /// records of the sent attempts with a signature, in the journal order
+    sent: Vec<(JournalRecord, Signature)>,
+}
+
+/// On-chain status of a sent attempt.
+#[derive(Debug, Clone)]
+struct AttemptStatus {
+    err: Option<TransactionError>,
+    confirmed: bool,
+}
+
+/// Append-only JSON-lines journal of the transactions executed by the pipeline (`--journal`).
+/// When the pipeline crashes halfway, the next run with the same journal file first resolves
+/// the transactions that were sent without a known result, so the new work is planned
+/// on the settled chain state.
+pub struct Journal {
+    path: PathBuf,
+    state: Mutex<JournalState>,
+}
+
+struct JournalState {
+    file: File,
+    summary: JournalSummary,
+}
+
+impl Journal {
+    /// Opens (or creates) the journal file and reconciles its pending transactions with the chain.
+    /// The nonce authority advances the nonces of the pending transactions that were not processed.
+    pub async fn open(
+        rpc_client: Arc<RpcClient>,
+        path: &Path,
+        nonce_authority: Arc<Keypair>,
+    ) -> anyhow::Result<Self> {
+        let pending = if path.exists() {
+            pending_records(read_records(path)?)
+        } else {
+            vec![]
+        };
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| anyhow!("Cannot open journal file {path:?}: {e}"))?;
+        let journal = Self {
+            path: path.to_path_buf(),
+            state: Mutex::new(JournalState {
+                file,
+                summary: JournalSummary {
+                    path: path.to_string_lossy().to_string(),
+                    ..Default::default()
+                },
+            }),
+        };
+        if !pending.is_empty() {
+            info!(
+                "Reconciling {} pending transactions of journal {path:?}",
+                pending.len()
+            );
+            journal
+                .reconcile(rpc_client, nonce_authority.as_ref(), pending)
+                .await?;
+        }
+        Ok(journal)
+    }
+
+    pub fn summary(&self) -> JournalSummary {
+        self.state
+            .lock()
+            .map(|state| state.summary.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn record_planned(&self, tx_uuid: &str, transaction: &Transaction) -> anyhow::Result<()> {
+        let mut record = JournalRecord::new(tx_uuid, JournalStatus::Planned);
+        record.instructions = journal_instructions(transaction);
+        self.append(record)
+    }
+
+    /// Has to be recorded before the transaction is sent, then a crash cannot lose its signature.
+    pub fn record_sent(
+        &self,
+        tx_uuid: &str,
+        transaction: &Transaction,
+        nonce_account: Option<&Pubkey>,
+    ) -> anyhow::Result<()> {
+        let mut record = JournalRecord::new(tx_uuid, JournalStatus::Sent);
+        record.signature = Some(transaction.signatures[0].to_string());
+        record.recent_blockhash = Some(transaction.message.recent_blockhash.to_string());
+        record.nonce_account = nonce_account.map(|n| n.to_string());
+        self.append(record)
+    }
+
+    pub fn record_result(
+        &self,
+        tx_uuid: &str,
+        result: &anyhow::Result<Signature>,
+    ) -> anyhow::Result<()> {
+        let record = match result {
+            Ok(signature) => {
+                let mut record = JournalRecord::new(tx_uuid, JournalStatus::Confirmed);
+                record.signature = Some(signature.to_string());
+                record
+            }
+            Err(e) => {
+                JournalRecord::new(tx_uuid, JournalStatus::Failed).with_error(format!("{e:#}"))
+            }
+        };
+        self.append(record)
+    }
+
+    fn append(&self, record: JournalRecord) -> anyhow::Result<()> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|e| anyhow!("Journal lock poisoned: {e}"))?;
+        let line = serde_json::to_string(&record)?;
+        writeln!(state.file, "{line}")
+            .and_then(|_| state.file.flush())
+            .map_err(|e| anyhow!("Cannot write to journal {:?}: {e}", self.path))?;
+        match record.status {
+            JournalStatus::Planned => state.summary.planned += 1,
+            JournalStatus::Confirmed => state.summary.confirmed += 1,
+            JournalStatus::Failed => state.summary.failed += 1,
+            JournalStatus::Sent | JournalStatus::Dropped => {}
+        }
+        Ok(())
+    }
+
+    fn append_reconciled(&self, record: JournalRecord) -> anyhow::Result<()> {
+        let status = record.status;
+        self.append(record)?;
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|e| anyhow!("Journal lock poisoned: {e}"))?;
+        // the reconciled records are not the work of this run
+        match status {
+            JournalStatus::Confirmed => {
+                state.summary.confirmed -= 1;
+                state.summary.reconciled_confirmed += 1;
+            }
+            JournalStatus::Failed => {
+                state.summary.failed -= 1;
+                state.summary.reconciled_failed += 1;
+            }
+            JournalStatus::Dropped => state.summary.reconciled_dropped += 1,
+            JournalStatus::Planned | JournalStatus::Sent => {}
+        }
+        Ok(())
+    }
+
+    /// Planned transactions were never sent and they are dropped.
+    /// All the sent attempts of a transaction are looked up on-chain; when none of them has a status,
+    /// they are waited for until their blockhashes expire (or their nonces are advanced),
+    /// then the transaction cannot land anymore.
+    async fn reconcile(
+        &self,
+        rpc_client: Arc<RpcClient>,
+        nonce_authority: &Keypair,
+        pending: Vec<PendingTransaction>,
+    ) -> anyhow::Result<()> {
+        let mut sent: Vec<PendingTransaction> = vec![];
+        for transaction in pending {
+            if transaction.sent.is_empty() {
+                self.append_reconciled(
+                    JournalRecord::new(&transaction.tx_uuid, JournalStatus::Dropped)
+                        .with_error("not sent by the previous run".to_string()),
+                )?;
+            } else {
+                sent.push(transaction);
+            }
+        }
+
+        while !sent.is_empty() {
+            let signatures = sent
+                .iter()
+                .flat_map(|transaction| transaction.sent.iter().map(|(_, s)| *s))
+                .collect::<Vec<Signature>>();
+            let mut statuses: HashMap<Signature, AttemptStatus> = HashMap::new();
+            for chunk in signatures.chunks(SIGNATURE_STATUSES_CHUNK) {
+                let chunk_statuses = rpc_client
+                    .get_signature_statuses_with_history(chunk)
+                    .await
+                    .map_err(|e| anyhow!("Cannot fetch journal signature statuses: {e}"))?
+                    .value;
+                for (signature, status) in chunk.iter().zip(chunk_statuses) {
+                    if let Some(status) = status {
+                        let confirmed = status.satisfies_commitment(CommitmentConfig::confirmed());
+                        statuses.insert(
+                            *signature,
+                            AttemptStatus {
+                                err: status.err,
+                                confirmed,
+                            },
+                        );
+                    }
+                }
+            }
+
+            let mut still_pending = vec![];
+            for transaction in sent {
+                let reconciled = match reconciled_record(&transaction, &statuses) {
+                    Some(reconciled) => Some(reconciled),
+                    None if statuses_of(&transaction, &statuses).next().is_some() => None,
+                    None => {
+                        let mut can_land = false;
+                        for (record, _) in &transaction.sent {
+                            if can_still_land(rpc_client.clone(), nonce_authority, record).await? {
+                                can_land = true;
+                                break;
+                            }
+                        }
+                        (!can_land).then(|| {
+                            JournalRecord::new(&transaction.tx_uuid, JournalStatus::Dropped)
+                                .with_error(format!(
+                                    "Transactions {} did not land and expired",
+                                    transaction
+                                        .sent
+                                        .iter()
+                                        .map(|(_, s)| s.to_string())
+                                        .collect::<Vec<String>>()
+                                        .join(", ")
+                                ))
+                        })
+                    }
+                };
+                match reconciled {
+                    Some(reconciled) => {
+                        info!(
+                            "Journal transaction {} reconciled as {:?}",
+                            transaction.tx_uuid, reconciled.status
+                        );
+                        self.append_reconciled(reconciled)?;
+                    }
+                    None => still_pending.push(transaction),
+                }
+            }
+            sent = still_pending;
+            if !sent.is_empty() {
+                info!(
+                    "Waiting for {} journal transactions that may still land",
+                    sent.len()
+                );
+                tokio::time::sleep(RECONCILE_POLL_INTERVAL).await;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn statuses_of<'a>(
+    transaction: &'a PendingTransaction,
+    statuses: &'a HashMap<Signature, AttemptStatus>,
+) -> impl Iterator<Item = (&'a Signature, &'a AttemptStatus)> {
+    transaction
+        .sent
+        .iter()
+        .filter_map(|(_, signature)| statuses.get(signature).map(|status| (signature, status)))
+}
+
+/// Any attempt of the transaction that was executed on-chain resolves the transaction.
+/// The attempt confirmed successfully takes precedence over the failed one.
+fn reconciled_record(
+    transaction: &PendingTransaction,
+    statuses: &HashMap<Signature, AttemptStatus>,
+) -> Option<JournalRecord> {
+    if let Some((signature, _)) = statuses_of(transaction, statuses)
+        .find(|(_, status)| status.err.is_none() && status.confirmed)
+    {
+        let mut confirmed = JournalRecord::new(&transaction.tx_uuid, JournalStatus::Confirmed);
+        confirmed.signature = Some(signature.to_string());
+        return Some(confirmed);
+    }
+    statuses_of(transaction, statuses)
+        .find(|(_, status)| status.err.is_some())
+        .map(|(signature, status)| {
+            JournalRecord::new(&transaction.tx_uuid, JournalStatus::Failed)
+                .with_error(format!("Transaction {signature} failed: {:?}", status.err))
+        })
+}
+
+/// The transaction with a recent blockhash may land until the blockhash expires,
+/// the transaction with a nonce may land until the nonce account is advanced.
+/// Nothing else advances the nonce of a transaction that was not processed,
+/// it is advanced here and the transaction is reported as able to land until its status is checked again.
+async fn can_still_land(
+    rpc_client: Arc<RpcClient>,
+    nonce_authority: &Keypair,
+    record: &JournalRecord,
+) -> anyhow::Result<bool> {
+    let recent_blockhash = match record
+        .recent_blockhash
+        .as_deref()
+        .and_then(|h| Hash::from_str(h).ok())
+    {
+        Some(recent_blockhash) => recent_blockhash,
+        None => return Ok(false),
+    };
+    if let Some(nonce_account) = record
+        .nonce_account
+        .as_deref()
+        .and_then(|n| Pubkey::from_str(n).ok())
+    {
+        let nonce_data = fetch_nonce_data(rpc_client.clone(), &nonce_account).await?;
+        if nonce_data.blockhash() != recent_blockhash {
+            return Ok(false);
+        }
+        if nonce_data.authority != nonce_authority.pubkey() {
+            return Err(anyhow!(
+                "Journal transaction {} of nonce account {nonce_account} was not processed and its nonce cannot be advanced, the nonce authority {} is not the fee payer",
+                record.tx_uuid,
+                nonce_data.authority
+            ));
+        }
+        let signature = advance_nonce(rpc_client, &nonce_account, nonce_authority).await?;
+        warn!(
+            "Journal transaction {} of nonce account {nonce_account} was not processed, nonce advanced by {signature}",
+            record.tx_uuid
+        );
+        return Ok(true);
+    }
+    rpc_client
+        .is_blockhash_valid(&recent_blockhash, CommitmentConfig::processed())
+        .await
+        .map_err(|e| anyhow!("Cannot check validity of blockhash {recent_blockhash}: {e}"))
+}
+
+fn read_records(path: &Path) -> anyhow::Result<Vec<JournalRecord>> {
+    let file = File::open(path).map_err(|e| anyhow!("Cannot open journal file {path:?}: {e}"))?;
+    let mut records = vec![];
+    for (line_number, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<JournalRecord>(&line) {
+            Ok(record) => records.push(record),
+            // a crash may leave the last line incomplete
+            Err(e) => warn!(
+                "Skipping invalid line {} of journal {path:?}: {e}",
+                line_number + 1
+            ),
+        }
+    }
+    Ok(records)
+}
+
+/// Transactions whose last record is not resolved, in the journal order,
+/// with all the attempts sent for them.
+fn pending_records(records: Vec<JournalRecord>) -> Vec<PendingTransaction> {
+    let mut order: Vec<String> = vec![];
+    let mut latest: HashMap<String, (JournalStatus, Vec<(JournalRecord, Signature)>)> =
+        HashMap::new();
+    for record in records {
+        let (status, sent) = latest.entry(record.tx_uuid.clone()).or_insert_with(|| {
+            order.push(record.tx_uuid.clone());
+            (record.status, vec![])
+        });
+        *status = record.status;
+        if record.status == JournalStatus::Sent {
+            if let Some(signature) = record
+                .signature
+                .as_deref()
+                .and_then(|s| Signature::from_str(s).ok())
+            {
+                sent.push((record, signature));
+            }
+        }
+    }
+    order
+        .into_iter()
+        .filter_map(|tx_uuid| {
+            latest
+                .remove(&tx_uuid)
+                .filter(|(status, _)| status.is_pending())
+                .map(|(_, sent)| PendingTransaction { tx_uuid, sent })
+        })
+        .collect()
+}
+
+pub(crate) fn journal_instructions(transaction: &Transaction) -> Vec<JournalInstruction> {
+    let message = &transaction.message;
+    message
+        .instructions
+        .iter()
+        .map(|instruction| JournalInstruction {
+            program_id: message.account_keys[instruction.program_id_index as usize].to_string(),
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|index| message.account_keys[*index as usize].to_string())
+                .collect(),
+            data: hex::encode(&instruction.data),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(tx_uuid: &str, status: JournalStatus) -> JournalRecord {
+        JournalRecord::new(tx_uuid, status)
+    }
+
+    fn sent(tx_uuid: &str, signature: &Signature) -> JournalRecord {
+        let mut record = JournalRecord::new(tx_uuid, JournalStatus::Sent);
+        record.signature = Some(signature.to_string());
+        record
+    }
+
+    #[test]
+    fn pending_records_takes_last_status() {
+        let records = vec![
+            record("a", JournalStatus::Planned),
+            record("b", JournalStatus::Planned),
+            record("c", JournalStatus::Planned),
+            record("a", JournalStatus::Sent),
+            record("b", JournalStatus::Sent),
+            record("b", JournalStatus::Confirmed),
+            record("c", JournalStatus::Sent),
+            record("c", JournalStatus::Failed),
+            record("d", JournalStatus::Planned),
+        ];
+        let pending = pending_records(records)
+            .into_iter()
+            .map(|t| t.tx_uuid)
+            .collect::<Vec<_>>();
+        assert_eq!(pending, vec!["a".to_string(), "d".to_string()]);
+    }
+
+    #[test]
+    fn pending_records_keeps_every_sent_signature() {
+        let first = Signature::from([1; 64]);
+        let second = Signature::from([2; 64]);
+        let records = vec![
+            record("a", JournalStatus::Planned),
+            sent("a", &first),
+            sent("a", &second),
+            record("b", JournalStatus::Planned),
+        ];
+        let pending = pending_records(records);
+        assert_eq!(pending.len(), 2);
+        assert_eq!(
+            pending[0]
+                .sent
+                .iter()
+                .map(|(_, s)| *s)
+                .collect::<Vec<Signature>>(),
+            vec![first, second]
+        );
+        assert!(pending[1].sent.is_empty());
+    }
+
+    #[test]
+    fn journal_record_line_format() {
+        let line = serde_json::to_string(&record("a", JournalStatus::Sent)).unwrap();
+        assert!(line.contains(r#""status":"sent""#));
+        assert!(!line.contains("signature"));
+        let parsed: JournalRecord = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed.status, JournalStatus::Sent);
+    }
+}
@@ -6,9 +6,9 @@ use anyhow::{anyhow, format_err};
 use log::{debug, error, info, warn};
 use merkle_tree::serde_serialize::pubkey_string_conversion;
 use serde::{Deserialize, Serialize};
+use settlement_common::file_format::read_from_file;
 use settlement_common::merkle_tree_collection::{MerkleTreeCollection, MerkleTreeMeta};
 use settlement_common::settlement_collection::{Settlement, SettlementCollection};
-use settlement_common::utils::read_from_json_file;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
@@ -34,6 +34,8 @@ pub struct BondSettlement {
     pub settlement_address: Pubkey,
     pub epoch: u64,
     pub merkle_root: [u8; 32],
+    #[serde(default)]
+    pub replaceable_seed: Option<[u8; 32]>,
     pub claims_count: u64,
     pub claims_lamports: u64,
 }
@@ -144,7 +146,7 @@ fn load_json_merkle_tree_settlement(
 ) -> Result<(), CliError> {
     debug!("Loading data from file: {path:?}");
 
-    let json_loading_result = match read_from_json_file(path) {
+    let json_loading_result = match read_from_file(path) {
         Ok(merkle_tree_collection) => {
             debug!("Loaded merkle tree collection from file: {path:?}");
             insert_json_parsed_data(loaded_data, Some(merkle_tree_collection), None)
@@ -152,7 +154,7 @@ fn load_json_merkle_tree_settlement(
         Err(merkle_tree_err) => {
             debug!("Failed to load as merkle tree collection: {merkle_tree_err:?}");
 
-            match read_from_json_file(path) {
+            match read_from_file(path) {
                 Ok(settlement_collection) => {
                     debug!("Loaded settlement collection from file: {path:?}");
                     insert_json_parsed_data(loaded_data, None, Some(settlement_collection))
@@ -306,7 +308,7 @@ pub fn load_merkle_tree_collections(
             continue;
         }
         info!("Loading merkle tree collection from: {path:?}");
-        let mut collection: MerkleTreeCollection = read_from_json_file(path)
+        let mut collection: MerkleTreeCollection = read_from_file(path)
             .map_err(|e| anyhow!("Failed to load merkle tree collection from {path:?}: {e}"))?;
         if let Some(config) = config_override {
             if collection.validator_bonds_config != Pubkey::default()
@@ -335,7 +337,7 @@ pub fn load_merkle_tree_collections(
 /// Returns settlement records grouped by epoch.
 pub async fn load_merkle_tree_with_on_chain(
     rpc_client: Arc<RpcClient>,
-    collections: &[MerkleTreeCollection],
+    collections: &[Arc<MerkleTreeCollection>],
     epoch: Option<u64>,
 ) -> Result<HashMap<u64, Vec<SettlementRecord>>, CliError> {
     let mut settlement_records_by_epoch =
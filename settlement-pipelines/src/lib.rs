@@ -2,14 +2,20 @@ use std::time::Duration;
 
 pub mod anchor;
 pub mod arguments;
+pub mod dry_run;
 pub mod executor;
 pub mod init;
 pub mod institutional_validators;
+pub mod journal;
 pub mod json_data;
+pub mod nonce_pool;
+pub mod offline_signing;
+pub mod pipeline_context;
 pub mod reporting;
 pub mod reporting_data;
 pub mod settlement_data;
 pub mod settlements;
+pub mod stages;
 pub mod stake_accounts;
 pub mod stake_accounts_cache;
 
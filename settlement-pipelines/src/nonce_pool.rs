@@ -0,0 +1,269 @@
+use crate::executor::NotLanded;
+use crate::journal::Journal;
+use anyhow::anyhow;
+use log::warn;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_nonce::state::{Data, State};
+use solana_nonce::versions::Versions;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::message::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::Transaction;
+use solana_system_interface::instruction::advance_nonce_account;
+use std::sync::Arc;
+
+/// Maximal size of the serialized transaction (see solana_packet::PACKET_DATA_SIZE)
+const PACKET_DATA_SIZE: usize = 1232;
+
+/// How many times the transaction is rebuilt with the current nonce when sending fails
+const NONCE_EXECUTION_ATTEMPTS: usize = 3;
+
+/// Pool of durable nonce accounts used by the executor instead of the recent blockhashes.
+/// The nonce authority of the accounts has to be the fee payer as the nonce advance instruction
+/// is added to every transaction and no other signer is available for it.
+/// One nonce account is used by one transaction at a time, the pool size limits the parallelism.
+pub struct NoncePool {
+    nonce_accounts: Vec<Pubkey>,
+}
+
+impl NoncePool {
+    /// Returns None when no nonce account is provided.
+    pub fn new(nonce_accounts: Vec<Pubkey>) -> Option<Self> {
+        if nonce_accounts.is_empty() {
+            None
+        } else {
+            Some(Self { nonce_accounts })
+        }
+    }
+
+    pub fn nonce_accounts(&self) -> &[Pubkey] {
+        &self.nonce_accounts
+    }
+
+    /// Transaction uses the current nonce value of the nonce account as its recent blockhash
+    /// and advances the nonce as the first instruction.
+    pub async fn nonce_transaction(
+        &self,
+        rpc_client: Arc<RpcClient>,
+        nonce_account: &Pubkey,
+        transaction: &Transaction,
+    ) -> anyhow::Result<(Transaction, Hash)> {
+        let nonce_data = fetch_nonce_data(rpc_client, nonce_account).await?;
+        let nonce_transaction = build_nonce_transaction(nonce_account, &nonce_data, transaction)?;
+        Ok((nonce_transaction, nonce_data.blockhash()))
+    }
+
+    /// Executes the transaction with the nonce account.
+    /// When sending fails and the transaction did not land, the transaction is sent again.
+    /// When the nonce value changed meanwhile, the previously sent transaction may have consumed it,
+    /// its signature is resolved from the transaction history before the transaction
+    /// is rebuilt with the current nonce value, so it cannot be executed twice.
+    /// Every sent attempt is recorded to the journal when provided.
+    pub async fn execute(
+        &self,
+        rpc_client: Arc<RpcClient>,
+        nonce_account: &Pubkey,
+        transaction: &Transaction,
+        signers: &[Arc<dyn Signer>],
+        journal: Option<(&Journal, &str)>,
+    ) -> anyhow::Result<Signature> {
+        let mut last_error = anyhow!("No execution attempt of nonce account {nonce_account}");
+        let mut previous_attempt: Option<(Signature, Hash)> = None;
+        for attempt in 1..=NONCE_EXECUTION_ATTEMPTS {
+            let nonce_data = fetch_nonce_data(rpc_client.clone(), nonce_account).await?;
+            let nonce = nonce_data.blockhash();
+            if let Some((previous_signature, previous_nonce)) = previous_attempt {
+                if previous_nonce != nonce {
+                    if let Some(result) =
+                        resolve_signature(rpc_client.clone(), &previous_signature).await?
+                    {
+                        return result;
+                    }
+                }
+            }
+            let mut nonce_transaction =
+                build_nonce_transaction(nonce_account, &nonce_data, transaction)?;
+            nonce_transaction.try_sign(signers, nonce)?;
+            let signature = nonce_transaction.signatures[0];
+            if let Some((journal, tx_uuid)) = journal {
+                journal.record_sent(tx_uuid, &nonce_transaction, Some(nonce_account))?;
+            }
+            previous_attempt = Some((signature, nonce));
+            match rpc_client
+                .send_and_confirm_transaction(&nonce_transaction)
+                .await
+            {
+                Ok(signature) => return Ok(signature),
+                Err(err) => match rpc_client.get_signature_status(&signature).await {
+                    // landed despite the reported error
+                    Ok(Some(Ok(()))) => return Ok(signature),
+                    // executed on-chain and failed, the nonce was advanced
+                    Ok(Some(Err(tx_err))) => {
+                        return Err(anyhow!("Transaction {signature} failed: {tx_err}"));
+                    }
+                    _ => {
+                        warn!(
+                            "Transaction {signature} with nonce account {nonce_account} not executed (attempt {attempt}/{NONCE_EXECUTION_ATTEMPTS}): {err}"
+                        );
+                        last_error = anyhow::Error::new(NotLanded(format!(
+                            "Transaction {signature} with nonce account {nonce_account} not executed: {err}"
+                        )));
+                    }
+                },
+            }
+        }
+        Err(last_error)
+    }
+}
+
+/// Resolves the result of the transaction searching the whole transaction history.
+/// Returns None when the transaction is not found, i.e., it was not executed.
+async fn resolve_signature(
+    rpc_client: Arc<RpcClient>,
+    signature: &Signature,
+) -> anyhow::Result<Option<anyhow::Result<Signature>>> {
+    let statuses = rpc_client
+        .get_signature_statuses_with_history(&[*signature])
+        .await
+        .map_err(|e| anyhow!("Cannot fetch status of transaction {signature}: {e}"))?
+        .value;
+    Ok(statuses
+        .into_iter()
+        .next()
+        .flatten()
+        .map(|status| match status.err {
+            None => Ok(*signature),
+            Some(tx_err) => Err(anyhow!("Transaction {signature} failed: {tx_err}")),
+        }))
+}
+
+fn build_nonce_transaction(
+    nonce_account: &Pubkey,
+    nonce_data: &Data,
+    transaction: &Transaction,
+) -> anyhow::Result<Transaction> {
+    let message = nonce_message(&transaction.message, nonce_account, &nonce_data.authority);
+    let nonce_transaction = Transaction::new_unsigned(message);
+    let transaction_size = bincode::serialized_size(&nonce_transaction)? as usize;
+    if transaction_size > PACKET_DATA_SIZE {
+        return Err(anyhow!(
+            "Transaction with nonce advance instruction of nonce account {nonce_account} is too large: {transaction_size} bytes"
+        ));
+    }
+    Ok(nonce_transaction)
+}
+
+pub(crate) async fn fetch_nonce_data(
+    rpc_client: Arc<RpcClient>,
+    nonce_account: &Pubkey,
+) -> anyhow::Result<Data> {
+    let account = rpc_client
+        .get_account(nonce_account)
+        .await
+        .map_err(|e| anyhow!("Cannot fetch nonce account {nonce_account}: {e}"))?;
+    let versions: Versions = bincode::deserialize(&account.data)
+        .map_err(|e| anyhow!("Account {nonce_account} is not a nonce account: {e}"))?;
+    match versions.state() {
+        State::Initialized(data) => Ok(data.clone()),
+        State::Uninitialized => Err(anyhow!("Nonce account {nonce_account} is not initialized")),
+    }
+}
+
+/// Advances the nonce account, a transaction signed with the previous nonce value cannot land then.
+pub(crate) async fn advance_nonce(
+    rpc_client: Arc<RpcClient>,
+    nonce_account: &Pubkey,
+    nonce_authority: &dyn Signer,
+) -> anyhow::Result<Signature> {
+    let recent_blockhash = rpc_client.get_latest_blockhash().await?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[advance_nonce_account(
+            nonce_account,
+            &nonce_authority.pubkey(),
+        )],
+        Some(&nonce_authority.pubkey()),
+        &[nonce_authority],
+        recent_blockhash,
+    );
+    rpc_client
+        .send_and_confirm_transaction(&transaction)
+        .await
+        .map_err(|e| anyhow!("Cannot advance nonce account {nonce_account}: {e}"))
+}
+
+/// Rebuilds the message with the advance nonce instruction placed first.
+fn nonce_message(message: &Message, nonce_account: &Pubkey, nonce_authority: &Pubkey) -> Message {
+    let mut instructions = vec![advance_nonce_account(nonce_account, nonce_authority)];
+    instructions.extend(message_instructions(message));
+    let fee_payer = message.account_keys.first();
+    Message::new(&instructions, fee_payer)
+}
+
+/// Instructions of the compiled message with the resolved account metas.
+pub(crate) fn message_instructions(message: &Message) -> Vec<Instruction> {
+    message
+        .instructions
+        .iter()
+        .map(|instruction| Instruction {
+            program_id: message.account_keys[instruction.program_id_index as usize],
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|index| {
+                    let index = *index as usize;
+                    AccountMeta {
+                        pubkey: message.account_keys[index],
+                        is_signer: message.is_signer(index),
+                        is_writable: message.is_maybe_writable(index, None),
+                    }
+                })
+                .collect(),
+            data: instruction.data.clone(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nonce_message_prepends_advance_instruction() {
+        let fee_payer = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+        let writable = Pubkey::new_unique();
+        let readonly = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[1, 2, 3],
+            vec![
+                AccountMeta::new_readonly(signer, true),
+                AccountMeta::new(writable, false),
+                AccountMeta::new_readonly(readonly, false),
+            ],
+        );
+        let message = Message::new(&[instruction.clone()], Some(&fee_payer));
+
+        let nonce_account = Pubkey::new_unique();
+        let nonce_message = nonce_message(&message, &nonce_account, &fee_payer);
+        assert_eq!(nonce_message.account_keys[0], fee_payer);
+        assert_eq!(
+            nonce_message.header.num_required_signatures,
+            message.header.num_required_signatures
+        );
+        assert_eq!(nonce_message.instructions.len(), 2);
+
+        let expected = Message::new(
+            &[
+                advance_nonce_account(&nonce_account, &fee_payer),
+                instruction,
+            ],
+            Some(&fee_payer),
+        );
+        assert_eq!(nonce_message, expected);
+    }
+}
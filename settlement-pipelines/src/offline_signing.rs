@@ -0,0 +1,227 @@
+use crate::executor::transaction_signers;
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::Transaction;
+use solana_transaction_builder_executor::TransactionBuilderExecutionData;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// File with the transactions exported by the pipelines run with `--export-unsigned-transactions`.
+/// Batches are kept in order of the pipeline execution, every batch corresponds
+/// to one [crate::executor] call of the pipeline.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct UnsignedTransactionsExport {
+    pub batches: Vec<UnsignedTransactionsBatch>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnsignedTransactionsBatch {
+    /// transactions of the batch are independent and may be executed in parallel,
+    /// otherwise they have to be executed one by one in the listed order
+    pub parallel: bool,
+    pub transactions: Vec<UnsignedTransaction>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnsignedTransaction {
+    pub tx_uuid: String,
+    /// pubkeys required to sign the transaction that were not available when exporting
+    pub missing_signers: Vec<String>,
+    /// hex encoded message data that the missing signers sign
+    pub message: String,
+    /// hex encoded bincode serialized transaction, signed by the locally available signers
+    pub transaction: String,
+}
+
+/// Signature collected offline for a transaction of the export file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OfflineSignature {
+    pub tx_uuid: String,
+    pub signer: String,
+    pub signature: String,
+}
+
+impl UnsignedTransaction {
+    /// Signs the transaction with the signers available in the transaction builder.
+    /// Signers without access to the private key (e.g., the operator authority loaded as a pubkey)
+    /// leave the default signature that is to be filled by [apply_offline_signatures].
+    pub fn from_execution_data(
+        execution_data: &TransactionBuilderExecutionData,
+        mut transaction: Transaction,
+        recent_blockhash: Hash,
+    ) -> anyhow::Result<Self> {
+        let signers = transaction_signers(execution_data, &transaction);
+        transaction
+            .try_partial_sign(&signers, recent_blockhash)
+            .map_err(|e| anyhow!("Cannot sign transaction {}: {e}", execution_data.tx_uuid))?;
+        let missing_signers = required_signers(&transaction)
+            .iter()
+            .zip(transaction.signatures.iter())
+            .filter(|(_, signature)| **signature == Signature::default())
+            .map(|(signer, _)| signer.to_string())
+            .collect();
+        Ok(Self {
+            tx_uuid: execution_data.tx_uuid.to_string(),
+            missing_signers,
+            message: hex::encode(transaction.message_data()),
+            transaction: hex::encode(bincode::serialize(&transaction)?),
+        })
+    }
+
+    pub fn to_transaction(&self) -> anyhow::Result<Transaction> {
+        let data = hex::decode(&self.transaction)
+            .map_err(|e| anyhow!("Cannot decode transaction {}: {e}", self.tx_uuid))?;
+        bincode::deserialize(&data)
+            .map_err(|e| anyhow!("Cannot deserialize transaction {}: {e}", self.tx_uuid))
+    }
+}
+
+fn required_signers(transaction: &Transaction) -> Vec<Pubkey> {
+    transaction.message.account_keys[..transaction.message.header.num_required_signatures as usize]
+        .to_vec()
+}
+
+/// Groups the offline signatures by the transaction uuid.
+pub fn signatures_per_transaction(
+    signatures: Vec<OfflineSignature>,
+) -> HashMap<String, Vec<OfflineSignature>> {
+    let mut per_transaction: HashMap<String, Vec<OfflineSignature>> = HashMap::new();
+    for signature in signatures {
+        per_transaction
+            .entry(signature.tx_uuid.clone())
+            .or_default()
+            .push(signature);
+    }
+    per_transaction
+}
+
+/// Places the offline collected signatures into the transaction.
+/// Every signature has to be made by a required signer of the transaction over its message,
+/// and the transaction has to be fully signed afterwards.
+pub fn apply_offline_signatures(
+    unsigned: &UnsignedTransaction,
+    signatures: &[OfflineSignature],
+) -> anyhow::Result<Transaction> {
+    let mut transaction = unsigned.to_transaction()?;
+    let message_data = transaction.message_data();
+    if hex::encode(&message_data) != unsigned.message {
+        return Err(anyhow!(
+            "Transaction {} message does not match the exported message data",
+            unsigned.tx_uuid
+        ));
+    }
+    let required_signers = required_signers(&transaction);
+    for offline_signature in signatures {
+        let signer = Pubkey::from_str(&offline_signature.signer).map_err(|e| {
+            anyhow!(
+                "Transaction {}: cannot parse signer '{}': {e}",
+                unsigned.tx_uuid,
+                offline_signature.signer
+            )
+        })?;
+        let signature = Signature::from_str(&offline_signature.signature).map_err(|e| {
+            anyhow!(
+                "Transaction {}: cannot parse signature of signer {signer}: {e}",
+                unsigned.tx_uuid
+            )
+        })?;
+        let position = required_signers
+            .iter()
+            .position(|required| *required == signer)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Transaction {}: {signer} is not a required signer",
+                    unsigned.tx_uuid
+                )
+            })?;
+        if !signature.verify(signer.as_ref(), &message_data) {
+            return Err(anyhow!(
+                "Transaction {}: signature {signature} of signer {signer} does not match the message",
+                unsigned.tx_uuid
+            ));
+        }
+        transaction.signatures[position] = signature;
+    }
+    let missing_signers = required_signers
+        .iter()
+        .zip(transaction.signatures.iter())
+        .filter(|(_, signature)| **signature == Signature::default())
+        .map(|(signer, _)| signer.to_string())
+        .collect::<Vec<String>>();
+    if !missing_signers.is_empty() {
+        return Err(anyhow!(
+            "Transaction {}: missing signatures of {missing_signers:?}",
+            unsigned.tx_uuid
+        ));
+    }
+    transaction
+        .verify()
+        .map_err(|e| anyhow!("Transaction {}: verification failed: {e}", unsigned.tx_uuid))?;
+    Ok(transaction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::instruction::{AccountMeta, Instruction};
+    use solana_sdk::message::Message;
+    use solana_sdk::signature::Keypair;
+    use solana_sdk::signer::Signer;
+
+    fn unsigned_transaction(fee_payer: &Keypair, authority: &Pubkey) -> UnsignedTransaction {
+        let message = Message::new(
+            &[Instruction::new_with_bytes(
+                Pubkey::new_unique(),
+                &[],
+                vec![AccountMeta::new(*authority, true)],
+            )],
+            Some(&fee_payer.pubkey()),
+        );
+        let mut transaction = Transaction::new_unsigned(message);
+        transaction
+            .try_partial_sign(&[fee_payer], Hash::new_unique())
+            .unwrap();
+        UnsignedTransaction {
+            tx_uuid: "tx".to_string(),
+            missing_signers: vec![authority.to_string()],
+            message: hex::encode(transaction.message_data()),
+            transaction: hex::encode(bincode::serialize(&transaction).unwrap()),
+        }
+    }
+
+    #[test]
+    fn apply_offline_signatures_verifies_signers() {
+        let fee_payer = Keypair::new();
+        let authority = Keypair::new();
+        let unsigned = unsigned_transaction(&fee_payer, &authority.pubkey());
+        let message_data = hex::decode(&unsigned.message).unwrap();
+
+        assert!(apply_offline_signatures(&unsigned, &[]).is_err());
+
+        let wrong_signer = Keypair::new();
+        let wrong_signature = OfflineSignature {
+            tx_uuid: "tx".to_string(),
+            signer: wrong_signer.pubkey().to_string(),
+            signature: wrong_signer.sign_message(&message_data).to_string(),
+        };
+        assert!(apply_offline_signatures(&unsigned, &[wrong_signature]).is_err());
+
+        let mismatched_signature = OfflineSignature {
+            tx_uuid: "tx".to_string(),
+            signer: authority.pubkey().to_string(),
+            signature: authority.sign_message(b"other message").to_string(),
+        };
+        assert!(apply_offline_signatures(&unsigned, &[mismatched_signature]).is_err());
+
+        let signature = OfflineSignature {
+            tx_uuid: "tx".to_string(),
+            signer: authority.pubkey().to_string(),
+            signature: authority.sign_message(&message_data).to_string(),
+        };
+        let transaction = apply_offline_signatures(&unsigned, &[signature]).unwrap();
+        assert!(transaction.is_signed());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::arguments::{
+    init_from_opts, GlobalOpts, InitializedGlobalOpts, PriorityFeePolicyOpts, TipPolicyOpts,
+};
+use crate::executor::PipelineExecutor;
+use crate::init::get_executor;
+use crate::json_data::{load_merkle_tree_collections, BondSettlement};
+use crate::stake_accounts_cache::StakeAccountsCache;
+use anchor_client::{DynSigner, Program};
+use anyhow::anyhow;
+use settlement_common::merkle_tree_collection::MerkleTreeCollection;
+use settlement_common::utils::read_from_json_file;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_transaction_executor::PriorityFeePolicy;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use validator_bonds::state::config::Config;
+use validator_bonds_common::config::get_config;
+use validator_bonds_common::stake_accounts::{get_clock, StakeActivation};
+
+/// Data shared by the pipeline stages (see [crate::stages]).
+/// A stage binary creates the context for its single stage, the `settlement-pipeline run`
+/// orchestrator creates it once and the stages reuse the loaded files and the fetched on-chain data
+/// that is not changed by the pipeline transactions.
+pub struct PipelineContext {
+    pub fee_payer: Arc<Keypair>,
+    pub operator_authority: Arc<dyn Signer>,
+    pub priority_fee_policy: PriorityFeePolicy,
+    pub rpc_client: Arc<RpcClient>,
+    pub program: Program<Arc<DynSigner>>,
+    pub transaction_executor: Arc<PipelineExecutor>,
+    /// config address provided on CLI, overrides the one from the merkle tree collections
+    pub config_override: Option<Pubkey>,
+    pub institutional_url: Option<String>,
+    pub stake_accounts_cache: StakeAccountsCache,
+    merkle_tree_collections: HashMap<PathBuf, Arc<MerkleTreeCollection>>,
+    configs: HashMap<Pubkey, Config>,
+    stake_activation: Option<StakeActivation>,
+    listed_settlements: Option<Vec<BondSettlement>>,
+}
+
+impl PipelineContext {
+    /// The executor is created before any on-chain data is loaded (see [get_executor]).
+    pub async fn new(
+        global_opts: &GlobalOpts,
+        priority_fee_policy_opts: &PriorityFeePolicyOpts,
+        tip_policy_opts: &TipPolicyOpts,
+    ) -> anyhow::Result<Self> {
+        let InitializedGlobalOpts {
+            fee_payer,
+            operator_authority,
+            priority_fee_policy,
+            tip_policy,
+            rpc_client,
+            program,
+        } = init_from_opts(global_opts, priority_fee_policy_opts, tip_policy_opts)?;
+        let transaction_executor = get_executor(
+            rpc_client.clone(),
+            fee_payer.clone(),
+            tip_policy,
+            global_opts,
+        )
+        .await?;
+        Ok(Self {
+            fee_payer,
+            operator_authority,
+            priority_fee_policy,
+            rpc_client,
+            program,
+            transaction_executor,
+            config_override: global_opts.config,
+            institutional_url: global_opts.institutional_url.clone(),
+            stake_accounts_cache: StakeAccountsCache::default(),
+            merkle_tree_collections: HashMap::new(),
+            configs: HashMap::new(),
+            stake_activation: None,
+            listed_settlements: None,
+        })
+    }
+
+    /// Merkle tree collection files are loaded once per path, the stages share the loaded data.
+    pub fn merkle_tree_collections(
+        &mut self,
+        files: &[PathBuf],
+    ) -> anyhow::Result<Vec<Arc<MerkleTreeCollection>>> {
+        let not_loaded = files
+            .iter()
+            .filter(|path| !self.merkle_tree_collections.contains_key(*path))
+            .cloned()
+            .collect::<Vec<PathBuf>>();
+        for path in not_loaded {
+            // files that cannot be used are skipped by the loading, they are reported on every call
+            if let Some(collection) =
+                load_merkle_tree_collections(&[path.clone()], self.config_override)?
+                    .into_iter()
+                    .next()
+            {
+                self.merkle_tree_collections
+                    .insert(path, Arc::new(collection));
+            }
+        }
+        Ok(files
+            .iter()
+            .filter_map(|path| self.merkle_tree_collections.get(path).cloned())
+            .collect())
+    }
+
+    pub async fn config(&mut self, config_address: &Pubkey) -> anyhow::Result<Config> {
+        if let Some(config) = self.configs.get(config_address) {
+            return Ok(config.clone());
+        }
+        let config = get_config(self.rpc_client.clone(), *config_address).await?;
+        self.configs.insert(*config_address, config.clone());
+        Ok(config)
+    }
+
+    /// Clock is fetched on every call (i.e., per stage), the stake history
+    /// is fetched again only when the epoch changed since the last call.
+    pub async fn stake_activation(&mut self) -> anyhow::Result<StakeActivation> {
+        let clock = get_clock(self.rpc_client.clone()).await?;
+        if let Some(stake_activation) = &mut self.stake_activation {
+            if stake_activation.clock.epoch == clock.epoch {
+                stake_activation.clock = clock;
+                return Ok(stake_activation.clone());
+            }
+        }
+        let stake_activation = StakeActivation::fetch(self.rpc_client.clone()).await?;
+        self.stake_activation = Some(stake_activation.clone());
+        Ok(stake_activation)
+    }
+
+    /// Settlements listed by the list stage within this run, otherwise loaded from the file.
+    pub fn listed_settlements(&mut self, path: &Path) -> anyhow::Result<Vec<BondSettlement>> {
+        if let Some(listed_settlements) = &self.listed_settlements {
+            return Ok(listed_settlements.clone());
+        }
+        let listed_settlements = read_from_json_file::<&Path, Vec<BondSettlement>>(&path)
+            .map_err(|e| anyhow!("Failed to load --listed-settlements: {e:?}"))?;
+        self.listed_settlements = Some(listed_settlements.clone());
+        Ok(listed_settlements)
+    }
+
+    pub fn set_listed_settlements(&mut self, listed_settlements: Vec<BondSettlement>) {
+        self.listed_settlements = Some(listed_settlements);
+    }
+}
@@ -1,16 +1,19 @@
 use crate::arguments::{ReportFormat, ReportOpts};
+use crate::dry_run::DryRunSummary;
+use crate::executor::{PipelineExecutionErrors, PipelineExecutor};
+use crate::journal::JournalSummary;
 use anyhow::format_err;
 use chrono::Utc;
 use log::{error, info};
 use serde::Serialize;
 use solana_sdk::pubkey::Pubkey;
-use solana_transaction_builder_executor::TransactionBuilderExecutionErrors;
 use std::fmt::{self, Display};
 use std::fs::File;
 use std::future::Future;
 use std::io::Write;
 use std::path::PathBuf;
 use std::pin::Pin;
+use std::sync::Arc;
 use validator_bonds_common::cli_result::{CliError, CliResult};
 
 pub trait PrintReportable {
@@ -39,6 +42,10 @@ pub struct ReportSummary {
     pub summary: serde_json::Value,
     pub errors: Vec<ErrorReportEntry>,
     pub warnings: Vec<ErrorReportEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub journal: Option<JournalSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<DryRunSummary>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -91,6 +98,7 @@ impl ReportOutput {
 pub struct ReportHandler<T: PrintReportable> {
     pub error_handler: ErrorHandler,
     pub reportable: T,
+    executor: Option<Arc<PipelineExecutor>>,
 }
 
 impl<T: PrintReportable> ReportHandler<T> {
@@ -98,9 +106,28 @@ impl<T: PrintReportable> ReportHandler<T> {
         Self {
             error_handler: ErrorHandler::default(),
             reportable,
+            executor: None,
         }
     }
 
+    /// The journal summary (`--journal`) and the dry run simulations (`--dry-run`)
+    /// of the executor are added to the report.
+    pub fn set_executor(&mut self, executor: Arc<PipelineExecutor>) {
+        self.executor = Some(executor);
+    }
+
+    pub fn journal_summary(&self) -> Option<JournalSummary> {
+        self.executor
+            .as_ref()
+            .and_then(|executor| executor.journal_summary())
+    }
+
+    pub fn dry_run_summary(&self) -> Option<DryRunSummary> {
+        self.executor
+            .as_ref()
+            .and_then(|executor| executor.dry_run_summary())
+    }
+
     pub async fn print_report(&self) {
         for report in self.reportable.get_report().await {
             println!("{report}");
@@ -125,7 +152,7 @@ impl<T: PrintReportable> ReportHandler<T> {
 
     pub fn add_tx_execution_result<D: Display>(
         &mut self,
-        execution_result: Result<(usize, usize), TransactionBuilderExecutionErrors>,
+        execution_result: Result<(usize, usize), PipelineExecutionErrors>,
         message: D,
     ) {
         self.error_handler
@@ -410,7 +437,7 @@ impl ErrorHandler {
 
     pub fn add_tx_execution_result<D: Display>(
         &mut self,
-        execution_result: Result<(usize, usize), TransactionBuilderExecutionErrors>,
+        execution_result: Result<(usize, usize), PipelineExecutionErrors>,
         message: D,
     ) {
         match execution_result {
@@ -614,7 +641,45 @@ pub async fn with_reporting_ext<T: ReportSerializable>(
         .transform_on_finalize(&mut report_handler.error_handler.entries);
 
     // Get text report for stdout (always printed for logging)
-    let text_report = report_handler.reportable.get_report().await;
+    let mut text_report = report_handler.reportable.get_report().await;
+    let journal = report_handler.journal_summary();
+    if let Some(journal) = &journal {
+        text_report.push(format!(
+            "Journal {}: planned {}, confirmed {}, failed {} transactions; reconciled from previous runs: confirmed {}, failed {}, dropped {}",
+            journal.path,
+            journal.planned,
+            journal.confirmed,
+            journal.failed,
+            journal.reconciled_confirmed,
+            journal.reconciled_failed,
+            journal.reconciled_dropped
+        ));
+    }
+    let dry_run = report_handler.dry_run_summary();
+    if let Some(dry_run) = &dry_run {
+        text_report.push(format!(
+            "Dry run: simulated {} transactions ({} failed simulations), {} compute units, {} settlements",
+            dry_run.simulated_transactions,
+            dry_run.failed_simulations,
+            dry_run.units_consumed,
+            dry_run.settlements.len()
+        ));
+        for settlement in dry_run.settlements.iter() {
+            let lamports_change: i64 = settlement
+                .lamport_changes
+                .iter()
+                .filter(|change| change.account == settlement.settlement)
+                .map(|change| change.change)
+                .sum();
+            text_report.push(format!(
+                "  settlement {}: {} instructions, ~{} compute units, settlement lamports change {}",
+                settlement.settlement,
+                settlement.instructions,
+                settlement.units_consumed,
+                lamports_change
+            ));
+        }
+    }
 
     // Build JSON report data
     let status = report_handler.error_handler.get_status();
@@ -629,6 +694,8 @@ pub async fn with_reporting_ext<T: ReportSerializable>(
         summary,
         errors,
         warnings,
+        journal,
+        dry_run,
     };
 
     // Always print text report
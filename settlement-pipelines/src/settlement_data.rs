@@ -8,6 +8,7 @@ use std::collections::HashMap;
 use std::fmt;
 use std::fmt::{Display, Formatter};
 use std::hash::Hash;
+use std::sync::Arc;
 use validator_bonds::state::bond::Bond;
 use validator_bonds::state::settlement::{find_settlement_staker_authority, Settlement};
 
@@ -29,6 +30,8 @@ pub struct SettlementRecord {
     pub settlement_staker_authority: Pubkey,
     // The merkle root of the merkle tree that the settlement is based on
     pub merkle_root: [u8; 32],
+    // Seed of a replaceable settlement, the settlement address is derived from it instead of the merkle root
+    pub replaceable_seed: Option<[u8; 32]>,
     // The merkle tree nodes that the settlement is based on
     pub tree_nodes: Vec<TreeNode>,
     // The maximum total claim sum (sum of SOLs) that can be claimed from the settlement
@@ -43,8 +46,8 @@ pub struct SettlementRecord {
     pub funding_sources: HashMap<SettlementFunder, u64>,
 }
 
-/// Two SettlementRecords are equal if they are of the same bond and have the same epoch and merkle root.
-/// That is implicitly derived within settlement address, see [`validator_bonds::state::settlement::find_settlement_address`].
+/// Two SettlementRecords are equal if they are of the same bond and have the same epoch and merkle root
+/// (or the same replaceable seed). That is implicitly derived within settlement address, see [`validator_bonds::state::settlement::find_settlement_address`].
 /// While there cannot be created two `Settlement` records on-chain.
 impl PartialEq for SettlementRecord {
     fn eq(&self, other: &Self) -> bool {
@@ -127,7 +130,7 @@ pub fn funder_display(funder: &SettlementFunderType) -> String {
 /// Parse settlement records from merkle tree collections only (no settlement collection needed).
 /// Returns records grouped by epoch.
 pub fn parse_from_merkle_tree_collections(
-    merkle_tree_collections: &[MerkleTreeCollection],
+    merkle_tree_collections: &[Arc<MerkleTreeCollection>],
     // When epoch is provided then it overrides the epoch from the merkle tree data
     epoch_override: Option<u64>,
 ) -> anyhow::Result<HashMap<u64, Vec<SettlementRecord>>> {
@@ -152,6 +155,7 @@ pub fn parse_from_merkle_tree_collections(
                 ));
             };
 
+            let replaceable_seed = merkle_tree.replaceable_seed;
             let epoch = epoch_override.unwrap_or(collection.epoch);
             let vote_account_address = merkle_tree.vote_account;
 
@@ -165,7 +169,7 @@ pub fn parse_from_merkle_tree_collections(
             let (derived_settlement, _) =
                 validator_bonds::state::settlement::find_settlement_address(
                     &derived_bond,
-                    &merkle_root.to_bytes(),
+                    &replaceable_seed.unwrap_or(merkle_root).to_bytes(),
                     epoch,
                 );
 
@@ -203,6 +207,7 @@ pub fn parse_from_merkle_tree_collections(
                 settlement_staker_authority: find_settlement_staker_authority(&settlement_address)
                     .0,
                 merkle_root: merkle_root.to_bytes(),
+                replaceable_seed: replaceable_seed.map(|seed| seed.to_bytes()),
                 tree_nodes: merkle_tree.tree_nodes.clone(),
                 max_total_claim_sum: merkle_tree.max_total_claim_sum,
                 max_total_claim: merkle_tree.max_total_claims as u64,
@@ -244,6 +249,7 @@ pub fn parse_settlements_from_json(
         .map(|(MerkleTreeMetaSettlement{merkle_tree, settlement}, epoch)|
             if merkle_tree.merkle_root.is_some() {
                 let merkle_root = merkle_tree.merkle_root.unwrap();
+                let replaceable_seed = merkle_tree.replaceable_seed;
                 let vote_account_address = merkle_tree.vote_account;
                 let (bond_address, _) = validator_bonds::state::bond::find_bond_address(
                     config_address,
@@ -253,7 +259,7 @@ pub fn parse_settlements_from_json(
                 let (settlement_address, _) =
                     validator_bonds::state::settlement::find_settlement_address(
                         &bond_address,
-                        &merkle_root.to_bytes(),
+                        &replaceable_seed.unwrap_or(merkle_root).to_bytes(),
                         epoch,
                     );
                 if epoch_override.is_none() {
@@ -279,6 +285,7 @@ pub fn parse_settlements_from_json(
                     )
                         .0,
                     merkle_root: merkle_root.to_bytes(),
+                    replaceable_seed: replaceable_seed.map(|seed| seed.to_bytes()),
                     tree_nodes: merkle_tree.tree_nodes.clone(),
                     max_total_claim_sum: merkle_tree.max_total_claim_sum,
                     max_total_claim: merkle_tree.max_total_claims as u64,
@@ -1,7 +1,9 @@
 use anyhow::anyhow;
 use log::{debug, info};
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::instruction::AccountMeta;
 use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
 use std::sync::Arc;
 use validator_bonds::state::config::{find_bonds_withdrawer_authority, Config};
 use validator_bonds::state::settlement::{find_settlement_staker_authority, Settlement};
@@ -48,14 +50,15 @@ pub async fn list_claimable_settlements(
     let claimable_settlements = all_settlements
         .into_iter()
         .filter(|(settlement_address, settlement)| {
-            let is_epoch_in_range = current_epoch <= settlement.epoch_created_for + config.epochs_to_claim_settlement;
+            let expiration_epoch = settlement.expiration_epoch(config);
+            let is_epoch_in_range = current_epoch <= expiration_epoch;
             let is_slot_past_threshold = current_slot >= settlement.slot_created_at + config.slots_to_start_settlement_claiming;
             info!(
-                "Settlement {} epoch_created_for: {}, current_epoch: {}, epochs_to_claim_settlement: {}, slot_created_at: {}, slots_to_start_settlement_claiming: {}, is_epoch_in_range: {}, is_slot_past_threshold: {}",
+                "Settlement {} epoch_created_for: {}, current_epoch: {}, expiration_epoch: {}, slot_created_at: {}, slots_to_start_settlement_claiming: {}, is_epoch_in_range: {}, is_slot_past_threshold: {}",
                 settlement_address,
                 settlement.epoch_created_for,
                 current_epoch,
-                config.epochs_to_claim_settlement,
+                expiration_epoch,
                 settlement.slot_created_at,
                 config.slots_to_start_settlement_claiming,
                 is_epoch_in_range,
@@ -159,6 +162,7 @@ pub async fn list_claimable_settlements(
     Ok(results)
 }
 
+/// Settlements of the config that can be closed, i.e., expired or fully claimed
 pub async fn load_expired_settlements(
     rpc_client: Arc<RpcClient>,
     config_address: &Pubkey,
@@ -182,19 +186,23 @@ pub async fn load_expired_settlements(
     let filtered_settlements: (Vec<_>, Vec<_>) = all_settlements.into_iter().zip(bonds_for_settlements.into_iter())
         .filter(|((settlement_address, settlement), (_, bond))| {
             let is_for_config = bond.as_ref().is_some_and(|b| b.config == *config_address);
-            let is_expired = current_epoch > settlement.epoch_created_for + config.epochs_to_claim_settlement;
+            let expiration_epoch = settlement.expiration_epoch(config);
+            let is_expired = current_epoch > expiration_epoch;
+            // fully claimed settlement can be closed before expiration
+            let is_fully_claimed = settlement.merkle_nodes_claimed >= settlement.max_merkle_nodes;
 
         debug!(
-            "Settlement {} epoch_created_for: {}, current_epoch: {}, epochs_to_claim_settlement: {}, is_for_config: {}, is_expired: {}",
+            "Settlement {} epoch_created_for: {}, current_epoch: {}, expiration_epoch: {}, is_for_config: {}, is_expired: {}, is_fully_claimed: {}",
             settlement_address,
             settlement.epoch_created_for,
             current_epoch,
-            config.epochs_to_claim_settlement,
+            expiration_epoch,
             is_for_config,
             is_expired,
+            is_fully_claimed,
         );
 
-        is_for_config && is_expired
+        is_for_config && (is_expired || is_fully_claimed)
     })
         .unzip();
 
@@ -264,3 +272,16 @@ pub async fn obtain_settlement_closing_refunds(
         split_rent_refund_account,
     })
 }
+
+/// The optional bond ledger is passed as the last of the remaining accounts of the instructions
+/// that update it (funding, claiming and closing settlements).
+/// Returns empty list when the bond has no ledger initialized.
+pub fn bond_ledger_remaining_accounts(
+    bond_ledgers: &HashMap<Pubkey, Pubkey>,
+    bond: &Pubkey,
+) -> Vec<AccountMeta> {
+    bond_ledgers
+        .get(bond)
+        .map(|bond_ledger| vec![AccountMeta::new(*bond_ledger, false)])
+        .unwrap_or_default()
+}
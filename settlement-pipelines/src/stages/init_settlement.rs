@@ -0,0 +1,820 @@
+use anchor_client::anchor_lang::AccountDeserialize;
+use anchor_client::{DynSigner, Program};
+use anyhow::{anyhow, Context};
+use clap::Args;
+use log::{debug, info, warn};
+use serde::Serialize;
+
+use crate::anchor::add_instruction_to_builder;
+use crate::arguments::load_keypair;
+use crate::executor::{execute_parallel, PipelineExecutor};
+use crate::institutional_validators::{fetch_validator_data, ValidatorsData};
+use crate::json_data::load_merkle_tree_with_on_chain;
+use crate::pipeline_context::PipelineContext;
+use crate::reporting::ErrorEntry::{Generic, VoteAccount};
+use crate::reporting::{
+    ErrorEntry, ErrorSeverity, PrintReportable, ReportHandler, ReportSerializable,
+};
+use crate::reporting_data::{
+    ReportingFunderSettlement, ReportingReasonSettlement, SettlementsReportData,
+};
+use crate::settlement_data::SettlementRecord;
+use solana_cli_output::display::build_balance_message;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::sysvar::clock::ID as clock_id;
+use solana_sdk_ids::system_program;
+use solana_transaction_builder::TransactionBuilder;
+use solana_transaction_executor::PriorityFeePolicy;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+use validator_bonds::constants::SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS;
+use validator_bonds::instructions::{
+    InitReplaceableSettlementArgs, InitSettlementArgs, InitSettlementClaimsChunkArgs,
+    ReplaceSettlementRootArgs,
+};
+use validator_bonds::state::settlement::{
+    find_settlement_claims_address, find_settlement_claims_chunk_address,
+};
+use validator_bonds::state::settlement_claims::{account_size, SettlementClaims};
+use validator_bonds::ID as validator_bonds_id;
+use validator_bonds_common::cli_result::CliError;
+use validator_bonds_common::constants::find_event_authority;
+use validator_bonds_common::settlements::get_settlements_for_pubkeys;
+use validator_bonds_common::utils::try_get_all_account_infos_for_pubkeys;
+use validator_bonds_common::utils_rpc_retry::{retry_get_pubkeys_operation, RetryConfig};
+
+#[derive(Args, Debug)]
+pub struct InitSettlementOpts {
+    /// Merkle tree collection file path generated by merkle-generator
+    #[arg(short = 'm', long)]
+    pub input_merkle_tree_collection: PathBuf,
+
+    /// forcing epoch, overriding from the settlement collection
+    #[arg(long)]
+    pub epoch: Option<u64>,
+
+    /// keypair payer for rent of accounts, if not provided, fee payer keypair is used
+    #[arg(long)]
+    pub rent_payer: Option<String>,
+}
+
+pub async fn init_settlement(
+    reporting: &mut ReportHandler<InitSettlementReport>,
+    context: &mut PipelineContext,
+    opts: &InitSettlementOpts,
+) -> anyhow::Result<()> {
+    let fee_payer = context.fee_payer.clone();
+    let operator_authority = context.operator_authority.clone();
+    let priority_fee_policy = context.priority_fee_policy.clone();
+    let rpc_client = context.rpc_client.clone();
+    let transaction_executor = context.transaction_executor.clone();
+
+    let rent_payer = if let Some(rent_payer) = opts.rent_payer.clone() {
+        load_keypair("--rent-payer", &rent_payer)?
+    } else {
+        fee_payer.clone()
+    };
+
+    let collections =
+        context.merkle_tree_collections(&[opts.input_merkle_tree_collection.clone()])?;
+    if collections.is_empty() || collections.iter().all(|c| c.merkle_trees.is_empty()) {
+        warn!("No merkle tree settlements");
+        return Ok(());
+    }
+
+    // Resolve config address: from CLI or from merkle tree
+    let config_address = context.config_override.unwrap_or_else(|| {
+        let config = collections[0].validator_bonds_config;
+        info!("Using config address from merkle tree: {config}");
+        config
+    });
+    info!(
+        "Loading merkle tree at: '{:?}', validator-bonds config: {}",
+        opts.input_merkle_tree_collection, config_address
+    );
+
+    // Load on-chain data for Settlement accounts that we need to create
+    let mut settlement_records =
+        load_on_chain_data(rpc_client.clone(), &collections, opts.epoch).await?;
+
+    let epoch = opts
+        .epoch
+        .map_or_else(|| settlement_records.first().unwrap().epoch, |v| v);
+    reporting
+        .reportable
+        .init(
+            epoch,
+            &settlement_records,
+            context.institutional_url.clone(),
+        )
+        .await;
+
+    init_settlements(
+        &context.program,
+        rpc_client.clone(),
+        transaction_executor.clone(),
+        &mut settlement_records,
+        &config_address,
+        fee_payer.clone(),
+        operator_authority.clone(),
+        rent_payer.clone(),
+        &priority_fee_policy,
+        reporting,
+    )
+    .await?;
+
+    upsize_settlements(
+        &context.program,
+        rpc_client.clone(),
+        transaction_executor.clone(),
+        &settlement_records,
+        fee_payer.clone(),
+        rent_payer.clone(),
+        &priority_fee_policy,
+        reporting,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Load on-chain data for Settlement accounts that we need to create
+async fn load_on_chain_data(
+    rpc_client: Arc<RpcClient>,
+    collections: &[Arc<settlement_common::merkle_tree_collection::MerkleTreeCollection>],
+    epoch: Option<u64>,
+) -> Result<Vec<SettlementRecord>, CliError> {
+    let settlement_records_per_epoch =
+        load_merkle_tree_with_on_chain(rpc_client, collections, epoch).await?;
+    if settlement_records_per_epoch.keys().len() != 1 {
+        return Err(CliError::Critical(anyhow!(
+            "Loading Settlements from JSON data expected one epoch data but got {}",
+            settlement_records_per_epoch.keys().len()
+        )));
+    }
+    if let Some((epoch, records)) = settlement_records_per_epoch.into_iter().next() {
+        debug!(
+            "Loaded {} settlement records for epoch {epoch}",
+            records.len(),
+        );
+        Ok(records)
+    } else {
+        Err(CliError::Critical(anyhow!(
+            "Loading Settlements from JSON data failed to get Settlement records"
+        )))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn init_settlements(
+    program: &Program<Arc<DynSigner>>,
+    rpc_client: Arc<RpcClient>,
+    transaction_executor: Arc<PipelineExecutor>,
+    settlement_records: &mut Vec<SettlementRecord>,
+    config_address: &Pubkey,
+    fee_payer: Arc<Keypair>,
+    operator_authority: Arc<dyn Signer>,
+    rent_payer: Arc<Keypair>,
+    priority_fee_policy: &PriorityFeePolicy,
+    reporting: &mut ReportHandler<InitSettlementReport>,
+) -> anyhow::Result<()> {
+    let mut transaction_builder = TransactionBuilder::limited(fee_payer.clone());
+    transaction_builder.add_signer_checked(&operator_authority);
+    transaction_builder.add_signer_checked(&rent_payer);
+
+    for record in settlement_records {
+        if record.bond_account.is_none() {
+            // the existence of the Bond is required for any init Settlement, when not exists, we skip the init
+            reporting.error().with_msg(format!(
+                "Cannot find bond account {} for vote account {}, funder {}, claim amount {} (settlement to init: {})",
+                record.bond_address,
+                record.vote_account_address,
+                record.funder,
+                build_balance_message(record.max_total_claim_sum, false, true),
+                record.settlement_address,
+            )).with_vote(record.vote_account_address).add();
+            continue;
+        }
+        if record.max_total_claim == 0 || record.max_total_claim_sum == 0 {
+            reporting.error().with_msg(format!(
+                "Cannot init Settlement with 0 at max_total_claim({}) or max_total_claim_sum({}), vote account {}, funder {} (settlement to init: {})",
+                record.max_total_claim,
+                build_balance_message(record.max_total_claim_sum, false, true),
+                record.vote_account_address,
+                record.funder,
+                record.settlement_address,
+            )).with_vote(record.vote_account_address).add();
+            continue;
+        }
+
+        if let Some(settlement) = &record.settlement_account {
+            if record.replaceable_seed.is_some() && settlement.merkle_root != record.merkle_root {
+                // replaceable settlement exists with an outdated merkle tree
+                let req = program
+                    .request()
+                    .accounts(validator_bonds::accounts::ReplaceSettlementRoot {
+                        config: *config_address,
+                        bond: record.bond_address,
+                        settlement: record.settlement_address,
+                        settlement_claims: find_settlement_claims_address(
+                            &record.settlement_address,
+                        )
+                        .0,
+                        operator_authority: operator_authority.pubkey(),
+                        rent_payer: rent_payer.pubkey(),
+                        system_program: system_program::ID,
+                        clock: clock_id,
+                        program: validator_bonds_id,
+                        event_authority: find_event_authority().0,
+                    })
+                    .args(validator_bonds::instruction::ReplaceSettlementRoot {
+                        replace_settlement_root_args: ReplaceSettlementRootArgs {
+                            merkle_root: record.merkle_root,
+                            max_total_claim: record.max_total_claim_sum,
+                            max_merkle_nodes: record.max_total_claim,
+                        },
+                    });
+                add_instruction_to_builder(
+                    &mut transaction_builder,
+                    &req,
+                    format!(
+                        "ReplaceSettlementRoot: {} (vote account {})",
+                        record.settlement_address, record.vote_account_address
+                    ),
+                )?;
+            } else {
+                debug!(
+                    "Settlement account {} already exists, skipping initialization",
+                    record.settlement_address
+                );
+            }
+            reporting
+                .reportable
+                .existing_settlements
+                .insert(record.settlement_address);
+        } else if let Some(replaceable_seed) = record.replaceable_seed {
+            let req = program
+                .request()
+                .accounts(validator_bonds::accounts::InitReplaceableSettlement {
+                    config: *config_address,
+                    bond: record.bond_address,
+                    operator_authority: operator_authority.pubkey(),
+                    system_program: system_program::ID,
+                    rent_payer: rent_payer.pubkey(),
+                    program: validator_bonds_id,
+                    settlement: record.settlement_address,
+                    settlement_claims: find_settlement_claims_address(&record.settlement_address).0,
+                    event_authority: find_event_authority().0,
+                })
+                .args(validator_bonds::instruction::InitReplaceableSettlement {
+                    init_replaceable_settlement_args: InitReplaceableSettlementArgs {
+                        replaceable_seed,
+                        merkle_root: record.merkle_root,
+                        rent_collector: rent_payer.pubkey(),
+                        max_total_claim: record.max_total_claim_sum,
+                        max_merkle_nodes: record.max_total_claim,
+                        epoch: record.epoch,
+                    },
+                });
+            add_instruction_to_builder(
+                &mut transaction_builder,
+                &req,
+                format!(
+                    "InitReplaceableSettlement: {} (vote account {})",
+                    record.settlement_address, record.vote_account_address
+                ),
+            )?;
+            reporting.reportable.add_created_settlement(record);
+        } else {
+            let req = program
+                .request()
+                .accounts(validator_bonds::accounts::InitSettlement {
+                    config: *config_address,
+                    bond: record.bond_address,
+                    operator_authority: operator_authority.pubkey(),
+                    system_program: system_program::ID,
+                    rent_payer: rent_payer.pubkey(),
+                    program: validator_bonds_id,
+                    settlement: record.settlement_address,
+                    settlement_claims: find_settlement_claims_address(&record.settlement_address).0,
+                    event_authority: find_event_authority().0,
+                })
+                .args(validator_bonds::instruction::InitSettlement {
+                    init_settlement_args: InitSettlementArgs {
+                        merkle_root: record.merkle_root,
+                        rent_collector: rent_payer.pubkey(),
+                        max_total_claim: record.max_total_claim_sum,
+                        max_merkle_nodes: record.max_total_claim,
+                        epoch: record.epoch,
+                    },
+                });
+            add_instruction_to_builder(
+                &mut transaction_builder,
+                &req,
+                format!(
+                    "InitSettlement: {} (vote account {})",
+                    record.settlement_address, record.vote_account_address
+                ),
+            )?;
+            reporting.reportable.add_created_settlement(record);
+        }
+    }
+
+    let (tx_count, ix_count) = execute_parallel(
+        rpc_client.clone(),
+        transaction_executor.clone(),
+        &mut transaction_builder,
+        priority_fee_policy,
+    )
+    .await
+    .map_err(CliError::retry_able)?;
+    info!(
+        "InitSettlement [{}]: txes {tx_count}/ixes {ix_count} executed successfully",
+        reporting.reportable.list_created_settlements()
+    );
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn upsize_settlements(
+    program: &Program<Arc<DynSigner>>,
+    rpc_client: Arc<RpcClient>,
+    transaction_executor: Arc<PipelineExecutor>,
+    settlement_records: &[SettlementRecord],
+    fee_payer: Arc<Keypair>,
+    rent_payer: Arc<Keypair>,
+    priority_fee_policy: &PriorityFeePolicy,
+    reporting: &mut ReportHandler<InitSettlementReport>,
+) -> anyhow::Result<()> {
+    let mut transaction_builder = TransactionBuilder::limited(fee_payer.clone());
+    transaction_builder.add_signer_checked(&rent_payer);
+
+    // re-loading settlements data from the chain on provided settlement addresses
+    let reloaded_settlements_data = retry_get_pubkeys_operation(
+        rpc_client.clone(),
+        &settlement_records
+            .iter()
+            .map(|s| s.settlement_address)
+            .collect::<Vec<Pubkey>>(),
+        get_settlements_for_pubkeys,
+        Some(RetryConfig {
+            max_retries: Some(5),
+            timeout_duration: Some(std::time::Duration::from_secs(300)),
+            retry_delay: std::time::Duration::from_secs(20),
+        }),
+        true,
+    )
+    .await
+    .context("Failed to reload Settlements data based of pubkeys");
+    let reloaded_settlements_records = reloaded_settlements_data
+        .map_err(CliError::retry_able)?
+        .into_iter()
+        .filter(|(settlement_address, settlement_data)| {
+            if settlement_data.is_none() {
+                let mut builder = reporting.error().with_msg(format!(
+                    "[UpsizeElements] Settlement account {settlement_address} does not exist on-chain"
+                ));
+                if let Some(vote_account) = settlement_records
+                    .iter()
+                    .find(|s| s.settlement_address == *settlement_address)
+                    .map(|s| s.vote_account_address)
+                {
+                    builder = builder.with_vote(vote_account);
+                }
+                builder.add();
+                false
+            } else {
+                true
+            }
+        })
+        .map(|(settlement_address, settlement)| (settlement_address, settlement.unwrap()))
+        .collect::<Vec<(Pubkey, validator_bonds::state::settlement::Settlement)>>();
+    let settlement_claims_pubkeys = reloaded_settlements_records
+        .iter()
+        .map(|(settlement_address, _)| find_settlement_claims_address(settlement_address).0)
+        .collect::<Vec<Pubkey>>();
+    let settlement_claims_infos =
+        try_get_all_account_infos_for_pubkeys(rpc_client.clone(), &settlement_claims_pubkeys, 5)
+            .await?;
+
+    for (
+        (settlement_claims_address, settlement_claims_info),
+        (settlement_address, settlement_data),
+    ) in settlement_claims_infos
+        .into_iter()
+        .zip(reloaded_settlements_records.iter())
+    {
+        let claims = if let Some(settlement_claims) = settlement_claims_info {
+            settlement_claims
+        } else {
+            reporting.error().with_msg(format!(
+                "CRITICAL [upsize_settlements]: No SettlementClaims account {settlement_claims_address} for an existing Settlement {settlement_address}"
+            )).add();
+            continue;
+        };
+        let required_size = account_size(settlement_data.max_merkle_nodes);
+        let number_of_upsize_calls = required_size
+            .saturating_sub(claims.data.len())
+            .div_ceil(validator_bonds::state::settlement_claims::MAX_PERMITTED_DATA_INCREASE);
+        debug!(
+            "[upsize_settlements] settlement {} (settlement claims: {}), max merkle nodes: {}, requires {} bytes, current size: {} bytes, upsize calls: {}",
+            settlement_address,
+            settlement_claims_address,
+            settlement_data.max_merkle_nodes,
+            required_size,
+            claims.data.len(),
+            number_of_upsize_calls
+        );
+        let vote_account = if let Some(settlement_record) = settlement_records
+            .iter()
+            .find(|s| s.settlement_address == *settlement_address)
+        {
+            settlement_record.vote_account_address
+        } else {
+            reporting
+                .error()
+                .with_msg(format!(
+                    "CRITICAL [upsize_settlements]: No vote account found for Settlement {settlement_address}"
+                ))
+                .add();
+            continue;
+        };
+
+        // large bitmap not upsized yet is split into chunk accounts created in parallel
+        let settlement_claims = match SettlementClaims::try_deserialize(&mut claims.data.as_slice())
+        {
+            Ok(settlement_claims) => settlement_claims,
+            Err(e) => {
+                reporting.error().with_msg(format!(
+                    "CRITICAL [upsize_settlements]: Cannot deserialize SettlementClaims account {settlement_claims_address}: {e}"
+                )).add();
+                continue;
+            }
+        };
+        let chunks = if settlement_claims.chunks > 0 {
+            settlement_claims.chunks
+        } else if settlement_data.max_merkle_nodes > SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS
+            && claims.data.len() == account_size(SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS)
+        {
+            u16::try_from(
+                settlement_data
+                    .max_merkle_nodes
+                    .div_ceil(SETTLEMENT_CLAIMS_CHUNK_MAX_RECORDS),
+            )?
+        } else {
+            0
+        };
+        if chunks > 0 {
+            let chunk_indexes = (1..chunks).collect::<Vec<u16>>();
+            let chunk_addresses = chunk_indexes
+                .iter()
+                .map(|chunk_index| {
+                    find_settlement_claims_chunk_address(settlement_address, *chunk_index).0
+                })
+                .collect::<Vec<Pubkey>>();
+            let chunk_infos =
+                try_get_all_account_infos_for_pubkeys(rpc_client.clone(), &chunk_addresses, 5)
+                    .await?;
+            for (chunk_index, (chunk_address, chunk_info)) in
+                chunk_indexes.into_iter().zip(chunk_infos.into_iter())
+            {
+                if chunk_info.is_some() {
+                    continue;
+                }
+                let req = program
+                    .request()
+                    .accounts(validator_bonds::accounts::InitSettlementClaimsChunk {
+                        settlement_claims: settlement_claims_address,
+                        settlement_claims_chunk: chunk_address,
+                        rent_payer: rent_payer.pubkey(),
+                        system_program: system_program::ID,
+                    })
+                    .args(validator_bonds::instruction::InitSettlementClaimsChunk {
+                        init_settlement_claims_chunk_args: InitSettlementClaimsChunkArgs {
+                            chunk_index,
+                        },
+                    });
+                add_instruction_to_builder(
+                    &mut transaction_builder,
+                    &req,
+                    format!(
+                        "InitSettlementClaimsChunk: {chunk_address} (chunk {chunk_index}, settlement: {settlement_address}, vote account {vote_account})",
+                    ),
+                )?;
+                reporting
+                    .reportable
+                    .add_upsized_settlement(*settlement_address);
+            }
+            continue;
+        }
+
+        for _ in 0..number_of_upsize_calls {
+            let req = program
+                .request()
+                .accounts(validator_bonds::accounts::UpsizeSettlementClaims {
+                    settlement_claims: settlement_claims_address,
+                    system_program: system_program::ID,
+                    rent_payer: rent_payer.pubkey(),
+                })
+                .args(validator_bonds::instruction::UpsizeSettlementClaims {});
+            add_instruction_to_builder(
+                &mut transaction_builder,
+                &req,
+                format!(
+                    "UpsizeSettlementClaims: {settlement_claims_address} (settlement: {settlement_address}, vote account {vote_account})",
+                ),
+            )?;
+            reporting
+                .reportable
+                .add_upsized_settlement(*settlement_address);
+        }
+    }
+
+    let (tx_count, ix_count) = execute_parallel(
+        rpc_client.clone(),
+        transaction_executor.clone(),
+        &mut transaction_builder,
+        priority_fee_policy,
+    )
+    .await
+    .map_err(CliError::retry_able)?;
+    info!(
+        "Upsize Settlement Claims [{}]: txes {tx_count}/ixes {ix_count} executed successfully",
+        reporting.reportable.list_created_settlements()
+    );
+    Ok(())
+}
+
+#[derive(Default)]
+pub struct InitSettlementReport {
+    epoch: u64,
+    json_loaded_settlements: HashSet<SettlementRecord>,
+    created_settlements: HashSet<SettlementRecord>,
+    existing_settlements: HashSet<Pubkey>,
+    upsized_settlements: HashMap<Pubkey, u32>,
+    institutional_validators: Option<ValidatorsData>,
+}
+
+impl PrintReportable for InitSettlementReport {
+    fn get_report(&self) -> Pin<Box<dyn Future<Output = Vec<String>> + '_>> {
+        let SettlementsReportData {
+            settlements_count: loaded_settlements_count,
+            settlements_max_claim_sum: loaded_settlements_max_claim_sum,
+            max_merkle_nodes_sum: loaded_max_merkle_nodes_sum,
+        } = self.list_loaded_settlements_data();
+        let settlements_info_by_reason: Vec<String> = self.list_settlements_data_by_reason()
+            .iter()
+            .flat_map(|(reason, (loaded_data, created_data))| {
+                vec![
+                    format!(
+                        "    Reason: {}, created settlements {}/{}, merkle nodes {}/{}, created claim amounts: {}/{}",
+                        reason,
+                        created_data.settlements_count,
+                        loaded_data.settlements_count,
+                        created_data.max_merkle_nodes_sum,
+                        loaded_data.max_merkle_nodes_sum,
+                        build_balance_message(created_data.settlements_max_claim_sum, false, true),
+                        build_balance_message(loaded_data.settlements_max_claim_sum, false, true),
+                    )
+                ]
+            })
+            .collect();
+        let settlements_info_by_funder: Vec<String> = ReportingFunderSettlement::items()
+            .iter()
+            .filter_map(|funder| {
+                let loaded_data = SettlementsReportData::calculate_for_funder(
+                    funder,
+                    &self.json_loaded_settlements,
+                );
+                if loaded_data.settlements_count == 0 {
+                    return None;
+                }
+                let created_data =
+                    SettlementsReportData::calculate_for_funder(funder, &self.created_settlements);
+                Some(format!(
+                    "    Funder: {}, created settlements {}/{}, claim amounts: {}/{}",
+                    funder,
+                    created_data.settlements_count,
+                    loaded_data.settlements_count,
+                    build_balance_message(created_data.settlements_max_claim_sum, false, true),
+                    build_balance_message(loaded_data.settlements_max_claim_sum, false, true),
+                ))
+            })
+            .collect();
+        Box::pin(async move {
+            let mut result_vec = vec![
+                format!(
+                    "InitSettlement (epoch: {}, sum merkle nodes: {}, sum claim amounts: {}): created {}/{} settlements, already existing {}",
+                    self.epoch,
+                    loaded_max_merkle_nodes_sum,
+                    build_balance_message(loaded_settlements_max_claim_sum, false, true),
+                    self.created_settlements.len(),
+                    loaded_settlements_count,
+                    self.existing_settlements.len(),
+                )];
+            result_vec.extend(settlements_info_by_reason);
+            result_vec.extend(settlements_info_by_funder);
+            result_vec.push(format!(
+                "UpsizeSettlementClaims: upsized settlements {}/{}",
+                self.upsized_settlements.len(),
+                loaded_settlements_count
+            ));
+            result_vec
+        })
+    }
+
+    fn transform_on_finalize(&self, entries: &mut Vec<ErrorEntry>) {
+        if let Some(institutional_validators) = &self.institutional_validators {
+            entries.iter_mut().for_each(|entry| {
+                match entry {
+                    Generic(_) => {
+                        // nothing
+                    }
+                    VoteAccount(vae) => {
+                        // when validator is not in institutional validators, we set severity to Info
+                        // the non-institutional validator is expected to not having a bond account created,
+                        // when re-staking from non-institutional validator to institutional validator,
+                        // we do not want to report its non-existence as an error
+                        // on the other hand when a validator is removed intentionally from institutional set,
+                        // the settlement should be created for it
+                        // that's the reason why we cannot just remove the non-institutional validators from settlement preparation
+                        if institutional_validators
+                            .validators
+                            .iter()
+                            .all(|v| v.vote_pubkey != vae.vote_account)
+                        {
+                            vae.base.severity = ErrorSeverity::Info;
+                        }
+                    }
+                }
+            });
+        }
+    }
+}
+
+impl InitSettlementReport {
+    pub fn report_handler() -> ReportHandler<Self> {
+        let init_settlement_report = Self::default();
+        ReportHandler::new(init_settlement_report)
+    }
+
+    async fn init(
+        &mut self,
+        epoch: u64,
+        json_settlements: &[SettlementRecord],
+        institutional_url: Option<String>,
+    ) {
+        self.epoch = epoch;
+        self.json_loaded_settlements = json_settlements.iter().cloned().collect();
+        if let Some(institutional_url_existing) = institutional_url {
+            self.institutional_validators =
+                Some(fetch_validator_data(&institutional_url_existing).await);
+        }
+    }
+
+    fn add_created_settlement(&mut self, settlement_record: &SettlementRecord) {
+        self.created_settlements.insert(settlement_record.clone());
+    }
+
+    fn add_upsized_settlement(&mut self, settlement_address: Pubkey) {
+        *self
+            .upsized_settlements
+            .entry(settlement_address)
+            .or_insert(0_u32) += 1_u32;
+    }
+
+    fn list_created_settlements(&self) -> String {
+        self.created_settlements
+            .iter()
+            .map(
+                |SettlementRecord {
+                     settlement_address,
+                     vote_account_address,
+                     ..
+                 }| format!("{settlement_address}/{vote_account_address}"),
+            )
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    fn list_loaded_settlements_data(&self) -> SettlementsReportData {
+        Self::list_settlements_data(
+            &self
+                .json_loaded_settlements
+                .iter()
+                .collect::<Vec<&SettlementRecord>>(),
+        )
+    }
+
+    /// Loading and created settlement data grouped by settlement reason.
+    /// The returned data contains a tuple of (Loaded, Created) SettlementsReportData.
+    fn list_settlements_data_by_reason(
+        &self,
+    ) -> HashMap<ReportingReasonSettlement, (SettlementsReportData, SettlementsReportData)> {
+        let mut data = HashMap::new();
+        for report_type in ReportingReasonSettlement::items() {
+            let (loaded_data, created_data) = (
+                SettlementsReportData::calculate_for_reason(
+                    &report_type,
+                    &self.json_loaded_settlements,
+                ),
+                SettlementsReportData::calculate_for_reason(
+                    &report_type,
+                    &self.created_settlements,
+                ),
+            );
+            data.insert(report_type, (loaded_data, created_data));
+        }
+        data
+    }
+
+    fn list_settlements_data(settlement_records: &[&SettlementRecord]) -> SettlementsReportData {
+        let settlements_count = settlement_records.len() as u64;
+        let settlements_max_claim_sum = settlement_records
+            .iter()
+            .map(|s| s.max_total_claim_sum)
+            .sum();
+        let max_merkle_nodes_sum = settlement_records.iter().map(|s| s.max_total_claim).sum();
+        SettlementsReportData {
+            settlements_count,
+            settlements_max_claim_sum,
+            max_merkle_nodes_sum,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct FunderInitSummary {
+    funder: String,
+    created_settlements: u64,
+    total_settlements: u64,
+    created_claim_sol: f64,
+    total_claim_sol: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct InitSettlementJsonSummary {
+    epoch: u64,
+    created_settlements: u64,
+    existing_settlements: u64,
+    total_merkle_nodes: u64,
+    total_max_claim_sol: f64,
+    upsized_settlements: u64,
+    funders: Vec<FunderInitSummary>,
+}
+
+impl ReportSerializable for InitSettlementReport {
+    fn command_name(&self) -> &'static str {
+        "init-settlement"
+    }
+
+    fn get_json_summary(&self) -> Pin<Box<dyn Future<Output = serde_json::Value> + '_>> {
+        Box::pin(async {
+            let lamports_to_sol = |lamports: u64| -> f64 { lamports as f64 / 1_000_000_000.0 };
+            let loaded_data = self.list_loaded_settlements_data();
+
+            let funders: Vec<FunderInitSummary> = ReportingFunderSettlement::items()
+                .iter()
+                .filter_map(|funder| {
+                    let loaded = SettlementsReportData::calculate_for_funder(
+                        funder,
+                        &self.json_loaded_settlements,
+                    );
+                    if loaded.settlements_count == 0 {
+                        return None;
+                    }
+                    let created = SettlementsReportData::calculate_for_funder(
+                        funder,
+                        &self.created_settlements,
+                    );
+                    Some(FunderInitSummary {
+                        funder: funder.to_string(),
+                        created_settlements: created.settlements_count,
+                        total_settlements: loaded.settlements_count,
+                        created_claim_sol: lamports_to_sol(created.settlements_max_claim_sum),
+                        total_claim_sol: lamports_to_sol(loaded.settlements_max_claim_sum),
+                    })
+                })
+                .collect();
+
+            let summary = InitSettlementJsonSummary {
+                epoch: self.epoch,
+                created_settlements: self.created_settlements.len() as u64,
+                existing_settlements: self.existing_settlements.len() as u64,
+                total_merkle_nodes: loaded_data.max_merkle_nodes_sum,
+                total_max_claim_sol: lamports_to_sol(loaded_data.settlements_max_claim_sum),
+                upsized_settlements: self.upsized_settlements.len() as u64,
+                funders,
+            };
+
+            serde_json::to_value(summary)
+                .unwrap_or_else(|e| serde_json::json!({"error": e.to_string()}))
+        })
+    }
+}
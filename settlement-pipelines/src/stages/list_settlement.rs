@@ -0,0 +1,212 @@
+use crate::json_data::BondSettlement;
+use crate::pipeline_context::PipelineContext;
+use crate::reporting::{PrintReportable, ReportHandler, ReportSerializable};
+use anyhow::anyhow;
+use clap::Args;
+use log::{debug, error, info, warn};
+use serde::Serialize;
+use settlement_common::file_format::read_from_file;
+use settlement_common::merkle_tree_collection::MerkleTreeCollection;
+use settlement_common::utils::write_to_json_file;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Arc;
+use validator_bonds::state::bond::find_bond_address;
+use validator_bonds::state::settlement::find_settlement_address;
+use validator_bonds_common::cli_result::CliError;
+
+#[derive(Args, Debug)]
+pub struct ListSettlementOpts {
+    /// Merkle tree collection JSON files.
+    /// Each file contains a self-contained MerkleTreeCollection with all necessary data.
+    #[arg(required = true, short = 'f', long, value_delimiter = ' ', num_args(1..))]
+    pub json_files: Vec<PathBuf>,
+
+    /// File where the listed settlements are written to
+    #[clap(long, short = 'p')]
+    pub listed_settlements: PathBuf,
+}
+
+/// Lists settlements of the merkle tree collections loaded by the context to the file,
+/// the listed settlements are kept in the context for the following stages.
+pub fn list_settlement(
+    reporting: &mut ReportHandler<ListSettlementReport>,
+    context: &mut PipelineContext,
+    opts: &ListSettlementOpts,
+) -> anyhow::Result<()> {
+    let collections = context.merkle_tree_collections(&opts.json_files)?;
+    if collections.is_empty() {
+        return Err(CliError::critical(anyhow!(
+            "No merkle tree collections loaded from provided files"
+        ))
+        .into());
+    }
+    let bond_settlements = list_settlements(&collections, context.config_override);
+    write_to_json_file(
+        &bond_settlements,
+        opts.listed_settlements.to_string_lossy().as_ref(),
+    )
+    .map_err(CliError::critical)?;
+    reporting
+        .reportable
+        .init(&bond_settlements, &opts.listed_settlements);
+    context.set_listed_settlements(bond_settlements);
+    Ok(())
+}
+
+/// Lists settlements of the merkle tree collections,
+/// config address from CLI overrides the one from the merkle tree collections.
+pub fn list_settlements(
+    merkle_tree_collection: &[Arc<MerkleTreeCollection>],
+    config_override: Option<Pubkey>,
+) -> Vec<BondSettlement> {
+    let config_address = config_override.unwrap_or_else(|| {
+        let config = merkle_tree_collection[0].validator_bonds_config;
+        info!("Using config address from merkle tree: {config}");
+        config
+    });
+    info!("Listing settlements for validator-bonds config: {config_address}");
+
+    let bond_settlements: Vec<BondSettlement> = merkle_tree_collection
+        .iter()
+        .flat_map(|mtc| mtc.merkle_trees.iter().map(move |tree| (tree, mtc.epoch)))
+        .filter(|(merkle_tree, epoch)| {
+            if merkle_tree.merkle_root.is_none() {
+                error!(
+                    "Merkle tree [epoch {}, vote account{}] does not have a root, skipping",
+                    epoch, merkle_tree.vote_account
+                );
+                return false;
+            }
+            merkle_tree.merkle_root.is_some()
+        })
+        .map(|(merkle_tree, epoch)| {
+            let merkle_root = merkle_tree
+                .merkle_root
+                .expect("Merkle root cannot be None as filtered above")
+                .to_bytes();
+            let replaceable_seed = merkle_tree.replaceable_seed.map(|seed| seed.to_bytes());
+            let (bond_address, _) = find_bond_address(&config_address, &merkle_tree.vote_account);
+            let (settlement_address, _) = find_settlement_address(
+                &bond_address,
+                &replaceable_seed.unwrap_or(merkle_root),
+                epoch,
+            );
+            BondSettlement {
+                config_address,
+                bond_address,
+                vote_account_address: merkle_tree.vote_account,
+                settlement_address,
+                epoch,
+                merkle_root,
+                replaceable_seed,
+                claims_count: merkle_tree.max_total_claims as u64,
+                claims_lamports: merkle_tree.max_total_claim_sum,
+            }
+        })
+        .collect();
+
+    info!(
+        "Settlements [#{}, {} SOLs, {} claims]: {:?}",
+        bond_settlements.len(),
+        bond_settlements
+            .iter()
+            .map(|s| s.claims_lamports)
+            .sum::<u64>()
+            / LAMPORTS_PER_SOL,
+        bond_settlements.iter().map(|s| s.claims_count).sum::<u64>(),
+        bond_settlements
+            .iter()
+            .map(|s| s.settlement_address.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    bond_settlements
+}
+
+pub fn load_merkle_tree_files(
+    merkle_tree_files: &[PathBuf],
+) -> anyhow::Result<Vec<Arc<MerkleTreeCollection>>> {
+    let mut merkle_trees: Vec<Arc<MerkleTreeCollection>> = vec![];
+    for path in merkle_tree_files.iter().filter(|path| {
+        if path.is_file() {
+            debug!("Processing file: {path:?}");
+            true
+        } else {
+            debug!("Skipping path: {path:?} as not a file");
+            false
+        }
+    }) {
+        read_from_file(path).map_or_else(
+            |e| warn!("Cannot load file '{path:?}' as MerkleTreeCollection: {e:?}"),
+            |s| merkle_trees.push(Arc::new(s)),
+        );
+    }
+
+    Ok(merkle_trees)
+}
+
+#[derive(Default)]
+pub struct ListSettlementReport {
+    listed_settlements_file: PathBuf,
+    settlements_count: u64,
+    claims_count: u64,
+    claims_lamports: u64,
+}
+
+impl ListSettlementReport {
+    pub fn report_handler() -> ReportHandler<Self> {
+        ReportHandler::new(Self::default())
+    }
+
+    fn init(&mut self, bond_settlements: &[BondSettlement], listed_settlements_file: &Path) {
+        self.listed_settlements_file = listed_settlements_file.to_path_buf();
+        self.settlements_count = bond_settlements.len() as u64;
+        self.claims_count = bond_settlements.iter().map(|s| s.claims_count).sum();
+        self.claims_lamports = bond_settlements.iter().map(|s| s.claims_lamports).sum();
+    }
+}
+
+impl PrintReportable for ListSettlementReport {
+    fn get_report(&self) -> Pin<Box<dyn Future<Output = Vec<String>> + '_>> {
+        Box::pin(async {
+            vec![format!(
+                "Listed {} settlements [{} SOLs, {} claims] to {:?}",
+                self.settlements_count,
+                self.claims_lamports / LAMPORTS_PER_SOL,
+                self.claims_count,
+                self.listed_settlements_file
+            )]
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct ListSettlementJsonSummary {
+    listed_settlements_file: String,
+    settlements_count: u64,
+    claims_count: u64,
+    claims_lamports: u64,
+}
+
+impl ReportSerializable for ListSettlementReport {
+    fn command_name(&self) -> &'static str {
+        "list-settlement"
+    }
+
+    fn get_json_summary(&self) -> Pin<Box<dyn Future<Output = serde_json::Value> + '_>> {
+        Box::pin(async {
+            let summary = ListSettlementJsonSummary {
+                listed_settlements_file: self.listed_settlements_file.to_string_lossy().to_string(),
+                settlements_count: self.settlements_count,
+                claims_count: self.claims_count,
+                claims_lamports: self.claims_lamports,
+            };
+            serde_json::to_value(summary)
+                .unwrap_or_else(|e| serde_json::json!({"error": e.to_string()}))
+        })
+    }
+}
@@ -0,0 +1,7 @@
+pub mod claim_settlement;
+pub mod close_settlement;
+pub mod fund_settlement;
+pub mod init_settlement;
+pub mod list_settlement;
+pub mod merge_stakes;
+pub mod verify_settlement;
@@ -6,31 +6,33 @@ use std::sync::Arc;
 use validator_bonds_common::stake_accounts::{collect_stake_accounts, CollectedStakeAccounts};
 
 #[derive(PartialEq, Eq, Hash, Clone)]
-struct StakeWithdrawAuthorityPair<'a> {
-    pub withdraw_authority: &'a Pubkey,
-    pub stake_authority: &'a Pubkey,
+struct StakeWithdrawAuthorityPair {
+    pub withdraw_authority: Pubkey,
+    pub stake_authority: Pubkey,
 }
 
-impl<'a> StakeWithdrawAuthorityPair<'a> {
-    pub fn new(withdraw_authority: &'a Pubkey, stake_authority: &'a Pubkey) -> Self {
+impl StakeWithdrawAuthorityPair {
+    pub fn new(withdraw_authority: &Pubkey, stake_authority: &Pubkey) -> Self {
         Self {
-            withdraw_authority,
-            stake_authority,
+            withdraw_authority: *withdraw_authority,
+            stake_authority: *stake_authority,
         }
     }
 }
 
+/// Stake accounts fetched per withdraw and stake authority pair,
+/// shared by the stages of the `settlement-pipeline run` (see [crate::pipeline_context]).
 #[derive(Default)]
-pub struct StakeAccountsCache<'a> {
-    cache: HashMap<StakeWithdrawAuthorityPair<'a>, CollectedStakeAccounts>,
+pub struct StakeAccountsCache {
+    cache: HashMap<StakeWithdrawAuthorityPair, CollectedStakeAccounts>,
 }
 
-impl<'a> StakeAccountsCache<'a> {
+impl StakeAccountsCache {
     pub async fn get(
         &mut self,
         rpc_client: Arc<RpcClient>,
-        withdraw_authority: &'a Pubkey,
-        stake_authority: &'a Pubkey,
+        withdraw_authority: &Pubkey,
+        stake_authority: &Pubkey,
     ) -> anyhow::Result<&CollectedStakeAccounts> {
         let stake_withdraw_pair =
             StakeWithdrawAuthorityPair::new(withdraw_authority, stake_authority);